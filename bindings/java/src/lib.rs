@@ -144,7 +144,7 @@ fn make_metadata<'a>(env: &mut Env<'a>, metadata: Metadata) -> Result<JObject<'a
     let mode = match metadata.mode() {
         EntryMode::FILE => 0,
         EntryMode::DIR => 1,
-        EntryMode::Unknown => 2,
+        EntryMode::LINK | EntryMode::Unknown => 2,
     };
 
     let last_modified = metadata.last_modified().map_or_else(
@@ -236,6 +236,7 @@ fn make_write_options<'a>(
         user_metadata: convert::read_map_field(env, options, "userMetadata")?,
         concurrent,
         chunk: convert::read_jlong_field_to_usize(env, options, "chunk")?,
+        ..Default::default()
     })
 }
 
@@ -249,6 +250,7 @@ fn make_list_options<'a>(
         recursive: convert::read_bool_field(env, options, "recursive").unwrap_or_default(),
         versions: convert::read_bool_field(env, options, "versions").unwrap_or_default(),
         deleted: convert::read_bool_field(env, options, "deleted").unwrap_or_default(),
+        ..Default::default()
     })
 }
 
@@ -274,6 +276,7 @@ fn make_stat_options(env: &mut Env, options: &JObject) -> Result<opendal::option
             options,
             "overrideContentDisposition",
         )?,
+        ..Default::default()
     })
 }
 
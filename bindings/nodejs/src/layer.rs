@@ -430,8 +430,8 @@ impl NodeLayer for opendal::layers::ThrottleLayer {
 ///
 /// # Note
 ///
-/// When setting the ThrottleLayer, always consider the largest possible operation size as the burst size,
-/// as **the burst size should be larger than any possible byte length to allow it to pass through**.
+/// The quota is shared by both read and write. Bytes larger than the burst size are consumed
+/// in multiple rounds, so a smaller burst size leads to smoother traffic.
 ///
 /// # Examples
 ///
@@ -94,6 +94,7 @@ impl From<StatOptions> for opendal::options::StatOptions {
             override_content_type: value.override_content_type,
             override_cache_control: value.override_cache_control,
             override_content_disposition: value.override_content_disposition,
+            checksum: false,
         }
     }
 }
@@ -232,6 +233,7 @@ impl From<ReadOptions> for opendal::options::ReadOptions {
             if_modified_since,
             if_unmodified_since,
             content_length_hint: None,
+            verify_checksum: false,
             override_content_type: value.content_type,
             override_cache_control: value.cache_control,
             override_content_disposition: value.content_disposition,
@@ -409,6 +411,7 @@ impl From<ListOptions> for opendal::options::ListOptions {
             recursive: value.recursive.unwrap_or_default(),
             versions: value.versions.unwrap_or_default(),
             deleted: value.deleted.unwrap_or_default(),
+            ..Default::default()
         }
     }
 }
@@ -503,6 +506,7 @@ impl From<WriteOptions> for opendal::options::WriteOptions {
             if_none_match: value.if_none_match,
             if_not_exists: value.if_not_exists.unwrap_or_default(),
             concurrent: value.concurrent.unwrap_or_default() as usize,
+            ..Default::default()
         }
     }
 }
@@ -520,6 +524,7 @@ impl From<DeleteOptions> for opendal::options::DeleteOptions {
         Self {
             version: value.version,
             recursive: value.recursive.unwrap_or_default(),
+            ..Default::default()
         }
     }
 }
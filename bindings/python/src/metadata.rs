@@ -219,7 +219,7 @@ impl EntryMode {
         match mode {
             ocore::EntryMode::FILE => Self::FILE,
             ocore::EntryMode::DIR => Self::DIR,
-            ocore::EntryMode::Unknown => Self::Unknown,
+            ocore::EntryMode::LINK | ocore::EntryMode::Unknown => Self::Unknown,
         }
     }
 }
@@ -542,6 +542,7 @@ impl Operator {
             let opts = ocore::options::DeleteOptions {
                 version,
                 recursive: recursive.unwrap_or(false),
+                ..Default::default()
             };
             self.core.delete_options(&path, opts).map_err(format_pyerr)
         } else {
@@ -1343,6 +1344,7 @@ impl AsyncOperator {
                 let opts = ocore::options::DeleteOptions {
                     version,
                     recursive: recursive.unwrap_or(false),
+                    ..Default::default()
                 };
                 this.delete_options(&path, opts).await.map_err(format_pyerr)
             } else {
@@ -154,6 +154,7 @@ impl From<ReadOptions> for ocore::options::ReadOptions {
             if_modified_since: opts.if_modified_since.map(Into::into),
             if_unmodified_since: opts.if_unmodified_since.map(Into::into),
             content_length_hint: None,
+            verify_checksum: false,
             concurrent: opts.concurrent.unwrap_or_default(),
             chunk: opts.chunk,
             gap: opts.gap,
@@ -195,6 +196,7 @@ impl From<WriteOptions> for ocore::options::WriteOptions {
             if_match: opts.if_match,
             if_none_match: opts.if_none_match,
             if_not_exists: opts.if_not_exists.unwrap_or(false),
+            ..Default::default()
         }
     }
 }
@@ -233,6 +235,7 @@ impl From<ListOptions> for ocore::options::ListOptions {
             recursive: opts.recursive.unwrap_or(false),
             versions: opts.versions.unwrap_or(false),
             deleted: opts.deleted.unwrap_or(false),
+            ..Default::default()
         }
     }
 }
@@ -280,6 +283,7 @@ impl From<StatOptions> for ocore::options::StatOptions {
             override_content_type: opts.content_type,
             override_cache_control: opts.cache_control,
             override_content_disposition: opts.content_disposition,
+            checksum: false,
         }
     }
 }
@@ -309,6 +313,7 @@ impl From<DeleteOptions> for ocore::options::DeleteOptions {
         Self {
             version: opts.version,
             recursive: opts.recursive.unwrap_or(false),
+            ..Default::default()
         }
     }
 }
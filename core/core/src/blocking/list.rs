@@ -45,6 +45,13 @@ impl Lister {
             lister: Some(lister),
         }
     }
+
+    /// Get the token to resume this listing after the last returned entry.
+    ///
+    /// See [`crate::Lister::next_token`] for details.
+    pub fn next_token(&self) -> Option<&str> {
+        self.lister.as_ref().and_then(|v| v.next_token())
+    }
 }
 
 impl Iterator for Lister {
@@ -20,6 +20,7 @@ use std::time::Duration;
 use tokio::runtime::Handle;
 
 use crate::Operator as AsyncOperator;
+use crate::raw::PresignedMultipart;
 use crate::raw::PresignedRequest;
 use crate::types::IntoOperatorUri;
 use crate::*;
@@ -193,6 +194,19 @@ impl Operator {
         self.handle.block_on(self.op.presign_stat(path, expire))
     }
 
+    /// Create a presigned request for stat with additional options.
+    ///
+    /// See [`Operator::presign_stat_options`] for more details.
+    pub fn presign_stat_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::StatOptions,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_stat_options(path, expire, opts))
+    }
+
     /// Create a presigned request for read.
     ///
     /// See [`Operator::presign_read`] for more details.
@@ -200,6 +214,19 @@ impl Operator {
         self.handle.block_on(self.op.presign_read(path, expire))
     }
 
+    /// Create a presigned request for read with additional options.
+    ///
+    /// See [`Operator::presign_read_options`] for more details.
+    pub fn presign_read_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::ReadOptions,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_read_options(path, expire, opts))
+    }
+
     /// Create a presigned request for write.
     ///
     /// See [`Operator::presign_write`] for more details.
@@ -207,6 +234,19 @@ impl Operator {
         self.handle.block_on(self.op.presign_write(path, expire))
     }
 
+    /// Create a presigned request for write with additional options.
+    ///
+    /// See [`Operator::presign_write_options`] for more details.
+    pub fn presign_write_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::WriteOptions,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_write_options(path, expire, opts))
+    }
+
     /// Create a presigned request for delete.
     ///
     /// See [`Operator::presign_delete`] for more details.
@@ -214,6 +254,87 @@ impl Operator {
         self.handle.block_on(self.op.presign_delete(path, expire))
     }
 
+    /// Create a presigned request for delete with additional options.
+    ///
+    /// See [`Operator::presign_delete_options`] for more details.
+    pub fn presign_delete_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::DeleteOptions,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_delete_options(path, expire, opts))
+    }
+
+    /// Create a presigned request for creating a multipart upload.
+    ///
+    /// See [`Operator::presign_create_multipart`] for more details.
+    pub fn presign_create_multipart(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_create_multipart(path, expire))
+    }
+
+    /// Create a presigned request for creating a multipart upload with additional options.
+    ///
+    /// See [`Operator::presign_create_multipart_options`] for more details.
+    pub fn presign_create_multipart_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::WriteOptions,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_create_multipart_options(path, expire, opts))
+    }
+
+    /// Create a presigned request for uploading a part of a multipart upload.
+    ///
+    /// See [`Operator::presign_upload_part`] for more details.
+    pub fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.handle.block_on(
+            self.op
+                .presign_upload_part(path, upload_id, part_number, expire),
+        )
+    }
+
+    /// Create a presigned request for completing a multipart upload.
+    ///
+    /// See [`Operator::presign_complete_multipart`] for more details.
+    pub fn presign_complete_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.handle
+            .block_on(self.op.presign_complete_multipart(path, upload_id, expire))
+    }
+
+    /// Create presigned requests for uploading parts and completing a multipart upload.
+    ///
+    /// See [`Operator::presign_multipart`] for more details.
+    pub fn presign_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: usize,
+        expire: Duration,
+    ) -> Result<PresignedMultipart> {
+        self.handle
+            .block_on(self.op.presign_multipart(path, upload_id, parts, expire))
+    }
+
     /// Get given path's metadata.
     ///
     /// # Behavior
@@ -355,6 +476,40 @@ impl Operator {
         self.spawn_block(async move { op.create_dir(&path).await })?
     }
 
+    /// Get the access control list of the given path.
+    ///
+    /// Refer to [`crate::Operator::get_acl`] for more details.
+    pub fn get_acl(&self, path: &str) -> Result<Acl> {
+        let op = self.op.clone();
+        let path = path.to_string();
+        self.spawn_block(async move { op.get_acl(&path).await })?
+    }
+
+    /// Set the access control list of the given path.
+    ///
+    /// Refer to [`crate::Operator::set_acl`] for more details.
+    pub fn set_acl(&self, path: &str, acl: Acl) -> Result<()> {
+        let op = self.op.clone();
+        let path = path.to_string();
+        self.spawn_block(async move { op.set_acl(&path, acl).await })?
+    }
+
+    /// Restore an archived file so that it can be read again.
+    ///
+    /// Refer to [`crate::Operator::restore`] for more details.
+    pub fn restore(&self, path: &str) -> Result<()> {
+        self.restore_options(path, options::RestoreOptions::default())
+    }
+
+    /// Restore an archived file with options.
+    ///
+    /// Refer to [`crate::Operator::restore_options`] for more details.
+    pub fn restore_options(&self, path: &str, opts: options::RestoreOptions) -> Result<()> {
+        let op = self.op.clone();
+        let path = path.to_string();
+        self.spawn_block(async move { op.restore_options(&path, opts).await })?
+    }
+
     /// Read the whole path into a bytes.
     ///
     /// This function will allocate a new bytes internally. For more precise memory control or
@@ -532,6 +687,28 @@ impl Operator {
         self.spawn_block(async move { op.copy_options(&from, &to, opts).await })?
     }
 
+    /// Copy a file from `from` in `src` to `to` in this operator.
+    ///
+    /// See [`crate::Operator::copy_from`] for more details.
+    pub fn copy_from(&self, src: &Operator, from: &str, to: &str) -> Result<Metadata> {
+        self.copy_from_options(src, from, to, options::CopyOptions::default())
+    }
+
+    /// Copy a file from `from` in `src` to `to` in this operator with additional options.
+    pub fn copy_from_options(
+        &self,
+        src: &Operator,
+        from: &str,
+        to: &str,
+        opts: options::CopyOptions,
+    ) -> Result<Metadata> {
+        let op = self.op.clone();
+        let src = src.op.clone();
+        let from = from.to_string();
+        let to = to.to_string();
+        self.spawn_block(async move { op.copy_from_options(&src, &from, &to, opts).await })?
+    }
+
     /// Create a copier from `from` to `to`.
     ///
     /// This function creates a new [`blocking::Copier`] that implements
@@ -580,6 +757,14 @@ impl Operator {
         self.spawn_block(async move { op.rename(&from, &to).await })?
     }
 
+    /// Rename a file from `from` to `to` with additional options.
+    pub fn rename_options(&self, from: &str, to: &str, opts: options::RenameOptions) -> Result<()> {
+        let op = self.op.clone();
+        let from = from.to_string();
+        let to = to.to_string();
+        self.spawn_block(async move { op.rename_options(&from, &to, opts).await })?
+    }
+
     /// Delete given path.
     ///
     /// # Notes
@@ -736,7 +921,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -797,7 +982,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::IoSlice;
 use std::io::Write;
 
 use futures::AsyncWriteExt;
@@ -63,6 +64,14 @@ impl Write for StdWriter {
         self.handle.block_on(w.write(buf))
     }
 
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        let Some(w) = self.w.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped").into());
+        };
+
+        self.handle.block_on(w.write_vectored(bufs))
+    }
+
     fn flush(&mut self) -> std::io::Result<()> {
         let Some(w) = self.w.as_mut() else {
             return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped").into());
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use bytes::Bytes;
+
 use super::std_writer::StdWriter;
 use crate::Writer as AsyncWriter;
 use crate::*;
@@ -73,6 +75,17 @@ impl Writer {
         self.handle.block_on(inner.write(bs))
     }
 
+    /// Write a list of [`Bytes`] into writer without coalescing them.
+    ///
+    /// Read [`Writer::write_vectored`](crate::Writer::write_vectored) for more details.
+    pub fn write_vectored(&mut self, bufs: impl IntoIterator<Item = Bytes>) -> Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped"));
+        };
+
+        self.handle.block_on(inner.write_vectored(bufs))
+    }
+
     /// Close the writer and make sure all data have been committed.
     ///
     /// ## Notes
@@ -186,6 +186,33 @@ impl Service for CapabilityOverrideService {
     ) -> Result<RpPresign> {
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -85,9 +85,13 @@ impl Service for CompleteService {
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
-        let append = args.append();
+        // Content length of the file is unknown to writers that don't start
+        // from the beginning.
+        let append = args.append() || args.offset().is_some();
+        // Data recorded by the checkpoint has been written before suspending.
+        let size = args.checkpoint().map(|v| v.size()).unwrap_or_default();
         let w = self.inner.write(ctx, path, args)?;
-        Ok(CompleteWriter::new(w, append))
+        Ok(CompleteWriter::new(w, append, size))
     }
 
     fn copy(
@@ -132,6 +136,33 @@ impl Service for CompleteService {
     ) -> Result<RpPresign> {
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 pub struct CompleteLister {
@@ -229,6 +260,25 @@ impl<R: oio::Read> oio::Read for CompleteReader<R> {
         check_complete(size, buffer.len() as u64)?;
         Ok((rp, buffer))
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let sizes = ranges
+            .iter()
+            .map(|v| if v.is_suffix() { None } else { v.size() })
+            .collect::<Vec<_>>();
+        let (rp, buffers) = self.inner.read_ranges(ranges).await?;
+        if buffers.len() != sizes.len() {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "reader got unexpected ranges count")
+                    .with_context("expect", sizes.len())
+                    .with_context("actual", buffers.len()),
+            );
+        }
+        for (size, buffer) in sizes.into_iter().zip(buffers.iter()) {
+            check_complete(size, buffer.len() as u64)?;
+        }
+        Ok((rp, buffers))
+    }
 }
 
 pub struct CompleteReadStream<R> {
@@ -314,11 +364,11 @@ pub struct CompleteWriter<W> {
 }
 
 impl<W> CompleteWriter<W> {
-    pub fn new(inner: W, append: bool) -> CompleteWriter<W> {
+    pub fn new(inner: W, append: bool, size: u64) -> CompleteWriter<W> {
         CompleteWriter {
             inner: Some(inner),
             append,
-            size: 0,
+            size,
             state: CompleteState::Open,
         }
     }
@@ -402,6 +452,9 @@ where
         if ret.content_length() == 0 {
             ret = ret.with_content_length(self.size);
         }
+        if ret.mode() == EntryMode::Unknown {
+            ret.set_mode(EntryMode::FILE);
+        }
         self.inner = None;
         self.state.transition(CompleteState::Closed);
 
@@ -421,6 +474,21 @@ where
 
         Ok(())
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let w = self.inner.as_mut().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
+        })?;
+
+        let checkpoint = w
+            .suspend()
+            .await
+            .inspect_err(|_| self.state.transition(CompleteState::Error))?;
+        self.inner = None;
+        self.state.transition(CompleteState::Closed);
+
+        Ok(checkpoint)
+    }
 }
 
 #[cfg(test)]
@@ -473,4 +541,33 @@ mod tests {
 
         assert_eq!(err.kind(), ErrorKind::Unexpected);
     }
+
+    struct MockWriter;
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, _: Buffer) -> Result<()> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::default().with_etag("\"abc\"".to_string()))
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_writer_completes_metadata() {
+        let mut writer = CompleteWriter::new(MockWriter, false, 0);
+        oio::Write::write(&mut writer, Buffer::from("hello"))
+            .await
+            .unwrap();
+
+        let meta = oio::Write::close(&mut writer).await.unwrap();
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(meta.etag(), Some("\"abc\""));
+    }
 }
@@ -133,6 +133,18 @@ impl Service for CorrectnessService {
         if args.append() && !capability.write_can_append {
             return Err(new_unsupported_error(scheme, Operation::Write, "append"));
         }
+        if args.offset().is_some() {
+            if !capability.write_can_seek {
+                return Err(new_unsupported_error(scheme, Operation::Write, "offset"));
+            }
+            if args.append() {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "offset can't be used together with append",
+                )
+                .with_operation(Operation::Write));
+            }
+        }
         if args.if_not_exists() && !capability.write_with_if_not_exists {
             return Err(new_unsupported_error(
                 scheme,
@@ -143,6 +155,33 @@ impl Service for CorrectnessService {
         if args.if_match().is_some() && !capability.write_with_if_match {
             return Err(new_unsupported_error(scheme, Operation::Write, "if_match"));
         }
+        if args.immutability_until().is_some() && !capability.write_with_immutability_until {
+            return Err(new_unsupported_error(
+                scheme,
+                Operation::Write,
+                "immutability_until",
+            ));
+        }
+        if args.legal_hold() && !capability.write_with_legal_hold {
+            return Err(new_unsupported_error(
+                scheme,
+                Operation::Write,
+                "legal_hold",
+            ));
+        }
+        if args.lock() && !capability.write_with_lock {
+            return Err(new_unsupported_error(scheme, Operation::Write, "lock"));
+        }
+        if args.ttl().is_some() && !capability.write_with_ttl {
+            return Err(new_unsupported_error(scheme, Operation::Write, "ttl"));
+        }
+        if args.checkpoint().is_some() && !capability.write_with_checkpoint {
+            return Err(new_unsupported_error(
+                scheme,
+                Operation::Write,
+                "checkpoint",
+            ));
+        }
         if let Some(if_none_match) = args.if_none_match() {
             if !capability.write_with_if_none_match {
                 let mut err = new_unsupported_error(scheme, Operation::Write, "if_none_match");
@@ -229,6 +268,22 @@ impl Service for CorrectnessService {
     }
 
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let capability = self.capability();
+        if args.version().is_some() && !capability.list_with_version {
+            return Err(new_unsupported_error(
+                self.info().scheme(),
+                Operation::List,
+                "version",
+            ));
+        }
+        if args.watch() && !capability.list_with_watch {
+            return Err(new_unsupported_error(
+                self.info().scheme(),
+                Operation::List,
+                "watch",
+            ));
+        }
+
         self.inner.list(ctx, path, args)
     }
 
@@ -248,6 +303,14 @@ impl Service for CorrectnessService {
         to: &str,
         args: OpRename,
     ) -> Result<RpRename> {
+        if args.atomic() && !self.capability().rename_is_atomic {
+            return Err(new_unsupported_error(
+                self.info().scheme(),
+                Operation::Rename,
+                "atomic",
+            ));
+        }
+
         self.inner.rename(ctx, from, to, args).await
     }
 
@@ -259,6 +322,33 @@ impl Service for CorrectnessService {
     ) -> Result<RpPresign> {
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 pub struct CheckWrapper<T> {
@@ -390,10 +480,7 @@ mod tests {
             _: &str,
             _: OpRename,
         ) -> Result<RpRename> {
-            Err(Error::new(
-                ErrorKind::Unsupported,
-                "operation is not supported",
-            ))
+            Ok(RpRename::new())
         }
 
         async fn presign(&self, _: &OperationContext, _: &str, _: OpPresign) -> Result<RpPresign> {
@@ -495,6 +582,25 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_list() {
+        let op = new_test_operator(Capability {
+            list: true,
+            ..Default::default()
+        });
+        let res = op.lister_with("path/").version("version").await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            list: true,
+            list_with_version: true,
+            ..Default::default()
+        });
+        let res = op.lister_with("path/").version("version").await;
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_write_with() {
         let op = new_test_operator(Capability {
@@ -542,6 +648,39 @@ mod tests {
         });
         let res = op.writer_with("path").append(true).await;
         assert!(res.is_ok());
+
+        let res = op.writer_with("path").offset(4096).await;
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            write: true,
+            write_can_append: true,
+            write_can_seek: true,
+            ..Default::default()
+        });
+        let res = op.writer_with("path").offset(4096).await;
+        assert!(res.is_ok());
+        let res = op.writer_with("path").offset(4096).append(true).await;
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn test_rename_with() {
+        let op = new_test_operator(Capability {
+            rename: true,
+            ..Default::default()
+        });
+        let res = op.rename_with("from", "to").atomic(true).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            rename: true,
+            rename_is_atomic: true,
+            ..Default::default()
+        });
+        let res = op.rename_with("from", "to").atomic(true).await;
+        assert!(res.is_ok());
     }
 
     #[tokio::test]
@@ -195,6 +195,45 @@ impl Service for ErrorContextService {
                 .with_context("path", path)
         })
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetAcl)
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetAcl)
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await.map_err(|err| {
+            err.with_operation(Operation::Restore)
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+        })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
@@ -260,6 +299,16 @@ impl<T: oio::Read> oio::Read for ErrorContextWrapper<T> {
                 .with_context("range", range.to_string())
         })
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let count = ranges.len();
+        self.inner.read_ranges(ranges).await.map_err(|err| {
+            err.with_operation(Operation::Read)
+                .with_context("service", self.scheme)
+                .with_context("path", &self.path)
+                .with_context("ranges", count)
+        })
+    }
 }
 
 impl<T: oio::ReadStream> oio::ReadStream for ErrorContextWrapper<T> {
@@ -309,6 +358,15 @@ impl<T: oio::Write> oio::Write for ErrorContextWrapper<T> {
                 .with_context("processed", self.processed.to_string())
         })
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await.map_err(|err| {
+            err.with_operation(Operation::Write)
+                .with_context("service", self.scheme)
+                .with_context("path", &self.path)
+                .with_context("written", self.processed.to_string())
+        })
+    }
 }
 
 impl<T: oio::List> oio::List for ErrorContextWrapper<T> {
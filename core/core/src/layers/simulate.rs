@@ -111,8 +111,10 @@ impl SimulateService {
         if self.config.read_with_suffix && cap.read {
             cap.read_with_suffix = true;
         }
-        if self.config.create_dir && cap.list && cap.write_can_empty {
+        if self.config.create_dir && !cap.create_dir && cap.list && cap.write_can_empty {
+            // Marker objects stay until they are deleted.
             cap.create_dir = true;
+            cap.create_dir_persistent = true;
         }
         if self.config.delete_recursive && cap.list && cap.delete {
             cap.delete_with_recursive = true;
@@ -154,24 +156,30 @@ impl SimulateService {
         }
 
         if path.ends_with('/') {
+            let simulate_stat_dir = self.config.stat_dir && capability.list_with_recursive;
+
             if capability.create_dir {
-                let meta = self
-                    .srv
-                    .stat(ctx, path, args.clone())
-                    .await?
-                    .into_metadata();
-
-                if meta.is_file() {
-                    return Err(Error::new(
-                        ErrorKind::NotFound,
-                        "stat expected a directory, but found a file",
-                    ));
-                }
+                let meta = match self.srv.stat(ctx, path, args.clone()).await {
+                    Ok(rp) => Some(rp.into_metadata()),
+                    // Directories without a persistent entry only exist while
+                    // they have children, look for them instead.
+                    Err(err) if err.kind() == ErrorKind::NotFound && simulate_stat_dir => None,
+                    Err(err) => return Err(err),
+                };
+
+                if let Some(meta) = meta {
+                    if meta.is_file() {
+                        return Err(Error::new(
+                            ErrorKind::NotFound,
+                            "stat expected a directory, but found a file",
+                        ));
+                    }
 
-                return Ok(RpStat::new(meta));
+                    return Ok(RpStat::new(meta));
+                }
             }
 
-            if self.config.stat_dir && capability.list_with_recursive {
+            if simulate_stat_dir {
                 let mut l = self.srv.list(
                     ctx,
                     path,
@@ -261,17 +269,22 @@ impl SimulateService {
             ));
         }
 
-        let non_recursive = args.clone().with_recursive(false);
+        let non_recursive = args.with_recursive(false);
 
         let mut lister = self.simulate_list(ctx, path, OpList::new().with_recursive(true))?;
 
-        while let Some(entry) = lister.next().await? {
+        // Fetch the next entry while the previous one is being deleted, so
+        // that listing the next page overlaps with sending a full batch.
+        let mut next = lister.next().await?;
+        while let Some(entry) = next {
             let entry = entry.into_entry();
             let mut entry_args = non_recursive.clone();
             if let Some(version) = entry.metadata().version() {
                 entry_args = entry_args.with_version(version);
             }
-            deleter.delete(entry.path(), entry_args).await?;
+            let (_, entry) =
+                futures::try_join!(deleter.delete(entry.path(), entry_args), lister.next())?;
+            next = entry;
         }
 
         Ok(())
@@ -369,6 +382,33 @@ impl Service for SimulateService {
     ) -> Result<RpPresign> {
         self.srv.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.srv.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.srv.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.srv.restore(ctx, path, args).await
+    }
 }
 
 pub type SimulateLister = FourWays<
@@ -449,6 +489,14 @@ impl oio::Read for SimulateReader {
         let range = self.resolve_range(range).await?;
         self.inner.read(range).await
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let mut resolved = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            resolved.push(self.resolve_range(range).await?);
+        }
+        self.inner.read_ranges(resolved).await
+    }
 }
 
 pub struct ServicerFlatLister {
@@ -774,6 +822,40 @@ mod tests {
         assert!(!srv.capability().read_with_suffix);
     }
 
+    #[test]
+    fn simulate_layer_creates_persistent_dir_markers() {
+        let capability = Capability {
+            list: true,
+            write: true,
+            write_can_empty: true,
+            ..Default::default()
+        };
+        let srv = Arc::new(MockService { capability }) as Servicer;
+
+        let srv = SimulateLayer::default().apply_service(srv);
+
+        assert!(srv.capability().create_dir);
+        assert!(srv.capability().create_dir_persistent);
+    }
+
+    #[test]
+    fn simulate_layer_keeps_native_create_dir_semantics() {
+        let capability = Capability {
+            list: true,
+            write: true,
+            write_can_empty: true,
+            create_dir: true,
+            create_dir_persistent: false,
+            ..Default::default()
+        };
+        let srv = Arc::new(MockService { capability }) as Servicer;
+
+        let srv = SimulateLayer::default().apply_service(srv);
+
+        assert!(srv.capability().create_dir);
+        assert!(!srv.capability().create_dir_persistent);
+    }
+
     #[tokio::test]
     async fn simulate_reader_uses_content_length_hint_for_suffix() -> Result<()> {
         let observed_range = Arc::new(Mutex::new(None));
@@ -238,6 +238,69 @@ pub trait Service: Send + Sync + Debug + Unpin + 'static {
         path: &str,
         args: OpPresign,
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend;
+
+    /// Invoke the `get_acl` operation on the specified path.
+    ///
+    /// Requires [`Capability::get_acl`].
+    ///
+    /// Services that don't support acl can use the default implementation
+    /// which returns `Unsupported`.
+    fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> impl Future<Output = Result<RpGetAcl>> + MaybeSend {
+        let _ = (ctx, path, args);
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ))
+        }
+    }
+
+    /// Invoke the `set_acl` operation on the specified path.
+    ///
+    /// Requires [`Capability::set_acl`].
+    ///
+    /// Services that don't support acl can use the default implementation
+    /// which returns `Unsupported`.
+    fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> impl Future<Output = Result<RpSetAcl>> + MaybeSend {
+        let _ = (ctx, path, args);
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ))
+        }
+    }
+
+    /// Invoke the `restore` operation on the specified path.
+    ///
+    /// Requires [`Capability::restore`].
+    ///
+    /// Services that don't support restore can use the default implementation
+    /// which returns `Unsupported`.
+    fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> impl Future<Output = Result<RpRestore>> + MaybeSend {
+        let _ = (ctx, path, args);
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ))
+        }
+    }
 }
 
 /// `ServiceDyn` is the dyn version of [`Service`].
@@ -317,6 +380,30 @@ pub trait ServiceDyn: Send + Sync + Debug + Unpin + 'static {
         path: &'a str,
         args: OpPresign,
     ) -> BoxedFuture<'a, Result<RpPresign>>;
+
+    /// Dyn version of [`Service::get_acl`].
+    fn get_acl_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpGetAcl,
+    ) -> BoxedFuture<'a, Result<RpGetAcl>>;
+
+    /// Dyn version of [`Service::set_acl`].
+    fn set_acl_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpSetAcl,
+    ) -> BoxedFuture<'a, Result<RpSetAcl>>;
+
+    /// Dyn version of [`Service::restore`].
+    fn restore_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpRestore,
+    ) -> BoxedFuture<'a, Result<RpRestore>>;
 }
 
 /// Type-erased service handle used by layer composition and operators.
@@ -409,6 +496,33 @@ impl<S: Service + ?Sized> ServiceDyn for S {
     ) -> BoxedFuture<'a, Result<RpPresign>> {
         Box::pin(self.presign(ctx, path, args))
     }
+
+    fn get_acl_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpGetAcl,
+    ) -> BoxedFuture<'a, Result<RpGetAcl>> {
+        Box::pin(self.get_acl(ctx, path, args))
+    }
+
+    fn set_acl_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpSetAcl,
+    ) -> BoxedFuture<'a, Result<RpSetAcl>> {
+        Box::pin(self.set_acl(ctx, path, args))
+    }
+
+    fn restore_dyn<'a>(
+        &'a self,
+        ctx: &'a OperationContext,
+        path: &'a str,
+        args: OpRestore,
+    ) -> BoxedFuture<'a, Result<RpRestore>> {
+        Box::pin(self.restore(ctx, path, args))
+    }
 }
 
 /// Service is used behind a [`Servicer`] everywhere.
@@ -485,6 +599,33 @@ impl<T: ServiceDyn + ?Sized> Service for Arc<T> {
     ) -> Result<RpPresign> {
         self.as_ref().presign_dyn(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.as_ref().get_acl_dyn(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.as_ref().set_acl_dyn(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.as_ref().restore_dyn(ctx, path, args).await
+    }
 }
 
 /// Dummy implementation of service.
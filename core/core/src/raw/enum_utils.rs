@@ -81,6 +81,13 @@ impl<ONE: oio::Write, TWO: oio::Write> oio::Write for TwoWays<ONE, TWO> {
             Self::Two(v) => v.abort().await,
         }
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        match self {
+            Self::One(v) => v.suspend().await,
+            Self::Two(v) => v.suspend().await,
+        }
+    }
 }
 
 impl<ONE: oio::Copy, TWO: oio::Copy> oio::Copy for TwoWays<ONE, TWO> {
@@ -165,6 +172,14 @@ impl<ONE: oio::Write, TWO: oio::Write, THREE: oio::Write> oio::Write
             Self::Three(v) => v.abort().await,
         }
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        match self {
+            Self::One(v) => v.suspend().await,
+            Self::Two(v) => v.suspend().await,
+            Self::Three(v) => v.suspend().await,
+        }
+    }
 }
 
 impl<ONE: oio::List, TWO: oio::List, THREE: oio::List> oio::List for ThreeWays<ONE, TWO, THREE> {
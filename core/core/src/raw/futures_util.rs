@@ -22,6 +22,7 @@ use std::sync::atomic::Ordering;
 
 use futures::FutureExt;
 
+use crate::raw::Instant;
 use crate::*;
 
 /// BoxedFuture is the type alias of [`futures::future::BoxFuture`].
@@ -134,6 +135,8 @@ pub struct ConcurrentTasks<I, O> {
 
     /// The maximum number of concurrent tasks.
     concurrent: usize,
+    /// Tunes the number of concurrent tasks within `concurrent` if set.
+    adaptive: Option<AdaptiveConcurrency>,
     /// The maximum number of completed tasks that can be buffered.
     prefetch: usize,
     /// Tracks the number of tasks that have finished execution but have not yet been collected.
@@ -168,12 +171,34 @@ impl<I: Send + 'static, O: Send + 'static> ConcurrentTasks<I, O> {
             tasks: VecDeque::with_capacity(concurrent),
             results: VecDeque::with_capacity(concurrent),
             concurrent,
+            adaptive: None,
             prefetch,
             completed_but_unretrieved: Arc::default(),
             errored: false,
         }
     }
 
+    /// Tune the number of concurrent tasks with given adaptive concurrency.
+    ///
+    /// The maximum number of concurrent tasks will be replaced by
+    /// [`AdaptiveConcurrency::max`].
+    pub fn with_adaptive_concurrency(mut self, adaptive: Option<AdaptiveConcurrency>) -> Self {
+        if let Some(adaptive) = &adaptive {
+            self.concurrent = adaptive.max();
+        }
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Return the number of tasks that are allowed to run right now.
+    #[inline]
+    fn limit(&self) -> usize {
+        match &self.adaptive {
+            Some(adaptive) => adaptive.current().min(self.concurrent),
+            None => self.concurrent,
+        }
+    }
+
     /// Return true if the tasks are running concurrently.
     #[inline]
     fn is_concurrent(&self) -> bool {
@@ -193,7 +218,7 @@ impl<I: Send + 'static, O: Send + 'static> ConcurrentTasks<I, O> {
     pub fn has_remaining(&self) -> bool {
         let completed = self.completed_but_unretrieved.load(Ordering::Relaxed);
         // Allow up to `prefetch` completed tasks to be buffered
-        self.tasks.len() < self.concurrent + completed.min(self.prefetch)
+        self.tasks.len() < self.limit() + completed.min(self.prefetch)
     }
 
     /// Chunk if there are remaining results to fetch.
@@ -205,9 +230,18 @@ impl<I: Send + 'static, O: Send + 'static> ConcurrentTasks<I, O> {
     /// Create a task with given input.
     pub fn create_task(&self, input: I) -> Task<(I, Result<O>)> {
         let completed = self.completed_but_unretrieved.clone();
+        let adaptive = self.adaptive.clone();
+        let start = Instant::now();
 
-        let fut = (self.factory)(input).inspect(move |_| {
+        let fut = (self.factory)(input).inspect(move |(_, o)| {
             completed.fetch_add(1, Ordering::Relaxed);
+            if let Some(adaptive) = adaptive {
+                match o {
+                    Ok(_) => adaptive.record(start.elapsed(), true),
+                    Err(err) if err.is_temporary() => adaptive.record(start.elapsed(), false),
+                    Err(_) => {}
+                }
+            }
         });
 
         self.executor.execute(fut)
@@ -239,7 +273,9 @@ impl<I: Send + 'static, O: Send + 'static> ConcurrentTasks<I, O> {
             };
         }
 
-        if !self.has_remaining() {
+        // Use `while` here since adaptive concurrency could lower the limit
+        // below the number of ongoing tasks.
+        while !self.has_remaining() {
             let (i, o) = self
                 .tasks
                 .front_mut()
@@ -466,4 +502,39 @@ mod tests {
         // Should be full again
         assert!(!tasks.has_remaining(), "Should be full again");
     }
+
+    #[tokio::test]
+    async fn test_adaptive_concurrency() {
+        let executor = Executor::new();
+        let adaptive = AdaptiveConcurrency::new(8);
+
+        let mut tasks = ConcurrentTasks::new(executor, 1, 0, |i: usize| {
+            Box::pin(async move {
+                sleep(Duration::from_millis(10)).await;
+                (i, Ok(i))
+            })
+        })
+        .with_adaptive_concurrency(Some(adaptive.clone()));
+
+        // Start with only one task in flight.
+        assert_eq!(tasks.limit(), 1);
+        tasks.execute(0).await.unwrap();
+        assert!(!tasks.has_remaining());
+
+        let mut ans = vec![];
+        for i in 1..256 {
+            tasks.execute(i).await.unwrap();
+            while let Some(o) = tasks.results.pop_front() {
+                ans.push(o);
+            }
+        }
+        while let Some(o) = tasks.next().await {
+            ans.push(o.unwrap());
+        }
+
+        assert_eq!(ans, (0..256).collect::<Vec<_>>());
+        assert_eq!(adaptive.succeeded(), 256);
+        assert!(adaptive.current() > 1, "concurrency should ramp up");
+        assert!(adaptive.current() <= 8, "concurrency must not exceed max");
+    }
 }
@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::*;
+
+/// Glob is a compiled glob pattern used to match entry paths.
+///
+/// # Syntax
+///
+/// - `*` matches any sequence of characters except `/`.
+/// - `**` matches any sequence of characters including `/`, and `**/`
+///   also matches an empty sequence of directories.
+/// - `?` matches exactly one character except `/`.
+/// - `[abc]`, `[a-z]` match one character from the class, `[!abc]` or `[^abc]`
+///   match one character not in the class.
+/// - `{a,b}` matches any of the comma separated alternatives.
+/// - `\` escapes the next character.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    pattern: String,
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone)]
+enum Token {
+    Char(char),
+    Any,
+    Star,
+    DoubleStar,
+    DoubleStarSlash,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    Alt(Vec<Vec<Token>>),
+}
+
+impl Glob {
+    /// Compile the given glob pattern.
+    ///
+    /// Returns [`ErrorKind::ConfigInvalid`] if the pattern is malformed, for example
+    /// an unclosed `[` or `{`.
+    pub fn new(pattern: &str) -> Result<Self> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut pos = 0;
+        let tokens = parse(&chars, &mut pos, false).map_err(|msg| {
+            Error::new(ErrorKind::ConfigInvalid, msg).with_context("glob", pattern)
+        })?;
+        debug_assert_eq!(pos, chars.len());
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            tokens,
+        })
+    }
+
+    /// Get the source pattern of this glob.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Check whether the given path matches this glob.
+    pub fn is_match(&self, path: &str) -> bool {
+        let text: Vec<char> = path.chars().collect();
+        match_tokens(&self.tokens, &text)
+    }
+
+    /// Get the leading directories of this glob that don't contain any glob
+    /// meta characters, such as `logs/2024/` for `logs/2024/*.parquet`.
+    ///
+    /// Every path matched by this glob starts with the returned prefix.
+    pub fn dir_prefix(&self) -> String {
+        let mut prefix = String::new();
+        let mut end = 0;
+        for token in &self.tokens {
+            let Token::Char(c) = token else {
+                break;
+            };
+            prefix.push(*c);
+            if *c == '/' {
+                end = prefix.len();
+            }
+        }
+        prefix.truncate(end);
+        prefix
+    }
+}
+
+/// Check whether the given pattern contains any glob meta characters.
+pub fn has_glob_meta(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '[', ']', '{', '}', '\\'])
+}
+
+fn parse(
+    chars: &[char],
+    pos: &mut usize,
+    in_alt: bool,
+) -> std::result::Result<Vec<Token>, &'static str> {
+    let mut tokens = Vec::new();
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        match c {
+            ',' | '}' if in_alt => return Ok(tokens),
+            '*' => {
+                if chars.get(*pos + 1) == Some(&'*') {
+                    *pos += 2;
+                    if chars.get(*pos) == Some(&'/') {
+                        *pos += 1;
+                        tokens.push(Token::DoubleStarSlash);
+                    } else {
+                        tokens.push(Token::DoubleStar);
+                    }
+                } else {
+                    *pos += 1;
+                    tokens.push(Token::Star);
+                }
+            }
+            '?' => {
+                *pos += 1;
+                tokens.push(Token::Any);
+            }
+            '\\' => {
+                let Some(&next) = chars.get(*pos + 1) else {
+                    return Err("pattern ends with a dangling escape");
+                };
+                *pos += 2;
+                tokens.push(Token::Char(next));
+            }
+            '[' => {
+                *pos += 1;
+                tokens.push(parse_class(chars, pos)?);
+            }
+            '{' => {
+                *pos += 1;
+                let mut alts = Vec::new();
+                loop {
+                    alts.push(parse(chars, pos, true)?);
+                    match chars.get(*pos) {
+                        Some(',') => *pos += 1,
+                        Some('}') => {
+                            *pos += 1;
+                            break;
+                        }
+                        _ => return Err("unclosed `{` in pattern"),
+                    }
+                }
+                tokens.push(Token::Alt(alts));
+            }
+            _ => {
+                *pos += 1;
+                tokens.push(Token::Char(c));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_class(chars: &[char], pos: &mut usize) -> std::result::Result<Token, &'static str> {
+    let negated = matches!(chars.get(*pos), Some('!') | Some('^'));
+    if negated {
+        *pos += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut first = true;
+    loop {
+        let Some(&c) = chars.get(*pos) else {
+            return Err("unclosed `[` in pattern");
+        };
+        // A `]` right after the opening bracket is a literal.
+        if c == ']' && !first {
+            *pos += 1;
+            return Ok(Token::Class { negated, ranges });
+        }
+        first = false;
+
+        let start = if c == '\\' {
+            *pos += 1;
+            *chars.get(*pos).ok_or("unclosed `[` in pattern")?
+        } else {
+            c
+        };
+        *pos += 1;
+
+        if chars.get(*pos) == Some(&'-') && chars.get(*pos + 1).is_some_and(|c| *c != ']') {
+            let end = chars[*pos + 1];
+            *pos += 2;
+            ranges.push((start, end));
+        } else {
+            ranges.push((start, start));
+        }
+    }
+}
+
+fn match_tokens(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+
+    match token {
+        Token::Char(c) => text.first() == Some(c) && match_tokens(rest, &text[1..]),
+        Token::Any => text.first().is_some_and(|c| *c != '/') && match_tokens(rest, &text[1..]),
+        Token::Class { negated, ranges } => match text.first() {
+            Some(&c) if c != '/' => {
+                let hit = ranges.iter().any(|(lo, hi)| *lo <= c && c <= *hi);
+                hit != *negated && match_tokens(rest, &text[1..])
+            }
+            _ => false,
+        },
+        Token::Star => {
+            for i in 0..=text.len() {
+                if match_tokens(rest, &text[i..]) {
+                    return true;
+                }
+                if text.get(i) == Some(&'/') {
+                    return false;
+                }
+            }
+            false
+        }
+        Token::DoubleStar => (0..=text.len()).any(|i| match_tokens(rest, &text[i..])),
+        Token::DoubleStarSlash => {
+            match_tokens(rest, text)
+                || (0..text.len())
+                    .filter(|i| text[*i] == '/')
+                    .any(|i| match_tokens(rest, &text[i + 1..]))
+        }
+        Token::Alt(alts) => alts.iter().any(|alt| {
+            let mut tokens = alt.clone();
+            tokens.extend_from_slice(rest);
+            match_tokens(&tokens, text)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_is_match() {
+        let cases = vec![
+            ("literal", "a.txt", "a.txt", true),
+            ("literal mismatch", "a.txt", "b.txt", false),
+            ("star", "*.parquet", "part-0.parquet", true),
+            (
+                "star stops at slash",
+                "*.parquet",
+                "dir/part-0.parquet",
+                false,
+            ),
+            ("star empty", "a*", "a", true),
+            ("double star", "**.parquet", "dir/part-0.parquet", true),
+            ("double star slash zero dirs", "**/*.csv", "a.csv", true),
+            ("double star slash nested", "**/*.csv", "x/y/a.csv", true),
+            (
+                "double star slash mismatch",
+                "**/*.csv",
+                "x/y/a.json",
+                false,
+            ),
+            ("question", "file_?", "file_a", true),
+            ("question no slash", "a?b", "a/b", false),
+            ("class", "[ab].txt", "b.txt", true),
+            ("class range", "log-[0-9]", "log-7", true),
+            ("class negated", "log-[!0-9]", "log-7", false),
+            ("class caret negated", "log-[^0-9]", "log-x", true),
+            ("class literal bracket", "[]]", "]", true),
+            ("alternatives", "*.{csv,json}", "a.json", true),
+            ("alternatives mismatch", "*.{csv,json}", "a.txt", false),
+            ("alternatives with glob", "{logs/**,*.md}", "logs/a/b", true),
+            ("escape", "a\\*b", "a*b", true),
+            ("escape mismatch", "a\\*b", "axb", false),
+            ("star skips dir", "*", "dir/", false),
+            ("star slash dir", "*/", "dir/", true),
+        ];
+
+        for (name, pattern, path, expected) in cases {
+            let glob = Glob::new(pattern).expect("pattern must be valid");
+            assert_eq!(glob.is_match(path), expected, "{name}: {pattern} ~ {path}");
+        }
+    }
+
+    #[test]
+    fn test_glob_dir_prefix() {
+        let cases = vec![
+            ("**/*.parquet", ""),
+            ("*.csv", ""),
+            ("logs/2024/*.parquet", "logs/2024/"),
+            ("logs/20*/a", "logs/"),
+            ("logs/{a,b}/c", "logs/"),
+            ("a\\*b/c/*", "a*b/c/"),
+            ("logs/", "logs/"),
+        ];
+
+        for (pattern, expected) in cases {
+            let glob = Glob::new(pattern).expect("pattern must be valid");
+            assert_eq!(glob.dir_prefix(), expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_glob_invalid() {
+        for pattern in ["[abc", "{a,b", "abc\\"] {
+            let err = Glob::new(pattern).expect_err("pattern must be invalid");
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "{pattern}");
+        }
+    }
+}
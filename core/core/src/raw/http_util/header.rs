@@ -37,6 +37,7 @@ use crate::EntryMode;
 use crate::Error;
 use crate::ErrorKind;
 use crate::Metadata;
+use crate::RestoreStatus;
 use crate::Result;
 use crate::raw::*;
 
@@ -113,6 +114,41 @@ pub fn parse_multipart_boundary(headers: &HeaderMap) -> Result<Option<&str>> {
     parse_header_to_str(headers, CONTENT_TYPE).map(|v| v.and_then(|v| v.split("boundary=").nth(1)))
 }
 
+/// Parse the restore status from the given header.
+///
+/// The value is in the format used by s3 compatible services, such as
+/// `ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"`.
+pub fn parse_restore_status<K>(headers: &HeaderMap, name: K) -> Result<Option<RestoreStatus>>
+where
+    HeaderName: TryFrom<K>,
+{
+    let Some(v) = parse_header_to_str(headers, name)? else {
+        return Ok(None);
+    };
+
+    // The expiry date contains `,` so the fields can't be split by it.
+    let field = |key: &str| {
+        let start = v.find(key)? + key.len();
+        let end = v[start..].find('"')?;
+        Some(&v[start..start + end])
+    };
+
+    match field("ongoing-request=\"") {
+        Some("true") => Ok(Some(RestoreStatus::InProgress)),
+        Some("false") => {
+            let expires = field("expiry-date=\"")
+                .map(Timestamp::parse_rfc2822)
+                .transpose()?;
+            Ok(Some(RestoreStatus::Restored { expires }))
+        }
+        _ => Err(
+            Error::new(ErrorKind::Unexpected, "restore header is invalid")
+                .with_operation("http_util::parse_restore_status")
+                .with_context("value", v),
+        ),
+    }
+}
+
 /// Parse header value to string according to name.
 #[inline]
 pub fn parse_header_to_str<K>(headers: &HeaderMap, name: K) -> Result<Option<&str>>
@@ -366,4 +402,41 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn test_parse_restore_status() {
+        let cases = vec![
+            (r#"ongoing-request="true""#, Some(RestoreStatus::InProgress)),
+            (
+                r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#,
+                Some(RestoreStatus::Restored {
+                    expires: Some("2012-12-21T00:00:00Z".parse().unwrap()),
+                }),
+            ),
+            (
+                r#"ongoing-request="false""#,
+                Some(RestoreStatus::Restored { expires: None }),
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-amz-restore", HeaderValue::from_str(input).unwrap());
+
+            let actual =
+                parse_restore_status(&headers, "x-amz-restore").expect("parse must success");
+
+            assert_eq!(actual, expected, "{input}")
+        }
+
+        let headers = HeaderMap::new();
+        assert_eq!(
+            parse_restore_status(&headers, "x-amz-restore").unwrap(),
+            None
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-restore", HeaderValue::from_static("invalid"));
+        assert!(parse_restore_status(&headers, "x-amz-restore").is_err());
+    }
 }
@@ -41,6 +41,7 @@ pub use header::parse_last_modified;
 pub use header::parse_location;
 pub use header::parse_multipart_boundary;
 pub use header::parse_prefixed_headers;
+pub use header::parse_restore_status;
 
 mod uri;
 pub use uri::QueryPairsWriter;
@@ -63,3 +64,4 @@ pub use multipart::MixedPart;
 pub use multipart::Multipart;
 pub use multipart::Part;
 pub use multipart::RelatedPart;
+pub use multipart::parse_multipart_byteranges;
@@ -31,9 +31,11 @@ use http::Uri;
 use http::Version;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
 use http::uri::PathAndQuery;
 
+use super::BytesContentRange;
 use super::new_request_build_error;
 use crate::*;
 
@@ -628,6 +630,67 @@ impl Part for RelatedPart {
     }
 }
 
+/// Parse a `multipart/byteranges` response body into content ranges and parts.
+///
+/// The body of each part is located by its `Content-Range` header, so binary
+/// data that happens to contain the boundary is handled correctly.
+pub fn parse_multipart_byteranges(
+    boundary: &str,
+    bs: Bytes,
+) -> Result<Vec<(BytesContentRange, Bytes)>> {
+    let invalid = |msg: &'static str| {
+        Error::new(ErrorKind::Unexpected, msg)
+            .with_operation("http_util::parse_multipart_byteranges")
+    };
+
+    let delimiter = format!("--{boundary}");
+    let delimiter = delimiter.as_bytes();
+    let mut parts = vec![];
+    let mut pos = find_bytes(&bs, delimiter, 0)
+        .ok_or_else(|| invalid("multipart byteranges response doesn't contain boundary"))?;
+
+    loop {
+        pos += delimiter.len();
+        if bs[pos..].starts_with(b"--") {
+            return Ok(parts);
+        }
+
+        let header_end = find_bytes(&bs, b"\r\n\r\n", pos)
+            .ok_or_else(|| invalid("multipart byteranges part doesn't contain headers"))?;
+        let headers = std::str::from_utf8(&bs[pos..header_end]).map_err(|err| {
+            invalid("multipart byteranges part headers are invalid").set_source(err)
+        })?;
+        let content_range = headers
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(k, _)| k.trim().eq_ignore_ascii_case(CONTENT_RANGE.as_str()))
+            .map(|(_, v)| v.trim())
+            .ok_or_else(|| invalid("multipart byteranges part doesn't contain content range"))?;
+        let content_range = BytesContentRange::from_str(content_range)?;
+        let len = content_range
+            .len()
+            .ok_or_else(|| invalid("multipart byteranges part content range is unknown"))?;
+
+        let start = header_end + 4;
+        let end = start + len as usize;
+        if end > bs.len() {
+            return Err(invalid("multipart byteranges part is truncated"));
+        }
+        parts.push((content_range, bs.slice(start..end)));
+
+        pos = find_bytes(&bs, delimiter, end)
+            .ok_or_else(|| invalid("multipart byteranges response doesn't contain end boundary"))?;
+    }
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|v| v + from)
+}
+
 #[cfg(test)]
 mod tests {
     use http::header::CONTENT_TYPE;
@@ -1177,4 +1240,38 @@ This is a text file.
 
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_parse_multipart_byteranges() -> Result<()> {
+        let body = Bytes::from_static(
+            b"--3d6b6a416f9b5\r\n\
+Content-Type: application/octet-stream\r\n\
+Content-Range: bytes 0-4/100\r\n\
+\r\n\
+--3d6\r\n\
+--3d6b6a416f9b5\r\n\
+content-range: bytes 50-52/100\r\n\
+\r\n\
+xyz\r\n\
+--3d6b6a416f9b5--\r\n",
+        );
+
+        let parts = parse_multipart_byteranges("3d6b6a416f9b5", body)?;
+        assert_eq!(
+            parts,
+            vec![
+                (
+                    BytesContentRange::default().with_range(0, 4).with_size(100),
+                    Bytes::from_static(b"--3d6")
+                ),
+                (
+                    BytesContentRange::default()
+                        .with_range(50, 52)
+                        .with_size(100),
+                    Bytes::from_static(b"xyz")
+                ),
+            ]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#[cfg(feature = "list-regex")]
+use regex_lite::Regex;
+
+use crate::raw::*;
+use crate::*;
+
+/// FilterLister is used to filter entries by suffix, glob pattern and regex.
+///
+/// Services that can't push `suffix` or `glob` down to the server could wrap
+/// their lister with `FilterLister` to apply the filter on the client side.
+///
+/// - `suffix` is matched against the full entry path.
+/// - `glob` and `regex` are matched against the entry path relative to the
+///   directory of the listed path.
+///
+/// Directories keep their trailing `/` in both cases, which is the same as how
+/// object storage services match object names.
+///
+/// The entry of the listed directory itself is filtered like any other entry.
+pub struct FilterLister<L> {
+    lister: L,
+    base: String,
+    suffix: Option<String>,
+    glob: Option<Glob>,
+    #[cfg(feature = "list-regex")]
+    regex: Option<Regex>,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for FilterLister.
+unsafe impl<L> Sync for FilterLister<L> {}
+
+impl<L> FilterLister<L> {
+    /// Create a new filter lister for entries listed under `path`.
+    pub fn new(
+        lister: L,
+        path: &str,
+        suffix: Option<String>,
+        glob: Option<Glob>,
+    ) -> FilterLister<L> {
+        let base = if path.ends_with('/') {
+            path.to_string()
+        } else {
+            get_parent(path).to_string()
+        };
+        let base = if base == "/" { String::new() } else { base };
+
+        FilterLister {
+            lister,
+            base,
+            suffix,
+            glob,
+            #[cfg(feature = "list-regex")]
+            regex: None,
+        }
+    }
+
+    /// Only return entries whose relative path matches the given regex.
+    ///
+    /// Requires the `list-regex` feature.
+    #[cfg(feature = "list-regex")]
+    pub fn with_regex(mut self, regex: Option<Regex>) -> Self {
+        self.regex = regex;
+        self
+    }
+
+    fn is_match(&self, path: &str) -> bool {
+        if let Some(suffix) = &self.suffix {
+            if !path.ends_with(suffix.as_str()) {
+                return false;
+            }
+        }
+
+        let rel = path.strip_prefix(self.base.as_str()).unwrap_or(path);
+        if let Some(glob) = &self.glob {
+            if !glob.is_match(rel) {
+                return false;
+            }
+        }
+        #[cfg(feature = "list-regex")]
+        if let Some(regex) = &self.regex {
+            if !regex.is_match(rel) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<L> oio::List for FilterLister<L>
+where
+    L: oio::List,
+{
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.lister.next().await {
+                Ok(Some(e)) if !self.is_match(e.path()) => continue,
+                v => return v,
+            }
+        }
+    }
+}
@@ -33,3 +33,6 @@ pub use hierarchy_list::HierarchyLister;
 
 mod prefix_list;
 pub use prefix_list::PrefixLister;
+
+mod filter_list;
+pub use filter_list::FilterLister;
@@ -44,6 +44,26 @@ pub trait Read: Unpin + Send + Sync {
     /// Read an exact bounded range into [`Buffer`].
     fn read(&self, range: BytesRange)
     -> impl Future<Output = Result<(RpRead, Buffer)>> + MaybeSend;
+
+    /// Read multiple exact bounded ranges within one request.
+    ///
+    /// Returned buffers must be in the same order as the given ranges.
+    ///
+    /// Only services with `read_with_multi_range` capability need to implement
+    /// this. Callers must fall back to [`Read::read`] when `Unsupported` is
+    /// returned.
+    fn read_ranges(
+        &self,
+        ranges: Vec<BytesRange>,
+    ) -> impl Future<Output = Result<(RpRead, Vec<Buffer>)>> + MaybeSend {
+        let _ = ranges;
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "reader doesn't support read multiple ranges",
+            ))
+        }
+    }
 }
 
 impl Read for () {
@@ -75,6 +95,12 @@ pub trait ReadDyn: Unpin + Send + Sync {
 
     /// The dyn version of [`Read::read`].
     fn read_dyn(&self, range: BytesRange) -> BoxedFuture<'_, Result<(RpRead, Buffer)>>;
+
+    /// The dyn version of [`Read::read_ranges`].
+    fn read_ranges_dyn(
+        &self,
+        ranges: Vec<BytesRange>,
+    ) -> BoxedFuture<'_, Result<(RpRead, Vec<Buffer>)>>;
 }
 
 impl<T: Read + ?Sized> ReadDyn for T {
@@ -88,6 +114,13 @@ impl<T: Read + ?Sized> ReadDyn for T {
     fn read_dyn(&self, range: BytesRange) -> BoxedFuture<'_, Result<(RpRead, Buffer)>> {
         Box::pin(self.read(range))
     }
+
+    fn read_ranges_dyn(
+        &self,
+        ranges: Vec<BytesRange>,
+    ) -> BoxedFuture<'_, Result<(RpRead, Vec<Buffer>)>> {
+        Box::pin(self.read_ranges(ranges))
+    }
 }
 
 impl<T: ReadDyn + ?Sized> Read for Box<T> {
@@ -98,6 +131,10 @@ impl<T: ReadDyn + ?Sized> Read for Box<T> {
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
         self.deref().read_dyn(range).await
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        self.deref().read_ranges_dyn(ranges).await
+    }
 }
 
 /// ReadStream is the internal trait used by OpenDAL to stream data from storage.
@@ -32,6 +32,22 @@ pub trait StreamRead: Send + Sync + Unpin + 'static {
         &self,
         range: BytesRange,
     ) -> impl Future<Output = Result<(RpRead, Box<dyn oio::ReadStreamDyn>)>> + MaybeSend;
+
+    /// Read multiple bounded ranges within one request.
+    ///
+    /// See [`oio::Read::read_ranges`] for details.
+    fn read_ranges(
+        &self,
+        ranges: Vec<BytesRange>,
+    ) -> impl Future<Output = Result<(RpRead, Vec<Buffer>)>> + MaybeSend {
+        let _ = ranges;
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "reader doesn't support read multiple ranges",
+            ))
+        }
+    }
 }
 
 /// StreamReader implements [`oio::Read`] based on [`StreamRead`].
@@ -73,4 +89,35 @@ impl<R: StreamRead> oio::Read for StreamReader<R> {
 
         Ok((rp, buffer))
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let expected = ranges
+            .iter()
+            .map(|range| {
+                range.size().ok_or_else(|| {
+                    Error::new(ErrorKind::Unsupported, "read requires a bounded range")
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (rp, buffers) = self.inner.read_ranges(ranges).await?;
+        if buffers.len() != expected.len() {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "reader got unexpected ranges count")
+                    .with_context("expect", expected.len())
+                    .with_context("actual", buffers.len()),
+            );
+        }
+        for (buffer, expected) in buffers.iter().zip(expected) {
+            if buffer.len() as u64 != expected {
+                return Err(
+                    Error::new(ErrorKind::Unexpected, "reader got unexpected data size")
+                        .with_context("expect", expected)
+                        .with_context("actual", buffer.len() as u64),
+                );
+            }
+        }
+
+        Ok((rp, buffers))
+    }
 }
@@ -37,6 +37,19 @@ pub trait Write: Unpin + Send + Sync {
 
     /// Abort the pending writer.
     fn abort(&mut self) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// Suspend the pending writer and return a checkpoint to resume it later.
+    ///
+    /// The upload must be kept alive on the service side: data that has been
+    /// uploaded is recorded by the checkpoint, while buffered data is dropped.
+    ///
+    /// Writers that can't be resumed will return `Unsupported`.
+    fn suspend(&mut self) -> impl Future<Output = Result<WriteCheckpoint>> + MaybeSend {
+        std::future::ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "output writer doesn't support suspend",
+        )))
+    }
 }
 
 impl Write for () {
@@ -69,6 +82,9 @@ pub trait WriteDyn: Unpin + Send + Sync {
 
     /// The dyn version of [`Write::abort`].
     fn abort_dyn(&mut self) -> BoxedFuture<'_, Result<()>>;
+
+    /// The dyn version of [`Write::suspend`].
+    fn suspend_dyn(&mut self) -> BoxedFuture<'_, Result<WriteCheckpoint>>;
 }
 
 impl<T: Write + ?Sized> WriteDyn for T {
@@ -83,6 +99,10 @@ impl<T: Write + ?Sized> WriteDyn for T {
     fn abort_dyn(&mut self) -> BoxedFuture<'_, Result<()>> {
         Box::pin(self.abort())
     }
+
+    fn suspend_dyn(&mut self) -> BoxedFuture<'_, Result<WriteCheckpoint>> {
+        Box::pin(self.suspend())
+    }
 }
 
 impl<T: WriteDyn + ?Sized> Write for Box<T> {
@@ -97,4 +117,8 @@ impl<T: WriteDyn + ?Sized> Write for Box<T> {
     async fn abort(&mut self) -> Result<()> {
         self.deref_mut().abort_dyn().await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.deref_mut().suspend_dyn().await
+    }
 }
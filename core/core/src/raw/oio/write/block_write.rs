@@ -159,6 +159,14 @@ impl<W: BlockWrite> BlockWriter<W> {
         }
     }
 
+    /// Tune the concurrency of this writer adaptively.
+    ///
+    /// Services should call this with [`OpWrite::adaptive_concurrency`].
+    pub fn with_adaptive_concurrency(mut self, adaptive: Option<AdaptiveConcurrency>) -> Self {
+        self.tasks = self.tasks.with_adaptive_concurrency(adaptive);
+        self
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
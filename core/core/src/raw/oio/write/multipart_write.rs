@@ -20,6 +20,8 @@ use std::sync::Arc;
 use futures::Future;
 use futures::FutureExt;
 use futures::select;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::raw::*;
 use crate::*;
@@ -110,7 +112,7 @@ pub trait MultipartWrite: Send + Sync + Unpin + 'static {
 /// - `part_number` is the index of the part, starting from 0.
 /// - `etag` is the `ETag` of the part.
 /// - `checksum` is the optional checksum of the part.
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MultipartPart {
     /// The number of the part, starting from 0.
     pub part_number: usize,
@@ -140,6 +142,8 @@ pub struct MultipartWriter<W: MultipartWrite> {
     parts: Vec<MultipartPart>,
     cache: Option<Buffer>,
     next_part_number: usize,
+    /// The size of data that has been sent as parts.
+    uploaded: u64,
 
     tasks: ConcurrentTasks<WriteInput<W>, MultipartPart>,
 }
@@ -159,6 +163,7 @@ impl<W: MultipartWrite> MultipartWriter<W> {
             parts: Vec::new(),
             cache: None,
             next_part_number: 0,
+            uploaded: 0,
 
             tasks: ConcurrentTasks::new(executor, concurrent, 8192, |input| {
                 Box::pin({
@@ -196,6 +201,28 @@ impl<W: MultipartWrite> MultipartWriter<W> {
         }
     }
 
+    /// Tune the concurrency of this writer adaptively.
+    ///
+    /// Services should call this with [`OpWrite::adaptive_concurrency`].
+    pub fn with_adaptive_concurrency(mut self, adaptive: Option<AdaptiveConcurrency>) -> Self {
+        self.tasks = self.tasks.with_adaptive_concurrency(adaptive);
+        self
+    }
+
+    /// Resume the multipart upload from the given checkpoint.
+    ///
+    /// Services that support [`Capability::write_with_checkpoint`] should
+    /// call this with [`OpWrite::checkpoint`].
+    pub fn with_checkpoint(mut self, checkpoint: Option<WriteCheckpoint>) -> Self {
+        if let Some(checkpoint) = checkpoint {
+            self.upload_id = Some(Arc::new(checkpoint.upload_id().to_string()));
+            self.parts = checkpoint.parts().to_vec();
+            self.next_part_number = self.parts.len();
+            self.uploaded = checkpoint.size();
+        }
+        self
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
@@ -225,7 +252,12 @@ where
             }
         };
 
-        let bytes = self.cache.clone().expect("pending write must exist");
+        // A resumed upload doesn't have any pending write yet.
+        let Some(bytes) = self.cache.clone() else {
+            self.fill_cache(bs);
+            return Ok(());
+        };
+        let size = bytes.len() as u64;
         let part_number = self.next_part_number;
 
         self.tasks
@@ -239,6 +271,7 @@ where
             .await?;
         self.cache = None;
         self.next_part_number += 1;
+        self.uploaded += size;
         self.fill_cache(bs);
         Ok(())
     }
@@ -305,6 +338,30 @@ where
         self.w.abort_part(&upload_id).await?;
         Ok(())
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let upload_id = match self.upload_id.clone() {
+            Some(v) => v,
+            None => {
+                let upload_id = Arc::new(self.w.initiate_part().await?);
+                self.upload_id = Some(upload_id.clone());
+                upload_id
+            }
+        };
+
+        // The cached write may be smaller than the part size required
+        // by services, callers will write it again after resuming.
+        self.cache = None;
+        while let Some(part) = self.tasks.next().await.transpose()? {
+            self.parts.push(part);
+        }
+
+        Ok(WriteCheckpoint::new(
+            upload_id.to_string(),
+            self.parts.clone(),
+            self.uploaded,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -513,4 +570,34 @@ mod tests {
             assert_eq!(inner.content.clone().unwrap().to_bytes(), bs);
         }
     }
+
+    #[tokio::test]
+    async fn test_multipart_writer_suspend_and_resume() {
+        let test = TestWrite::new();
+
+        let mut w = MultipartWriter::new(Executor::default(), test.clone(), 4);
+        for _ in 0..10 {
+            while w.write(vec![1; 100].into()).await.is_err() {}
+        }
+        let checkpoint = loop {
+            if let Ok(v) = w.suspend().await {
+                break v;
+            }
+        };
+        // The last write is still cached and won't be recorded.
+        assert_eq!(checkpoint.size(), 900);
+        assert_eq!(checkpoint.parts().len(), 9);
+        assert_eq!(checkpoint.upload_id(), test.lock().await.upload_id);
+
+        let mut w = MultipartWriter::new(Executor::default(), test.clone(), 4)
+            .with_checkpoint(Some(checkpoint));
+        for _ in 0..2 {
+            while w.write(vec![1; 100].into()).await.is_err() {}
+        }
+        while w.close().await.is_err() {}
+
+        let actual_parts: Vec<_> = w.parts.into_iter().map(|v| v.part_number).collect();
+        assert_eq!(actual_parts, (0..11).collect::<Vec<_>>());
+        assert_eq!(test.lock().await.length, 1100);
+    }
 }
@@ -49,6 +49,12 @@ pub enum Operation {
     List,
     /// Operation to generate a presigned URL.
     Presign,
+    /// Operation to get the access control list of a path.
+    GetAcl,
+    /// Operation to set the access control list of a path.
+    SetAcl,
+    /// Operation to restore an archived file.
+    Restore,
 }
 
 impl Operation {
@@ -77,6 +83,9 @@ impl From<Operation> for &'static str {
             Operation::Delete => "delete",
             Operation::List => "list",
             Operation::Presign => "presign",
+            Operation::GetAcl => "get_acl",
+            Operation::SetAcl => "set_acl",
+            Operation::Restore => "restore",
         }
     }
 }
@@ -19,7 +19,11 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use crate::Acl;
+use crate::AdaptiveConcurrency;
 use crate::BytesRange;
+use crate::WriteCheckpoint;
+use crate::WriteCompression;
 use crate::options;
 use crate::raw::*;
 
@@ -134,6 +138,22 @@ pub struct OpList {
     ///
     /// Default to `false`
     deleted: bool,
+    /// The version of the namespace to list entries from, such as a git revision.
+    ///
+    /// Default to `None`, which lists the current version.
+    version: Option<String>,
+    /// Only return entries whose path ends with this suffix.
+    suffix: Option<String>,
+    /// Only return entries whose path relative to the listed directory
+    /// matches this glob pattern.
+    glob: Option<String>,
+    /// Only return entries whose path relative to the listed directory
+    /// matches this regular expression.
+    regex: Option<String>,
+    /// Keep streaming changes after existing entries have been listed.
+    ///
+    /// Default to `false`.
+    watch: bool,
 }
 
 impl OpList {
@@ -216,6 +236,61 @@ impl OpList {
     pub fn deleted(&self) -> bool {
         self.deleted
     }
+
+    /// Change the namespace version of this list operation.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Get the namespace version of this list operation.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Change the suffix filter of this list operation.
+    pub fn with_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Get the suffix filter of this list operation.
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    /// Change the glob filter of this list operation.
+    pub fn with_glob(mut self, glob: &str) -> Self {
+        self.glob = Some(glob.to_string());
+        self
+    }
+
+    /// Get the glob filter of this list operation.
+    pub fn glob(&self) -> Option<&str> {
+        self.glob.as_deref()
+    }
+
+    /// Change the regex filter of this list operation.
+    pub fn with_regex(mut self, regex: &str) -> Self {
+        self.regex = Some(regex.to_string());
+        self
+    }
+
+    /// Get the regex filter of this list operation.
+    pub fn regex(&self) -> Option<&str> {
+        self.regex.as_deref()
+    }
+
+    /// Change the watch of this list operation.
+    pub fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Get the watch of this list operation.
+    pub fn watch(&self) -> bool {
+        self.watch
+    }
 }
 
 impl From<options::ListOptions> for OpList {
@@ -226,6 +301,91 @@ impl From<options::ListOptions> for OpList {
             recursive: value.recursive,
             versions: value.versions,
             deleted: value.deleted,
+            version: value.version,
+            suffix: value.suffix,
+            glob: value.glob,
+            regex: value.regex,
+            watch: value.watch,
+        }
+    }
+}
+
+/// Args for `get_acl` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetAcl {}
+
+impl OpGetAcl {
+    /// Create a new `OpGetAcl`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_acl` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetAcl {
+    acl: Acl,
+}
+
+impl OpSetAcl {
+    /// Create a new `OpSetAcl`.
+    pub fn new(acl: Acl) -> Self {
+        Self { acl }
+    }
+
+    /// Get the acl to apply.
+    pub fn acl(&self) -> &Acl {
+        &self.acl
+    }
+}
+
+/// Args for `restore` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone, Default)]
+pub struct OpRestore {
+    days: Option<u32>,
+    tier: Option<String>,
+}
+
+impl OpRestore {
+    /// Create a new `OpRestore`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the days that the restored copy stays readable.
+    pub fn with_days(mut self, days: u32) -> Self {
+        self.days = Some(days);
+        self
+    }
+
+    /// Get the days that the restored copy stays readable.
+    pub fn days(&self) -> Option<u32> {
+        self.days
+    }
+
+    /// Change the retrieval tier of this restore.
+    pub fn with_tier(mut self, tier: &str) -> Self {
+        self.tier = Some(tier.to_string());
+        self
+    }
+
+    /// Get the retrieval tier of this restore.
+    pub fn tier(&self) -> Option<&str> {
+        self.tier.as_deref()
+    }
+}
+
+impl From<options::RestoreOptions> for OpRestore {
+    fn from(value: options::RestoreOptions) -> Self {
+        Self {
+            days: value.days,
+            tier: value.tier,
         }
     }
 }
@@ -277,6 +437,25 @@ pub enum PresignOperation {
     Write(OpWrite),
     /// Presign a delete operation.
     Delete(OpDelete),
+    /// Presign the request that creates a multipart upload.
+    CreateMultipart(OpWrite),
+    /// Presign the request that uploads one part of a multipart upload.
+    ///
+    /// `part_number` starts from `1`.
+    UploadPart {
+        /// The id of the multipart upload.
+        upload_id: String,
+        /// The number of this part, starting from `1`.
+        part_number: usize,
+    },
+    /// Presign the request that completes a multipart upload.
+    ///
+    /// The caller is responsible for sending the list of uploaded parts in
+    /// the service specific body format.
+    CompleteMultipart {
+        /// The id of the multipart upload.
+        upload_id: String,
+    },
 }
 
 impl From<OpStat> for PresignOperation {
@@ -315,6 +494,9 @@ pub struct OpRead {
     override_content_disposition: Option<String>,
     version: Option<String>,
     content_length_hint: Option<u64>,
+    verify_checksum: bool,
+    first_byte_timeout: Option<Duration>,
+    deadline: Option<Duration>,
 }
 
 impl OpRead {
@@ -415,6 +597,42 @@ impl OpRead {
     pub(crate) fn content_length_hint(&self) -> Option<u64> {
         self.content_length_hint
     }
+
+    /// Set whether to verify the content checksum of the option
+    pub fn with_verify_checksum(mut self, verify_checksum: bool) -> Self {
+        self.verify_checksum = verify_checksum;
+        self
+    }
+
+    /// Get whether to verify the content checksum from option
+    ///
+    /// Services could use this to request checksums while reading, like
+    /// sending `x-amz-checksum-mode: ENABLED` for s3.
+    pub fn verify_checksum(&self) -> bool {
+        self.verify_checksum
+    }
+
+    /// Set the timeout for the service to start responding to each read request.
+    pub fn with_first_byte_timeout(mut self, timeout: Duration) -> Self {
+        self.first_byte_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout for the service to start responding to each read request.
+    pub fn first_byte_timeout(&self) -> Option<Duration> {
+        self.first_byte_timeout
+    }
+
+    /// Set the total time allowed for this read.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Get the total time allowed for this read.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
 }
 
 /// Args for reader operation.
@@ -506,6 +724,9 @@ impl From<options::ReadOptions> for (BytesRange, OpRead, OpReader) {
                 override_content_disposition: value.override_content_disposition,
                 version: value.version,
                 content_length_hint: value.content_length_hint,
+                verify_checksum: value.verify_checksum,
+                first_byte_timeout: value.first_byte_timeout,
+                deadline: value.deadline,
             },
             OpReader {
                 // Ensure concurrent is at least 1
@@ -531,6 +752,9 @@ impl From<options::ReaderOptions> for (OpRead, OpReader) {
                 override_content_disposition: None,
                 version: value.version,
                 content_length_hint: value.content_length_hint,
+                verify_checksum: false,
+                first_byte_timeout: value.first_byte_timeout,
+                deadline: value.deadline,
             },
             OpReader {
                 // Ensure concurrent is at least 1
@@ -554,6 +778,7 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    checksum: bool,
 }
 
 impl OpStat {
@@ -650,6 +875,20 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set whether to return the checksum of the option
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Get whether to return the checksum from option
+    ///
+    /// Services could use this to request checksums, like sending
+    /// `x-amz-checksum-mode: ENABLED` for s3.
+    pub fn checksum(&self) -> bool {
+        self.checksum
+    }
 }
 
 impl From<options::StatOptions> for OpStat {
@@ -663,6 +902,7 @@ impl From<options::StatOptions> for OpStat {
             override_cache_control: value.override_cache_control,
             override_content_disposition: value.override_content_disposition,
             version: value.version,
+            checksum: value.checksum,
         }
     }
 }
@@ -671,7 +911,9 @@ impl From<options::StatOptions> for OpStat {
 #[derive(Debug, Clone, Default)]
 pub struct OpWrite {
     append: bool,
+    offset: Option<u64>,
     concurrent: usize,
+    adaptive_concurrency: Option<AdaptiveConcurrency>,
     content_type: Option<String>,
     content_disposition: Option<String>,
     content_encoding: Option<String>,
@@ -679,7 +921,14 @@ pub struct OpWrite {
     if_match: Option<String>,
     if_none_match: Option<String>,
     if_not_exists: bool,
+    immutability_until: Option<Timestamp>,
+    legal_hold: bool,
+    lock: bool,
+    ttl: Option<Duration>,
     user_metadata: Option<HashMap<String, String>>,
+    checkpoint: Option<WriteCheckpoint>,
+    chunk_timeout: Option<Duration>,
+    deadline: Option<Duration>,
 }
 
 impl OpWrite {
@@ -709,6 +958,27 @@ impl OpWrite {
         self
     }
 
+    /// Get the offset from op.
+    ///
+    /// The offset is the position in the existing content to start writing at.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will overwrite the existing content
+    /// starting at offset instead of replacing the whole file.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not
+    /// support writing at an offset.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Get the content type from option
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
@@ -764,6 +1034,18 @@ impl OpWrite {
         self
     }
 
+    /// Get the adaptive concurrency.
+    pub fn adaptive_concurrency(&self) -> Option<&AdaptiveConcurrency> {
+        self.adaptive_concurrency.as_ref()
+    }
+
+    /// Set the adaptive concurrency to tune concurrent write tasks.
+    pub fn with_adaptive_concurrency(mut self, adaptive: AdaptiveConcurrency) -> Self {
+        self.concurrent = adaptive.max();
+        self.adaptive_concurrency = Some(adaptive);
+        self
+    }
+
     /// Set the If-Match of the option
     pub fn with_if_match(mut self, s: &str) -> Self {
         self.if_match = Some(s.to_string());
@@ -797,6 +1079,50 @@ impl OpWrite {
         self.if_not_exists
     }
 
+    /// Set the time until which the object is immutable.
+    pub fn with_immutability_until(mut self, v: Timestamp) -> Self {
+        self.immutability_until = Some(v);
+        self
+    }
+
+    /// Get the time until which the object is immutable.
+    pub fn immutability_until(&self) -> Option<Timestamp> {
+        self.immutability_until
+    }
+
+    /// Set the legal hold of the op.
+    pub fn with_legal_hold(mut self, v: bool) -> Self {
+        self.legal_hold = v;
+        self
+    }
+
+    /// Get the legal hold from the op.
+    pub fn legal_hold(&self) -> bool {
+        self.legal_hold
+    }
+
+    /// Set the lock of the op.
+    pub fn with_lock(mut self, v: bool) -> Self {
+        self.lock = v;
+        self
+    }
+
+    /// Get the lock from the op.
+    pub fn lock(&self) -> bool {
+        self.lock
+    }
+
+    /// Set the ttl of the op.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Get the ttl from the op.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
     /// Set the user defined metadata of the op
     pub fn with_user_metadata(mut self, metadata: HashMap<String, String>) -> Self {
         self.user_metadata = Some(metadata);
@@ -807,12 +1133,46 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the checkpoint to resume a suspended write.
+    pub fn with_checkpoint(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Get the checkpoint to resume from.
+    pub fn checkpoint(&self) -> Option<&WriteCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Set the timeout for each chunk written to the service.
+    pub fn with_chunk_timeout(mut self, timeout: Duration) -> Self {
+        self.chunk_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout for each chunk written to the service.
+    pub fn chunk_timeout(&self) -> Option<Duration> {
+        self.chunk_timeout
+    }
+
+    /// Set the total time allowed for this write.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Get the total time allowed for this write.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
 }
 
 /// Args for `writer` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpWriter {
     chunk: Option<usize>,
+    compression: Option<WriteCompression>,
 }
 
 impl OpWriter {
@@ -841,15 +1201,39 @@ impl OpWriter {
         self.chunk = Some(chunk);
         self
     }
+
+    /// Get the compression from op.
+    ///
+    /// The compression is applied by the writer before data is chunked.
+    pub fn compression(&self) -> Option<WriteCompression> {
+        self.compression
+    }
+
+    /// Set the compression of op.
+    pub fn with_compression(mut self, compression: WriteCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
 }
 
 impl From<options::WriteOptions> for (OpWrite, OpWriter) {
-    fn from(value: options::WriteOptions) -> Self {
+    fn from(mut value: options::WriteOptions) -> Self {
+        if let Some(compression) = value.compression {
+            value.content_encoding = Some(compression.content_encoding().to_string());
+        }
+
         (
             OpWrite {
                 append: value.append,
-                // Ensure concurrent is at least 1
-                concurrent: value.concurrent.max(1),
+                offset: value.offset,
+                // Ensure concurrent is at least 1, and large enough to let
+                // services pick their concurrent writers in adaptive mode.
+                concurrent: value
+                    .adaptive_concurrency
+                    .as_ref()
+                    .map_or(value.concurrent, |v| v.max())
+                    .max(1),
+                adaptive_concurrency: value.adaptive_concurrency,
                 content_type: value.content_type,
                 content_disposition: value.content_disposition,
                 content_encoding: value.content_encoding,
@@ -857,9 +1241,19 @@ impl From<options::WriteOptions> for (OpWrite, OpWriter) {
                 if_match: value.if_match,
                 if_none_match: value.if_none_match,
                 if_not_exists: value.if_not_exists,
+                immutability_until: value.immutability_until,
+                legal_hold: value.legal_hold,
+                lock: value.lock,
+                ttl: value.ttl,
                 user_metadata: value.user_metadata,
+                checkpoint: value.checkpoint,
+                chunk_timeout: value.chunk_timeout,
+                deadline: value.deadline,
+            },
+            OpWriter {
+                chunk: value.chunk,
+                compression: value.compression,
             },
-            OpWriter { chunk: value.chunk },
         )
     }
 }
@@ -987,11 +1381,35 @@ impl From<options::CopyOptions> for (OpCopy, OpCopier) {
 
 /// Args for `rename` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpRename {}
+pub struct OpRename {
+    atomic: bool,
+}
 
 impl OpRename {
     /// Create a new `OpMove`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Require the rename to be atomic.
+    ///
+    /// Services must either rename in one step or return an error, instead of
+    /// deleting `to` first or copying the data.
+    pub fn with_atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    /// Get if the rename is required to be atomic.
+    pub fn atomic(&self) -> bool {
+        self.atomic
+    }
+}
+
+impl From<options::RenameOptions> for OpRename {
+    fn from(value: options::RenameOptions) -> Self {
+        Self {
+            atomic: value.atomic,
+        }
+    }
 }
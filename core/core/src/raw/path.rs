@@ -262,6 +262,7 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     match mode {
         EntryMode::FILE => !path.ends_with('/'),
         EntryMode::DIR => path.ends_with('/'),
+        EntryMode::LINK => !path.ends_with('/'),
         EntryMode::Unknown => false,
     }
 }
@@ -83,6 +83,35 @@ impl PresignedRequest {
     }
 }
 
+/// PresignedMultipart is the set of presigned requests for a multipart upload.
+///
+/// Send every request in [`PresignedMultipart::parts`] with the data of that
+/// part, then send [`PresignedMultipart::complete`] with the list of uploaded
+/// parts to finish the upload.
+#[derive(Debug, Clone)]
+pub struct PresignedMultipart {
+    parts: Vec<PresignedRequest>,
+    complete: PresignedRequest,
+}
+
+impl PresignedMultipart {
+    /// Create a new PresignedMultipart
+    pub fn new(parts: Vec<PresignedRequest>, complete: PresignedRequest) -> Self {
+        Self { parts, complete }
+    }
+
+    /// Return the presigned requests for uploading parts, the request at
+    /// index `i` uploads part number `i + 1`.
+    pub fn parts(&self) -> &[PresignedRequest] {
+        &self.parts
+    }
+
+    /// Return the presigned request for completing the multipart upload.
+    pub fn complete(&self) -> &PresignedRequest {
+        &self.complete
+    }
+}
+
 impl<T: Default> From<PresignedRequest> for Request<T> {
     fn from(v: PresignedRequest) -> Self {
         let mut builder = Request::builder().method(v.method).uri(v.uri);
@@ -183,6 +212,46 @@ impl RpRename {
     }
 }
 
+/// Reply for `get_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetAcl {
+    acl: Acl,
+}
+
+impl RpGetAcl {
+    /// Create a new reply for `get_acl`.
+    pub fn new(acl: Acl) -> Self {
+        RpGetAcl { acl }
+    }
+
+    /// Consume RpGetAcl to get the inner acl.
+    pub fn into_acl(self) -> Acl {
+        self.acl
+    }
+}
+
+/// Reply for `set_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetAcl {}
+
+impl RpSetAcl {
+    /// Create a new reply for `set_acl`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpRestore {}
+
+impl RpRestore {
+    /// Create a new reply for `restore`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
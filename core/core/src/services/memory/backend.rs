@@ -75,6 +75,7 @@ impl MemoryBackend {
             write_with_content_disposition: true,
             write_with_content_encoding: true,
             write_with_if_not_exists: true,
+            write_with_user_metadata: true,
             delete: true,
             stat: true,
             list: true,
@@ -240,7 +241,8 @@ impl oio::StreamRead for MemoryReader {
         let content = value
             .content
             .slice(range.to_content_range(value.content.len())?);
-        let metadata = Metadata::new(EntryMode::FILE).with_content_length(total_size);
+        let mut metadata = value.metadata;
+        metadata.set_content_length(total_size);
         Ok((
             RpRead::new(metadata),
             Box::new(content) as Box<dyn oio::ReadStreamDyn>,
@@ -67,6 +67,9 @@ impl oio::Write for MemoryWriter {
         if let Some(v) = self.op.content_encoding() {
             metadata.set_content_encoding(v);
         }
+        if let Some(v) = self.op.user_metadata() {
+            metadata = metadata.with_user_metadata(v.clone());
+        }
 
         let value = MemoryValue {
             metadata: metadata.clone(),
@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::*;
+
+/// Acl describes the POSIX access control list of a file or directory.
+///
+/// Returned by [`Operator::get_acl`] and applied by [`Operator::set_acl`].
+/// Fields that are `None` are unknown when returned, and left unchanged when
+/// applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Acl {
+    owner: Option<String>,
+    group: Option<String>,
+    permissions: Option<String>,
+    entries: Vec<AclEntry>,
+}
+
+impl Acl {
+    /// Create a new empty acl.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the owner of the path.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Set the owner of the path.
+    pub fn with_owner(mut self, owner: &str) -> Self {
+        self.owner = Some(owner.to_string());
+        self
+    }
+
+    /// Get the owning group of the path.
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Set the owning group of the path.
+    pub fn with_group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Get the permissions of the path in symbolic notation like `rwxr-x---`.
+    pub fn permissions(&self) -> Option<&str> {
+        self.permissions.as_deref()
+    }
+
+    /// Set the permissions of the path in symbolic notation like `rwxr-x---`.
+    pub fn with_permissions(mut self, permissions: &str) -> Self {
+        self.permissions = Some(permissions.to_string());
+        self
+    }
+
+    /// Get the acl entries of the path.
+    pub fn entries(&self) -> &[AclEntry] {
+        &self.entries
+    }
+
+    /// Set the acl entries of the path.
+    pub fn with_entries(mut self, entries: impl IntoIterator<Item = AclEntry>) -> Self {
+        self.entries = entries.into_iter().collect();
+        self
+    }
+
+    /// Parse acl entries in the short text form like `user::rwx,group::r-x,other::---`.
+    pub fn parse_entries(s: &str) -> Result<Vec<AclEntry>> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(AclEntry::from_str)
+            .collect()
+    }
+
+    /// Format acl entries into the short text form like `user::rwx,group::r-x,other::---`.
+    pub fn format_entries(entries: &[AclEntry]) -> String {
+        entries
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// The kind of an [`AclEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclEntryKind {
+    /// The entry applies to the owner or the given user.
+    User,
+    /// The entry applies to the owning group or the given group.
+    Group,
+    /// The entry limits the permissions of named users and groups.
+    Mask,
+    /// The entry applies to everyone else.
+    Other,
+}
+
+impl AclEntryKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AclEntryKind::User => "user",
+            AclEntryKind::Group => "group",
+            AclEntryKind::Mask => "mask",
+            AclEntryKind::Other => "other",
+        }
+    }
+}
+
+/// AclEntry is one entry of the POSIX access control list.
+///
+/// The text form is `[default:]kind:[qualifier]:permissions`, for example
+/// `user::rwx` or `default:group:data-team:r-x`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AclEntry {
+    default: bool,
+    kind: AclEntryKind,
+    qualifier: Option<String>,
+    permissions: String,
+}
+
+impl AclEntry {
+    /// Create a new acl entry with given kind and permissions like `r-x`.
+    pub fn new(kind: AclEntryKind, permissions: &str) -> Self {
+        Self {
+            default: false,
+            kind,
+            qualifier: None,
+            permissions: permissions.to_string(),
+        }
+    }
+
+    /// Check if this entry is a default entry.
+    ///
+    /// Default entries are inherited by children created under a directory.
+    pub fn is_default(&self) -> bool {
+        self.default
+    }
+
+    /// Mark this entry as a default entry.
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// Get the kind of this entry.
+    pub fn kind(&self) -> AclEntryKind {
+        self.kind
+    }
+
+    /// Get the user or group this entry applies to.
+    ///
+    /// Returns `None` for the owner, the owning group, mask and other.
+    pub fn qualifier(&self) -> Option<&str> {
+        self.qualifier.as_deref()
+    }
+
+    /// Set the user or group this entry applies to.
+    pub fn with_qualifier(mut self, qualifier: &str) -> Self {
+        self.qualifier = Some(qualifier.to_string());
+        self
+    }
+
+    /// Get the permissions of this entry like `r-x`.
+    pub fn permissions(&self) -> &str {
+        &self.permissions
+    }
+}
+
+impl Display for AclEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.default {
+            f.write_str("default:")?;
+        }
+        write!(
+            f,
+            "{}:{}:{}",
+            self.kind.as_str(),
+            self.qualifier.as_deref().unwrap_or_default(),
+            self.permissions
+        )
+    }
+}
+
+impl FromStr for AclEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let new_error =
+            || Error::new(ErrorKind::Unexpected, "acl entry is invalid").with_context("input", s);
+
+        let (default, rest) = match s.strip_prefix("default:") {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let mut parts = rest.splitn(3, ':');
+        let (Some(kind), Some(qualifier), Some(permissions)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(new_error());
+        };
+
+        let kind = match kind {
+            "user" => AclEntryKind::User,
+            "group" => AclEntryKind::Group,
+            "mask" => AclEntryKind::Mask,
+            "other" => AclEntryKind::Other,
+            _ => return Err(new_error()),
+        };
+        let valid = permissions.len() == 3
+            && permissions
+                .chars()
+                .zip(['r', 'w', 'x'])
+                .all(|(c, expect)| c == expect || c == '-');
+        if !valid {
+            return Err(new_error());
+        }
+
+        Ok(Self {
+            default,
+            kind,
+            qualifier: (!qualifier.is_empty()).then(|| qualifier.to_string()),
+            permissions: permissions.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entries() {
+        let entries = Acl::parse_entries(
+            "user::rwx,user:alice:r-x,default:group:data:r--,mask::r-x,other::---",
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                AclEntry::new(AclEntryKind::User, "rwx"),
+                AclEntry::new(AclEntryKind::User, "r-x").with_qualifier("alice"),
+                AclEntry::new(AclEntryKind::Group, "r--")
+                    .with_qualifier("data")
+                    .with_default(true),
+                AclEntry::new(AclEntryKind::Mask, "r-x"),
+                AclEntry::new(AclEntryKind::Other, "---"),
+            ]
+        );
+        assert_eq!(
+            Acl::format_entries(&entries),
+            "user::rwx,user:alice:r-x,default:group:data:r--,mask::r-x,other::---"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_entry() {
+        for input in ["user::rw", "owner::rwx", "user:rwx", "user::xwr"] {
+            assert!(
+                input.parse::<AclEntry>().is_err(),
+                "{input} should be invalid"
+            );
+        }
+    }
+}
@@ -36,7 +36,7 @@ use crate::*;
 /// Range: bytes=<range-start>-<range-end>
 /// Range: bytes=-<suffix-length>
 /// ```
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum BytesRange {
     /// Read from `offset` with optional `size`.
     Range {
@@ -20,6 +20,8 @@ use std::fmt::Debug;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::raw::Operation;
+
 /// Capability defines the supported operations and their constraints for a storage Operator.
 ///
 /// # Overview
@@ -58,6 +60,12 @@ use serde::Serialize;
 /// - Metadata Results: Returning metadata capabilities (e.g., `stat_has_content_length`)
 ///
 /// All capability fields are public and can be accessed directly.
+///
+/// # Argument Matrix
+///
+/// [`Capability::operations`] and [`Capability::operation`] summarize these
+/// fields per operation, reporting which arguments (named after the fields of
+/// the matching `*Options` struct) the operator accepts.
 #[derive(Copy, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Capability {
@@ -100,6 +108,8 @@ pub struct Capability {
     pub read_with_version: bool,
     /// Indicates if suffix read operations are supported.
     pub read_with_suffix: bool,
+    /// Indicates if reading multiple ranges within one request is supported.
+    pub read_with_multi_range: bool,
 
     /// Indicates if the operator supports write operations.
     pub write: bool,
@@ -109,6 +119,8 @@ pub struct Capability {
     pub write_can_empty: bool,
     /// Indicates if append operations are supported.
     pub write_can_append: bool,
+    /// Indicates if write operations can start at an offset of the existing content.
+    pub write_can_seek: bool,
     /// Indicates if Content-Type can be specified during write operations.
     pub write_with_content_type: bool,
     /// Indicates if Content-Disposition can be specified during write operations.
@@ -123,8 +135,21 @@ pub struct Capability {
     pub write_with_if_none_match: bool,
     /// Indicates if write operations can be conditional on object non-existence.
     pub write_with_if_not_exists: bool,
+    /// Indicates if write operations can set an immutability policy on the object.
+    pub write_with_immutability_until: bool,
+    /// Indicates if write operations can set a legal hold on the object.
+    pub write_with_legal_hold: bool,
+    /// Indicates if write operations can hold an exclusive lock on the object while writing.
+    pub write_with_lock: bool,
+    /// Indicates if write operations can set the time to live of the object.
+    ///
+    /// Only key-value services with native per key expiration support it. Object
+    /// stores like s3, gcs and azblob expire objects via bucket lifecycle rules instead.
+    pub write_with_ttl: bool,
     /// Indicates if custom user metadata can be attached during write operations.
     pub write_with_user_metadata: bool,
+    /// Indicates if write operations can be suspended and resumed from a checkpoint.
+    pub write_with_checkpoint: bool,
     /// Maximum size supported for multipart uploads.
     /// For example, AWS S3 supports up to 5GiB per part in multipart uploads.
     pub write_multi_max_size: Option<usize>,
@@ -137,6 +162,11 @@ pub struct Capability {
 
     /// Indicates if directory creation is supported.
     pub create_dir: bool,
+    /// Indicates if directories created by `create_dir` persist on their own.
+    ///
+    /// If true, an empty directory stays visible to `stat` and `list` until
+    /// it is deleted. Otherwise, a directory only exists while it has children.
+    pub create_dir_persistent: bool,
 
     /// Indicates if delete operations are supported.
     pub delete: bool,
@@ -164,6 +194,8 @@ pub struct Capability {
 
     /// Indicates if rename operations are supported.
     pub rename: bool,
+    /// Indicates if rename operations replace `to` in a single atomic step.
+    pub rename_is_atomic: bool,
 
     /// Indicates if list operations are supported.
     pub list: bool,
@@ -177,6 +209,14 @@ pub struct Capability {
     pub list_with_versions: bool,
     /// Indicates if listing with deleted files included is supported.
     pub list_with_deleted: bool,
+    /// Indicates if listing entries of a specific namespace version is supported.
+    pub list_with_version: bool,
+    /// Indicates if listing entries filtered by path suffix is supported on the server side.
+    pub list_with_suffix: bool,
+    /// Indicates if listing entries filtered by glob pattern is supported on the server side.
+    pub list_with_glob: bool,
+    /// Indicates if list operations can keep streaming changes after listing existing entries.
+    pub list_with_watch: bool,
 
     /// Indicates if presigned URL generation is supported.
     pub presign: bool,
@@ -188,11 +228,216 @@ pub struct Capability {
     pub presign_write: bool,
     /// Indicates if presigned URLs for delete operations are supported.
     pub presign_delete: bool,
+    /// Indicates if presigned URLs for multipart upload operations are supported.
+    pub presign_multipart: bool,
+
+    /// Indicates if getting the access control list is supported.
+    pub get_acl: bool,
+    /// Indicates if setting the access control list is supported.
+    pub set_acl: bool,
+
+    /// Indicates if restoring archived files is supported.
+    pub restore: bool,
 
     /// Indicate if the operator supports shared access.
     pub shared: bool,
 }
 
+impl Capability {
+    /// Get the arguments supported by `op`.
+    ///
+    /// Returns `None` if the operation itself is not supported.
+    pub fn operation(&self, op: Operation) -> Option<OperationCapability> {
+        let (supported, args) = match op {
+            Operation::Info => (true, vec![]),
+            Operation::Stat => (
+                self.stat,
+                filter_args(&[
+                    ("version", self.stat_with_version),
+                    ("if_match", self.stat_with_if_match),
+                    ("if_none_match", self.stat_with_if_none_match),
+                    ("if_modified_since", self.stat_with_if_modified_since),
+                    ("if_unmodified_since", self.stat_with_if_unmodified_since),
+                    (
+                        "override_cache_control",
+                        self.stat_with_override_cache_control,
+                    ),
+                    (
+                        "override_content_disposition",
+                        self.stat_with_override_content_disposition,
+                    ),
+                    (
+                        "override_content_type",
+                        self.stat_with_override_content_type,
+                    ),
+                ]),
+            ),
+            Operation::Read => (
+                self.read,
+                filter_args(&[
+                    // Ranged and chunked reads are always handled by opendal.
+                    ("range", true),
+                    ("concurrent", true),
+                    ("chunk", true),
+                    ("suffix", self.read_with_suffix),
+                    ("multi_range", self.read_with_multi_range),
+                    ("version", self.read_with_version),
+                    ("if_match", self.read_with_if_match),
+                    ("if_none_match", self.read_with_if_none_match),
+                    ("if_modified_since", self.read_with_if_modified_since),
+                    ("if_unmodified_since", self.read_with_if_unmodified_since),
+                    (
+                        "override_cache_control",
+                        self.read_with_override_cache_control,
+                    ),
+                    (
+                        "override_content_disposition",
+                        self.read_with_override_content_disposition,
+                    ),
+                    (
+                        "override_content_type",
+                        self.read_with_override_content_type,
+                    ),
+                ]),
+            ),
+            Operation::Write => (
+                self.write,
+                filter_args(&[
+                    ("append", self.write_can_append),
+                    ("offset", self.write_can_seek),
+                    ("concurrent", self.write_can_multi),
+                    ("chunk", self.write_can_multi),
+                    ("content_type", self.write_with_content_type),
+                    ("content_disposition", self.write_with_content_disposition),
+                    ("content_encoding", self.write_with_content_encoding),
+                    ("cache_control", self.write_with_cache_control),
+                    ("if_match", self.write_with_if_match),
+                    ("if_none_match", self.write_with_if_none_match),
+                    ("if_not_exists", self.write_with_if_not_exists),
+                    ("immutability_until", self.write_with_immutability_until),
+                    ("legal_hold", self.write_with_legal_hold),
+                    ("lock", self.write_with_lock),
+                    ("ttl", self.write_with_ttl),
+                    ("user_metadata", self.write_with_user_metadata),
+                    ("checkpoint", self.write_with_checkpoint),
+                ]),
+            ),
+            Operation::CreateDir => (self.create_dir, vec![]),
+            Operation::Delete => (
+                self.delete,
+                filter_args(&[
+                    ("version", self.delete_with_version),
+                    ("recursive", self.delete_with_recursive),
+                ]),
+            ),
+            Operation::Copy => (
+                self.copy,
+                filter_args(&[
+                    ("if_not_exists", self.copy_with_if_not_exists),
+                    ("if_match", self.copy_with_if_match),
+                    ("source_version", self.copy_with_source_version),
+                    ("concurrent", self.copy_can_multi),
+                    ("chunk", self.copy_can_multi),
+                ]),
+            ),
+            Operation::Rename => (
+                self.rename,
+                filter_args(&[("atomic", self.rename_is_atomic)]),
+            ),
+            Operation::List => (
+                self.list,
+                filter_args(&[
+                    ("limit", self.list_with_limit),
+                    ("start_after", self.list_with_start_after),
+                    ("recursive", self.list_with_recursive),
+                    ("versions", self.list_with_versions),
+                    ("deleted", self.list_with_deleted),
+                    ("version", self.list_with_version),
+                    // Suffix, glob and regex filters fall back to client side filtering.
+                    ("suffix", true),
+                    ("glob", true),
+                    ("regex", cfg!(feature = "list-regex")),
+                    ("watch", self.list_with_watch),
+                ]),
+            ),
+            Operation::Presign => (
+                self.presign,
+                filter_args(&[
+                    ("read", self.presign_read),
+                    ("stat", self.presign_stat),
+                    ("write", self.presign_write),
+                    ("delete", self.presign_delete),
+                    ("multipart", self.presign_multipart),
+                ]),
+            ),
+            Operation::GetAcl => (self.get_acl, vec![]),
+            Operation::SetAcl => (self.set_acl, vec![]),
+            Operation::Restore => (self.restore, vec!["days", "tier"]),
+        };
+
+        supported.then_some(OperationCapability {
+            operation: op,
+            args,
+        })
+    }
+
+    /// Get the arguments supported by every operation of this capability.
+    ///
+    /// Unsupported operations are omitted.
+    pub fn operations(&self) -> Vec<OperationCapability> {
+        [
+            Operation::Stat,
+            Operation::Read,
+            Operation::Write,
+            Operation::CreateDir,
+            Operation::Delete,
+            Operation::Copy,
+            Operation::Rename,
+            Operation::List,
+            Operation::Presign,
+            Operation::GetAcl,
+            Operation::SetAcl,
+            Operation::Restore,
+        ]
+        .into_iter()
+        .filter_map(|op| self.operation(op))
+        .collect()
+    }
+}
+
+fn filter_args(args: &[(&'static str, bool)]) -> Vec<&'static str> {
+    args.iter()
+        .filter(|(_, supported)| *supported)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// The arguments an operation supports on an operator.
+///
+/// Returned by [`Capability::operation`] and [`Capability::operations`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperationCapability {
+    operation: Operation,
+    args: Vec<&'static str>,
+}
+
+impl OperationCapability {
+    /// The operation described.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Names of the supported arguments.
+    pub fn args(&self) -> &[&'static str] {
+        &self.args
+    }
+
+    /// Check if the argument `name` is supported.
+    pub fn supports(&self, name: &str) -> bool {
+        self.args.contains(&name)
+    }
+}
+
 impl Debug for Capability {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // NOTE: All services in opendal are readable.
@@ -215,3 +460,37 @@ impl Debug for Capability {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_capability() {
+        let cap = Capability {
+            read: true,
+            read_with_version: true,
+            write: true,
+            write_can_append: true,
+            write_with_if_not_exists: true,
+            ..Default::default()
+        };
+
+        let read = cap.operation(Operation::Read).unwrap();
+        assert!(read.supports("version"));
+        assert!(read.supports("range"));
+        assert!(!read.supports("if_match"));
+
+        let write = cap.operation(Operation::Write).unwrap();
+        assert_eq!(write.args(), &["append", "if_not_exists"]);
+
+        assert!(cap.operation(Operation::Delete).is_none());
+        assert_eq!(
+            cap.operations()
+                .iter()
+                .map(|v| v.operation())
+                .collect::<Vec<_>>(),
+            vec![Operation::Read, Operation::Write]
+        );
+    }
+}
@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt;
+
+#[cfg(feature = "checksum")]
+use base64::Engine;
+#[cfg(feature = "checksum")]
+use base64::engine::general_purpose::STANDARD;
+#[cfg(feature = "checksum")]
+use crc_fast::CrcAlgorithm;
+#[cfg(feature = "checksum")]
+use md5::Digest;
+
+#[cfg(feature = "checksum")]
+use crate::*;
+
+/// The algorithm of a [`Checksum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChecksumAlgorithm {
+    /// CRC32C (Castagnoli), the value is the base64 of the big-endian u32.
+    Crc32c,
+    /// MD5, the value is the base64 of the digest.
+    Md5,
+    /// SHA-256, the value is the base64 of the digest.
+    Sha256,
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Crc32c => write!(f, "crc32c"),
+            Self::Md5 => write!(f, "md5"),
+            Self::Sha256 => write!(f, "sha256"),
+        }
+    }
+}
+
+/// Checksum of the whole content advertised by services.
+///
+/// The value is kept as the base64 encoded form that services return, for
+/// example `x-amz-checksum-crc32c` of s3, `crc32c` of gcs and `Content-MD5`
+/// of azblob.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    value: String,
+}
+
+impl Checksum {
+    /// Create a new checksum with base64 encoded value.
+    pub fn new(algorithm: ChecksumAlgorithm, value: impl Into<String>) -> Self {
+        Self {
+            algorithm,
+            value: value.into(),
+        }
+    }
+
+    /// Calculate the checksum of given content.
+    ///
+    /// Requires the `checksum` feature.
+    #[cfg(feature = "checksum")]
+    pub fn calculate(algorithm: ChecksumAlgorithm, content: &Buffer) -> Self {
+        let digest = match algorithm {
+            ChecksumAlgorithm::Crc32c => {
+                let mut digest = crc_fast::Digest::new(CrcAlgorithm::Crc32Iscsi);
+                content.clone().for_each(|b| digest.update(&b));
+                (digest.finalize() as u32).to_be_bytes().to_vec()
+            }
+            ChecksumAlgorithm::Md5 => {
+                let mut hasher = md5::Md5::new();
+                content.clone().for_each(|b| hasher.update(&b));
+                hasher.finalize().to_vec()
+            }
+            ChecksumAlgorithm::Sha256 => {
+                let mut hasher = sha2::Sha256::new();
+                content.clone().for_each(|b| hasher.update(&b));
+                hasher.finalize().to_vec()
+            }
+        };
+
+        Self::new(algorithm, STANDARD.encode(digest))
+    }
+
+    /// Get the algorithm of this checksum.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Get the base64 encoded value of this checksum.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Verify the given content against this checksum.
+    ///
+    /// Returns [`ErrorKind::Unexpected`] if the content doesn't match.
+    ///
+    /// Requires the `checksum` feature.
+    #[cfg(feature = "checksum")]
+    pub fn verify(&self, content: &Buffer) -> Result<()> {
+        let actual = Self::calculate(self.algorithm, content);
+        if actual.value == self.value {
+            return Ok(());
+        }
+
+        Err(Error::new(ErrorKind::Unexpected, "checksum mismatch")
+            .with_context("algorithm", self.algorithm)
+            .with_context("expect", &self.value)
+            .with_context("actual", actual.value))
+    }
+}
+
+#[cfg(all(test, feature = "checksum"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_checksum() {
+        let content = Buffer::from(vec![
+            bytes::Bytes::from("Hello, "),
+            bytes::Bytes::from("World!"),
+        ]);
+
+        let cases = [
+            (ChecksumAlgorithm::Crc32c, "TVUQaA=="),
+            (ChecksumAlgorithm::Md5, "ZajifYh5KDgxtmS9i38K1A=="),
+            (
+                ChecksumAlgorithm::Sha256,
+                "3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8=",
+            ),
+        ];
+        for (algorithm, expected) in cases {
+            assert_eq!(
+                Checksum::calculate(algorithm, &content).value(),
+                expected,
+                "{algorithm}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_checksum_mismatch() {
+        let checksum = Checksum::new(ChecksumAlgorithm::Md5, "ZajifYh5KDgxtmS9i38K1A==");
+        assert!(checksum.verify(&Buffer::from("Hello, World!")).is_ok());
+
+        let err = checksum
+            .verify(&Buffer::from("Hello, OpenDAL!"))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
+}
@@ -69,6 +69,12 @@ impl ReadContext {
         &self.ctx
     }
 
+    /// Get the capability of the underlying service.
+    #[inline]
+    pub(crate) fn capability(&self) -> Capability {
+        self.srv.capability()
+    }
+
     /// Get the path.
     #[inline]
     pub fn path(&self) -> &str {
@@ -17,6 +17,8 @@
 
 use std::sync::Arc;
 
+use bytes::Buf;
+
 use crate::raw::oio::Write;
 use crate::raw::*;
 use crate::*;
@@ -107,12 +109,26 @@ pub struct WriteGenerator<W> {
     /// exactly `chunk_size` bytes.
     exact: bool,
     buffer: oio::QueueBuf,
+    /// Compress data before it's buffered, so chunks carry one continuous stream.
+    compressor: Option<Compressor>,
 }
 
 impl WriteGenerator<oio::Writer> {
     /// Create a new exact buf writer.
     pub fn create(ctx: Arc<WriteContext>) -> Result<Self> {
         let (chunk_size, exact) = ctx.calculate_chunk_size();
+
+        let compressor = match ctx.options().compression() {
+            Some(_) if ctx.args().checkpoint().is_some() => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "resuming from checkpoint is not supported with compression",
+                ));
+            }
+            Some(compression) => Some(Compressor::new(compression)?),
+            None => None,
+        };
+
         let w = ctx.srv.write(&ctx.ctx, ctx.path(), ctx.args().clone())?;
 
         Ok(Self {
@@ -120,6 +136,7 @@ impl WriteGenerator<oio::Writer> {
             chunk_size,
             exact,
             buffer: oio::QueueBuf::new(),
+            compressor,
         })
     }
 
@@ -131,13 +148,35 @@ impl WriteGenerator<oio::Writer> {
             chunk_size,
             exact,
             buffer: oio::QueueBuf::new(),
+            compressor: None,
         }
     }
 }
 
 impl WriteGenerator<oio::Writer> {
-    /// Write the entire buffer into writer.
-    pub async fn write(&mut self, mut bs: Buffer) -> Result<usize> {
+    /// Write the buffer into writer, returns the size that has been consumed.
+    pub async fn write(&mut self, bs: Buffer) -> Result<usize> {
+        let Some(compressor) = self.compressor.as_mut() else {
+            return self.write_chunk(bs).await;
+        };
+
+        // Compressed data doesn't map back to the input, so we must consume
+        // the entire buffer.
+        let size = bs.len();
+        let bs = compressor.compress(bs)?;
+        self.write_all(bs).await?;
+        Ok(size)
+    }
+
+    async fn write_all(&mut self, mut bs: Buffer) -> Result<()> {
+        while !bs.is_empty() {
+            let n = self.write_chunk(bs.clone()).await?;
+            bs.advance(n);
+        }
+        Ok(())
+    }
+
+    async fn write_chunk(&mut self, mut bs: Buffer) -> Result<usize> {
         let Some(chunk_size) = self.chunk_size else {
             let size = bs.len();
             self.w.write(bs).await?;
@@ -188,6 +227,11 @@ impl WriteGenerator<oio::Writer> {
 
     /// Finish the write process.
     pub async fn close(&mut self) -> Result<Metadata> {
+        if let Some(compressor) = self.compressor.as_mut() {
+            let bs = compressor.finish()?;
+            self.write_all(bs).await?;
+        }
+
         loop {
             if self.buffer.is_empty() {
                 break;
@@ -205,6 +249,19 @@ impl WriteGenerator<oio::Writer> {
         self.buffer.clear();
         self.w.abort().await
     }
+
+    /// Suspend the write process, the buffered data will be dropped.
+    pub async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        if self.compressor.is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "suspend is not supported with compression",
+            ));
+        }
+
+        self.buffer.clear();
+        self.w.suspend().await
+    }
 }
 
 #[cfg(test)]
@@ -263,6 +320,26 @@ mod tests {
         }
     }
 
+    /// Records how many [`Bytes`] every written buffer is made of.
+    struct ChunkCountWriter {
+        counts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Write for ChunkCountWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.counts.lock().await.push(bs.count());
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::default())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
     fn setup() {
         let _ = logforth::starter_log::builder()
             .dispatch(|d| {
@@ -423,6 +500,47 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_buf_writer_keeps_chunks() -> Result<()> {
+        setup();
+
+        let chunks = || {
+            Buffer::from(vec![
+                Bytes::from_static(b"header,"),
+                Bytes::from(vec![1; 16]),
+                Bytes::from(vec![2; 16]),
+            ])
+        };
+
+        // Without chunk size, the buffer is written as is.
+        let counts = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(
+            Box::new(ChunkCountWriter {
+                counts: counts.clone(),
+            }),
+            None,
+            false,
+        );
+        assert_eq!(39, w.write(chunks()).await?);
+        w.close().await?;
+        assert_eq!(*counts.lock().await, vec![3]);
+
+        // Queued buffers are flushed together without being copied into one.
+        let counts = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(
+            Box::new(ChunkCountWriter {
+                counts: counts.clone(),
+            }),
+            Some(64),
+            false,
+        );
+        assert_eq!(39, w.write(chunks()).await?);
+        assert_eq!(39, w.write(chunks()).await?);
+        w.close().await?;
+        assert_eq!(*counts.lock().await, vec![6]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_fuzz_exact_buf_writer() -> Result<()> {
         setup();
@@ -542,4 +660,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "compress-zstd")]
+    #[tokio::test]
+    async fn test_exact_buf_writer_with_zstd_compression() -> Result<()> {
+        setup();
+
+        let mut rng = rng();
+        let mut expected = vec![0; 64 * 1024];
+        rng.fill_bytes(&mut expected[..32 * 1024]);
+
+        let buf = Arc::new(Mutex::new(vec![]));
+        let write_sizes = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(
+            Box::new(MockWriter {
+                buf: buf.clone(),
+                write_sizes: write_sizes.clone(),
+            }),
+            Some(1024),
+            true,
+        );
+        w.compressor = Some(Compressor::new(WriteCompression::Zstd { level: 3 })?);
+
+        for chunk in expected.chunks(3000) {
+            let n = w.write(Bytes::copy_from_slice(chunk).into()).await?;
+            assert_eq!(n, chunk.len());
+        }
+        w.close().await?;
+
+        let buf = buf.lock().await;
+        let decoded = zstd::stream::decode_all(buf.as_slice()).expect("decode must succeed");
+        assert_eq!(sha256_digest(&decoded), sha256_digest(&expected));
+
+        let write_sizes = write_sizes.lock().await;
+        let (last, rest) = write_sizes.split_last().expect("must have writes");
+        assert!(rest.iter().all(|&size| size == 1024));
+        assert!(*last <= 1024);
+        Ok(())
+    }
 }
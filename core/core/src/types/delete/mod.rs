@@ -24,3 +24,6 @@ pub use deleter::Deleter;
 
 mod futures_delete_sink;
 pub use futures_delete_sink::FuturesDeleteSink;
+
+mod progress;
+pub use progress::DeleteProgress;
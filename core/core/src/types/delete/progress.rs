@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// DeleteProgress reports the progress of a recursive delete.
+///
+/// A recursive delete with progress lists the prefix and sends the entries to
+/// the service's deleter, which groups them into batch requests when the
+/// service supports batch delete. For large prefixes this can take a while,
+/// and the progress can be observed by:
+///
+/// - Keeping a clone of the handle and reading [`DeleteProgress::deleted`]:
+///   all clones share the same state.
+/// - Registering a callback via [`DeleteProgress::with_callback`], which is
+///   called with the total count every time an entry is handed to the deleter.
+///
+/// Entries are counted once they are handed to the deleter. Services with
+/// batch delete may still buffer the last batch until the delete returns, so
+/// the count is only final after the delete succeeded. Entries are counted
+/// even on services that support recursive delete natively, since the prefix
+/// is always listed once a progress is set.
+///
+/// # Examples
+///
+/// ```
+/// # use opendal_core::Result;
+/// # use opendal_core::Operator;
+/// use opendal_core::DeleteProgress;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let progress = DeleteProgress::with_callback(|deleted| {
+///     if deleted % 10_000 == 0 {
+///         println!("deleted {deleted} entries");
+///     }
+/// });
+/// op.delete_with("path/to/dir/")
+///     .recursive(true)
+///     .progress(progress.clone())
+///     .await?;
+///
+/// println!("deleted {} entries in total", progress.deleted());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct DeleteProgress {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    deleted: AtomicU64,
+    callback: Option<Box<dyn Fn(u64) + Send + Sync>>,
+}
+
+impl DeleteProgress {
+    /// Create a new delete progress without callback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new delete progress that calls `f` with the total number of
+    /// deleted entries every time it advances.
+    pub fn with_callback(f: impl Fn(u64) + Send + Sync + 'static) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                deleted: AtomicU64::new(0),
+                callback: Some(Box::new(f)),
+            }),
+        }
+    }
+
+    /// Get the number of entries deleted so far.
+    pub fn deleted(&self) -> u64 {
+        self.inner.deleted.load(Ordering::Relaxed)
+    }
+
+    /// Record that `n` more entries have been deleted.
+    pub(crate) fn advance(&self, n: u64) {
+        let deleted = self.inner.deleted.fetch_add(n, Ordering::Relaxed) + n;
+        if let Some(f) = &self.inner.callback {
+            f(deleted);
+        }
+    }
+}
+
+impl Debug for DeleteProgress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeleteProgress")
+            .field("deleted", &self.deleted())
+            .field("callback", &self.inner.callback.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for DeleteProgress {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for DeleteProgress {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_advance_calls_callback_with_total() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let progress = {
+            let seen = seen.clone();
+            DeleteProgress::with_callback(move |v| seen.lock().unwrap().push(v))
+        };
+
+        let cloned = progress.clone();
+        cloned.advance(1);
+        cloned.advance(2);
+
+        assert_eq!(progress.deleted(), 3);
+        assert_eq!(*seen.lock().unwrap(), vec![1, 3]);
+        assert_eq!(progress, cloned);
+        assert_ne!(progress, DeleteProgress::new());
+    }
+}
@@ -0,0 +1,444 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use bytes::Buf;
+use futures::AsyncRead;
+use futures::AsyncSeek;
+use futures::AsyncWrite;
+
+use crate::raw::*;
+use crate::*;
+
+type WritebackFuture = BoxedStaticFuture<(BTreeMap<u64, Vec<u8>>, Result<()>)>;
+
+/// File is a handle that supports reading, writing and seeking within one path.
+///
+/// File implements [`AsyncRead`], [`AsyncWrite`] and [`AsyncSeek`] on top
+/// of [`Reader`] and [`Operator::write`], which makes it possible to use
+/// OpenDAL as the storage of engines that expect a random access file.
+///
+/// # Notes
+///
+/// - Writes are buffered in memory as dirty ranges, and reads always observe
+///   them. Dirty ranges are only written back to storage on `flush` or `close`.
+/// - If the service supports [`Capability::write_can_seek`], every dirty range
+///   is written back in place. Otherwise, the whole file is read back and
+///   rewritten during writeback.
+/// - Buffered writes are discarded if File is dropped without `flush`.
+/// - If the file doesn't exist yet, it will be created by the first `flush`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::SeekFrom;
+///
+/// use futures::AsyncReadExt;
+/// use futures::AsyncSeekExt;
+/// use futures::AsyncWriteExt;
+/// use opendal_core::Operator;
+///
+/// async fn test(op: Operator) -> std::io::Result<()> {
+///     let mut f = op.open("path/to/file").await?;
+///     f.seek(SeekFrom::Start(4096)).await?;
+///     f.write_all(&[0; 4096]).await?;
+///     f.flush().await?;
+///
+///     f.seek(SeekFrom::Start(0)).await?;
+///     let mut bs = vec![0; 4096];
+///     f.read_exact(&mut bs).await?;
+///     Ok(())
+/// }
+/// ```
+pub struct File {
+    op: Operator,
+    path: String,
+    reader: Reader,
+    can_seek: bool,
+
+    /// Whether the file exists in storage.
+    exists: bool,
+    /// Size of the content persisted in storage.
+    persisted_size: u64,
+    /// Size of the file including buffered writes.
+    size: u64,
+    pos: u64,
+    /// Non-overlapping dirty ranges keyed by their offset.
+    dirty: BTreeMap<u64, Vec<u8>>,
+
+    state: State,
+}
+
+enum State {
+    Idle,
+    /// Reading `size` bytes of persisted content at `offset`.
+    Reading {
+        offset: u64,
+        size: u64,
+        fut: BoxedStaticFuture<Result<Buffer>>,
+    },
+    Flushing(WritebackFuture),
+}
+
+impl File {
+    /// NOTE: don't allow users to create File directly.
+    pub(crate) fn new(
+        op: Operator,
+        path: String,
+        reader: Reader,
+        can_seek: bool,
+        size: Option<u64>,
+    ) -> Self {
+        File {
+            op,
+            path,
+            reader,
+            can_seek,
+            exists: size.is_some(),
+            persisted_size: size.unwrap_or_default(),
+            size: size.unwrap_or_default(),
+            pos: 0,
+            dirty: BTreeMap::new(),
+            state: State::Idle,
+        }
+    }
+
+    /// Get the length of this file, including buffered writes.
+    pub fn len(&self) -> u64 {
+        self.size
+    }
+
+    /// Check if this file is empty, including buffered writes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Drive the in-flight writeback to finish, if any.
+    fn poll_writeback(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let State::Flushing(fut) = &mut self.state else {
+            return Poll::Ready(Ok(()));
+        };
+
+        let (dirty, res) = ready!(fut.as_mut().poll(cx));
+        self.state = State::Idle;
+        match res {
+            Ok(()) => {
+                self.exists = true;
+                self.persisted_size = self.size;
+                Poll::Ready(Ok(()))
+            }
+            Err(err) => {
+                // Keep dirty ranges so that the next flush can retry.
+                self.dirty = dirty;
+                Poll::Ready(Err(format_std_io_error(err)))
+            }
+        }
+    }
+
+    fn start_writeback(&mut self) -> WritebackFuture {
+        let op = self.op.clone();
+        let path = self.path.clone();
+        let reader = self.reader.clone();
+        let dirty = std::mem::take(&mut self.dirty);
+        let (exists, persisted_size, size) = (self.exists, self.persisted_size, self.size);
+        let can_seek = self.can_seek;
+
+        Box::pin(async move {
+            let res = if can_seek && exists {
+                write_in_place(&op, &path, &dirty).await
+            } else {
+                rewrite(&op, &path, &reader, &dirty, exists, persisted_size, size).await
+            };
+            (dirty, res)
+        })
+    }
+
+    /// Fill `buf` with `persisted` data, zeros for the holes, and dirty ranges on top.
+    fn fill_buf(&self, offset: u64, persisted: Buffer, buf: &mut [u8]) {
+        let n = persisted.len().min(buf.len());
+        persisted.slice(..n).copy_to_slice(&mut buf[..n]);
+        buf[n..].fill(0);
+
+        let end = offset + buf.len() as u64;
+        for (&start, data) in self.dirty.range(..end).rev() {
+            let data_end = start + data.len() as u64;
+            if data_end <= offset {
+                break;
+            }
+
+            let (from, to) = (start.max(offset), data_end.min(end));
+            buf[(from - offset) as usize..(to - offset) as usize]
+                .copy_from_slice(&data[(from - start) as usize..(to - start) as usize]);
+        }
+    }
+}
+
+async fn write_in_place(op: &Operator, path: &str, dirty: &BTreeMap<u64, Vec<u8>>) -> Result<()> {
+    for (&offset, data) in dirty {
+        op.write_with(path, data.clone()).offset(offset).await?;
+    }
+    Ok(())
+}
+
+async fn rewrite(
+    op: &Operator,
+    path: &str,
+    reader: &Reader,
+    dirty: &BTreeMap<u64, Vec<u8>>,
+    exists: bool,
+    persisted_size: u64,
+    size: u64,
+) -> Result<()> {
+    let mut content = if exists && persisted_size > 0 {
+        reader.read(0..persisted_size).await?.to_vec()
+    } else {
+        Vec::new()
+    };
+    content.resize(size as usize, 0);
+
+    for (&offset, data) in dirty {
+        content[offset as usize..offset as usize + data.len()].copy_from_slice(data);
+    }
+    op.write(path, content).await?;
+    Ok(())
+}
+
+/// Insert `data` at `offset` into dirty ranges, merging all ranges it
+/// overlaps or touches.
+fn insert_dirty(dirty: &mut BTreeMap<u64, Vec<u8>>, offset: u64, data: &[u8]) {
+    let end = offset + data.len() as u64;
+    let merged = dirty
+        .range(..=end)
+        .rev()
+        .take_while(|(start, v)| *start + v.len() as u64 >= offset)
+        .map(|(start, _)| *start)
+        .collect::<Vec<_>>();
+
+    let start = merged.last().map_or(offset, |v| offset.min(*v));
+    // Reuse the first range as base so that sequential writes don't copy
+    // the whole range again.
+    let mut base = match merged.last() {
+        Some(v) if *v <= offset => dirty.remove(v).expect("dirty range must exist"),
+        _ => Vec::new(),
+    };
+    for v in merged.iter().rev() {
+        let Some(data) = dirty.remove(v) else {
+            continue;
+        };
+        let (from, to) = ((v - start) as usize, (v - start) as usize + data.len());
+        if base.len() < to {
+            base.resize(to, 0);
+        }
+        base[from..to].copy_from_slice(&data);
+    }
+
+    let (from, to) = ((offset - start) as usize, (end - start) as usize);
+    if base.len() < to {
+        base.resize(to, 0);
+    }
+    base[from..to].copy_from_slice(data);
+    dirty.insert(start, base);
+}
+
+impl AsyncRead for File {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_writeback(cx))?;
+
+        loop {
+            match &mut this.state {
+                State::Idle => {
+                    if this.pos >= this.size || buf.is_empty() {
+                        return Poll::Ready(Ok(0));
+                    }
+
+                    let offset = this.pos;
+                    let size = (buf.len() as u64).min(this.size - offset);
+                    let end = (offset + size).min(this.persisted_size);
+                    let reader = this.reader.clone();
+                    let fut: BoxedStaticFuture<Result<Buffer>> = Box::pin(async move {
+                        if offset >= end {
+                            return Ok(Buffer::new());
+                        }
+                        reader.read(offset..end).await
+                    });
+                    this.state = State::Reading { offset, size, fut };
+                }
+                State::Reading { offset, size, fut } => {
+                    let res = ready!(fut.as_mut().poll(cx));
+                    let (offset, size) = (*offset, *size);
+                    this.state = State::Idle;
+
+                    let persisted = res.map_err(format_std_io_error)?;
+                    let n = (size as usize).min(buf.len());
+                    this.fill_buf(offset, persisted, &mut buf[..n]);
+                    this.pos = offset + n as u64;
+                    return Poll::Ready(Ok(n));
+                }
+                State::Flushing(_) => unreachable!("writeback must be finished before reading"),
+            }
+        }
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_writeback(cx))?;
+        // Drop the in-flight read since its data may be outdated.
+        this.state = State::Idle;
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        insert_dirty(&mut this.dirty, this.pos, buf);
+        this.pos += buf.len() as u64;
+        this.size = this.size.max(this.pos);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !matches!(this.state, State::Flushing(_)) {
+            if this.dirty.is_empty() && this.exists {
+                return Poll::Ready(Ok(()));
+            }
+            this.state = State::Flushing(this.start_writeback());
+        }
+
+        this.poll_writeback(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+impl AsyncSeek for File {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        ready!(this.poll_writeback(cx))?;
+
+        let new_pos = match pos {
+            SeekFrom::Start(pos) => pos as i64,
+            SeekFrom::End(pos) => this.size as i64 + pos,
+            SeekFrom::Current(pos) => this.pos as i64 + pos,
+        };
+
+        // Check if new_pos is negative.
+        if new_pos < 0 {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )));
+        }
+
+        let new_pos = new_pos as u64;
+        if new_pos != this.pos {
+            this.state = State::Idle;
+        }
+        this.pos = new_pos;
+        Poll::Ready(Ok(this.pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::AsyncReadExt;
+    use futures::AsyncSeekExt;
+    use futures::AsyncWriteExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_dirty() {
+        let mut dirty = BTreeMap::new();
+        insert_dirty(&mut dirty, 4, b"ab");
+        insert_dirty(&mut dirty, 10, b"cd");
+        assert_eq!(dirty.len(), 2);
+
+        // Touching ranges are merged.
+        insert_dirty(&mut dirty, 6, b"ef");
+        assert_eq!(dirty.len(), 2);
+        assert_eq!(dirty[&4], b"abef");
+
+        // Overlapping ranges are merged, new data wins.
+        insert_dirty(&mut dirty, 2, b"xxxxxxxxx");
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[&2], b"xxxxxxxxxd");
+    }
+
+    #[tokio::test]
+    async fn test_file_read_write_seek() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?;
+        op.write("test", "Hello, World!").await?;
+
+        let mut f = op.open("test").await?;
+        assert_eq!(f.len(), 13);
+
+        f.seek(SeekFrom::Start(7)).await.unwrap();
+        f.write_all(b"OpenDAL!").await.unwrap();
+        assert_eq!(f.len(), 15);
+
+        // Reads observe buffered writes before flush.
+        f.seek(SeekFrom::Start(0)).await.unwrap();
+        let mut bs = String::new();
+        f.read_to_string(&mut bs).await.unwrap();
+        assert_eq!(bs, "Hello, OpenDAL!");
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello, World!");
+
+        f.flush().await.unwrap();
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello, OpenDAL!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_file_create_with_hole() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?;
+
+        let mut f = op.open("test").await?;
+        assert!(f.is_empty());
+
+        f.seek(SeekFrom::Start(4)).await.unwrap();
+        f.write_all(b"data").await.unwrap();
+        f.close().await.unwrap();
+
+        assert_eq!(op.read("test").await?.to_vec(), b"\0\0\0\0data");
+        Ok(())
+    }
+}
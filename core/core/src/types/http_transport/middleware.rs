@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::Future;
+
+use http::Request;
+use http::Response;
+
+use super::HttpBody;
+use super::HttpTransport;
+use super::HttpTransporter;
+use crate::types::Buffer;
+use crate::types::Result;
+
+/// Middleware applied to every request sent through an [`HttpTransporter`].
+///
+/// A middleware receives the request together with the transport it wraps,
+/// so it can rewrite the request, inspect the response, or skip sending
+/// entirely. Install it with [`HttpTransporter::with_middleware`].
+///
+/// # Examples
+///
+/// ```
+/// use http::HeaderValue;
+/// use http::Request;
+/// use http::Response;
+/// use opendal_core::Buffer;
+/// use opendal_core::HttpBody;
+/// use opendal_core::HttpMiddleware;
+/// use opendal_core::HttpTransporter;
+/// use opendal_core::Result;
+///
+/// struct AuthHeader(HeaderValue);
+///
+/// impl HttpMiddleware for AuthHeader {
+///     async fn handle(
+///         &self,
+///         mut req: Request<Buffer>,
+///         next: &HttpTransporter,
+///     ) -> Result<Response<HttpBody>> {
+///         req.headers_mut().insert("x-corp-auth", self.0.clone());
+///         next.fetch(req).await
+///     }
+/// }
+///
+/// let transport = HttpTransporter::default()
+///     .with_middleware(AuthHeader(HeaderValue::from_static("secret")));
+/// ```
+pub trait HttpMiddleware: Send + Sync + Unpin + 'static {
+    /// Handle a request, calling `next` to send it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn handle(
+        &self,
+        req: Request<Buffer>,
+        next: &HttpTransporter,
+    ) -> impl Future<Output = Result<Response<HttpBody>>> + Send;
+
+    /// Handle a request, calling `next` to send it.
+    #[cfg(target_arch = "wasm32")]
+    fn handle(
+        &self,
+        req: Request<Buffer>,
+        next: &HttpTransporter,
+    ) -> impl Future<Output = Result<Response<HttpBody>>>;
+}
+
+/// Transport that runs a middleware in front of the wrapped transport.
+pub(super) struct MiddlewareTransport<M> {
+    pub(super) middleware: M,
+    pub(super) next: HttpTransporter,
+}
+
+impl<M: HttpMiddleware> HttpTransport for MiddlewareTransport<M> {
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        self.middleware.handle(req, &self.next).await
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        self.middleware.handle(req, &self.next).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use http::HeaderValue;
+
+    use super::*;
+
+    /// Transport that records the headers it receives.
+    #[derive(Default, Clone)]
+    struct Recorder {
+        headers: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpTransport for Recorder {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let mut headers = self.headers.lock().unwrap();
+            for (k, v) in req.headers() {
+                headers.push(format!("{k}={}", v.to_str().unwrap()));
+            }
+            Ok(Response::new(HttpBody::new(
+                futures::stream::empty(),
+                Some(0),
+            )))
+        }
+    }
+
+    struct AppendHeader(&'static str);
+
+    impl HttpMiddleware for AppendHeader {
+        async fn handle(
+            &self,
+            mut req: Request<Buffer>,
+            next: &HttpTransporter,
+        ) -> Result<Response<HttpBody>> {
+            req.headers_mut()
+                .append("x-middleware", HeaderValue::from_static(self.0));
+            let mut resp = next.fetch(req).await?;
+            resp.headers_mut()
+                .append("x-middleware", HeaderValue::from_static(self.0));
+            Ok(resp)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_order() -> Result<()> {
+        let recorder = Recorder::default();
+        let transport = HttpTransporter::new(recorder.clone())
+            .with_middleware(AppendHeader("inner"))
+            .with_middleware(AppendHeader("outer"));
+
+        let resp = transport.fetch(Request::new(Buffer::new())).await?;
+
+        // The last installed middleware sees the request first.
+        assert_eq!(
+            *recorder.headers.lock().unwrap(),
+            vec!["x-middleware=outer", "x-middleware=inner"]
+        );
+        // And the response last.
+        let values: Vec<_> = resp
+            .headers()
+            .get_all("x-middleware")
+            .iter()
+            .map(|v| v.to_str().unwrap())
+            .collect();
+        assert_eq!(values, vec!["inner", "outer"]);
+        Ok(())
+    }
+}
@@ -35,6 +35,9 @@ use crate::types::Result;
 
 mod body;
 pub use body::HttpBody;
+mod middleware;
+pub use middleware::HttpMiddleware;
+use middleware::MiddlewareTransport;
 
 static DEFAULT_HTTP_TRANSPORTER: OnceLock<HttpTransporter> = OnceLock::new();
 
@@ -100,6 +103,18 @@ impl HttpTransporter {
         let _ = DEFAULT_HTTP_TRANSPORTER.set(Self::new(transport));
     }
 
+    /// Wrap this transport with an [`HttpMiddleware`].
+    ///
+    /// Middlewares apply to every request sent through the returned
+    /// transport, including the ones used to load credentials. The middleware
+    /// installed last runs first.
+    pub fn with_middleware(self, middleware: impl HttpMiddleware) -> Self {
+        Self::new(MiddlewareTransport {
+            middleware,
+            next: self,
+        })
+    }
+
     /// Send a request and consume the response body into a [`Buffer`].
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
         let (parts, mut body) = self.fetch(req).await?.into_parts();
@@ -21,6 +21,8 @@ use std::task::Poll;
 use std::task::ready;
 
 use futures::Stream;
+#[cfg(feature = "list-regex")]
+use regex_lite::Regex;
 
 use crate::raw::*;
 use crate::*;
@@ -30,8 +32,16 @@ use crate::*;
 ///
 /// - Lister implements `Stream<Item = Result<Entry>>`.
 /// - Lister will return `None` if there is no more entries or error has been returned.
+///
+/// # Resuming
+///
+/// For services that support `list_with_start_after`, a long listing can be
+/// checkpointed with [`Lister::next_token`] and resumed later, even in another
+/// process, by passing the token to `lister_with(path).start_after(token)`.
 pub struct Lister {
     lister: Option<oio::Lister>,
+    start_after: Option<String>,
+    last_path: Option<String>,
 
     fut: Option<BoxedStaticFuture<(oio::Lister, Result<Option<oio::Entry>>)>>,
     errored: bool,
@@ -50,15 +60,86 @@ impl Lister {
         path: &str,
         args: OpList,
     ) -> Result<Self> {
-        let lister = srv.list(&ctx, path, args)?;
+        let start_after = args.start_after().map(|v| v.to_string());
+
+        // Services with `list_with_suffix` or `list_with_glob` apply the filter
+        // on the server side, the entries are still filtered here so that
+        // results stay consistent across services.
+        let lister = if args.suffix().is_some() || args.glob().is_some() || args.regex().is_some() {
+            let suffix = args.suffix().map(|v| v.to_string());
+            let glob = args.glob().map(Glob::new).transpose()?;
+            #[cfg(feature = "list-regex")]
+            let regex = args
+                .regex()
+                .map(|v| {
+                    Regex::new(v).map_err(|err| {
+                        Error::new(ErrorKind::ConfigInvalid, "regex is invalid")
+                            .with_context("regex", v)
+                            .set_source(err)
+                    })
+                })
+                .transpose()?;
+            #[cfg(not(feature = "list-regex"))]
+            if let Some(v) = args.regex() {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "list with regex requires the list-regex feature",
+                )
+                .with_context("regex", v));
+            }
+
+            // The glob is matched against paths relative to the listed dir, so
+            // a recursive listing only needs to walk its leading directories.
+            // Services filtering by glob on the server side expect the
+            // original path instead.
+            let list_path = match &glob {
+                Some(glob) if args.recursive() && !srv.capability().list_with_glob => {
+                    let base = if path.ends_with('/') {
+                        path
+                    } else {
+                        get_parent(path)
+                    };
+                    let base = if base == "/" { "" } else { base };
+                    let narrowed = format!("{base}{}", glob.dir_prefix());
+                    if narrowed.starts_with(path) {
+                        narrowed
+                    } else {
+                        path.to_string()
+                    }
+                }
+                _ => path.to_string(),
+            };
+
+            let lister = srv.list(&ctx, &list_path, args)?;
+            let lister = oio::FilterLister::new(lister, path, suffix, glob);
+            #[cfg(feature = "list-regex")]
+            let lister = lister.with_regex(regex);
+            Box::new(lister) as oio::Lister
+        } else {
+            srv.list(&ctx, path, args)?
+        };
 
         Ok(Self {
             lister: Some(lister),
+            start_after,
+            last_path: None,
 
             fut: None,
             errored: false,
         })
     }
+
+    /// Get the token to resume this listing after the last returned entry.
+    ///
+    /// Returns `None` if no entry has been returned and the listing didn't
+    /// start from a token either.
+    ///
+    /// The token is the path of the last returned entry, so it stays valid
+    /// across process restarts. It's not meaningful when listing with
+    /// `versions` or `deleted`, since one path can be returned many times.
+    pub fn next_token(&self) -> Option<&str> {
+        self.last_path.as_deref().or(self.start_after.as_deref())
+    }
 }
 
 impl Stream for Lister {
@@ -70,21 +151,34 @@ impl Stream for Lister {
             return Poll::Ready(None);
         }
 
-        if let Some(mut lister) = self.lister.take() {
-            let fut = async move {
-                let res = lister.next_dyn().await;
-                (lister, res)
+        loop {
+            if let Some(mut lister) = self.lister.take() {
+                let fut = async move {
+                    let res = lister.next_dyn().await;
+                    (lister, res)
+                };
+                self.fut = Some(Box::pin(fut));
+            }
+
+            let Some(fut) = self.fut.as_mut() else {
+                return Poll::Ready(None);
             };
-            self.fut = Some(Box::pin(fut));
-        }
 
-        if let Some(fut) = self.fut.as_mut() {
             let (lister, entry) = ready!(fut.as_mut().poll(cx));
             self.lister = Some(lister);
             self.fut = None;
 
             return match entry {
-                Ok(Some(oe)) => Poll::Ready(Some(Ok(oe.into_entry()))),
+                Ok(Some(oe)) => {
+                    // Services may return entries that sort before `start_after`
+                    // again, like the common prefix of a resumed non-recursive
+                    // listing or the listed dir itself.
+                    if self.start_after.as_deref().is_some_and(|v| oe.path() <= v) {
+                        continue;
+                    }
+                    self.last_path = Some(oe.path().to_string());
+                    Poll::Ready(Some(Ok(oe.into_entry())))
+                }
                 Ok(None) => {
                     self.lister = None;
                     Poll::Ready(None)
@@ -95,7 +189,5 @@ impl Stream for Lister {
                 }
             };
         }
-
-        Poll::Ready(None)
     }
 }
@@ -61,6 +61,10 @@ pub struct Metadata {
     etag: Option<String>,
     last_modified: Option<Timestamp>,
     version: Option<String>,
+    checksum: Option<Checksum>,
+    storage_class: Option<String>,
+    restore_status: Option<RestoreStatus>,
+    link_target: Option<String>,
 
     user_metadata: Option<HashMap<String, String>>,
 }
@@ -103,6 +107,18 @@ impl fmt::Debug for Metadata {
         if let Some(version) = &self.version {
             ds.field("version", version);
         }
+        if let Some(checksum) = &self.checksum {
+            ds.field("checksum", checksum);
+        }
+        if let Some(storage_class) = &self.storage_class {
+            ds.field("storage_class", storage_class);
+        }
+        if let Some(restore_status) = &self.restore_status {
+            ds.field("restore_status", restore_status);
+        }
+        if let Some(link_target) = &self.link_target {
+            ds.field("link_target", link_target);
+        }
         if let Some(user_metadata) = &self.user_metadata {
             ds.field("user_metadata", user_metadata);
         }
@@ -128,7 +144,11 @@ impl Metadata {
             last_modified: None,
             etag: None,
             content_disposition: None,
+            checksum: None,
             version: None,
+            storage_class: None,
+            restore_status: None,
+            link_target: None,
             user_metadata: None,
         }
     }
@@ -310,6 +330,28 @@ impl Metadata {
         self
     }
 
+    /// Checksum of the whole content of this entry.
+    ///
+    /// Services like s3, gcs and azblob advertise checksums (CRC32C, MD5 or
+    /// SHA256) of stored objects. OpenDAL will try its best to set this value,
+    /// and it will be used to verify content while reading with
+    /// `verify_checksum` enabled.
+    pub fn checksum(&self) -> Option<&Checksum> {
+        self.checksum.as_ref()
+    }
+
+    /// Set checksum of this entry.
+    pub fn set_checksum(&mut self, v: Checksum) -> &mut Self {
+        self.checksum = Some(v);
+        self
+    }
+
+    /// Set checksum of this entry.
+    pub fn with_checksum(mut self, v: Checksum) -> Self {
+        self.checksum = Some(v);
+        self
+    }
+
     /// Content Type of this entry.
     ///
     /// Content Type is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-type).
@@ -450,6 +492,66 @@ impl Metadata {
         self
     }
 
+    /// Storage class of the file, such as `STANDARD_IA` or `ARCHIVE`.
+    ///
+    /// The value is returned as is from the service, files in archive classes
+    /// usually need to be restored by [`Operator::restore`](crate::Operator::restore)
+    /// before they can be read.
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Set the storage class of the file.
+    pub fn set_storage_class(&mut self, v: &str) -> &mut Self {
+        self.storage_class = Some(v.to_string());
+        self
+    }
+
+    /// With the storage class of the file.
+    pub fn with_storage_class(mut self, v: String) -> Self {
+        self.storage_class = Some(v);
+        self
+    }
+
+    /// Status of restoring the archived file, if a restore has been requested.
+    ///
+    /// Refer to [`RestoreStatus`] for more information.
+    pub fn restore_status(&self) -> Option<RestoreStatus> {
+        self.restore_status
+    }
+
+    /// Set the restore status of the file.
+    pub fn set_restore_status(&mut self, v: RestoreStatus) -> &mut Self {
+        self.restore_status = Some(v);
+        self
+    }
+
+    /// With the restore status of the file.
+    pub fn with_restore_status(mut self, v: RestoreStatus) -> Self {
+        self.restore_status = Some(v);
+        self
+    }
+
+    /// Target of the symbolic link, only available if the mode is [`EntryMode::LINK`].
+    ///
+    /// The value is returned as is from the service, it may be relative to the
+    /// link's parent dir and may point outside of the service's root.
+    pub fn link_target(&self) -> Option<&str> {
+        self.link_target.as_deref()
+    }
+
+    /// Set the target of the symbolic link.
+    pub fn set_link_target(&mut self, v: &str) -> &mut Self {
+        self.link_target = Some(v.to_string());
+        self
+    }
+
+    /// With the target of the symbolic link.
+    pub fn with_link_target(mut self, v: String) -> Self {
+        self.link_target = Some(v);
+        self
+    }
+
     /// User defined metadata of this entry
     ///
     /// The prefix of the user defined metadata key(for example: in oss, it's x-oss-meta-)
@@ -23,6 +23,7 @@ pub use buffer::Buffer;
 
 mod http_transport;
 pub use http_transport::HttpBody;
+pub use http_transport::HttpMiddleware;
 pub use http_transport::HttpTransport;
 pub use http_transport::HttpTransporter;
 
@@ -32,12 +33,22 @@ pub use entry::Entry;
 mod metadata;
 pub use metadata::Metadata;
 
+mod checksum;
+pub use checksum::Checksum;
+pub use checksum::ChecksumAlgorithm;
+
+mod restore;
+pub use restore::RestoreStatus;
+
 mod read;
 pub use read::*;
 
 mod write;
 pub use write::*;
 
+mod file;
+pub use file::File;
+
 mod list;
 pub use list::Lister;
 
@@ -68,8 +79,14 @@ pub use error::Error;
 pub use error::ErrorKind;
 pub use error::Result;
 
+mod acl;
+pub use acl::Acl;
+pub use acl::AclEntry;
+pub use acl::AclEntryKind;
+
 mod capability;
 pub use capability::Capability;
+pub use capability::OperationCapability;
 
 mod bytes_range;
 pub use bytes_range::BytesRange;
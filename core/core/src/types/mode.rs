@@ -25,6 +25,11 @@ pub enum EntryMode {
     FILE,
     /// DIR means the path can be listed.
     DIR,
+    /// LINK means the path is a symbolic link.
+    ///
+    /// The link target can be found in [`Metadata::link_target`](crate::Metadata::link_target)
+    /// if the service reports it.
+    LINK,
     /// Unknown means we don't know what we can do on this path.
     #[default]
     Unknown,
@@ -41,6 +46,11 @@ impl EntryMode {
         self == EntryMode::DIR
     }
 
+    /// Check if this mode is LINK.
+    pub fn is_link(self) -> bool {
+        self == EntryMode::LINK
+    }
+
     /// Create entry mode from given path.
     #[allow(dead_code)]
     pub fn from_path(path: &str) -> Self {
@@ -57,6 +67,7 @@ impl Display for EntryMode {
         match self {
             EntryMode::FILE => write!(f, "file"),
             EntryMode::DIR => write!(f, "dir"),
+            EntryMode::LINK => write!(f, "link"),
             EntryMode::Unknown => write!(f, "unknown"),
         }
     }
@@ -18,6 +18,9 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use bytes::Bytes;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -491,6 +494,110 @@ impl Operator {
         Ok(rp.into_metadata())
     }
 
+    /// Retrieve the metadata of a batch of paths.
+    ///
+    /// Stat requests are pipelined with 16 in flight by default, use
+    /// [`Operator::stat_batch_options`] to tune it.
+    ///
+    /// Returns one result per path in the same order as the input. A failed
+    /// path, like a `NotFound` one, doesn't stop the rest of the batch.
+    ///
+    /// Every path is still sent as a single stat request. Backend batch
+    /// endpoints are not used: Azure Blob batch only supports delete and set
+    /// tier, and the GCS JSON batch endpoint would need a batch stat API in
+    /// every layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op.stat_batch(["a.txt", "b.txt", "dir/"]).await?;
+    /// for meta in results {
+    ///     match meta {
+    ///         Ok(meta) => println!("{}", meta.content_length()),
+    ///         Err(err) => println!("stat failed: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_batch<I, P>(&self, paths: I) -> Result<Vec<Result<Metadata>>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        self.stat_batch_options(paths, options::StatBatchOptions::default())
+            .await
+    }
+
+    /// Retrieve the metadata of a batch of paths with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::StatBatchOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::options;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .stat_batch_options(["a.txt", "b.txt"], options::StatBatchOptions {
+    ///         concurrent: 64,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_batch_options<I, P>(
+        &self,
+        paths: I,
+        opts: options::StatBatchOptions,
+    ) -> Result<Vec<Result<Metadata>>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let ctx = self.context().clone();
+        let srv = self.service().clone();
+
+        // Every stat reports its own result, the task itself never fails so
+        // that one bad path won't abort the batch.
+        let mut tasks = ConcurrentTasks::new(
+            ctx.executor().clone(),
+            opts.concurrent.max(1),
+            0,
+            |input: (OperationContext, Servicer, String)| {
+                Box::pin(async move {
+                    let (ctx, srv, path) = input.clone();
+                    let result =
+                        Self::stat_inner(ctx, srv, path, options::StatOptions::default()).await;
+                    (input, Ok(result))
+                })
+            },
+        );
+
+        let mut results = Vec::new();
+        for path in paths {
+            let path = normalize_path(path.as_ref());
+            tasks.execute((ctx.clone(), srv.clone(), path)).await?;
+            while tasks.has_result() {
+                if let Some(result) = tasks.next().await.transpose()? {
+                    results.push(result);
+                }
+            }
+        }
+        while let Some(result) = tasks.next().await.transpose()? {
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
     /// Check whether this path exists.
     ///
     /// # Example
@@ -515,6 +622,42 @@ impl Operator {
         }
     }
 
+    /// Check whether each of a batch of paths exists.
+    ///
+    /// This is built on [`Operator::stat_batch`]: `NotFound` becomes `false`,
+    /// while other errors are reported for their own path without stopping the
+    /// rest of the batch. Results are in the same order as the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = ["a.txt", "b.txt"];
+    /// let results = op.exists_batch(paths).await?;
+    /// for (path, exists) in paths.iter().zip(results) {
+    ///     println!("{path}: {}", exists?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists_batch<I, P>(&self, paths: I) -> Result<Vec<Result<bool>>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let results = self.stat_batch(paths).await?;
+        Ok(results
+            .into_iter()
+            .map(|r| match r {
+                Ok(_) => Ok(true),
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+                Err(err) => Err(err),
+            })
+            .collect())
+    }
+
     /// Create a directory at the specified path.
     ///
     /// # Notes
@@ -557,6 +700,157 @@ impl Operator {
         Ok(())
     }
 
+    /// Get the access control list of the given path.
+    ///
+    /// # Notes
+    ///
+    /// Check [`Capability::get_acl`] before using this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let acl = op.get_acl("path/to/file").await?;
+    /// println!("owner: {:?}, entries: {:?}", acl.owner(), acl.entries());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_acl(&self, path: &str) -> Result<Acl> {
+        let path = normalize_path(path);
+
+        let rp = self.srv.get_acl(&self.ctx, &path, OpGetAcl::new()).await?;
+
+        Ok(rp.into_acl())
+    }
+
+    /// Set the access control list of the given path.
+    ///
+    /// # Notes
+    ///
+    /// Check [`Capability::set_acl`] before using this method.
+    ///
+    /// # Behavior
+    ///
+    /// - Fields of `acl` that are not set will be left unchanged.
+    /// - Non-empty entries replace all existing entries of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::Acl;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let entries = Acl::parse_entries("user::rwx,group::r-x,other::---")?;
+    /// op.set_acl("path/to/dir/", Acl::new().with_entries(entries))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_acl(&self, path: &str, acl: Acl) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.srv
+            .set_acl(&self.ctx, &path, OpSetAcl::new(acl))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Restore an archived file so that it can be read again.
+    ///
+    /// # Notes
+    ///
+    /// Check [`Capability::restore`] before using this method.
+    ///
+    /// # Behavior
+    ///
+    /// - The restore is started in the background, the file becomes readable
+    ///   once the service finishes restoring it.
+    /// - The restored copy expires after the given days, the archived file is
+    ///   kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore(&self, path: &str) -> Result<()> {
+        self.restore_with(path).await
+    }
+
+    /// Restore an archived file with additional options.
+    ///
+    /// # Options
+    ///
+    /// Visit [`options::RestoreOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore_with("path/to/file")
+    ///     .days(7)
+    ///     .tier("Expedited")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore_with(&self, path: &str) -> FutureRestore<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.context().clone(),
+            self.service().clone(),
+            path,
+            options::RestoreOptions::default(),
+            Self::restore_inner,
+        )
+    }
+
+    /// Restore an archived file with additional options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::options;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.restore_options("path/to/file", options::RestoreOptions {
+    ///     days: Some(7),
+    ///     ..Default::default()
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_options(&self, path: &str, opts: options::RestoreOptions) -> Result<()> {
+        let path = normalize_path(path);
+        Self::restore_inner(self.context().clone(), self.service().clone(), path, opts).await
+    }
+
+    async fn restore_inner(
+        ctx: OperationContext,
+        srv: Servicer,
+        path: String,
+        opts: options::RestoreOptions,
+    ) -> Result<()> {
+        srv.restore(&ctx, &path, opts.into()).await?;
+        Ok(())
+    }
+
     /// Read the entire file into bytes from given path.
     ///
     /// # Notes
@@ -669,10 +963,49 @@ impl Operator {
         }
 
         let (range, args, opts) = opts.into();
+        let verify_checksum = args.verify_checksum();
+        if verify_checksum && cfg!(not(feature = "checksum")) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "verify checksum requires the checksum feature",
+            )
+            .with_operation("read")
+            .with_context("service", srv.info().scheme())
+            .with_context("path", &path));
+        }
+        if verify_checksum && !range.is_full() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "verify checksum requires reading the whole content",
+            )
+            .with_operation("read")
+            .with_context("service", srv.info().scheme())
+            .with_context("path", &path)
+            .with_context("range", range.to_string()));
+        }
+
         let reader = srv.read(&ctx, &path, args.clone())?;
-        let read_context = ReadContext::new(ctx, srv, path, args, opts, reader);
+        let read_context = ReadContext::new(ctx, srv.clone(), path.clone(), args, opts, reader);
         let r = Reader::new(read_context);
         let buf = r.read(range).await?;
+
+        #[cfg(feature = "checksum")]
+        if verify_checksum {
+            let Some(checksum) = r.metadata().and_then(|v| v.checksum()) else {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "service doesn't advertise a checksum for this object",
+                )
+                .with_operation("read")
+                .with_context("service", srv.info().scheme())
+                .with_context("path", &path));
+            };
+            checksum.verify(&buf).map_err(|err| {
+                err.with_operation("read")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("path", &path)
+            })?;
+        }
         Ok(buf)
     }
 
@@ -959,6 +1292,144 @@ impl Operator {
         w.close().await
     }
 
+    /// Write all data read from an [`AsyncRead`](futures::AsyncRead) source to the
+    /// specified path.
+    ///
+    /// # Notes
+    ///
+    /// The source is streamed without buffering the whole payload in memory:
+    ///
+    /// - If [`options::WriteFromOptions::size`] is known and fits in one chunk, the
+    ///   source is uploaded in one request.
+    /// - Otherwise the source is read chunk by chunk and written with multipart
+    ///   uploads if the service supports [`Capability::write_can_multi`].
+    ///
+    /// Services that can't write in parts have to upload in one request, so the
+    /// whole source is buffered for them.
+    ///
+    /// The chunk size is `chunk` of the write options if set, or decided by the
+    /// service capability otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use futures::io::Cursor;
+    /// use opendal_core::options;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = Cursor::new(vec![0; 4096]);
+    /// op.write_from("path/to/file", r, options::WriteFromOptions {
+    ///     size: Some(4096),
+    ///     ..Default::default()
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_from<R>(
+        &self,
+        path: &str,
+        r: R,
+        opts: options::WriteFromOptions,
+    ) -> Result<Metadata>
+    where
+        R: AsyncRead + Unpin + MaybeSend,
+    {
+        let path = normalize_path(path);
+        Self::write_from_inner(
+            self.context().clone(),
+            self.service().clone(),
+            path,
+            r,
+            opts,
+        )
+        .await
+    }
+
+    async fn write_from_inner<R>(
+        ctx: OperationContext,
+        srv: Servicer,
+        path: String,
+        mut r: R,
+        opts: options::WriteFromOptions,
+    ) -> Result<Metadata>
+    where
+        R: AsyncRead + Unpin + MaybeSend,
+    {
+        /// The chunk size used if neither the user nor the service decides it.
+        const DEFAULT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                    .with_operation("Operator::write_from")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let cap = srv.capability();
+        let options::WriteFromOptions { size, mut write } = opts;
+        let chunk = write
+            .chunk
+            .or(cap.write_multi_min_size)
+            .unwrap_or(DEFAULT_CHUNK_SIZE);
+
+        let one_shot = match size {
+            Some(size) => size <= chunk as u64 || !cap.write_can_multi,
+            None => !cap.write_can_multi,
+        };
+        if !one_shot {
+            write.chunk.get_or_insert(chunk);
+        }
+
+        let (args, opts) = write.into();
+        let write_context = WriteContext::new(ctx, srv, path, args, opts);
+        let mut w = Writer::new(write_context).await?;
+
+        // Read one more byte than the given size to detect sources that are too long.
+        let limit = match (one_shot, size) {
+            (true, Some(size)) => size.saturating_add(1),
+            (true, None) => u64::MAX,
+            (false, _) => chunk as u64,
+        };
+
+        let mut written = 0;
+        let res = async {
+            loop {
+                let bs = read_to_buffer(&mut r, limit).await?;
+                if bs.is_empty() {
+                    break;
+                }
+                written += bs.len() as u64;
+                w.write(bs).await?;
+            }
+
+            match size {
+                Some(size) if size != written => Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "source size doesn't match the given size",
+                )
+                .with_operation("Operator::write_from")
+                .with_context("expect", size)
+                .with_context("actual", written)),
+                _ => Ok(()),
+            }
+        }
+        .await;
+
+        match res {
+            Ok(()) => w.close().await,
+            Err(err) => {
+                // Abort the pending write, the error of source is more useful.
+                let _ = w.abort().await;
+                Err(err)
+            }
+        }
+    }
+
     /// Create a new writer of given path.
     ///
     /// # Notes
@@ -1125,6 +1596,63 @@ impl Operator {
         Ok(w)
     }
 
+    /// Open a [`File`] to read, write and seek within given path.
+    ///
+    /// # Notes
+    ///
+    /// - The file will be created on the first flush if it doesn't exist.
+    /// - Writes are buffered until flush, refer to [`File`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Operator;
+    /// use futures::AsyncWriteExt;
+    ///
+    /// # async fn test(op: Operator) -> std::io::Result<()> {
+    /// let mut f = op.open("path/to/file").await?;
+    /// f.write_all(b"hello").await?;
+    /// f.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open(&self, path: &str) -> Result<File> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "open path is a directory")
+                    .with_operation("Operator::open")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let capability = self.info().capability();
+        if !capability.read || !capability.write {
+            return Err(
+                Error::new(ErrorKind::Unsupported, "open requires both read and write")
+                    .with_operation("Operator::open")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let size = match self.stat(&path).await {
+            Ok(meta) => Some(meta.content_length()),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+        let reader = self.reader(&path).await?;
+        Ok(File::new(
+            self.clone(),
+            path,
+            reader,
+            capability.write_can_seek,
+            size,
+        ))
+    }
+
     /// Copy a file from `from` to `to`.
     ///
     /// # Notes
@@ -1292,6 +1820,19 @@ impl Operator {
         srv: Servicer,
         from: String,
         (opts, to): (options::CopyOptions, String),
+    ) -> Result<Metadata> {
+        if opts.recursive {
+            Self::copy_dir_inner(ctx, srv, from, (opts, to)).await
+        } else {
+            Self::copy_file_inner(ctx, srv, from, (opts, to)).await
+        }
+    }
+
+    async fn copy_file_inner(
+        ctx: OperationContext,
+        srv: Servicer,
+        from: String,
+        (opts, to): (options::CopyOptions, String),
     ) -> Result<Metadata> {
         if !validate_path(&from, EntryMode::FILE) {
             return Err(
@@ -1337,11 +1878,314 @@ impl Operator {
         from: String,
         (opts, to): (options::CopyOptions, String),
     ) -> Result<Copier> {
+        if opts.recursive {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "recursive copy is not supported by copier, use copy_with instead",
+            )
+            .with_operation("Operator::copier")
+            .with_context("service", srv.info().scheme()));
+        }
+
+        if !validate_path(&from, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "from path is a directory")
+                    .with_operation("Operator::copier")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("from", from),
+            );
+        }
+
+        if !validate_path(&to, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                    .with_operation("Operator::copier")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("to", to),
+            );
+        }
+
+        if from == to && opts.source_version.is_none() {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
+                    .with_operation("Operator::copier")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("from", &from)
+                    .with_context("to", &to),
+            );
+        }
+
+        let (args, opts) = opts.into();
+        std::future::ready(Copier::create(ctx, srv, &from, &to, args, opts)).await
+    }
+
+    async fn copy_dir_inner(
+        ctx: OperationContext,
+        srv: Servicer,
+        from: String,
+        (opts, to): (options::CopyOptions, String),
+    ) -> Result<Metadata> {
+        for (name, path) in [("from", &from), ("to", &to)] {
+            if !validate_path(path, EntryMode::DIR) {
+                return Err(Error::new(
+                    ErrorKind::NotADirectory,
+                    format!("{name} path is not a directory"),
+                )
+                .with_operation("Operator::copy")
+                .with_context("service", srv.info().scheme())
+                .with_context(name, path));
+            }
+        }
+
+        // Copying into a path under `from` would list the entries we just created.
+        if from == "/" || to.starts_with(&from) {
+            return Err(
+                Error::new(ErrorKind::IsSameFile, "to path is inside from path")
+                    .with_operation("Operator::copy")
+                    .with_context("service", srv.info().scheme())
+                    .with_context("from", &from)
+                    .with_context("to", &to),
+            );
+        }
+
+        if opts.source_version.is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "source_version is not supported by recursive copy",
+            )
+            .with_operation("Operator::copy")
+            .with_context("service", srv.info().scheme()));
+        }
+
+        let capability = srv.capability();
+        if capability.create_dir {
+            srv.create_dir(&ctx, &to, OpCreateDir::new()).await?;
+        }
+
+        let mut tasks = ConcurrentTasks::new(
+            ctx.executor().clone(),
+            opts.concurrent.max(1),
+            0,
+            |input: CopyEntryInput| {
+                Box::pin(async move {
+                    let result = Self::copy_entry(&input).await;
+                    (input, result)
+                })
+            },
+        );
+
+        let mut lister = Lister::create(
+            ctx.clone(),
+            srv.clone(),
+            &from,
+            OpList::new().with_recursive(true),
+        )?;
+        while let Some(entry) = lister.try_next().await? {
+            let Some(relative) = entry.path().strip_prefix(from.as_str()) else {
+                continue;
+            };
+            if relative.is_empty() {
+                continue;
+            }
+            let target = format!("{to}{relative}");
+
+            if entry.metadata().is_dir() {
+                if capability.create_dir {
+                    srv.create_dir(&ctx, &target, OpCreateDir::new()).await?;
+                }
+                continue;
+            }
+
+            let mut file_opts = opts.clone();
+            file_opts.recursive = false;
+            if entry.metadata().has_content_length() {
+                file_opts.source_content_length_hint = Some(entry.metadata().content_length());
+            }
+            tasks
+                .execute(CopyEntryInput {
+                    ctx: ctx.clone(),
+                    srv: srv.clone(),
+                    from: entry.path().to_string(),
+                    to: target,
+                    opts: file_opts,
+                })
+                .await?;
+            while tasks.has_result() {
+                tasks.next().await.transpose()?;
+            }
+        }
+        while tasks.next().await.transpose()?.is_some() {}
+
+        Ok(Metadata::new(EntryMode::DIR))
+    }
+
+    /// Copy a single file for [`Operator::copy_dir_inner`].
+    ///
+    /// Falls back to streaming the content when the service can't copy.
+    async fn copy_entry(input: &CopyEntryInput) -> Result<()> {
+        let CopyEntryInput {
+            ctx,
+            srv,
+            from,
+            to,
+            opts,
+        } = input.clone();
+
+        if srv.capability().copy {
+            return Self::copy_file_inner(ctx, srv, from, (opts, to))
+                .await
+                .map(|_| ());
+        }
+
+        let reader = Self::reader_inner(
+            ctx.clone(),
+            srv.clone(),
+            from,
+            options::ReaderOptions::default(),
+        )
+        .await?;
+        let write_opts = options::WriteOptions {
+            if_not_exists: opts.if_not_exists,
+            if_match: opts.if_match,
+            ..Default::default()
+        };
+        let writer = Self::writer_inner(ctx, srv, to, write_opts).await?;
+        Self::copy_stream(reader, writer).await.map(|_| ())
+    }
+
+    /// Stream all content from `reader` into `writer`, aborting the writer on failure.
+    async fn copy_stream(reader: Reader, mut writer: Writer) -> Result<Metadata> {
+        let mut stream = reader.into_stream(..).await?;
+        loop {
+            let res = match stream.try_next().await {
+                Ok(Some(buf)) => writer.write(buf).await,
+                Ok(None) => break,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = res {
+                let _ = writer.abort().await;
+                return Err(err);
+            }
+        }
+        writer.close().await
+    }
+
+    /// Copy a file from `from` of operator `src` to `to` of this operator.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file.
+    /// - `to` will be overwritten if it exists.
+    /// - If both operators point to the same storage, the native [`Operator::copy`]
+    ///   will be used. Otherwise the content is streamed from `src` into this operator.
+    /// - Operators built separately are treated as the same storage if they share
+    ///   scheme, name and root, and both see the same `etag` and size at `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(gcs: Operator, s3: Operator) -> Result<()> {
+    /// s3.copy_from(&gcs, "path/to/file", "path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_from(&self, src: &Operator, from: &str, to: &str) -> Result<Metadata> {
+        self.copy_from_options(src, from, to, options::CopyOptions::default())
+            .await
+    }
+
+    /// Copy a file from `from` of operator `src` to `to` of this operator with additional options.
+    ///
+    /// # Notes
+    ///
+    /// - `concurrent` and `chunk` are used by both reading from `src` and writing
+    ///   into this operator while streaming.
+    /// - `recursive` is not supported.
+    ///
+    /// # Options
+    ///
+    /// Visit [`options::CopyOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(gcs: Operator, s3: Operator) -> Result<()> {
+    /// s3.copy_from_with(&gcs, "path/to/file", "path/to/file")
+    ///     .concurrent(8)
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_from_with(
+        &self,
+        src: &Operator,
+        from: &str,
+        to: &str,
+    ) -> FutureCopyFrom<impl Future<Output = Result<Metadata>>> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        OperatorFuture::new(
+            self.context().clone(),
+            self.service().clone(),
+            to,
+            (options::CopyOptions::default(), src.clone(), from),
+            Self::copy_from_inner,
+        )
+    }
+
+    /// Copy a file from `from` of operator `src` to `to` of this operator with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::CopyOptions`] for all available options.
+    pub async fn copy_from_options(
+        &self,
+        src: &Operator,
+        from: &str,
+        to: &str,
+        opts: impl Into<options::CopyOptions>,
+    ) -> Result<Metadata> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+        let opts = opts.into();
+
+        Self::copy_from_inner(
+            self.context().clone(),
+            self.service().clone(),
+            to,
+            (opts, src.clone(), from),
+        )
+        .await
+    }
+
+    async fn copy_from_inner(
+        ctx: OperationContext,
+        srv: Servicer,
+        to: String,
+        (opts, src, from): (options::CopyOptions, Operator, String),
+    ) -> Result<Metadata> {
+        if opts.recursive {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "recursive copy is not supported by copy_from",
+            )
+            .with_operation("Operator::copy_from")
+            .with_context("service", srv.info().scheme()));
+        }
+
         if !validate_path(&from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("Operator::copier")
-                    .with_context("service", srv.info().scheme())
+                    .with_operation("Operator::copy_from")
+                    .with_context("service", src.info().scheme())
                     .with_context("from", from),
             );
         }
@@ -1349,24 +2193,78 @@ impl Operator {
         if !validate_path(&to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
-                    .with_operation("Operator::copier")
+                    .with_operation("Operator::copy_from")
                     .with_context("service", srv.info().scheme())
                     .with_context("to", to),
             );
         }
 
-        if from == to && opts.source_version.is_none() {
-            return Err(
-                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
-                    .with_operation("Operator::copier")
-                    .with_context("service", srv.info().scheme())
-                    .with_context("from", &from)
-                    .with_context("to", &to),
-            );
+        if srv.capability().copy
+            && Self::is_same_storage(&ctx, &srv, &src, &from, &opts.source_version).await
+        {
+            return Self::copy_file_inner(ctx, srv, from, (opts, to)).await;
         }
 
-        let (args, opts) = opts.into();
-        std::future::ready(Copier::create(ctx, srv, &from, &to, args, opts)).await
+        let reader = src
+            .reader_options(
+                &from,
+                options::ReaderOptions {
+                    version: opts.source_version.clone(),
+                    content_length_hint: opts.source_content_length_hint,
+                    concurrent: opts.concurrent,
+                    chunk: opts.chunk,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let write_opts = options::WriteOptions {
+            if_not_exists: opts.if_not_exists,
+            if_match: opts.if_match,
+            concurrent: opts.concurrent,
+            chunk: opts.chunk,
+            ..Default::default()
+        };
+        let writer = Self::writer_inner(ctx, srv, to, write_opts).await?;
+        Self::copy_stream(reader, writer).await
+    }
+
+    /// Check whether `src` points to the same storage as `srv`, so that `from` can
+    /// be copied natively.
+    ///
+    /// `name` doesn't always identify the storage, for example the same bucket name
+    /// on different endpoints, so both sides must also see the same object.
+    async fn is_same_storage(
+        ctx: &OperationContext,
+        srv: &Servicer,
+        src: &Operator,
+        from: &str,
+        version: &Option<String>,
+    ) -> bool {
+        if Arc::ptr_eq(srv, src.service()) {
+            return true;
+        }
+
+        // Scheme, name and root must match.
+        if srv.info() != src.service().info() {
+            return false;
+        }
+
+        let opts = options::StatOptions {
+            version: version.clone(),
+            ..Default::default()
+        };
+        let (dst_meta, src_meta) = futures::join!(
+            Self::stat_inner(ctx.clone(), srv.clone(), from.to_string(), opts.clone()),
+            src.stat_options(from, opts),
+        );
+        match (dst_meta, src_meta) {
+            (Ok(dst_meta), Ok(src_meta)) => {
+                dst_meta.etag().is_some()
+                    && dst_meta.etag() == src_meta.etag()
+                    && dst_meta.content_length() == src_meta.content_length()
+            }
+            _ => false,
+        }
     }
 
     /// Rename a file from `from` to `to`.
@@ -1389,24 +2287,108 @@ impl Operator {
     /// # }
     /// ```
     pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        self.rename_options(from, to, options::RenameOptions::default())
+            .await
+    }
+
+    /// Rename a file from `from` to `to` with additional options.
+    ///
+    /// # Notes
+    ///
+    /// Refer to [`Operator::rename`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename_with("path/to/file", "path/to/file2")
+    ///     .atomic(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_with(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> FutureRename<impl Future<Output = Result<()>>> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        OperatorFuture::new(
+            self.context().clone(),
+            self.service().clone(),
+            from,
+            (options::RenameOptions::default(), to),
+            Self::rename_inner,
+        )
+    }
+
+    /// Rename a file from `from` to `to` with additional options.
+    ///
+    /// # Notes
+    ///
+    /// Refer to [`Operator::rename`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::options;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename_options(
+    ///     "path/to/file",
+    ///     "path/to/file2",
+    ///     options::RenameOptions { atomic: true },
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_options(
+        &self,
+        from: &str,
+        to: &str,
+        opts: impl Into<options::RenameOptions>,
+    ) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
+        let opts = opts.into();
+
+        Self::rename_inner(
+            self.context().clone(),
+            self.service().clone(),
+            from,
+            (opts, to),
+        )
+        .await
+    }
 
+    async fn rename_inner(
+        ctx: OperationContext,
+        srv: Servicer,
+        from: String,
+        (opts, to): (options::RenameOptions, String),
+    ) -> Result<()> {
         if !validate_path(&from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
                     .with_operation(Operation::Rename)
-                    .with_context("service", self.info().scheme())
+                    .with_context("service", srv.info().scheme())
                     .with_context("from", from),
             );
         }
 
-        let to = normalize_path(to);
-
         if !validate_path(&to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation(Operation::Rename)
-                    .with_context("service", self.info().scheme())
+                    .with_context("service", srv.info().scheme())
                     .with_context("to", to),
             );
         }
@@ -1415,15 +2397,13 @@ impl Operator {
             return Err(
                 Error::new(ErrorKind::IsSameFile, "from and to paths are same")
                     .with_operation(Operation::Rename)
-                    .with_context("service", self.info().scheme())
+                    .with_context("service", srv.info().scheme())
                     .with_context("from", from)
                     .with_context("to", to),
             );
         }
 
-        self.srv
-            .rename(&self.ctx, &from, &to, OpRename::new())
-            .await?;
+        srv.rename(&ctx, &from, &to, opts.into()).await?;
 
         Ok(())
     }
@@ -1517,15 +2497,54 @@ impl Operator {
         ctx: OperationContext,
         srv: Servicer,
         path: String,
-        opts: options::DeleteOptions,
+        mut opts: options::DeleteOptions,
     ) -> Result<()> {
+        let progress = opts.progress.take();
         let mut deleter = srv.delete(&ctx)?;
-        let args = opts.into();
-        deleter.delete_dyn(&path, args).await?;
+        let args: OpDelete = opts.into();
+        match progress {
+            Some(progress) if args.recursive() => {
+                Self::delete_with_progress(&ctx, &srv, &path, args, &mut deleter, &progress)
+                    .await?;
+            }
+            _ => deleter.delete_dyn(&path, args).await?,
+        }
         deleter.close_dyn().await?;
         Ok(())
     }
 
+    /// Delete every entry under `path` one by one so that each of them can
+    /// be counted by `progress`.
+    async fn delete_with_progress(
+        ctx: &OperationContext,
+        srv: &Servicer,
+        path: &str,
+        args: OpDelete,
+        deleter: &mut oio::Deleter,
+        progress: &DeleteProgress,
+    ) -> Result<()> {
+        let non_recursive = args.with_recursive(false);
+        let mut lister = srv.list(ctx, path, OpList::new().with_recursive(true))?;
+
+        // Fetch the next entry while the previous one is being deleted, so
+        // that listing the next page overlaps with sending a full batch.
+        let mut next = lister.next_dyn().await?;
+        while let Some(entry) = next {
+            let mut entry_args = non_recursive.clone();
+            if let Some(version) = entry.metadata().version() {
+                entry_args = entry_args.with_version(version);
+            }
+            let (_, entry) = futures::try_join!(
+                deleter.delete_dyn(entry.path(), entry_args),
+                lister.next_dyn()
+            )?;
+            next = entry;
+            progress.advance(1);
+        }
+
+        Ok(())
+    }
+
     /// Delete an infallible iterator of paths.
     ///
     /// Also see:
@@ -1598,6 +2617,149 @@ impl Operator {
         Ok(())
     }
 
+    /// Delete a batch of paths and report the result of every path.
+    ///
+    /// Unlike [`Operator::delete_iter`], a failed path doesn't abort the
+    /// batch. Returns one result per path in the same order as the input, so
+    /// callers can retry only the failed subset.
+    ///
+    /// Also see [`Operator::delete_batch_options`] to tune the concurrency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = vec!["a.txt", "b.txt"];
+    /// let results = op.delete_batch(paths.clone()).await?;
+    /// let failed: Vec<_> = paths
+    ///     .into_iter()
+    ///     .zip(results)
+    ///     .filter(|(_, r)| r.is_err())
+    ///     .map(|(p, _)| p)
+    ///     .collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_batch<I, D>(&self, iter: I) -> Result<Vec<Result<()>>>
+    where
+        I: IntoIterator<Item = D>,
+        D: IntoDeleteInput,
+    {
+        self.delete_batch_options(iter, options::DeleteBatchOptions::default())
+            .await
+    }
+
+    /// Delete a batch of paths with additional options and report the result
+    /// of every path.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::DeleteBatchOptions`] for all available options.
+    ///
+    /// # Behavior
+    ///
+    /// If the service supports batch delete, paths are first deleted in
+    /// batches of [`Capability::delete_max_size`]. Paths of a batch that fails
+    /// are deleted again one by one to find out which of them failed; this is
+    /// safe since deleting a missing path succeeds.
+    pub async fn delete_batch_options<I, D>(
+        &self,
+        iter: I,
+        opts: options::DeleteBatchOptions,
+    ) -> Result<Vec<Result<()>>>
+    where
+        I: IntoIterator<Item = D>,
+        D: IntoDeleteInput,
+    {
+        let ctx = self.context().clone();
+        let srv = self.service().clone();
+
+        let inputs: Vec<(String, options::DeleteOptions)> = iter
+            .into_iter()
+            .map(|input| {
+                let input = input.into_delete_input();
+                let opts = options::DeleteOptions {
+                    version: input.version,
+                    recursive: input.recursive,
+                    ..Default::default()
+                };
+                (normalize_path(&input.path), opts)
+            })
+            .collect();
+        let mut results: Vec<Option<Result<()>>> = Vec::new();
+        results.resize_with(inputs.len(), || None);
+
+        let batch_size = self.info().capability().delete_max_size.unwrap_or(1);
+        if batch_size > 1 {
+            for (idx, chunk) in inputs.chunks(batch_size).enumerate() {
+                if chunk.len() == 1 {
+                    continue;
+                }
+
+                let result = async {
+                    let mut deleter = srv.delete(&ctx)?;
+                    for (path, opts) in chunk {
+                        deleter.delete_dyn(path, opts.clone().into()).await?;
+                    }
+                    deleter.close_dyn().await
+                }
+                .await;
+                if result.is_ok() {
+                    let start = idx * batch_size;
+                    for r in &mut results[start..start + chunk.len()] {
+                        *r = Some(Ok(()));
+                    }
+                }
+            }
+        }
+
+        // Every delete reports its own result, the task itself never fails so
+        // that one bad path won't abort the batch.
+        let mut tasks = ConcurrentTasks::new(
+            ctx.executor().clone(),
+            opts.concurrent.max(1),
+            0,
+            |input: (OperationContext, Servicer, String, options::DeleteOptions)| {
+                Box::pin(async move {
+                    let (ctx, srv, path, opts) = input.clone();
+                    let result = Self::delete_inner(ctx, srv, path, opts).await;
+                    (input, Ok(result))
+                })
+            },
+        );
+
+        let mut pending = Vec::new();
+        let mut done = Vec::new();
+        for (idx, (path, opts)) in inputs.into_iter().enumerate() {
+            if results[idx].is_some() {
+                continue;
+            }
+
+            pending.push(idx);
+            tasks
+                .execute((ctx.clone(), srv.clone(), path, opts))
+                .await?;
+            while tasks.has_result() {
+                if let Some(result) = tasks.next().await.transpose()? {
+                    done.push(result);
+                }
+            }
+        }
+        while let Some(result) = tasks.next().await.transpose()? {
+            done.push(result);
+        }
+        for (idx, result) in pending.into_iter().zip(done) {
+            results[idx] = Some(result);
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|r| r.expect("every path must have a delete result"))
+            .collect())
+    }
+
     /// Create a [`Deleter`] to continuously remove content from storage.
     ///
     /// It leverages batch deletion capabilities provided by storage services for efficient removal.
@@ -1685,7 +2847,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1712,6 +2874,7 @@ impl Operator {
     /// See [`options::ListOptions`] for the full set. Common knobs:
     /// - Traversal: `recursive` (default `false`) toggles depth-first listing under the prefix.
     /// - Pagination: `limit` and `start_after` tune page size and resume positions (backend dependent).
+    /// - Filtering: `suffix` and `glob` select entries by path, pushed down to the backend when supported.
     /// - Versioning: `versions` / `deleted` ask versioned backends to return extra entries.
     ///
     /// # Examples
@@ -1732,7 +2895,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1788,7 +2951,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1819,7 +2982,7 @@ impl Operator {
     ///
     /// # Options
     ///
-    /// Takes the same [`options::ListOptions`] as [`list_with`](Operator::list_with): traversal (`recursive`), pagination (`limit`, `start_after`), and versioning (`versions`, `deleted`).
+    /// Takes the same [`options::ListOptions`] as [`list_with`](Operator::list_with): traversal (`recursive`), pagination (`limit`, `start_after`), filtering (`suffix`, `glob`), and versioning (`versions`, `deleted`).
     ///
     /// # Examples
     ///
@@ -1839,7 +3002,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1874,7 +3037,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1927,7 +3090,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::LINK | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -2457,4 +3620,251 @@ impl Operator {
         let rp = srv.presign(&ctx, &path, op).await?;
         Ok(rp.into_presigned_request())
     }
+
+    /// Presign an operation for creating a multipart upload.
+    ///
+    /// The response of the presigned request carries the upload id in the
+    /// service specific body format, for example `UploadId` in the
+    /// `InitiateMultipartUploadResult` XML of s3 and gcs. Pass the upload id
+    /// to [`Self::presign_multipart`] to presign the remaining requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal_core::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_create_multipart("test.txt", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `POST`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?uploads&X-Amz-Algorithm=AWS4-HMAC-SHA256&...`
+    pub async fn presign_create_multipart(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.presign_create_multipart_options(path, expire, options::WriteOptions::default())
+            .await
+    }
+
+    /// Presign an operation for creating a multipart upload with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::WriteOptions`] for all available options, only the
+    /// options that are sent while creating the upload (such as `content_type`)
+    /// take effect.
+    pub async fn presign_create_multipart_options(
+        &self,
+        path: &str,
+        expire: Duration,
+        opts: options::WriteOptions,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+        let (op_write, _) = opts.into();
+        let op = OpPresign::new(PresignOperation::CreateMultipart(op_write), expire);
+        let rp = self.service().presign(self.context(), &path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for uploading one part of a multipart upload.
+    ///
+    /// `part_number` starts from `1`. The presigned request must be sent with
+    /// the data of this part as body.
+    pub async fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+        let op = OpPresign::new(
+            PresignOperation::UploadPart {
+                upload_id: upload_id.to_string(),
+                part_number,
+            },
+            expire,
+        );
+        let rp = self.service().presign(self.context(), &path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for completing a multipart upload.
+    ///
+    /// The presigned request must be sent with the list of uploaded parts in
+    /// the service specific body format, for example the
+    /// `CompleteMultipartUpload` XML of s3 and gcs.
+    pub async fn presign_complete_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+        let op = OpPresign::new(
+            PresignOperation::CompleteMultipart {
+                upload_id: upload_id.to_string(),
+            },
+            expire,
+        );
+        let rp = self.service().presign(self.context(), &path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign all requests needed to upload `parts` parts to an existing
+    /// multipart upload and complete it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal_core::Operator;
+    ///
+    /// async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    ///     let signed = op
+    ///         .presign_multipart("test.txt", upload_id, 3, Duration::from_secs(3600))
+    ///         .await?;
+    ///     for (idx, part) in signed.parts().iter().enumerate() {
+    ///         // Upload part `idx + 1` via `part.method()` and `part.uri()`.
+    ///     }
+    ///     // Send the uploaded parts list via `signed.complete()`.
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: usize,
+        expire: Duration,
+    ) -> Result<PresignedMultipart> {
+        let mut signed_parts = Vec::with_capacity(parts);
+        for part_number in 1..=parts {
+            signed_parts.push(
+                self.presign_upload_part(path, upload_id, part_number, expire)
+                    .await?,
+            );
+        }
+        let complete = self
+            .presign_complete_multipart(path, upload_id, expire)
+            .await?;
+
+        Ok(PresignedMultipart::new(signed_parts, complete))
+    }
+}
+
+/// Input of the tasks spawned by recursive copy.
+#[derive(Clone)]
+struct CopyEntryInput {
+    ctx: OperationContext,
+    srv: Servicer,
+    from: String,
+    to: String,
+    opts: options::CopyOptions,
+}
+
+/// Read from the source until `limit` bytes are read or it ends.
+async fn read_to_buffer<R>(r: &mut R, limit: u64) -> Result<Buffer>
+where
+    R: AsyncRead + Unpin,
+{
+    const READ_SIZE: u64 = 64 * 1024;
+
+    let mut bufs = vec![];
+    let mut total = 0;
+    while total < limit {
+        let mut buf = vec![0; READ_SIZE.min(limit - total) as usize];
+        let n = r.read(&mut buf).await.map_err(new_std_io_error)?;
+        if n == 0 {
+            break;
+        }
+        buf.truncate(n);
+        total += n as u64;
+        bufs.push(Bytes::from(buf));
+    }
+
+    Ok(Buffer::from(bufs))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::layers::CapabilityOverrideLayer;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_delete_batch_reports_failed_paths() -> Result<()> {
+        // Memory doesn't support delete with version, so `bad` is rejected
+        // while the other paths of the same batch are still deleted.
+        let op = Operator::new(services::Memory::default())?.layer(CapabilityOverrideLayer::new(
+            |mut cap| {
+                cap.delete_max_size = Some(2);
+                cap
+            },
+        ));
+        for path in ["a", "b", "c", "d"] {
+            op.write(path, "data").await?;
+        }
+
+        let results = op
+            .delete_batch([
+                ("a".to_string(), OpDelete::new()),
+                ("b".to_string(), OpDelete::new()),
+                ("bad".to_string(), OpDelete::new().with_version("v1")),
+                ("c".to_string(), OpDelete::new()),
+                ("d".to_string(), OpDelete::new()),
+            ])
+            .await?;
+        let failed: Vec<_> = results.iter().map(|r| r.is_err()).collect();
+        assert_eq!(failed, vec![false, false, true, false, false]);
+        assert_eq!(
+            results[2].as_ref().unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+
+        // [bad, c] fails as a batch, so c is only deleted by the retry.
+        for path in ["a", "b", "c", "d"] {
+            assert!(!op.exists(path).await?, "{path} should be deleted");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_recursive_reports_progress() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?;
+        for path in ["dir/a", "dir/b/c", "dir/b/d", "other"] {
+            op.write(path, "data").await?;
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let progress = {
+            let seen = seen.clone();
+            DeleteProgress::with_callback(move |v| seen.lock().unwrap().push(v))
+        };
+        op.delete_with("dir/")
+            .recursive(true)
+            .progress(progress.clone())
+            .await?;
+
+        assert!(op.list_with("dir/").recursive(true).await?.is_empty());
+        assert!(op.exists("other").await?);
+        assert!(progress.deleted() >= 3);
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.last().copied(), Some(progress.deleted()));
+
+        Ok(())
+    }
 }
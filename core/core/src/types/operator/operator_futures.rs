@@ -126,6 +126,14 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
         self.args.version = Some(v.to_string());
         self
     }
+
+    /// Set whether to return the checksum for this operation.
+    ///
+    /// Refer to [`options::StatOptions::checksum`] for more details.
+    pub fn checksum(mut self, v: bool) -> Self {
+        self.args.checksum = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -259,6 +267,8 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
     /// - `1024..` means read bytes in range `[1024, n)` of file
     /// - `BytesRange::suffix(1024)` means read the last `min(1024, n)` bytes of file
     ///
+    /// Refer to [`options::ReadOptions::range`] for how suffix ranges are served.
+    ///
     /// ```
     /// # use opendal_core::Result;
     /// # use opendal_core::Operator;
@@ -281,6 +291,14 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
         self
     }
 
+    /// Set `verify_checksum` for this `read` request.
+    ///
+    /// Refer to [`options::ReadOptions::verify_checksum`] for more details.
+    pub fn verify_checksum(mut self, v: bool) -> Self {
+        self.args.verify_checksum = v;
+        self
+    }
+
     /// Set `concurrent` for the reader.
     ///
     /// OpenDAL by default to write file without concurrent. This is not efficient for cases when users
@@ -426,6 +444,22 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
         self.args.if_unmodified_since = Some(v.into());
         self
     }
+
+    /// Set the timeout for the service to start responding to each read request.
+    ///
+    /// Refer to [`options::ReadOptions::first_byte_timeout`] for more details.
+    pub fn first_byte_timeout(mut self, v: Duration) -> Self {
+        self.args.first_byte_timeout = Some(v);
+        self
+    }
+
+    /// Set the total time allowed for this read.
+    ///
+    /// Refer to [`options::ReadOptions::deadline`] for more details.
+    pub fn deadline(mut self, v: Duration) -> Self {
+        self.args.deadline = Some(v);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::read_with`] or [`Operator::reader_with`].
@@ -626,6 +660,22 @@ impl<F: Future<Output = Result<Reader>>> FutureReader<F> {
         self.args.if_unmodified_since = Some(v.into());
         self
     }
+
+    /// Set the timeout for the service to start responding to each read request.
+    ///
+    /// Refer to [`options::ReaderOptions::first_byte_timeout`] for more details.
+    pub fn first_byte_timeout(mut self, v: Duration) -> Self {
+        self.args.first_byte_timeout = Some(v);
+        self
+    }
+
+    /// Set the total time allowed for this read.
+    ///
+    /// Refer to [`options::ReaderOptions::deadline`] for more details.
+    pub fn deadline(mut self, v: Duration) -> Self {
+        self.args.deadline = Some(v);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::write_with`].
@@ -660,6 +710,29 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self
     }
 
+    /// Sets the offset to start writing at.
+    ///
+    /// Refer to [`options::WriteOptions::offset`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .offset(8192)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.args.0.offset = Some(offset);
+        self
+    }
+
     /// Sets chunk size for buffered writes.
     ///
     /// Refer to [`options::WriteOptions::chunk`] for more details.
@@ -715,6 +788,14 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self
     }
 
+    /// Tune the concurrency of this write adaptively.
+    ///
+    /// Refer to [`FutureWriter::adaptive_concurrency`] for more details.
+    pub fn adaptive_concurrency(mut self, adaptive: AdaptiveConcurrency) -> Self {
+        self.args.0.adaptive_concurrency = Some(adaptive);
+        self
+    }
+
     /// Sets Cache-Control header for this write operation.
     ///
     /// Refer to [`options::WriteOptions::cache_control`] for more details.
@@ -897,6 +978,70 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self
     }
 
+    /// Sets the time until which the written object can't be modified or deleted.
+    ///
+    /// Refer to [`options::WriteOptions::immutability_until`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::raw::Timestamp;
+    ///
+    /// # async fn test(op: Operator, until: Timestamp) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .immutability_until(until)
+    ///     .legal_hold(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn immutability_until(mut self, v: Timestamp) -> Self {
+        self.args.0.immutability_until = Some(v);
+        self
+    }
+
+    /// Sets a legal hold on the written object.
+    ///
+    /// Refer to [`options::WriteOptions::legal_hold`] for more details.
+    pub fn legal_hold(mut self, v: bool) -> Self {
+        self.args.0.legal_hold = v;
+        self
+    }
+
+    /// Holds an exclusive lock on the object while writing.
+    ///
+    /// Refer to [`options::WriteOptions::lock`] for more details.
+    pub fn lock(mut self, v: bool) -> Self {
+        self.args.0.lock = v;
+        self
+    }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::ttl`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # use std::time::Duration;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .ttl(Duration::from_secs(3600))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.args.0.ttl = Some(ttl);
+        self
+    }
+
     /// Sets user metadata for this write request.
     ///
     /// Refer to [`options::WriteOptions::user_metadata`] for more details.
@@ -925,6 +1070,54 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self.args.0.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Resume a suspended writer with the remaining data.
+    ///
+    /// Refer to [`FutureWriter::checkpoint`] for more details.
+    pub fn checkpoint(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.args.0.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Compress data while writing.
+    ///
+    /// Refer to [`options::WriteOptions::compression`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::WriteCompression;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file.zst", vec![0; 4096])
+    ///     .compress(WriteCompression::Zstd { level: 3 })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compress(mut self, compression: WriteCompression) -> Self {
+        self.args.0.compression = Some(compression);
+        self
+    }
+
+    /// Set the timeout for each chunk written to the service.
+    ///
+    /// Refer to [`options::WriteOptions::chunk_timeout`] for more details.
+    pub fn chunk_timeout(mut self, v: Duration) -> Self {
+        self.args.0.chunk_timeout = Some(v);
+        self
+    }
+
+    /// Set the total time allowed for this write.
+    ///
+    /// Refer to [`options::WriteOptions::deadline`] for more details.
+    pub fn deadline(mut self, v: Duration) -> Self {
+        self.args.0.deadline = Some(v);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -959,6 +1152,29 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self
     }
 
+    /// Sets the offset to start writing at.
+    ///
+    /// Refer to [`options::WriteOptions::offset`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").offset(8192).await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.args.offset = Some(offset);
+        self
+    }
+
     /// Sets chunk size for buffered writes.
     ///
     /// Refer to [`options::WriteOptions::chunk`] for more details.
@@ -1022,6 +1238,37 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self
     }
 
+    /// Tune the concurrency of this writer adaptively.
+    ///
+    /// Refer to [`options::WriteOptions::adaptive_concurrency`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::AdaptiveConcurrency;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// // Ramp up to 16 parallel operations at most.
+    /// let adaptive = AdaptiveConcurrency::new(16);
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .adaptive_concurrency(adaptive.clone())
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    ///
+    /// // Observe the chosen concurrency.
+    /// let _ = adaptive.current();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn adaptive_concurrency(mut self, adaptive: AdaptiveConcurrency) -> Self {
+        self.args.adaptive_concurrency = Some(adaptive);
+        self
+    }
+
     /// Sets Cache-Control header for this write operation.
     ///
     /// Refer to [`options::WriteOptions::cache_control`] for more details.
@@ -1234,6 +1481,38 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self
     }
 
+    /// Sets the time until which the written object can't be modified or deleted.
+    ///
+    /// Refer to [`options::WriteOptions::immutability_until`] for more details.
+    pub fn immutability_until(mut self, v: Timestamp) -> Self {
+        self.args.immutability_until = Some(v);
+        self
+    }
+
+    /// Sets a legal hold on the written object.
+    ///
+    /// Refer to [`options::WriteOptions::legal_hold`] for more details.
+    pub fn legal_hold(mut self, v: bool) -> Self {
+        self.args.legal_hold = v;
+        self
+    }
+
+    /// Holds an exclusive lock on the object while writing.
+    ///
+    /// Refer to [`options::WriteOptions::lock`] for more details.
+    pub fn lock(mut self, v: bool) -> Self {
+        self.args.lock = v;
+        self
+    }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::ttl`] for more details.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.args.ttl = Some(ttl);
+        self
+    }
+
     /// Sets user metadata for this write request.
     ///
     /// Refer to [`options::WriteOptions::user_metadata`] for more details.
@@ -1264,6 +1543,85 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.args.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Resume a suspended writer from the given checkpoint.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_checkpoint`] before using this feature.
+    ///
+    /// The checkpoint is returned by [`Writer::suspend`], data written to the
+    /// resumed writer must start at [`WriteCheckpoint::size`] of the source.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// # async fn test(op: Operator, data: Vec<u8>) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").chunk(8 * 1024 * 1024).await?;
+    /// w.write(data[..16 * 1024 * 1024].to_vec()).await?;
+    /// let checkpoint = w.suspend().await?;
+    ///
+    /// // Later, maybe in another process.
+    /// let offset = checkpoint.size() as usize;
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .checkpoint(checkpoint)
+    ///     .await?;
+    /// w.write(data[offset..].to_vec()).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checkpoint(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.args.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Compress data while writing.
+    ///
+    /// Refer to [`options::WriteOptions::compression`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    /// use opendal_core::WriteCompression;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file.zst")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .compress(WriteCompression::Zstd { level: 3 })
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compress(mut self, compression: WriteCompression) -> Self {
+        self.args.compression = Some(compression);
+        self
+    }
+
+    /// Set the timeout for each chunk written to the service.
+    ///
+    /// Refer to [`options::WriteOptions::chunk_timeout`] for more details.
+    pub fn chunk_timeout(mut self, v: Duration) -> Self {
+        self.args.chunk_timeout = Some(v);
+        self
+    }
+
+    /// Set the total time allowed for this write.
+    ///
+    /// Refer to [`options::WriteOptions::deadline`] for more details.
+    pub fn deadline(mut self, v: Duration) -> Self {
+        self.args.deadline = Some(v);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -1283,6 +1641,52 @@ impl<F: Future<Output = Result<()>>> FutureDelete<F> {
         self.args.recursive = recursive;
         self
     }
+
+    /// Report the progress of the recursive deletion.
+    ///
+    /// Refer to [`DeleteProgress`] for more details.
+    pub fn progress(mut self, progress: DeleteProgress) -> Self {
+        self.args.progress = Some(progress);
+        self
+    }
+}
+
+/// Future that generated by [`Operator::rename_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureRename<F> = OperatorFuture<(options::RenameOptions, String), (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureRename<F> {
+    /// Require the rename to be atomic.
+    ///
+    /// Refer to [`options::RenameOptions::atomic`] for more details.
+    pub fn atomic(mut self, v: bool) -> Self {
+        self.args.0.atomic = v;
+        self
+    }
+}
+
+/// Future that generated by [`Operator::restore_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureRestore<F> = OperatorFuture<options::RestoreOptions, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureRestore<F> {
+    /// Set the days that the restored copy stays readable.
+    ///
+    /// Refer to [`options::RestoreOptions::days`] for more details.
+    pub fn days(mut self, v: u32) -> Self {
+        self.args.days = Some(v);
+        self
+    }
+
+    /// Set the retrieval tier of this restore.
+    ///
+    /// Refer to [`options::RestoreOptions::tier`] for more details.
+    pub fn tier(mut self, v: &str) -> Self {
+        self.args.tier = Some(v.to_string());
+        self
+    }
 }
 
 /// Future that generated by [`Operator::deleter_with`].
@@ -1307,6 +1711,8 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
 
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
+    ///
+    /// Pass the token returned by `Lister::next_token` to resume a listing.
     pub fn start_after(mut self, v: &str) -> Self {
         self.args.start_after = Some(v.to_string());
         self
@@ -1349,6 +1755,38 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
         self.args.deleted = v;
         self
     }
+
+    /// Set the version of the namespace to list entries from.
+    ///
+    /// Refer to [`options::ListOptions::version`] for more details.
+    pub fn version(mut self, v: &str) -> Self {
+        self.args.version = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose path ends with the given suffix.
+    ///
+    /// Refer to [`options::ListOptions::suffix`] for more details.
+    pub fn suffix(mut self, v: &str) -> Self {
+        self.args.suffix = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose relative path matches the given glob pattern.
+    ///
+    /// Refer to [`options::ListOptions::glob`] for more details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.args.glob = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose relative path matches the given regular expression.
+    ///
+    /// Refer to [`options::ListOptions::regex`] for more details.
+    pub fn regex(mut self, v: &str) -> Self {
+        self.args.regex = Some(v.to_string());
+        self
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -1368,6 +1806,8 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
 
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
+    ///
+    /// Pass the token returned by `Lister::next_token` to resume a listing.
     pub fn start_after(mut self, v: &str) -> Self {
         self.args.start_after = Some(v.to_string());
         self
@@ -1410,6 +1850,46 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
         self.args.deleted = v;
         self
     }
+
+    /// Set the version of the namespace to list entries from.
+    ///
+    /// Refer to [`options::ListOptions::version`] for more details.
+    pub fn version(mut self, v: &str) -> Self {
+        self.args.version = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose path ends with the given suffix.
+    ///
+    /// Refer to [`options::ListOptions::suffix`] for more details.
+    pub fn suffix(mut self, v: &str) -> Self {
+        self.args.suffix = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose relative path matches the given glob pattern.
+    ///
+    /// Refer to [`options::ListOptions::glob`] for more details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.args.glob = Some(v.to_string());
+        self
+    }
+
+    /// Only return entries whose relative path matches the given regular expression.
+    ///
+    /// Refer to [`options::ListOptions::regex`] for more details.
+    pub fn regex(mut self, v: &str) -> Self {
+        self.args.regex = Some(v.to_string());
+        self
+    }
+
+    /// Keep streaming changes under the prefix after existing entries have been listed.
+    ///
+    /// Refer to [`options::ListOptions::watch`] for more details.
+    pub fn watch(mut self, v: bool) -> Self {
+        self.args.watch = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::copy_with`].
@@ -1450,6 +1930,30 @@ impl<F: Future<Output = Result<Metadata>>> FutureCopy<F> {
         self
     }
 
+    /// Copies the directory and all entries under it recursively.
+    ///
+    /// Refer to [`options::CopyOptions::recursive`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal_core::Result;
+    /// # use opendal_core::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .copy_with("source/dir/", "target/dir/")
+    ///     .recursive(true)
+    ///     .concurrent(8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.args.0.recursive = v;
+        self
+    }
+
     /// Sets source version for this copy operation.
     ///
     /// Refer to [`options::CopyOptions::source_version`] for more details.
@@ -1483,6 +1987,62 @@ impl<F: Future<Output = Result<Metadata>>> FutureCopy<F> {
     }
 }
 
+/// Future that generated by [`Operator::copy_from_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureCopyFrom<F> = OperatorFuture<(options::CopyOptions, Operator, String), Metadata, F>;
+
+impl<F: Future<Output = Result<Metadata>>> FutureCopyFrom<F> {
+    /// Sets the condition that copy operation will succeed only if target does not exist.
+    ///
+    /// Refer to [`options::CopyOptions::if_not_exists`] for more details.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.args.0.if_not_exists = v;
+        self
+    }
+
+    /// Sets the condition that copy operation will succeed only if the
+    /// destination object currently has the given ETag.
+    ///
+    /// Refer to [`options::CopyOptions::if_match`] for more details.
+    pub fn if_match(mut self, etag: &str) -> Self {
+        self.args.0.if_match = Some(etag.to_string());
+        self
+    }
+
+    /// Sets source version for this copy operation.
+    ///
+    /// Refer to [`options::CopyOptions::source_version`] for more details.
+    pub fn source_version(mut self, version: impl Into<String>) -> Self {
+        self.args.0.source_version = Some(version.into());
+        self
+    }
+
+    /// Sets concurrent read and write operations for this copy.
+    ///
+    /// Refer to [`options::CopyOptions::concurrent`] for more details.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.args.0.concurrent = v.max(1);
+        self
+    }
+
+    /// Sets chunk size for read and write operations of this copy.
+    ///
+    /// Refer to [`options::CopyOptions::chunk`] for more details.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.args.0.chunk = Some(v);
+        self
+    }
+
+    /// Sets known source content length as a hint for this `copy` request.
+    ///
+    /// Refer to [`options::CopyOptions::source_content_length_hint`] for more details.
+    pub fn source_content_length_hint(mut self, v: u64) -> Self {
+        self.args.0.source_content_length_hint = Some(v);
+        self
+    }
+}
+
 /// Future that generated by [`Operator::copier_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
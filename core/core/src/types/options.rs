@@ -17,8 +17,13 @@
 
 //! Options module provides options definitions for operations.
 
+use crate::raw::Duration;
 use crate::raw::Timestamp;
+use crate::types::AdaptiveConcurrency;
 use crate::types::BytesRange;
+use crate::types::DeleteProgress;
+use crate::types::WriteCheckpoint;
+use crate::types::WriteCompression;
 use std::collections::HashMap;
 
 /// Options for delete operations.
@@ -32,6 +37,27 @@ pub struct DeleteOptions {
     /// - If `true`, all entries under the path (or sharing the prefix for file-like paths)
     ///   will be removed.
     pub recursive: bool,
+    /// Report the progress of a recursive delete.
+    ///
+    /// Refer to [`DeleteProgress`] for more details.
+    pub progress: Option<DeleteProgress>,
+}
+
+/// Options for batch delete operations.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeleteBatchOptions {
+    /// Sets the number of single path delete requests sent at the same time.
+    ///
+    /// Results are always returned in the same order as the input paths.
+    ///
+    /// Default to 16.
+    pub concurrent: usize,
+}
+
+impl Default for DeleteBatchOptions {
+    fn default() -> Self {
+        Self { concurrent: 16 }
+    }
 }
 
 /// Options for list operations.
@@ -39,7 +65,8 @@ pub struct DeleteOptions {
 /// # Groups
 /// - Traversal: `recursive`.
 /// - Pagination: `limit`, `start_after`.
-/// - Versioning: `versions`, `deleted` (effective on version-aware backends).
+/// - Filtering: `suffix`, `glob`, `regex`.
+/// - Versioning: `versions`, `deleted`, `version` (effective on version-aware backends).
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct ListOptions {
@@ -47,6 +74,8 @@ pub struct ListOptions {
     pub limit: Option<usize>,
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
+    ///
+    /// Accepts the token from `Lister::next_token` to resume a listing.
     pub start_after: Option<String>,
     /// Whether to list recursively under the prefix; default `false`.
     pub recursive: bool,
@@ -54,6 +83,63 @@ pub struct ListOptions {
     pub versions: bool,
     /// Include delete markers when supported by version-aware backends; default `false`.
     pub deleted: bool,
+    /// List entries as they exist in the given version of the namespace.
+    ///
+    /// This is meaningful for services that version the whole namespace instead of single
+    /// objects, such as a git revision (branch, tag or commit) of a repository.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::list_with_version`] before using this option.
+    pub version: Option<String>,
+    /// Only return entries whose path ends with the given suffix, such as `.parquet`.
+    ///
+    /// Directories keep their trailing `/` when matched, so a suffix like
+    /// `.parquet` only selects files.
+    ///
+    /// ### Capability
+    ///
+    /// Services with [`Capability::list_with_suffix`] filter on the server side,
+    /// other services fall back to filtering the returned entries.
+    pub suffix: Option<String>,
+    /// Only return entries whose path relative to the listed directory matches
+    /// the given glob pattern, such as `**/*.parquet`.
+    ///
+    /// The pattern supports `*` (any characters except `/`), `**` (any characters
+    /// including `/`), `?`, character classes like `[a-z]` or `[!0-9]`, alternatives
+    /// like `{csv,json}` and `\` to escape special characters. Directories keep
+    /// their trailing `/` when matched, so `*` only selects files while `*/` selects
+    /// directories.
+    ///
+    /// When listing recursively, the leading directories of the pattern are used
+    /// to narrow the listing, so `logs/2024/**` only lists entries under `logs/2024/`.
+    ///
+    /// ### Capability
+    ///
+    /// Services with [`Capability::list_with_glob`] filter on the server side,
+    /// other services fall back to filtering the returned entries.
+    pub glob: Option<String>,
+    /// Only return entries whose path relative to the listed directory matches
+    /// the given regular expression, such as `^\d{4}/.*\.parquet$`.
+    ///
+    /// The expression is searched in the path, use `^` and `$` to match the whole
+    /// path. Directories keep their trailing `/` when matched.
+    ///
+    /// Entries are always filtered on the client side. Requires the `list-regex`
+    /// feature, otherwise an error with kind [`ErrorKind::Unsupported`] will be
+    /// returned.
+    pub regex: Option<String>,
+    /// Keep streaming changes under the prefix after existing entries have been listed.
+    ///
+    /// Entries put after the listing started are returned as they happen, deleted
+    /// entries are returned with [`Metadata::is_deleted`](crate::Metadata::is_deleted)
+    /// set. The lister never ends on its own, so this option should be used with
+    /// [`Operator::lister_with`](crate::Operator::lister_with).
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::list_with_watch`] before using this option.
+    pub watch: bool,
 }
 
 /// Options for read operations.
@@ -68,6 +154,9 @@ pub struct ReadOptions {
     /// - `1024..` means read bytes in range `[1024, n)` of file
     /// - `BytesRange::suffix(1024)` means read the last `min(1024, n)` bytes of file
     ///
+    /// Suffix ranges are sent as `bytes=-N` to services with [`Capability::read_with_suffix`],
+    /// other services fall back to `stat` and a range read.
+    ///
     /// The type implements `From<RangeBounds<u64>>`, so users can use `(1024..).into()` instead.
     pub range: BytesRange,
     /// Set `version` for this operation.
@@ -156,6 +245,26 @@ pub struct ReadOptions {
     ///
     /// This option is only meaningful when used along with presign.
     pub override_content_disposition: Option<String>,
+    /// Verify the content against the checksum advertised by the service.
+    ///
+    /// When enabled, the whole object must be read and OpenDAL will return an
+    /// error with kind [`ErrorKind::Unexpected`] if the content doesn't match
+    /// [`Metadata::checksum`]. If the service doesn't advertise a checksum for
+    /// the object, an error with kind [`ErrorKind::Unsupported`] will be returned.
+    ///
+    /// Requires the `checksum` feature, otherwise an error with kind
+    /// [`ErrorKind::Unsupported`] will be returned.
+    pub verify_checksum: bool,
+    /// Timeout for the service to start responding to each read request,
+    /// such as receiving the first byte of the body.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its first byte timeout.
+    pub first_byte_timeout: Option<Duration>,
+    /// Total time allowed for this read, from creating the reader until the last
+    /// byte is read.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its deadline.
+    pub deadline: Option<Duration>,
 }
 
 /// Options for reader operations.
@@ -248,6 +357,16 @@ pub struct ReaderOptions {
     /// This option helps prevent memory exhaustion when reading large files with high
     /// concurrency settings.
     pub prefetch: usize,
+    /// Timeout for the service to start responding to each read request,
+    /// such as receiving the first byte of the body.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its first byte timeout.
+    pub first_byte_timeout: Option<Duration>,
+    /// Total time allowed for this read, from creating the reader until the last
+    /// byte is read.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its deadline.
+    pub deadline: Option<Duration>,
 }
 
 /// Options for stat operations.
@@ -301,6 +420,30 @@ pub struct StatOptions {
     ///
     /// This option is only meaningful when used along with presign.
     pub override_content_disposition: Option<String>,
+
+    /// Ask the service to return the checksum of the object in [`Metadata::checksum`].
+    ///
+    /// Some services like s3 only return checksums when asked, which could
+    /// require extra permissions (like `kms:Decrypt` for SSE-KMS objects).
+    /// Services that always return checksums ignore this option.
+    pub checksum: bool,
+}
+
+/// Options for batch stat operations.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StatBatchOptions {
+    /// Sets the number of stat requests sent at the same time.
+    ///
+    /// Results are always returned in the same order as the input paths.
+    ///
+    /// Default to 16.
+    pub concurrent: usize,
+}
+
+impl Default for StatBatchOptions {
+    fn default() -> Self {
+        Self { concurrent: 16 }
+    }
 }
 
 /// Options for write operations.
@@ -323,6 +466,26 @@ pub struct WriteOptions {
     /// This operation allows adding data to existing files instead of overwriting them.
     pub append: bool,
 
+    /// Sets the offset to start writing at.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_can_seek`] before using this option.
+    ///
+    /// ### Behavior
+    ///
+    /// - By default, write operations overwrite existing files
+    /// - When offset is set:
+    ///   - Data will overwrite the existing content starting at offset, content
+    ///     before and after the written range is kept
+    ///   - The file will be extended if data is written past the end of it
+    ///   - If file doesn't exist, it will be created
+    /// - Can't be used together with `append`
+    /// - If not supported, will return an error
+    ///
+    /// This operation allows updating fixed-size pages of a file in place.
+    pub offset: Option<u64>,
+
     /// Sets Cache-Control header for this write operation.
     ///
     /// ### Capability
@@ -476,6 +639,62 @@ pub struct WriteOptions {
     /// This operation provides a way to ensure write operations only create new resources
     /// without overwriting existing ones, useful for implementing "create if not exists" logic.
     pub if_not_exists: bool,
+    /// Sets the time until which the written object can't be modified or deleted.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_immutability_until`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object is protected by a time based retention policy
+    ///   until the given time
+    /// - Will return error if not supported
+    ///
+    /// This is used to store write once, read many (WORM) data.
+    pub immutability_until: Option<Timestamp>,
+    /// Sets a legal hold on the written object.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_legal_hold`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object can't be modified or deleted until the legal
+    ///   hold is cleared
+    /// - Will return error if not supported
+    pub legal_hold: bool,
+    /// Holds an exclusive lock on the object while writing.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_lock`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the lock is acquired before writing and released after
+    ///   the write is done, writes from others fail while the lock is held
+    /// - Will return error if the object is locked by others
+    /// - Will return error if not supported
+    pub lock: bool,
+    /// Sets the time to live of the written object.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_ttl`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object expires and is removed by the service after
+    ///   the given duration since the write
+    /// - Services may round the duration up to their own precision or minimum,
+    ///   for example to seconds
+    /// - Will return error if not supported
+    ///
+    /// This is useful for cache-like workloads. Object stores like s3, gcs and
+    /// azblob don't support it, their objects expire via bucket lifecycle rules.
+    pub ttl: Option<Duration>,
 
     /// Sets concurrent write operations for this writer.
     ///
@@ -502,6 +721,17 @@ pub struct WriteOptions {
     /// - Better utilize available bandwidth
     /// - Trade memory for performance
     pub concurrent: usize,
+    /// Tune the concurrency of this writer adaptively.
+    ///
+    /// ## Behavior
+    ///
+    /// - The writer starts with one task in flight and ramps up and down
+    ///   within [`AdaptiveConcurrency::max`] based on per-part latency and
+    ///   errors. `concurrent` will be ignored if this is set.
+    /// - Users can keep a clone of the handle to observe the chosen
+    ///   concurrency while writing.
+    /// - Only takes effect for services that write in concurrent parts.
+    pub adaptive_concurrency: Option<AdaptiveConcurrency>,
     /// Sets chunk size for buffered writes.
     ///
     /// ### Capability
@@ -528,6 +758,69 @@ pub struct WriteOptions {
     /// - Lower operation costs
     /// - Better utilize network bandwidth
     pub chunk: Option<usize>,
+
+    /// Resume a suspended write from the given checkpoint.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_checkpoint`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - The checkpoint is returned by [`Writer::suspend`](crate::Writer::suspend).
+    /// - The writer continues the upload recorded by the checkpoint, so data
+    ///   written must start at [`WriteCheckpoint::size`] of the source.
+    /// - Other options must be the same as the suspended write.
+    pub checkpoint: Option<WriteCheckpoint>,
+
+    /// Compress data while writing.
+    ///
+    /// ### Behavior
+    ///
+    /// - Data is compressed before it's buffered into chunks, so chunked and
+    ///   multipart writes produce one continuous compressed stream.
+    /// - `content_encoding` is set to the encoding of given compression,
+    ///   overriding the one set by user.
+    /// - Can't be used together with `checkpoint`, and writers with compression
+    ///   can't be suspended.
+    /// - Returns `Unsupported` if the feature of the algorithm is not enabled,
+    ///   for example `compress-zstd`.
+    pub compression: Option<WriteCompression>,
+
+    /// Timeout for each chunk written to the service, such as uploading one part
+    /// of a multipart upload.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its write chunk timeout.
+    pub chunk_timeout: Option<Duration>,
+    /// Total time allowed for this write, from creating the writer until it's closed.
+    ///
+    /// This option is enforced by `TimeoutLayer` and overrides its deadline.
+    pub deadline: Option<Duration>,
+}
+
+/// Options for writing from an `AsyncRead` source.
+#[derive(Debug, Clone, Default)]
+pub struct WriteFromOptions {
+    /// The size of the source if it's known.
+    ///
+    /// ### Behavior
+    ///
+    /// - If the size fits in one chunk, or the service can't write in parts, the
+    ///   source is read and uploaded in one request.
+    /// - Otherwise the source is streamed chunk by chunk, which uses multipart upload
+    ///   on services supporting it.
+    /// - The write fails if the source doesn't contain exactly `size` bytes.
+    /// - If not set, the source is streamed until it ends.
+    pub size: Option<u64>,
+
+    /// Options of the write, such as `chunk` and `concurrent`.
+    pub write: WriteOptions,
+}
+
+impl From<WriteOptions> for WriteFromOptions {
+    fn from(write: WriteOptions) -> Self {
+        Self { size: None, write }
+    }
 }
 
 /// Options for copy operations.
@@ -562,6 +855,18 @@ pub struct CopyOptions {
     ///   destination object's ETag matches the given value.
     pub if_match: Option<String>,
 
+    /// Copy all entries under the directory `from` into the directory `to`.
+    ///
+    /// ### Behavior
+    ///
+    /// - Both paths must be directories, and `to` must not be inside `from`.
+    /// - Entries are found by listing `from` recursively and keep their paths
+    ///   relative to `from`.
+    /// - Files are copied server-side if the service supports
+    ///   [`Capability::copy`], otherwise they are streamed through a read and a write.
+    /// - Copy conditions apply to every file, `source_version` is not supported.
+    pub recursive: bool,
+
     /// Copy from a specific source object version.
     ///
     /// ### Capability
@@ -586,6 +891,9 @@ pub struct CopyOptions {
     ///
     /// This is a best-effort execution option. Services that cannot split copy
     /// into concurrent server-side tasks can ignore it.
+    ///
+    /// For recursive copies, it also bounds the number of files copied at the
+    /// same time.
     pub concurrent: usize,
 
     /// Sets chunk size for segmented copy operations.
@@ -601,3 +909,35 @@ pub struct CopyOptions {
     /// step. Services that cannot split copy operations can ignore it.
     pub chunk: Option<usize>,
 }
+
+/// Options for restore operations.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RestoreOptions {
+    /// The number of days the restored copy stays readable.
+    ///
+    /// Services use their own default if not set.
+    pub days: Option<u32>,
+    /// The retrieval tier of the restore, such as `Expedited`, `Standard` or `Bulk`.
+    ///
+    /// Faster tiers usually cost more. Services use their own default if not set.
+    pub tier: Option<String>,
+}
+
+/// Options for rename operations.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct RenameOptions {
+    /// Require the rename to be atomic.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::rename_is_atomic`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, `from` is moved to `to` in a single step: readers see
+    ///   either the old `to` or the renamed file, never a missing or partial one.
+    /// - Services that can't replace an existing `to` atomically return an
+    ///   `AlreadyExists` error instead of deleting it first.
+    /// - If not supported, an `Unsupported` error is returned.
+    pub atomic: bool,
+}
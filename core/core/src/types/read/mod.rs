@@ -27,3 +27,11 @@ pub use futures_async_reader::FuturesAsyncReader;
 
 mod futures_bytes_stream;
 pub use futures_bytes_stream::FuturesBytesStream;
+
+mod sized_stream;
+pub use sized_stream::SizedStream;
+
+#[cfg(feature = "http-body")]
+mod reader_body;
+#[cfg(feature = "http-body")]
+pub use reader_body::ReaderBody;
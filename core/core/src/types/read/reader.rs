@@ -166,6 +166,9 @@ impl Reader {
     /// close ranges. Merged ranges will be split by `chunk`, then executed
     /// with `concurrent` and `prefetch`.
     ///
+    /// If the service supports `read_with_multi_range`, merged ranges will be
+    /// fetched within one request instead.
+    ///
     /// The returning `Buffer` may share the same underlying memory without
     /// any extra copy.
     pub async fn fetch(&self, ranges: Vec<Range<u64>>) -> Result<Vec<Buffer>> {
@@ -198,6 +201,13 @@ impl Reader {
     }
 
     async fn fetch_merged_ranges(&self, ranges: &[Range<u64>]) -> Result<Vec<Buffer>> {
+        if ranges.len() > 1 && self.ctx.capability().read_with_multi_range {
+            match self.fetch_multi_range(ranges).await {
+                Err(err) if err.kind() == ErrorKind::Unsupported => {}
+                res => return res,
+            }
+        }
+
         let inputs = self.plan_fetch_reads(ranges);
         let mut parts = (0..ranges.len())
             .map(|_| Vec::new())
@@ -257,6 +267,39 @@ impl Reader {
             .collect())
     }
 
+    /// Fetch all merged ranges within one request.
+    ///
+    /// Services with `read_with_multi_range` capability could serve all ranges
+    /// at once (e.g. HTTP `multipart/byteranges`), so `chunk` is not applied.
+    async fn fetch_multi_range(&self, ranges: &[Range<u64>]) -> Result<Vec<Buffer>> {
+        let ranges = ranges
+            .iter()
+            .map(|v| BytesRange::new(v.start, Some(v.end - v.start)))
+            .collect::<Vec<_>>();
+        let (rp, buffers) = self.ctx.reader().read_ranges(ranges.clone()).await?;
+        if buffers.len() != ranges.len() {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "reader got unexpected ranges count")
+                    .with_context("expect", ranges.len())
+                    .with_context("actual", buffers.len()),
+            );
+        }
+
+        for (range, buffer) in ranges.iter().zip(buffers.iter()) {
+            let size = range.size().expect("multi range fetch must be bounded");
+            if buffer.len() as u64 != size {
+                return Err(
+                    Error::new(ErrorKind::Unexpected, "reader got unexpected data size")
+                        .with_context("expect", size)
+                        .with_context("actual", buffer.len() as u64),
+                );
+            }
+        }
+
+        self.ctx.observe_read_response(rp);
+        Ok(buffers)
+    }
+
     fn plan_fetch_reads(&self, ranges: &[Range<u64>]) -> Vec<FetchReadInput> {
         let chunk = self.ctx.options().chunk().map(|v| v as u64);
         let mut inputs = Vec::with_capacity(ranges.len());
@@ -535,6 +578,68 @@ impl Reader {
     ) -> Result<FuturesBytesStream> {
         FuturesBytesStream::new(self.ctx, range).await
     }
+
+    /// Convert reader into [`SizedStream`] which implements [`futures::Stream`]
+    /// and knows the size of data in advance.
+    ///
+    /// Unbounded ranges like `..` are resolved against the content length of the
+    /// file, which may involve an extra stat.
+    ///
+    /// # Inputs
+    ///
+    /// - `range`: The range of data to read. range like `..` it will read all data from reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use bytes::Bytes;
+    /// use futures::TryStreamExt;
+    /// use opendal_core::Operator;
+    /// use opendal_core::Result;
+    ///
+    /// async fn test(op: Operator) -> io::Result<()> {
+    ///     let s = op.reader("hello.txt").await?.into_sized_stream(..).await?;
+    ///     let content_length = s.size();
+    ///     let bs: Vec<Bytes> = s.try_collect().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn into_sized_stream(self, range: impl Into<BytesRange>) -> Result<SizedStream> {
+        let range = self.ctx.parse_into_range(range).await?;
+        let size = range.end.saturating_sub(range.start);
+        let stream = FuturesBytesStream::new(self.ctx, range).await?;
+        Ok(SizedStream::new(stream, size))
+    }
+
+    /// Convert reader into [`ReaderBody`] which implements [`http_body::Body`],
+    /// so it can be returned from hyper or axum handlers directly.
+    ///
+    /// The body carries an exact size hint, which is used as the `Content-Length`
+    /// of the response.
+    ///
+    /// # Inputs
+    ///
+    /// - `range`: The range of data to read. range like `..` it will read all data from reader.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use axum::body::Body;
+    /// use opendal_core::Operator;
+    /// use opendal_core::Result;
+    ///
+    /// async fn serve(op: Operator) -> Result<Body> {
+    ///     Ok(Body::new(op.reader("hello.txt").await?.into_http_body(..).await?))
+    /// }
+    /// ```
+    #[cfg(feature = "http-body")]
+    pub async fn into_http_body(self, range: impl Into<BytesRange>) -> Result<ReaderBody> {
+        let stream = self.into_sized_stream(range).await?;
+        Ok(ReaderBody::new(stream))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use http_body::Frame;
+use http_body::SizeHint;
+
+use crate::*;
+
+/// ReaderBody is the adapter of [`http_body::Body`] generated by [`Reader::into_http_body`].
+///
+/// The size hint is exact, so servers like hyper and axum will send it as the
+/// `Content-Length` of the response.
+pub struct ReaderBody {
+    stream: SizedStream,
+}
+
+impl ReaderBody {
+    /// NOTE: don't allow users to create ReaderBody directly.
+    pub(crate) fn new(stream: SizedStream) -> Self {
+        ReaderBody { stream }
+    }
+
+    /// Get the total size of this body.
+    pub fn size(&self) -> u64 {
+        self.stream.size()
+    }
+}
+
+impl http_body::Body for ReaderBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        match ready!(this.stream.poll_next_unpin(cx)) {
+            Some(Ok(bs)) => Poll::Ready(Some(Ok(Frame::data(bs)))),
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.stream.remaining() == 0
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.stream.remaining())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_body::Body;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reader_body() -> Result<()> {
+        let op = Operator::via_iter(services::MEMORY_SCHEME, [])?;
+        op.write("test", "Hello, World!").await?;
+
+        let mut body = op.reader("test").await?.into_http_body(4..8).await?;
+        assert_eq!(body.size_hint().exact(), Some(4));
+
+        let mut bs = Vec::new();
+        while let Some(frame) = std::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await
+        {
+            bs.extend_from_slice(frame.unwrap().data_ref().unwrap());
+        }
+        assert_eq!(bs, b"o, W");
+        assert!(body.is_end_stream());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+use bytes::Bytes;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::*;
+
+/// SizedStream is the adapter of [`Stream`] generated by [`Reader::into_sized_stream`].
+///
+/// It works like [`FuturesBytesStream`], but knows the size of the data before reading,
+/// so it can be used as the `Content-Length` of a response.
+///
+/// SizedStream returns an error if the data ends before `size` bytes are read,
+/// so a truncated object won't be served as a complete one.
+pub struct SizedStream {
+    stream: FuturesBytesStream,
+    size: u64,
+    remaining: u64,
+}
+
+impl SizedStream {
+    /// NOTE: don't allow users to create SizedStream directly.
+    pub(crate) fn new(stream: FuturesBytesStream, size: u64) -> Self {
+        SizedStream {
+            stream,
+            size,
+            remaining: size,
+        }
+    }
+
+    /// Get the total size of this stream.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the size of data that has not been returned yet.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+impl Stream for SizedStream {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        match ready!(this.stream.poll_next_unpin(cx)) {
+            Some(Ok(bs)) => {
+                this.remaining = this.remaining.saturating_sub(bs.len() as u64);
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None if this.remaining > 0 => {
+                let err = io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "stream ended with {} of {} bytes remaining",
+                        this.remaining, this.size
+                    ),
+                );
+                // Only report the error once.
+                this.remaining = 0;
+                Poll::Ready(Some(Err(err)))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sized_stream() -> Result<()> {
+        let op = Operator::via_iter(services::MEMORY_SCHEME, [])?;
+        op.write("test", "Hello, World!").await?;
+
+        let s = op.reader("test").await?.into_sized_stream(..).await?;
+        assert_eq!(s.size(), 13);
+        let bs: Vec<Bytes> = s.try_collect().await.unwrap();
+        assert_eq!(bs.concat(), b"Hello, World!");
+
+        let s = op.reader("test").await?.into_sized_stream(4..8).await?;
+        assert_eq!(s.size(), 4);
+        let bs: Vec<Bytes> = s.try_collect().await.unwrap();
+        assert_eq!(bs.concat(), b"o, W");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sized_stream_too_short() -> Result<()> {
+        let op = Operator::via_iter(services::MEMORY_SCHEME, [])?;
+        op.write("test", "Hello").await?;
+
+        let s = op.reader("test").await?.into_sized_stream(0..10).await?;
+        assert_eq!(s.size(), 10);
+        assert!(s.try_collect::<Vec<Bytes>>().await.is_err());
+
+        Ok(())
+    }
+}
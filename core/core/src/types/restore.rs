@@ -0,0 +1,42 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::Timestamp;
+
+/// Status of restoring an archived file by [`Operator::restore`](crate::Operator::restore).
+///
+/// Returned by [`Metadata::restore_status`](crate::Metadata::restore_status) for files that are archived or
+/// have been restored, `None` there means that no restore has been requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RestoreStatus {
+    /// The restore is still running, the file can't be read yet.
+    InProgress,
+    /// The file has been restored and can be read.
+    Restored {
+        /// The time the restored copy expires, `None` if it's kept until
+        /// the file is archived again.
+        expires: Option<Timestamp>,
+    },
+}
+
+impl RestoreStatus {
+    /// Check whether the file has been restored and can be read.
+    pub fn is_restored(&self) -> bool {
+        matches!(self, RestoreStatus::Restored { .. })
+    }
+}
@@ -0,0 +1,274 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::raw::Duration;
+
+/// Latency above `LATENCY_TOLERANCE` times the best observed latency is
+/// treated as congestion.
+const LATENCY_TOLERANCE: u32 = 2;
+/// Weight of the latest sample in the smoothed latency, in percent.
+const LATENCY_SMOOTHING: u32 = 20;
+
+/// AdaptiveConcurrency tunes the concurrency of a writer while it's running.
+///
+/// Instead of using a static `concurrent`, the writer starts with one task in
+/// flight and adjusts the limit with AIMD (additive increase, multiplicative
+/// decrease) based on the observed latency and errors of each part:
+///
+/// - After a full window of successful parts whose latency stays within
+///   twice of the best observed latency, the limit is increased by one.
+/// - If a part hits a temporary error or its latency exceeds that bound,
+///   the limit is halved. At most one decrease happens per window.
+/// - The limit always stays within `1..=max`.
+///
+/// The handle is cheap to clone and all clones share the same state, so
+/// users can keep a clone to observe the chosen concurrency and the counters
+/// while the writer is running. Sharing one handle across writers makes them
+/// tune a single limit together.
+///
+/// # Examples
+///
+/// ```
+/// # use opendal_core::Result;
+/// # use opendal_core::Operator;
+/// use opendal_core::AdaptiveConcurrency;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let adaptive = AdaptiveConcurrency::new(16);
+/// let mut w = op
+///     .writer_with("path/to/file")
+///     .chunk(8 * 1024 * 1024)
+///     .adaptive_concurrency(adaptive.clone())
+///     .await?;
+/// w.write(vec![0; 64 * 1024 * 1024]).await?;
+/// w.close().await?;
+///
+/// println!(
+///     "concurrency: {}, succeeded: {}, failed: {}",
+///     adaptive.current(),
+///     adaptive.succeeded(),
+///     adaptive.failed()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AdaptiveConcurrency {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    max: usize,
+    current: AtomicUsize,
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The best latency observed so far.
+    min_latency: Option<Duration>,
+    /// The smoothed latency of recent parts.
+    latency: Option<Duration>,
+    /// Parts completed since the last change of the limit.
+    window: usize,
+}
+
+impl AdaptiveConcurrency {
+    /// Create a new adaptive concurrency that ramps up to `max` at most.
+    ///
+    /// `max` less than 1 will be treated as 1.
+    pub fn new(max: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                max: max.max(1),
+                current: AtomicUsize::new(1),
+                succeeded: AtomicU64::new(0),
+                failed: AtomicU64::new(0),
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+
+    /// Get the maximum concurrency.
+    pub fn max(&self) -> usize {
+        self.inner.max
+    }
+
+    /// Get the currently chosen concurrency.
+    pub fn current(&self) -> usize {
+        self.inner.current.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of parts that succeeded.
+    pub fn succeeded(&self) -> u64 {
+        self.inner.succeeded.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of parts that failed with temporary errors.
+    pub fn failed(&self) -> u64 {
+        self.inner.failed.load(Ordering::Relaxed)
+    }
+
+    /// Get the smoothed latency of recent parts.
+    ///
+    /// Returns `None` if no part has succeeded yet.
+    pub fn latency(&self) -> Option<Duration> {
+        self.inner.state.lock().expect("lock must be valid").latency
+    }
+
+    /// Record the result of a completed part.
+    ///
+    /// Permanent errors are not recorded since they abort the write anyway.
+    pub(crate) fn record(&self, latency: Duration, ok: bool) {
+        let mut state = self.inner.state.lock().expect("lock must be valid");
+        let current = self.current();
+        state.window += 1;
+
+        if !ok {
+            self.inner.failed.fetch_add(1, Ordering::Relaxed);
+            self.decrease(&mut state, current, "temporary error");
+            return;
+        }
+        self.inner.succeeded.fetch_add(1, Ordering::Relaxed);
+
+        let min_latency = match state.min_latency {
+            Some(v) if v <= latency => v,
+            _ => latency,
+        };
+        state.min_latency = Some(min_latency);
+        state.latency = Some(match state.latency {
+            Some(v) => (v * (100 - LATENCY_SMOOTHING) + latency * LATENCY_SMOOTHING) / 100,
+            None => latency,
+        });
+
+        if latency > min_latency * LATENCY_TOLERANCE {
+            self.decrease(&mut state, current, "latency spike");
+        } else if state.window >= current && current < self.inner.max {
+            state.window = 0;
+            self.inner.current.store(current + 1, Ordering::Relaxed);
+            log::debug!("adaptive concurrency increased to {}", current + 1);
+        }
+    }
+
+    fn decrease(&self, state: &mut State, current: usize, reason: &str) {
+        // Only decrease once per window, parts that are already in flight
+        // were started with the old limit.
+        if state.window < current {
+            return;
+        }
+        state.window = 0;
+        let next = (current / 2).max(1);
+        if next != current {
+            self.inner.current.store(next, Ordering::Relaxed);
+            log::debug!("adaptive concurrency decreased to {next} for {reason}");
+        }
+    }
+}
+
+impl Debug for AdaptiveConcurrency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveConcurrency")
+            .field("max", &self.max())
+            .field("current", &self.current())
+            .field("succeeded", &self.succeeded())
+            .field("failed", &self.failed())
+            .finish()
+    }
+}
+
+impl PartialEq for AdaptiveConcurrency {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for AdaptiveConcurrency {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_additive_increase() {
+        let ac = AdaptiveConcurrency::new(4);
+        assert_eq!(ac.current(), 1);
+
+        // Each window of `current` successful parts increases the limit by one.
+        ac.record(10 * MS, true);
+        assert_eq!(ac.current(), 2);
+        ac.record(10 * MS, true);
+        assert_eq!(ac.current(), 2);
+        ac.record(10 * MS, true);
+        assert_eq!(ac.current(), 3);
+
+        for _ in 0..100 {
+            ac.record(10 * MS, true);
+        }
+        assert_eq!(ac.current(), 4, "limit must not exceed max");
+        assert_eq!(ac.succeeded(), 103);
+        assert_eq!(ac.latency(), Some(10 * MS));
+    }
+
+    #[test]
+    fn test_multiplicative_decrease() {
+        let ac = AdaptiveConcurrency::new(64);
+        while ac.current() < 16 {
+            ac.record(10 * MS, true);
+        }
+
+        // The first error of a window halves the limit.
+        for _ in 0..15 {
+            ac.record(10 * MS, true);
+        }
+        ac.record(10 * MS, false);
+        assert_eq!(ac.current(), 8);
+        assert_eq!(ac.failed(), 1);
+
+        // Following errors in the same window are ignored.
+        ac.record(10 * MS, false);
+        assert_eq!(ac.current(), 8);
+
+        // Latency spikes are treated as congestion too.
+        for _ in 0..6 {
+            ac.record(10 * MS, true);
+        }
+        ac.record(100 * MS, true);
+        assert_eq!(ac.current(), 4);
+    }
+
+    #[test]
+    fn test_never_below_one() {
+        let ac = AdaptiveConcurrency::new(0);
+        assert_eq!(ac.max(), 1);
+        for _ in 0..10 {
+            ac.record(10 * MS, false);
+        }
+        assert_eq!(ac.current(), 1);
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::oio::MultipartPart;
+
+/// WriteCheckpoint records the progress of a suspended multipart upload.
+///
+/// It's returned by [`Writer::suspend`](crate::Writer::suspend) and can be
+/// passed to [`FutureWriter::checkpoint`](crate::operator_futures::FutureWriter::checkpoint)
+/// to continue the upload later, even from another process. The checkpoint
+/// implements `Serialize` and `Deserialize` so users can persist it anywhere.
+///
+/// Only data that has been uploaded as parts is recorded. Callers must resume
+/// writing from [`WriteCheckpoint::size`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteCheckpoint {
+    upload_id: String,
+    parts: Vec<MultipartPart>,
+    size: u64,
+}
+
+impl WriteCheckpoint {
+    /// Create a new checkpoint.
+    pub fn new(upload_id: String, parts: Vec<MultipartPart>, size: u64) -> Self {
+        Self {
+            upload_id,
+            parts,
+            size,
+        }
+    }
+
+    /// Get the upload id of this checkpoint.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the completed parts of this checkpoint.
+    pub fn parts(&self) -> &[MultipartPart] {
+        &self.parts
+    }
+
+    /// Get the number of bytes that have been uploaded.
+    ///
+    /// Writes after resuming must start at this offset of the source data.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt;
+
+use crate::*;
+
+/// WriteCompression is the algorithm used to compress data while writing.
+///
+/// The data is compressed by OpenDAL before it's buffered into chunks, so
+/// services receive one continuous compressed stream no matter how it's split
+/// into multipart uploads. The `content_encoding` of the write is set to the
+/// matching encoding, services that support
+/// [`Capability::write_with_content_encoding`] will store it along with the
+/// object.
+///
+/// Set it by [`FutureWrite::compress`](crate::operator_futures::FutureWrite::compress)
+/// or [`FutureWriter::compress`](crate::operator_futures::FutureWriter::compress).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WriteCompression {
+    /// zstd with given compression level, `0` means the default level of zstd.
+    ///
+    /// Requires the `compress-zstd` feature.
+    Zstd {
+        /// The compression level, valid range is `1..=22`.
+        level: i32,
+    },
+}
+
+impl WriteCompression {
+    /// The `Content-Encoding` value for data compressed by this algorithm.
+    pub fn content_encoding(&self) -> &'static str {
+        match self {
+            Self::Zstd { .. } => "zstd",
+        }
+    }
+}
+
+impl fmt::Display for WriteCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zstd { level } => write!(f, "zstd(level={level})"),
+        }
+    }
+}
+
+/// Compressor turns written data into the compressed stream.
+pub(crate) enum Compressor {
+    #[cfg(feature = "compress-zstd")]
+    Zstd(Box<zstd::stream::write::Encoder<'static, Vec<u8>>>),
+    /// No compressor can be built without compress features.
+    #[cfg(not(feature = "compress-zstd"))]
+    #[allow(dead_code)]
+    Never(std::convert::Infallible),
+}
+
+impl Compressor {
+    pub(crate) fn new(compression: WriteCompression) -> Result<Self> {
+        match compression {
+            #[cfg(feature = "compress-zstd")]
+            WriteCompression::Zstd { level } => {
+                let encoder = zstd::stream::write::Encoder::new(Vec::new(), level)
+                    .map_err(new_compress_error)?;
+                Ok(Self::Zstd(Box::new(encoder)))
+            }
+            #[cfg(not(feature = "compress-zstd"))]
+            WriteCompression::Zstd { .. } => Err(Error::new(
+                ErrorKind::Unsupported,
+                "zstd compression requires the compress-zstd feature",
+            )),
+        }
+    }
+
+    /// Compress given buffer, returns the compressed data that is ready.
+    ///
+    /// The encoder could keep some data internally, so the returned buffer
+    /// could be empty.
+    pub(crate) fn compress(&mut self, bs: Buffer) -> Result<Buffer> {
+        match self {
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(encoder) => {
+                use std::io::Write;
+
+                for chunk in bs {
+                    encoder.write_all(&chunk).map_err(new_compress_error)?;
+                }
+                Ok(Buffer::from(std::mem::take(encoder.get_mut())))
+            }
+            #[cfg(not(feature = "compress-zstd"))]
+            Self::Never(v) => {
+                let _ = bs;
+                match *v {}
+            }
+        }
+    }
+
+    /// Finish the compressed stream, returns the remaining data.
+    pub(crate) fn finish(&mut self) -> Result<Buffer> {
+        match self {
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(encoder) => {
+                encoder.do_finish().map_err(new_compress_error)?;
+                Ok(Buffer::from(std::mem::take(encoder.get_mut())))
+            }
+            #[cfg(not(feature = "compress-zstd"))]
+            Self::Never(v) => match *v {},
+        }
+    }
+}
+
+#[cfg(feature = "compress-zstd")]
+fn new_compress_error(err: std::io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "compress data failed").set_source(err)
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::io;
+use std::io::IoSlice;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
@@ -72,6 +73,38 @@ impl AsyncWrite for FuturesAsyncWriter {
         }
     }
 
+    /// Fill the internal buffer with as many slices as possible, instead of
+    /// writing only the first non-empty one.
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            let mut n = 0;
+            for buf in bufs {
+                let written = this.buf.put(buf);
+                n += written;
+                if written < buf.len() {
+                    break;
+                }
+            }
+            if n > 0 || bufs.iter().all(|buf| buf.is_empty()) {
+                return Poll::Ready(Ok(n));
+            }
+
+            ready!(this.sink.poll_ready_unpin(cx)).map_err(format_std_io_error)?;
+
+            let bs = this.buf.get().expect("frozen buffer must be valid");
+            this.sink
+                .start_send_unpin(Buffer::from(bs))
+                .map_err(format_std_io_error)?;
+            this.buf.clean();
+        }
+    }
+
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         let this = self.get_mut();
 
@@ -135,4 +168,24 @@ mod tests {
 
         let _: Box<dyn Unpin + MaybeSend + Sync + 'static> = Box::new(v);
     }
+
+    #[tokio::test]
+    async fn test_write_vectored() {
+        use futures::AsyncWriteExt;
+
+        let op = Operator::via_iter(services::MEMORY_SCHEME, []).unwrap();
+        let mut w = op.writer("test").await.unwrap().into_futures_async_write();
+
+        let bufs = [
+            IoSlice::new(b"hello"),
+            IoSlice::new(b""),
+            IoSlice::new(b", world"),
+        ];
+        let n = w.write_vectored(&bufs).await.unwrap();
+        assert_eq!(n, 12);
+        w.close().await.unwrap();
+
+        let bs = op.read("test").await.unwrap();
+        assert_eq!(bs.to_bytes(), "hello, world");
+    }
 }
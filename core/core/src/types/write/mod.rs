@@ -18,6 +18,16 @@
 mod writer;
 pub use writer::Writer;
 
+mod adaptive_concurrency;
+pub use adaptive_concurrency::AdaptiveConcurrency;
+
+mod checkpoint;
+pub use checkpoint::WriteCheckpoint;
+
+mod compression;
+pub(crate) use compression::Compressor;
+pub use compression::WriteCompression;
+
 mod buffer_sink;
 pub use buffer_sink::BufferSink;
 mod futures_async_writer;
@@ -18,6 +18,7 @@
 use std::sync::Arc;
 
 use bytes::Buf;
+use bytes::Bytes;
 
 use crate::raw::*;
 use crate::*;
@@ -146,6 +147,34 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a list of [`Bytes`] into writer without coalescing them.
+    ///
+    /// The chunks are kept as is in a non-contiguous [`Buffer`] and handed
+    /// to the service without being copied into one, even if the writer
+    /// buffers them up to a chunk size. The reqwest transport sends every
+    /// chunk as its own body frame, so hyper can write them with vectored IO.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use opendal_core::Operator;
+    /// use opendal_core::Result;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let header = Bytes::from("header,");
+    ///     let payload = Bytes::from(vec![0; 4096]);
+    ///
+    ///     let mut w = op.writer("hello.txt").await?;
+    ///     w.write_vectored([header, payload]).await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn write_vectored(&mut self, bufs: impl IntoIterator<Item = Bytes>) -> Result<()> {
+        self.write(bufs.into_iter().collect::<Buffer>()).await
+    }
+
     /// Write [`bytes::Buf`] into inner writer.
     ///
     /// This operation will write all data in given buffer into writer.
@@ -202,6 +231,22 @@ impl Writer {
         self.inner.close().await
     }
 
+    /// Suspend the writer and return a checkpoint to resume it later.
+    ///
+    /// The ongoing multipart upload will be kept, resume it by passing the
+    /// checkpoint to [`FutureWriter::checkpoint`](crate::operator_futures::FutureWriter::checkpoint).
+    ///
+    /// ## Notes
+    ///
+    /// - Only uploaded parts are recorded, data buffered by the writer is dropped.
+    ///   Check [`WriteCheckpoint::size`] to find out where to resume.
+    /// - Services without [`Capability::write_with_checkpoint`] will return
+    ///   `Unsupported`.
+    /// - The writer can't be used anymore after suspended.
+    pub async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
+
     /// Convert writer into [`BufferSink`] which implements [`Sink<Buffer>`].
     ///
     /// # Notes
@@ -412,6 +457,25 @@ mod tests {
         assert_eq!(buf.to_bytes(), content);
     }
 
+    #[tokio::test]
+    async fn test_writer_write_vectored() {
+        let op = Operator::new(services::Memory::default()).unwrap();
+        let path = "test_file";
+
+        let content = gen_random_bytes();
+        let (head, tail) = content.split_at(content.len() / 2);
+        let mut writer = op.writer(path).await.unwrap();
+        writer
+            .write_vectored([Bytes::copy_from_slice(head), Bytes::copy_from_slice(tail)])
+            .await
+            .expect("write must succeed");
+        writer.close().await.expect("close must succeed");
+
+        let buf = op.read(path).await.expect("read to end mut succeed");
+
+        assert_eq!(buf.to_bytes(), content);
+    }
+
     #[tokio::test]
     async fn test_writer_write_from() {
         let op = Operator::new(services::Memory::default()).unwrap();
@@ -45,6 +45,11 @@ static DEFAULT_REQWEST_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest
 /// # Notes
 ///
 /// Reqwest must be configured with a TLS feature before sending HTTPS requests.
+///
+/// Client certificates for mTLS, proxies and other connection settings are
+/// configured on the [`reqwest::Client`] passed to [`ReqwestTransport::new`].
+/// Use [`HttpTransporter::with_middleware`](opendal_core::HttpTransporter::with_middleware)
+/// to change requests or responses for every service.
 #[derive(Clone)]
 pub struct ReqwestTransport {
     client: reqwest::Client,
@@ -176,6 +181,8 @@ fn is_temporary_error(err: &reqwest::Error) -> bool {
     err.is_decode()
 }
 
+/// Send every chunk of the buffer as a frame, so that hyper can write them
+/// with vectored IO instead of copying them into one.
 #[cfg(not(target_arch = "wasm32"))]
 struct HttpBufferBody(Buffer);
 
@@ -202,3 +209,34 @@ impl http_body::Body for HttpBufferBody {
         http_body::SizeHint::with_exact(self.0.len() as u64)
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::pin::Pin;
+    use std::task::Context;
+    use std::task::Poll;
+
+    use bytes::Bytes;
+    use http_body::Body;
+
+    use super::*;
+
+    #[test]
+    fn test_http_buffer_body_sends_every_chunk_as_frame() {
+        let chunks = vec![
+            Bytes::from_static(b"header,"),
+            Bytes::from(vec![1; 16]),
+            Bytes::from(vec![2; 16]),
+        ];
+        let mut body = HttpBufferBody(Buffer::from(chunks.clone()));
+        assert_eq!(body.size_hint().exact(), Some(39));
+
+        let mut cx = Context::from_waker(std::task::Waker::noop());
+        let mut frames = vec![];
+        while let Poll::Ready(Some(frame)) = Pin::new(&mut body).poll_frame(&mut cx) {
+            frames.push(frame.unwrap().into_data().unwrap());
+        }
+        assert_eq!(frames, chunks);
+        assert!(body.is_end_stream());
+    }
+}
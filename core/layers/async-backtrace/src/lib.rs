@@ -158,6 +158,33 @@ impl Service for AsyncBacktraceAccessor {
     ) -> Result<RpPresign> {
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[doc(hidden)]
@@ -209,6 +236,11 @@ impl<R: oio::Write> oio::Write for AsyncBacktraceWrapper<R> {
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
+
+    #[async_backtrace::framed]
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
 }
 
 impl<R: oio::List> oio::List for AsyncBacktraceWrapper<R> {
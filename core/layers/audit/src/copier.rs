@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Metadata;
+use opendal_core::Result;
+use opendal_core::raw::Operation;
+use opendal_core::raw::oio;
+
+use crate::log::AuditEvent;
+use crate::log::AuditLog;
+
+/// Copier that records the copy once it succeeds.
+pub struct AuditCopier<C> {
+    c: C,
+    from: String,
+    to: String,
+    log: Arc<AuditLog>,
+}
+
+impl<C> AuditCopier<C> {
+    pub(crate) fn new(c: C, from: String, to: String, log: Arc<AuditLog>) -> Self {
+        Self { c, from, to, log }
+    }
+}
+
+impl<C: oio::Copy> oio::Copy for AuditCopier<C> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        self.c.next().await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let metadata = self.c.close().await?;
+        self.log
+            .append(
+                AuditEvent::new(Operation::Copy, &self.from)
+                    .with_target(&self.to)
+                    .with_metadata(&metadata),
+            )
+            .await?;
+        Ok(metadata)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.c.abort().await
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Result;
+use opendal_core::raw::OpDelete;
+use opendal_core::raw::Operation;
+use opendal_core::raw::oio;
+
+use crate::log::AuditEvent;
+use crate::log::AuditLog;
+
+/// Deleter that records deleted paths once the deletion succeeds.
+pub struct AuditDeleter<D> {
+    deleter: D,
+    deleted: Vec<(String, Option<String>)>,
+    log: Arc<AuditLog>,
+}
+
+impl<D> AuditDeleter<D> {
+    pub(crate) fn new(deleter: D, log: Arc<AuditLog>) -> Self {
+        Self {
+            deleter,
+            deleted: vec![],
+            log,
+        }
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for AuditDeleter<D> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let version = args.version().map(|v| v.to_string());
+        self.deleter.delete(path, args).await?;
+        self.deleted.push((path.to_string(), version));
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        // Deletes are only applied once close succeeds.
+        self.deleter.close().await?;
+        for (path, version) in std::mem::take(&mut self.deleted) {
+            self.log
+                .append(AuditEvent::new(Operation::Delete, &path).with_version(version.as_deref()))
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Audit trail layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod copier;
+mod deleter;
+mod log;
+mod writer;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::log::AuditEvent;
+use crate::log::AuditLog;
+
+pub use copier::AuditCopier;
+pub use deleter::AuditDeleter;
+pub use log::AuditRecord;
+pub use writer::AuditWriter;
+
+/// Audit trail layer that records every mutation into another [`Operator`].
+///
+/// # Operation Behavior
+///
+/// - `write`, `delete`, `copy` and `rename`: an [`AuditRecord`] is stored into the sink
+///   operator once the operation succeeds. Failed operations are not recorded.
+/// - Other operations are passed through to the underlying service.
+///
+/// Records are stored as JSON files named by their sequence, like
+/// `00000000000000000000.json`, at the root of the sink operator. Each record contains
+/// the hash of the previous record, so any modification of the log can be detected by
+/// [`AuditLayer::verify`]. The chain continues from the last record found in the sink,
+/// which makes it possible to keep one log across restarts.
+///
+/// If storing a record fails, the error is returned to the caller even though the
+/// mutation itself has been applied.
+///
+/// # Notes
+///
+/// Only one process should append to a sink at a time. Concurrent writers are rejected
+/// if the sink supports [`Capability::write_with_if_not_exists`], otherwise they may
+/// overwrite each other's records.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_audit::AuditLayer;
+/// # async fn test() -> Result<()> {
+/// let sink = Operator::new(services::Memory::default())?;
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(AuditLayer::new(sink.clone()).with_actor("alice"));
+///
+/// op.write("test", "Hello, World!").await?;
+/// assert_eq!(AuditLayer::verify(&sink).await?, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuditLayer {
+    sink: Operator,
+    actor: Option<String>,
+    log: Arc<AuditLog>,
+}
+
+impl Debug for AuditLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLayer")
+            .field("sink", &self.sink.info().scheme())
+            .field("actor", &self.actor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuditLayer {
+    /// Create a new `AuditLayer` that stores records in the given operator.
+    pub fn new(sink: Operator) -> Self {
+        Self {
+            log: Arc::new(AuditLog::new(sink.clone(), None)),
+            sink,
+            actor: None,
+        }
+    }
+
+    /// Set the actor recorded as the initiator of every mutation.
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        let actor = actor.into();
+        self.log = Arc::new(AuditLog::new(self.sink.clone(), Some(actor.clone())));
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Verify the hash chain of all records stored in the sink.
+    ///
+    /// Returns the number of verified records, or an error pointing to the first
+    /// record that is missing or has been tampered with.
+    pub async fn verify(sink: &Operator) -> Result<u64> {
+        log::verify(sink).await
+    }
+}
+
+impl Layer for AuditLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(AuditService {
+            inner,
+            log: self.log.clone(),
+        })
+    }
+}
+
+/// Service wrapped by [`AuditLayer`].
+pub struct AuditService {
+    inner: Servicer,
+    log: Arc<AuditLog>,
+}
+
+impl Debug for AuditService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for AuditService {
+    type Reader = oio::Reader;
+    type Writer = AuditWriter<oio::Writer>;
+    type Lister = oio::Lister;
+    type Deleter = AuditDeleter<oio::Deleter>;
+    type Copier = AuditCopier<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(AuditWriter::new(w, path.to_string(), self.log.clone()))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let d = self.inner.delete(ctx)?;
+        Ok(AuditDeleter::new(d, self.log.clone()))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let c = self.inner.copy(ctx, from, to, args, opts)?;
+        Ok(AuditCopier::new(
+            c,
+            from.to_string(),
+            to.to_string(),
+            self.log.clone(),
+        ))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        let rp = self.inner.rename(ctx, from, to, args).await?;
+        self.log
+            .append(AuditEvent::new(Operation::Rename, from).with_target(to))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_mutations() {
+        let sink = Operator::new(Memory::default()).unwrap();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(sink.clone()).with_actor("alice"));
+
+        op.write("a", "Hello, World!").await.unwrap();
+        op.write("b", "Hello, World!").await.unwrap();
+        op.delete("a").await.unwrap();
+        op.read("b").await.unwrap();
+        op.list("/").await.unwrap();
+
+        assert_eq!(AuditLayer::verify(&sink).await.unwrap(), 3);
+
+        let bs = sink.read("00000000000000000002.json").await.unwrap();
+        let record: AuditRecord = serde_json::from_slice(&bs.to_vec()).unwrap();
+        assert_eq!(record.operation, "delete");
+        assert_eq!(record.path, "a");
+        assert_eq!(record.actor.as_deref(), Some("alice"));
+    }
+
+    #[tokio::test]
+    async fn test_continue_chain() {
+        let sink = Operator::new(Memory::default()).unwrap();
+        let inner = Operator::new(Memory::default()).unwrap();
+
+        let op = inner.clone().layer(AuditLayer::new(sink.clone()));
+        op.write("a", "1").await.unwrap();
+
+        // A new layer continues the chain left in the sink.
+        let op = inner.layer(AuditLayer::new(sink.clone()));
+        op.write("a", "2").await.unwrap();
+
+        assert_eq!(AuditLayer::verify(&sink).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_detect_tampering() {
+        let sink = Operator::new(Memory::default()).unwrap();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(sink.clone()));
+        op.write("a", "1").await.unwrap();
+        op.write("b", "2").await.unwrap();
+
+        let path = "00000000000000000000.json";
+        let bs = sink.read(path).await.unwrap();
+        let mut record: AuditRecord = serde_json::from_slice(&bs.to_vec()).unwrap();
+        record.path = "c".to_string();
+        sink.write(path, serde_json::to_vec(&record).unwrap())
+            .await
+            .unwrap();
+        let err = AuditLayer::verify(&sink).await.unwrap_err();
+        assert!(err.to_string().contains("doesn't match its hash"));
+
+        // Recomputing the hash breaks the link to the next record.
+        record.hash = record.compute_hash();
+        sink.write(path, serde_json::to_vec(&record).unwrap())
+            .await
+            .unwrap();
+        let err = AuditLayer::verify(&sink).await.unwrap_err();
+        assert!(err.to_string().contains("doesn't chain"));
+
+        sink.delete(path).await.unwrap();
+        let err = AuditLayer::verify(&sink).await.unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+}
@@ -0,0 +1,255 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Write;
+
+use mea::mutex::Mutex;
+use opendal_core::raw::*;
+use opendal_core::*;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// The `prev_hash` of the first record in an audit log.
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// A record of one mutation stored by [`AuditLayer`](crate::AuditLayer).
+///
+/// Every record carries the hash of the record before it, so modifying,
+/// removing or reordering records breaks the chain and is detected by
+/// [`AuditLayer::verify`](crate::AuditLayer::verify).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Position of this record in the log, starting from 0.
+    pub seq: u64,
+    /// Time the mutation finished, in RFC 3339 format.
+    pub timestamp: String,
+    /// Actor configured by [`AuditLayer::with_actor`](crate::AuditLayer::with_actor).
+    pub actor: Option<String>,
+    /// The operation, such as `write`, `delete`, `copy` or `rename`.
+    pub operation: String,
+    /// The path being mutated, or the source path of `copy` and `rename`.
+    pub path: String,
+    /// The target path of `copy` and `rename`.
+    pub target: Option<String>,
+    /// The etag of the written content, if returned by the service.
+    pub etag: Option<String>,
+    /// The version written or deleted, if known.
+    pub version: Option<String>,
+    /// Hash of the previous record.
+    pub prev_hash: String,
+    /// Hash of this record, covering all other fields including `prev_hash`.
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// Compute the hash of this record with `hash` left out.
+    pub fn compute_hash(&self) -> String {
+        let record = AuditRecord {
+            hash: String::new(),
+            ..self.clone()
+        };
+        let bs = serde_json::to_vec(&record).expect("audit record must be serializable");
+
+        let mut s = String::with_capacity(64);
+        for b in Sha256::digest(bs) {
+            let _ = write!(s, "{b:02x}");
+        }
+        s
+    }
+}
+
+/// The details of a mutation to be recorded.
+pub(crate) struct AuditEvent<'a> {
+    pub(crate) operation: Operation,
+    pub(crate) path: &'a str,
+    pub(crate) target: Option<&'a str>,
+    pub(crate) etag: Option<&'a str>,
+    pub(crate) version: Option<&'a str>,
+}
+
+impl<'a> AuditEvent<'a> {
+    pub(crate) fn new(operation: Operation, path: &'a str) -> Self {
+        Self {
+            operation,
+            path,
+            target: None,
+            etag: None,
+            version: None,
+        }
+    }
+
+    pub(crate) fn with_target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub(crate) fn with_metadata(mut self, metadata: &'a Metadata) -> Self {
+        self.etag = metadata.etag();
+        self.version = metadata.version();
+        self
+    }
+
+    pub(crate) fn with_version(mut self, version: Option<&'a str>) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+/// The position to append the next record at.
+struct ChainHead {
+    seq: u64,
+    prev_hash: String,
+}
+
+/// An append-only hash chained log stored in an [`Operator`].
+pub(crate) struct AuditLog {
+    sink: Operator,
+    actor: Option<String>,
+    /// Loaded from the sink on the first append, so that the chain continues
+    /// from records written by previous processes.
+    head: Mutex<Option<ChainHead>>,
+}
+
+impl AuditLog {
+    pub(crate) fn new(sink: Operator, actor: Option<String>) -> Self {
+        Self {
+            sink,
+            actor,
+            head: Mutex::new(None),
+        }
+    }
+
+    pub(crate) async fn append(&self, event: AuditEvent<'_>) -> Result<()> {
+        let mut head = self.head.lock().await;
+        let head = match &mut *head {
+            Some(head) => head,
+            None => head.insert(load_head(&self.sink).await?),
+        };
+
+        let mut record = AuditRecord {
+            seq: head.seq,
+            timestamp: Timestamp::now().to_string(),
+            actor: self.actor.clone(),
+            operation: event.operation.into_static().to_string(),
+            path: event.path.to_string(),
+            target: event.target.map(|v| v.to_string()),
+            etag: event.etag.map(|v| v.to_string()),
+            version: event.version.map(|v| v.to_string()),
+            prev_hash: head.prev_hash.clone(),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash();
+
+        let bs = serde_json::to_vec(&record).map_err(new_json_serialize_error)?;
+        // Refuse to overwrite records if another writer appended to the same sink.
+        let if_not_exists = self.sink.info().capability().write_with_if_not_exists;
+        self.sink
+            .write_with(&record_path(record.seq), bs)
+            .if_not_exists(if_not_exists)
+            .await
+            .map_err(|err| {
+                err.with_operation("AuditLayer::append")
+                    .with_context("operation", &record.operation)
+                    .with_context("path", &record.path)
+            })?;
+
+        head.seq += 1;
+        head.prev_hash = record.hash;
+        Ok(())
+    }
+}
+
+fn record_path(seq: u64) -> String {
+    format!("{seq:020}.json")
+}
+
+/// List all records stored in the sink, sorted by their sequence.
+async fn list_records(sink: &Operator) -> Result<Vec<(u64, String)>> {
+    let mut records = sink
+        .list("/")
+        .await?
+        .into_iter()
+        .filter(|entry| entry.metadata().is_file())
+        .filter_map(|entry| {
+            let seq = entry.name().strip_suffix(".json")?.parse::<u64>().ok()?;
+            Some((seq, entry.path().to_string()))
+        })
+        .collect::<Vec<_>>();
+    records.sort_unstable_by_key(|(seq, _)| *seq);
+    Ok(records)
+}
+
+async fn read_record(sink: &Operator, path: &str) -> Result<AuditRecord> {
+    let bs = sink.read(path).await?;
+    serde_json::from_slice(&bs.to_bytes()).map_err(|err| {
+        new_json_deserialize_error(err)
+            .with_operation("AuditLayer::read_record")
+            .with_context("path", path)
+    })
+}
+
+async fn load_head(sink: &Operator) -> Result<ChainHead> {
+    let Some((seq, path)) = list_records(sink).await?.pop() else {
+        return Ok(ChainHead {
+            seq: 0,
+            prev_hash: GENESIS_HASH.to_string(),
+        });
+    };
+
+    let record = read_record(sink, &path).await?;
+    Ok(ChainHead {
+        seq: seq + 1,
+        prev_hash: record.hash,
+    })
+}
+
+/// Verify all records in the sink, returning the number of records.
+pub(crate) async fn verify(sink: &Operator) -> Result<u64> {
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut count = 0;
+
+    for (seq, path) in list_records(sink).await? {
+        let tampered = |msg: &str| {
+            Error::new(ErrorKind::Unexpected, msg.to_string())
+                .with_operation("AuditLayer::verify")
+                .with_context("path", &path)
+        };
+
+        if seq != count {
+            return Err(tampered("audit record is missing"));
+        }
+        let record = read_record(sink, &path).await?;
+        if record.seq != seq {
+            return Err(tampered("audit record sequence doesn't match its path"));
+        }
+        if record.prev_hash != prev_hash {
+            return Err(tampered(
+                "audit record doesn't chain to the previous record",
+            ));
+        }
+        if record.compute_hash() != record.hash {
+            return Err(tampered("audit record doesn't match its hash"));
+        }
+
+        prev_hash = record.hash;
+        count += 1;
+    }
+
+    Ok(count)
+}
@@ -164,6 +164,42 @@ impl Service for AwaitTreeAccessor {
             .instrument_await(format!("opendal::{}", Operation::Presign))
             .await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner
+            .get_acl(ctx, path, args)
+            .instrument_await(format!("opendal::{}", Operation::GetAcl))
+            .await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner
+            .set_acl(ctx, path, args)
+            .instrument_await(format!("opendal::{}", Operation::SetAcl))
+            .await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner
+            .restore(ctx, path, args)
+            .instrument_await(format!("opendal::{}", Operation::Restore))
+            .await
+    }
 }
 
 #[doc(hidden)]
@@ -228,6 +264,13 @@ impl<R: oio::Write> oio::Write for AwaitTreeWrapper<R> {
             .instrument_await(format!("opendal::{}", Operation::Write.into_static()))
             .await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner
+            .suspend()
+            .instrument_await(format!("opendal::{}", Operation::Write.into_static()))
+            .await
+    }
 }
 
 impl<R: oio::List> oio::List for AwaitTreeWrapper<R> {
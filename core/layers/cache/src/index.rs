@@ -0,0 +1,318 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+use std::time::Instant;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Key of a cached object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ObjectKey {
+    /// Identifies the service this object belongs to, so that services
+    /// sharing one cache never read each other's chunks.
+    pub(crate) namespace: String,
+    pub(crate) path: String,
+    pub(crate) version: Option<String>,
+}
+
+impl ObjectKey {
+    /// Build the path of the given chunk in the cache storage.
+    pub(crate) fn chunk_path(&self, idx: u64) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.namespace.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.path.as_bytes());
+        hasher.update([0]);
+        if let Some(version) = &self.version {
+            hasher.update([1]);
+            hasher.update(version.as_bytes());
+        }
+
+        let mut p = String::with_capacity(64 + 21);
+        for b in hasher.finalize() {
+            let _ = write!(p, "{b:02x}");
+        }
+        let _ = write!(p, "/{idx}");
+        p
+    }
+}
+
+#[derive(Debug)]
+struct ObjectEntry {
+    content_length: u64,
+    generation: u64,
+    fetched_at: Instant,
+    /// Cached chunks of this object, chunk index => (size, access tick).
+    chunks: HashMap<u64, (u64, u64)>,
+}
+
+/// CacheIndex tracks which chunks are stored in the cache and evicts the
+/// least recently used ones once the total size exceeds `max_size`.
+///
+/// Files removed from the index are queued in `pending` so they can be
+/// deleted from the cache storage outside of the lock.
+#[derive(Debug)]
+pub(crate) struct CacheIndex {
+    max_size: u64,
+    size: u64,
+    tick: u64,
+    objects: HashMap<ObjectKey, ObjectEntry>,
+    lru: BTreeMap<u64, (ObjectKey, u64)>,
+    pending: Vec<String>,
+}
+
+impl CacheIndex {
+    pub(crate) fn new(max_size: u64) -> Self {
+        Self {
+            max_size,
+            size: 0,
+            tick: 0,
+            objects: HashMap::new(),
+            lru: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Total size of all cached chunks.
+    #[cfg(test)]
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the content length and generation of a fresh object.
+    ///
+    /// Objects older than `ttl` are invalidated and treated as missing.
+    pub(crate) fn object(&mut self, key: &ObjectKey, ttl: Option<Duration>) -> Option<(u64, u64)> {
+        let entry = self.objects.get(key)?;
+        if ttl.is_some_and(|ttl| entry.fetched_at.elapsed() >= ttl) {
+            self.remove_object(key);
+            return None;
+        }
+        Some((entry.content_length, entry.generation))
+    }
+
+    /// Record the content length of an object, dropping all its chunks
+    /// cached before.
+    ///
+    /// Returns the generation chunks of this object must be inserted with.
+    pub(crate) fn insert_object(&mut self, key: ObjectKey, content_length: u64) -> u64 {
+        self.remove_object(&key);
+        self.tick += 1;
+        let generation = self.tick;
+        self.objects.insert(
+            key,
+            ObjectEntry {
+                content_length,
+                generation,
+                fetched_at: Instant::now(),
+                chunks: HashMap::new(),
+            },
+        );
+        generation
+    }
+
+    /// Mark the chunk as recently used, returns `false` if it's not cached.
+    pub(crate) fn touch_chunk(&mut self, key: &ObjectKey, idx: u64) -> bool {
+        self.tick += 1;
+        let tick = self.tick;
+        let Some((_, chunk_tick)) = self
+            .objects
+            .get_mut(key)
+            .and_then(|entry| entry.chunks.get_mut(&idx))
+        else {
+            return false;
+        };
+
+        let old = std::mem::replace(chunk_tick, tick);
+        if let Some(v) = self.lru.remove(&old) {
+            self.lru.insert(tick, v);
+        }
+        true
+    }
+
+    /// Record a chunk that has been written to the cache storage, evicting
+    /// least recently used chunks if needed.
+    ///
+    /// Returns `false` if the object has been invalidated since `generation`
+    /// was handed out, in which case the written chunk is queued for deletion.
+    pub(crate) fn insert_chunk(
+        &mut self,
+        key: &ObjectKey,
+        generation: u64,
+        idx: u64,
+        size: u64,
+    ) -> bool {
+        self.tick += 1;
+        let tick = self.tick;
+        let Some(entry) = self
+            .objects
+            .get_mut(key)
+            .filter(|entry| entry.generation == generation)
+        else {
+            self.pending.push(key.chunk_path(idx));
+            return false;
+        };
+        if let Some((old_size, old_tick)) = entry.chunks.insert(idx, (size, tick)) {
+            self.size -= old_size;
+            self.lru.remove(&old_tick);
+        }
+        self.size += size;
+        self.lru.insert(tick, (key.clone(), idx));
+
+        while self.size > self.max_size {
+            let Some((_, (key, idx))) = self.lru.pop_first() else {
+                break;
+            };
+            if let Some(entry) = self.objects.get_mut(&key) {
+                if let Some((size, _)) = entry.chunks.remove(&idx) {
+                    self.size -= size;
+                }
+            }
+            self.pending.push(key.chunk_path(idx));
+        }
+        true
+    }
+
+    /// Forget a chunk, for example after it failed to be read back.
+    pub(crate) fn remove_chunk(&mut self, key: &ObjectKey, idx: u64) {
+        let Some(entry) = self.objects.get_mut(key) else {
+            return;
+        };
+        if let Some((size, tick)) = entry.chunks.remove(&idx) {
+            self.size -= size;
+            self.lru.remove(&tick);
+            self.pending.push(key.chunk_path(idx));
+        }
+    }
+
+    /// Forget an object and all of its chunks.
+    pub(crate) fn remove_object(&mut self, key: &ObjectKey) {
+        let Some(entry) = self.objects.remove(key) else {
+            return;
+        };
+        for (idx, (size, tick)) in entry.chunks {
+            self.size -= size;
+            self.lru.remove(&tick);
+            self.pending.push(key.chunk_path(idx));
+        }
+    }
+
+    /// Forget all versions of the object at given path.
+    pub(crate) fn invalidate(&mut self, namespace: &str, path: &str) {
+        let keys: Vec<_> = self
+            .objects
+            .keys()
+            .filter(|k| k.namespace == namespace && k.path == path)
+            .cloned()
+            .collect();
+        for key in keys {
+            self.remove_object(&key);
+        }
+    }
+
+    /// Take the paths of chunks that should be deleted from the cache storage.
+    pub(crate) fn take_pending(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(path: &str) -> ObjectKey {
+        ObjectKey {
+            namespace: "memory".to_string(),
+            path: path.to_string(),
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let mut index = CacheIndex::new(10);
+        let (a, b) = (key("a"), key("b"));
+        let ga = index.insert_object(a.clone(), 8);
+        let gb = index.insert_object(b.clone(), 8);
+
+        assert!(index.insert_chunk(&a, ga, 0, 4));
+        assert!(index.insert_chunk(&b, gb, 0, 4));
+        // Use `a` again so `b` becomes the least recently used chunk.
+        assert!(index.touch_chunk(&a, 0));
+        assert!(index.insert_chunk(&a, ga, 1, 4));
+
+        assert_eq!(index.size(), 8);
+        assert!(index.touch_chunk(&a, 0));
+        assert!(index.touch_chunk(&a, 1));
+        assert!(!index.touch_chunk(&b, 0));
+        assert_eq!(index.take_pending(), vec![b.chunk_path(0)]);
+    }
+
+    #[test]
+    fn test_invalidate_all_versions() {
+        let mut index = CacheIndex::new(100);
+        let a = key("a");
+        let mut a_v1 = key("a");
+        a_v1.version = Some("v1".to_string());
+        let g = index.insert_object(a.clone(), 4);
+        let g_v1 = index.insert_object(a_v1.clone(), 4);
+        index.insert_chunk(&a, g, 0, 4);
+        index.insert_chunk(&a_v1, g_v1, 0, 4);
+
+        index.invalidate("memory", "a");
+
+        assert_eq!(index.size(), 0);
+        assert_eq!(index.object(&a, None), None);
+        assert_eq!(index.object(&a_v1, None), None);
+        assert_eq!(index.take_pending().len(), 2);
+    }
+
+    #[test]
+    fn test_reject_chunk_of_stale_generation() {
+        let mut index = CacheIndex::new(100);
+        let a = key("a");
+        let stale = index.insert_object(a.clone(), 4);
+        index.invalidate("memory", "a");
+        let fresh = index.insert_object(a.clone(), 8);
+
+        assert!(!index.insert_chunk(&a, stale, 0, 4));
+        assert!(!index.touch_chunk(&a, 0));
+        assert_eq!(index.take_pending(), vec![a.chunk_path(0)]);
+        assert!(index.insert_chunk(&a, fresh, 0, 8));
+        assert_eq!(index.size(), 8);
+    }
+
+    #[test]
+    fn test_expire_by_ttl() {
+        let mut index = CacheIndex::new(100);
+        let a = key("a");
+        let g = index.insert_object(a.clone(), 4);
+        index.insert_chunk(&a, g, 0, 4);
+
+        assert_eq!(
+            index.object(&a, Some(Duration::from_secs(60))),
+            Some((4, g))
+        );
+        assert_eq!(index.object(&a, Some(Duration::ZERO)), None);
+        assert_eq!(index.size(), 0);
+    }
+}
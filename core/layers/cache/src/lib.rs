@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read-through cache layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod copier;
+mod deleter;
+mod index;
+mod reader;
+mod writer;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::index::CacheIndex;
+
+pub use copier::CacheCopier;
+pub use deleter::CacheDeleter;
+pub use reader::CacheReader;
+pub use writer::CacheWriter;
+
+/// Read-through cache layer that stores object content in another [`Operator`].
+///
+/// # Operation Behavior
+///
+/// - `read`: objects are split into fixed size chunks. Only the chunks covered by the
+///   requested range are fetched from the service and stored into the cache operator,
+///   later reads of the same chunks are served from the cache.
+/// - `write`, `delete`, `copy` and `rename`: cached content of the affected paths is
+///   invalidated once the operation succeeds.
+/// - Other operations are passed through to the underlying service.
+///
+/// Reads carrying conditions (`if_match`, `if_modified_since`, ...), response overrides
+/// or checksum verification bypass the cache. Objects with different versions are cached
+/// separately.
+///
+/// The cache index lives in memory: chunks are evicted in least recently used order once
+/// their total size exceeds [`CacheLayer::with_max_size`], and objects fetched more than
+/// [`CacheLayer::with_ttl`] ago are fetched again. Content left in the cache operator by
+/// a previous process is not reused.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_cache::CacheLayer;
+/// # fn main() -> Result<()> {
+/// // Any operator works as cache storage, for example one backed by a local directory.
+/// let cache = Operator::new(services::Memory::default())?;
+/// let op = Operator::new(services::Memory::default())?.layer(
+///     CacheLayer::new(cache)
+///         .with_max_size(256 * 1024 * 1024)
+///         .with_ttl(Duration::from_secs(600)),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CacheLayer {
+    cache: Operator,
+    index: Arc<Mutex<CacheIndex>>,
+    max_size: u64,
+    ttl: Option<Duration>,
+    chunk_size: u64,
+}
+
+impl Debug for CacheLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheLayer")
+            .field("cache", &self.cache.info().scheme())
+            .field("max_size", &self.max_size)
+            .field("ttl", &self.ttl)
+            .field("chunk_size", &self.chunk_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CacheLayer {
+    /// Create a new `CacheLayer` that stores cached content in the given operator.
+    ///
+    /// By default the cache holds up to 1 GiB in 4 MiB chunks and never expires.
+    pub fn new(cache: Operator) -> Self {
+        let max_size = 1024 * 1024 * 1024;
+        Self {
+            cache,
+            index: Arc::new(Mutex::new(CacheIndex::new(max_size))),
+            max_size,
+            ttl: None,
+            chunk_size: 4 * 1024 * 1024,
+        }
+    }
+
+    /// Set the maximum total size in bytes of cached content.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self.index = Arc::new(Mutex::new(CacheIndex::new(max_size)));
+        self
+    }
+
+    /// Set how long cached objects stay valid after being fetched.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Set the size of chunks objects are split into.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        self.chunk_size = chunk_size as u64;
+        self
+    }
+}
+
+impl Layer for CacheLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        let info = inner.info();
+        let namespace = format!("{}\n{}\n{}", info.scheme(), info.name(), info.root());
+        Arc::new(CacheService {
+            inner,
+            core: Arc::new(CacheCore {
+                cache: self.cache.clone(),
+                index: self.index.clone(),
+                namespace,
+                ttl: self.ttl,
+                chunk_size: self.chunk_size,
+            }),
+        })
+    }
+}
+
+pub(crate) struct CacheCore {
+    pub(crate) cache: Operator,
+    pub(crate) index: Arc<Mutex<CacheIndex>>,
+    pub(crate) namespace: String,
+    pub(crate) ttl: Option<Duration>,
+    pub(crate) chunk_size: u64,
+}
+
+impl CacheCore {
+    pub(crate) fn index(&self) -> std::sync::MutexGuard<'_, CacheIndex> {
+        self.index.lock().expect("cache index lock poisoned")
+    }
+
+    /// Invalidate cached content of given paths and remove it from cache storage.
+    pub(crate) async fn invalidate(&self, paths: &[&str]) {
+        {
+            let mut index = self.index();
+            for path in paths {
+                index.invalidate(&self.namespace, path);
+            }
+        }
+        self.purge().await;
+    }
+
+    /// Remove evicted chunks from cache storage.
+    ///
+    /// Failures are ignored since the index has forgotten these chunks already.
+    pub(crate) async fn purge(&self) {
+        let paths = self.index().take_pending();
+        if paths.is_empty() {
+            return;
+        }
+        if let Err(err) = self.cache.delete_iter(paths).await {
+            log::warn!("cache layer failed to remove evicted chunks: {err}");
+        }
+    }
+}
+
+/// Service wrapped by [`CacheLayer`].
+pub struct CacheService {
+    inner: Servicer,
+    core: Arc<CacheCore>,
+}
+
+impl Debug for CacheService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for CacheService {
+    type Reader = CacheReader;
+    type Writer = CacheWriter<oio::Writer>;
+    type Lister = oio::Lister;
+    type Deleter = CacheDeleter<oio::Deleter>;
+    type Copier = CacheCopier<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        Ok(CacheReader::new(
+            self.inner.clone(),
+            ctx.clone(),
+            self.core.clone(),
+            path.to_string(),
+            args,
+        ))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(CacheWriter::new(w, path.to_string(), self.core.clone()))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let d = self.inner.delete(ctx)?;
+        Ok(CacheDeleter::new(d, self.core.clone()))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let c = self.inner.copy(ctx, from, to, args, opts)?;
+        Ok(CacheCopier::new(c, to.to_string(), self.core.clone()))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        let rp = self.inner.rename(ctx, from, to, args).await?;
+        self.core.invalidate(&[from, to]).await;
+        Ok(rp)
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+    use opendal_testkit::CountLayer;
+
+    use super::*;
+
+    fn new_operator(layer: impl Fn(Operator) -> CacheLayer) -> (Operator, Operator, CountLayer) {
+        let cache = Operator::new(Memory::default()).unwrap();
+        let count = CountLayer::new();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(count.clone())
+            .layer(layer(cache.clone()).with_chunk_size(4));
+        (op, cache, count)
+    }
+
+    fn content() -> Vec<u8> {
+        (0..10).collect()
+    }
+
+    #[tokio::test]
+    async fn test_read_through() {
+        let (op, cache, count) = new_operator(CacheLayer::new);
+        op.write("a", content()).await.unwrap();
+
+        assert_eq!(op.read("a").await.unwrap().to_vec(), content());
+        assert_eq!(count.readers(), 3);
+        assert_eq!(cache.list_with("/").recursive(true).await.unwrap().len(), 3);
+
+        assert_eq!(op.read("a").await.unwrap().to_vec(), content());
+        assert_eq!(count.readers(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_read_range() {
+        let (op, _, count) = new_operator(CacheLayer::new);
+        op.write("a", content()).await.unwrap();
+
+        let bs = op.read_with("a").range(5..7).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![5, 6]);
+        assert_eq!(count.readers(), 1);
+
+        // Chunk `4..8` is cached, only `0..4` is fetched.
+        let bs = op.read_with("a").range(2..6).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![2, 3, 4, 5]);
+        assert_eq!(count.readers(), 2);
+
+        let bs = op.read_with("a").range(..6).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(count.readers(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_evict_by_max_size() {
+        let (op, cache, count) = new_operator(|cache| CacheLayer::new(cache).with_max_size(8));
+        op.write("a", content()).await.unwrap();
+
+        assert_eq!(op.read("a").await.unwrap().to_vec(), content());
+        assert_eq!(count.readers(), 3);
+        // The first chunk has been evicted to keep the cache within 8 bytes.
+        assert_eq!(cache.list_with("/").recursive(true).await.unwrap().len(), 2);
+
+        let bs = op.read_with("a").range(4..).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![4, 5, 6, 7, 8, 9]);
+        assert_eq!(count.readers(), 3);
+
+        let bs = op.read_with("a").range(..4).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![0, 1, 2, 3]);
+        assert_eq!(count.readers(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_expire_by_ttl() {
+        let (op, _, count) =
+            new_operator(|cache| CacheLayer::new(cache).with_ttl(Duration::from_millis(50)));
+        op.write("a", content()).await.unwrap();
+
+        op.read("a").await.unwrap();
+        op.read("a").await.unwrap();
+        assert_eq!(count.readers(), 3);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        op.read("a").await.unwrap();
+        assert_eq!(count.readers(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_on_write_and_delete() {
+        let (op, cache, _) = new_operator(CacheLayer::new);
+        op.write("a", content()).await.unwrap();
+        op.read("a").await.unwrap();
+
+        op.write("a", vec![1, 2, 3]).await.unwrap();
+        assert!(
+            cache
+                .list_with("/")
+                .recursive(true)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(op.read("a").await.unwrap().to_vec(), vec![1, 2, 3]);
+
+        op.delete("a").await.unwrap();
+        assert!(
+            cache
+                .list_with("/")
+                .recursive(true)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(op.read("a").await.unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_bypass_checksum_read() {
+        let (op, cache, _) = new_operator(CacheLayer::new);
+        op.write("a", content()).await.unwrap();
+
+        // Memory doesn't advertise checksums, the read must still bypass the cache.
+        let err = op.read_with("a").verify_checksum(true).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        assert!(
+            cache
+                .list_with("/")
+                .recursive(true)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::raw::oio::Read as _;
+use opendal_core::raw::oio::ReadStream as _;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::CacheCore;
+use crate::index::ObjectKey;
+
+/// Reader that serves object content from the cache, fetching missing chunks
+/// from the underlying service.
+pub struct CacheReader {
+    srv: Servicer,
+    ctx: OperationContext,
+    core: Arc<CacheCore>,
+    path: String,
+    args: OpRead,
+}
+
+impl CacheReader {
+    pub(crate) fn new(
+        srv: Servicer,
+        ctx: OperationContext,
+        core: Arc<CacheCore>,
+        path: String,
+        args: OpRead,
+    ) -> Self {
+        Self {
+            srv,
+            ctx,
+            core,
+            path,
+            args,
+        }
+    }
+
+    /// Reads whose result depends on more than the object content can't be
+    /// served from the cache.
+    fn bypass(&self) -> bool {
+        let args = &self.args;
+        self.path.ends_with('/')
+            || args.if_match().is_some()
+            || args.if_none_match().is_some()
+            || args.if_modified_since().is_some()
+            || args.if_unmodified_since().is_some()
+            || args.override_content_type().is_some()
+            || args.override_cache_control().is_some()
+            || args.override_content_disposition().is_some()
+            || args.verify_checksum()
+    }
+
+    /// Get the content length and index generation of the object, stat it
+    /// from the underlying service if it's not cached yet.
+    async fn object(&self, key: &ObjectKey) -> Result<(u64, u64)> {
+        let cached = self.core.index().object(key, self.core.ttl);
+        if let Some(object) = cached {
+            return Ok(object);
+        }
+        // The object may have expired, clean up its chunks.
+        self.core.purge().await;
+
+        let mut op = OpStat::new();
+        if let Some(version) = self.args.version() {
+            op = op.with_version(version);
+        }
+        let content_length = self
+            .srv
+            .stat(&self.ctx, &self.path, op)
+            .await?
+            .into_metadata()
+            .content_length();
+        let generation = self.core.index().insert_object(key.clone(), content_length);
+        Ok((content_length, generation))
+    }
+}
+
+impl oio::Read for CacheReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        if self.bypass() {
+            let r = self.srv.read(&self.ctx, &self.path, self.args.clone())?;
+            return r.open(range).await;
+        }
+
+        let key = ObjectKey {
+            namespace: self.core.namespace.clone(),
+            path: self.path.clone(),
+            version: self.args.version().map(|v| v.to_string()),
+        };
+        let (content_length, generation) = self.object(&key).await?;
+        let content_range = range.to_content_range(content_length as usize)?;
+
+        let rp = RpRead::new(Metadata::new(EntryMode::FILE).with_content_length(content_length));
+        let stream = CacheStream {
+            srv: self.srv.clone(),
+            ctx: self.ctx.clone(),
+            core: self.core.clone(),
+            path: self.path.clone(),
+            key,
+            generation,
+            content_length,
+            offset: content_range.start as u64,
+            end: content_range.end as u64,
+        };
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, mut stream) = self.open(range).await?;
+        let buffer = stream.read_all().await?;
+        Ok((rp, buffer))
+    }
+}
+
+/// Stream that yields the requested range chunk by chunk.
+struct CacheStream {
+    srv: Servicer,
+    ctx: OperationContext,
+    core: Arc<CacheCore>,
+    path: String,
+    key: ObjectKey,
+    generation: u64,
+    content_length: u64,
+    offset: u64,
+    end: u64,
+}
+
+impl CacheStream {
+    async fn read_chunk(&self, idx: u64) -> Result<Buffer> {
+        let start = idx * self.core.chunk_size;
+        let size = self.core.chunk_size.min(self.content_length - start);
+        let cache_path = self.key.chunk_path(idx);
+
+        let cached = self.core.index().touch_chunk(&self.key, idx);
+        if cached {
+            match self.core.cache.read(&cache_path).await {
+                Ok(bs) if bs.len() as u64 == size => return Ok(bs),
+                Ok(bs) => log::warn!(
+                    "cache layer got chunk {cache_path} of unexpected size, expect {size}, actual {}",
+                    bs.len()
+                ),
+                Err(err) => log::warn!("cache layer failed to read chunk {cache_path}: {err}"),
+            }
+            self.core.index().remove_chunk(&self.key, idx);
+        }
+
+        let mut args = OpRead::new();
+        if let Some(version) = &self.key.version {
+            args = args.with_version(version);
+        }
+        let r = self.srv.read(&self.ctx, &self.path, args)?;
+        let (_, bs) = r.read(BytesRange::new(start, Some(size))).await?;
+
+        match self.core.cache.write(&cache_path, bs.clone()).await {
+            Ok(_) => {
+                self.core
+                    .index()
+                    .insert_chunk(&self.key, self.generation, idx, size);
+            }
+            Err(err) => log::warn!("cache layer failed to write chunk {cache_path}: {err}"),
+        }
+        self.core.purge().await;
+        Ok(bs)
+    }
+}
+
+impl oio::ReadStream for CacheStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.offset >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        let chunk_size = self.core.chunk_size;
+        let idx = self.offset / chunk_size;
+        let chunk_start = idx * chunk_size;
+        let bs = self.read_chunk(idx).await?;
+
+        let from = (self.offset - chunk_start) as usize;
+        let to = (self.end - chunk_start).min(bs.len() as u64) as usize;
+        if to <= from {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "reader got unexpected data size")
+                    .with_context("path", &self.path)
+                    .with_context("offset", self.offset),
+            );
+        }
+        self.offset = chunk_start + to as u64;
+        Ok(bs.slice(from..to))
+    }
+}
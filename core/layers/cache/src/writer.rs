@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Buffer;
+use opendal_core::Metadata;
+use opendal_core::Result;
+use opendal_core::WriteCheckpoint;
+use opendal_core::raw::oio;
+
+use crate::CacheCore;
+
+/// Writer that invalidates cached content of the path once the write succeeds.
+pub struct CacheWriter<W> {
+    w: W,
+    path: String,
+    core: Arc<CacheCore>,
+}
+
+impl<W> CacheWriter<W> {
+    pub(crate) fn new(w: W, path: String, core: Arc<CacheCore>) -> Self {
+        Self { w, path, core }
+    }
+}
+
+impl<W: oio::Write> oio::Write for CacheWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.w.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let metadata = self.w.close().await?;
+        self.core.invalidate(&[&self.path]).await;
+        Ok(metadata)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.w.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.w.suspend().await
+    }
+}
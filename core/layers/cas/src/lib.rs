@@ -0,0 +1,530 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Content-addressable storage layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod reader;
+mod writer;
+
+use std::sync::Arc;
+
+use opendal_core::raw::oio::Read as _;
+use opendal_core::raw::oio::ReadStream as _;
+use opendal_core::raw::*;
+use opendal_core::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub use reader::CasReader;
+pub use writer::CasWriter;
+
+/// Default directory to store content blobs.
+const DEFAULT_OBJECTS_DIR: &str = "objects/";
+
+/// Store written content by its BLAKE3 hash to deduplicate identical files.
+///
+/// # Layout
+///
+/// Content is stored once under `objects/<hash>`, where `hash` is the hex
+/// encoded BLAKE3 digest of the content. The user path only holds a small JSON
+/// manifest that points to the blob, so writing the same content to many paths
+/// only stores it once.
+///
+/// Reads, stats and lists resolve the manifest transparently, and return the
+/// size of the content instead of the manifest.
+///
+/// # Notes
+///
+/// - Writes are staged under `objects/.tmp/` and moved into place on close if the
+///   service supports `rename` or `copy`. Otherwise, content is buffered in memory.
+/// - `delete` only removes the manifest. Blobs may be shared by other paths, so
+///   they are kept and must be garbage collected by users.
+/// - The objects directory is reserved by this layer and hidden from `list`.
+/// - Append, seek and presign are not supported, as all of them bypass the manifest.
+/// - Reads and lists issue an extra read to load each manifest.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_cas::CasLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(CasLayer::new());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CasLayer {
+    objects_dir: String,
+}
+
+impl Default for CasLayer {
+    fn default() -> Self {
+        Self {
+            objects_dir: DEFAULT_OBJECTS_DIR.to_string(),
+        }
+    }
+}
+
+impl CasLayer {
+    /// Create a new [`CasLayer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the directory to store content blobs, relative to the operator root.
+    ///
+    /// Default to `objects/`.
+    ///
+    /// # Panics
+    ///
+    /// Input dir must not be empty or root.
+    pub fn with_objects_dir(mut self, dir: &str) -> Self {
+        let dir = dir.trim_matches('/');
+        assert!(!dir.is_empty(), "objects dir must not be empty or root");
+        self.objects_dir = format!("{dir}/");
+        self
+    }
+}
+
+impl Layer for CasLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(CasService {
+            core: Arc::new(CasCore {
+                inner,
+                objects_dir: self.objects_dir.clone(),
+            }),
+        })
+    }
+}
+
+/// Manifest stored at the user path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Manifest {
+    /// The hash algorithm, always `blake3` for now.
+    pub algorithm: String,
+    /// The hex encoded hash of the content.
+    pub hash: String,
+    /// The size of the content.
+    pub size: u64,
+}
+
+impl Manifest {
+    pub(crate) fn new(hash: blake3::Hash, size: u64) -> Self {
+        Self {
+            algorithm: "blake3".to_string(),
+            hash: hash.to_hex().to_string(),
+            size,
+        }
+    }
+
+    fn parse(path: &str, bs: &[u8]) -> Result<Self> {
+        let manifest: Manifest = serde_json::from_slice(bs).map_err(|err| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "file is not a manifest written by CasLayer",
+            )
+            .with_context("path", path)
+            .set_source(err)
+        })?;
+
+        let valid = manifest.algorithm == "blake3"
+            && manifest.hash.len() == 64
+            && manifest.hash.bytes().all(|b| b.is_ascii_hexdigit());
+        if !valid {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "manifest has invalid hash")
+                    .with_context("path", path)
+                    .with_context("algorithm", &manifest.algorithm)
+                    .with_context("hash", &manifest.hash),
+            );
+        }
+        Ok(manifest)
+    }
+}
+
+pub(crate) struct CasCore {
+    pub inner: Servicer,
+    pub objects_dir: String,
+}
+
+impl CasCore {
+    /// Path of the blob that holds the content with given hash.
+    pub(crate) fn blob_path(&self, hash: &str) -> String {
+        format!("{}{hash}", self.objects_dir)
+    }
+
+    /// Path to stage a new blob before its hash is known.
+    pub(crate) fn temp_path(&self) -> String {
+        format!("{}.tmp/{}", self.objects_dir, uuid::Uuid::new_v4())
+    }
+
+    fn is_internal(&self, path: &str) -> bool {
+        path.starts_with(&self.objects_dir)
+    }
+
+    /// Load the manifest at given path.
+    ///
+    /// Returns the metadata of the manifest if the service reports it on read.
+    pub(crate) async fn read_manifest(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRead,
+    ) -> Result<(Option<Metadata>, Manifest)> {
+        let r = self.inner.read(ctx, path, args)?;
+        let (rp, mut stream) = r.open(BytesRange::new(0, None)).await?;
+        let bs = stream.read_all().await?;
+        let manifest = Manifest::parse(path, &bs.to_vec())?;
+        Ok((rp.into_metadata(), manifest))
+    }
+}
+
+#[doc(hidden)]
+pub struct CasService {
+    core: Arc<CasCore>,
+}
+
+impl std::fmt::Debug for CasService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CasService")
+            .field("inner", &self.core.inner)
+            .field("objects_dir", &self.core.objects_dir)
+            .finish()
+    }
+}
+
+impl Service for CasService {
+    type Reader = CasReader;
+    type Writer = CasWriter;
+    type Lister = CasLister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.core.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        let mut cap = self.core.inner.capability();
+        cap.read_with_multi_range = false;
+        cap.write_can_append = false;
+        cap.write_can_seek = false;
+        // The content hash of a suspended write can't be carried over.
+        cap.write_with_checkpoint = false;
+        cap.presign = false;
+        cap.presign_read = false;
+        cap.presign_write = false;
+        cap.presign_stat = false;
+        cap.presign_multipart = false;
+        // Content lives in shared blobs, acl of the manifest doesn't protect it.
+        cap.get_acl = false;
+        cap.set_acl = false;
+        // Restoring the manifest won't bring its blobs back from archive.
+        cap.restore = false;
+        cap
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.core.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut op_read = OpRead::new();
+        if let Some(v) = args.version() {
+            op_read = op_read.with_version(v);
+        }
+
+        let meta = self.core.inner.stat(ctx, path, args).await?.into_metadata();
+        if !meta.mode().is_file() {
+            return Ok(RpStat::new(meta));
+        }
+
+        let (_, manifest) = self.core.read_manifest(ctx, path, op_read).await?;
+        Ok(RpStat::new(content_metadata(&meta, &manifest)))
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        Ok(CasReader::new(self.core.clone(), ctx.clone(), path, args))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "append is not supported by CasLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        if args.offset().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "offset is not supported by CasLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        if args.checkpoint().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checkpoint is not supported by CasLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+
+        Ok(CasWriter::new(self.core.clone(), ctx.clone(), path, args))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.core.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.core
+            .inner
+            .list(ctx, path, args)
+            .map(|inner| CasLister {
+                core: self.core.clone(),
+                ctx: ctx.clone(),
+                inner,
+            })
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.core.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.core.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(&self, _: &OperationContext, _: &str, _: OpPresign) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "presign is not supported by CasLayer",
+        )
+        .with_operation(Operation::Presign))
+    }
+}
+
+#[doc(hidden)]
+pub struct CasLister {
+    core: Arc<CasCore>,
+    ctx: OperationContext,
+    inner: oio::Lister,
+}
+
+impl oio::List for CasLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            let Some(entry) = self.inner.next().await? else {
+                return Ok(None);
+            };
+
+            let (path, meta) = entry.into_parts();
+            if self.core.is_internal(&path) {
+                continue;
+            }
+            if !meta.mode().is_file() {
+                return Ok(Some(oio::Entry::with(path, meta)));
+            }
+
+            match self
+                .core
+                .read_manifest(&self.ctx, &path, OpRead::new())
+                .await
+            {
+                Ok((_, manifest)) => {
+                    let meta = content_metadata(&meta, &manifest);
+                    return Ok(Some(oio::Entry::with(path, meta)));
+                }
+                // The file has been removed since listed.
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// Build the metadata of the content from the metadata of its manifest.
+///
+/// Fields that describe the manifest bytes like `content_md5` are dropped.
+pub(crate) fn content_metadata(meta: &Metadata, manifest: &Manifest) -> Metadata {
+    let mut content = Metadata::new(EntryMode::FILE).with_content_length(manifest.size);
+    if let Some(v) = meta.last_modified() {
+        content.set_last_modified(v);
+    }
+    if let Some(v) = meta.etag() {
+        content.set_etag(v);
+    }
+    if let Some(v) = meta.version() {
+        content.set_version(v);
+    }
+    if let Some(v) = meta.is_current() {
+        content.set_is_current(v);
+    }
+    if let Some(v) = meta.content_type() {
+        content.set_content_type(v);
+    }
+    if let Some(v) = meta.content_encoding() {
+        content.set_content_encoding(v);
+    }
+    if let Some(v) = meta.content_disposition() {
+        content.set_content_disposition(v);
+    }
+    if let Some(v) = meta.cache_control() {
+        content.set_cache_control(v);
+    }
+    if let Some(v) = meta.user_metadata() {
+        content = content.with_user_metadata(v.clone());
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_operator() -> Result<(Operator, Operator)> {
+        let memory = Operator::new(services::Memory::default())?;
+        let op = memory.clone().layer(CasLayer::new());
+        Ok((memory, op))
+    }
+
+    async fn assert_roundtrip(op: &Operator) -> Result<()> {
+        for size in [0, 1, 100] {
+            let path = format!("dir/file-{size}");
+            let data = (0..size).map(|i| i as u8).collect::<Vec<_>>();
+            op.write(&path, data.clone()).await?;
+
+            let meta = op.stat(&path).await?;
+            assert_eq!(meta.content_length(), size as u64);
+            assert_eq!(op.read(&path).await?.to_vec(), data);
+        }
+
+        let buf = op.read_with("dir/file-100").range(10..20).await?;
+        assert_eq!(buf.to_vec(), (10..20).collect::<Vec<u8>>());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_roundtrip() -> Result<()> {
+        let (_, op) = new_operator()?;
+        assert_roundtrip(&op).await
+    }
+
+    #[tokio::test]
+    async fn test_roundtrip_with_staged_blob() -> Result<()> {
+        let mut root = std::env::temp_dir();
+        root.push(format!("opendal-cas-{}", uuid::Uuid::new_v4()));
+        let fs = Operator::new(opendal_service_fs::Fs::default().root(&root.to_string_lossy()))?;
+        let op = fs.clone().layer(CasLayer::new());
+
+        let res = assert_roundtrip(&op).await;
+        // Staged blobs must be moved away.
+        let staged = fs.list("objects/.tmp/").await;
+        let _ = std::fs::remove_dir_all(&root);
+
+        res?;
+        let staged = staged?;
+        assert!(staged.iter().all(|e| e.metadata().is_dir()), "{staged:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dedup() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        op.write("a", "hello, world").await?;
+        op.write("b", "hello, world").await?;
+        op.write("c", "something else").await?;
+
+        let hash = blake3::hash(b"hello, world").to_hex().to_string();
+        let raw = memory.read("a").await?.to_vec();
+        assert_eq!(raw, memory.read("b").await?.to_vec());
+        assert_eq!(Manifest::parse("a", &raw)?.hash, hash);
+
+        let blobs = memory.list_with("objects/").recursive(true).await?;
+        let mut blobs = blobs
+            .into_iter()
+            .filter(|e| e.metadata().is_file())
+            .map(|e| e.path().to_string())
+            .collect::<Vec<_>>();
+        blobs.sort();
+        assert_eq!(blobs.len(), 2);
+        assert!(blobs.contains(&format!("objects/{hash}")));
+
+        // Deleting a path keeps the blob shared with others.
+        op.delete("a").await?;
+        assert_eq!(op.read("b").await?.to_vec(), b"hello, world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list() -> Result<()> {
+        let (_, op) = new_operator()?;
+        op.write("dir/a", "hello").await?;
+        op.write("b", "hello, world").await?;
+
+        let mut entries = op
+            .list_with("")
+            .recursive(true)
+            .await?
+            .into_iter()
+            .filter(|e| e.metadata().is_file())
+            .map(|e| (e.path().to_string(), e.metadata().content_length()))
+            .collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(
+            entries,
+            vec![("b".to_string(), 12), ("dir/a".to_string(), 5)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_invalid_manifest() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        memory.write("raw", "not a manifest").await?;
+
+        let err = op.read("raw").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+}
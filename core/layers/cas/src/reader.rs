@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::CasCore;
+use crate::content_metadata;
+
+/// The blob resolved from a manifest.
+struct Blob {
+    /// Content metadata as seen by users.
+    metadata: Metadata,
+    reader: oio::Reader,
+}
+
+/// Reader that resolves the manifest and reads from its blob.
+pub struct CasReader {
+    core: Arc<CasCore>,
+    ctx: OperationContext,
+    path: String,
+    args: OpRead,
+
+    blob: OnceLock<Arc<Blob>>,
+}
+
+impl CasReader {
+    pub(crate) fn new(core: Arc<CasCore>, ctx: OperationContext, path: &str, args: OpRead) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+            args,
+            blob: OnceLock::new(),
+        }
+    }
+
+    async fn blob(&self) -> Result<Arc<Blob>> {
+        if let Some(blob) = self.blob.get() {
+            return Ok(blob.clone());
+        }
+
+        let (meta, manifest) = self
+            .core
+            .read_manifest(&self.ctx, &self.path, self.args.clone())
+            .await?;
+        let meta = meta.unwrap_or_else(|| Metadata::new(EntryMode::FILE));
+        let reader = self.core.inner.read(
+            &self.ctx,
+            &self.core.blob_path(&manifest.hash),
+            OpRead::new(),
+        )?;
+
+        let blob = Arc::new(Blob {
+            metadata: content_metadata(&meta, &manifest),
+            reader,
+        });
+        Ok(self.blob.get_or_init(|| blob).clone())
+    }
+}
+
+impl oio::Read for CasReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let blob = self.blob().await?;
+        let (_, stream) = blob.reader.open(range).await?;
+        Ok((RpRead::new(blob.metadata.clone()), stream))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let blob = self.blob().await?;
+        let (_, buffer) = blob.reader.read(range).await?;
+        Ok((RpRead::new(blob.metadata.clone()), buffer))
+    }
+}
@@ -0,0 +1,205 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::raw::oio::Copy as _;
+use opendal_core::raw::oio::Delete as _;
+use opendal_core::raw::oio::Write as _;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::CasCore;
+use crate::Manifest;
+use crate::content_metadata;
+
+/// Where written content is kept until its hash is known.
+enum Staging {
+    /// Content is written to a temporary blob that's moved into place on close.
+    Temp {
+        path: String,
+        writer: Option<oio::Writer>,
+    },
+    /// Content is buffered in memory for services that can't move blobs.
+    Memory(oio::QueueBuf),
+}
+
+/// Writer that hashes content into a blob and writes its manifest on close.
+pub struct CasWriter {
+    core: Arc<CasCore>,
+    ctx: OperationContext,
+    path: String,
+    args: OpWrite,
+
+    hasher: blake3::Hasher,
+    size: u64,
+    staging: Staging,
+}
+
+impl CasWriter {
+    pub(crate) fn new(
+        core: Arc<CasCore>,
+        ctx: OperationContext,
+        path: &str,
+        args: OpWrite,
+    ) -> Self {
+        let cap = core.inner.capability();
+        let staging = if cap.rename || cap.copy {
+            Staging::Temp {
+                path: core.temp_path(),
+                writer: None,
+            }
+        } else {
+            Staging::Memory(oio::QueueBuf::new())
+        };
+
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+            args,
+            hasher: blake3::Hasher::new(),
+            size: 0,
+            staging,
+        }
+    }
+
+    async fn blob_exists(&self, path: &str) -> Result<bool> {
+        match self.core.inner.stat(&self.ctx, path, OpStat::new()).await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn write_blob(&self, path: &str, bs: Buffer) -> Result<()> {
+        let mut w = self.core.inner.write(&self.ctx, path, OpWrite::new())?;
+        w.write(bs).await?;
+        w.close().await?;
+        Ok(())
+    }
+
+    /// Move the staged blob to its final path.
+    async fn move_blob(&self, from: &str, to: &str) -> Result<()> {
+        if self.core.inner.capability().rename {
+            self.core
+                .inner
+                .rename(&self.ctx, from, to, OpRename::new())
+                .await?;
+            return Ok(());
+        }
+
+        let mut copier =
+            self.core
+                .inner
+                .copy(&self.ctx, from, to, OpCopy::new(), OpCopier::new())?;
+        if let Err(err) = copier.close().await {
+            let _ = copier.abort().await;
+            return Err(err);
+        }
+        self.delete_blob(from).await
+    }
+
+    async fn delete_blob(&self, path: &str) -> Result<()> {
+        let mut d = self.core.inner.delete(&self.ctx)?;
+        d.delete(path, OpDelete::new()).await?;
+        d.close().await
+    }
+}
+
+impl oio::Write for CasWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        for chunk in bs.clone() {
+            self.hasher.update(&chunk);
+        }
+        self.size += bs.len() as u64;
+
+        match &mut self.staging {
+            Staging::Temp { path, writer } => {
+                let w = match writer {
+                    Some(w) => w,
+                    None => {
+                        writer.insert(self.core.inner.write(&self.ctx, path, OpWrite::new())?)
+                    }
+                };
+                w.write(bs).await
+            }
+            Staging::Memory(buf) => {
+                buf.push(bs);
+                Ok(())
+            }
+        }
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let manifest = Manifest::new(self.hasher.finalize(), self.size);
+        let blob_path = self.core.blob_path(&manifest.hash);
+
+        match &mut self.staging {
+            Staging::Temp { path, writer } => {
+                let path = path.clone();
+                let staged = match writer.take() {
+                    Some(mut w) => {
+                        w.close().await?;
+                        true
+                    }
+                    None => false,
+                };
+
+                if self.blob_exists(&blob_path).await? {
+                    if staged {
+                        self.delete_blob(&path).await?;
+                    }
+                } else if staged {
+                    self.move_blob(&path, &blob_path).await?;
+                } else {
+                    self.write_blob(&blob_path, Buffer::new()).await?;
+                }
+            }
+            Staging::Memory(buf) => {
+                let bs = buf.take().collect();
+                if !self.blob_exists(&blob_path).await? {
+                    self.write_blob(&blob_path, bs).await?;
+                }
+            }
+        }
+
+        let content = serde_json::to_vec(&manifest).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "failed to serialize manifest").set_source(err)
+        })?;
+        let mut w = self
+            .core
+            .inner
+            .write(&self.ctx, &self.path, self.args.clone())?;
+        w.write(Buffer::from(content)).await?;
+        let meta = w.close().await?;
+        Ok(content_metadata(&meta, &manifest))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        match &mut self.staging {
+            Staging::Temp { writer, .. } => match writer.take() {
+                Some(mut w) => w.abort().await,
+                None => Ok(()),
+            },
+            Staging::Memory(buf) => {
+                buf.clear();
+                Ok(())
+            }
+        }
+    }
+}
@@ -20,8 +20,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use opendal_core::raw::*;
 use opendal_core::*;
@@ -40,46 +42,107 @@ use rand::rngs::StdRng;
 /// For example: If we specify an error rate of 0.5, there is a 50% chance
 /// of an EOF error for every read operation.
 ///
-/// # Note
+/// # Faults
 ///
-/// For now, ChaosLayer only injects read operations. More operations may
-/// be added in the future.
+/// - [`ChaosLayer::new`] sets the error ratio of read operations.
+/// - [`ChaosLayer::with_error_ratio`] sets the error ratio of any operation.
+///   Readers, writers, listers and deleters fail at every call of them.
+/// - [`ChaosLayer::with_latency`] delays every call of an operation by a
+///   random duration up to the given one, use it on [`Operation::Write`] to
+///   simulate slow writes.
+/// - [`ChaosLayer::with_truncate_ratio`] ends read streams early, returning
+///   less data than requested.
+///
+/// All injected errors are temporary, so they can be retried.
+///
+/// # Reproducible
+///
+/// With [`ChaosLayer::with_seed`], the same sequence of calls always gets the
+/// same faults. Calls issued concurrently may draw faults in different orders.
 ///
 /// # Examples
 ///
 /// ```no_run
+/// # use std::time::Duration;
+/// # use opendal_core::raw::Operation;
 /// # use opendal_core::services;
 /// # use opendal_core::Operator;
 /// # use opendal_core::Result;
 /// # use opendal_layer_chaos::ChaosLayer;
 /// #
 /// # fn main() -> Result<()> {
-/// let _ = Operator::new(services::Memory::default())?
-///     .layer(ChaosLayer::new(0.1));
+/// let _ = Operator::new(services::Memory::default())?.layer(
+///     ChaosLayer::new(0.1)
+///         .with_seed(42)
+///         .with_error_ratio(Operation::Write, 0.05)
+///         .with_latency(Operation::Write, Duration::from_millis(100))
+///         .with_truncate_ratio(0.01),
+/// );
 /// # Ok(())
 /// # }
 /// ```
 #[derive(Clone, Debug)]
 pub struct ChaosLayer {
     rng: Arc<Mutex<StdRng>>,
-    error_ratio: f64,
+    error_ratios: HashMap<Operation, f64>,
+    latencies: HashMap<Operation, Duration>,
+    truncate_ratio: f64,
 }
 
 impl ChaosLayer {
-    /// Create a new [`ChaosLayer`] with specified error ratio.
+    /// Create a new [`ChaosLayer`] with specified error ratio of read operations.
     ///
     /// # Panics
     ///
     /// Input error_ratio must in [0.0..=1.0]
     pub fn new(error_ratio: f64) -> Self {
+        Self {
+            rng: Arc::new(Mutex::new(StdRng::from_rng(&mut rand::rng()))),
+            error_ratios: HashMap::new(),
+            latencies: HashMap::new(),
+            truncate_ratio: 0.0,
+        }
+        .with_error_ratio(Operation::Read, error_ratio)
+    }
+
+    /// Seed the random number generator to make faults reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Arc::new(Mutex::new(StdRng::seed_from_u64(seed)));
+        self
+    }
+
+    /// Set the error ratio of given operation.
+    ///
+    /// # Panics
+    ///
+    /// Input error_ratio must in [0.0..=1.0]
+    pub fn with_error_ratio(mut self, op: Operation, error_ratio: f64) -> Self {
         assert!(
             (0.0..=1.0).contains(&error_ratio),
             "error_ratio must between 0.0 and 1.0"
         );
-        Self {
-            rng: Arc::new(Mutex::new(StdRng::from_rng(&mut rand::rng()))),
-            error_ratio,
-        }
+        self.error_ratios.insert(op, error_ratio);
+        self
+    }
+
+    /// Delay every call of given operation by a random duration in `[0, latency]`.
+    pub fn with_latency(mut self, op: Operation, latency: Duration) -> Self {
+        self.latencies.insert(op, latency);
+        self
+    }
+
+    /// Set the ratio of read calls that return truncated data and end the stream.
+    ///
+    /// # Panics
+    ///
+    /// Input truncate_ratio must in [0.0..=1.0]
+    pub fn with_truncate_ratio(mut self, truncate_ratio: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&truncate_ratio),
+            "truncate_ratio must between 0.0 and 1.0"
+        );
+        self.truncate_ratio = truncate_ratio;
+        self
     }
 }
 
@@ -93,26 +156,88 @@ impl ChaosLayer {
     fn layer(&self, inner: Servicer) -> ChaosService {
         ChaosService {
             inner,
-            rng: self.rng.clone(),
-            error_ratio: self.error_ratio,
+            chaos: Arc::new(Chaos {
+                rng: self.rng.clone(),
+                error_ratios: self.error_ratios.clone(),
+                latencies: self.latencies.clone(),
+                truncate_ratio: self.truncate_ratio,
+            }),
         }
     }
 }
 
+#[derive(Debug)]
+struct Chaos {
+    rng: Arc<Mutex<StdRng>>,
+    error_ratios: HashMap<Operation, f64>,
+    latencies: HashMap<Operation, Duration>,
+    truncate_ratio: f64,
+}
+
+impl Chaos {
+    /// If I feel lucky, we can return the correct response. Otherwise,
+    /// we need to generate an error.
+    fn i_feel_lucky(&self, op: Operation) -> bool {
+        match self.error_ratios.get(&op) {
+            Some(ratio) if *ratio > 0.0 => !self.rng.lock().unwrap().random_bool(*ratio),
+            _ => true,
+        }
+    }
+
+    /// Sleep for the latency of given operation, then fail it if unlucky.
+    async fn inject(&self, op: Operation) -> Result<()> {
+        if let Some(latency) = self.latencies.get(&op).filter(|v| !v.is_zero()) {
+            let delay = self
+                .rng
+                .lock()
+                .unwrap()
+                .random_range(Duration::ZERO..=*latency);
+            tokio::time::sleep(delay).await;
+        }
+
+        if self.i_feel_lucky(op) {
+            Ok(())
+        } else {
+            Err(Self::unexpected_eof(op))
+        }
+    }
+
+    /// Truncate the buffer if unlucky, returns whether it's truncated.
+    fn truncate(&self, buf: &mut Buffer) -> bool {
+        if self.truncate_ratio <= 0.0 || buf.is_empty() {
+            return false;
+        }
+
+        let mut rng = self.rng.lock().unwrap();
+        if !rng.random_bool(self.truncate_ratio) {
+            return false;
+        }
+        let size = rng.random_range(0..buf.len());
+        buf.truncate(size);
+        true
+    }
+
+    fn unexpected_eof(op: Operation) -> Error {
+        Error::new(ErrorKind::Unexpected, "I am your chaos!")
+            .with_operation("chaos")
+            .with_context("operation", op)
+            .set_temporary()
+    }
+}
+
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct ChaosService {
     inner: Servicer,
-    rng: Arc<Mutex<StdRng>>,
-    error_ratio: f64,
+    chaos: Arc<Chaos>,
 }
 
 impl Service for ChaosService {
     type Reader = ChaosReader<oio::Reader>;
-    type Writer = oio::Writer;
-    type Lister = oio::Lister;
-    type Deleter = oio::Deleter;
-    type Copier = oio::Copier;
+    type Writer = ChaosWrapper<oio::Writer>;
+    type Lister = ChaosWrapper<oio::Lister>;
+    type Deleter = ChaosWrapper<oio::Deleter>;
+    type Copier = ChaosWrapper<oio::Copier>;
 
     fn info(&self) -> ServiceInfo {
         self.inner.info()
@@ -128,21 +253,25 @@ impl Service for ChaosService {
         path: &str,
         args: OpCreateDir,
     ) -> Result<RpCreateDir> {
+        self.chaos.inject(Operation::CreateDir).await?;
         self.inner.create_dir(ctx, path, args).await
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.chaos.inject(Operation::Stat).await?;
         self.inner.stat(ctx, path, args).await
     }
 
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
         self.inner
             .read(ctx, path, args)
-            .map(|r| ChaosReader::new(r, Arc::clone(&self.rng), self.error_ratio))
+            .map(|r| ChaosReader::new(r, self.chaos.clone()))
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
-        self.inner.write(ctx, path, args)
+        self.inner
+            .write(ctx, path, args)
+            .map(|w| ChaosWrapper::new(w, self.chaos.clone(), Operation::Write))
     }
 
     fn copy(
@@ -153,15 +282,21 @@ impl Service for ChaosService {
         args: OpCopy,
         opts: OpCopier,
     ) -> Result<Self::Copier> {
-        self.inner.copy(ctx, from, to, args, opts)
+        self.inner
+            .copy(ctx, from, to, args, opts)
+            .map(|c| ChaosWrapper::new(c, self.chaos.clone(), Operation::Copy))
     }
 
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
-        self.inner.list(ctx, path, args)
+        self.inner
+            .list(ctx, path, args)
+            .map(|l| ChaosWrapper::new(l, self.chaos.clone(), Operation::List))
     }
 
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
-        self.inner.delete(ctx)
+        self.inner
+            .delete(ctx)
+            .map(|d| ChaosWrapper::new(d, self.chaos.clone(), Operation::Delete))
     }
 
     async fn rename(
@@ -171,6 +306,7 @@ impl Service for ChaosService {
         to: &str,
         args: OpRename,
     ) -> Result<RpRename> {
+        self.chaos.inject(Operation::Rename).await?;
         self.inner.rename(ctx, from, to, args).await
     }
 
@@ -180,70 +316,221 @@ impl Service for ChaosService {
         path: &str,
         args: OpPresign,
     ) -> Result<RpPresign> {
+        self.chaos.inject(Operation::Presign).await?;
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.chaos.inject(Operation::GetAcl).await?;
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.chaos.inject(Operation::SetAcl).await?;
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.chaos.inject(Operation::Restore).await?;
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[doc(hidden)]
 pub struct ChaosReader<R> {
     inner: R,
-    rng: Arc<Mutex<StdRng>>,
-
-    error_ratio: f64,
+    chaos: Arc<Chaos>,
+    /// Set after a truncated read, the stream ends early.
+    truncated: bool,
 }
 
 impl<R> ChaosReader<R> {
-    fn new(inner: R, rng: Arc<Mutex<StdRng>>, error_ratio: f64) -> Self {
+    fn new(inner: R, chaos: Arc<Chaos>) -> Self {
         Self {
             inner,
-            rng,
-            error_ratio,
+            chaos,
+            truncated: false,
         }
     }
-
-    /// If I feel lucky, we can return the correct response. Otherwise,
-    /// we need to generate an error.
-    fn i_feel_lucky(&self) -> bool {
-        let point: u32 = self.rng.lock().unwrap().random_range(0..100);
-        point >= (self.error_ratio * 100.0) as u32
-    }
-
-    fn unexpected_eof() -> Error {
-        Error::new(ErrorKind::Unexpected, "I am your chaos!")
-            .with_operation("chaos")
-            .set_temporary()
-    }
 }
 
 impl<R: oio::ReadStream> oio::ReadStream for ChaosReader<R> {
     async fn read(&mut self) -> Result<Buffer> {
-        if self.i_feel_lucky() {
-            self.inner.read().await
-        } else {
-            Err(Self::unexpected_eof())
+        if self.truncated {
+            return Ok(Buffer::new());
         }
+
+        self.chaos.inject(Operation::Read).await?;
+        let mut buf = self.inner.read().await?;
+        self.truncated = self.chaos.truncate(&mut buf);
+        Ok(buf)
     }
 }
 
 impl<R: oio::Read> oio::Read for ChaosReader<R> {
     async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
-        if self.i_feel_lucky() {
-            let (rp, stream) = self.inner.open(range).await?;
-            Ok((
-                rp,
-                Box::new(ChaosReader::new(stream, self.rng.clone(), self.error_ratio))
-                    as Box<dyn oio::ReadStreamDyn>,
-            ))
-        } else {
-            Err(Self::unexpected_eof())
-        }
+        self.chaos.inject(Operation::Read).await?;
+        let (rp, stream) = self.inner.open(range).await?;
+        Ok((
+            rp,
+            Box::new(ChaosReader::new(stream, self.chaos.clone())) as Box<dyn oio::ReadStreamDyn>,
+        ))
     }
 
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
-        if self.i_feel_lucky() {
-            self.inner.read(range).await
-        } else {
-            Err(Self::unexpected_eof())
+        self.chaos.inject(Operation::Read).await?;
+        let (rp, mut buf) = self.inner.read(range).await?;
+        self.chaos.truncate(&mut buf);
+        Ok((rp, buf))
+    }
+}
+
+/// Wrapper to inject faults of `op` into every call of writer, lister,
+/// deleter and copier.
+#[doc(hidden)]
+pub struct ChaosWrapper<R> {
+    inner: R,
+    chaos: Arc<Chaos>,
+    op: Operation,
+}
+
+impl<R> ChaosWrapper<R> {
+    fn new(inner: R, chaos: Arc<Chaos>, op: Operation) -> Self {
+        Self { inner, chaos, op }
+    }
+}
+
+impl<R: oio::Write> oio::Write for ChaosWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.chaos.inject(self.op).await?;
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.chaos.inject(self.op).await?;
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
+}
+
+impl<R: oio::List> oio::List for ChaosWrapper<R> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        self.chaos.inject(self.op).await?;
+        self.inner.next().await
+    }
+}
+
+impl<R: oio::Delete> oio::Delete for ChaosWrapper<R> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.chaos.inject(self.op).await?;
+        self.inner.delete(path, args).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+impl<R: oio::Copy> oio::Copy for ChaosWrapper<R> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        self.chaos.inject(self.op).await?;
+        self.inner.next().await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    async fn run(layer: ChaosLayer) -> Vec<bool> {
+        let op = Operator::new(Memory::default()).unwrap().layer(layer);
+        let mut results = vec![];
+        for i in 0..32 {
+            results.push(op.write(&format!("file-{i}"), "hello").await.is_ok());
+        }
+        results
+    }
+
+    #[tokio::test]
+    async fn test_seeded_faults_are_reproducible() {
+        let layer = || ChaosLayer::new(0.0).with_error_ratio(Operation::Write, 0.5);
+        let first = run(layer().with_seed(7)).await;
+        let second = run(layer().with_seed(7)).await;
+        assert_eq!(first, second);
+        assert!(first.iter().any(|v| *v) && first.iter().any(|v| !*v));
+    }
+
+    #[tokio::test]
+    async fn test_error_ratio_per_operation() {
+        let op = Operator::new(Memory::default()).unwrap().layer(
+            ChaosLayer::new(0.0)
+                .with_seed(1)
+                .with_error_ratio(Operation::Stat, 1.0),
+        );
+
+        op.write("file", "hello").await.unwrap();
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hello");
+        let err = op.stat("file").await.unwrap_err();
+        assert!(err.is_temporary());
+        assert!(err.to_string().contains("I am your chaos!"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_truncated_read() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(ChaosLayer::new(0.0).with_seed(1).with_truncate_ratio(1.0));
+
+        op.write("file", "hello, world").await.unwrap();
+        let buf = op.read("file").await.unwrap();
+        assert!(buf.len() < 12, "{buf:?}");
+    }
+
+    #[tokio::test]
+    async fn test_latency() {
+        let latency = Duration::from_millis(20);
+        let op = Operator::new(Memory::default()).unwrap().layer(
+            ChaosLayer::new(0.0)
+                .with_seed(3)
+                .with_latency(Operation::Write, latency),
+        );
+
+        let start = std::time::Instant::now();
+        for i in 0..5 {
+            op.write(&format!("file-{i}"), "hello").await.unwrap();
         }
+        assert!(start.elapsed() >= latency / 10, "{:?}", start.elapsed());
     }
 }
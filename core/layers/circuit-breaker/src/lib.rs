@@ -0,0 +1,395 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Circuit breaker layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use http::StatusCode;
+use log::warn;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Add a circuit breaker for every host the service sends HTTP requests to.
+///
+/// # Notes
+///
+/// When the storage service browns out, retrying every request only adds more
+/// load to it. `CircuitBreakerLayer` tracks consecutive failures per host, and
+/// after `failure_threshold` of them the circuit trips: all requests to this
+/// host fail fast without being sent during the `cooldown` period.
+///
+/// Once the cooldown elapses, the circuit becomes half-open and lets a single
+/// probe request through while other requests still fail fast. The circuit is
+/// closed again if the probe succeeds, or re-opened for another cooldown if it
+/// fails.
+///
+/// A request is considered failed if the HTTP transport returns an error (like
+/// connection errors or timeouts), or the response status is `5xx` or `429`.
+///
+/// The error returned while the circuit is open is not temporary, so that
+/// `RetryLayer` won't retry it.
+///
+/// All operators wrapped by the same layer share the circuit state, so hosts
+/// used by many operators are tracked together.
+///
+/// Services that don't talk HTTP are not affected by this layer.
+///
+/// # Default
+///
+/// - failure_threshold: 5
+/// - cooldown: 30 seconds
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// #
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_circuit_breaker::CircuitBreakerLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(
+///     CircuitBreakerLayer::new()
+///         .with_failure_threshold(10)
+///         .with_cooldown(Duration::from_secs(60)),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CircuitBreakerLayer {
+    failure_threshold: usize,
+    cooldown: Duration,
+    circuits: Arc<Mutex<HashMap<String, Circuit>>>,
+}
+
+impl std::fmt::Debug for CircuitBreakerLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreakerLayer")
+            .field("failure_threshold", &self.failure_threshold)
+            .field("cooldown", &self.cooldown)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for CircuitBreakerLayer {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+            circuits: Arc::default(),
+        }
+    }
+}
+
+impl CircuitBreakerLayer {
+    /// Create a new [`CircuitBreakerLayer`] with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of consecutive failures to trip the circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is zero.
+    pub fn with_failure_threshold(mut self, threshold: usize) -> Self {
+        assert!(threshold > 0, "failure threshold must be positive");
+        self.failure_threshold = threshold;
+        self
+    }
+
+    /// Set how long the circuit stays open before a probe request is allowed.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Layer for CircuitBreakerLayer {
+    fn apply_context(&self, _srv: Servicer, inner: OperationContext) -> OperationContext {
+        let transport = HttpTransporter::new(CircuitBreakerHttpTransport {
+            inner: inner.http_transport().clone(),
+            layer: self.clone(),
+        });
+        inner.with_http_transport(transport)
+    }
+}
+
+enum Circuit {
+    Closed {
+        failures: usize,
+    },
+    Open {
+        until: Instant,
+    },
+    /// A probe request is in flight.
+    HalfOpen,
+}
+
+impl CircuitBreakerLayer {
+    /// Check whether a request to `host` is allowed, returns `true` if the
+    /// request is a probe.
+    fn acquire(&self, host: &str) -> Result<bool> {
+        let mut circuits = self.circuits.lock().expect("circuits lock poisoned");
+        let Some(circuit) = circuits.get_mut(host) else {
+            return Ok(false);
+        };
+
+        match circuit {
+            Circuit::Closed { .. } => Ok(false),
+            Circuit::Open { until } if Instant::now() >= *until => {
+                *circuit = Circuit::HalfOpen;
+                Ok(true)
+            }
+            Circuit::Open { .. } | Circuit::HalfOpen => {
+                Err(Error::new(ErrorKind::Unexpected, "circuit breaker is open")
+                    .with_context("host", host)
+                    .with_context("cooldown", format!("{:?}", self.cooldown)))
+            }
+        }
+    }
+
+    fn record(&self, host: &str, failed: bool) {
+        let mut circuits = self.circuits.lock().expect("circuits lock poisoned");
+        if !failed {
+            circuits.remove(host);
+            return;
+        }
+
+        let circuit = circuits
+            .entry(host.to_string())
+            .or_insert(Circuit::Closed { failures: 0 });
+        match circuit {
+            Circuit::Closed { failures } => {
+                *failures += 1;
+                if *failures >= self.failure_threshold {
+                    warn!(
+                        "circuit breaker tripped for host {host} after {failures} consecutive failures"
+                    );
+                    *circuit = self.open();
+                }
+            }
+            Circuit::HalfOpen => {
+                warn!("circuit breaker probe to host {host} failed, re-opened");
+                *circuit = self.open();
+            }
+            // Failures of requests sent before the circuit tripped.
+            Circuit::Open { .. } => {}
+        }
+    }
+
+    /// Allow another probe if the probe request is cancelled before finished.
+    fn release_probe(&self, host: &str) {
+        let mut circuits = self.circuits.lock().expect("circuits lock poisoned");
+        if let Some(circuit @ Circuit::HalfOpen) = circuits.get_mut(host) {
+            *circuit = Circuit::Open {
+                until: Instant::now(),
+            };
+        }
+    }
+
+    fn open(&self) -> Circuit {
+        Circuit::Open {
+            until: Instant::now() + self.cooldown,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CircuitBreakerHttpTransport {
+    inner: HttpTransporter,
+    layer: CircuitBreakerLayer,
+}
+
+impl HttpTransport for CircuitBreakerHttpTransport {
+    async fn fetch(&self, req: http::Request<Buffer>) -> Result<http::Response<HttpBody>> {
+        let host = req
+            .uri()
+            .authority()
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+
+        let probe = self.layer.acquire(&host)?;
+        let mut guard = ProbeGuard {
+            layer: &self.layer,
+            host: &host,
+            probe,
+        };
+
+        let result = self.inner.fetch(req).await;
+        let failed = match &result {
+            Ok(resp) => {
+                resp.status().is_server_error() || resp.status() == StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(_) => true,
+        };
+        guard.probe = false;
+        self.layer.record(&host, failed);
+
+        result
+    }
+}
+
+struct ProbeGuard<'a> {
+    layer: &'a CircuitBreakerLayer,
+    host: &'a str,
+    probe: bool,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            self.layer.release_probe(self.host);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU16;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use futures::stream;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockTransport {
+        status: Arc<AtomicU16>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockTransport {
+        fn set_status(&self, status: StatusCode) {
+            self.status.store(status.as_u16(), Ordering::SeqCst);
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl HttpTransport for MockTransport {
+        async fn fetch(&self, _: http::Request<Buffer>) -> Result<http::Response<HttpBody>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let status = StatusCode::from_u16(self.status.load(Ordering::SeqCst)).unwrap();
+            Ok(http::Response::builder()
+                .status(status)
+                .body(HttpBody::new(stream::empty(), Some(0)))
+                .unwrap())
+        }
+    }
+
+    fn new_transport(layer: CircuitBreakerLayer) -> (MockTransport, CircuitBreakerHttpTransport) {
+        let mock = MockTransport::default();
+        mock.set_status(StatusCode::OK);
+        let transport = CircuitBreakerHttpTransport {
+            inner: HttpTransporter::new(mock.clone()),
+            layer,
+        };
+        (mock, transport)
+    }
+
+    async fn fetch(transport: &CircuitBreakerHttpTransport, url: &str) -> Result<StatusCode> {
+        let req = http::Request::get(url).body(Buffer::new()).unwrap();
+        transport.fetch(req).await.map(|resp| resp.status())
+    }
+
+    #[tokio::test]
+    async fn test_trip_and_recover() {
+        let layer = CircuitBreakerLayer::new()
+            .with_failure_threshold(3)
+            .with_cooldown(Duration::from_millis(50));
+        let (mock, transport) = new_transport(layer);
+
+        mock.set_status(StatusCode::SERVICE_UNAVAILABLE);
+        for _ in 0..3 {
+            assert!(fetch(&transport, "http://a.test/").await.is_ok());
+        }
+
+        // The circuit is open, requests fail fast without being sent.
+        let err = fetch(&transport, "http://a.test/").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(!err.is_temporary());
+        assert_eq!(mock.calls(), 3);
+
+        // Other hosts are not affected.
+        assert!(fetch(&transport, "http://b.test/").await.is_ok());
+        assert_eq!(mock.calls(), 4);
+
+        // The probe fails and re-opens the circuit.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(fetch(&transport, "http://a.test/").await.is_ok());
+        assert!(fetch(&transport, "http://a.test/").await.is_err());
+        assert_eq!(mock.calls(), 5);
+
+        // The probe succeeds and closes the circuit.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        mock.set_status(StatusCode::OK);
+        for _ in 0..5 {
+            assert!(fetch(&transport, "http://a.test/").await.is_ok());
+        }
+        assert_eq!(mock.calls(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failures() {
+        let layer = CircuitBreakerLayer::new().with_failure_threshold(2);
+        let (mock, transport) = new_transport(layer);
+
+        for status in [
+            StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::OK,
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::NOT_FOUND,
+            StatusCode::BAD_GATEWAY,
+        ] {
+            mock.set_status(status);
+            assert_eq!(fetch(&transport, "http://a.test/").await.unwrap(), status);
+        }
+
+        // Client errors other than 429 are successes that reset the failures,
+        // so it takes one more failure to trip.
+        assert!(fetch(&transport, "http://a.test/").await.is_ok());
+        assert!(fetch(&transport, "http://a.test/").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_probe() {
+        let layer = CircuitBreakerLayer::new()
+            .with_failure_threshold(1)
+            .with_cooldown(Duration::ZERO);
+        layer.record("a.test", true);
+
+        assert!(layer.acquire("a.test").unwrap());
+        assert!(layer.acquire("a.test").is_err());
+
+        layer.release_probe("a.test");
+        assert!(layer.acquire("a.test").unwrap());
+    }
+}
@@ -64,6 +64,20 @@ impl ConcurrentLimitSemaphore for Arc<Semaphore> {
 /// that the total number of concurrent requests across the entire
 /// application does not exceed the limit.
 ///
+/// # Metadata and data operations
+///
+/// Operations are split into two classes that can be limited separately on
+/// top of the total limit:
+///
+/// - Metadata operations: `create_dir`, `stat`, `list`, `delete`, `rename`,
+///   `presign`, `get_acl`, `set_acl` and `restore`.
+/// - Data operations: `read`, `write` and `copy`.
+///
+/// Permits are handed out in the order they were requested, and an operation
+/// only queues for a total permit after it got a permit of its class. A burst
+/// of large reads waiting on the data limit therefore can't starve metadata
+/// operations, and the other way around.
+///
 /// # Examples
 ///
 /// Add a concurrent limit layer to the operator:
@@ -99,15 +113,37 @@ impl ConcurrentLimitSemaphore for Arc<Semaphore> {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Reserve part of the total limit for metadata operations:
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_concurrent_limit::ConcurrentLimitLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(
+///     ConcurrentLimitLayer::new(128)
+///         .with_metadata_concurrent_limit(32)
+///         .with_data_concurrent_limit(96),
+/// );
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone)]
 pub struct ConcurrentLimitLayer<S: ConcurrentLimitSemaphore = Arc<Semaphore>> {
     operation_semaphore: S,
+    metadata_semaphore: Option<S>,
+    data_semaphore: Option<S>,
     http_semaphore: Option<S>,
 }
 
 impl<S: ConcurrentLimitSemaphore> std::fmt::Debug for ConcurrentLimitLayer<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ConcurrentLimitLayer")
+            .field("has_metadata_limit", &self.metadata_semaphore.is_some())
+            .field("has_data_limit", &self.data_semaphore.is_some())
             .field("has_http_limit", &self.http_semaphore.is_some())
             .finish_non_exhaustive()
     }
@@ -131,6 +167,22 @@ impl ConcurrentLimitLayer<Arc<Semaphore>> {
     pub fn with_http_concurrent_limit(self, permits: usize) -> Self {
         self.with_http_semaphore(Arc::new(Semaphore::new(permits)))
     }
+
+    /// Set a concurrent limit for metadata operations.
+    ///
+    /// This convenience helper constructs a new semaphore with the specified
+    /// number of permits and calls [`ConcurrentLimitLayer::with_metadata_semaphore`].
+    pub fn with_metadata_concurrent_limit(self, permits: usize) -> Self {
+        self.with_metadata_semaphore(Arc::new(Semaphore::new(permits)))
+    }
+
+    /// Set a concurrent limit for data operations.
+    ///
+    /// This convenience helper constructs a new semaphore with the specified
+    /// number of permits and calls [`ConcurrentLimitLayer::with_data_semaphore`].
+    pub fn with_data_concurrent_limit(self, permits: usize) -> Self {
+        self.with_data_semaphore(Arc::new(Semaphore::new(permits)))
+    }
 }
 
 impl<S: ConcurrentLimitSemaphore> ConcurrentLimitLayer<S> {
@@ -146,10 +198,30 @@ impl<S: ConcurrentLimitSemaphore> ConcurrentLimitLayer<S> {
     pub fn with_semaphore(operation_semaphore: S) -> Self {
         Self {
             operation_semaphore,
+            metadata_semaphore: None,
+            data_semaphore: None,
             http_semaphore: None,
         }
     }
 
+    /// Provide a custom semaphore for metadata operations.
+    ///
+    /// Metadata operations will acquire a permit from it before acquiring
+    /// the operation permit.
+    pub fn with_metadata_semaphore(mut self, semaphore: S) -> Self {
+        self.metadata_semaphore = Some(semaphore);
+        self
+    }
+
+    /// Provide a custom semaphore for data operations.
+    ///
+    /// Data operations will acquire a permit from it before acquiring the
+    /// operation permit.
+    pub fn with_data_semaphore(mut self, semaphore: S) -> Self {
+        self.data_semaphore = Some(semaphore);
+        self
+    }
+
     /// Provide a custom HTTP concurrency semaphore instance.
     pub fn with_http_semaphore(mut self, semaphore: S) -> Self {
         self.http_semaphore = Some(semaphore);
@@ -183,11 +255,48 @@ where
     fn layer(&self, inner: Servicer) -> ConcurrentLimitService<S> {
         ConcurrentLimitService {
             inner,
-            semaphore: self.operation_semaphore.clone(),
+            metadata: OperationLimit {
+                class: self.metadata_semaphore.clone(),
+                total: self.operation_semaphore.clone(),
+            },
+            data: OperationLimit {
+                class: self.data_semaphore.clone(),
+                total: self.operation_semaphore.clone(),
+            },
         }
     }
 }
 
+/// The semaphores an operation of one class has to acquire.
+#[derive(Clone)]
+struct OperationLimit<S> {
+    class: Option<S>,
+    total: S,
+}
+
+impl<S: ConcurrentLimitSemaphore> OperationLimit<S> {
+    async fn acquire(&self) -> OperationPermit<S::Permit> {
+        // Acquire the class permit first, so operations waiting for a
+        // saturated class don't queue on the total semaphore and block
+        // the other class.
+        let class = match &self.class {
+            Some(semaphore) => Some(semaphore.acquire().await),
+            None => None,
+        };
+        let total = self.total.acquire().await;
+        OperationPermit {
+            _class: class,
+            _total: total,
+        }
+    }
+}
+
+/// Permits held by a running operation, released on drop.
+struct OperationPermit<P> {
+    _class: Option<P>,
+    _total: P,
+}
+
 #[doc(hidden)]
 pub struct ConcurrentLimitHttpTransport<S: ConcurrentLimitSemaphore> {
     inner: HttpTransporter,
@@ -241,7 +350,8 @@ where
 #[derive(Clone)]
 pub struct ConcurrentLimitService<S: ConcurrentLimitSemaphore> {
     inner: Servicer,
-    semaphore: S,
+    metadata: OperationLimit<S>,
+    data: OperationLimit<S>,
 }
 
 impl<S: ConcurrentLimitSemaphore> std::fmt::Debug for ConcurrentLimitService<S> {
@@ -276,20 +386,20 @@ where
         path: &str,
         args: OpCreateDir,
     ) -> Result<RpCreateDir> {
-        let _permit = self.semaphore.acquire().await;
+        let _permit = self.metadata.acquire().await;
         self.inner.create_dir(ctx, path, args).await
     }
 
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
         self.inner
             .read(ctx, path, args)
-            .map(|r| ConcurrentLimitReader::new(r, self.semaphore.clone()))
+            .map(|r| ConcurrentLimitReader::new(r, self.data.clone()))
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         self.inner
             .write(ctx, path, args)
-            .map(|w| ConcurrentLimitWrapper::new(w, self.semaphore.clone()))
+            .map(|w| ConcurrentLimitWrapper::new(w, self.data.clone()))
     }
 
     fn copy(
@@ -302,7 +412,7 @@ where
     ) -> Result<Self::Copier> {
         self.inner
             .copy(ctx, from, to, args, opts)
-            .map(|c| ConcurrentLimitWrapper::new(c, self.semaphore.clone()))
+            .map(|c| ConcurrentLimitWrapper::new(c, self.data.clone()))
     }
 
     async fn rename(
@@ -312,25 +422,25 @@ where
         to: &str,
         args: OpRename,
     ) -> Result<RpRename> {
-        let _permit = self.semaphore.acquire().await;
+        let _permit = self.metadata.acquire().await;
         self.inner.rename(ctx, from, to, args).await
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
-        let _permit = self.semaphore.acquire().await;
+        let _permit = self.metadata.acquire().await;
         self.inner.stat(ctx, path, args).await
     }
 
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
         self.inner
             .delete(ctx)
-            .map(|w| ConcurrentLimitWrapper::new(w, self.semaphore.clone()))
+            .map(|w| ConcurrentLimitWrapper::new(w, self.metadata.clone()))
     }
 
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
         self.inner
             .list(ctx, path, args)
-            .map(|s| ConcurrentLimitWrapper::new(s, self.semaphore.clone()))
+            .map(|s| ConcurrentLimitWrapper::new(s, self.metadata.clone()))
     }
 
     async fn presign(
@@ -339,20 +449,50 @@ where
         path: &str,
         args: OpPresign,
     ) -> Result<RpPresign> {
-        let _permit = self.semaphore.acquire().await;
+        let _permit = self.metadata.acquire().await;
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let _permit = self.metadata.acquire().await;
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let _permit = self.metadata.acquire().await;
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let _permit = self.metadata.acquire().await;
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[doc(hidden)]
 pub struct ConcurrentLimitReader<R, S> {
     inner: R,
-    semaphore: S,
+    limit: OperationLimit<S>,
 }
 
 impl<R, S> ConcurrentLimitReader<R, S> {
-    fn new(inner: R, semaphore: S) -> Self {
-        Self { inner, semaphore }
+    fn new(inner: R, limit: OperationLimit<S>) -> Self {
+        Self { inner, limit }
     }
 }
 
@@ -361,20 +501,20 @@ where
     S::Permit: Send + Sync + 'static + Unpin,
 {
     async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
-        let permit = self.semaphore.acquire().await;
+        let permit = self.limit.acquire().await;
         let (rp, stream) = self.inner.open(range).await?;
         Ok((
             rp,
             Box::new(ConcurrentLimitWrapper::new_with_permit(
                 stream,
-                self.semaphore.clone(),
+                self.limit.clone(),
                 permit,
             )) as Box<dyn oio::ReadStreamDyn>,
         ))
     }
 
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
-        let _permit = self.semaphore.acquire().await;
+        let _permit = self.limit.acquire().await;
         self.inner.read(range).await
     }
 }
@@ -382,31 +522,35 @@ where
 #[doc(hidden)]
 pub struct ConcurrentLimitWrapper<R, S: ConcurrentLimitSemaphore> {
     inner: R,
-    semaphore: S,
+    limit: OperationLimit<S>,
     // Hold this permit until the wrapped operation body is dropped.
-    permit: Option<S::Permit>,
+    permit: Option<OperationPermit<S::Permit>>,
 }
 
 impl<R, S: ConcurrentLimitSemaphore> ConcurrentLimitWrapper<R, S> {
-    fn new(inner: R, semaphore: S) -> Self {
+    fn new(inner: R, limit: OperationLimit<S>) -> Self {
         Self {
             inner,
-            semaphore,
+            limit,
             permit: None,
         }
     }
 
-    fn new_with_permit(inner: R, semaphore: S, permit: S::Permit) -> Self {
+    fn new_with_permit(
+        inner: R,
+        limit: OperationLimit<S>,
+        permit: OperationPermit<S::Permit>,
+    ) -> Self {
         Self {
             inner,
-            semaphore,
+            limit,
             permit: Some(permit),
         }
     }
 
     async fn acquire(&mut self) {
         if self.permit.is_none() {
-            self.permit = Some(self.semaphore.acquire().await);
+            self.permit = Some(self.limit.acquire().await);
         }
     }
 }
@@ -453,6 +597,11 @@ where
         self.acquire().await;
         self.inner.abort().await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.acquire().await;
+        self.inner.suspend().await
+    }
 }
 
 impl<R: oio::List, S: ConcurrentLimitSemaphore> oio::List for ConcurrentLimitWrapper<R, S>
@@ -539,6 +688,62 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn data_limit_does_not_block_metadata_operations() {
+        let data_semaphore = Arc::new(Semaphore::new(1));
+        // A single total permit: a read waiting for its data permit must not
+        // hold it, otherwise stat would be blocked too.
+        let layer = ConcurrentLimitLayer::new(1).with_data_semaphore(data_semaphore.clone());
+
+        let op = Operator::new(services::Memory::default())
+            .expect("operator must build")
+            .layer(layer);
+
+        let permit = data_semaphore.clone().acquire_owned(1).await;
+
+        let blocked = timeout(Duration::from_millis(50), op.read("any")).await;
+        assert!(blocked.is_err(), "read should wait for the data permit");
+
+        let stat = timeout(Duration::from_millis(50), op.stat("any")).await;
+        assert!(stat.is_ok(), "stat should not wait for the data permit");
+
+        drop(permit);
+
+        let completed = timeout(Duration::from_millis(50), op.read("any")).await;
+        assert!(
+            completed.is_ok(),
+            "read should proceed once data permit is released"
+        );
+    }
+
+    #[tokio::test]
+    async fn metadata_limit_does_not_block_data_operations() {
+        let metadata_semaphore = Arc::new(Semaphore::new(1));
+        let layer =
+            ConcurrentLimitLayer::new(1).with_metadata_semaphore(metadata_semaphore.clone());
+
+        let op = Operator::new(services::Memory::default())
+            .expect("operator must build")
+            .layer(layer);
+
+        let permit = metadata_semaphore.clone().acquire_owned(1).await;
+
+        let blocked = timeout(Duration::from_millis(50), op.stat("any")).await;
+        assert!(blocked.is_err(), "stat should wait for the metadata permit");
+
+        timeout(Duration::from_millis(50), op.write("any", "data"))
+            .await
+            .expect("write should not wait for the metadata permit")
+            .expect("write should succeed");
+
+        drop(permit);
+
+        timeout(Duration::from_millis(50), op.stat("any"))
+            .await
+            .expect("stat should proceed once metadata permit is released")
+            .expect("stat should succeed");
+    }
+
     #[tokio::test]
     async fn operation_semaphore_limits_copy_and_rename() {
         #[derive(Clone, Debug)]
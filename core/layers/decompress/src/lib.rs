@@ -0,0 +1,378 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Streaming decompression layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod reader;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub use reader::DecompressReader;
+
+/// Compression formats supported by [`DecompressLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compression {
+    /// gzip, detected by `Content-Encoding: gzip` or the `.gz` extension.
+    Gzip,
+    /// zstd, detected by `Content-Encoding: zstd` or the `.zst` extension.
+    Zstd,
+}
+
+impl Compression {
+    /// Detect compression from the value of `Content-Encoding`.
+    pub fn from_content_encoding(v: &str) -> Option<Self> {
+        let v = v.trim();
+        if v.eq_ignore_ascii_case("gzip") || v.eq_ignore_ascii_case("x-gzip") {
+            Some(Compression::Gzip)
+        } else if v.eq_ignore_ascii_case("zstd") {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+
+    /// Detect compression from the extension of given path.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, ext) = path.rsplit_once('.')?;
+        if ext.eq_ignore_ascii_case("gz") {
+            Some(Compression::Gzip)
+        } else if ext.eq_ignore_ascii_case("zst") {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compression::Gzip => write!(f, "gzip"),
+            Compression::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// Add transparent decompression on read for the underlying service.
+///
+/// # Detection
+///
+/// An object is decompressed if the service reports a supported
+/// `content_encoding` for it, or, if extension detection is enabled
+/// (the default), its path ends with `.gz` or `.zst`. Other objects are
+/// returned as is.
+///
+/// # Notes
+///
+/// - `stat` and `list` still return the compressed size stored by the service.
+/// - Range reads are applied to the decompressed content, which means the
+///   object has to be decompressed from the start. Suffix ranges are not
+///   supported on compressed objects.
+/// - Chunked and concurrent reads use the compressed size to split the range,
+///   don't set `chunk` for compressed objects.
+/// - Writes are passed through as is.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_decompress::DecompressLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(DecompressLayer::new());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DecompressLayer {
+    detect_extension: bool,
+}
+
+impl Default for DecompressLayer {
+    fn default() -> Self {
+        Self {
+            detect_extension: true,
+        }
+    }
+}
+
+impl DecompressLayer {
+    /// Create a new [`DecompressLayer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to detect compression from the path extension.
+    ///
+    /// If disabled, only the `content_encoding` reported by the service is used.
+    ///
+    /// Default to `true`.
+    pub fn with_extension_detection(mut self, enabled: bool) -> Self {
+        self.detect_extension = enabled;
+        self
+    }
+}
+
+impl Layer for DecompressLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        std::sync::Arc::new(DecompressService {
+            inner,
+            detect_extension: self.detect_extension,
+        })
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DecompressService {
+    inner: Servicer,
+    detect_extension: bool,
+}
+
+impl Service for DecompressService {
+    type Reader = DecompressReader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let compression = if self.detect_extension {
+            Compression::from_path(path)
+        } else {
+            None
+        };
+        let inner = self.inner.read(ctx, path, args)?;
+        Ok(DecompressReader::new(inner, path, compression))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn content(size: usize) -> Vec<u8> {
+        (0..size).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn gzip(bs: &[u8]) -> Vec<u8> {
+        let mut e = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        e.write_all(bs).unwrap();
+        e.finish().unwrap()
+    }
+
+    fn zstd(bs: &[u8]) -> Vec<u8> {
+        zstd::stream::encode_all(bs, 3).unwrap()
+    }
+
+    fn new_operator() -> Result<(Operator, Operator)> {
+        let memory = Operator::new(services::Memory::default())?;
+        Ok((memory.clone(), memory.layer(DecompressLayer::new())))
+    }
+
+    #[test]
+    fn test_detect_compression() {
+        assert_eq!(Compression::from_path("a/b.gz"), Some(Compression::Gzip));
+        assert_eq!(Compression::from_path("b.ZST"), Some(Compression::Zstd));
+        assert_eq!(Compression::from_path("b.tar"), None);
+        assert_eq!(Compression::from_path("gz"), None);
+        assert_eq!(
+            Compression::from_content_encoding("x-gzip"),
+            Some(Compression::Gzip)
+        );
+        assert_eq!(Compression::from_content_encoding("br"), None);
+    }
+
+    #[tokio::test]
+    async fn test_decompress_by_extension() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        let data = content(100_000);
+        let compressed = gzip(&data);
+
+        memory.write("data.gz", compressed.clone()).await?;
+        memory.write("data.zst", zstd(&data)).await?;
+        memory.write("data.txt", data.clone()).await?;
+
+        for path in ["data.gz", "data.zst", "data.txt"] {
+            assert_eq!(op.read(path).await?.to_vec(), data, "{path}");
+        }
+
+        // stat still reports the stored size.
+        let meta = op.stat("data.gz").await?;
+        assert_eq!(meta.content_length(), compressed.len() as u64);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decompress_by_content_encoding() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        let data = content(1024);
+
+        memory
+            .write_with("data", gzip(&data))
+            .content_encoding("gzip")
+            .await?;
+        assert_eq!(op.read("data").await?.to_vec(), data);
+        assert_eq!(
+            op.read_with("data").range(10..20).await?.to_vec(),
+            data[10..20]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decompress_range() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        let data = content(100_000);
+        memory.write("data.zst", zstd(&data)).await?;
+
+        let reader = op.reader("data.zst").await?;
+        for (start, end) in [(0, 10), (3, 9), (65536, 70000), (99_990, 100_000)] {
+            let buf = reader.read(start as u64..end as u64).await?;
+            assert_eq!(buf.to_vec(), data[start..end], "range {start}..{end}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decompress_truncated() -> Result<()> {
+        let (memory, op) = new_operator()?;
+        let compressed = gzip(&content(1024));
+        memory
+            .write("data.gz", compressed[..compressed.len() / 2].to_vec())
+            .await?;
+
+        let err = op.read("data.gz").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_extension_detection_disabled() -> Result<()> {
+        let memory = Operator::new(services::Memory::default())?;
+        let op = memory
+            .clone()
+            .layer(DecompressLayer::new().with_extension_detection(false));
+
+        let compressed = gzip(b"hello, world");
+        memory.write("data.gz", compressed.clone()).await?;
+        assert_eq!(op.read("data.gz").await?.to_vec(), compressed);
+        Ok(())
+    }
+}
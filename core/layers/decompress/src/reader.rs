@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::Write;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::Compression;
+
+/// Reader that decompresses objects on the fly.
+pub struct DecompressReader {
+    inner: oio::Reader,
+    path: String,
+    /// Compression detected from the path, if any.
+    compression: Option<Compression>,
+}
+
+impl DecompressReader {
+    pub(crate) fn new(inner: oio::Reader, path: &str, compression: Option<Compression>) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            compression,
+        }
+    }
+
+    fn unsupported_suffix(&self, compression: Compression) -> Error {
+        Error::new(
+            ErrorKind::Unsupported,
+            "suffix range is not supported on compressed objects",
+        )
+        .with_operation(Operation::Read)
+        .with_context("path", &self.path)
+        .with_context("compression", compression.to_string())
+    }
+}
+
+impl oio::Read for DecompressReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        if let Some(compression) = self.compression {
+            if matches!(range, BytesRange::Suffix { .. }) {
+                return Err(self.unsupported_suffix(compression));
+            }
+        }
+
+        // Compressed objects must be read from the very beginning, but we only
+        // know about `content_encoding` after the object has been opened.
+        let full = BytesRange::new(0, None);
+        let inner_range = if self.compression.is_some() {
+            full
+        } else {
+            range
+        };
+        let (rp, mut stream) = self.inner.open(inner_range).await?;
+
+        let compression = rp
+            .metadata()
+            .and_then(|m| m.content_encoding())
+            .and_then(Compression::from_content_encoding)
+            .or(self.compression);
+        let Some(compression) = compression else {
+            return Ok((rp, stream));
+        };
+
+        if inner_range != full {
+            if matches!(range, BytesRange::Suffix { .. }) {
+                return Err(self.unsupported_suffix(compression));
+            }
+            (_, stream) = self.inner.open(full).await?;
+        }
+
+        let stream = DecompressStream {
+            inner: stream,
+            decoder: Decoder::new(compression)?,
+            skip: range.offset(),
+            remaining: range.size(),
+            done: false,
+        };
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, mut stream) = self.open(range).await?;
+
+        let mut bufs = Vec::new();
+        loop {
+            let buf = stream.read_dyn().await?;
+            if buf.is_empty() {
+                break;
+            }
+            bufs.push(buf.to_bytes());
+        }
+        Ok((rp, Buffer::from(bufs)))
+    }
+}
+
+/// Push based decoders, compressed input is written in and decompressed
+/// output is collected into the inner `Vec`.
+enum Decoder {
+    Gzip(Box<flate2::write::MultiGzDecoder<Vec<u8>>>),
+    Zstd(Box<zstd::stream::write::Decoder<'static, Vec<u8>>>),
+}
+
+impl Decoder {
+    fn new(compression: Compression) -> Result<Self> {
+        Ok(match compression {
+            Compression::Gzip => {
+                Decoder::Gzip(Box::new(flate2::write::MultiGzDecoder::new(Vec::new())))
+            }
+            Compression::Zstd => Decoder::Zstd(Box::new(
+                zstd::stream::write::Decoder::new(Vec::new()).map_err(new_decompress_error)?,
+            )),
+        })
+    }
+
+    fn write(&mut self, bs: &[u8]) -> Result<()> {
+        match self {
+            Decoder::Gzip(d) => d.write_all(bs),
+            Decoder::Zstd(d) => d.write_all(bs),
+        }
+        .map_err(new_decompress_error)
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        match self {
+            Decoder::Gzip(d) => d.try_finish(),
+            Decoder::Zstd(d) => d.flush(),
+        }
+        .map_err(new_decompress_error)
+    }
+
+    fn take(&mut self) -> Vec<u8> {
+        match self {
+            Decoder::Gzip(d) => std::mem::take(d.get_mut()),
+            Decoder::Zstd(d) => std::mem::take(d.get_mut()),
+        }
+    }
+}
+
+fn new_decompress_error(err: std::io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "failed to decompress content").set_source(err)
+}
+
+/// Stream that decompresses the whole object and returns the requested range.
+struct DecompressStream {
+    inner: Box<dyn oio::ReadStreamDyn>,
+    decoder: Decoder,
+
+    /// Decompressed bytes to drop before the requested range.
+    skip: u64,
+    /// Decompressed bytes left in the requested range.
+    remaining: Option<u64>,
+    done: bool,
+}
+
+impl oio::ReadStream for DecompressStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        loop {
+            if self.done || self.remaining == Some(0) {
+                return Ok(Buffer::new());
+            }
+
+            let buf = self.inner.read_dyn().await?;
+            if buf.is_empty() {
+                self.decoder.finish()?;
+                self.done = true;
+            } else {
+                for bs in buf {
+                    self.decoder.write(&bs)?;
+                }
+            }
+
+            let mut out = self.decoder.take();
+            if self.skip > 0 {
+                let n = (self.skip as usize).min(out.len());
+                out.drain(..n);
+                self.skip -= n as u64;
+            }
+            if let Some(remaining) = self.remaining.as_mut() {
+                out.truncate((*remaining).min(out.len() as u64) as usize);
+                *remaining -= out.len() as u64;
+            }
+            if !out.is_empty() {
+                return Ok(Buffer::from(out));
+            }
+        }
+    }
+}
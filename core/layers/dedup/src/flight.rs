@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use mea::once::OnceCell;
+use opendal_core::*;
+
+type Flight<T> = Arc<OnceCell<std::result::Result<T, Arc<Error>>>>;
+
+/// Calls in flight, keyed by their arguments.
+///
+/// The first caller of a key runs the call, and callers arriving while it's
+/// running wait for and share its result. If the running caller is dropped,
+/// one of the waiting callers takes over. Keys are forgotten once the call
+/// completes, so results are never reused by later calls.
+pub(crate) struct Flights<K, T> {
+    inflight: Mutex<HashMap<K, Flight<T>>>,
+}
+
+impl<K, T> Default for Flights<K, T> {
+    fn default() -> Self {
+        Self {
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, T: Clone> Flights<K, T> {
+    fn inflight(&self) -> std::sync::MutexGuard<'_, HashMap<K, Flight<T>>> {
+        self.inflight.lock().expect("dedup flights lock poisoned")
+    }
+
+    pub(crate) async fn run<F>(&self, key: K, f: F) -> Result<T>
+    where
+        F: AsyncFnOnce() -> Result<T>,
+    {
+        let flight = self.inflight().entry(key.clone()).or_default().clone();
+
+        let result = flight
+            .get_or_init(async || f().await.map_err(Arc::new))
+            .await
+            .clone();
+
+        {
+            let mut inflight = self.inflight();
+            if inflight.get(&key).is_some_and(|v| Arc::ptr_eq(v, &flight)) {
+                inflight.remove(&key);
+            }
+        }
+
+        result.map_err(|err| share_error(&err))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.inflight().len()
+    }
+}
+
+/// Build an error for every caller sharing a failed call, keeping the
+/// original one as source.
+fn share_error(err: &Arc<Error>) -> Error {
+    Error::new(err.kind(), err.message())
+        .with_permanent(err.is_permanent())
+        .with_temporary(err.is_temporary())
+        .with_persistent(err.is_persistent())
+        .set_source(err.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use super::*;
+
+    async fn run_concurrently(
+        flights: Arc<Flights<&'static str, usize>>,
+        calls: Arc<AtomicUsize>,
+        result: fn() -> Result<usize>,
+    ) -> Vec<Result<usize>> {
+        let tasks = (0..10)
+            .map(|_| {
+                let flights = flights.clone();
+                let calls = calls.clone();
+                tokio::spawn(async move {
+                    flights
+                        .run("key", async || {
+                            calls.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            result()
+                        })
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            results.push(task.await.unwrap());
+        }
+        results
+    }
+
+    #[tokio::test]
+    async fn test_run_shares_result() {
+        let flights = Arc::new(Flights::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let results = run_concurrently(flights.clone(), calls.clone(), || Ok(42)).await;
+        assert!(results.into_iter().all(|v| v.unwrap() == 42));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(flights.len(), 0);
+
+        // Completed calls are not reused.
+        run_concurrently(flights.clone(), calls.clone(), || Ok(42)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_shares_error() {
+        let flights = Arc::new(Flights::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let results = run_concurrently(flights.clone(), calls.clone(), || {
+            Err(Error::new(ErrorKind::Unexpected, "boom").set_temporary())
+        })
+        .await;
+        for err in results.into_iter().map(|v| v.unwrap_err()) {
+            assert_eq!(err.kind(), ErrorKind::Unexpected);
+            assert_eq!(err.message(), "boom");
+            assert!(err.is_temporary());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(flights.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_takes_over_cancelled_call() {
+        let flights = Arc::new(Flights::<&str, usize>::default());
+
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(10),
+            flights.run("key", async || {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(1)
+            }),
+        )
+        .await;
+        assert!(cancelled.is_err());
+
+        let v = flights.run("key", async || Ok(2)).await.unwrap();
+        assert_eq!(v, 2);
+        assert_eq!(flights.len(), 0);
+    }
+}
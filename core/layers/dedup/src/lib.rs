@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Request deduplication layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod flight;
+mod reader;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::flight::Flights;
+
+pub use reader::DedupReader;
+
+/// Deduplicate concurrent identical `stat` and `read` calls.
+///
+/// # Operation Behavior
+///
+/// - `stat`: calls with the same path and arguments that overlap in time are sent
+///   to the service once, and all callers share the result.
+/// - `read`: reads of the same path, arguments and range that overlap in time are
+///   sent to the service once. The shared content is fully buffered in memory
+///   before it's handed to the callers, even if they read it as a stream.
+/// - Other operations are passed through to the underlying service.
+///
+/// Results are only shared between calls in flight at the same time, nothing is
+/// cached once a call completes. Errors are shared as well: every caller gets an
+/// error of the same kind and status with the original error as its source.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_dedup::DedupLayer;
+/// # fn main() -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?.layer(DedupLayer::new());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DedupLayer {}
+
+impl DedupLayer {
+    /// Create a new `DedupLayer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Layer for DedupLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(DedupService {
+            inner,
+            core: Arc::new(DedupCore::default()),
+        })
+    }
+}
+
+/// Arguments identifying a `stat` call.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct StatKey {
+    path: String,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<Timestamp>,
+    if_unmodified_since: Option<Timestamp>,
+    override_content_type: Option<String>,
+    override_cache_control: Option<String>,
+    override_content_disposition: Option<String>,
+    version: Option<String>,
+}
+
+impl StatKey {
+    fn new(path: &str, args: &OpStat) -> Self {
+        Self {
+            path: path.to_string(),
+            if_match: args.if_match().map(|v| v.to_string()),
+            if_none_match: args.if_none_match().map(|v| v.to_string()),
+            if_modified_since: args.if_modified_since(),
+            if_unmodified_since: args.if_unmodified_since(),
+            override_content_type: args.override_content_type().map(|v| v.to_string()),
+            override_cache_control: args.override_cache_control().map(|v| v.to_string()),
+            override_content_disposition: args
+                .override_content_disposition()
+                .map(|v| v.to_string()),
+            version: args.version().map(|v| v.to_string()),
+        }
+    }
+}
+
+/// Arguments identifying a `read` call, except for the range.
+///
+/// `content_length_hint` is left out since it doesn't change the result.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ReadKey {
+    path: String,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<Timestamp>,
+    if_unmodified_since: Option<Timestamp>,
+    override_content_type: Option<String>,
+    override_cache_control: Option<String>,
+    override_content_disposition: Option<String>,
+    version: Option<String>,
+    verify_checksum: bool,
+}
+
+impl ReadKey {
+    fn new(path: &str, args: &OpRead) -> Self {
+        Self {
+            path: path.to_string(),
+            if_match: args.if_match().map(|v| v.to_string()),
+            if_none_match: args.if_none_match().map(|v| v.to_string()),
+            if_modified_since: args.if_modified_since(),
+            if_unmodified_since: args.if_unmodified_since(),
+            override_content_type: args.override_content_type().map(|v| v.to_string()),
+            override_cache_control: args.override_cache_control().map(|v| v.to_string()),
+            override_content_disposition: args
+                .override_content_disposition()
+                .map(|v| v.to_string()),
+            version: args.version().map(|v| v.to_string()),
+            verify_checksum: args.verify_checksum(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct DedupCore {
+    stats: Flights<StatKey, RpStat>,
+    reads: Flights<(ReadKey, BytesRange), (RpRead, Buffer)>,
+}
+
+/// Service wrapped by [`DedupLayer`].
+pub struct DedupService {
+    inner: Servicer,
+    core: Arc<DedupCore>,
+}
+
+impl Debug for DedupService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DedupService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for DedupService {
+    type Reader = DedupReader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        let key = StatKey::new(path, &args);
+        self.core
+            .stats
+            .run(key, async || self.inner.stat(ctx, path, args).await)
+            .await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let key = ReadKey::new(path, &args);
+        let r = self.inner.read(ctx, path, args)?;
+        Ok(DedupReader::new(r, key, self.core.clone()))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_and_stat() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(DedupLayer::new());
+        op.write("a", (0..10).collect::<Vec<u8>>()).await.unwrap();
+
+        let (full, range, meta) =
+            tokio::join!(op.read("a"), op.read_with("a").range(2..5), op.stat("a"));
+        assert_eq!(full.unwrap().to_vec(), (0..10).collect::<Vec<u8>>());
+        assert_eq!(range.unwrap().to_vec(), vec![2, 3, 4]);
+        assert_eq!(meta.unwrap().content_length(), 10);
+
+        let err = op.stat("b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::DedupCore;
+use crate::ReadKey;
+
+/// Reader returned by [`crate::DedupLayer`].
+///
+/// Identical ranges read at the same time share one read of the underlying
+/// reader.
+pub struct DedupReader {
+    inner: oio::Reader,
+    key: ReadKey,
+    core: Arc<DedupCore>,
+}
+
+impl DedupReader {
+    pub(crate) fn new(inner: oio::Reader, key: ReadKey, core: Arc<DedupCore>) -> Self {
+        Self { inner, key, core }
+    }
+}
+
+impl oio::Read for DedupReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let (rp, buffer) = self.read(range).await?;
+        Ok((rp, Box::new(buffer) as Box<dyn oio::ReadStreamDyn>))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        self.core
+            .reads
+            .run((self.key.clone(), range), async || {
+                if range.size().is_some() {
+                    return self.inner.read(range).await;
+                }
+
+                // Unbounded ranges can only be served by draining a stream.
+                let (rp, mut stream) = self.inner.open(range).await?;
+                let buffer = oio::ReadStream::read_all(&mut stream).await?;
+                Ok((rp, buffer))
+            })
+            .await
+    }
+}
@@ -45,6 +45,9 @@ use probe::probe_lazy;
 ///     5. delete
 ///     6. list
 ///     7. presign
+///     8. get_acl
+///     9. set_acl
+///     10. restore
 ///
 /// 2. ${operation}_end, arguments: path
 ///     1. create_dir
@@ -54,6 +57,9 @@ use probe::probe_lazy;
 ///     5. delete
 ///     6. list
 ///     7. presign
+///     8. get_acl
+///     9. set_acl
+///     10. restore
 ///
 /// ### For Reader
 ///
@@ -261,6 +267,45 @@ impl Service for DTraceService {
         probe_lazy!(opendal, presign_end, c_path.as_ptr());
         result
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let c_path = CString::new(path).unwrap();
+        probe_lazy!(opendal, get_acl_start, c_path.as_ptr());
+        let result = self.inner.get_acl(ctx, path, args).await;
+        probe_lazy!(opendal, get_acl_end, c_path.as_ptr());
+        result
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let c_path = CString::new(path).unwrap();
+        probe_lazy!(opendal, set_acl_start, c_path.as_ptr());
+        let result = self.inner.set_acl(ctx, path, args).await;
+        probe_lazy!(opendal, set_acl_end, c_path.as_ptr());
+        result
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let c_path = CString::new(path).unwrap();
+        probe_lazy!(opendal, restore_start, c_path.as_ptr());
+        let result = self.inner.restore(ctx, path, args).await;
+        probe_lazy!(opendal, restore_end, c_path.as_ptr());
+        result
+    }
 }
 
 #[doc(hidden)]
@@ -439,4 +484,8 @@ impl<R: oio::Write> oio::Write for DtraceLayerWrapper<R> {
                 probe_lazy!(opendal, writer_close_error, c_path.as_ptr());
             })
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
 }
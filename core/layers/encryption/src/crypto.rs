@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Key;
+use aes_gcm::Nonce;
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::KeyInit;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use opendal_core::*;
+
+/// User metadata key that stores the wrapped data key.
+pub(crate) const KEY_METADATA: &str = "opendal-encryption-key";
+/// User metadata key that stores the plaintext segment size.
+pub(crate) const SEGMENT_SIZE_METADATA: &str = "opendal-encryption-segment-size";
+
+/// Size of the AES-GCM authentication tag appended to every segment.
+const TAG_SIZE: u64 = 16;
+/// Size of the AES-GCM nonce.
+const NONCE_SIZE: usize = 12;
+
+/// Layout of the segmented ciphertext.
+///
+/// Plaintext is split into segments of `segment_size` bytes, the last one may
+/// be shorter (or empty for empty content). Every segment is sealed on its own,
+/// so any plaintext range maps to a whole number of ciphertext segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Layout {
+    segment_size: u64,
+}
+
+impl Layout {
+    pub(crate) fn new(segment_size: usize) -> Self {
+        Self {
+            segment_size: segment_size as u64,
+        }
+    }
+
+    /// Load the layout recorded in user metadata, falling back to `default`.
+    pub(crate) fn from_user_metadata(
+        user_metadata: Option<&HashMap<String, String>>,
+        default: Layout,
+    ) -> Result<Self> {
+        let Some(v) = user_metadata.and_then(|m| m.get(SEGMENT_SIZE_METADATA)) else {
+            return Ok(default);
+        };
+
+        match v.parse::<u64>() {
+            Ok(segment_size) if segment_size > 0 => Ok(Self { segment_size }),
+            _ => Err(Error::new(
+                ErrorKind::Unexpected,
+                "encryption segment size in metadata is invalid",
+            )
+            .with_context("segment_size", v)),
+        }
+    }
+
+    pub(crate) fn segment_size(&self) -> u64 {
+        self.segment_size
+    }
+
+    /// Size of a full sealed segment.
+    pub(crate) fn sealed_segment_size(&self) -> u64 {
+        self.segment_size + TAG_SIZE
+    }
+
+    /// Number of segments used to store `plaintext_len` bytes.
+    pub(crate) fn segments(&self, plaintext_len: u64) -> u64 {
+        plaintext_len.div_ceil(self.segment_size).max(1)
+    }
+
+    /// Convert a ciphertext length back into the plaintext length.
+    pub(crate) fn plaintext_len(&self, ciphertext_len: u64) -> Result<u64> {
+        let segments = ciphertext_len.div_ceil(self.sealed_segment_size());
+        let last =
+            ciphertext_len.saturating_sub(segments.saturating_sub(1) * self.sealed_segment_size());
+        if segments == 0 || last < TAG_SIZE {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "content length doesn't match the encryption layout",
+            )
+            .with_context("content_length", ciphertext_len.to_string()));
+        }
+
+        Ok(ciphertext_len - segments * TAG_SIZE)
+    }
+}
+
+/// Key used to seal the segments of one object.
+pub(crate) struct DataKey {
+    cipher: Aes256Gcm,
+}
+
+impl DataKey {
+    /// Generate a fresh random data key, returning it with its raw bytes.
+    pub(crate) fn generate() -> (Self, [u8; 32]) {
+        let raw: [u8; 32] = rand::random();
+        (Self::from_bytes(&raw), raw)
+    }
+
+    fn from_bytes(raw: &[u8]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(raw)),
+        }
+    }
+
+    /// Segment nonces are derived from the segment index and a final flag, the
+    /// flag keeps a truncated object from decrypting as a shorter valid one.
+    fn nonce(index: u64, last: bool) -> [u8; NONCE_SIZE] {
+        let mut nonce = [0; NONCE_SIZE];
+        nonce[3..11].copy_from_slice(&index.to_be_bytes());
+        nonce[11] = last as u8;
+        nonce
+    }
+
+    pub(crate) fn seal(&self, index: u64, last: bool, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = Self::nonce(index, last);
+        self.cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to encrypt segment"))
+    }
+
+    pub(crate) fn open(&self, index: u64, last: bool, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = Self::nonce(index, last);
+        self.cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext)
+            .map_err(|_| {
+                Error::new(ErrorKind::Unexpected, "failed to decrypt segment")
+                    .with_context("segment", index.to_string())
+            })
+    }
+}
+
+/// Key used to wrap the per-object data keys.
+pub(crate) struct MasterKey {
+    cipher: Aes256Gcm,
+}
+
+impl MasterKey {
+    pub(crate) fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        }
+    }
+
+    /// Wrap a raw data key into the form stored in user metadata.
+    pub(crate) fn wrap(&self, raw: &[u8; 32]) -> Result<String> {
+        let nonce: [u8; NONCE_SIZE] = rand::random();
+        let mut wrapped = nonce.to_vec();
+        wrapped.extend(
+            self.cipher
+                .encrypt(Nonce::from_slice(&nonce), raw.as_slice())
+                .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to wrap data key"))?,
+        );
+        Ok(BASE64.encode(wrapped))
+    }
+
+    /// Unwrap the data key stored in user metadata.
+    pub(crate) fn unwrap(&self, wrapped: &str) -> Result<DataKey> {
+        let err = || {
+            Error::new(
+                ErrorKind::PermissionDenied,
+                "failed to unwrap data key, the master key may be wrong",
+            )
+        };
+
+        let wrapped = BASE64.decode(wrapped).map_err(|_| err())?;
+        if wrapped.len() <= NONCE_SIZE {
+            return Err(err());
+        }
+        let (nonce, ciphertext) = wrapped.split_at(NONCE_SIZE);
+        let raw = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| err())?;
+        if raw.len() != 32 {
+            return Err(err());
+        }
+        Ok(DataKey::from_bytes(&raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_roundtrip() {
+        let layout = Layout::new(4);
+        for plaintext_len in 0..20 {
+            let ciphertext_len = plaintext_len + layout.segments(plaintext_len) * TAG_SIZE;
+            assert_eq!(layout.plaintext_len(ciphertext_len).unwrap(), plaintext_len);
+        }
+
+        assert!(layout.plaintext_len(0).is_err());
+        assert!(layout.plaintext_len(TAG_SIZE - 1).is_err());
+        // A trailing segment shorter than a tag can't be produced by the writer.
+        assert!(
+            layout
+                .plaintext_len(layout.sealed_segment_size() + 3)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_wrap_unwrap() {
+        let master = MasterKey::new(&[7; 32]);
+        let (key, raw) = DataKey::generate();
+        let sealed = key.seal(0, true, b"hello").unwrap();
+
+        let wrapped = master.wrap(&raw).unwrap();
+        let unwrapped = master.unwrap(&wrapped).unwrap();
+        assert_eq!(unwrapped.open(0, true, &sealed).unwrap(), b"hello");
+        // The final flag is bound into the nonce.
+        assert!(unwrapped.open(0, false, &sealed).is_err());
+
+        let other = MasterKey::new(&[8; 32]);
+        let err = other.unwrap(&wrapped).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+}
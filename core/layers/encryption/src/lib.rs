@@ -0,0 +1,441 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Client-side encryption layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod crypto;
+mod reader;
+mod writer;
+
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crypto::DataKey;
+use crypto::KEY_METADATA;
+use crypto::Layout;
+use crypto::MasterKey;
+use crypto::SEGMENT_SIZE_METADATA;
+pub use reader::EncryptionReader;
+pub use writer::EncryptionWriter;
+
+/// Default plaintext size of one sealed segment.
+const DEFAULT_SEGMENT_SIZE: usize = 64 * 1024;
+
+/// Add client-side encryption for the underlying service.
+///
+/// # Envelope
+///
+/// Every object is encrypted with its own random data key using AES-256-GCM.
+/// The data key is wrapped with the master key given to this layer and stored
+/// in the object's user metadata, so the provider never sees either key.
+///
+/// Content is sealed in fixed-size segments, each one authenticated on its
+/// own. Range reads only fetch and decrypt the segments they cover, and the
+/// `content_length` returned by `stat`, `list` and `read` is the plaintext size.
+///
+/// # Notes
+///
+/// - The underlying service must support writing user metadata.
+/// - Append and presign are not supported, as both expose the raw ciphertext.
+/// - Reads issue an extra `stat` to load the data key.
+/// - `list` returns the stored size for services that don't return user
+///   metadata in listings, use `stat` to get the plaintext size.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_encryption::EncryptionLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let key = [0u8; 32]; // load the master key from a secret store instead.
+/// let _ = Operator::new(services::Memory::default())?.layer(EncryptionLayer::new(key));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EncryptionLayer {
+    master: Arc<MasterKey>,
+    layout: Layout,
+}
+
+impl std::fmt::Debug for EncryptionLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionLayer")
+            .field("layout", &self.layout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EncryptionLayer {
+    /// Create a new [`EncryptionLayer`] with the given 256-bit master key.
+    pub fn new(key: [u8; 32]) -> Self {
+        Self {
+            master: Arc::new(MasterKey::new(&key)),
+            layout: Layout::new(DEFAULT_SEGMENT_SIZE),
+        }
+    }
+
+    /// Set the plaintext size of each sealed segment for new objects.
+    ///
+    /// Smaller segments make small range reads cheaper at the cost of 16 bytes
+    /// of overhead per segment. Existing objects keep the size they were
+    /// written with.
+    ///
+    /// Default to 64 KiB.
+    ///
+    /// # Panics
+    ///
+    /// Input segment_size must be larger than 0.
+    pub fn with_segment_size(mut self, segment_size: usize) -> Self {
+        assert!(segment_size > 0, "segment_size must be larger than 0");
+        self.layout = Layout::new(segment_size);
+        self
+    }
+}
+
+impl Layer for EncryptionLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(EncryptionService {
+            inner,
+            master: self.master.clone(),
+            layout: self.layout,
+        })
+    }
+}
+
+#[doc(hidden)]
+pub struct EncryptionService {
+    inner: Servicer,
+    master: Arc<MasterKey>,
+    layout: Layout,
+}
+
+impl std::fmt::Debug for EncryptionService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionService")
+            .field("inner", &self.inner)
+            .field("layout", &self.layout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for EncryptionService {
+    type Reader = EncryptionReader;
+    type Writer = EncryptionWriter;
+    type Lister = EncryptionLister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        let mut cap = self.inner.capability();
+        cap.write_can_append = false;
+        cap.write_can_seek = false;
+        // A resumed write would seal its tail with a fresh data key.
+        cap.write_with_checkpoint = false;
+        cap.presign = false;
+        cap.presign_read = false;
+        cap.presign_write = false;
+        cap.presign_stat = false;
+        cap.presign_multipart = false;
+        cap
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        let rp = self.inner.stat(ctx, path, args).await?;
+        let meta = plaintext_metadata(rp.into_metadata(), self.layout)?;
+        Ok(RpStat::new(meta))
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        Ok(EncryptionReader::new(
+            self.inner.clone(),
+            ctx.clone(),
+            path,
+            args,
+            self.master.clone(),
+            self.layout,
+        ))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "append is not supported by EncryptionLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        if args.offset().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "offset is not supported by EncryptionLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        if args.checkpoint().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checkpoint is not supported by EncryptionLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        if !self.inner.capability().write_with_user_metadata {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "EncryptionLayer requires the service to support user metadata",
+            )
+            .with_operation(Operation::Write)
+            .with_context("service", self.inner.info().scheme()));
+        }
+
+        let (key, raw) = DataKey::generate();
+        let mut user_metadata = args.user_metadata().cloned().unwrap_or_default();
+        user_metadata.insert(KEY_METADATA.to_string(), self.master.wrap(&raw)?);
+        user_metadata.insert(
+            SEGMENT_SIZE_METADATA.to_string(),
+            self.layout.segment_size().to_string(),
+        );
+        let args = args.with_user_metadata(user_metadata);
+
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(EncryptionWriter::new(w, key, self.layout))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner
+            .list(ctx, path, args)
+            .map(|inner| EncryptionLister {
+                inner,
+                layout: self.layout,
+            })
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(&self, _: &OperationContext, _: &str, _: OpPresign) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "presign is not supported by EncryptionLayer",
+        )
+        .with_operation(Operation::Presign))
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[doc(hidden)]
+pub struct EncryptionLister {
+    inner: oio::Lister,
+    layout: Layout,
+}
+
+impl oio::List for EncryptionLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let Some(entry) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let (path, meta) = entry.into_parts();
+        // Listing often returns partial metadata, keep entries that can't be mapped as is.
+        let meta = plaintext_metadata(meta.clone(), self.layout).unwrap_or(meta);
+        Ok(Some(oio::Entry::with(path, meta)))
+    }
+}
+
+/// Remove the envelope entries from user metadata.
+fn strip_user_metadata(meta: Metadata) -> Metadata {
+    let Some(user_metadata) = meta.user_metadata() else {
+        return meta;
+    };
+
+    let mut user_metadata = user_metadata.clone();
+    user_metadata.remove(KEY_METADATA);
+    user_metadata.remove(SEGMENT_SIZE_METADATA);
+    meta.with_user_metadata(user_metadata)
+}
+
+/// Rewrite file metadata returned by the service into its plaintext form.
+///
+/// Objects without an envelope in user metadata are not written by this layer
+/// and are returned unchanged.
+fn plaintext_metadata(meta: Metadata, default: Layout) -> Result<Metadata> {
+    if !meta.mode().is_file() {
+        return Ok(meta);
+    }
+    if !meta
+        .user_metadata()
+        .is_some_and(|m| m.contains_key(KEY_METADATA))
+    {
+        return Ok(meta);
+    }
+
+    let layout = Layout::from_user_metadata(meta.user_metadata(), default)?;
+    let content_length = layout.plaintext_len(meta.content_length())?;
+    let mut meta = strip_user_metadata(meta);
+    meta.set_content_length(content_length);
+    Ok(meta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_operator() -> Result<Operator> {
+        Ok(Operator::new(services::Memory::default())?
+            .layer(EncryptionLayer::new([1; 32]).with_segment_size(16)))
+    }
+
+    fn content(size: usize) -> Vec<u8> {
+        (0..size).map(|i| i as u8).collect()
+    }
+
+    #[tokio::test]
+    async fn test_roundtrip() -> Result<()> {
+        let op = new_operator()?;
+
+        for size in [0, 1, 15, 16, 17, 100] {
+            let path = format!("file-{size}");
+            let data = content(size);
+            op.write(&path, data.clone()).await?;
+
+            let meta = op.stat(&path).await?;
+            assert_eq!(meta.content_length(), size as u64);
+            assert!(meta.user_metadata().is_none_or(|m| m.is_empty()));
+
+            assert_eq!(op.read(&path).await?.to_vec(), data);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_range() -> Result<()> {
+        let op = new_operator()?;
+        let data = content(100);
+        let mut w = op.writer("file").await?;
+        for chunk in data.chunks(7) {
+            w.write(chunk.to_vec()).await?;
+        }
+        w.close().await?;
+
+        let reader = op.reader("file").await?;
+        for (start, end) in [(0, 100), (3, 9), (15, 17), (16, 32), (40, 100), (99, 100)] {
+            let buf = reader.read(start as u64..end as u64).await?;
+            assert_eq!(buf.to_vec(), data[start..end], "range {start}..{end}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ciphertext_is_stored() -> Result<()> {
+        let memory = Operator::new(services::Memory::default())?;
+        let op = memory
+            .clone()
+            .layer(EncryptionLayer::new([1; 32]).with_segment_size(16));
+
+        op.write("file", "hello, world").await?;
+        let raw = memory.read("file").await?.to_vec();
+        assert_eq!(raw.len(), 12 + 16);
+        assert!(!raw.windows(5).any(|w| w == b"hello"));
+
+        let other = memory.layer(EncryptionLayer::new([2; 32]));
+        let err = other.read("file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stat_plain_object() -> Result<()> {
+        let memory = Operator::new(services::Memory::default())?;
+        let op = memory
+            .clone()
+            .layer(EncryptionLayer::new([1; 32]).with_segment_size(16));
+
+        memory.write("plain", "hello, world").await?;
+        let meta = op.stat("plain").await?;
+        assert_eq!(meta.content_length(), 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use bytes::Bytes;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::crypto::DataKey;
+use crate::crypto::KEY_METADATA;
+use crate::crypto::Layout;
+use crate::crypto::MasterKey;
+use crate::strip_user_metadata;
+
+/// Everything needed to decrypt one object, loaded from its metadata.
+struct Envelope {
+    key: DataKey,
+    layout: Layout,
+    ciphertext_len: u64,
+    /// Object metadata as seen by users, with the plaintext content length.
+    metadata: Metadata,
+    /// The inner reader, pinned to the etag of the stated object if possible.
+    reader: oio::Reader,
+}
+
+/// Reader that maps plaintext ranges onto sealed segments.
+pub struct EncryptionReader {
+    srv: Servicer,
+    ctx: OperationContext,
+    path: String,
+    args: OpRead,
+    master: Arc<MasterKey>,
+    layout: Layout,
+
+    envelope: OnceLock<Arc<Envelope>>,
+}
+
+impl EncryptionReader {
+    pub(crate) fn new(
+        srv: Servicer,
+        ctx: OperationContext,
+        path: &str,
+        args: OpRead,
+        master: Arc<MasterKey>,
+        layout: Layout,
+    ) -> Self {
+        Self {
+            srv,
+            ctx,
+            path: path.to_string(),
+            args,
+            master,
+            layout,
+            envelope: OnceLock::new(),
+        }
+    }
+
+    async fn envelope(&self) -> Result<Arc<Envelope>> {
+        if let Some(envelope) = self.envelope.get() {
+            return Ok(envelope.clone());
+        }
+
+        let mut op_stat = OpStat::new();
+        if let Some(v) = self.args.version() {
+            op_stat = op_stat.with_version(v);
+        }
+        let meta = self
+            .srv
+            .stat(&self.ctx, &self.path, op_stat)
+            .await?
+            .into_metadata();
+
+        let Some(wrapped) = meta.user_metadata().and_then(|m| m.get(KEY_METADATA)) else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "object doesn't carry an encryption key, it's not written by EncryptionLayer",
+            )
+            .with_context("path", &self.path));
+        };
+        let key = self.master.unwrap(wrapped)?;
+        let layout = Layout::from_user_metadata(meta.user_metadata(), self.layout)?;
+        let ciphertext_len = meta.content_length();
+        let plaintext_len = layout.plaintext_len(ciphertext_len)?;
+
+        // Make sure the segments we read belong to the key we just loaded.
+        let mut args = self.args.clone();
+        if let Some(etag) = meta.etag() {
+            if args.if_match().is_none() && self.srv.capability().read_with_if_match {
+                args = args.with_if_match(etag);
+            }
+        }
+        let reader = self.srv.read(&self.ctx, &self.path, args)?;
+
+        let mut metadata = strip_user_metadata(meta);
+        metadata.set_content_length(plaintext_len);
+
+        let envelope = Arc::new(Envelope {
+            key,
+            layout,
+            ciphertext_len,
+            metadata,
+            reader,
+        });
+        Ok(self.envelope.get_or_init(|| envelope).clone())
+    }
+}
+
+impl oio::Read for EncryptionReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let envelope = self.envelope().await?;
+        let plaintext_len = envelope.metadata.content_length();
+        let rp = RpRead::new(envelope.metadata.clone());
+
+        let (start, end) = match range {
+            BytesRange::Suffix { size } => (plaintext_len.saturating_sub(size), plaintext_len),
+            _ => {
+                let start = range.offset();
+                let end = range
+                    .size()
+                    .map_or(plaintext_len, |size| start.saturating_add(size))
+                    .min(plaintext_len);
+                (start, end)
+            }
+        };
+        if start >= end {
+            return Ok((rp, Box::new(Buffer::new()) as Box<dyn oio::ReadStreamDyn>));
+        }
+
+        let layout = envelope.layout;
+        let first = start / layout.segment_size();
+        let last = (end - 1) / layout.segment_size();
+        let offset = first * layout.sealed_segment_size();
+        let size =
+            ((last + 1) * layout.sealed_segment_size()).min(envelope.ciphertext_len) - offset;
+
+        let (_, inner) = envelope
+            .reader
+            .open(BytesRange::new(offset, Some(size)))
+            .await?;
+        let stream = DecryptStream {
+            inner,
+            envelope: envelope.clone(),
+            segments: layout.segments(plaintext_len),
+            index: first,
+            buffer: oio::QueueBuf::new(),
+            skip: start - first * layout.segment_size(),
+            remaining: end - start,
+        };
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, mut stream) = self.open(range).await?;
+
+        let mut bufs = Vec::new();
+        loop {
+            let buf = stream.read_dyn().await?;
+            if buf.is_empty() {
+                break;
+            }
+            bufs.push(buf.to_bytes());
+        }
+        Ok((rp, Buffer::from(bufs)))
+    }
+}
+
+/// Stream that opens sealed segments one by one.
+struct DecryptStream {
+    inner: Box<dyn oio::ReadStreamDyn>,
+    envelope: Arc<Envelope>,
+    segments: u64,
+
+    /// Index of the next segment to open.
+    index: u64,
+    /// Sealed bytes that don't form a whole segment yet.
+    buffer: oio::QueueBuf,
+    /// Plaintext bytes to drop from the first segment.
+    skip: u64,
+    /// Plaintext bytes left in the requested range.
+    remaining: u64,
+}
+
+impl oio::ReadStream for DecryptStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.remaining == 0 {
+            return Ok(Buffer::new());
+        }
+
+        let layout = self.envelope.layout;
+        let last = self.index + 1 == self.segments;
+        let sealed_size = if last {
+            self.envelope.ciphertext_len - self.index * layout.sealed_segment_size()
+        } else {
+            layout.sealed_segment_size()
+        } as usize;
+
+        while self.buffer.len() < sealed_size {
+            let buf = self.inner.read_dyn().await?;
+            if buf.is_empty() {
+                return Err(
+                    Error::new(ErrorKind::Unexpected, "encrypted segment is truncated")
+                        .with_context("segment", self.index.to_string()),
+                );
+            }
+            self.buffer.push(buf);
+        }
+
+        let mut buf = self.buffer.take().collect();
+        let sealed = buf.split_to(sealed_size);
+        self.buffer.push(buf);
+
+        let plaintext = self
+            .envelope
+            .key
+            .open(self.index, last, &sealed.to_bytes())?;
+        self.index += 1;
+
+        let start = (self.skip as usize).min(plaintext.len());
+        let end = (start + self.remaining as usize).min(plaintext.len());
+        self.skip = 0;
+        self.remaining -= (end - start) as u64;
+        Ok(Buffer::from(Bytes::from(plaintext).slice(start..end)))
+    }
+}
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::crypto::DataKey;
+use crate::crypto::Layout;
+use crate::strip_user_metadata;
+
+/// Writer that seals plaintext segment by segment.
+///
+/// Sealed data is held back until the next write so that the final segment is
+/// sent together with it on close. This keeps the number of writes sent to the
+/// inner writer no larger than the number received.
+pub struct EncryptionWriter {
+    inner: oio::Writer,
+    key: DataKey,
+    layout: Layout,
+
+    /// Plaintext that doesn't fill a segment yet.
+    buffer: oio::QueueBuf,
+    /// Sealed segments that haven't been written yet.
+    pending: Vec<Bytes>,
+    /// Index of the next segment to seal.
+    index: u64,
+    written: u64,
+}
+
+impl EncryptionWriter {
+    pub(crate) fn new(inner: oio::Writer, key: DataKey, layout: Layout) -> Self {
+        Self {
+            inner,
+            key,
+            layout,
+            buffer: oio::QueueBuf::new(),
+            pending: Vec::new(),
+            index: 0,
+            written: 0,
+        }
+    }
+
+    fn seal_next(&mut self, plaintext: &[u8], last: bool) -> Result<()> {
+        let sealed = self.key.seal(self.index, last, plaintext)?;
+        self.pending.push(Bytes::from(sealed));
+        self.index += 1;
+        Ok(())
+    }
+}
+
+impl oio::Write for EncryptionWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.written += bs.len() as u64;
+
+        let pending = std::mem::take(&mut self.pending);
+        self.buffer.push(bs);
+
+        // Keep at least one byte buffered so the final segment is only sealed on close.
+        let segment_size = self.layout.segment_size() as usize;
+        while self.buffer.len() > segment_size {
+            let mut buf = self.buffer.take().collect();
+            let segment = buf.split_to(segment_size);
+            self.buffer.push(buf);
+            self.seal_next(&segment.to_bytes(), false)?;
+        }
+
+        if !pending.is_empty() {
+            self.inner.write(Buffer::from(pending)).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let last = self.buffer.take().collect();
+        self.seal_next(&last.to_bytes(), true)?;
+
+        let pending = std::mem::take(&mut self.pending);
+        self.inner.write(Buffer::from(pending)).await?;
+
+        let mut meta = self.inner.close().await?;
+        meta.set_content_length(self.written);
+        Ok(strip_user_metadata(meta))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        self.pending.clear();
+        self.inner.abort().await
+    }
+}
@@ -242,6 +242,36 @@ impl Service for FastraceAccessor {
         let _guard = Span::enter_with_local_parent(Operation::Presign.into_static());
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let _guard = Span::enter_with_local_parent(Operation::GetAcl.into_static());
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let _guard = Span::enter_with_local_parent(Operation::SetAcl.into_static());
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let _guard = Span::enter_with_local_parent(Operation::Restore.into_static());
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[doc(hidden)]
@@ -318,6 +348,12 @@ impl<R: oio::Write> oio::Write for FastraceWrapper<R> {
         let _span = LocalSpan::enter_with_local_parent(Operation::Write.into_static());
         self.inner.close()
     }
+
+    fn suspend(&mut self) -> impl Future<Output = Result<WriteCheckpoint>> + MaybeSend {
+        let _guard = self.span.set_local_parent();
+        let _span = LocalSpan::enter_with_local_parent(Operation::Write.into_static());
+        self.inner.suspend()
+    }
 }
 
 impl<R: oio::List> oio::List for FastraceWrapper<R> {
@@ -278,8 +278,9 @@ impl Service for FoyerService {
         let inner = self.operation_inner(ctx);
         let size_limit = self.size_limit.clone();
         let path = path.to_string();
+        let resumed = args.checkpoint().is_some();
         let w = inner.srv.write(&inner.ctx, &path, args)?;
-        Ok(Writer::new(w, path, inner, size_limit))
+        Ok(Writer::new(w, path, inner, size_limit, resumed))
     }
 
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
@@ -335,6 +336,33 @@ impl Service for FoyerService {
         self.inner.presign(ctx, path, args).await
     }
 
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+
     // TODO(MrCroxx): Implement copy, rename with foyer cache.
 }
 
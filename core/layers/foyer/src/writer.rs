@@ -20,6 +20,7 @@ use std::sync::Arc;
 use opendal_core::Buffer;
 use opendal_core::Metadata;
 use opendal_core::Result;
+use opendal_core::WriteCheckpoint;
 use opendal_core::raw::oio;
 
 use crate::FoyerKey;
@@ -33,6 +34,9 @@ pub struct Writer<W> {
     pub(crate) inner: Arc<Inner>,
     pub(crate) size_limit: std::ops::Range<usize>,
     pub(crate) skip_cache: bool,
+    /// Resumed writes only see the tail of the content, so they invalidate
+    /// the cached entry instead of filling it.
+    pub(crate) resumed: bool,
 }
 
 impl<W> Writer<W> {
@@ -41,6 +45,7 @@ impl<W> Writer<W> {
         path: String,
         inner: Arc<Inner>,
         size_limit: std::ops::Range<usize>,
+        resumed: bool,
     ) -> Self {
         Self {
             w,
@@ -48,13 +53,17 @@ impl<W> Writer<W> {
             path,
             inner,
             size_limit,
-            skip_cache: false,
+            skip_cache: resumed,
+            resumed,
         }
     }
 }
 
 impl<W: oio::Write> oio::Write for Writer<W> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if self.resumed {
+            return self.w.write(bs).await;
+        }
         if self.size_limit.contains(&(self.buf.len() + bs.len())) {
             self.buf.push(bs.clone());
             self.skip_cache = false;
@@ -68,11 +77,13 @@ impl<W: oio::Write> oio::Write for Writer<W> {
     async fn close(&mut self) -> Result<Metadata> {
         let buffer = self.buf.clone().collect();
         let metadata = self.w.close().await?;
-        if !self.skip_cache {
-            let key = FoyerKey {
-                path: self.path.clone(),
-                version: metadata.version().map(|v| v.to_string()),
-            };
+        let key = FoyerKey {
+            path: self.path.clone(),
+            version: metadata.version().map(|v| v.to_string()),
+        };
+        if self.resumed {
+            self.inner.cache.remove(&key);
+        } else if !self.skip_cache {
             self.inner.cache.insert(key.clone(), FoyerValue(buffer));
             self.inner
                 .deleted_keys
@@ -87,4 +98,9 @@ impl<W: oio::Write> oio::Write for Writer<W> {
         self.buf.clear();
         self.w.abort().await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.buf.clear();
+        self.w.suspend().await
+    }
 }
@@ -0,0 +1,682 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Request hook layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+type StartHook = Arc<dyn Fn(&RequestStart<'_>) + Send + Sync>;
+type EndHook = Arc<dyn Fn(&RequestEnd<'_>) + Send + Sync>;
+
+/// Observe every request sent to the underlying service with callbacks.
+///
+/// This is a lightweight alternative to writing a layer for profilers and custom
+/// instrumentation: register [`HookLayer::on_request_start`] and
+/// [`HookLayer::on_request_end`], and every request reports its service,
+/// operation, path, result, bytes and duration.
+///
+/// # Operation Behavior
+///
+/// - `stat`, `create_dir`, `rename`, `presign`, `get_acl`, `set_acl` and `restore`:
+///   one request per call.
+/// - `read`: one request per call to the underlying reader. A streaming read ends
+///   once the stream is drained, and `bytes` counts the data returned.
+/// - `write` and `copy`: one request from creating the writer or copier until it's
+///   closed or aborted, and `bytes` counts the data written or copied.
+/// - `list`: one request from creating the lister until it returns all entries.
+/// - `delete`: one request per path, which ends once the deleter is closed.
+///
+/// Requests that are dropped before finishing, e.g. a cancelled future or a
+/// partially consumed stream, end with an `Unexpected` error.
+///
+/// # Notes
+///
+/// Hooks are called inline on the request path, so they should be cheap and must
+/// not block. Apply this layer last to observe the requests issued by users, or
+/// before other layers like `RetryLayer` to observe every attempt.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_hook::HookLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(
+///     HookLayer::new()
+///         .on_request_start(|req| println!("start #{} {} {}", req.id, req.operation, req.path))
+///         .on_request_end(|req| {
+///             println!(
+///                 "end #{} {} {}: {} bytes in {:?}, error: {:?}",
+///                 req.id, req.operation, req.path, req.bytes, req.duration, req.error
+///             )
+///         }),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct HookLayer {
+    on_start: Option<StartHook>,
+    on_end: Option<EndHook>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Debug for HookLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookLayer")
+            .field("on_request_start", &self.on_start.is_some())
+            .field("on_request_end", &self.on_end.is_some())
+            .finish()
+    }
+}
+
+impl HookLayer {
+    /// Create a new `HookLayer` without any hooks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the hook called before a request is sent.
+    ///
+    /// Setting it again replaces the previous hook.
+    pub fn on_request_start(
+        mut self,
+        f: impl Fn(&RequestStart<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_start = Some(Arc::new(f));
+        self
+    }
+
+    /// Set the hook called once a request finishes.
+    ///
+    /// Setting it again replaces the previous hook.
+    pub fn on_request_end(mut self, f: impl Fn(&RequestEnd<'_>) + Send + Sync + 'static) -> Self {
+        self.on_end = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Layer for HookLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        let info = inner.info();
+        Arc::new(HookService {
+            inner,
+            hooks: Arc::new(Hooks {
+                scheme: info.scheme(),
+                namespace: info.name(),
+                on_start: self.on_start.clone(),
+                on_end: self.on_end.clone(),
+                next_id: self.next_id.clone(),
+            }),
+        })
+    }
+}
+
+/// A request that is about to be sent, passed to [`HookLayer::on_request_start`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RequestStart<'a> {
+    /// The id to match this request with its [`RequestEnd`], unique within the layer.
+    pub id: u64,
+    /// The scheme of the service, like `s3`.
+    pub scheme: &'static str,
+    /// The namespace of the service, like the bucket name.
+    pub namespace: &'a str,
+    /// The operation of this request.
+    pub operation: Operation,
+    /// The path of this request, or the source path for `copy` and `rename`.
+    pub path: &'a str,
+}
+
+/// A finished request, passed to [`HookLayer::on_request_end`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RequestEnd<'a> {
+    /// The id of this request, same as the one passed to [`RequestStart`].
+    pub id: u64,
+    /// The scheme of the service, like `s3`.
+    pub scheme: &'static str,
+    /// The namespace of the service, like the bucket name.
+    pub namespace: &'a str,
+    /// The operation of this request.
+    pub operation: Operation,
+    /// The path of this request, or the source path for `copy` and `rename`.
+    pub path: &'a str,
+    /// The error of this request, `None` if it succeeded.
+    pub error: Option<&'a Error>,
+    /// The number of bytes read, written or copied by this request.
+    pub bytes: u64,
+    /// The time elapsed since this request started.
+    pub duration: Duration,
+}
+
+struct Hooks {
+    scheme: &'static str,
+    namespace: Arc<str>,
+    on_start: Option<StartHook>,
+    on_end: Option<EndHook>,
+    next_id: Arc<AtomicU64>,
+}
+
+/// A request in flight, which reports its end exactly once.
+struct Request {
+    hooks: Arc<Hooks>,
+    id: u64,
+    operation: Operation,
+    path: String,
+    start: Instant,
+    bytes: u64,
+    done: bool,
+}
+
+impl Request {
+    fn start(hooks: &Arc<Hooks>, operation: Operation, path: &str) -> Self {
+        let id = hooks.next_id.fetch_add(1, Ordering::Relaxed);
+        if let Some(f) = &hooks.on_start {
+            f(&RequestStart {
+                id,
+                scheme: hooks.scheme,
+                namespace: &hooks.namespace,
+                operation,
+                path,
+            });
+        }
+
+        Self {
+            hooks: hooks.clone(),
+            id,
+            operation,
+            path: path.to_string(),
+            start: Instant::now(),
+            bytes: 0,
+            done: false,
+        }
+    }
+
+    fn finish(&mut self, error: Option<&Error>) {
+        if self.done {
+            return;
+        }
+        self.done = true;
+
+        if let Some(f) = &self.hooks.on_end {
+            f(&RequestEnd {
+                id: self.id,
+                scheme: self.hooks.scheme,
+                namespace: &self.hooks.namespace,
+                operation: self.operation,
+                path: &self.path,
+                error,
+                bytes: self.bytes,
+                duration: self.start.elapsed(),
+            });
+        }
+    }
+}
+
+impl Drop for Request {
+    fn drop(&mut self) {
+        if !self.done {
+            let err = Error::new(ErrorKind::Unexpected, "request is dropped before finished");
+            self.finish(Some(&err));
+        }
+    }
+}
+
+/// Service wrapped by [`HookLayer`].
+#[doc(hidden)]
+pub struct HookService {
+    inner: Servicer,
+    hooks: Arc<Hooks>,
+}
+
+impl Debug for HookService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HookService {
+    async fn observe<T>(
+        &self,
+        operation: Operation,
+        path: &str,
+        fut: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let mut req = Request::start(&self.hooks, operation, path);
+        let res = fut.await;
+        req.finish(res.as_ref().err());
+        res
+    }
+
+    /// Start a request that lives as long as the returned wrapper.
+    fn wrap<T>(&self, operation: Operation, path: &str, res: Result<T>) -> Result<HookWrapper<T>> {
+        let mut request = Request::start(&self.hooks, operation, path);
+        match res {
+            Ok(inner) => Ok(HookWrapper { inner, request }),
+            Err(err) => {
+                request.finish(Some(&err));
+                Err(err)
+            }
+        }
+    }
+}
+
+impl Service for HookService {
+    type Reader = HookReader<oio::Reader>;
+    type Writer = HookWrapper<oio::Writer>;
+    type Lister = HookWrapper<oio::Lister>;
+    type Deleter = HookDeleter<oio::Deleter>;
+    type Copier = HookWrapper<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.observe(
+            Operation::CreateDir,
+            path,
+            self.inner.create_dir(ctx, path, args),
+        )
+        .await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.observe(Operation::Stat, path, self.inner.stat(ctx, path, args))
+            .await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.inner.read(ctx, path, args).map(|inner| HookReader {
+            inner,
+            hooks: self.hooks.clone(),
+            path: path.to_string(),
+        })
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.wrap(Operation::Write, path, self.inner.write(ctx, path, args))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx).map(|inner| HookDeleter {
+            inner,
+            hooks: self.hooks.clone(),
+            pending: Vec::new(),
+        })
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.wrap(Operation::List, path, self.inner.list(ctx, path, args))
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.wrap(
+            Operation::Copy,
+            from,
+            self.inner.copy(ctx, from, to, args, opts),
+        )
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.observe(
+            Operation::Rename,
+            from,
+            self.inner.rename(ctx, from, to, args),
+        )
+        .await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.observe(
+            Operation::Presign,
+            path,
+            self.inner.presign(ctx, path, args),
+        )
+        .await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.observe(Operation::GetAcl, path, self.inner.get_acl(ctx, path, args))
+            .await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.observe(Operation::SetAcl, path, self.inner.set_acl(ctx, path, args))
+            .await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.observe(
+            Operation::Restore,
+            path,
+            self.inner.restore(ctx, path, args),
+        )
+        .await
+    }
+}
+
+/// Reader that reports every call as a request.
+#[doc(hidden)]
+pub struct HookReader<R> {
+    inner: R,
+    hooks: Arc<Hooks>,
+    path: String,
+}
+
+impl<R: oio::Read> oio::Read for HookReader<R> {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let mut request = Request::start(&self.hooks, Operation::Read, &self.path);
+        match self.inner.open(range).await {
+            Ok((rp, inner)) => Ok((rp, Box::new(HookWrapper { inner, request }))),
+            Err(err) => {
+                request.finish(Some(&err));
+                Err(err)
+            }
+        }
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let mut request = Request::start(&self.hooks, Operation::Read, &self.path);
+        let res = self.inner.read(range).await;
+        if let Ok((_, buf)) = &res {
+            request.bytes = buf.len() as u64;
+        }
+        request.finish(res.as_ref().err());
+        res
+    }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let mut request = Request::start(&self.hooks, Operation::Read, &self.path);
+        let res = self.inner.read_ranges(ranges).await;
+        if let Ok((_, bufs)) = &res {
+            request.bytes = bufs.iter().map(|buf| buf.len() as u64).sum();
+        }
+        request.finish(res.as_ref().err());
+        res
+    }
+}
+
+/// Wrapper that reports one request over its whole lifetime.
+#[doc(hidden)]
+pub struct HookWrapper<R> {
+    inner: R,
+    request: Request,
+}
+
+impl<R> HookWrapper<R> {
+    /// Finish the request if `res` is the last result of it.
+    fn finish_with<T>(&mut self, res: &Result<T>, last: bool) {
+        match res {
+            Err(err) => self.request.finish(Some(err)),
+            Ok(_) if last => self.request.finish(None),
+            Ok(_) => {}
+        }
+    }
+}
+
+impl<R: oio::ReadStream> oio::ReadStream for HookWrapper<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let res = self.inner.read().await;
+        if let Ok(buf) = &res {
+            self.request.bytes += buf.len() as u64;
+        }
+        let last = matches!(&res, Ok(buf) if buf.is_empty());
+        self.finish_with(&res, last);
+        res
+    }
+}
+
+impl<R: oio::Write> oio::Write for HookWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len() as u64;
+        let res = self.inner.write(bs).await;
+        if res.is_ok() {
+            self.request.bytes += size;
+        }
+        self.finish_with(&res, false);
+        res
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let res = self.inner.close().await;
+        self.finish_with(&res, true);
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let res = self.inner.abort().await;
+        self.finish_with(&res, true);
+        res
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let res = self.inner.suspend().await;
+        self.finish_with(&res, true);
+        res
+    }
+}
+
+impl<R: oio::List> oio::List for HookWrapper<R> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let res = self.inner.next().await;
+        let last = matches!(res, Ok(None));
+        self.finish_with(&res, last);
+        res
+    }
+}
+
+impl<R: oio::Copy> oio::Copy for HookWrapper<R> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        let res = self.inner.next().await;
+        if let Ok(Some(n)) = res {
+            self.request.bytes += n as u64;
+        }
+        self.finish_with(&res, false);
+        res
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let res = self.inner.close().await;
+        self.finish_with(&res, true);
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let res = self.inner.abort().await;
+        self.finish_with(&res, true);
+        res
+    }
+}
+
+/// Deleter that reports every deleted path as a request ending on close.
+#[doc(hidden)]
+pub struct HookDeleter<D> {
+    inner: D,
+    hooks: Arc<Hooks>,
+    pending: Vec<Request>,
+}
+
+impl<D: oio::Delete> oio::Delete for HookDeleter<D> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let mut request = Request::start(&self.hooks, Operation::Delete, path);
+        let res = self.inner.delete(path, args).await;
+        match &res {
+            Ok(()) => self.pending.push(request),
+            Err(err) => request.finish(Some(err)),
+        }
+        res
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let res = self.inner.close().await;
+        for mut request in self.pending.drain(..) {
+            request.finish(res.as_ref().err());
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Start(u64, Operation, String),
+        End(u64, Operation, String, u64, Option<ErrorKind>),
+    }
+
+    fn new_operator() -> (Operator, Arc<Mutex<Vec<Event>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (start, end) = (events.clone(), events.clone());
+        let layer = HookLayer::new()
+            .on_request_start(move |req| {
+                assert_eq!(req.scheme, "memory");
+                start.lock().unwrap().push(Event::Start(
+                    req.id,
+                    req.operation,
+                    req.path.to_string(),
+                ));
+            })
+            .on_request_end(move |req| {
+                end.lock().unwrap().push(Event::End(
+                    req.id,
+                    req.operation,
+                    req.path.to_string(),
+                    req.bytes,
+                    req.error.map(|err| err.kind()),
+                ));
+            });
+
+        let op = Operator::new(Memory::default()).unwrap().layer(layer);
+        (op, events)
+    }
+
+    #[tokio::test]
+    async fn test_observe_requests() {
+        let (op, events) = new_operator();
+
+        op.write("a", "Hello, World!").await.unwrap();
+        op.read("a").await.unwrap();
+        let _ = op.stat("b").await;
+        op.delete("a").await.unwrap();
+
+        let events = events.lock().unwrap();
+        let expected = [
+            Event::Start(0, Operation::Write, "a".to_string()),
+            Event::End(0, Operation::Write, "a".to_string(), 13, None),
+            Event::Start(1, Operation::Read, "a".to_string()),
+            Event::End(1, Operation::Read, "a".to_string(), 13, None),
+            Event::Start(2, Operation::Stat, "b".to_string()),
+            Event::End(
+                2,
+                Operation::Stat,
+                "b".to_string(),
+                0,
+                Some(ErrorKind::NotFound),
+            ),
+            Event::Start(3, Operation::Delete, "a".to_string()),
+            Event::End(3, Operation::Delete, "a".to_string(), 0, None),
+        ];
+        assert_eq!(events.as_slice(), expected.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_observe_dropped_request() {
+        let (op, events) = new_operator();
+
+        let w = op.writer("a").await.unwrap();
+        drop(w);
+
+        let events = events.lock().unwrap();
+        assert_eq!(
+            events.last(),
+            Some(&Event::End(
+                0,
+                Operation::Write,
+                "a".to_string(),
+                0,
+                Some(ErrorKind::Unexpected)
+            ))
+        );
+    }
+}
@@ -35,6 +35,9 @@ const LABEL_READ: &str = "opendal.read";
 const LABEL_RENAME: &str = "opendal.rename";
 const LABEL_STAT: &str = "opendal.stat";
 const LABEL_PRESIGN: &str = "opendal.presign";
+const LABEL_GET_ACL: &str = "opendal.get_acl";
+const LABEL_SET_ACL: &str = "opendal.set_acl";
+const LABEL_RESTORE: &str = "opendal.restore";
 
 const LABEL_READER_READ: &str = "opendal.reader.read";
 const LABEL_WRITER_WRITE: &str = "opendal.writer.write";
@@ -185,6 +188,33 @@ impl Service for HotpathAccessor {
     ) -> Result<RpPresign> {
         hotpath::measure_async(LABEL_PRESIGN, self.inner.presign(ctx, path, args)).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        hotpath::measure_async(LABEL_GET_ACL, self.inner.get_acl(ctx, path, args)).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        hotpath::measure_async(LABEL_SET_ACL, self.inner.set_acl(ctx, path, args)).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        hotpath::measure_async(LABEL_RESTORE, self.inner.restore(ctx, path, args)).await
+    }
 }
 
 #[doc(hidden)]
@@ -230,6 +260,10 @@ impl<R: oio::Write> oio::Write for HotpathWrapper<R> {
     async fn abort(&mut self) -> Result<()> {
         hotpath::measure_async(LABEL_WRITER_ABORT, self.inner.abort()).await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
 }
 
 impl<R: oio::List> oio::List for HotpathWrapper<R> {
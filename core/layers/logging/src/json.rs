@@ -0,0 +1,309 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use log::Level;
+use log::log;
+use opendal_core::raw::*;
+use opendal_core::*;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::LoggingInterceptor;
+
+static ACCESS_LOG_TARGET: &str = "opendal::access";
+
+/// Context keys whose values are emitted as JSON numbers.
+const NUMERIC_KEYS: &[&str] = &[
+    "duration_us",
+    "size",
+    "read",
+    "written",
+    "listed",
+    "deleted",
+    "copied",
+];
+
+/// JsonLoggingInterceptor emits one JSON record per finished operation.
+///
+/// Only terminal events (`finished`, `failed`, `close succeeded`, ...) are
+/// emitted, so every record describes one completed access. A record looks
+/// like:
+///
+/// ```json
+/// {"service":"s3","name":"bucket","operation":"read","message":"finished","path":"a/b","range":"0-1023","read":1024,"size":0,"duration_us":5301}
+/// ```
+///
+/// Failed operations additionally carry `error_kind` and `error`.
+///
+/// Records are logged at `Info` level under the `opendal::access` target by
+/// default; errors use `Warn`, or `Error` for unexpected errors.
+///
+/// # Sampling
+///
+/// Successes and errors are sampled separately. A rate of `0.01` emits
+/// exactly one out of every hundred records, spread evenly. Clones share
+/// their sampling state.
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_logging::JsonLoggingInterceptor;
+/// # use opendal_layer_logging::LoggingLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let interceptor = JsonLoggingInterceptor::default()
+///     .with_success_sample_rate(0.01)
+///     .with_error_sample_rate(1.0);
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(LoggingLayer::new(interceptor));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsonLoggingInterceptor {
+    target: String,
+    success_sample_rate: f64,
+    error_sample_rate: f64,
+
+    successes: Arc<AtomicU64>,
+    errors: Arc<AtomicU64>,
+}
+
+impl Default for JsonLoggingInterceptor {
+    fn default() -> Self {
+        Self {
+            target: ACCESS_LOG_TARGET.to_string(),
+            success_sample_rate: 1.0,
+            error_sample_rate: 1.0,
+
+            successes: Arc::new(AtomicU64::new(0)),
+            errors: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+impl JsonLoggingInterceptor {
+    /// Set the log target of the records.
+    ///
+    /// Default to `opendal::access`.
+    pub fn with_target(mut self, target: &str) -> Self {
+        self.target = target.to_string();
+        self
+    }
+
+    /// Set the fraction of successful operations to log.
+    ///
+    /// Default to `1.0`, which logs every success.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not within `0.0..=1.0`.
+    pub fn with_success_sample_rate(mut self, rate: f64) -> Self {
+        assert!((0.0..=1.0).contains(&rate), "rate must be within 0.0..=1.0");
+        self.success_sample_rate = rate;
+        self
+    }
+
+    /// Set the fraction of failed operations to log.
+    ///
+    /// Default to `1.0`, which logs every error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not within `0.0..=1.0`.
+    pub fn with_error_sample_rate(mut self, rate: f64) -> Self {
+        assert!((0.0..=1.0).contains(&rate), "rate must be within 0.0..=1.0");
+        self.error_sample_rate = rate;
+        self
+    }
+
+    /// Build the record for this event, or `None` if it should be skipped.
+    fn record(
+        &self,
+        info: &ServiceInfo,
+        operation: Operation,
+        context: &[(&str, &str)],
+        message: &str,
+        err: Option<&Error>,
+    ) -> Option<String> {
+        if message == "started" || message.starts_with("created ") {
+            return None;
+        }
+
+        let sampled = match err {
+            Some(_) => sample(&self.errors, self.error_sample_rate),
+            None => sample(&self.successes, self.success_sample_rate),
+        };
+        if !sampled {
+            return None;
+        }
+
+        let mut record = Map::new();
+        record.insert("service".to_string(), info.scheme().into());
+        record.insert("name".to_string(), info.name().as_ref().into());
+        record.insert("operation".to_string(), operation.into_static().into());
+        record.insert("message".to_string(), message.into());
+        for (k, v) in context {
+            if v.is_empty() {
+                continue;
+            }
+            let value = match v.parse::<u64>() {
+                Ok(n) if NUMERIC_KEYS.contains(k) => Value::from(n),
+                _ => Value::from(*v),
+            };
+            record.insert(k.to_string(), value);
+        }
+        if let Some(err) = err {
+            record.insert("error_kind".to_string(), err.kind().into_static().into());
+            record.insert("error".to_string(), err.to_string().into());
+        }
+
+        Some(Value::Object(record).to_string())
+    }
+}
+
+impl LoggingInterceptor for JsonLoggingInterceptor {
+    fn log(
+        &self,
+        info: &ServiceInfo,
+        operation: Operation,
+        context: &[(&str, &str)],
+        message: &str,
+        err: Option<&Error>,
+    ) {
+        let Some(record) = self.record(info, operation, context, message, err) else {
+            return;
+        };
+
+        let lvl = match err {
+            Some(err) if err.kind() == ErrorKind::Unexpected => Level::Error,
+            Some(_) => Level::Warn,
+            None => Level::Info,
+        };
+        log!(target: &self.target, lvl, "{record}");
+    }
+}
+
+/// Decide whether the next event passes the sampling `rate`.
+///
+/// The `n`-th event is kept when `n * rate` crosses an integer, which keeps
+/// exactly `rate` of the events without any randomness.
+fn sample(counter: &AtomicU64, rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    if rate <= 0.0 {
+        return false;
+    }
+
+    let n = counter.fetch_add(1, Ordering::Relaxed);
+    ((n + 1) as f64 * rate).floor() > (n as f64 * rate).floor()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> ServiceInfo {
+        ServiceInfo::new("memory", "/", "test")
+    }
+
+    #[test]
+    fn test_record() {
+        let interceptor = JsonLoggingInterceptor::default();
+
+        let record = interceptor
+            .record(
+                &info(),
+                Operation::Read,
+                &[
+                    ("path", "a/b"),
+                    ("range", "0-1023"),
+                    ("read", "1024"),
+                    ("duration_us", "42"),
+                ],
+                "finished",
+                None,
+            )
+            .unwrap();
+        let record: Value = serde_json::from_str(&record).unwrap();
+        assert_eq!(record["operation"], "read");
+        assert_eq!(record["path"], "a/b");
+        assert_eq!(record["range"], "0-1023");
+        assert_eq!(record["read"], 1024);
+        assert_eq!(record["duration_us"], 42);
+        assert!(record.get("error_kind").is_none());
+
+        let err = Error::new(ErrorKind::NotFound, "not found");
+        let record = interceptor
+            .record(
+                &info(),
+                Operation::Stat,
+                &[("path", "123")],
+                "failed",
+                Some(&err),
+            )
+            .unwrap();
+        let record: Value = serde_json::from_str(&record).unwrap();
+        assert_eq!(record["path"], "123");
+        assert_eq!(record["error_kind"], "NotFound");
+
+        assert!(
+            interceptor
+                .record(&info(), Operation::Stat, &[], "started", None)
+                .is_none()
+        );
+        assert!(
+            interceptor
+                .record(&info(), Operation::Read, &[], "created reader", None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_sampling() {
+        let interceptor = JsonLoggingInterceptor::default()
+            .with_success_sample_rate(0.01)
+            .with_error_sample_rate(1.0);
+        let err = Error::new(ErrorKind::Unexpected, "boom");
+
+        let mut successes = 0;
+        let mut errors = 0;
+        for _ in 0..1000 {
+            let cloned = interceptor.clone();
+            if cloned
+                .record(&info(), Operation::Stat, &[], "finished", None)
+                .is_some()
+            {
+                successes += 1;
+            }
+            if cloned
+                .record(&info(), Operation::Stat, &[], "failed", Some(&err))
+                .is_some()
+            {
+                errors += 1;
+            }
+        }
+        assert_eq!(successes, 10);
+        assert_eq!(errors, 1000);
+    }
+}
@@ -29,6 +29,9 @@ use log::log;
 use opendal_core::raw::*;
 use opendal_core::*;
 
+mod json;
+pub use json::JsonLoggingInterceptor;
+
 static LOGGING_TARGET: &str = "opendal::services";
 
 /// Add [log](https://docs.rs/log/) for every operation.
@@ -75,6 +78,11 @@ static LOGGING_TARGET: &str = "opendal::services";
 /// RUST_LOG="info,opendal::services=debug" ./app
 /// ```
 ///
+/// # Structured Logging
+///
+/// Use [`JsonLoggingInterceptor`] to emit one JSON record per finished
+/// operation, with separate sampling rates for successes and errors.
+///
 /// # Logging Interceptor
 ///
 /// You can implement your own logging interceptor to customize the logging behavior.
@@ -223,6 +231,10 @@ impl LoggingInterceptor for DefaultLoggingInterceptor {
     }
 }
 
+fn elapsed_micros(start: Instant) -> String {
+    start.elapsed().as_micros().to_string()
+}
+
 struct LoggingContext<'a>(&'a [(&'a str, &'a str)]);
 
 impl Display for LoggingContext<'_> {
@@ -255,9 +267,18 @@ impl<I: LoggingInterceptor> LoggingService<I> {
         self.logger.log(&self.info, op, context, "started", None);
     }
 
-    fn log_finish(&self, op: Operation, context: &[(&str, &str)], err: Option<&Error>) {
+    fn log_finish(
+        &self,
+        op: Operation,
+        start: Instant,
+        context: &[(&str, &str)],
+        err: Option<&Error>,
+    ) {
         let message = if err.is_some() { "failed" } else { "finished" };
-        self.logger.log(&self.info, op, context, message, err);
+        let duration = elapsed_micros(start);
+        let mut context = context.to_vec();
+        context.push(("duration_us", &duration));
+        self.logger.log(&self.info, op, &context, message, err);
     }
 }
 
@@ -283,9 +304,11 @@ impl<I: LoggingInterceptor> Service for LoggingService<I> {
         args: OpCreateDir,
     ) -> Result<RpCreateDir> {
         self.log_start(Operation::CreateDir, &[("path", path)]);
+        let start = Instant::now();
         let result = self.inner.create_dir(ctx, path, args).await;
         self.log_finish(
             Operation::CreateDir,
+            start,
             &[("path", path)],
             result.as_ref().err(),
         );
@@ -344,8 +367,14 @@ impl<I: LoggingInterceptor> Service for LoggingService<I> {
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
         self.log_start(Operation::Stat, &[("path", path)]);
+        let start = Instant::now();
         let result = self.inner.stat(ctx, path, args).await;
-        self.log_finish(Operation::Stat, &[("path", path)], result.as_ref().err());
+        self.log_finish(
+            Operation::Stat,
+            start,
+            &[("path", path)],
+            result.as_ref().err(),
+        );
         result
     }
 
@@ -355,7 +384,7 @@ impl<I: LoggingInterceptor> Service for LoggingService<I> {
             .delete(ctx)
             .map(|d| {
                 self.logger
-                    .log(&self.info, Operation::Delete, &[], "finished", None);
+                    .log(&self.info, Operation::Delete, &[], "created deleter", None);
                 LoggingDeleter::new(self.info.clone(), self.logger.clone(), d)
             })
             .inspect_err(|err| {
@@ -404,9 +433,11 @@ impl<I: LoggingInterceptor> Service for LoggingService<I> {
         args: OpRename,
     ) -> Result<RpRename> {
         self.log_start(Operation::Rename, &[("from", from), ("to", to)]);
+        let start = Instant::now();
         let result = self.inner.rename(ctx, from, to, args).await;
         self.log_finish(
             Operation::Rename,
+            start,
             &[("from", from), ("to", to)],
             result.as_ref().err(),
         );
@@ -445,8 +476,68 @@ impl<I: LoggingInterceptor> Service for LoggingService<I> {
         args: OpPresign,
     ) -> Result<RpPresign> {
         self.log_start(Operation::Presign, &[("path", path)]);
+        let start = Instant::now();
         let result = self.inner.presign(ctx, path, args).await;
-        self.log_finish(Operation::Presign, &[("path", path)], result.as_ref().err());
+        self.log_finish(
+            Operation::Presign,
+            start,
+            &[("path", path)],
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.log_start(Operation::GetAcl, &[("path", path)]);
+        let start = Instant::now();
+        let result = self.inner.get_acl(ctx, path, args).await;
+        self.log_finish(
+            Operation::GetAcl,
+            start,
+            &[("path", path)],
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.log_start(Operation::SetAcl, &[("path", path)]);
+        let start = Instant::now();
+        let result = self.inner.set_acl(ctx, path, args).await;
+        self.log_finish(
+            Operation::SetAcl,
+            start,
+            &[("path", path)],
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.log_start(Operation::Restore, &[("path", path)]);
+        let start = Instant::now();
+        let result = self.inner.restore(ctx, path, args).await;
+        self.log_finish(
+            Operation::Restore,
+            start,
+            &[("path", path)],
+            result.as_ref().err(),
+        );
         result
     }
 }
@@ -458,6 +549,7 @@ pub struct LoggingReader<R, I: LoggingInterceptor> {
     path: String,
     range: Option<BytesRange>,
 
+    start: Instant,
     read: u64,
     inner: R,
 }
@@ -480,6 +572,7 @@ impl<R, I: LoggingInterceptor> LoggingReader<R, I> {
             path: path.to_string(),
             range,
 
+            start: Instant::now(),
             read: 0,
             inner: reader,
         }
@@ -505,6 +598,7 @@ impl<R: oio::ReadStream, I: LoggingInterceptor> oio::ReadStream for LoggingReade
                         ("range", &range),
                         ("read", &self.read.to_string()),
                         ("size", &bs.len().to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "finished",
                     None,
@@ -524,6 +618,7 @@ impl<R: oio::ReadStream, I: LoggingInterceptor> oio::ReadStream for LoggingReade
                         ("path", &self.path),
                         ("range", &range),
                         ("read", &self.read.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "failed",
                     Some(&err),
@@ -561,6 +656,7 @@ impl<R: oio::Read, I: LoggingInterceptor> oio::Read for LoggingReader<R, I> {
     }
 
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let start = Instant::now();
         match self.inner.read(range).await {
             Ok((rp, buffer)) => {
                 self.logger.log(
@@ -570,6 +666,7 @@ impl<R: oio::Read, I: LoggingInterceptor> oio::Read for LoggingReader<R, I> {
                         ("path", &self.path),
                         ("range", &range.to_string()),
                         ("size", &buffer.len().to_string()),
+                        ("duration_us", &elapsed_micros(start)),
                     ],
                     "finished",
                     None,
@@ -580,7 +677,11 @@ impl<R: oio::Read, I: LoggingInterceptor> oio::Read for LoggingReader<R, I> {
                 self.logger.log(
                     &self.info,
                     Operation::Read,
-                    &[("path", &self.path), ("range", &range.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("range", &range.to_string()),
+                        ("duration_us", &elapsed_micros(start)),
+                    ],
                     "failed",
                     Some(&err),
                 );
@@ -596,6 +697,7 @@ pub struct LoggingWriter<W, I> {
     logger: I,
     path: String,
 
+    start: Instant,
     written: u64,
     inner: W,
 }
@@ -607,6 +709,7 @@ impl<W, I> LoggingWriter<W, I> {
             logger,
             path: path.to_string(),
 
+            start: Instant::now(),
             written: 0,
             inner: writer,
         }
@@ -630,6 +733,7 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
                         ("path", &self.path),
                         ("written", &self.written.to_string()),
                         ("size", &size.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "failed",
                     Some(&err),
@@ -645,7 +749,11 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
                 self.logger.log(
                     &self.info,
                     Operation::Write,
-                    &[("path", &self.path), ("written", &self.written.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "abort succeeded",
                     None,
                 );
@@ -655,7 +763,11 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
                 self.logger.log(
                     &self.info,
                     Operation::Write,
-                    &[("path", &self.path), ("written", &self.written.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "abort failed",
                     Some(&err),
                 );
@@ -670,7 +782,11 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
                 self.logger.log(
                     &self.info,
                     Operation::Write,
-                    &[("path", &self.path), ("written", &self.written.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "close succeeded",
                     None,
                 );
@@ -680,7 +796,11 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
                 self.logger.log(
                     &self.info,
                     Operation::Write,
-                    &[("path", &self.path), ("written", &self.written.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "close failed",
                     Some(&err),
                 );
@@ -688,6 +808,39 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
             }
         }
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        match self.inner.suspend().await {
+            Ok(checkpoint) => {
+                self.logger.log(
+                    &self.info,
+                    Operation::Write,
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
+                    "suspend succeeded",
+                    None,
+                );
+                Ok(checkpoint)
+            }
+            Err(err) => {
+                self.logger.log(
+                    &self.info,
+                    Operation::Write,
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
+                    "suspend failed",
+                    Some(&err),
+                );
+                Err(err)
+            }
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -696,6 +849,7 @@ pub struct LoggingLister<P, I: LoggingInterceptor> {
     logger: I,
     path: String,
 
+    start: Instant,
     listed: usize,
     inner: P,
 }
@@ -707,6 +861,7 @@ impl<P, I: LoggingInterceptor> LoggingLister<P, I> {
             logger,
             path: path.to_string(),
 
+            start: Instant::now(),
             listed: 0,
             inner,
         }
@@ -725,7 +880,11 @@ impl<P: oio::List, I: LoggingInterceptor> oio::List for LoggingLister<P, I> {
                 self.logger.log(
                     &self.info,
                     Operation::List,
-                    &[("path", &self.path), ("listed", &self.listed.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("listed", &self.listed.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "finished",
                     None,
                 );
@@ -734,7 +893,11 @@ impl<P: oio::List, I: LoggingInterceptor> oio::List for LoggingLister<P, I> {
                 self.logger.log(
                     &self.info,
                     Operation::List,
-                    &[("path", &self.path), ("listed", &self.listed.to_string())],
+                    &[
+                        ("path", &self.path),
+                        ("listed", &self.listed.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "failed",
                     Some(err),
                 );
@@ -750,6 +913,7 @@ pub struct LoggingDeleter<D, I: LoggingInterceptor> {
     info: ServiceInfo,
     logger: I,
 
+    start: Instant,
     deleted: usize,
     inner: D,
 }
@@ -760,6 +924,7 @@ impl<D, I: LoggingInterceptor> LoggingDeleter<D, I> {
             info,
             logger,
 
+            start: Instant::now(),
             deleted: 0,
             inner,
         }
@@ -805,7 +970,10 @@ impl<D: oio::Delete, I: LoggingInterceptor> oio::Delete for LoggingDeleter<D, I>
                 self.logger.log(
                     &self.info,
                     Operation::Delete,
-                    &[("deleted", &self.deleted.to_string())],
+                    &[
+                        ("deleted", &self.deleted.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "succeeded",
                     None,
                 );
@@ -814,7 +982,10 @@ impl<D: oio::Delete, I: LoggingInterceptor> oio::Delete for LoggingDeleter<D, I>
                 self.logger.log(
                     &self.info,
                     Operation::Delete,
-                    &[("deleted", &self.deleted.to_string())],
+                    &[
+                        ("deleted", &self.deleted.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
+                    ],
                     "failed",
                     Some(err),
                 );
@@ -832,6 +1003,7 @@ pub struct LoggingCopier<C, I: LoggingInterceptor> {
     from: String,
     to: String,
 
+    start: Instant,
     copied: u64,
     inner: C,
 }
@@ -844,6 +1016,7 @@ impl<C, I: LoggingInterceptor> LoggingCopier<C, I> {
             from: from.to_string(),
             to: to.to_string(),
 
+            start: Instant::now(),
             copied: 0,
             inner,
         }
@@ -865,6 +1038,7 @@ impl<C: oio::Copy, I: LoggingInterceptor> oio::Copy for LoggingCopier<C, I> {
                         ("from", &self.from),
                         ("to", &self.to),
                         ("copied", &self.copied.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "finished",
                     None,
@@ -879,6 +1053,7 @@ impl<C: oio::Copy, I: LoggingInterceptor> oio::Copy for LoggingCopier<C, I> {
                         ("from", &self.from),
                         ("to", &self.to),
                         ("copied", &self.copied.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "failed",
                     Some(&err),
@@ -902,6 +1077,7 @@ impl<C: oio::Copy, I: LoggingInterceptor> oio::Copy for LoggingCopier<C, I> {
                         ("from", &self.from),
                         ("to", &self.to),
                         ("copied", &self.copied.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "abort succeeded",
                     None,
@@ -916,6 +1092,7 @@ impl<C: oio::Copy, I: LoggingInterceptor> oio::Copy for LoggingCopier<C, I> {
                         ("from", &self.from),
                         ("to", &self.to),
                         ("copied", &self.copied.to_string()),
+                        ("duration_us", &elapsed_micros(self.start)),
                     ],
                     "abort failed",
                     Some(&err),
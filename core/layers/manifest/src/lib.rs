@@ -0,0 +1,350 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Signed integrity manifest layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod manifest;
+mod reader;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub use manifest::Manifest;
+pub use manifest::ManifestEntry;
+pub use reader::ManifestReader;
+
+use crate::manifest::normalize_prefix;
+use crate::manifest::tampered;
+
+/// Verify files under a prefix against a [`Manifest`] while reading them.
+///
+/// [`Manifest::build`] records the size and SHA-256 hash of every file under a
+/// prefix, and [`Manifest::sign`] signs the merkle root of all entries. The
+/// manifest can be distributed along with the data, and verified by
+/// [`Manifest::verify_signature`] before use.
+///
+/// # Operation Behavior
+///
+/// - `read`: files under the prefix are hashed while streaming. Once a read that
+///   covers the whole file reaches the end, an error is returned if the content
+///   doesn't match its entry. Ranged reads are passed through without
+///   verification. Reading a file that is not listed in the manifest returns an
+///   error.
+/// - Other operations are passed through to the underlying service.
+///
+/// To check a tree that is already downloaded, use [`Manifest::verify`] instead,
+/// which also reports files that are missing or not listed.
+///
+/// # Notes
+///
+/// The manifest is signed with HMAC-SHA256, so the key must be shared with, and
+/// kept secret by, everyone verifying the data.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_manifest::Manifest;
+/// # use opendal_layer_manifest::ManifestLayer;
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut manifest = Manifest::build(&op, "datasets/train/").await?;
+/// manifest.sign(b"secret");
+///
+/// // Later, on the consumer side.
+/// manifest.verify_signature(b"secret")?;
+/// let op = op.layer(ManifestLayer::new(manifest).with_prefix("datasets/train/"));
+/// let bs = op.read("datasets/train/part-0000.parquet").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ManifestLayer {
+    manifest: Arc<Manifest>,
+    prefix: String,
+}
+
+impl Debug for ManifestLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManifestLayer")
+            .field("root", &self.manifest.root)
+            .field("entries", &self.manifest.entries.len())
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+impl ManifestLayer {
+    /// Create a new `ManifestLayer` that verifies reads against given manifest.
+    pub fn new(manifest: Manifest) -> Self {
+        Self {
+            manifest: Arc::new(manifest),
+            prefix: String::new(),
+        }
+    }
+
+    /// Set the prefix that the manifest is rooted at, relative to the operator root.
+    ///
+    /// Default to the operator root.
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = normalize_prefix(prefix);
+        self
+    }
+}
+
+impl Layer for ManifestLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(ManifestService {
+            inner,
+            manifest: self.manifest.clone(),
+            prefix: self.prefix.clone(),
+        })
+    }
+}
+
+/// Service wrapped by [`ManifestLayer`].
+pub struct ManifestService {
+    inner: Servicer,
+    manifest: Arc<Manifest>,
+    prefix: String,
+}
+
+impl Debug for ManifestService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManifestService")
+            .field("inner", &self.inner)
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for ManifestService {
+    type Reader = oio::Reader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let Some(rel) = path.strip_prefix(&self.prefix) else {
+            return self.inner.read(ctx, path, args);
+        };
+        let Some(entry) = self.manifest.entries.get(rel) else {
+            return Err(tampered("file is not listed in manifest")
+                .with_operation(Operation::Read)
+                .with_context("path", path));
+        };
+
+        let r = self.inner.read(ctx, path, args)?;
+        Ok(Box::new(ManifestReader::new(r, path, entry.clone())))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    async fn new_operator() -> Operator {
+        let op = Operator::new(Memory::default()).unwrap();
+        op.write("data/a", "hello").await.unwrap();
+        op.write("data/sub/b", "world").await.unwrap();
+        op.write("other", "ignored").await.unwrap();
+        op
+    }
+
+    #[tokio::test]
+    async fn test_build_and_sign() {
+        let op = new_operator().await;
+        let mut manifest = Manifest::build(&op, "data").await.unwrap();
+        assert_eq!(
+            manifest.entries.keys().collect::<Vec<_>>(),
+            vec!["a", "sub/b"]
+        );
+        assert_eq!(manifest.entries["a"].size, 5);
+
+        assert!(manifest.verify_signature(b"key").is_err());
+        manifest.sign(b"key");
+        manifest.verify_signature(b"key").unwrap();
+        assert!(manifest.verify_signature(b"other").is_err());
+
+        // Round trip through serde keeps the signature valid.
+        let bs = serde_json::to_vec(&manifest).unwrap();
+        let decoded: Manifest = serde_json::from_slice(&bs).unwrap();
+        decoded.verify_signature(b"key").unwrap();
+
+        // Modified entries no longer match the signed root.
+        let mut modified = decoded.clone();
+        modified.entries.get_mut("a").unwrap().size = 6;
+        let err = modified.verify_signature(b"key").unwrap_err();
+        assert!(err.to_string().contains("don't match its root"));
+
+        modified.root = modified.compute_root();
+        let err = modified.verify_signature(b"key").unwrap_err();
+        assert!(err.to_string().contains("doesn't match its signature"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_tree() {
+        let op = new_operator().await;
+        let manifest = Manifest::build(&op, "data/").await.unwrap();
+
+        // A copy of the tree at another prefix is verified by relative paths.
+        op.write("copy/a", "hello").await.unwrap();
+        op.write("copy/sub/b", "world").await.unwrap();
+        manifest.verify(&op, "copy/").await.unwrap();
+
+        op.write("copy/sub/b", "WORLD").await.unwrap();
+        let err = manifest.verify(&op, "copy/").await.unwrap_err();
+        assert!(err.to_string().contains("content doesn't match"));
+
+        op.delete("copy/sub/b").await.unwrap();
+        let err = manifest.verify(&op, "copy/").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        op.write("copy/sub/b", "world").await.unwrap();
+        op.write("copy/c", "extra").await.unwrap();
+        let err = manifest.verify(&op, "copy/").await.unwrap_err();
+        assert!(err.to_string().contains("not listed"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_while_reading() {
+        let op = new_operator().await;
+        let manifest = Manifest::build(&op, "data/").await.unwrap();
+        let layered = op
+            .clone()
+            .layer(ManifestLayer::new(manifest).with_prefix("data/"));
+
+        assert_eq!(layered.read("data/a").await.unwrap().to_vec(), b"hello");
+        assert_eq!(layered.read("other").await.unwrap().to_vec(), b"ignored");
+
+        op.write("data/a", "HELLO").await.unwrap();
+        let err = layered.read("data/a").await.unwrap_err();
+        assert!(err.to_string().contains("content doesn't match"));
+        // Ranged reads are not verified.
+        let bs = layered.read_with("data/a").range(1..3).await.unwrap();
+        assert_eq!(bs.to_vec(), b"EL");
+
+        op.write("data/a", "hello, world").await.unwrap();
+        let err = layered.read("data/a").await.unwrap_err();
+        assert!(err.to_string().contains("size doesn't match"));
+
+        op.write("data/c", "new").await.unwrap();
+        let err = layered.read("data/c").await.unwrap_err();
+        assert!(err.to_string().contains("not listed"));
+    }
+}
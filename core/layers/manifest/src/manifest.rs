@@ -0,0 +1,336 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use futures::TryStreamExt;
+use hmac::Hmac;
+use hmac::KeyInit;
+use hmac::Mac;
+use opendal_core::*;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The only hash algorithm supported for now.
+const ALGORITHM: &str = "sha256";
+
+/// Domain separators of merkle tree nodes, so a leaf can't be taken as a branch.
+const LEAF_PREFIX: u8 = 0x00;
+const BRANCH_PREFIX: u8 = 0x01;
+
+/// Integrity manifest of all files under a prefix.
+///
+/// Files are recorded by their paths relative to the prefix, so a tree can be
+/// verified after it has been downloaded to another place. All entries are
+/// covered by `root`, a merkle root built in path order, and `signature` signs
+/// the root with HMAC-SHA256.
+///
+/// The manifest is serializable by serde, users can store it alongside the
+/// data in any format they prefer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The hash algorithm, always `sha256` for now.
+    pub algorithm: String,
+    /// Files keyed by their paths relative to the prefix.
+    pub entries: BTreeMap<String, ManifestEntry>,
+    /// Hex encoded merkle root over all entries.
+    pub root: String,
+    /// Hex encoded HMAC-SHA256 of `root`, if the manifest has been signed.
+    pub signature: Option<String>,
+}
+
+/// A file recorded in [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// The size of the file.
+    pub size: u64,
+    /// Hex encoded hash of the file content.
+    pub hash: String,
+}
+
+impl Manifest {
+    /// Create an unsigned manifest from given entries.
+    pub fn new(entries: BTreeMap<String, ManifestEntry>) -> Self {
+        let root = merkle_root(&entries);
+        Self {
+            algorithm: ALGORITHM.to_string(),
+            entries,
+            root,
+            signature: None,
+        }
+    }
+
+    /// Build an unsigned manifest of all files under `prefix`.
+    ///
+    /// Every file is read once and hashed while streaming, so files of any size
+    /// can be recorded without buffering them in memory.
+    pub async fn build(op: &Operator, prefix: &str) -> Result<Self> {
+        let prefix = normalize_prefix(prefix);
+
+        let mut entries = BTreeMap::new();
+        for entry in op.list_with(list_path(&prefix)).recursive(true).await? {
+            if !entry.metadata().is_file() {
+                continue;
+            }
+            let path = entry.path();
+            let Some(rel) = path.strip_prefix(&prefix) else {
+                continue;
+            };
+            let (size, hash) = hash_file(op, path).await?;
+            entries.insert(rel.to_string(), ManifestEntry { size, hash });
+        }
+
+        Ok(Self::new(entries))
+    }
+
+    /// Compute the merkle root of current entries.
+    pub fn compute_root(&self) -> String {
+        merkle_root(&self.entries)
+    }
+
+    /// Sign the manifest with given key.
+    pub fn sign(&mut self, key: &[u8]) {
+        self.root = self.compute_root();
+        self.signature = Some(hex(&sign(key, &self.root)));
+    }
+
+    /// Verify that the manifest is signed by given key and its entries are
+    /// not modified since then.
+    pub fn verify_signature(&self, key: &[u8]) -> Result<()> {
+        self.check_root()?;
+
+        let Some(signature) = &self.signature else {
+            return Err(tampered("manifest is not signed"));
+        };
+        let mut mac = new_mac(key);
+        mac.update(self.root.as_bytes());
+        if mac.verify_slice(&unhex(signature)?).is_err() {
+            return Err(tampered("manifest doesn't match its signature"));
+        }
+        Ok(())
+    }
+
+    /// Verify all files under `prefix` against the manifest.
+    ///
+    /// Returns an error pointing to the first file that is missing, not listed
+    /// in the manifest, or doesn't match its entry.
+    ///
+    /// This doesn't check the signature, use [`Manifest::verify_signature`] before
+    /// trusting the manifest.
+    pub async fn verify(&self, op: &Operator, prefix: &str) -> Result<()> {
+        self.check_root()?;
+        let prefix = normalize_prefix(prefix);
+
+        let mut seen = BTreeSet::new();
+        for entry in op.list_with(list_path(&prefix)).recursive(true).await? {
+            if !entry.metadata().is_file() {
+                continue;
+            }
+            let path = entry.path();
+            let Some(rel) = path.strip_prefix(&prefix) else {
+                continue;
+            };
+            let Some(expected) = self.entries.get(rel) else {
+                return Err(tampered("file is not listed in manifest").with_context("path", path));
+            };
+
+            let (size, hash) = hash_file(op, path).await?;
+            check_entry(path, expected, size, &hash)?;
+            seen.insert(rel.to_string());
+        }
+
+        if let Some(path) = self.entries.keys().find(|p| !seen.contains(*p)) {
+            return Err(
+                Error::new(ErrorKind::NotFound, "file in manifest is missing")
+                    .with_operation("Manifest::verify")
+                    .with_context("path", format!("{prefix}{path}")),
+            );
+        }
+        Ok(())
+    }
+
+    fn check_root(&self) -> Result<()> {
+        if self.algorithm != ALGORITHM {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "manifest hash algorithm is not supported",
+            )
+            .with_context("algorithm", &self.algorithm));
+        }
+        if self.compute_root() != self.root {
+            return Err(tampered("manifest entries don't match its root"));
+        }
+        Ok(())
+    }
+}
+
+/// Hash the content of a file while streaming, so verification can be done in
+/// the same pass as reading.
+pub(crate) struct Hasher {
+    hasher: Sha256,
+    size: u64,
+}
+
+impl Hasher {
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, buf: &Buffer) {
+        self.size += buf.len() as u64;
+        for bs in buf.clone() {
+            self.hasher.update(&bs);
+        }
+    }
+
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the size and hex encoded hash of all data.
+    pub(crate) fn finish(self) -> (u64, String) {
+        (self.size, hex(&self.hasher.finalize()))
+    }
+}
+
+/// Check the size and hash of a file against its entry.
+pub(crate) fn check_entry(
+    path: &str,
+    expected: &ManifestEntry,
+    size: u64,
+    hash: &str,
+) -> Result<()> {
+    if size != expected.size {
+        return Err(tampered("file size doesn't match manifest")
+            .with_context("path", path)
+            .with_context("expected", expected.size)
+            .with_context("actual", size));
+    }
+    if hash != expected.hash {
+        return Err(tampered("file content doesn't match manifest")
+            .with_context("path", path)
+            .with_context("expected", &expected.hash)
+            .with_context("actual", hash));
+    }
+    Ok(())
+}
+
+/// Normalize the prefix into `""` for root or `dir/` otherwise.
+pub(crate) fn normalize_prefix(prefix: &str) -> String {
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        String::new()
+    } else {
+        format!("{prefix}/")
+    }
+}
+
+fn list_path(prefix: &str) -> &str {
+    if prefix.is_empty() { "/" } else { prefix }
+}
+
+pub(crate) fn tampered(msg: &str) -> Error {
+    Error::new(ErrorKind::Unexpected, msg.to_string())
+}
+
+async fn hash_file(op: &Operator, path: &str) -> Result<(u64, String)> {
+    let mut stream = op.reader(path).await?.into_stream(..).await?;
+    let mut hasher = Hasher::new();
+    while let Some(buf) = stream.try_next().await? {
+        hasher.update(&buf);
+    }
+    Ok(hasher.finish())
+}
+
+fn merkle_root(entries: &BTreeMap<String, ManifestEntry>) -> String {
+    let mut level: Vec<[u8; 32]> = entries
+        .iter()
+        .map(|(path, entry)| {
+            let mut h = Sha256::new();
+            h.update([LEAF_PREFIX]);
+            h.update((path.len() as u64).to_be_bytes());
+            h.update(path.as_bytes());
+            h.update(entry.size.to_be_bytes());
+            h.update(entry.hash.as_bytes());
+            h.finalize().into()
+        })
+        .collect();
+    if level.is_empty() {
+        return hex(&Sha256::digest([]));
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => {
+                    let mut h = Sha256::new();
+                    h.update([BRANCH_PREFIX]);
+                    h.update(left);
+                    h.update(right);
+                    h.finalize().into()
+                }
+                // The odd node is promoted to the next level as is.
+                [node] => *node,
+                _ => unreachable!("chunks must not be empty"),
+            })
+            .collect();
+    }
+    hex(&level[0])
+}
+
+fn new_mac(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key).expect("hmac accepts keys of any size")
+}
+
+fn sign(key: &[u8], root: &str) -> Vec<u8> {
+    let mut mac = new_mac(key);
+    mac.update(root.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bs: &[u8]) -> String {
+    let mut s = String::with_capacity(bs.len() * 2);
+    for b in bs {
+        let _ = write!(s, "{b:02x}");
+    }
+    s
+}
+
+fn unhex(s: &str) -> Result<Vec<u8>> {
+    let invalid = || tampered("manifest signature is not valid hex");
+    if s.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            s.get(i..i + 2)
+                .and_then(|v| u8::from_str_radix(v, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
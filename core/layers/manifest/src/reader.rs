@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::ManifestEntry;
+use crate::manifest::Hasher;
+use crate::manifest::check_entry;
+use crate::manifest::tampered;
+
+/// Reader that verifies the content against its manifest entry.
+///
+/// Only reads that cover the whole file are verified, other ranges are passed
+/// through as is.
+pub struct ManifestReader {
+    inner: oio::Reader,
+    path: String,
+    entry: ManifestEntry,
+}
+
+impl ManifestReader {
+    pub(crate) fn new(inner: oio::Reader, path: &str, entry: ManifestEntry) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            entry,
+        }
+    }
+
+    fn covers(&self, range: BytesRange) -> bool {
+        !range.is_suffix()
+            && range.offset() == 0
+            && range.size().is_none_or(|size| size == self.entry.size)
+    }
+
+    fn verifier(&self) -> Verifier {
+        Verifier {
+            path: self.path.clone(),
+            entry: self.entry.clone(),
+            hasher: Some(Hasher::new()),
+        }
+    }
+}
+
+impl oio::Read for ManifestReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let (rp, stream) = self.inner.open(range).await?;
+        if !self.covers(range) {
+            return Ok((rp, stream));
+        }
+
+        let stream = ManifestStream {
+            inner: stream,
+            verifier: self.verifier(),
+        };
+        Ok((rp, Box::new(stream)))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, buffer) = self.inner.read(range).await?;
+        if self.covers(range) {
+            let mut verifier = self.verifier();
+            verifier.update(&buffer)?;
+            verifier.finish()?;
+        }
+        Ok((rp, buffer))
+    }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        self.inner.read_ranges(ranges).await
+    }
+}
+
+/// Stream that hashes data while reading and verifies it at the end.
+struct ManifestStream {
+    inner: Box<dyn oio::ReadStreamDyn>,
+    verifier: Verifier,
+}
+
+impl oio::ReadStream for ManifestStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        let buf = self.inner.read().await?;
+        if buf.is_empty() {
+            self.verifier.finish()?;
+        } else {
+            self.verifier.update(&buf)?;
+        }
+        Ok(buf)
+    }
+}
+
+struct Verifier {
+    path: String,
+    entry: ManifestEntry,
+    /// `None` once the content has been verified.
+    hasher: Option<Hasher>,
+}
+
+impl Verifier {
+    fn update(&mut self, buf: &Buffer) -> Result<()> {
+        let Some(hasher) = &mut self.hasher else {
+            return Ok(());
+        };
+        hasher.update(buf);
+
+        // Fail early instead of hashing data that can't match.
+        if hasher.size() > self.entry.size {
+            return Err(tampered("file size doesn't match manifest")
+                .with_operation(Operation::Read)
+                .with_context("path", &self.path)
+                .with_context("expected", self.entry.size));
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        let Some(hasher) = self.hasher.take() else {
+            return Ok(());
+        };
+        let (size, hash) = hasher.finish();
+        check_entry(&self.path, &self.entry, size, &hash)
+            .map_err(|err| err.with_operation(Operation::Read))
+    }
+}
@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use opendal_core::Metadata;
+use opendal_core::raw::oio;
+
+struct CacheEntry<T> {
+    value: T,
+    expires_at: Instant,
+}
+
+/// In memory cache of stat and list results.
+///
+/// Every invalidation bumps the generation, results fetched before an
+/// invalidation are dropped instead of being cached.
+pub(crate) struct MetaCache {
+    ttl: Duration,
+    negative_ttl: Option<Duration>,
+    capacity: usize,
+    generation: u64,
+
+    /// `None` means the path is known to be not found.
+    stats: HashMap<String, CacheEntry<Option<Metadata>>>,
+    /// Keyed by listed path and whether the list is recursive.
+    lists: HashMap<(String, bool), CacheEntry<Arc<Vec<oio::Entry>>>>,
+}
+
+impl MetaCache {
+    pub(crate) fn new(ttl: Duration, negative_ttl: Option<Duration>, capacity: usize) -> Self {
+        Self {
+            ttl,
+            negative_ttl,
+            capacity,
+            generation: 0,
+            stats: HashMap::new(),
+            lists: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Get the cached stat of path.
+    ///
+    /// Returns `Some(None)` if the path is cached as not found.
+    pub(crate) fn get_stat(&self, path: &str) -> Option<Option<Metadata>> {
+        self.stats
+            .get(path)
+            .filter(|v| v.expires_at > Instant::now())
+            .map(|v| v.value.clone())
+    }
+
+    pub(crate) fn put_stat(&mut self, generation: u64, path: &str, meta: Option<Metadata>) {
+        let ttl = match (&meta, self.negative_ttl) {
+            (Some(_), _) => self.ttl,
+            (None, Some(ttl)) => ttl,
+            (None, None) => return,
+        };
+        if generation != self.generation || !self.reserve() {
+            return;
+        }
+
+        self.stats.insert(
+            path.to_string(),
+            CacheEntry {
+                value: meta,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    pub(crate) fn get_list(&self, path: &str, recursive: bool) -> Option<Arc<Vec<oio::Entry>>> {
+        self.lists
+            .get(&(path.to_string(), recursive))
+            .filter(|v| v.expires_at > Instant::now())
+            .map(|v| v.value.clone())
+    }
+
+    pub(crate) fn put_list(
+        &mut self,
+        generation: u64,
+        path: &str,
+        recursive: bool,
+        entries: Vec<oio::Entry>,
+    ) {
+        if generation != self.generation || !self.reserve() {
+            return;
+        }
+
+        self.lists.insert(
+            (path.to_string(), recursive),
+            CacheEntry {
+                value: Arc::new(entries),
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+    }
+
+    /// Forget everything that could have changed by writing or deleting path.
+    ///
+    /// This includes the path itself, the stat of its parent dirs and the
+    /// lists of its parent dirs. If path is a dir, everything under it is
+    /// forgotten as well.
+    pub(crate) fn invalidate(&mut self, path: &str) {
+        self.generation += 1;
+
+        let is_dir = path.ends_with('/');
+        let affects = |key: &str| {
+            key == path
+                || key == "/"
+                || (key.ends_with('/') && path.starts_with(key))
+                || (is_dir && key.starts_with(path))
+        };
+        self.stats.retain(|k, _| !affects(k));
+        self.lists.retain(|(k, _), _| !affects(k));
+    }
+
+    /// Make room for a new entry, returns false if the cache is still full.
+    fn reserve(&mut self) -> bool {
+        if self.stats.len() + self.lists.len() < self.capacity {
+            return true;
+        }
+
+        let now = Instant::now();
+        self.stats.retain(|_, v| v.expires_at > now);
+        self.lists.retain(|_, v| v.expires_at > now);
+        self.stats.len() + self.lists.len() < self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::EntryMode;
+
+    use super::*;
+
+    fn file() -> Option<Metadata> {
+        Some(Metadata::new(EntryMode::FILE))
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let mut cache = MetaCache::new(Duration::from_secs(60), None, 100);
+        let g = cache.generation();
+        for path in ["a/b/c", "a/b/", "a/", "a/x", "d"] {
+            cache.put_stat(g, path, file());
+        }
+        cache.put_list(g, "/", false, vec![]);
+        cache.put_list(g, "a/", true, vec![]);
+        cache.put_list(g, "d/", false, vec![]);
+
+        cache.invalidate("a/b/c");
+        for path in ["a/b/c", "a/b/", "a/"] {
+            assert!(cache.get_stat(path).is_none(), "{path} must be invalidated");
+        }
+        assert!(cache.get_stat("a/x").is_some());
+        assert!(cache.get_stat("d").is_some());
+        assert!(cache.get_list("/", false).is_none());
+        assert!(cache.get_list("a/", true).is_none());
+        assert!(cache.get_list("d/", false).is_some());
+
+        let g = cache.generation();
+        cache.put_stat(g, "a/b/c", file());
+        cache.invalidate("a/");
+        assert!(cache.get_stat("a/b/c").is_none());
+        assert!(cache.get_stat("a/x").is_none());
+    }
+
+    #[test]
+    fn test_drop_stale_results() {
+        let mut cache = MetaCache::new(Duration::from_secs(60), None, 100);
+        let g = cache.generation();
+        cache.invalidate("a");
+        cache.put_stat(g, "a", file());
+        assert!(cache.get_stat("a").is_none());
+    }
+
+    #[test]
+    fn test_negative_cache() {
+        let mut cache = MetaCache::new(Duration::from_secs(60), None, 100);
+        cache.put_stat(cache.generation(), "a", None);
+        assert!(cache.get_stat("a").is_none());
+
+        let mut cache = MetaCache::new(Duration::from_secs(60), Some(Duration::from_secs(1)), 100);
+        cache.put_stat(cache.generation(), "a", None);
+        assert_eq!(cache.get_stat("a"), Some(None));
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut cache = MetaCache::new(Duration::from_secs(60), None, 2);
+        let g = cache.generation();
+        cache.put_stat(g, "a", file());
+        cache.put_stat(g, "b", file());
+        cache.put_stat(g, "c", file());
+        assert!(cache.get_stat("a").is_some());
+        assert!(cache.get_stat("c").is_none());
+    }
+}
@@ -0,0 +1,53 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Metadata;
+use opendal_core::Result;
+use opendal_core::raw::oio;
+
+use crate::MetaCacheCore;
+
+/// Copier that invalidates cached metadata of the target once the copy is done.
+pub struct MetaCacheCopier<C> {
+    c: C,
+    to: String,
+    core: Arc<MetaCacheCore>,
+}
+
+impl<C> MetaCacheCopier<C> {
+    pub(crate) fn new(c: C, to: String, core: Arc<MetaCacheCore>) -> Self {
+        Self { c, to, core }
+    }
+}
+
+impl<C: oio::Copy> oio::Copy for MetaCacheCopier<C> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        self.c.next().await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let res = self.c.close().await;
+        self.core.invalidate(&[&self.to]);
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.c.abort().await
+    }
+}
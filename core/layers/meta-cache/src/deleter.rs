@@ -0,0 +1,58 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Result;
+use opendal_core::raw::OpDelete;
+use opendal_core::raw::oio;
+
+use crate::MetaCacheCore;
+
+/// Deleter that invalidates cached metadata of deleted paths.
+pub struct MetaCacheDeleter<D> {
+    deleter: D,
+    paths: Vec<String>,
+    core: Arc<MetaCacheCore>,
+}
+
+impl<D> MetaCacheDeleter<D> {
+    pub(crate) fn new(deleter: D, core: Arc<MetaCacheCore>) -> Self {
+        Self {
+            deleter,
+            paths: vec![],
+            core,
+        }
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for MetaCacheDeleter<D> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.deleter.delete(path, args).await?;
+        self.paths.push(path.to_string());
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        // Deletes may have been applied even if close fails, invalidate anyway.
+        let res = self.deleter.close().await;
+        let paths: Vec<&str> = self.paths.iter().map(String::as_str).collect();
+        self.core.invalidate(&paths);
+        self.paths.clear();
+        res
+    }
+}
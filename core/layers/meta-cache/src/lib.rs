@@ -0,0 +1,444 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Metadata cache layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod cache;
+mod copier;
+mod deleter;
+mod lister;
+mod writer;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::Duration;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::cache::MetaCache;
+
+pub use copier::MetaCacheCopier;
+pub use deleter::MetaCacheDeleter;
+pub use lister::MetaCacheLister;
+pub use writer::MetaCacheWriter;
+
+/// Metadata cache layer that caches `stat` and `list` results in memory.
+///
+/// # Operation Behavior
+///
+/// - `stat`: results are cached for [`MetaCacheLayer::with_ttl`]. `NotFound` errors
+///   are only cached if [`MetaCacheLayer::with_negative_ttl`] is set.
+/// - `list`: entries are cached once the lister has been fully consumed, later
+///   lists of the same path are served from the cache.
+/// - `write`, `delete`, `copy`, `rename` and `create_dir`: cached results of the
+///   affected paths, their parent dirs and the lists of their parent dirs are
+///   invalidated once the operation is done.
+/// - Other operations are passed through to the underlying service.
+///
+/// Stats carrying conditions, response overrides or versions, and lists with
+/// `start_after`, filters, versions or deleted entries bypass the cache.
+///
+/// Only changes made through the same operator are seen, changes made by others
+/// become visible once the cached results expire. Once the cache holds
+/// [`MetaCacheLayer::with_capacity`] results, new results are not cached until
+/// old ones expire.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_meta_cache::MetaCacheLayer;
+/// # fn main() -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?.layer(
+///     MetaCacheLayer::new()
+///         .with_ttl(Duration::from_secs(30))
+///         .with_negative_ttl(Duration::from_secs(5)),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MetaCacheLayer {
+    ttl: Duration,
+    negative_ttl: Option<Duration>,
+    capacity: usize,
+}
+
+impl Default for MetaCacheLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetaCacheLayer {
+    /// Create a new `MetaCacheLayer`.
+    ///
+    /// By default results are cached for 60 seconds, up to 10000 results, and
+    /// `NotFound` is not cached.
+    pub fn new() -> Self {
+        Self {
+            ttl: Duration::from_secs(60),
+            negative_ttl: None,
+            capacity: 10_000,
+        }
+    }
+
+    /// Set how long stat and list results stay cached.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Cache `NotFound` results of stat for the given duration.
+    pub fn with_negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the max number of cached stat and list results.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl Layer for MetaCacheLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(MetaCacheService {
+            inner,
+            core: Arc::new(MetaCacheCore {
+                cache: Mutex::new(MetaCache::new(self.ttl, self.negative_ttl, self.capacity)),
+            }),
+        })
+    }
+}
+
+pub(crate) struct MetaCacheCore {
+    cache: Mutex<MetaCache>,
+}
+
+impl MetaCacheCore {
+    pub(crate) fn cache(&self) -> MutexGuard<'_, MetaCache> {
+        self.cache.lock().expect("meta cache lock poisoned")
+    }
+
+    pub(crate) fn invalidate(&self, paths: &[&str]) {
+        let mut cache = self.cache();
+        for path in paths {
+            cache.invalidate(path);
+        }
+    }
+}
+
+fn is_cacheable_stat(args: &OpStat) -> bool {
+    args.if_match().is_none()
+        && args.if_none_match().is_none()
+        && args.if_modified_since().is_none()
+        && args.if_unmodified_since().is_none()
+        && args.override_content_type().is_none()
+        && args.override_cache_control().is_none()
+        && args.override_content_disposition().is_none()
+        && args.version().is_none()
+}
+
+fn is_cacheable_list(args: &OpList) -> bool {
+    args.start_after().is_none()
+        && !args.versions()
+        && !args.deleted()
+        && args.version().is_none()
+        && args.suffix().is_none()
+        && args.glob().is_none()
+        && args.regex().is_none()
+        && !args.watch()
+}
+
+/// Service wrapped by [`MetaCacheLayer`].
+pub struct MetaCacheService {
+    inner: Servicer,
+    core: Arc<MetaCacheCore>,
+}
+
+impl Debug for MetaCacheService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetaCacheService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Service for MetaCacheService {
+    type Reader = oio::Reader;
+    type Writer = MetaCacheWriter<oio::Writer>;
+    type Lister = MetaCacheLister<oio::Lister>;
+    type Deleter = MetaCacheDeleter<oio::Deleter>;
+    type Copier = MetaCacheCopier<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        let rp = self.inner.create_dir(ctx, path, args).await?;
+        self.core.invalidate(&[path]);
+        Ok(rp)
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable_stat(&args) {
+            return self.inner.stat(ctx, path, args).await;
+        }
+
+        let generation = {
+            let cache = self.core.cache();
+            match cache.get_stat(path) {
+                Some(Some(meta)) => return Ok(RpStat::new(meta)),
+                Some(None) => {
+                    return Err(Error::new(ErrorKind::NotFound, "path not found")
+                        .with_context("cached", "true"));
+                }
+                None => cache.generation(),
+            }
+        };
+
+        match self.inner.stat(ctx, path, args).await {
+            Ok(rp) => {
+                let meta = rp.into_metadata();
+                self.core
+                    .cache()
+                    .put_stat(generation, path, Some(meta.clone()));
+                Ok(RpStat::new(meta))
+            }
+            Err(err) => {
+                if err.kind() == ErrorKind::NotFound {
+                    self.core.cache().put_stat(generation, path, None);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(MetaCacheWriter::new(w, path.to_string(), self.core.clone()))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let d = self.inner.delete(ctx)?;
+        Ok(MetaCacheDeleter::new(d, self.core.clone()))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let recursive = args.recursive();
+        let cacheable = is_cacheable_list(&args);
+
+        let generation = {
+            let cache = self.core.cache();
+            if cacheable {
+                if let Some(entries) = cache.get_list(path, recursive) {
+                    return Ok(MetaCacheLister::cached(entries));
+                }
+            }
+            cache.generation()
+        };
+
+        let lister = self.inner.list(ctx, path, args)?;
+        Ok(MetaCacheLister::fetch(
+            lister,
+            cacheable,
+            self.core.clone(),
+            path.to_string(),
+            recursive,
+            generation,
+        ))
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let c = self.inner.copy(ctx, from, to, args, opts)?;
+        Ok(MetaCacheCopier::new(c, to.to_string(), self.core.clone()))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        let res = self.inner.rename(ctx, from, to, args).await;
+        self.core.invalidate(&[from, to]);
+        res
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        // Restore status is part of the metadata.
+        let res = self.inner.restore(ctx, path, args).await;
+        self.core.invalidate(&[path]);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+    use opendal_testkit::CountLayer;
+
+    use super::*;
+
+    fn new_operator(layer: MetaCacheLayer) -> (Operator, CountLayer) {
+        let count = CountLayer::new();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(count.clone())
+            .layer(layer);
+        (op, count)
+    }
+
+    #[tokio::test]
+    async fn test_cache_stat() {
+        let (op, count) = new_operator(MetaCacheLayer::new());
+        op.write("a", "hello").await.unwrap();
+
+        assert_eq!(op.stat("a").await.unwrap().content_length(), 5);
+        assert_eq!(op.stat("a").await.unwrap().content_length(), 5);
+        assert_eq!(count.stats(), 1);
+
+        op.write("a", "hello, world").await.unwrap();
+        assert_eq!(op.stat("a").await.unwrap().content_length(), 12);
+        assert_eq!(count.stats(), 2);
+
+        op.delete("a").await.unwrap();
+        assert!(op.stat("a").await.is_err());
+        assert_eq!(count.stats(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_negative_cache() {
+        let (op, count) = new_operator(MetaCacheLayer::new());
+        assert!(op.stat("a").await.is_err());
+        assert!(op.stat("a").await.is_err());
+        assert_eq!(count.stats(), 2);
+
+        let (op, count) =
+            new_operator(MetaCacheLayer::new().with_negative_ttl(Duration::from_secs(60)));
+        for _ in 0..2 {
+            let err = op.stat("a").await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+        }
+        assert_eq!(count.stats(), 1);
+
+        op.write("a", "hello").await.unwrap();
+        assert!(op.stat("a").await.is_ok());
+        assert_eq!(count.stats(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expire_by_ttl() {
+        let (op, count) = new_operator(MetaCacheLayer::new().with_ttl(Duration::from_millis(50)));
+        op.write("a", "hello").await.unwrap();
+
+        op.stat("a").await.unwrap();
+        op.stat("a").await.unwrap();
+        assert_eq!(count.stats(), 1);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        op.stat("a").await.unwrap();
+        assert_eq!(count.stats(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_list() {
+        let (op, count) = new_operator(MetaCacheLayer::new());
+        op.write("dir/a", "hello").await.unwrap();
+        op.write("dir/b", "world").await.unwrap();
+
+        let names = |entries: Vec<Entry>| {
+            let mut names: Vec<_> = entries.into_iter().map(|e| e.name().to_string()).collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(names(op.list("dir/").await.unwrap()), vec!["a", "b"]);
+        assert_eq!(names(op.list("dir/").await.unwrap()), vec!["a", "b"]);
+        assert_eq!(count.lists(), 1);
+
+        op.write("dir/c", "!").await.unwrap();
+        assert_eq!(names(op.list("dir/").await.unwrap()), vec!["a", "b", "c"]);
+        assert_eq!(count.lists(), 2);
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Result;
+use opendal_core::raw::oio;
+
+use crate::MetaCacheCore;
+
+/// Lister that serves cached entries, or caches the entries of the underlying
+/// lister once it's fully consumed.
+pub struct MetaCacheLister<L> {
+    state: State<L>,
+}
+
+enum State<L> {
+    Cached {
+        entries: Arc<Vec<oio::Entry>>,
+        idx: usize,
+    },
+    Fetch {
+        lister: L,
+        /// Entries returned so far, `None` if they won't be cached.
+        entries: Option<Vec<oio::Entry>>,
+        core: Arc<MetaCacheCore>,
+        path: String,
+        recursive: bool,
+        /// The cache generation at the start of the list.
+        generation: u64,
+    },
+}
+
+impl<L> MetaCacheLister<L> {
+    pub(crate) fn cached(entries: Arc<Vec<oio::Entry>>) -> Self {
+        Self {
+            state: State::Cached { entries, idx: 0 },
+        }
+    }
+
+    pub(crate) fn fetch(
+        lister: L,
+        cacheable: bool,
+        core: Arc<MetaCacheCore>,
+        path: String,
+        recursive: bool,
+        generation: u64,
+    ) -> Self {
+        Self {
+            state: State::Fetch {
+                lister,
+                entries: cacheable.then(Vec::new),
+                core,
+                path,
+                recursive,
+                generation,
+            },
+        }
+    }
+}
+
+impl<L: oio::List> oio::List for MetaCacheLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        match &mut self.state {
+            State::Cached { entries, idx } => {
+                let entry = entries.get(*idx).cloned();
+                *idx += 1;
+                Ok(entry)
+            }
+            State::Fetch {
+                lister,
+                entries,
+                core,
+                path,
+                recursive,
+                generation,
+            } => {
+                let res = lister.next().await;
+                match &res {
+                    Ok(Some(entry)) => {
+                        if let Some(entries) = entries {
+                            entries.push(entry.clone());
+                        }
+                    }
+                    Ok(None) => {
+                        if let Some(entries) = entries.take() {
+                            core.cache()
+                                .put_list(*generation, path, *recursive, entries);
+                        }
+                    }
+                    // A partial list must not be cached.
+                    Err(_) => *entries = None,
+                }
+                res
+            }
+        }
+    }
+}
@@ -0,0 +1,810 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Mirror layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::join_all;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Replicate writes to mirror operators.
+///
+/// The inner service is the primary, every mirror receives the same mutations:
+///
+/// - `write`, `delete`, `create_dir`, `rename` and `copy` are sent to the
+///   primary and all mirrors concurrently.
+/// - `read`, `stat`, `list`, `presign` and acl operations are served by the
+///   primary only.
+///
+/// Whether a mirrored operation succeeds is decided by [`MirrorConsistency`].
+/// Failed targets are reported in the error context as `failed`, and logged
+/// as warnings if the operation still succeeds. Changes that have been applied
+/// to other targets are not rolled back.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_core::services;
+/// # use opendal_layer_mirror::MirrorConsistency;
+/// # use opendal_layer_mirror::MirrorLayer;
+/// # fn test(dr: Operator) -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?.layer(
+///     MirrorLayer::default()
+///         .with_mirror(dr)
+///         .with_consistency(MirrorConsistency::All),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MirrorLayer {
+    mirrors: Vec<MirrorTarget>,
+    consistency: MirrorConsistency,
+}
+
+impl MirrorLayer {
+    /// Add a mirror operator that receives all mutations.
+    pub fn with_mirror(mut self, op: Operator) -> Self {
+        let (ctx, srv) = op.into_parts();
+        let name = format!("mirror[{}]", self.mirrors.len());
+        self.mirrors.push(MirrorTarget { name, srv, ctx });
+        self
+    }
+
+    /// Set the consistency of mirrored operations.
+    ///
+    /// Default to [`MirrorConsistency::All`].
+    pub fn with_consistency(mut self, consistency: MirrorConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
+}
+
+/// Consistency of mirrored operations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MirrorConsistency {
+    /// The operation succeeds only if the primary and all mirrors succeed.
+    #[default]
+    All,
+    /// The operation succeeds if a majority of the primary and mirrors succeed.
+    ///
+    /// Reads are still served by the primary, so objects written without the
+    /// primary are not visible until the primary is repaired.
+    Quorum,
+}
+
+impl MirrorConsistency {
+    fn required(&self, total: usize) -> usize {
+        match self {
+            MirrorConsistency::All => total,
+            MirrorConsistency::Quorum => total / 2 + 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MirrorTarget {
+    name: String,
+    srv: Servicer,
+    ctx: OperationContext,
+}
+
+impl Layer for MirrorLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(MirrorService {
+            inner,
+            mirrors: self.mirrors.clone(),
+            consistency: self.consistency,
+        })
+    }
+}
+
+/// Service that replicates mutations to mirror operators.
+pub struct MirrorService {
+    inner: Servicer,
+    mirrors: Vec<MirrorTarget>,
+    consistency: MirrorConsistency,
+}
+
+impl Debug for MirrorService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorService")
+            .field("inner", &self.inner)
+            .field("mirrors", &self.mirrors.len())
+            .field("consistency", &self.consistency)
+            .finish()
+    }
+}
+
+impl MirrorService {
+    fn targets(&self, ctx: &OperationContext) -> Vec<MirrorTarget> {
+        let primary = MirrorTarget {
+            name: "primary".to_string(),
+            srv: self.inner.clone(),
+            ctx: ctx.clone(),
+        };
+        std::iter::once(primary)
+            .chain(self.mirrors.iter().cloned())
+            .collect()
+    }
+
+    async fn fan_out<T, F, Fut>(&self, ctx: &OperationContext, op: Operation, f: F) -> Result<T>
+    where
+        F: Fn(MirrorTarget) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let targets = self.targets(ctx);
+        let names = targets.iter().map(|t| t.name.clone()).collect::<Vec<_>>();
+        let results = join_all(targets.into_iter().map(f)).await;
+
+        let mut outcome = MirrorOutcome::new(self.consistency, op, names.len());
+        for (name, res) in names.into_iter().zip(results) {
+            outcome.record(name, res);
+        }
+        outcome.finish()
+    }
+
+    /// Build the mirrored object of every target, failed targets are kept to
+    /// be reported later.
+    fn build_slots<T>(
+        &self,
+        ctx: &OperationContext,
+        op: Operation,
+        f: impl Fn(&MirrorTarget) -> Result<T>,
+    ) -> Result<Vec<MirrorSlot<T>>> {
+        let slots = self
+            .targets(ctx)
+            .iter()
+            .map(|target| MirrorSlot::new(target.name.clone(), f(target)))
+            .collect::<Vec<_>>();
+        check_slots(self.consistency, op, &slots)?;
+        Ok(slots)
+    }
+}
+
+impl Service for MirrorService {
+    type Reader = oio::Reader;
+    type Writer = MirrorWrapper<oio::Writer>;
+    type Lister = oio::Lister;
+    type Deleter = MirrorWrapper<oio::Deleter>;
+    type Copier = MirrorWrapper<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        let mut cap = self.inner.capability();
+        // Every target holds its own upload, they can't share one checkpoint.
+        cap.write_with_checkpoint = false;
+        cap
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.fan_out(ctx, Operation::CreateDir, |target| {
+            let args = args.clone();
+            async move { target.srv.create_dir(&target.ctx, path, args).await }
+        })
+        .await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        if args.checkpoint().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checkpoint is not supported by MirrorLayer",
+            )
+            .with_operation(Operation::Write));
+        }
+        let slots = self.build_slots(ctx, Operation::Write, |target| {
+            target.srv.write(&target.ctx, path, args.clone())
+        })?;
+        Ok(MirrorWrapper::new(
+            self.consistency,
+            Operation::Write,
+            slots,
+        ))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let slots = self.build_slots(ctx, Operation::Delete, |target| {
+            target.srv.delete(&target.ctx)
+        })?;
+        Ok(MirrorWrapper::new(
+            self.consistency,
+            Operation::Delete,
+            slots,
+        ))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let slots = self.build_slots(ctx, Operation::Copy, |target| {
+            target
+                .srv
+                .copy(&target.ctx, from, to, args.clone(), opts.clone())
+        })?;
+        Ok(MirrorWrapper::new(self.consistency, Operation::Copy, slots))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.fan_out(ctx, Operation::Rename, |target| {
+            let args = args.clone();
+            async move { target.srv.rename(&target.ctx, from, to, args).await }
+        })
+        .await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+/// Collects the results of a mirrored operation and decides whether it
+/// succeeds under the consistency.
+struct MirrorOutcome<T> {
+    consistency: MirrorConsistency,
+    op: Operation,
+    total: usize,
+    value: Option<T>,
+    succeeded: usize,
+    failed: Vec<(String, Error)>,
+}
+
+impl<T> MirrorOutcome<T> {
+    fn new(consistency: MirrorConsistency, op: Operation, total: usize) -> Self {
+        Self {
+            consistency,
+            op,
+            total,
+            value: None,
+            succeeded: 0,
+            failed: vec![],
+        }
+    }
+
+    fn record(&mut self, name: String, res: Result<T>) {
+        match res {
+            Ok(v) => {
+                self.succeeded += 1;
+                // Targets are recorded in order, so the primary's value is preferred.
+                if self.value.is_none() {
+                    self.value = Some(v);
+                }
+            }
+            Err(err) => self.failed.push((name, err)),
+        }
+    }
+
+    fn finish(self) -> Result<T> {
+        let required = self.consistency.required(self.total);
+        if self.succeeded >= required {
+            if !self.failed.is_empty() {
+                log::warn!(
+                    "mirrored {} succeeded on {} of {} targets, failed: {}",
+                    self.op,
+                    self.succeeded,
+                    self.total,
+                    format_failed(&self.failed)
+                );
+            }
+            return Ok(self.value.expect("succeeded target must have value"));
+        }
+
+        Err(new_mirror_error(
+            self.op,
+            self.succeeded,
+            self.total,
+            required,
+            self.failed,
+        ))
+    }
+}
+
+fn format_failed(failed: &[(String, Error)]) -> String {
+    failed
+        .iter()
+        .map(|(name, err)| format!("{name}: {err}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn new_mirror_error(
+    op: Operation,
+    succeeded: usize,
+    total: usize,
+    required: usize,
+    failed: Vec<(String, Error)>,
+) -> Error {
+    let kind = failed
+        .first()
+        .map(|(_, err)| err.kind())
+        .unwrap_or(ErrorKind::Unexpected);
+    let temporary = failed.iter().all(|(_, err)| err.is_temporary());
+
+    let mut err = Error::new(
+        kind,
+        format!("mirrored {op} succeeded on {succeeded} of {total} targets, {required} required"),
+    )
+    .with_operation(op.into_static());
+    for (name, e) in &failed {
+        err = err.with_context("failed", format!("{name}: {e}"));
+    }
+    if let Some((_, e)) = failed.into_iter().next() {
+        err = err.set_source(e);
+    }
+    if temporary {
+        err = err.set_temporary();
+    }
+    err
+}
+
+/// The mirrored object of a target, `error` is set once it failed.
+struct MirrorSlot<T> {
+    name: String,
+    inner: Option<T>,
+    error: Option<Error>,
+    /// Whether the copy of this target has completed.
+    done: bool,
+}
+
+impl<T> MirrorSlot<T> {
+    fn new(name: String, res: Result<T>) -> Self {
+        match res {
+            Ok(inner) => Self {
+                name,
+                inner: Some(inner),
+                error: None,
+                done: false,
+            },
+            Err(err) => Self {
+                name,
+                inner: None,
+                error: Some(err),
+                done: false,
+            },
+        }
+    }
+
+    fn fail(&mut self, err: Error) {
+        self.inner = None;
+        self.error = Some(err);
+    }
+}
+
+/// Return error if the alive slots can't satisfy the consistency anymore.
+fn check_slots<T>(
+    consistency: MirrorConsistency,
+    op: Operation,
+    slots: &[MirrorSlot<T>],
+) -> Result<()> {
+    let total = slots.len();
+    let required = consistency.required(total);
+    let alive = slots.iter().filter(|s| s.inner.is_some()).count();
+    if alive >= required {
+        return Ok(());
+    }
+
+    let failed = slots
+        .iter()
+        .filter_map(|s| s.error.as_ref().map(|e| (s.name.clone(), clone_error(e))))
+        .collect();
+    Err(new_mirror_error(op, alive, total, required, failed))
+}
+
+/// Keep kind and message of the error so it can be reported more than once.
+fn clone_error(err: &Error) -> Error {
+    let mut e = Error::new(err.kind(), err.to_string());
+    if err.is_temporary() {
+        e = e.set_temporary();
+    }
+    e
+}
+
+/// Wrapper that drives the writer, deleter or copier of every target.
+pub struct MirrorWrapper<T> {
+    consistency: MirrorConsistency,
+    op: Operation,
+    slots: Vec<MirrorSlot<T>>,
+}
+
+impl<T> MirrorWrapper<T> {
+    fn new(consistency: MirrorConsistency, op: Operation, slots: Vec<MirrorSlot<T>>) -> Self {
+        Self {
+            consistency,
+            op,
+            slots,
+        }
+    }
+
+    /// Mark the failed slots and check if the consistency is still satisfied.
+    fn apply<R>(&mut self, results: Vec<(usize, Result<R>)>) -> Result<Vec<(usize, R)>> {
+        let mut values = Vec::with_capacity(results.len());
+        for (idx, res) in results {
+            match res {
+                Ok(v) => values.push((idx, v)),
+                Err(err) => self.slots[idx].fail(err),
+            }
+        }
+        check_slots(self.consistency, self.op, &self.slots)?;
+        Ok(values)
+    }
+
+    /// Take all slots and collect the final results into an outcome.
+    fn outcome<R>(&mut self, results: Vec<(usize, Result<R>)>) -> Result<R> {
+        let mut results = results;
+        let mut outcome = MirrorOutcome::new(self.consistency, self.op, self.slots.len());
+        for (idx, slot) in self.slots.iter_mut().enumerate() {
+            let res = match results.iter().position(|(i, _)| *i == idx) {
+                Some(pos) => results.swap_remove(pos).1,
+                None => Err(slot.error.take().unwrap_or_else(|| {
+                    Error::new(ErrorKind::Unexpected, "mirror target is closed")
+                })),
+            };
+            outcome.record(slot.name.clone(), res);
+        }
+        outcome.finish()
+    }
+}
+
+impl oio::Write for MirrorWrapper<oio::Writer> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let results = join_all(self.slots.iter_mut().enumerate().filter_map(|(idx, s)| {
+            let bs = bs.clone();
+            s.inner
+                .as_mut()
+                .map(|w| async move { (idx, w.write(bs).await) })
+        }))
+        .await;
+        self.apply(results)?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let results = join_all(self.slots.iter_mut().enumerate().filter_map(|(idx, s)| {
+            s.inner
+                .as_mut()
+                .map(|w| async move { (idx, w.close().await) })
+        }))
+        .await;
+        self.outcome(results)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let results = join_all(
+            self.slots
+                .iter_mut()
+                .filter_map(|s| s.inner.as_mut().map(|w| w.abort())),
+        )
+        .await;
+        results.into_iter().collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+}
+
+impl oio::Delete for MirrorWrapper<oio::Deleter> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let results = join_all(self.slots.iter_mut().enumerate().filter_map(|(idx, s)| {
+            let args = args.clone();
+            s.inner
+                .as_mut()
+                .map(|d| async move { (idx, d.delete(path, args).await) })
+        }))
+        .await;
+        self.apply(results)?;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let results = join_all(self.slots.iter_mut().enumerate().filter_map(|(idx, s)| {
+            s.inner
+                .as_mut()
+                .map(|d| async move { (idx, d.close().await) })
+        }))
+        .await;
+        self.outcome(results)
+    }
+}
+
+impl oio::Copy for MirrorWrapper<oio::Copier> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        let results = join_all(
+            self.slots
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, s)| !s.done)
+                .filter_map(|(idx, s)| {
+                    s.inner
+                        .as_mut()
+                        .map(|c| async move { (idx, c.next().await) })
+                }),
+        )
+        .await;
+        let values = self.apply(results)?;
+
+        // Report the progress of the first target that is still copying, the
+        // copy is completed once all targets are completed.
+        let mut progress = None;
+        for (idx, v) in values {
+            match v {
+                Some(n) => {
+                    progress.get_or_insert(n);
+                }
+                None => self.slots[idx].done = true,
+            }
+        }
+        Ok(progress)
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let results = join_all(self.slots.iter_mut().enumerate().filter_map(|(idx, s)| {
+            s.inner
+                .as_mut()
+                .map(|c| async move { (idx, c.close().await) })
+        }))
+        .await;
+        self.outcome(results)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let results = join_all(
+            self.slots
+                .iter_mut()
+                .filter_map(|s| s.inner.as_mut().map(|c| c.abort())),
+        )
+        .await;
+        results.into_iter().collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Service that fails all mutations.
+    #[derive(Debug)]
+    struct FailingService;
+
+    fn failing_error() -> Error {
+        Error::new(ErrorKind::PermissionDenied, "mirror is unavailable")
+    }
+
+    impl Service for FailingService {
+        type Reader = ();
+        type Writer = ();
+        type Lister = ();
+        type Deleter = ();
+        type Copier = ();
+
+        fn info(&self) -> ServiceInfo {
+            ServiceInfo::with_scheme("failing")
+        }
+
+        fn capability(&self) -> Capability {
+            Capability {
+                write: true,
+                delete: true,
+                create_dir: true,
+                ..Default::default()
+            }
+        }
+
+        async fn create_dir(
+            &self,
+            _: &OperationContext,
+            _: &str,
+            _: OpCreateDir,
+        ) -> Result<RpCreateDir> {
+            Err(failing_error())
+        }
+
+        async fn stat(&self, _: &OperationContext, _: &str, _: OpStat) -> Result<RpStat> {
+            Err(failing_error())
+        }
+
+        fn read(&self, _: &OperationContext, _: &str, _: OpRead) -> Result<Self::Reader> {
+            Err(failing_error())
+        }
+
+        fn write(&self, _: &OperationContext, _: &str, _: OpWrite) -> Result<Self::Writer> {
+            Err(failing_error())
+        }
+
+        fn list(&self, _: &OperationContext, _: &str, _: OpList) -> Result<Self::Lister> {
+            Err(failing_error())
+        }
+
+        fn delete(&self, _: &OperationContext) -> Result<Self::Deleter> {
+            Err(failing_error())
+        }
+
+        fn copy(
+            &self,
+            _: &OperationContext,
+            _: &str,
+            _: &str,
+            _: OpCopy,
+            _: OpCopier,
+        ) -> Result<Self::Copier> {
+            Err(failing_error())
+        }
+
+        async fn rename(
+            &self,
+            _: &OperationContext,
+            _: &str,
+            _: &str,
+            _: OpRename,
+        ) -> Result<RpRename> {
+            Err(failing_error())
+        }
+
+        async fn presign(&self, _: &OperationContext, _: &str, _: OpPresign) -> Result<RpPresign> {
+            Err(failing_error())
+        }
+    }
+
+    fn new_memory_operator() -> Operator {
+        Operator::new(services::Memory::default()).unwrap()
+    }
+
+    fn new_failing_operator() -> Operator {
+        Operator::from_parts(OperationContext::default(), Arc::new(FailingService))
+    }
+
+    #[tokio::test]
+    async fn test_mirror_write_and_delete() -> Result<()> {
+        let primary = new_memory_operator();
+        let mirror = new_memory_operator();
+        let op = primary
+            .clone()
+            .layer(MirrorLayer::default().with_mirror(mirror.clone()));
+
+        op.write("a.txt", "hello").await?;
+        assert_eq!(primary.read("a.txt").await?.to_vec(), b"hello");
+        assert_eq!(mirror.read("a.txt").await?.to_vec(), b"hello");
+
+        op.delete("a.txt").await?;
+        assert!(!primary.exists("a.txt").await?);
+        assert!(!mirror.exists("a.txt").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mirror_all_reports_failed_targets() -> Result<()> {
+        let primary = new_memory_operator();
+        let op = primary.clone().layer(
+            MirrorLayer::default()
+                .with_mirror(new_memory_operator())
+                .with_mirror(new_failing_operator()),
+        );
+
+        let err = op.write("a.txt", "hello").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.to_string().contains("mirror[1]"), "{err}");
+
+        let err = op.create_dir("dir/").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mirror_quorum() -> Result<()> {
+        let primary = new_memory_operator();
+        let mirror = new_memory_operator();
+        let op = primary.clone().layer(
+            MirrorLayer::default()
+                .with_mirror(mirror.clone())
+                .with_mirror(new_failing_operator())
+                .with_consistency(MirrorConsistency::Quorum),
+        );
+
+        op.write("a.txt", "hello").await?;
+        assert_eq!(mirror.read("a.txt").await?.to_vec(), b"hello");
+        op.delete("a.txt").await?;
+        assert!(!mirror.exists("a.txt").await?);
+
+        let op = primary.clone().layer(
+            MirrorLayer::default()
+                .with_mirror(new_failing_operator())
+                .with_mirror(new_failing_operator())
+                .with_consistency(MirrorConsistency::Quorum),
+        );
+        assert!(op.write("b.txt", "hello").await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_consistency_required() {
+        assert_eq!(MirrorConsistency::All.required(3), 3);
+        assert_eq!(MirrorConsistency::Quorum.required(1), 1);
+        assert_eq!(MirrorConsistency::Quorum.required(2), 2);
+        assert_eq!(MirrorConsistency::Quorum.required(3), 2);
+    }
+}
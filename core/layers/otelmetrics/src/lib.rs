@@ -34,6 +34,19 @@ use opentelemetry::metrics::UpDownCounter;
 /// This layer records operation metrics from OpenDAL API calls and HTTP metrics
 /// from requests made through OpenDAL's HTTP fetcher.
 ///
+/// # Instruments
+///
+/// - `opendal.operation.duration`: latency of successful operations, its count is
+///   the number of operations.
+/// - `opendal.operation.errors`: number of failed operations, labeled by `error`.
+/// - `opendal.operation.bytes` and `opendal.operation.bytes_rate`: bytes read or
+///   written and the throughput.
+/// - `opendal.operation.ttfb`, `opendal.operation.executing`,
+///   `opendal.operation.entries` and `opendal.operation.entries_rate`.
+/// - `opendal.http.*`: the same set for HTTP requests sent by services.
+///
+/// All instruments carry `scheme`, `namespace`, `root` and `operation` attributes.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -505,3 +518,109 @@ fn register_f64_histogram_meter(
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::Operator;
+    use opendal_core::services;
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry_sdk::metrics::InMemoryMetricExporter;
+    use opentelemetry_sdk::metrics::PeriodicReader;
+    use opentelemetry_sdk::metrics::SdkMeterProvider;
+    use opentelemetry_sdk::metrics::data::AggregatedMetrics;
+    use opentelemetry_sdk::metrics::data::MetricData;
+
+    use super::*;
+
+    /// Collect `(operation, count, sum)` of the given u64 or f64 histogram.
+    fn histogram(exporter: &InMemoryMetricExporter, name: &str) -> Vec<(String, u64, f64)> {
+        let mut points = Vec::new();
+        let metrics = exporter.get_finished_metrics().unwrap();
+        let metric = metrics
+            .iter()
+            .flat_map(|rm| rm.scope_metrics())
+            .flat_map(|sm| sm.metrics())
+            .find(|m| m.name() == name)
+            .unwrap_or_else(|| panic!("metric {name} must be recorded"));
+
+        match metric.data() {
+            AggregatedMetrics::U64(MetricData::Histogram(h)) => {
+                for p in h.data_points() {
+                    points.push((operation(p.attributes()), p.count(), p.sum() as f64));
+                }
+            }
+            AggregatedMetrics::F64(MetricData::Histogram(h)) => {
+                for p in h.data_points() {
+                    points.push((operation(p.attributes()), p.count(), p.sum()));
+                }
+            }
+            v => panic!("metric {name} must be histogram, got {v:?}"),
+        }
+        points
+    }
+
+    fn operation<'a>(mut attrs: impl Iterator<Item = &'a KeyValue>) -> String {
+        attrs
+            .find(|kv| kv.key.as_str() == observe::LABEL_OPERATION)
+            .map(|kv| kv.value.to_string())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_operation_metrics() -> opendal_core::Result<()> {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+        let meter = provider.meter("opendal");
+
+        let op = Operator::new(services::Memory::default())?
+            .layer(OtelMetricsLayer::builder().register(&meter));
+        op.write("file", "hello").await?;
+        op.read("file").await?;
+        op.stat("file").await?;
+        op.stat("file").await?;
+        assert!(op.stat("not_exist").await.is_err());
+
+        provider.force_flush().unwrap();
+
+        let durations = histogram(&exporter, "opendal.operation.duration");
+        let count = |op: &str| -> u64 {
+            durations
+                .iter()
+                .filter(|(name, _, _)| name == op)
+                .map(|(_, count, _)| count)
+                .sum()
+        };
+        // Failed operations are counted by `opendal.operation.errors` instead.
+        assert_eq!(count("stat"), 2);
+        assert!(count("write") >= 1);
+        assert!(count("read") >= 1);
+
+        let bytes = histogram(&exporter, "opendal.operation.bytes");
+        for op in ["read", "write"] {
+            let total: f64 = bytes
+                .iter()
+                .filter(|(name, _, _)| name == op)
+                .map(|(_, _, sum)| sum)
+                .sum();
+            assert_eq!(total, 5.0, "bytes of {op}");
+        }
+
+        let metrics = exporter.get_finished_metrics().unwrap();
+        let errors = metrics
+            .iter()
+            .flat_map(|rm| rm.scope_metrics())
+            .flat_map(|sm| sm.metrics())
+            .find(|m| m.name() == "opendal.operation.errors")
+            .expect("errors must be recorded");
+        let AggregatedMetrics::U64(MetricData::Sum(sum)) = errors.data() else {
+            panic!("errors must be u64 counter");
+        };
+        let points: Vec<_> = sum.data_points().collect();
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].value(), 1);
+        assert_eq!(operation(points[0].attributes()), "stat");
+        Ok(())
+    }
+}
@@ -208,6 +208,48 @@ impl Service for OtelTraceService {
         let cx = TraceContext::current_with_span(span);
         self.inner.presign(ctx, path, args).with_context(cx).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let tracer = global::tracer("opendal");
+        let mut span = tracer.start("get_acl");
+        span.set_attribute(KeyValue::new("path", path.to_string()));
+        span.set_attribute(KeyValue::new("args", format!("{args:?}")));
+        let cx = TraceContext::current_with_span(span);
+        self.inner.get_acl(ctx, path, args).with_context(cx).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let tracer = global::tracer("opendal");
+        let mut span = tracer.start("set_acl");
+        span.set_attribute(KeyValue::new("path", path.to_string()));
+        span.set_attribute(KeyValue::new("args", format!("{args:?}")));
+        let cx = TraceContext::current_with_span(span);
+        self.inner.set_acl(ctx, path, args).with_context(cx).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let tracer = global::tracer("opendal");
+        let mut span = tracer.start("restore");
+        span.set_attribute(KeyValue::new("path", path.to_string()));
+        span.set_attribute(KeyValue::new("args", format!("{args:?}")));
+        let cx = TraceContext::current_with_span(span);
+        self.inner.restore(ctx, path, args).with_context(cx).await
+    }
 }
 
 #[doc(hidden)]
@@ -263,6 +305,10 @@ impl<R: oio::Write> oio::Write for OtelTraceWrapper<R> {
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().with_context(self.cx.clone()).await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().with_context(self.cx.clone()).await
+    }
 }
 
 impl<R: oio::List> oio::List for OtelTraceWrapper<R> {
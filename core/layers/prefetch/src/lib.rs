@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read-ahead prefetch layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod reader;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub use reader::PrefetchReader;
+
+/// Read-ahead layer that keeps chunks in flight ahead of sequential reads.
+///
+/// # Operation Behavior
+///
+/// - `read`: the requested range is split into fixed size chunks. While the consumer
+///   is reading one chunk, the next `window` chunks are already being fetched from the
+///   service. Ranges no larger than one chunk are passed through directly.
+/// - Other operations are passed through to the underlying service.
+///
+/// Dropping the reader stream cancels all chunks in flight.
+///
+/// Unlike [`ReaderOptions::concurrent`], which only takes effect for readers created by
+/// [`Operator::reader_with`], this layer applies to every read on the operator,
+/// including [`Operator::read`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_prefetch::PrefetchLayer;
+/// # fn main() -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(PrefetchLayer::new(4).with_chunk_size(8 * 1024 * 1024));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrefetchLayer {
+    window: usize,
+    chunk_size: u64,
+}
+
+impl PrefetchLayer {
+    /// Create a new `PrefetchLayer` that keeps `window` chunks in flight ahead of
+    /// the consumer.
+    ///
+    /// Chunks are 4 MiB by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window must be greater than 0");
+        Self {
+            window,
+            chunk_size: 4 * 1024 * 1024,
+        }
+    }
+
+    /// Set the size of chunks reads are split into.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        self.chunk_size = chunk_size as u64;
+        self
+    }
+}
+
+impl Layer for PrefetchLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(PrefetchService {
+            inner,
+            window: self.window,
+            chunk_size: self.chunk_size,
+        })
+    }
+}
+
+/// Service wrapped by [`PrefetchLayer`].
+#[derive(Debug)]
+pub struct PrefetchService {
+    inner: Servicer,
+    window: usize,
+    chunk_size: u64,
+}
+
+impl Service for PrefetchService {
+    type Reader = PrefetchReader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let r = self.inner.read(ctx, path, args.clone())?;
+        Ok(PrefetchReader::new(
+            self.inner.clone(),
+            ctx.clone(),
+            path.to_string(),
+            args,
+            r,
+            self.window,
+            self.chunk_size,
+        ))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use opendal_core::services::Memory;
+    use opendal_testkit::CountLayer;
+
+    use super::*;
+
+    fn new_operator(window: usize) -> (Operator, CountLayer) {
+        let count = CountLayer::new().with_read_delay(Duration::from_millis(20));
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(count.clone())
+            .layer(PrefetchLayer::new(window).with_chunk_size(4));
+        (op, count)
+    }
+
+    fn content() -> Vec<u8> {
+        (0..30).collect()
+    }
+
+    #[tokio::test]
+    async fn test_read() {
+        let (op, count) = new_operator(2);
+        op.write("a", content()).await.unwrap();
+
+        assert_eq!(op.read("a").await.unwrap().to_vec(), content());
+        assert_eq!(count.reads(), 8);
+
+        let bs = op.read_with("a").range(5..23).await.unwrap();
+        assert_eq!(bs.to_vec(), (5..23).collect::<Vec<u8>>());
+
+        let bs = op.read_with("a").range(27..).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![27, 28, 29]);
+    }
+
+    #[tokio::test]
+    async fn test_pass_through_small_range() {
+        let (op, count) = new_operator(2);
+        op.write("a", content()).await.unwrap();
+
+        let bs = op.read_with("a").range(1..5).await.unwrap();
+        assert_eq!(bs.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(count.reads(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_window_in_flight() {
+        let (op, count) = new_operator(3);
+        op.write("a", content()).await.unwrap();
+
+        assert_eq!(op.read("a").await.unwrap().to_vec(), content());
+        // The chunk being awaited plus `window` chunks ahead of it.
+        assert_eq!(count.max_inflight_reads(), 4);
+        assert_eq!(count.inflight_reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_drop() {
+        let (op, count) = new_operator(3);
+        op.write("a", content()).await.unwrap();
+
+        let mut stream = op.reader("a").await.unwrap().into_stream(..).await.unwrap();
+        let bs = stream.next().await.unwrap().unwrap();
+        assert_eq!(bs.to_vec(), vec![0, 1, 2, 3]);
+        drop(stream);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        // Chunks beyond the window are never fetched once the stream is dropped.
+        assert!(count.completed_reads() <= 4);
+        assert_eq!(count.reads(), 4);
+    }
+}
@@ -0,0 +1,147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::raw::oio::ReadStream as _;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader that fetches the requested range in chunks, keeping a window of
+/// chunks in flight ahead of the consumer.
+pub struct PrefetchReader {
+    srv: Servicer,
+    ctx: OperationContext,
+    path: String,
+    args: OpRead,
+    reader: Arc<oio::Reader>,
+    window: usize,
+    chunk_size: u64,
+}
+
+impl PrefetchReader {
+    pub(crate) fn new(
+        srv: Servicer,
+        ctx: OperationContext,
+        path: String,
+        args: OpRead,
+        reader: oio::Reader,
+        window: usize,
+        chunk_size: u64,
+    ) -> Self {
+        Self {
+            srv,
+            ctx,
+            path,
+            args,
+            reader: Arc::new(reader),
+            window,
+            chunk_size,
+        }
+    }
+}
+
+impl oio::Read for PrefetchReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        // Ranges fitting in one chunk gain nothing from prefetching.
+        if !range.is_suffix() && range.size().is_some_and(|size| size <= self.chunk_size) {
+            return self.reader.open(range).await;
+        }
+
+        let mut op = OpStat::new();
+        if let Some(version) = self.args.version() {
+            op = op.with_version(version);
+        }
+        let metadata = self
+            .srv
+            .stat(&self.ctx, &self.path, op)
+            .await?
+            .into_metadata();
+        let content_range = range.to_content_range(metadata.content_length() as usize)?;
+
+        // The chunk being consumed is in flight as well.
+        let tasks = ConcurrentTasks::new(
+            self.ctx.executor().clone(),
+            self.window + 1,
+            0,
+            |(reader, range): (Arc<oio::Reader>, BytesRange)| {
+                Box::pin(async move {
+                    let result = match reader.read(range).await {
+                        Ok((_, bs)) if Some(bs.len() as u64) == range.size() => Ok(bs),
+                        Ok((_, bs)) => Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "reader got unexpected data size",
+                        )
+                        .with_context("range", range)
+                        .with_context("actual", bs.len())),
+                        Err(err) => Err(err),
+                    };
+                    ((reader, range), result)
+                })
+            },
+        );
+        let stream = PrefetchStream {
+            reader: self.reader.clone(),
+            tasks,
+            chunk_size: self.chunk_size,
+            offset: content_range.start as u64,
+            end: content_range.end as u64,
+        };
+        Ok((
+            RpRead::new(metadata),
+            Box::new(stream) as Box<dyn oio::ReadStreamDyn>,
+        ))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, mut stream) = self.open(range).await?;
+        let buffer = stream.read_all().await?;
+        Ok((rp, buffer))
+    }
+}
+
+/// Stream that yields prefetched chunks in order.
+///
+/// Dropping the stream cancels all chunks in flight.
+struct PrefetchStream {
+    reader: Arc<oio::Reader>,
+    tasks: ConcurrentTasks<(Arc<oio::Reader>, BytesRange), Buffer>,
+    chunk_size: u64,
+    /// Offset of the next chunk to submit.
+    offset: u64,
+    end: u64,
+}
+
+impl oio::ReadStream for PrefetchStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        while self.offset < self.end && self.tasks.has_remaining() {
+            let size = self.chunk_size.min(self.end - self.offset);
+            self.tasks
+                .execute((
+                    self.reader.clone(),
+                    BytesRange::new(self.offset, Some(size)),
+                ))
+                .await?;
+            self.offset += size;
+        }
+
+        match self.tasks.next().await {
+            Some(result) => result,
+            None => Ok(Buffer::new()),
+        }
+    }
+}
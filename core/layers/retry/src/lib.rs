@@ -20,10 +20,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::sync::Mutex;
 
+use backon::BackoffBuilder;
 use backon::BlockingRetryable;
+use backon::ExponentialBackoff;
 use backon::ExponentialBuilder;
 use backon::Retryable;
 use opendal_core::raw::*;
@@ -37,6 +41,13 @@ use opendal_core::*;
 /// returns true. If operation still failed, this layer will set error to
 /// `Persistent` which means error has been retried.
 ///
+/// # Resumable reads
+///
+/// When a read stream fails in the middle, this layer reopens it from the
+/// last delivered offset instead of starting over. If the service returns
+/// an etag, the reopened stream must carry the same etag, otherwise the read
+/// fails with [`ErrorKind::ConditionNotMatch`] since the content has changed.
+///
 /// # Stateful operation bodies
 ///
 /// While retrying stateful operation bodies, please make sure either:
@@ -114,8 +125,41 @@ use opendal_core::*;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Retry budget and per-operation overrides
+///
+/// A retry budget limits the retries across all operations of the operator,
+/// so a degraded backend doesn't get hit by a retry storm. Overrides change
+/// the backoff of a single operation.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// #
+/// # use opendal_core::raw::Operation;
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_retry::RetryLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?.layer(
+///     RetryLayer::default()
+///         // Allow bursts of 100 retries, and 10 more retries every second.
+///         .with_retry_budget(100, Duration::from_millis(100))
+///         // Never retry writes.
+///         .with_operation_override(Operation::Write, |l| l.with_max_times(0))
+///         // Retry stats quickly and more often.
+///         .with_operation_override(Operation::Stat, |l| {
+///             l.with_min_delay(Duration::from_millis(10)).with_max_times(10)
+///         }),
+/// );
+/// # Ok(())
+/// # }
+/// ```
 pub struct RetryLayer<I: RetryInterceptor = DefaultRetryInterceptor> {
     builder: ExponentialBuilder,
+    overrides: HashMap<Operation, ExponentialBuilder>,
+    budget: Option<Arc<RetryBudget>>,
     notify: Arc<I>,
 }
 
@@ -123,6 +167,8 @@ impl<I: RetryInterceptor> Debug for RetryLayer<I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RetryLayer")
             .field("builder", &self.builder)
+            .field("overrides", &self.overrides)
+            .field("budget", &self.budget)
             .finish_non_exhaustive()
     }
 }
@@ -131,6 +177,8 @@ impl<I: RetryInterceptor> Clone for RetryLayer<I> {
     fn clone(&self) -> Self {
         Self {
             builder: self.builder,
+            overrides: self.overrides.clone(),
+            budget: self.budget.clone(),
             notify: self.notify.clone(),
         }
     }
@@ -140,6 +188,8 @@ impl Default for RetryLayer {
     fn default() -> Self {
         Self {
             builder: ExponentialBuilder::default(),
+            overrides: HashMap::new(),
+            budget: None,
             notify: Arc::new(DefaultRetryInterceptor),
         }
     }
@@ -169,6 +219,8 @@ impl<I: RetryInterceptor> RetryLayer<I> {
     pub fn with_notify<NI: RetryInterceptor>(self, notify: NI) -> RetryLayer<NI> {
         RetryLayer {
             builder: self.builder,
+            overrides: self.overrides,
+            budget: self.budget,
             notify: Arc::new(notify),
         }
     }
@@ -213,6 +265,52 @@ impl<I: RetryInterceptor> RetryLayer<I> {
         self.builder = self.builder.with_max_times(max_times);
         self
     }
+
+    /// Set the max total delay of current backoff.
+    ///
+    /// Backoff will return `None` once the delays of one operation add up to
+    /// more than `total_delay`.
+    pub fn with_total_delay(mut self, total_delay: Duration) -> Self {
+        self.builder = self.builder.with_total_delay(Some(total_delay));
+        self
+    }
+
+    /// Set a retry budget shared by all operations of this layer.
+    ///
+    /// The budget is a token bucket holding at most `max_retries` tokens,
+    /// and one token is added back every `refill_interval`. Every retry takes
+    /// a token, and errors are returned without retrying once the bucket is
+    /// empty.
+    pub fn with_retry_budget(mut self, max_retries: usize, refill_interval: Duration) -> Self {
+        self.budget = Some(Arc::new(RetryBudget::new(max_retries, refill_interval)));
+        self
+    }
+
+    /// Override the backoff of given operation.
+    ///
+    /// `f` is called with a copy of current layer, and the backoff settings of
+    /// the returned layer are used for `op`. Readers, writers, listers,
+    /// deleters and copiers use the backoff of `Read`, `Write`, `List`,
+    /// `Delete` and `Copy` respectively. The retry budget is still shared.
+    ///
+    /// ```no_run
+    /// use opendal_core::Operator;
+    /// use opendal_core::raw::Operation;
+    /// use opendal_core::services;
+    /// use opendal_layer_retry::RetryLayer;
+    ///
+    /// let _ = Operator::new(services::Memory::default())
+    ///     .expect("must init")
+    ///     .layer(
+    ///         RetryLayer::default()
+    ///             .with_operation_override(Operation::Write, |l| l.with_max_times(0)),
+    ///     );
+    /// ```
+    pub fn with_operation_override(mut self, op: Operation, f: impl FnOnce(Self) -> Self) -> Self {
+        let builder = f(self.clone()).builder;
+        self.overrides.insert(op, builder);
+        self
+    }
 }
 
 impl<I: RetryInterceptor> Layer for RetryLayer<I> {
@@ -227,6 +325,8 @@ impl<I: RetryInterceptor> RetryLayer<I> {
             inner,
             notify: self.notify.clone(),
             builder: self.builder,
+            overrides: Arc::new(self.overrides.clone()),
+            budget: self.budget.clone(),
         }
     }
 }
@@ -278,11 +378,108 @@ impl RetryInterceptor for DefaultRetryInterceptor {
     }
 }
 
+/// RetryBudget is a token bucket of retries shared by all operations.
+#[derive(Debug)]
+struct RetryBudget {
+    max_tokens: f64,
+    refill_interval: Duration,
+    /// The tokens left and the time they were last refilled.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RetryBudget {
+    fn new(max_retries: usize, refill_interval: Duration) -> Self {
+        let max_tokens = max_retries as f64;
+        Self {
+            max_tokens,
+            refill_interval,
+            state: Mutex::new((max_tokens, Instant::now())),
+        }
+    }
+
+    /// Take a token for a retry, returns `false` if the budget is exhausted.
+    fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().expect("lock must succeed");
+        let (tokens, refilled_at) = &mut *state;
+
+        let now = Instant::now();
+        let refilled =
+            now.duration_since(*refilled_at).as_secs_f64() / self.refill_interval.as_secs_f64();
+        *tokens = (*tokens + refilled).min(self.max_tokens);
+        *refilled_at = now;
+
+        if *tokens < 1.0 {
+            return false;
+        }
+        *tokens -= 1.0;
+        true
+    }
+}
+
+/// RetryBackoff builds the backoff of one operation, stopping early once the
+/// retry budget is exhausted.
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct RetryBackoff {
+    builder: ExponentialBuilder,
+    budget: Option<Arc<RetryBudget>>,
+}
+
+impl From<ExponentialBuilder> for RetryBackoff {
+    fn from(builder: ExponentialBuilder) -> Self {
+        Self {
+            builder,
+            budget: None,
+        }
+    }
+}
+
+impl BackoffBuilder for RetryBackoff {
+    type Backoff = RetryBackoffIter;
+
+    fn build(self) -> Self::Backoff {
+        RetryBackoffIter {
+            inner: self.builder.build(),
+            budget: self.budget,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct RetryBackoffIter {
+    inner: ExponentialBackoff,
+    budget: Option<Arc<RetryBudget>>,
+}
+
+impl Iterator for RetryBackoffIter {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let dur = self.inner.next()?;
+        // Only take a token when we are really going to retry.
+        match &self.budget {
+            Some(budget) if !budget.try_acquire() => None,
+            _ => Some(dur),
+        }
+    }
+}
+
 #[doc(hidden)]
 pub struct RetryService<I: RetryInterceptor> {
     inner: Servicer,
     notify: Arc<I>,
     builder: ExponentialBuilder,
+    overrides: Arc<HashMap<Operation, ExponentialBuilder>>,
+    budget: Option<Arc<RetryBudget>>,
+}
+
+impl<I: RetryInterceptor> RetryService<I> {
+    fn backoff(&self, op: Operation) -> RetryBackoff {
+        RetryBackoff {
+            builder: self.overrides.get(&op).copied().unwrap_or(self.builder),
+            budget: self.budget.clone(),
+        }
+    }
 }
 
 impl<I: RetryInterceptor> Debug for RetryService<I> {
@@ -316,7 +513,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     ) -> Result<RpCreateDir> {
         let mut attempt: u32 = 0;
         { || self.inner.create_dir(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::CreateDir))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -334,7 +531,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
         let mut attempt: u32 = 0;
         let reader = { || self.inner.read(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Read))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -348,13 +545,17 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
             .call()
             .map_err(|err| err.set_persistent())?;
 
-        Ok(RetryReader::new(reader, self.notify.clone(), self.builder))
+        Ok(RetryReader::new(
+            reader,
+            self.notify.clone(),
+            self.backoff(Operation::Read),
+        ))
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let mut attempt: u32 = 0;
         let writer = { || self.inner.write(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Write))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -368,13 +569,17 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
             .call()
             .map_err(|err| err.set_persistent())?;
 
-        Ok(RetryWrapper::new(writer, self.notify.clone(), self.builder))
+        Ok(RetryWrapper::new(
+            writer,
+            self.notify.clone(),
+            self.backoff(Operation::Write),
+        ))
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
         let mut attempt: u32 = 0;
         { || self.inner.stat(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Stat))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -392,7 +597,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
         let mut attempt: u32 = 0;
         let deleter = { || self.inner.delete(ctx) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Delete))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -409,7 +614,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
         Ok(RetryWrapper::new(
             deleter,
             self.notify.clone(),
-            self.builder,
+            self.backoff(Operation::Delete),
         ))
     }
 
@@ -423,7 +628,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     ) -> Result<Self::Copier> {
         let mut attempt: u32 = 0;
         let copier = { || self.inner.copy(ctx, from, to, args.clone(), opts.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Copy))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -437,7 +642,11 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
             .call()
             .map_err(|err| err.set_persistent())?;
 
-        Ok(RetryWrapper::new(copier, self.notify.clone(), self.builder))
+        Ok(RetryWrapper::new(
+            copier,
+            self.notify.clone(),
+            self.backoff(Operation::Copy),
+        ))
     }
 
     async fn rename(
@@ -449,7 +658,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     ) -> Result<RpRename> {
         let mut attempt: u32 = 0;
         { || self.inner.rename(ctx, from, to, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Rename))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -467,7 +676,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
         let mut attempt: u32 = 0;
         let lister = { || self.inner.list(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::List))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -481,7 +690,11 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
             .call()
             .map_err(|err| err.set_persistent())?;
 
-        Ok(RetryWrapper::new(lister, self.notify.clone(), self.builder))
+        Ok(RetryWrapper::new(
+            lister,
+            self.notify.clone(),
+            self.backoff(Operation::List),
+        ))
     }
 
     async fn presign(
@@ -492,7 +705,7 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
     ) -> Result<RpPresign> {
         let mut attempt: u32 = 0;
         { || self.inner.presign(ctx, path, args.clone()) }
-            .retry(self.builder)
+            .retry(self.backoff(Operation::Presign))
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -506,21 +719,90 @@ impl<I: RetryInterceptor> Service for RetryService<I> {
             .await
             .map_err(|err| err.set_persistent())
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let mut attempt: u32 = 0;
+        { || self.inner.get_acl(ctx, path, args.clone()) }
+            .retry(self.backoff(Operation::GetAcl))
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                attempt += 1;
+                self.notify.intercept(RetryEvent {
+                    op: Operation::GetAcl,
+                    err,
+                    retry_after: dur,
+                    attempt,
+                })
+            })
+            .await
+            .map_err(|err| err.set_persistent())
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let mut attempt: u32 = 0;
+        { || self.inner.set_acl(ctx, path, args.clone()) }
+            .retry(self.backoff(Operation::SetAcl))
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                attempt += 1;
+                self.notify.intercept(RetryEvent {
+                    op: Operation::SetAcl,
+                    err,
+                    retry_after: dur,
+                    attempt,
+                })
+            })
+            .await
+            .map_err(|err| err.set_persistent())
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let mut attempt: u32 = 0;
+        { || self.inner.restore(ctx, path, args.clone()) }
+            .retry(self.backoff(Operation::Restore))
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                attempt += 1;
+                self.notify.intercept(RetryEvent {
+                    op: Operation::Restore,
+                    err,
+                    retry_after: dur,
+                    attempt,
+                })
+            })
+            .await
+            .map_err(|err| err.set_persistent())
+    }
 }
 
 #[doc(hidden)]
 pub struct RetryReader<R, I> {
     inner: Arc<R>,
     notify: Arc<I>,
-    builder: ExponentialBuilder,
+    backoff: RetryBackoff,
 }
 
 impl<R, I> RetryReader<R, I> {
-    fn new(inner: R, notify: Arc<I>, builder: ExponentialBuilder) -> Self {
+    fn new(inner: R, notify: Arc<I>, backoff: RetryBackoff) -> Self {
         Self {
             inner: Arc::new(inner),
             notify,
-            builder,
+            backoff,
         }
     }
 }
@@ -531,7 +813,7 @@ impl<R: oio::Read + 'static, I: RetryInterceptor> oio::Read for RetryReader<R, I
 
         let mut attempt: u32 = 0;
         let (rp, stream) = { || self.inner.open(range) }
-            .retry(self.builder)
+            .retry(self.backoff.clone())
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -545,14 +827,16 @@ impl<R: oio::Read + 'static, I: RetryInterceptor> oio::Read for RetryReader<R, I
             .await
             .map_err(|e| e.set_persistent())?;
 
+        let etag = rp.metadata().and_then(|m| m.etag()).map(|v| v.to_string());
         Ok((
             rp,
             Box::new(RetryReadStream::new(
                 self.inner.clone(),
                 stream,
                 range,
+                etag,
                 self.notify.clone(),
-                self.builder,
+                self.backoff.clone(),
             )) as Box<dyn oio::ReadStreamDyn>,
         ))
     }
@@ -562,7 +846,7 @@ impl<R: oio::Read + 'static, I: RetryInterceptor> oio::Read for RetryReader<R, I
 
         let mut attempt: u32 = 0;
         { || self.inner.read(range) }
-            .retry(self.builder)
+            .retry(self.backoff.clone())
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 attempt += 1;
@@ -584,8 +868,11 @@ pub struct RetryReadStream<R, I> {
     stream: Option<Box<dyn oio::ReadStreamDyn>>,
     range: BytesRange,
     read: u64,
+    /// The etag returned by the first open, used to make sure the content
+    /// doesn't change while we resume the stream.
+    etag: Option<String>,
     notify: Arc<I>,
-    builder: ExponentialBuilder,
+    backoff: RetryBackoff,
 }
 
 impl<R, I> RetryReadStream<R, I> {
@@ -593,16 +880,18 @@ impl<R, I> RetryReadStream<R, I> {
         reader: Arc<R>,
         stream: Box<dyn oio::ReadStreamDyn>,
         range: BytesRange,
+        etag: Option<String>,
         notify: Arc<I>,
-        builder: ExponentialBuilder,
+        backoff: RetryBackoff,
     ) -> Self {
         Self {
             reader,
             stream: Some(stream),
             range,
             read: 0,
+            etag,
             notify,
-            builder,
+            backoff,
         }
     }
 }
@@ -615,6 +904,7 @@ impl<R: oio::Read, I: RetryInterceptor> oio::ReadStream for RetryReadStream<R, I
         let stream = self.stream.take();
         let range = self.range;
         let read = self.read;
+        let etag = self.etag.clone();
         let mut attempt: u32 = 0;
 
         let ((stream, range, read), res) = {
@@ -624,17 +914,34 @@ impl<R: oio::Read, I: RetryInterceptor> oio::ReadStream for RetryReadStream<R, I
                 u64,
             )| {
                 let reader = reader.clone();
+                let etag = etag.clone();
                 async move {
                     let mut stream = match stream {
                         Some(stream) => stream,
                         None => {
+                            // Resume from the last delivered offset.
                             range.advance(read);
                             read = 0;
 
-                            match reader.open(range).await {
-                                Ok((_, stream)) => stream,
+                            let (rp, stream) = match reader.open(range).await {
+                                Ok(v) => v,
                                 Err(err) => return ((None, range, read), Err(err)),
+                            };
+
+                            let actual = rp.metadata().and_then(|m| m.etag());
+                            if let (Some(expected), Some(actual)) = (etag.as_deref(), actual) {
+                                if expected != actual {
+                                    let err = Error::new(
+                                        ErrorKind::ConditionNotMatch,
+                                        "content changed while resuming read",
+                                    )
+                                    .with_context("expected_etag", expected)
+                                    .with_context("actual_etag", actual);
+                                    return ((None, range, read), Err(err));
+                                }
                             }
+
+                            stream
                         }
                     };
 
@@ -652,7 +959,7 @@ impl<R: oio::Read, I: RetryInterceptor> oio::ReadStream for RetryReadStream<R, I
                 }
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context((stream, range, read))
         .notify(|err, dur| {
@@ -679,15 +986,15 @@ pub struct RetryWrapper<R, I> {
     inner: Option<R>,
     notify: Arc<I>,
 
-    builder: ExponentialBuilder,
+    backoff: RetryBackoff,
 }
 
 impl<R, I> RetryWrapper<R, I> {
-    fn new(inner: R, notify: Arc<I>, backoff: ExponentialBuilder) -> Self {
+    fn new(inner: R, notify: Arc<I>, backoff: RetryBackoff) -> Self {
         Self {
             inner: Some(inner),
             notify,
-            builder: backoff,
+            backoff,
         }
     }
 
@@ -715,7 +1022,7 @@ impl<R: oio::ReadStream, I: RetryInterceptor> oio::ReadStream for RetryWrapper<R
                 (r, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -748,7 +1055,7 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
                 ((r, bs), res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context((inner, bs))
         .notify(|err, dur| {
@@ -779,7 +1086,7 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
                 (r, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -810,7 +1117,38 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
                 (r, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
+        .when(|e| e.is_temporary())
+        .context(inner)
+        .notify(|err, dur| {
+            attempt += 1;
+            self.notify.intercept(RetryEvent {
+                op: Operation::Write,
+                err,
+                retry_after: dur,
+                attempt,
+            })
+        })
+        .await;
+
+        self.inner = Some(inner);
+        res.map_err(|err| err.set_persistent())
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        use backon::RetryableWithContext;
+
+        let inner = self.take_inner()?;
+        let mut attempt: u32 = 0;
+
+        let (inner, res) = {
+            |mut r: R| async move {
+                let res = r.suspend().await;
+
+                (r, res)
+            }
+        }
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -843,7 +1181,7 @@ impl<P: oio::List, I: RetryInterceptor> oio::List for RetryWrapper<P, I> {
                 (p, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -881,7 +1219,7 @@ impl<P: oio::Delete, I: RetryInterceptor> oio::Delete for RetryWrapper<P, I> {
                 }
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -912,7 +1250,7 @@ impl<P: oio::Delete, I: RetryInterceptor> oio::Delete for RetryWrapper<P, I> {
                 (p, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -945,7 +1283,7 @@ impl<C: oio::Copy, I: RetryInterceptor> oio::Copy for RetryWrapper<C, I> {
                 (c, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -976,7 +1314,7 @@ impl<C: oio::Copy, I: RetryInterceptor> oio::Copy for RetryWrapper<C, I> {
                 (c, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -1007,7 +1345,7 @@ impl<C: oio::Copy, I: RetryInterceptor> oio::Copy for RetryWrapper<C, I> {
                 (c, res)
             }
         }
-        .retry(self.builder)
+        .retry(self.backoff.clone())
         .when(|e| e.is_temporary())
         .context(inner)
         .notify(|err, dur| {
@@ -1511,7 +1849,8 @@ mod tests {
             Arc::new(recorder.clone()),
             ExponentialBuilder::default()
                 .with_min_delay(Duration::from_millis(1))
-                .with_max_delay(Duration::from_millis(1)),
+                .with_max_delay(Duration::from_millis(1))
+                .into(),
         );
 
         let (_, mut stream) = oio::Read::open(&reader, BytesRange::default()).await?;
@@ -1532,6 +1871,103 @@ mod tests {
         Ok(())
     }
 
+    /// A reader that fails after the first chunk of every stream and returns
+    /// the etag of the current open.
+    struct ResumableReader {
+        etags: Vec<&'static str>,
+        opens: Arc<Mutex<Vec<BytesRange>>>,
+    }
+
+    impl oio::StreamRead for ResumableReader {
+        async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+            let mut opens = self.opens.lock().unwrap();
+            let etag = self.etags[opens.len().min(self.etags.len() - 1)];
+            opens.push(range);
+
+            let rp = RpRead::new(Metadata::new(EntryMode::FILE).with_etag(etag.to_string()));
+            let buf = Buffer::from(Bytes::from("Hello, World!"));
+            let stream = ResumableReadStream {
+                buf: buf.slice(range.to_range_as_usize()),
+                done: false,
+            };
+            Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+        }
+    }
+
+    struct ResumableReadStream {
+        buf: Buffer,
+        done: bool,
+    }
+
+    impl oio::ReadStream for ResumableReadStream {
+        async fn read(&mut self) -> Result<Buffer> {
+            if self.done {
+                return Err(Error::new(ErrorKind::Unexpected, "connection reset").set_temporary());
+            }
+            self.done = true;
+
+            let n = self.buf.len().min(7);
+            Ok(self.buf.slice(..n))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_read_stream_resume_from_offset() -> Result<()> {
+        setup();
+
+        let opens = Arc::new(Mutex::new(Vec::new()));
+        let reader = RetryReader::new(
+            oio::StreamReader::new(ResumableReader {
+                etags: vec!["\"abc\""],
+                opens: opens.clone(),
+            }),
+            Arc::new(DefaultRetryInterceptor),
+            ExponentialBuilder::default()
+                .with_min_delay(Duration::from_millis(1))
+                .with_max_delay(Duration::from_millis(1))
+                .into(),
+        );
+
+        let (_, mut stream) = oio::Read::open(&reader, BytesRange::default()).await?;
+        let mut content = Vec::new();
+        content.extend_from_slice(&oio::ReadStream::read(&mut stream).await?.to_bytes());
+        content.extend_from_slice(&oio::ReadStream::read(&mut stream).await?.to_bytes());
+
+        assert_eq!(content, b"Hello, World!");
+        assert_eq!(
+            *opens.lock().unwrap(),
+            vec![BytesRange::default(), BytesRange::new(7, None)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_read_stream_etag_changed() -> Result<()> {
+        setup();
+
+        let reader = RetryReader::new(
+            oio::StreamReader::new(ResumableReader {
+                etags: vec!["\"abc\"", "\"def\""],
+                opens: Arc::default(),
+            }),
+            Arc::new(DefaultRetryInterceptor),
+            ExponentialBuilder::default()
+                .with_min_delay(Duration::from_millis(1))
+                .with_max_delay(Duration::from_millis(1))
+                .into(),
+        );
+
+        let (_, mut stream) = oio::Read::open(&reader, BytesRange::default()).await?;
+        let buf = oio::ReadStream::read(&mut stream).await?;
+        assert_eq!(buf.to_bytes(), Bytes::from_static(b"Hello, "));
+
+        let err = oio::ReadStream::read(&mut stream)
+            .await
+            .expect_err("read must fail after etag changed");
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_retry_batch() -> Result<()> {
         setup();
@@ -1592,4 +2028,73 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_retry_operation_override() -> Result<()> {
+        setup();
+
+        let builder = MockBuilder::default();
+        let op = Operator::new(builder.clone())?.layer(
+            RetryLayer::default()
+                .with_min_delay(Duration::from_millis(1))
+                .with_max_delay(Duration::from_millis(1))
+                .with_operation_override(Operation::Read, |l| l.with_max_times(0)),
+        );
+
+        let r = op.reader("retryable_error").await?;
+        let mut content = Vec::new();
+        let err = r
+            .read_into(&mut content, ..)
+            .await
+            .expect_err("read must fail without retry");
+        assert!(err.is_persistent());
+        assert_eq!(*builder.attempt.lock().unwrap(), 1);
+
+        // Other operations still use the default backoff.
+        op.copy("from", "to").await.expect("copy must succeed");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget() -> Result<()> {
+        setup();
+
+        let builder = MockBuilder::default();
+        let op = Operator::new(builder.clone())?.layer(
+            RetryLayer::default()
+                .with_min_delay(Duration::from_millis(1))
+                .with_max_delay(Duration::from_millis(1))
+                .with_retry_budget(1, Duration::from_secs(3600)),
+        );
+
+        // The read needs two retries in a row, but the budget only has one.
+        let r = op.reader("retryable_error").await?;
+        let mut content = Vec::new();
+        let err = r
+            .read_into(&mut content, ..)
+            .await
+            .expect_err("read must fail once budget is exhausted");
+        assert!(err.is_persistent());
+        assert_eq!(*builder.attempt.lock().unwrap(), 2);
+
+        // The budget is shared, copy can't retry either.
+        let err = op
+            .copy("from", "to")
+            .await
+            .expect_err("copy must fail once budget is exhausted");
+        assert!(err.is_persistent());
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_budget_refill() {
+        let budget = RetryBudget::new(2, Duration::from_millis(10));
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+
+        std::thread::sleep(Duration::from_millis(15));
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+    }
 }
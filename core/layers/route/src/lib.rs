@@ -261,6 +261,42 @@ impl Service for RouteAccessor {
             RouteSelected::Target(target) => target.srv.presign(&target.ctx, path, args).await,
         }
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        match self.select(path) {
+            RouteSelected::Default(srv) => srv.get_acl(ctx, path, args).await,
+            RouteSelected::Target(target) => target.srv.get_acl(&target.ctx, path, args).await,
+        }
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        match self.select(path) {
+            RouteSelected::Default(srv) => srv.set_acl(ctx, path, args).await,
+            RouteSelected::Target(target) => target.srv.set_acl(&target.ctx, path, args).await,
+        }
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        match self.select(path) {
+            RouteSelected::Default(srv) => srv.restore(ctx, path, args).await,
+            RouteSelected::Target(target) => target.srv.restore(&target.ctx, path, args).await,
+        }
+    }
 }
 
 /// Deleter that batches deletions per routed service.
@@ -0,0 +1,392 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Snapshot layer implementation for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Read-only view of the objects under a prefix at the time it's captured.
+///
+/// [`SnapshotLayer::capture`] lists all objects under the prefix and records
+/// their versions, or their etags if the service doesn't support reading
+/// with versions. The returned operator:
+///
+/// - `read` and `stat`: always access the recorded versions. If the service
+///   pins with etags, `ConditionNotMatch` is returned once the object has been
+///   changed. `NotFound` is returned once the object has been removed, or if
+///   the object wasn't there at capture time.
+/// - `list`: returns the recorded objects.
+/// - All other operations return `Unsupported`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_snapshot::SnapshotLayer;
+/// # async fn test(op: Operator) -> Result<()> {
+/// let snapshot = SnapshotLayer::capture(&op, "datasets/train/").await?;
+///
+/// // Reads the data as it was at capture time.
+/// let bs = snapshot.read("datasets/train/part-0000.parquet").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SnapshotLayer {
+    entries: Arc<BTreeMap<String, SnapshotEntry>>,
+}
+
+#[derive(Clone, Debug)]
+struct SnapshotEntry {
+    metadata: Metadata,
+    pin: Pin,
+}
+
+/// How the recorded object is pinned.
+#[derive(Clone, Debug)]
+enum Pin {
+    Version(String),
+    Etag(String),
+}
+
+impl SnapshotLayer {
+    /// Capture all objects under `prefix` and return an operator that reads
+    /// them as they are now.
+    ///
+    /// Returns `Unsupported` if the service can't read with versions or etags,
+    /// or doesn't report them for an object.
+    pub async fn capture(op: &Operator, prefix: &str) -> Result<Operator> {
+        let cap = op.info().capability();
+        let by_version = cap.read_with_version && cap.stat_with_version;
+        let by_etag = cap.read_with_if_match && cap.stat_with_if_match;
+        if !by_version && !by_etag {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "service can't read with versions or etags",
+            )
+            .with_operation("SnapshotLayer::capture"));
+        }
+
+        let mut entries = BTreeMap::new();
+        for entry in op.list_with(prefix).recursive(true).await? {
+            if !entry.metadata().is_file() {
+                continue;
+            }
+            let (path, mut metadata) = entry.into_parts();
+
+            // List results may not carry versions or etags.
+            let missing = if by_version {
+                metadata.version().is_none()
+            } else {
+                metadata.etag().is_none()
+            };
+            if missing {
+                metadata = op.stat(&path).await?;
+            }
+
+            let pin = match (metadata.version(), metadata.etag()) {
+                (Some(v), _) if by_version => Pin::Version(v.to_string()),
+                (_, Some(etag)) if by_etag => Pin::Etag(etag.to_string()),
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "service doesn't report the version or etag of object",
+                    )
+                    .with_operation("SnapshotLayer::capture")
+                    .with_context("path", path));
+                }
+            };
+            entries.insert(path, SnapshotEntry { metadata, pin });
+        }
+
+        Ok(op.clone().layer(SnapshotLayer {
+            entries: Arc::new(entries),
+        }))
+    }
+}
+
+impl Layer for SnapshotLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(SnapshotService {
+            inner,
+            entries: self.entries.clone(),
+        })
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SnapshotService {
+    inner: Servicer,
+    entries: Arc<BTreeMap<String, SnapshotEntry>>,
+}
+
+impl SnapshotService {
+    fn entry(&self, path: &str) -> Result<&SnapshotEntry> {
+        self.entries.get(path).ok_or_else(|| {
+            Error::new(ErrorKind::NotFound, "path is not in snapshot").with_context("path", path)
+        })
+    }
+
+    /// Check if any recorded object lives under the given dir.
+    fn has_dir(&self, dir: &str) -> bool {
+        self.entries
+            .range(dir.to_string()..)
+            .next()
+            .is_some_and(|(k, _)| k.starts_with(dir))
+    }
+
+    /// Map errors of the inner service to tell users the snapshot is broken.
+    fn map_err(err: Error, path: &str) -> Error {
+        match err.kind() {
+            ErrorKind::NotFound => err.with_context("snapshot", format!("{path} has been removed")),
+            ErrorKind::ConditionNotMatch => {
+                err.with_context("snapshot", format!("{path} has been changed"))
+            }
+            _ => err,
+        }
+    }
+}
+
+fn unsupported() -> Error {
+    Error::new(
+        ErrorKind::Unsupported,
+        "operation is not supported by snapshot",
+    )
+}
+
+impl Service for SnapshotService {
+    type Reader = oio::Reader;
+    type Writer = ();
+    type Lister = SnapshotLister;
+    type Deleter = ();
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        let inner = self.inner.capability();
+        Capability {
+            stat: true,
+            read: true,
+            list: true,
+            list_with_recursive: true,
+            shared: inner.shared,
+            ..Default::default()
+        }
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(unsupported())
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        if path.ends_with('/') {
+            let dir = if path == "/" { "" } else { path };
+            return if self.has_dir(dir) {
+                Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+            } else {
+                Err(Error::new(ErrorKind::NotFound, "path is not in snapshot")
+                    .with_context("path", path))
+            };
+        }
+
+        let entry = self.entry(path)?;
+        let args = match &entry.pin {
+            Pin::Version(v) => args.with_version(v),
+            Pin::Etag(etag) => args.with_if_match(etag),
+        };
+        self.inner
+            .stat(ctx, path, args)
+            .await
+            .map_err(|err| Self::map_err(err, path))
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let entry = self.entry(path)?;
+        let args = match &entry.pin {
+            Pin::Version(v) => args.with_version(v),
+            Pin::Etag(etag) => args.with_if_match(etag),
+        };
+        self.inner
+            .read(ctx, path, args)
+            .map_err(|err| Self::map_err(err, path))
+    }
+
+    fn write(&self, _ctx: &OperationContext, _path: &str, _args: OpWrite) -> Result<Self::Writer> {
+        Err(unsupported())
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Err(unsupported())
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let dir = if path == "/" { "" } else { path };
+
+        let mut entries = Vec::new();
+        let mut dirs = BTreeSet::new();
+        for (key, entry) in self
+            .entries
+            .range(dir.to_string()..)
+            .take_while(|(k, _)| k.starts_with(dir))
+        {
+            if !args.recursive() {
+                // Only return the first level of dirs.
+                if let Some(idx) = key[dir.len()..].find('/') {
+                    dirs.insert(&key[..dir.len() + idx + 1]);
+                    continue;
+                }
+            }
+            entries.push(oio::Entry::new(key, entry.metadata.clone()));
+        }
+        entries.extend(
+            dirs.into_iter()
+                .map(|d| oio::Entry::new(d, Metadata::new(EntryMode::DIR))),
+        );
+
+        Ok(SnapshotLister {
+            entries: entries.into_iter(),
+        })
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(unsupported())
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(unsupported())
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(unsupported())
+    }
+}
+
+#[doc(hidden)]
+pub struct SnapshotLister {
+    entries: IntoIter<oio::Entry>,
+}
+
+impl oio::List for SnapshotLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        Ok(self.entries.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::services::Memory;
+    use opendal_testkit::EtagLayer;
+
+    use super::*;
+
+    async fn new_operator() -> Operator {
+        // Memory ignores `if_match`, so `EtagLayer` goes right above it.
+        let srv: Servicer = Arc::new(Memory::default().build().unwrap());
+        let op = Operator::from_parts(OperationContext::default(), srv).layer(EtagLayer::new());
+        for (path, content) in [("data/a", "hello"), ("data/sub/b", "world"), ("other", "!")] {
+            op.write(path, content).await.unwrap();
+        }
+        op
+    }
+
+    #[tokio::test]
+    async fn test_read_snapshot() {
+        let op = new_operator().await;
+        let etag = op.stat("data/a").await.unwrap().etag().map(String::from);
+        let snapshot = SnapshotLayer::capture(&op, "data/").await.unwrap();
+
+        assert_eq!(snapshot.read("data/a").await.unwrap().to_vec(), b"hello");
+        assert_eq!(
+            snapshot.stat("data/a").await.unwrap().etag(),
+            etag.as_deref()
+        );
+        assert!(snapshot.stat("data/sub/").await.unwrap().is_dir());
+
+        // Objects out of the prefix are not in snapshot.
+        let err = snapshot.read("other").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        op.write("data/a", "changed").await.unwrap();
+        let err = snapshot.read("data/a").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        op.delete("data/sub/b").await.unwrap();
+        let err = snapshot.read("data/sub/b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_list_snapshot() {
+        let op = new_operator().await;
+        let snapshot = SnapshotLayer::capture(&op, "data/").await.unwrap();
+        op.write("data/c", "new").await.unwrap();
+
+        let paths = |entries: Vec<Entry>| {
+            let mut paths: Vec<_> = entries.into_iter().map(|e| e.path().to_string()).collect();
+            paths.sort();
+            paths
+        };
+        assert_eq!(
+            paths(snapshot.list("data/").await.unwrap()),
+            vec!["data/a", "data/sub/"]
+        );
+        assert_eq!(
+            paths(snapshot.list_with("data/").recursive(true).await.unwrap()),
+            vec!["data/a", "data/sub/b"]
+        );
+
+        let err = snapshot.write("data/a", "bytes").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+}
@@ -466,6 +466,40 @@ impl Service for TailCutService {
         )
         .await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.with_deadline(Operation::GetAcl, None, self.inner.get_acl(ctx, path, args))
+            .await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.with_deadline(Operation::SetAcl, None, self.inner.set_acl(ctx, path, args))
+            .await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.with_deadline(
+            Operation::Restore,
+            None,
+            self.inner.restore(ctx, path, args),
+        )
+        .await
+    }
 }
 
 #[doc(hidden)]
@@ -642,6 +676,19 @@ impl<R: oio::Write> oio::Write for TailCutWrapper<R> {
         )
         .await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let deadline = self.calculate_deadline(Operation::Write);
+        Self::with_io_deadline(
+            deadline,
+            self.config.percentile,
+            &self.stats,
+            self.size,
+            Operation::Write,
+            self.inner.suspend(),
+        )
+        .await
+    }
 }
 
 impl<R: oio::List> oio::List for TailCutWrapper<R> {
@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Metadata;
+use opendal_core::Result;
+use opendal_core::raw::oio;
+
+use crate::usage::TenantUsage;
+
+/// Copier that meters the bytes copied by the tenant against its quota.
+pub struct TenantCopier<C> {
+    inner: C,
+    usage: Arc<TenantUsage>,
+}
+
+impl<C> TenantCopier<C> {
+    pub(crate) fn new(inner: C, usage: Arc<TenantUsage>) -> Self {
+        Self { inner, usage }
+    }
+}
+
+impl<C: oio::Copy> oio::Copy for TenantCopier<C> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        // The size of a step is only known once it is done, so the copy fails after
+        // the step that exceeds the quota.
+        let n = self.inner.next().await?;
+        if let Some(n) = n {
+            self.usage.record_write(n)?;
+        }
+        Ok(n)
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
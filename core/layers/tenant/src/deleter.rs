@@ -0,0 +1,46 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Result;
+use opendal_core::raw::*;
+
+use crate::jail;
+
+/// Deleter that resolves paths under the tenant prefix.
+pub struct TenantDeleter<D> {
+    inner: D,
+    prefix: Arc<str>,
+}
+
+impl<D> TenantDeleter<D> {
+    pub(crate) fn new(inner: D, prefix: Arc<str>) -> Self {
+        Self { inner, prefix }
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for TenantDeleter<D> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let path = jail(&self.prefix, path)?;
+        self.inner.delete(&path, args).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,366 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tenant isolation layer for Apache OpenDAL.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+mod copier;
+mod deleter;
+mod lister;
+mod reader;
+mod usage;
+mod writer;
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub use copier::TenantCopier;
+pub use deleter::TenantDeleter;
+pub use lister::TenantLister;
+pub use reader::TenantReader;
+pub use usage::TenantUsage;
+pub use writer::TenantWriter;
+
+/// Tenant layer that jails all paths into a per-tenant prefix and meters the bytes
+/// transferred by the tenant.
+///
+/// # Operation Behavior
+///
+/// - Every path is resolved under the tenant prefix before being passed to the
+///   underlying service. Paths that contain `..` or `.` segments are rejected with
+///   [`ErrorKind::PermissionDenied`], so a tenant can never reach outside its prefix.
+/// - `list`: entries are returned relative to the tenant prefix, and entries outside
+///   of it are skipped.
+/// - `read`: the bytes returned to the caller are added to [`TenantUsage::bytes_read`].
+/// - `write` and `copy`: the bytes transferred are added to
+///   [`TenantUsage::bytes_written`].
+///
+/// # Quota
+///
+/// [`TenantLayer::with_write_quota`] limits the total bytes a tenant can write
+/// through this layer. A write that would exceed the quota fails with
+/// [`ErrorKind::PermissionDenied`] before sending any data. Server-side copies only
+/// report their size once a step is done, so a copy fails after the step that
+/// exceeds the quota. The quota is accounted on transferred bytes, so overwriting or
+/// deleting files doesn't release it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_tenant::TenantLayer;
+/// # async fn test() -> Result<()> {
+/// let layer = TenantLayer::new("tenant-a").with_write_quota(1024 * 1024);
+/// let usage = layer.usage();
+///
+/// let op = Operator::new(services::Memory::default())?.layer(layer);
+///
+/// // Stored as `tenant-a/test` in the underlying service.
+/// op.write("test", "Hello, World!").await?;
+/// assert_eq!(usage.bytes_written(), 13);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TenantLayer {
+    prefix: Arc<str>,
+    usage: Arc<TenantUsage>,
+}
+
+impl Debug for TenantLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantLayer")
+            .field("prefix", &self.prefix)
+            .field("usage", &self.usage)
+            .finish()
+    }
+}
+
+impl TenantLayer {
+    /// Create a new `TenantLayer` that jails all paths into the given prefix.
+    ///
+    /// Leading and trailing `/` of the prefix are ignored, so `tenant-a`,
+    /// `/tenant-a` and `tenant-a/` are the same.
+    pub fn new(prefix: &str) -> Self {
+        let prefix = prefix.trim_matches('/');
+        let prefix = if prefix.is_empty() {
+            String::new()
+        } else {
+            format!("{prefix}/")
+        };
+
+        Self {
+            prefix: prefix.into(),
+            usage: Arc::new(TenantUsage::new(None)),
+        }
+    }
+
+    /// Limit the total bytes the tenant can write through this layer.
+    pub fn with_write_quota(mut self, quota: u64) -> Self {
+        self.usage = Arc::new(TenantUsage::new(Some(quota)));
+        self
+    }
+
+    /// Get the usage counters of this tenant.
+    ///
+    /// The counters are shared by all operators built with this layer.
+    pub fn usage(&self) -> Arc<TenantUsage> {
+        self.usage.clone()
+    }
+}
+
+impl Layer for TenantLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(TenantService {
+            inner,
+            prefix: self.prefix.clone(),
+            usage: self.usage.clone(),
+        })
+    }
+}
+
+/// Service wrapped by [`TenantLayer`].
+pub struct TenantService {
+    inner: Servicer,
+    prefix: Arc<str>,
+    usage: Arc<TenantUsage>,
+}
+
+impl Debug for TenantService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantService")
+            .field("inner", &self.inner)
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TenantService {
+    fn jail(&self, path: &str) -> Result<String> {
+        jail(&self.prefix, path)
+    }
+}
+
+/// Resolve the path under the tenant prefix, rejecting paths that could escape it.
+pub(crate) fn jail(prefix: &str, path: &str) -> Result<String> {
+    if path.split('/').any(|seg| seg == ".." || seg == ".") {
+        return Err(Error::new(
+            ErrorKind::PermissionDenied,
+            "path escapes the tenant prefix",
+        )
+        .with_context("prefix", prefix)
+        .with_context("path", path));
+    }
+
+    let path = path.trim_start_matches('/');
+    Ok(format!("{prefix}{path}"))
+}
+
+impl Service for TenantService {
+    type Reader = TenantReader<oio::Reader>;
+    type Writer = TenantWriter<oio::Writer>;
+    type Lister = TenantLister<oio::Lister>;
+    type Deleter = TenantDeleter<oio::Deleter>;
+    type Copier = TenantCopier<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, &self.jail(path)?, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, &self.jail(path)?, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let r = self.inner.read(ctx, &self.jail(path)?, args)?;
+        Ok(TenantReader::new(r, self.usage.clone()))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, &self.jail(path)?, args)?;
+        Ok(TenantWriter::new(w, self.usage.clone()))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let d = self.inner.delete(ctx)?;
+        Ok(TenantDeleter::new(d, self.prefix.clone()))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, mut args: OpList) -> Result<Self::Lister> {
+        if let Some(start_after) = args.start_after() {
+            let start_after = self.jail(start_after)?;
+            args = args.with_start_after(&start_after);
+        }
+
+        let l = self.inner.list(ctx, &self.jail(path)?, args)?;
+        Ok(TenantLister::new(l, self.prefix.clone()))
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let c = self
+            .inner
+            .copy(ctx, &self.jail(from)?, &self.jail(to)?, args, opts)?;
+        Ok(TenantCopier::new(c, self.usage.clone()))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner
+            .rename(ctx, &self.jail(from)?, &self.jail(to)?, args)
+            .await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, &self.jail(path)?, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, &self.jail(path)?, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, &self.jail(path)?, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, &self.jail(path)?, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    #[test]
+    fn test_jail() {
+        assert_eq!(jail("a/", "b/c").unwrap(), "a/b/c");
+        assert_eq!(jail("a/", "/").unwrap(), "a/");
+        assert_eq!(jail("", "b").unwrap(), "b");
+
+        for path in ["../b", "b/../../c", "b/./c", ".."] {
+            let err = jail("a/", path).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::PermissionDenied, "{path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_isolate_tenants() {
+        let inner = Operator::new(Memory::default()).unwrap();
+        let a = inner.clone().layer(TenantLayer::new("a"));
+        let b = inner.clone().layer(TenantLayer::new("/b/"));
+
+        a.write("dir/file", "a").await.unwrap();
+        b.write("dir/file", "bb").await.unwrap();
+
+        assert_eq!(inner.read("a/dir/file").await.unwrap().to_vec(), b"a");
+        assert_eq!(inner.read("b/dir/file").await.unwrap().to_vec(), b"bb");
+        assert_eq!(a.read("dir/file").await.unwrap().to_vec(), b"a");
+
+        let mut paths: Vec<String> = a
+            .lister_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        paths.sort();
+        assert_eq!(paths, vec!["dir/file"]);
+
+        a.delete("dir/file").await.unwrap();
+        assert!(!inner.exists("a/dir/file").await.unwrap());
+        assert!(inner.exists("b/dir/file").await.unwrap());
+
+        let err = a.read("../b/dir/file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn test_meter_usage() {
+        let layer = TenantLayer::new("a").with_write_quota(8);
+        let usage = layer.usage();
+        let op = Operator::new(Memory::default()).unwrap().layer(layer);
+
+        op.write("x", "12345").await.unwrap();
+        op.read("x").await.unwrap();
+        op.read_with("x").range(1..3).await.unwrap();
+        assert_eq!(usage.bytes_written(), 5);
+        assert_eq!(usage.bytes_read(), 7);
+
+        let err = op.write("y", "12345").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(usage.bytes_written(), 5);
+        assert_eq!(usage.remaining_quota(), Some(3));
+    }
+}
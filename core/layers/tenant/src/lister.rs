@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Result;
+use opendal_core::raw::*;
+
+/// Lister that returns entries relative to the tenant prefix.
+pub struct TenantLister<L> {
+    inner: L,
+    prefix: Arc<str>,
+}
+
+impl<L> TenantLister<L> {
+    pub(crate) fn new(inner: L, prefix: Arc<str>) -> Self {
+        Self { inner, prefix }
+    }
+}
+
+impl<L: oio::List> oio::List for TenantLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        while let Some(mut entry) = self.inner.next().await? {
+            // Entries outside the tenant prefix should never be returned, skip them
+            // in case the underlying service does.
+            let Some(path) = entry.path().strip_prefix(self.prefix.as_ref()) else {
+                continue;
+            };
+            let path = if path.is_empty() { "/" } else { path }.to_string();
+            entry.set_path(&path);
+            return Ok(Some(entry));
+        }
+
+        Ok(None)
+    }
+}
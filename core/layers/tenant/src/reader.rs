@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::usage::TenantUsage;
+
+/// Reader that meters the bytes read by the tenant.
+pub struct TenantReader<R> {
+    inner: R,
+    usage: Arc<TenantUsage>,
+}
+
+impl<R> TenantReader<R> {
+    pub(crate) fn new(inner: R, usage: Arc<TenantUsage>) -> Self {
+        Self { inner, usage }
+    }
+}
+
+impl<R: oio::Read> oio::Read for TenantReader<R> {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let (rp, stream) = self.inner.open(range).await?;
+        Ok((
+            rp,
+            Box::new(TenantReader::new(stream, self.usage.clone())) as Box<dyn oio::ReadStreamDyn>,
+        ))
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let (rp, bs) = self.inner.read(range).await?;
+        self.usage.record_read(bs.len());
+        Ok((rp, bs))
+    }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        let (rp, bufs) = self.inner.read_ranges(ranges).await?;
+        self.usage
+            .record_read(bufs.iter().map(|bs| bs.len()).sum::<usize>());
+        Ok((rp, bufs))
+    }
+}
+
+impl<R: oio::ReadStream> oio::ReadStream for TenantReader<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let bs = self.inner.read().await?;
+        self.usage.record_read(bs.len());
+        Ok(bs)
+    }
+}
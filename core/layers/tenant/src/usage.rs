@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use opendal_core::*;
+
+/// Usage counters of a tenant.
+pub struct TenantUsage {
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+    write_quota: Option<u64>,
+}
+
+impl Debug for TenantUsage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantUsage")
+            .field("bytes_read", &self.bytes_read())
+            .field("bytes_written", &self.bytes_written())
+            .field("write_quota", &self.write_quota)
+            .finish()
+    }
+}
+
+impl TenantUsage {
+    pub(crate) fn new(write_quota: Option<u64>) -> Self {
+        Self {
+            bytes_read: AtomicU64::new(0),
+            bytes_written: AtomicU64::new(0),
+            write_quota,
+        }
+    }
+
+    /// Total bytes read by the tenant.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read.load(Ordering::Relaxed)
+    }
+
+    /// Total bytes written by the tenant, including server-side copies.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// The write quota of the tenant, `None` means unlimited.
+    pub fn write_quota(&self) -> Option<u64> {
+        self.write_quota
+    }
+
+    /// Bytes the tenant can still write, `None` means unlimited.
+    pub fn remaining_quota(&self) -> Option<u64> {
+        self.write_quota
+            .map(|quota| quota.saturating_sub(self.bytes_written()))
+    }
+
+    pub(crate) fn record_read(&self, n: usize) {
+        self.bytes_read.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Account `n` bytes to be written, failing if the quota would be exceeded.
+    pub(crate) fn acquire_write(&self, n: usize) -> Result<()> {
+        let n = n as u64;
+        let Some(quota) = self.write_quota else {
+            self.bytes_written.fetch_add(n, Ordering::Relaxed);
+            return Ok(());
+        };
+
+        let mut used = self.bytes_written.load(Ordering::Relaxed);
+        loop {
+            match used.checked_add(n).filter(|total| *total <= quota) {
+                Some(total) => match self.bytes_written.compare_exchange_weak(
+                    used,
+                    total,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return Ok(()),
+                    Err(actual) => used = actual,
+                },
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::PermissionDenied,
+                        "tenant write quota exceeded",
+                    )
+                    .with_context("quota", quota)
+                    .with_context("used", used)
+                    .with_context("size", n));
+                }
+            }
+        }
+    }
+
+    /// Account `n` bytes that have already been written, like server-side copies.
+    ///
+    /// The bytes are always accounted, but an error is returned if the quota has
+    /// been exceeded.
+    pub(crate) fn record_write(&self, n: usize) -> Result<()> {
+        let n = n as u64;
+        let used = self.bytes_written.fetch_add(n, Ordering::Relaxed) + n;
+        match self.write_quota {
+            Some(quota) if used > quota => Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "tenant write quota exceeded",
+            )
+            .with_context("quota", quota)
+            .with_context("used", used)),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use opendal_core::Buffer;
+use opendal_core::Metadata;
+use opendal_core::Result;
+use opendal_core::WriteCheckpoint;
+use opendal_core::raw::oio;
+
+use crate::usage::TenantUsage;
+
+/// Writer that meters the bytes written by the tenant against its quota.
+pub struct TenantWriter<W> {
+    inner: W,
+    usage: Arc<TenantUsage>,
+}
+
+impl<W> TenantWriter<W> {
+    pub(crate) fn new(inner: W, usage: Arc<TenantUsage>) -> Self {
+        Self { inner, usage }
+    }
+}
+
+impl<W: oio::Write> oio::Write for TenantWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.usage.acquire_write(bs.len())?;
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
+}
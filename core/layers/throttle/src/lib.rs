@@ -41,10 +41,14 @@ use opendal_core::*;
 /// [Governor](https://docs.rs/governor/latest/governor/index.html).
 /// By setting the `bandwidth` and `burst`, we can control the byte flow rate of underlying services.
 ///
+/// Read and write can be throttled separately by [`ThrottleLayer::with_read`] and
+/// [`ThrottleLayer::with_write`]. The quota is shared by all readers and writers created
+/// from the same operator, so concurrent operations can't exceed the bandwidth together.
+///
 /// # Note
 ///
-/// When setting the ThrottleLayer, always consider the largest possible operation size as the burst size,
-/// as **the burst size should be larger than any possible byte length to allow it to pass through**.
+/// Bytes larger than the burst size are consumed in multiple rounds, so a smaller burst size
+/// leads to smoother traffic while a larger burst size allows short spikes.
 ///
 /// Read more about [Quota](https://docs.rs/governor/latest/governor/struct.Quota.html#examples)
 ///
@@ -65,26 +69,62 @@ use opendal_core::*;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug)]
+///
+/// This example limits read to 100 MiB/s and write to 10 MiB/s.
+///
+/// ```no_run
+/// # use opendal_core::services;
+/// # use opendal_core::Operator;
+/// # use opendal_core::Result;
+/// # use opendal_layer_throttle::ThrottleLayer;
+/// #
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         ThrottleLayer::default()
+///             .with_read(100 * 1024 * 1024, 8 * 1024 * 1024)
+///             .with_write(10 * 1024 * 1024, 8 * 1024 * 1024),
+///     );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
 pub struct ThrottleLayer {
-    rate_limiter: SharedRateLimiter,
+    read: Option<SharedRateLimiter>,
+    write: Option<SharedRateLimiter>,
 }
 
 impl ThrottleLayer {
     /// Create a new `ThrottleLayer` with given bandwidth and burst.
     ///
+    /// The quota is shared by both read and write.
+    ///
     /// - bandwidth: the maximum number of bytes allowed to pass through per second.
     /// - burst: the maximum number of bytes allowed to pass through at once.
     pub fn new(bandwidth: u32, burst: u32) -> Self {
-        assert!(bandwidth > 0);
-        assert!(burst > 0);
+        let limiter = SharedRateLimiter::new(bandwidth, burst);
         Self {
-            rate_limiter: Arc::new(RateLimiter::direct(
-                Quota::per_second(NonZeroU32::new(bandwidth).unwrap())
-                    .allow_burst(NonZeroU32::new(burst).unwrap()),
-            )),
+            read: Some(limiter.clone()),
+            write: Some(limiter),
         }
     }
+
+    /// Throttle read with given bandwidth and burst.
+    ///
+    /// This replaces the read quota set by [`ThrottleLayer::new`].
+    pub fn with_read(mut self, bandwidth: u32, burst: u32) -> Self {
+        self.read = Some(SharedRateLimiter::new(bandwidth, burst));
+        self
+    }
+
+    /// Throttle write with given bandwidth and burst.
+    ///
+    /// This replaces the write quota set by [`ThrottleLayer::new`].
+    pub fn with_write(mut self, bandwidth: u32, burst: u32) -> Self {
+        self.write = Some(SharedRateLimiter::new(bandwidth, burst));
+        self
+    }
 }
 
 impl Layer for ThrottleLayer {
@@ -97,7 +137,8 @@ impl ThrottleLayer {
     fn layer(&self, inner: Servicer) -> ThrottleAccessor {
         ThrottleAccessor {
             inner,
-            rate_limiter: self.rate_limiter.clone(),
+            read: self.read.clone(),
+            write: self.write.clone(),
         }
     }
 }
@@ -105,13 +146,50 @@ impl ThrottleLayer {
 /// Share an atomic RateLimiter instance across all threads in one operator.
 /// If want to add more observability in the future, replace the default NoOpMiddleware with other middleware types.
 /// Read more about [Middleware](https://docs.rs/governor/latest/governor/middleware/index.html)
-type SharedRateLimiter = Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>;
+#[derive(Clone, Debug)]
+struct SharedRateLimiter {
+    limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
+    burst: NonZeroU32,
+}
+
+impl SharedRateLimiter {
+    fn new(bandwidth: u32, burst: u32) -> Self {
+        let bandwidth = NonZeroU32::new(bandwidth).expect("bandwidth must be larger than 0");
+        let burst = NonZeroU32::new(burst).expect("burst must be larger than 0");
+        Self {
+            limiter: Arc::new(RateLimiter::direct(
+                Quota::per_second(bandwidth).allow_burst(burst),
+            )),
+            burst,
+        }
+    }
+
+    /// Wait until `len` bytes are allowed to pass through.
+    ///
+    /// Bytes larger than burst are consumed in multiple rounds.
+    async fn until_ready(&self, len: usize) -> Result<()> {
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = remaining.min(self.burst.get() as usize);
+            let n = NonZeroU32::new(n as u32).expect("n is non-zero so NonZeroU32 must exist");
+            self.limiter.until_n_ready(n).await.map_err(|_| {
+                Error::new(
+                    ErrorKind::RateLimited,
+                    "burst size is smaller than the request size",
+                )
+            })?;
+            remaining -= n.get() as usize;
+        }
+        Ok(())
+    }
+}
 
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct ThrottleAccessor {
     inner: Servicer,
-    rate_limiter: SharedRateLimiter,
+    read: Option<SharedRateLimiter>,
+    write: Option<SharedRateLimiter>,
 }
 
 impl Service for ThrottleAccessor {
@@ -143,7 +221,7 @@ impl Service for ThrottleAccessor {
     }
 
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
-        let limiter = self.rate_limiter.clone();
+        let limiter = self.read.clone();
 
         self.inner
             .read(ctx, path, args)
@@ -151,7 +229,7 @@ impl Service for ThrottleAccessor {
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
-        let limiter = self.rate_limiter.clone();
+        let limiter = self.write.clone();
 
         self.inner
             .write(ctx, path, args)
@@ -195,26 +273,59 @@ impl Service for ThrottleAccessor {
     ) -> Result<RpPresign> {
         self.inner.presign(ctx, path, args).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
 }
 
 #[doc(hidden)]
 pub struct ThrottleWrapper<R> {
     inner: R,
-    limiter: SharedRateLimiter,
+    limiter: Option<SharedRateLimiter>,
 }
 
 impl<R> ThrottleWrapper<R> {
-    fn new(inner: R, rate_limiter: SharedRateLimiter) -> Self {
-        Self {
-            inner,
-            limiter: rate_limiter,
+    fn new(inner: R, limiter: Option<SharedRateLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+
+    async fn until_ready(&self, len: usize) -> Result<()> {
+        match &self.limiter {
+            Some(limiter) => limiter.until_ready(len).await,
+            None => Ok(()),
         }
     }
 }
 
 impl<R: oio::ReadStream> oio::ReadStream for ThrottleWrapper<R> {
     async fn read(&mut self) -> Result<Buffer> {
-        self.inner.read().await
+        let bs = self.inner.read().await?;
+        self.until_ready(bs.len()).await?;
+        Ok(bs)
     }
 }
 
@@ -229,34 +340,15 @@ impl<R: oio::Read> oio::Read for ThrottleWrapper<R> {
     }
 
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
-        self.inner.read(range).await
+        let (rp, bs) = self.inner.read(range).await?;
+        self.until_ready(bs.len()).await?;
+        Ok((rp, bs))
     }
 }
 
 impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
-        let len = bs.len();
-        if len == 0 {
-            return self.inner.write(bs).await;
-        }
-
-        if len > u32::MAX as usize {
-            return Err(Error::new(
-                ErrorKind::RateLimited,
-                "request size exceeds throttle quota capacity",
-            ));
-        }
-
-        let buf_length =
-            NonZeroU32::new(len as u32).expect("len is non-zero so NonZeroU32 must exist");
-
-        self.limiter.until_n_ready(buf_length).await.map_err(|_| {
-            Error::new(
-                ErrorKind::RateLimited,
-                "burst size is smaller than the request size",
-            )
-        })?;
-
+        self.until_ready(bs.len()).await?;
         self.inner.write(bs).await
     }
 
@@ -267,4 +359,8 @@ impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
 }
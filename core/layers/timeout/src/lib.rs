@@ -43,10 +43,31 @@ use opendal_core::*;
 ///   and `list`, and every method call on returned readers, writers, listers,
 ///   deleters, and copiers.
 ///
+/// Finer grained budgets can be set for specific operations. They replace the
+/// budgets above when set:
+///
+/// - `metadata_timeout` bounds `stat` and every `Lister::next` call.
+/// - `first_byte_timeout` bounds opening each read stream, which lasts until the
+///   service starts responding. Reading the body is still bounded by `io_timeout`.
+/// - `write_chunk_timeout` bounds every `Writer::write` call. Chunks uploaded
+///   concurrently in the background are still bounded by `io_timeout`.
+/// - `deadline` bounds the whole operation, from calling it until its returned
+///   reader, writer, lister, deleter or copier finishes. Every call is bounded by
+///   what's left of the deadline.
+///
+/// Reads and writes can override `first_byte_timeout`, `write_chunk_timeout` and
+/// `deadline` per operation, see [`ReadOptions::first_byte_timeout`],
+/// [`WriteOptions::chunk_timeout`] and [`ReadOptions::deadline`].
+///
+/// [`ReadOptions::first_byte_timeout`]: options::ReadOptions::first_byte_timeout
+/// [`WriteOptions::chunk_timeout`]: options::WriteOptions::chunk_timeout
+/// [`ReadOptions::deadline`]: options::ReadOptions::deadline
+///
 /// # Default
 ///
 /// - timeout: 60 seconds
 /// - io_timeout: 10 seconds
+/// - metadata_timeout, first_byte_timeout, write_chunk_timeout and deadline: not set
 ///
 /// # Cancellation Safety
 ///
@@ -123,6 +144,10 @@ use opendal_core::*;
 pub struct TimeoutLayer {
     timeout: Duration,
     io_timeout: Duration,
+    metadata_timeout: Option<Duration>,
+    first_byte_timeout: Option<Duration>,
+    write_chunk_timeout: Option<Duration>,
+    deadline: Option<Duration>,
 }
 
 impl Default for TimeoutLayer {
@@ -130,6 +155,10 @@ impl Default for TimeoutLayer {
         Self {
             timeout: Duration::from_secs(60),
             io_timeout: Duration::from_secs(10),
+            metadata_timeout: None,
+            first_byte_timeout: None,
+            write_chunk_timeout: None,
+            deadline: None,
         }
     }
 }
@@ -155,6 +184,40 @@ impl TimeoutLayer {
         self.io_timeout = timeout;
         self
     }
+
+    /// Set the timeout for metadata operations.
+    ///
+    /// This timeout is for `stat` and every `Lister::next` call.
+    pub fn with_metadata_timeout(mut self, timeout: Duration) -> Self {
+        self.metadata_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for the service to start responding to reads.
+    ///
+    /// This timeout is for opening every read stream, reading the body is
+    /// bounded by the IO timeout.
+    pub fn with_first_byte_timeout(mut self, timeout: Duration) -> Self {
+        self.first_byte_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for writing a chunk.
+    ///
+    /// This timeout is for every `Writer::write` call.
+    pub fn with_write_chunk_timeout(mut self, timeout: Duration) -> Self {
+        self.write_chunk_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the total time allowed for an operation.
+    ///
+    /// The deadline starts when the operation is called and covers every call on
+    /// the returned reader, writer, lister, deleter or copier.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
 }
 
 impl Layer for TimeoutLayer {
@@ -178,6 +241,10 @@ impl TimeoutLayer {
             inner,
             timeout: self.timeout,
             io_timeout: self.io_timeout,
+            metadata_timeout: self.metadata_timeout,
+            first_byte_timeout: self.first_byte_timeout,
+            write_chunk_timeout: self.write_chunk_timeout,
+            deadline: self.deadline,
         }
     }
 }
@@ -188,16 +255,32 @@ pub struct TimeoutService {
     inner: Servicer,
     timeout: Duration,
     io_timeout: Duration,
+    metadata_timeout: Option<Duration>,
+    first_byte_timeout: Option<Duration>,
+    write_chunk_timeout: Option<Duration>,
+    deadline: Option<Duration>,
 }
 
 impl TimeoutService {
-    async fn timeout<F: Future<Output = Result<T>>, T>(&self, op: Operation, fut: F) -> Result<T> {
-        tokio::time::timeout(self.timeout, fut).await.map_err(|_| {
-            Error::new(ErrorKind::Unexpected, "operation timeout reached")
-                .with_operation(op)
-                .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                .set_temporary()
-        })?
+    /// Get the instant the operation started now must finish by.
+    fn deadline(&self, deadline: Option<Duration>) -> Option<Instant> {
+        deadline.or(self.deadline).map(|v| Instant::now() + v)
+    }
+
+    async fn timeout<F: Future<Output = Result<T>>, T>(
+        &self,
+        op: Operation,
+        timeout: Duration,
+        fut: F,
+    ) -> Result<T> {
+        with_timeout(
+            timeout,
+            self.deadline(None),
+            op.into_static(),
+            "operation timeout reached",
+            fut,
+        )
+        .await
     }
 }
 
@@ -222,20 +305,32 @@ impl Service for TimeoutService {
         path: &str,
         args: OpCreateDir,
     ) -> Result<RpCreateDir> {
-        self.timeout(Operation::CreateDir, self.inner.create_dir(ctx, path, args))
-            .await
+        self.timeout(
+            Operation::CreateDir,
+            self.timeout,
+            self.inner.create_dir(ctx, path, args),
+        )
+        .await
     }
 
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
-        self.inner
-            .read(ctx, path, args)
-            .map(|r| TimeoutWrapper::new(r, self.io_timeout))
+        let deadline = self.deadline(args.deadline());
+        let first_byte_timeout = args.first_byte_timeout().or(self.first_byte_timeout);
+        self.inner.read(ctx, path, args).map(|r| {
+            TimeoutWrapper::new(r, self.io_timeout)
+                .with_first_byte_timeout(first_byte_timeout)
+                .with_deadline(deadline)
+        })
     }
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
-        self.inner
-            .write(ctx, path, args)
-            .map(|r| TimeoutWrapper::new(r, self.io_timeout))
+        let deadline = self.deadline(args.deadline());
+        let chunk_timeout = args.chunk_timeout().or(self.write_chunk_timeout);
+        self.inner.write(ctx, path, args).map(|w| {
+            TimeoutWrapper::new(w, self.io_timeout)
+                .with_chunk_timeout(chunk_timeout)
+                .with_deadline(deadline)
+        })
     }
 
     fn copy(
@@ -248,7 +343,7 @@ impl Service for TimeoutService {
     ) -> Result<Self::Copier> {
         self.inner
             .copy(ctx, from, to, args, opts)
-            .map(|c| TimeoutWrapper::new(c, self.io_timeout))
+            .map(|c| TimeoutWrapper::new(c, self.io_timeout).with_deadline(self.deadline(None)))
     }
 
     async fn rename(
@@ -258,25 +353,34 @@ impl Service for TimeoutService {
         to: &str,
         args: OpRename,
     ) -> Result<RpRename> {
-        self.timeout(Operation::Rename, self.inner.rename(ctx, from, to, args))
-            .await
+        self.timeout(
+            Operation::Rename,
+            self.timeout,
+            self.inner.rename(ctx, from, to, args),
+        )
+        .await
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
-        self.timeout(Operation::Stat, self.inner.stat(ctx, path, args))
-            .await
+        self.timeout(
+            Operation::Stat,
+            self.metadata_timeout.unwrap_or(self.timeout),
+            self.inner.stat(ctx, path, args),
+        )
+        .await
     }
 
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
         self.inner
             .delete(ctx)
-            .map(|r| TimeoutWrapper::new(r, self.io_timeout))
+            .map(|r| TimeoutWrapper::new(r, self.io_timeout).with_deadline(self.deadline(None)))
     }
 
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let timeout = self.metadata_timeout.unwrap_or(self.io_timeout);
         self.inner
             .list(ctx, path, args)
-            .map(|r| TimeoutWrapper::new(r, self.io_timeout))
+            .map(|r| TimeoutWrapper::new(r, timeout).with_deadline(self.deadline(None)))
     }
 
     async fn presign(
@@ -285,8 +389,54 @@ impl Service for TimeoutService {
         path: &str,
         args: OpPresign,
     ) -> Result<RpPresign> {
-        self.timeout(Operation::Presign, self.inner.presign(ctx, path, args))
-            .await
+        self.timeout(
+            Operation::Presign,
+            self.timeout,
+            self.inner.presign(ctx, path, args),
+        )
+        .await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.timeout(
+            Operation::GetAcl,
+            self.timeout,
+            self.inner.get_acl(ctx, path, args),
+        )
+        .await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.timeout(
+            Operation::SetAcl,
+            self.timeout,
+            self.inner.set_acl(ctx, path, args),
+        )
+        .await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.timeout(
+            Operation::Restore,
+            self.timeout,
+            self.inner.restore(ctx, path, args),
+        )
+        .await
     }
 }
 
@@ -311,57 +461,112 @@ impl Execute for TimeoutExecutor {
     }
 }
 
+/// Run `fut` within `timeout`, or what's left before `deadline` if that's sooner.
+async fn with_timeout<F: Future<Output = Result<T>>, T>(
+    timeout: Duration,
+    deadline: Option<Instant>,
+    op: &'static str,
+    message: &'static str,
+    fut: F,
+) -> Result<T> {
+    let remaining = deadline.map(|v| v.saturating_duration_since(Instant::now()));
+    match remaining {
+        Some(remaining) if remaining < timeout => {
+            tokio::time::timeout(remaining, fut).await.map_err(|_| {
+                // Retrying can't help since the deadline is shared by all calls.
+                Error::new(ErrorKind::Unexpected, "operation deadline reached")
+                    .with_operation(op)
+                    .with_context("remaining", remaining.as_secs_f64().to_string())
+            })?
+        }
+        _ => tokio::time::timeout(timeout, fut).await.map_err(|_| {
+            Error::new(ErrorKind::Unexpected, message)
+                .with_operation(op)
+                .with_context("timeout", timeout.as_secs_f64().to_string())
+                .set_temporary()
+        })?,
+    }
+}
+
 #[doc(hidden)]
 pub struct TimeoutWrapper<R> {
     inner: R,
 
     timeout: Duration,
+    /// Timeout for `open`, falls back to `timeout`.
+    first_byte_timeout: Option<Duration>,
+    /// Timeout for `write`, falls back to `timeout`.
+    chunk_timeout: Option<Duration>,
+    deadline: Option<Instant>,
 }
 
 impl<R> TimeoutWrapper<R> {
     fn new(inner: R, timeout: Duration) -> Self {
-        Self { inner, timeout }
+        Self {
+            inner,
+            timeout,
+            first_byte_timeout: None,
+            chunk_timeout: None,
+            deadline: None,
+        }
+    }
+
+    fn with_first_byte_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.first_byte_timeout = timeout;
+        self
+    }
+
+    fn with_chunk_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.chunk_timeout = timeout;
+        self
+    }
+
+    fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
     }
 
     #[inline]
     async fn io_timeout<F: Future<Output = Result<T>>, T>(
         timeout: Duration,
+        deadline: Option<Instant>,
         op: &'static str,
         fut: F,
     ) -> Result<T> {
-        tokio::time::timeout(timeout, fut).await.map_err(|_| {
-            Error::new(ErrorKind::Unexpected, "io operation timeout reached")
-                .with_operation(op)
-                .with_context("timeout", timeout.as_secs_f64().to_string())
-                .set_temporary()
-        })?
+        with_timeout(timeout, deadline, op, "io operation timeout reached", fut).await
     }
 }
 
 impl<R: oio::ReadStream> oio::ReadStream for TimeoutWrapper<R> {
     async fn read(&mut self) -> Result<Buffer> {
         let fut = self.inner.read();
-        Self::io_timeout(self.timeout, Operation::Read.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Read.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
     async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
         let (rp, stream) = Self::io_timeout(
-            self.timeout,
+            self.first_byte_timeout.unwrap_or(self.timeout),
+            self.deadline,
             Operation::Read.into_static(),
             self.inner.open(range),
         )
         .await?;
-        Ok((
-            rp,
-            Box::new(TimeoutWrapper::new(stream, self.timeout)) as Box<dyn oio::ReadStreamDyn>,
-        ))
+        let stream = TimeoutWrapper::new(stream, self.timeout).with_deadline(self.deadline);
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
     }
 
     async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
         Self::io_timeout(
             self.timeout,
+            self.deadline,
             Operation::Read.into_static(),
             self.inner.read(range),
         )
@@ -372,53 +577,118 @@ impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
 impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
         let fut = self.inner.write(bs);
-        Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
+        Self::io_timeout(
+            self.chunk_timeout.unwrap_or(self.timeout),
+            self.deadline,
+            Operation::Write.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn close(&mut self) -> Result<Metadata> {
         let fut = self.inner.close();
-        Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Write.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn abort(&mut self) -> Result<()> {
         let fut = self.inner.abort();
-        Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Write.into_static(),
+            fut,
+        )
+        .await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let fut = self.inner.suspend();
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Write.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<R: oio::List> oio::List for TimeoutWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         let fut = self.inner.next();
-        Self::io_timeout(self.timeout, Operation::List.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::List.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<R: oio::Delete> oio::Delete for TimeoutWrapper<R> {
     async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
         let fut = self.inner.delete(path, args);
-        Self::io_timeout(self.timeout, Operation::Delete.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Delete.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn close(&mut self) -> Result<()> {
         let fut = self.inner.close();
-        Self::io_timeout(self.timeout, Operation::Delete.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Delete.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<C: oio::Copy> oio::Copy for TimeoutWrapper<C> {
     async fn next(&mut self) -> Result<Option<usize>> {
         let fut = self.inner.next();
-        Self::io_timeout(self.timeout, Operation::Copy.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Copy.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn close(&mut self) -> Result<Metadata> {
         let fut = self.inner.close();
-        Self::io_timeout(self.timeout, Operation::Copy.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Copy.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn abort(&mut self) -> Result<()> {
         let fut = self.inner.abort();
-        Self::io_timeout(self.timeout, Operation::Copy.into_static(), fut).await
+        Self::io_timeout(
+            self.timeout,
+            self.deadline,
+            Operation::Copy.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
@@ -436,7 +706,7 @@ mod tests {
 
     impl Service for MockService {
         type Reader = MockReader;
-        type Writer = ();
+        type Writer = MockWriter;
         type Lister = MockLister;
         type Deleter = MockDeleter;
         type Copier = MockCopier;
@@ -448,6 +718,7 @@ mod tests {
         fn capability(&self) -> Capability {
             Capability {
                 read: true,
+                write: true,
                 delete: true,
                 list: true,
                 copy: true,
@@ -479,11 +750,9 @@ mod tests {
             Ok(MockReader)
         }
 
+        /// Return a writer whose writes never complete.
         fn write(&self, _ctx: &OperationContext, _: &str, _: OpWrite) -> Result<Self::Writer> {
-            Err(Error::new(
-                ErrorKind::Unsupported,
-                "operation is not supported",
-            ))
+            Ok(MockWriter)
         }
 
         /// Return a deleter whose operations never complete.
@@ -540,6 +809,23 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Clone, Default)]
+    struct MockWriter;
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, _: Buffer) -> Result<()> {
+            pending().await
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::default())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
     #[derive(Debug, Clone, Default)]
     struct MockLister;
 
@@ -678,4 +964,86 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::Unexpected);
         assert!(err.to_string().contains("timeout"));
     }
+
+    #[tokio::test]
+    async fn test_metadata_timeout() {
+        use oio::List;
+
+        let service = TimeoutLayer::default()
+            .with_metadata_timeout(Duration::from_millis(100))
+            .apply_service(Arc::new(MockService));
+        let ctx = OperationContext::new();
+        let mut lister = service.list(&ctx, "test", OpList::default()).unwrap();
+
+        let fut = async {
+            let err = lister.next().await.unwrap_err();
+            assert!(err.to_string().contains("timeout"));
+            assert!(err.is_temporary());
+        };
+        timeout(Duration::from_secs(2), fut)
+            .await
+            .expect("this test should not exceed 2 seconds")
+    }
+
+    #[tokio::test]
+    async fn test_first_byte_timeout() {
+        use oio::Read;
+
+        let service = TimeoutLayer::default().apply_service(Arc::new(MockService));
+        let ctx = OperationContext::new();
+        let args = OpRead::new().with_first_byte_timeout(Duration::from_millis(100));
+        let reader = service.read(&ctx, "test", args).unwrap();
+
+        let fut = async {
+            let Err(err) = reader.open(BytesRange::default()).await else {
+                panic!("open should time out");
+            };
+            assert!(err.to_string().contains("timeout"));
+        };
+        timeout(Duration::from_secs(2), fut)
+            .await
+            .expect("this test should not exceed 2 seconds")
+    }
+
+    #[tokio::test]
+    async fn test_write_chunk_timeout() {
+        let op = Operator::from_parts(OperationContext::default(), Arc::new(MockService))
+            .layer(TimeoutLayer::default());
+
+        let fut = async {
+            let err = op
+                .write_with("test", "hello")
+                .chunk_timeout(Duration::from_millis(100))
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("timeout"));
+        };
+        timeout(Duration::from_secs(2), fut)
+            .await
+            .expect("this test should not exceed 2 seconds")
+    }
+
+    #[tokio::test]
+    async fn test_deadline() {
+        use oio::List;
+
+        let service = TimeoutLayer::default()
+            .with_deadline(Duration::from_millis(100))
+            .apply_service(Arc::new(MockService));
+        let ctx = OperationContext::new();
+        let mut lister = service.list(&ctx, "test", OpList::default()).unwrap();
+
+        let fut = async {
+            let err = lister.next().await.unwrap_err();
+            assert!(err.to_string().contains("deadline"));
+            assert!(!err.is_temporary());
+
+            // The deadline has passed, later calls fail immediately.
+            let err = lister.next().await.unwrap_err();
+            assert!(err.to_string().contains("deadline"));
+        };
+        timeout(Duration::from_secs(2), fut)
+            .await
+            .expect("this test should not exceed 2 seconds")
+    }
 }
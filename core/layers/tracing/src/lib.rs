@@ -294,6 +294,36 @@ impl Service for TracingService {
         let span = span!(Level::DEBUG, "presign", path, ?args);
         self.inner.presign(ctx, path, args).instrument(span).await
     }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        let span = span!(Level::DEBUG, "get_acl", path, ?args);
+        self.inner.get_acl(ctx, path, args).instrument(span).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let span = span!(Level::DEBUG, "set_acl", path, ?args);
+        self.inner.set_acl(ctx, path, args).instrument(span).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let span = span!(Level::DEBUG, "restore", path, ?args);
+        self.inner.restore(ctx, path, args).instrument(span).await
+    }
 }
 
 #[doc(hidden)]
@@ -342,6 +372,10 @@ impl<R: oio::Write> oio::Write for TracingWrapper<R> {
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().instrument(self.span.clone()).await
     }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().instrument(self.span.clone()).await
+    }
 }
 
 impl<R: oio::List> oio::List for TracingWrapper<R> {
@@ -145,6 +145,7 @@ impl Builder for AliyunDriveBuilder {
                 capability: Capability {
                     stat: true,
                     create_dir: true,
+                    create_dir_persistent: true,
                     read: true,
                     read_with_suffix: true,
                     write: true,
@@ -96,20 +96,17 @@ impl Builder for AlluxioBuilder {
                 info: ServiceInfo::new(ALLUXIO_SCHEME, &root, ""),
                 capability: Capability {
                     stat: true,
-
-                    // FIXME:
-                    //
-                    // alluxio's read support is not implemented correctly
-                    // We need to refactor by use [page_read](https://github.com/Alluxio/alluxio-py/blob/main/alluxio/const.py#L18)
-                    read: false,
+                    read: true,
 
                     write: true,
                     write_can_multi: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
                     delete: true,
 
                     list: true,
+                    rename: true,
 
                     shared: true,
 
@@ -273,20 +273,7 @@ impl AlluxioCore {
         }
     }
 
-    pub async fn read(
-        &self,
-        ctx: &OperationContext,
-        stream_id: u64,
-        range: BytesRange,
-    ) -> Result<Response<HttpBody>> {
-        if !range.is_full() {
-            return Err(Error::new(
-                ErrorKind::Unsupported,
-                "alluxio stream read doesn't support range",
-            )
-            .with_context("range", format!("{range:?}")));
-        }
-
+    pub async fn read(&self, ctx: &OperationContext, stream_id: u64) -> Result<Response<HttpBody>> {
         let req = Request::post(format!(
             "{}/api/v1/streams/{}/read",
             self.endpoint, stream_id,
@@ -333,15 +320,18 @@ impl AlluxioCore {
         }
     }
 
-    pub(super) async fn close(&self, ctx: &OperationContext, stream_id: u64) -> Result<()> {
+    pub(super) async fn close(
+        &self,
+        ctx: &OperationContext,
+        stream_id: u64,
+        op: Operation,
+    ) -> Result<()> {
         let req = Request::post(format!(
             "{}/api/v1/streams/{}/close",
             self.endpoint, stream_id
         ));
 
-        let req = req
-            .extension(Operation::Write)
-            .extension(ServiceOperation("CloseStream"));
+        let req = req.extension(op).extension(ServiceOperation("CloseStream"));
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
@@ -356,29 +346,6 @@ impl AlluxioCore {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_read_rejects_range() {
-        let core = AlluxioCore {
-            info: ServiceInfo::new("alluxio", "", ""),
-            capability: Capability::default(),
-            root: "/".to_string(),
-            endpoint: "http://127.0.0.1:1".to_string(),
-        };
-
-        let ctx = OperationContext::new();
-        let err = match core.read(&ctx, 1, BytesRange::from(0_u64..1)).await {
-            Ok(_) => panic!("range read should be rejected"),
-            Err(err) => err,
-        };
-
-        assert_eq!(err.kind(), ErrorKind::Unsupported);
-    }
-}
-
 #[derive(Debug, Serialize)]
 struct CreateFileRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
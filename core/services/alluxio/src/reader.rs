@@ -14,10 +14,14 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::Response;
 
 use super::backend::*;
+use super::core::AlluxioCore;
 use super::core::parse_error;
-use http::Response;
 use opendal_core::raw::*;
 use opendal_core::*;
 
@@ -49,7 +53,7 @@ impl oio::StreamRead for AlluxioReader {
         let path = self.path.as_str();
         let stream_id = backend.core.open_file(&self.ctx, path).await?;
 
-        let resp = backend.core.read(&self.ctx, stream_id, range).await?;
+        let resp = backend.core.read(&self.ctx, stream_id).await?;
         if !resp.status().is_success() {
             let (part, mut body) = resp.into_parts();
             let buf = body.to_buffer().await?;
@@ -57,8 +61,156 @@ impl oio::StreamRead for AlluxioReader {
         }
 
         let rp = RpRead::new(parse_into_metadata(path, resp.headers())?);
-        let stream = resp.into_body();
+        let stream = AlluxioStream::new(
+            backend.core.clone(),
+            self.ctx.clone(),
+            stream_id,
+            resp.into_body(),
+            range,
+        );
 
         Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
     }
 }
+
+/// Stream over an opened alluxio file.
+///
+/// Alluxio streams always start at the beginning of the file, so the
+/// requested range is applied by skipping and capping the body. The remote
+/// stream is closed once the range has been consumed.
+struct AlluxioStream {
+    core: Arc<AlluxioCore>,
+    ctx: OperationContext,
+    stream_id: u64,
+    body: HttpBody,
+
+    skip: u64,
+    remaining: Option<u64>,
+    closed: bool,
+}
+
+impl AlluxioStream {
+    fn new(
+        core: Arc<AlluxioCore>,
+        ctx: OperationContext,
+        stream_id: u64,
+        body: HttpBody,
+        range: BytesRange,
+    ) -> Self {
+        Self {
+            core,
+            ctx,
+            stream_id,
+            body,
+            skip: range.offset(),
+            remaining: range.size(),
+            closed: false,
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if !self.closed {
+            self.closed = true;
+            self.core
+                .close(&self.ctx, self.stream_id, Operation::Read)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl oio::ReadStream for AlluxioStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        loop {
+            if self.closed || self.remaining == Some(0) {
+                self.close().await?;
+                return Ok(Buffer::new());
+            }
+
+            let mut buf = oio::ReadStream::read(&mut self.body).await?;
+            if buf.is_empty() {
+                self.close().await?;
+                return Ok(buf);
+            }
+
+            if self.skip > 0 {
+                let n = self.skip.min(buf.len() as u64);
+                buf.advance(n as usize);
+                self.skip -= n;
+                if buf.is_empty() {
+                    continue;
+                }
+            }
+
+            if let Some(remaining) = self.remaining.as_mut() {
+                if buf.len() as u64 > *remaining {
+                    buf.truncate(*remaining as usize);
+                }
+                *remaining -= buf.len() as u64;
+            }
+
+            return Ok(buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use bytes::Bytes;
+    use futures::stream;
+    use http::StatusCode;
+
+    use super::*;
+
+    /// Record requests and reply with an empty success response.
+    struct MockTransport {
+        closed: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpTransport for MockTransport {
+        async fn fetch(&self, req: http::Request<Buffer>) -> Result<Response<HttpBody>> {
+            self.closed.lock().unwrap().push(req.uri().to_string());
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .body(HttpBody::new(stream::empty(), Some(0)))
+                .unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_reads_range() {
+        let closed = Arc::new(Mutex::new(Vec::new()));
+        let ctx =
+            OperationContext::new().with_http_transport(HttpTransporter::new(MockTransport {
+                closed: closed.clone(),
+            }));
+        let core = Arc::new(AlluxioCore {
+            info: ServiceInfo::new("alluxio", "", ""),
+            capability: Capability::default(),
+            root: "/".to_string(),
+            endpoint: "http://127.0.0.1:1".to_string(),
+        });
+
+        let chunks =
+            ["hel", "lo w", "orld"].map(|s| Ok(Buffer::from(Bytes::from_static(s.as_bytes()))));
+        let body = HttpBody::new(stream::iter(chunks), Some(11));
+        let mut s = AlluxioStream::new(core, ctx, 7, body, BytesRange::new(3, Some(5)));
+
+        let mut content = Vec::new();
+        loop {
+            let buf = oio::ReadStream::read(&mut s).await.unwrap();
+            if buf.is_empty() {
+                break;
+            }
+            content.extend_from_slice(&buf.to_vec());
+        }
+
+        assert_eq!(content, b"lo wo");
+        assert_eq!(
+            *closed.lock().unwrap(),
+            vec!["http://127.0.0.1:1/api/v1/streams/7/close".to_string()]
+        );
+    }
+}
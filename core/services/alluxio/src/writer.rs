@@ -62,7 +62,9 @@ impl oio::Write for AlluxioWriter {
         let Some(stream_id) = self.stream_id else {
             return Ok(Metadata::default());
         };
-        self.core.close(&self.ctx, stream_id).await?;
+        self.core
+            .close(&self.ctx, stream_id, Operation::Write)
+            .await?;
 
         Ok(Metadata::default())
     }
@@ -39,12 +39,15 @@ use super::copier::new_azblob_copier;
 use super::core::AzblobCore;
 use super::core::constants::AZBLOB_COPY_MAX_BLOCK_SIZE;
 use super::core::constants::AZBLOB_COPY_MIN_BLOCK_SIZE;
+use super::core::constants::X_MS_ARCHIVE_STATUS;
 use super::core::constants::X_MS_META_PREFIX;
 use super::core::constants::X_MS_VERSION_ID;
+use super::core::parse_checksum;
 use super::core::parse_error;
 use super::deleter::AzblobDeleter;
 use super::lister::AzblobLister;
 use super::reader::*;
+use super::writer::AzblobPageWriter;
 use super::writer::AzblobWriter;
 use super::writer::AzblobWriters;
 use opendal_core::raw::*;
@@ -254,6 +257,19 @@ impl AzblobBuilder {
         self
     }
 
+    /// Set how `create_dir` handles directory marker blobs.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` blob so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    pub fn dir_marker(mut self, mode: &str) -> Self {
+        if !mode.is_empty() {
+            self.config.dir_marker = Some(mode.to_string());
+        }
+
+        self
+    }
+
     /// from_connection_string will make a builder from connection string
     ///
     /// connection string looks like:
@@ -364,6 +380,18 @@ impl Builder for AzblobBuilder {
             }
         };
 
+        let (create_dir, create_dir_persistent) = match self.config.dir_marker.as_deref() {
+            Some("force") => (true, true),
+            Some("skip") => (true, false),
+            None => (false, false),
+            v => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    format!("{v:?} is not a supported dir_marker."),
+                ));
+            }
+        };
+
         let ctx = Context::new().with_file_read(TokioFileRead).with_env(OsEnv);
 
         let mut credential = DefaultCredentialProvider::new();
@@ -392,6 +420,9 @@ impl Builder for AzblobBuilder {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
+            stat_with_version: true,
 
             read: true,
 
@@ -400,19 +431,28 @@ impl Builder for AzblobBuilder {
             read_with_override_content_disposition: true,
             read_with_if_modified_since: true,
             read_with_if_unmodified_since: true,
+            read_with_version: true,
 
             write: true,
             write_can_append: true,
+            write_can_seek: true,
             write_can_empty: true,
             write_can_multi: true,
             write_with_cache_control: true,
             write_with_content_type: true,
+            write_with_if_match: true,
             write_with_if_not_exists: true,
             write_with_if_none_match: true,
             write_with_user_metadata: true,
+            write_with_immutability_until: true,
+            write_with_legal_hold: true,
+
+            create_dir,
+            create_dir_persistent,
 
             delete: true,
             delete_max_size: Some(AZBLOB_BATCH_LIMIT),
+            delete_with_version: true,
 
             copy: true,
             copy_with_if_not_exists: true,
@@ -422,11 +462,15 @@ impl Builder for AzblobBuilder {
 
             list: true,
             list_with_recursive: true,
+            list_with_versions: true,
 
             presign: self.config.sas_token.is_some(),
             presign_stat: self.config.sas_token.is_some(),
             presign_read: self.config.sas_token.is_some(),
             presign_write: self.config.sas_token.is_some(),
+            presign_multipart: self.config.sas_token.is_some(),
+
+            restore: true,
 
             shared: true,
 
@@ -473,14 +517,31 @@ impl Service for AzblobBackend {
 
     async fn create_dir(
         &self,
-        _ctx: &OperationContext,
-        _path: &str,
+        ctx: &OperationContext,
+        path: &str,
         _args: OpCreateDir,
     ) -> Result<RpCreateDir> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        if !self.core.capability.create_dir {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        // Without marker blobs, the directory appears once it has children.
+        if !self.core.capability.create_dir_persistent {
+            return Ok(RpCreateDir::default());
+        }
+
+        let resp = self
+            .core
+            .azblob_put_blob(ctx, path, Some(0), &OpWrite::default(), Buffer::new())
+            .await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpCreateDir::default()),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
@@ -498,6 +559,15 @@ impl Service for AzblobBackend {
                 if let Some(version_id) = parse_header_to_str(headers, X_MS_VERSION_ID)? {
                     meta.set_version(version_id);
                 }
+                if let Some(v) = parse_checksum(headers)? {
+                    meta.set_checksum(v);
+                }
+                // Blobs that are being rehydrated report `rehydrate-pending-to-<tier>`.
+                if parse_header_to_str(headers, X_MS_ARCHIVE_STATUS)?
+                    .is_some_and(|v| v.starts_with("rehydrate-pending"))
+                {
+                    meta.set_restore_status(RestoreStatus::InProgress);
+                }
 
                 let user_meta = parse_prefixed_headers(headers, X_MS_META_PREFIX);
                 if !user_meta.is_empty() {
@@ -524,20 +594,28 @@ impl Service for AzblobBackend {
 
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: AzblobWriters = {
-            let w = AzblobWriter::new(
-                self.core.clone(),
-                ctx.clone(),
-                args.clone(),
-                path.to_string(),
-            );
-            let w = if args.append() {
-                AzblobWriters::Two(oio::AppendWriter::new(w))
+            let w = if args.offset().is_some() {
+                AzblobWriters::Three(oio::AppendWriter::new(AzblobPageWriter::new(
+                    self.core.clone(),
+                    ctx.clone(),
+                    args.clone(),
+                    path.to_string(),
+                )))
             } else {
-                AzblobWriters::One(oio::BlockWriter::new(
-                    ctx.executor().clone(),
-                    w,
-                    args.concurrent(),
-                ))
+                let w = AzblobWriter::new(
+                    self.core.clone(),
+                    ctx.clone(),
+                    args.clone(),
+                    path.to_string(),
+                );
+                if args.append() {
+                    AzblobWriters::Two(oio::AppendWriter::new(w))
+                } else {
+                    AzblobWriters::One(
+                        oio::BlockWriter::new(ctx.executor().clone(), w, args.concurrent())
+                            .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                    )
+                }
             };
 
             Ok(w)
@@ -565,6 +643,7 @@ impl Service for AzblobBackend {
                 path.to_string(),
                 args.recursive(),
                 args.limit(),
+                args.versions(),
             );
 
             Ok(oio::PageLister::new(l))
@@ -602,6 +681,21 @@ impl Service for AzblobBackend {
         ))
     }
 
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let resp = self.core.azblob_set_blob_tier(ctx, path, &args).await?;
+
+        match resp.status() {
+            // `202 Accepted` is returned while the blob is being rehydrated.
+            StatusCode::OK | StatusCode::ACCEPTED => Ok(RpRestore::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(
         &self,
         ctx: &OperationContext,
@@ -615,6 +709,24 @@ impl Service for AzblobBackend {
                 self.core
                     .azblob_put_blob_request(path, None, &OpWrite::default(), Buffer::new())
             }
+            PresignOperation::CreateMultipart(_) => Err(Error::new(
+                ErrorKind::Unsupported,
+                "azblob doesn't need to create multipart uploads, use any unique upload id instead",
+            )),
+            PresignOperation::UploadPart {
+                upload_id,
+                part_number,
+            } => self.core.azblob_put_block_with_id_request(
+                path,
+                &presign_block_id(upload_id, *part_number),
+                None,
+                &OpWrite::default(),
+                Buffer::new(),
+            ),
+            PresignOperation::CompleteMultipart { .. } => {
+                self.core
+                    .azblob_put_block_list_request(path, Buffer::new(), &OpWrite::default())
+            }
             PresignOperation::Delete(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
@@ -637,3 +749,25 @@ impl Service for AzblobBackend {
         )))
     }
 }
+
+/// Build the block id used by presigned multipart uploads.
+///
+/// Azure requires all block ids of a blob to have the same length, so the
+/// part number is zero padded to fit the 50000 blocks limit.
+fn presign_block_id(upload_id: &str, part_number: usize) -> String {
+    BASE64_STANDARD.encode(format!("{upload_id}-{part_number:05}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presign_block_id() {
+        assert_eq!(presign_block_id("upload", 1), "dXBsb2FkLTAwMDAx");
+        assert_eq!(
+            presign_block_id("upload", 1).len(),
+            presign_block_id("upload", 50000).len()
+        );
+    }
+}
@@ -84,6 +84,15 @@ pub struct AzblobConfig {
     /// Skip signature will skip loading credentials and signing requests.
     #[serde(default)]
     pub skip_signature: bool,
+
+    /// How `create_dir` handles directory marker objects.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` blob so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    ///
+    /// By default, the marker blob is written by `SimulateLayer`.
+    pub dir_marker: Option<String>,
 }
 
 impl Debug for AzblobConfig {
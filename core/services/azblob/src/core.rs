@@ -56,15 +56,43 @@ pub mod constants {
     pub const X_MS_COPY_SOURCE_RANGE: &str = "x-ms-source-range";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
+    pub const X_MS_BLOB_CONTENT_LENGTH: &str = "x-ms-blob-content-length";
+    pub const X_MS_PAGE_WRITE: &str = "x-ms-page-write";
+    pub const X_MS_RANGE: &str = "x-ms-range";
     pub const X_MS_META_PREFIX: &str = "x-ms-meta-";
 
+    // Version level immutability of the blob, the container must have version
+    // level immutability support enabled.
+    pub const X_MS_IMMUTABILITY_POLICY_UNTIL_DATE: &str = "x-ms-immutability-policy-until-date";
+    pub const X_MS_IMMUTABILITY_POLICY_MODE: &str = "x-ms-immutability-policy-mode";
+    pub const X_MS_LEGAL_HOLD: &str = "x-ms-legal-hold";
+
     // indicates the version of the blob, and it can be used in subsequent requests to access the blob.
     pub const X_MS_VERSION_ID: &str = "x-ms-version-id";
 
+    // md5 of the whole blob, returned for range requests.
+    pub const X_MS_BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
+
     // Server-side encryption with customer-provided headers
     pub const X_MS_ENCRYPTION_KEY: &str = "x-ms-encryption-key";
     pub const X_MS_ENCRYPTION_KEY_SHA256: &str = "x-ms-encryption-key-sha256";
     pub const X_MS_ENCRYPTION_ALGORITHM: &str = "x-ms-encryption-algorithm";
+
+    // Access tier and rehydration of archived blobs.
+    pub const X_MS_ACCESS_TIER: &str = "x-ms-access-tier";
+    pub const X_MS_ARCHIVE_STATUS: &str = "x-ms-archive-status";
+    pub const X_MS_REHYDRATE_PRIORITY: &str = "x-ms-rehydrate-priority";
+}
+
+/// Parse the md5 checksum of the whole blob.
+///
+/// Blobs uploaded by put block list don't have md5 unless it's set explicitly.
+pub fn parse_checksum(headers: &http::HeaderMap) -> Result<Option<Checksum>> {
+    let md5 = match parse_header_to_str(headers, constants::X_MS_BLOB_CONTENT_MD5)? {
+        Some(v) => Some(v),
+        None => parse_content_md5(headers)?,
+    };
+    Ok(md5.map(|v| Checksum::new(ChecksumAlgorithm::Md5, v)))
 }
 
 pub struct AzblobCore {
@@ -218,16 +246,17 @@ impl AzblobCore {
         range: BytesRange,
         args: &OpRead,
     ) -> Result<Request<Buffer>> {
-        let mut url = self.build_path_url(path);
+        let url = self.build_path_url(path);
 
+        let mut url = QueryPairsWriter::new(&url);
         if let Some(override_content_disposition) = args.override_content_disposition() {
-            url.push_str(&format!(
-                "?rscd={}",
-                percent_encode_path(override_content_disposition)
-            ));
+            url = url.push("rscd", &percent_encode_path(override_content_disposition));
+        }
+        if let Some(version) = args.version() {
+            url = url.push("versionid", &percent_encode_path(version));
         }
 
-        let mut req = Request::get(&url);
+        let mut req = Request::get(url.finish());
 
         // Set SSE headers.
         req = self.insert_sse_headers(req);
@@ -274,6 +303,70 @@ impl AzblobCore {
         ctx.http_transport().fetch(req).await
     }
 
+    /// Insert conditional headers of write.
+    ///
+    /// Blob writes are committed by put blob or put block list, so the
+    /// conditions must be checked there.
+    fn insert_write_condition_headers(
+        mut req: http::request::Builder,
+        args: &OpWrite,
+    ) -> http::request::Builder {
+        // Specify the wildcard character (*) to perform the operation only if
+        // the resource does not exist, and fail the operation if it does exist.
+        if args.if_not_exists() {
+            req = req.header(IF_NONE_MATCH, "*");
+        } else if let Some(v) = args.if_none_match() {
+            req = req.header(IF_NONE_MATCH, v);
+        }
+
+        if let Some(v) = args.if_match() {
+            req = req.header(IF_MATCH, v);
+        }
+
+        req
+    }
+
+    fn insert_user_metadata_headers(
+        mut req: http::request::Builder,
+        args: &OpWrite,
+    ) -> http::request::Builder {
+        if let Some(user_metadata) = args.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("{X_MS_META_PREFIX}{key}"), value)
+            }
+        }
+
+        req
+    }
+
+    /// Insert immutability policy and legal hold headers of write.
+    ///
+    /// The policy is always set in `Unlocked` mode, locking a policy can't be
+    /// undone and is left to the administrators.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-blob#request-headers-all-blob-types
+    fn insert_immutability_headers(
+        mut req: http::request::Builder,
+        args: &OpWrite,
+    ) -> http::request::Builder {
+        if let Some(until) = args.immutability_until() {
+            req = req
+                .header(
+                    constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE,
+                    until.format_http_date(),
+                )
+                .header(constants::X_MS_IMMUTABILITY_POLICY_MODE, "Unlocked");
+        }
+
+        if args.legal_hold() {
+            req = req.header(constants::X_MS_LEGAL_HOLD, "true");
+        }
+
+        req
+    }
+
     pub fn azblob_put_blob_request(
         &self,
         path: &str,
@@ -296,15 +389,7 @@ impl AzblobCore {
             req = req.header(CONTENT_TYPE, ty)
         }
 
-        // Specify the wildcard character (*) to perform the operation only if
-        // the resource does not exist, and fail the operation if it does exist.
-        if args.if_not_exists() {
-            req = req.header(IF_NONE_MATCH, "*");
-        }
-
-        if let Some(v) = args.if_none_match() {
-            req = req.header(IF_NONE_MATCH, v);
-        }
+        req = Self::insert_write_condition_headers(req, args);
 
         if let Some(cache_control) = args.cache_control() {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
@@ -313,11 +398,8 @@ impl AzblobCore {
         // Set SSE headers.
         req = self.insert_sse_headers(req);
 
-        if let Some(user_metadata) = args.user_metadata() {
-            for (key, value) in user_metadata {
-                req = req.header(format!("{X_MS_META_PREFIX}{key}"), value)
-            }
-        }
+        req = Self::insert_user_metadata_headers(req, args);
+        req = Self::insert_immutability_headers(req, args);
 
         let req = req
             .extension(Operation::Write)
@@ -354,6 +436,7 @@ impl AzblobCore {
     /// The following custom header could be set:
     /// - `content-type`
     /// - `x-ms-blob-cache-control`
+    /// - `x-ms-meta-*`
     ///
     /// # Reference
     ///
@@ -384,6 +467,9 @@ impl AzblobCore {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
 
+        req = Self::insert_user_metadata_headers(req, args);
+        req = Self::insert_immutability_headers(req, args);
+
         let req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("PutBlob"))
@@ -453,6 +539,150 @@ impl AzblobCore {
         self.send(ctx, req).await
     }
 
+    /// For page blob, it could be created by `put` an empty blob with
+    /// `x-ms-blob-type` header set to `PageBlob` and the max size of blob.
+    ///
+    /// # Notes
+    ///
+    /// The size must be aligned to 512 bytes, and the blob is filled with
+    /// zeros until pages are written to it.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-blob
+    fn azblob_init_page_blob_request(
+        &self,
+        path: &str,
+        size: u64,
+        args: &OpWrite,
+    ) -> Result<Request<Buffer>> {
+        let mut req = Request::put(self.build_path_url(path));
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        // The content-length header must be set to zero
+        // when creating a page blob.
+        req = req.header(CONTENT_LENGTH, 0);
+        req = req.header(
+            HeaderName::from_static(constants::X_MS_BLOB_TYPE),
+            "PageBlob",
+        );
+        req = req.header(constants::X_MS_BLOB_CONTENT_LENGTH, size);
+
+        if let Some(ty) = args.content_type() {
+            req = req.header(CONTENT_TYPE, ty)
+        }
+
+        if let Some(cache_control) = args.cache_control() {
+            req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
+        }
+
+        req = Self::insert_user_metadata_headers(req, args);
+        req = Self::insert_immutability_headers(req, args);
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("PutBlob"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    pub async fn azblob_init_page_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        size: u64,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let req = self.azblob_init_page_blob_request(path, size, args)?;
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    /// Resize a page blob to `size` which must be aligned to 512 bytes.
+    ///
+    /// Other properties of the blob are kept since only the content length
+    /// is set.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-properties
+    pub async fn azblob_resize_page_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        size: u64,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?comp=properties", &self.build_path_url(path));
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, 0)
+            .header(constants::X_MS_BLOB_CONTENT_LENGTH, size)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("SetBlobProperties"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    /// Write pages to a page blob at `position`.
+    ///
+    /// # Notes
+    ///
+    /// - Both `position` and `size` must be aligned to 512 bytes.
+    /// - The maximum size of the content could be written is 4MB.
+    /// - The pages must be inside the blob, resize it first to write past the end.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-page
+    fn azblob_put_page_request(
+        &self,
+        path: &str,
+        position: u64,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Request<Buffer>> {
+        let url = format!("{}?comp=page", &self.build_path_url(path));
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, size)
+            .header(constants::X_MS_PAGE_WRITE, "update")
+            .header(
+                constants::X_MS_RANGE,
+                BytesRange::from(position..position + size).to_header(),
+            );
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("PutPage"))
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    pub async fn azblob_put_page(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        position: u64,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let req = self.azblob_put_page_request(path, position, size, body)?;
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
     pub fn azblob_put_block_request(
         &self,
         path: &str,
@@ -460,15 +690,25 @@ impl AzblobCore {
         size: Option<u64>,
         args: &OpWrite,
         body: Buffer,
+    ) -> Result<Request<Buffer>> {
+        let block_id = BASE64_STANDARD.encode(block_id.as_bytes());
+        self.azblob_put_block_with_id_request(path, &block_id, size, args, body)
+    }
+
+    /// Build a Put Block request with an already base64 encoded block id.
+    pub fn azblob_put_block_with_id_request(
+        &self,
+        path: &str,
+        block_id: &str,
+        size: Option<u64>,
+        args: &OpWrite,
+        body: Buffer,
     ) -> Result<Request<Buffer>> {
         // To be written as part of a blob, a block must have been successfully written to the server in an earlier Put Block operation.
         // refer to https://learn.microsoft.com/en-us/rest/api/storageservices/put-block?tabs=microsoft-entra-id
         let url = QueryPairsWriter::new(&self.build_path_url(path))
             .push("comp", "block")
-            .push(
-                "blockid",
-                &percent_encode_path(&BASE64_STANDARD.encode(block_id.as_bytes())),
-            )
+            .push("blockid", &percent_encode_path(block_id))
             .finish();
 
         let mut req = Request::put(&url);
@@ -576,16 +816,6 @@ impl AzblobCore {
         block_ids: Vec<Uuid>,
         args: &OpWrite,
     ) -> Result<Request<Buffer>> {
-        let url = format!("{}?comp=blocklist", &self.build_path_url(path));
-
-        let req = Request::put(&url);
-
-        // Set SSE headers.
-        let mut req = self.insert_sse_headers(req);
-        if let Some(cache_control) = args.cache_control() {
-            req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
-        }
-
         let content = quick_xml::se::to_string(&PutBlockListRequest {
             latest: block_ids
                 .into_iter()
@@ -597,12 +827,41 @@ impl AzblobCore {
         })
         .map_err(new_xml_serialize_error)?;
 
-        req = req.header(CONTENT_LENGTH, content.len());
+        self.azblob_put_block_list_request(path, Buffer::from(Bytes::from(content)), args)
+    }
+
+    /// Build a Put Block List request with the serialized block list as body.
+    ///
+    /// An empty body leaves `Content-Length` unset so that the request can be presigned
+    /// before the block list is known.
+    pub fn azblob_put_block_list_request(
+        &self,
+        path: &str,
+        body: Buffer,
+        args: &OpWrite,
+    ) -> Result<Request<Buffer>> {
+        let url = format!("{}?comp=blocklist", &self.build_path_url(path));
+
+        let req = Request::put(&url);
+
+        // Set SSE headers.
+        let mut req = self.insert_sse_headers(req);
+        if let Some(cache_control) = args.cache_control() {
+            req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
+        }
+
+        req = Self::insert_write_condition_headers(req, args);
+        req = Self::insert_user_metadata_headers(req, args);
+        req = Self::insert_immutability_headers(req, args);
+
+        if !body.is_empty() {
+            req = req.header(CONTENT_LENGTH, body.len());
+        }
 
         let req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("PutBlockList"))
-            .body(Buffer::from(Bytes::from(content)))
+            .body(body)
             .map_err(new_request_build_error)?;
 
         Ok(req)
@@ -687,6 +946,14 @@ impl AzblobCore {
             req = req.header(IF_MATCH, if_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(IF_MODIFIED_SINCE, if_modified_since.format_http_date());
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
+        }
+
         let req = req
             .extension(Operation::Stat)
             .extension(ServiceOperation("GetBlobProperties"))
@@ -707,8 +974,15 @@ impl AzblobCore {
         self.send(ctx, req).await
     }
 
-    fn azblob_delete_blob_request(&self, path: &str) -> Result<Request<Buffer>> {
-        Request::delete(self.build_path_url(path))
+    fn azblob_delete_blob_request(&self, path: &str, args: &OpDelete) -> Result<Request<Buffer>> {
+        let mut url = self.build_path_url(path);
+        if let Some(version) = args.version() {
+            url = QueryPairsWriter::new(&url)
+                .push("versionid", &percent_encode_path(version))
+                .finish();
+        }
+
+        Request::delete(url)
             .header(CONTENT_LENGTH, 0)
             .extension(Operation::Delete)
             .extension(ServiceOperation("DeleteBlob"))
@@ -720,8 +994,39 @@ impl AzblobCore {
         &self,
         ctx: &OperationContext,
         path: &str,
+        args: &OpDelete,
     ) -> Result<Response<Buffer>> {
-        let req = self.azblob_delete_blob_request(path)?;
+        let req = self.azblob_delete_blob_request(path, args)?;
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    /// Rehydrate an archived blob by moving it back to the hot tier.
+    ///
+    /// Rehydration is permanent on azblob, so `days` is ignored.
+    pub async fn azblob_set_blob_tier(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?comp=tier", self.build_path_url(path));
+
+        let priority = match args.tier() {
+            Some(tier) if tier.eq_ignore_ascii_case("high") => "High",
+            Some(tier) if tier.eq_ignore_ascii_case("expedited") => "High",
+            _ => "Standard",
+        };
+
+        let req = Request::put(&url)
+            .header(constants::X_MS_ACCESS_TIER, "Hot")
+            .header(constants::X_MS_REHYDRATE_PRIORITY, priority)
+            .header(CONTENT_LENGTH, 0)
+            .extension(Operation::Restore)
+            .extension(ServiceOperation("SetBlobTier"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
         let req = self.sign(ctx, req).await?;
         self.send(ctx, req).await
     }
@@ -767,6 +1072,7 @@ impl AzblobCore {
         next_marker: &str,
         delimiter: &str,
         limit: Option<usize>,
+        versions: bool,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = QueryPairsWriter::new(&format!("{}/{}", self.endpoint, self.container))
@@ -785,6 +1091,9 @@ impl AzblobCore {
         if !next_marker.is_empty() {
             url = url.push("marker", next_marker);
         }
+        if versions {
+            url = url.push("include", "versions");
+        }
 
         let req = Request::get(url.finish())
             .extension(Operation::List)
@@ -799,7 +1108,7 @@ impl AzblobCore {
     pub async fn azblob_batch_delete(
         &self,
         ctx: &OperationContext,
-        paths: &[String],
+        batch: &[(String, OpDelete)],
     ) -> Result<Response<Buffer>> {
         let url = format!(
             "{}/{}?restype=container&comp=batch",
@@ -808,8 +1117,8 @@ impl AzblobCore {
 
         let mut multipart = Multipart::new();
 
-        for (idx, path) in paths.iter().enumerate() {
-            let req = self.azblob_delete_blob_request(path)?;
+        for (idx, (path, args)) in batch.iter().enumerate() {
+            let req = self.azblob_delete_blob_request(path, args)?;
             let req = self.batch_sign(ctx, req).await?;
 
             multipart = multipart.part(
@@ -859,6 +1168,10 @@ pub struct BlobPrefix {
 pub struct Blob {
     pub properties: Properties,
     pub name: String,
+    /// Only returned if versions are included in the list.
+    pub version_id: Option<String>,
+    /// Only returned if versions are included in the list.
+    pub is_current_version: Option<bool>,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -883,6 +1196,37 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_checksum() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(parse_checksum(&headers).unwrap(), None);
+
+        headers.insert(
+            http::header::HeaderName::from_static("content-md5"),
+            "1B2M2Y8AsgTpgAmY7PhCfg==".parse().unwrap(),
+        );
+        assert_eq!(
+            parse_checksum(&headers).unwrap(),
+            Some(Checksum::new(
+                ChecksumAlgorithm::Md5,
+                "1B2M2Y8AsgTpgAmY7PhCfg=="
+            ))
+        );
+
+        // Range requests return md5 of the whole blob in `x-ms-blob-content-md5`.
+        headers.insert(
+            constants::X_MS_BLOB_CONTENT_MD5,
+            "llJ/+jOlx5GdA1sL7SdKuw==".parse().unwrap(),
+        );
+        assert_eq!(
+            parse_checksum(&headers).unwrap(),
+            Some(Checksum::new(
+                ChecksumAlgorithm::Md5,
+                "llJ/+jOlx5GdA1sL7SdKuw=="
+            ))
+        );
+    }
+
     #[test]
     fn test_parse_xml() {
         let bs = bytes::Bytes::from(
@@ -1040,6 +1384,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_xml_with_versions() {
+        let bs = bytes::Bytes::from(
+            r#"
+            <?xml version="1.0" encoding="utf-8"?>
+            <EnumerationResults ServiceEndpoint="https://test.blob.core.windows.net/" ContainerName="myazurebucket">
+                <Prefix>dir1/</Prefix>
+                <Blobs>
+                    <Blob>
+                        <Name>dir1/file</Name>
+                        <VersionId>2022-03-20T11:29:03.1234567Z</VersionId>
+                        <Properties>
+                            <Last-Modified>Sun, 20 Mar 2022 11:29:03 GMT</Last-Modified>
+                            <Etag>0x8DA0A64D66790C3</Etag>
+                            <Content-Length>3485277</Content-Length>
+                        </Properties>
+                    </Blob>
+                    <Blob>
+                        <Name>dir1/file</Name>
+                        <VersionId>2022-03-29T01:54:07.1234567Z</VersionId>
+                        <IsCurrentVersion>true</IsCurrentVersion>
+                        <Properties>
+                            <Last-Modified>Tue, 29 Mar 2022 01:54:07 GMT</Last-Modified>
+                            <Etag>0x8DA112702D88FE4</Etag>
+                            <Content-Length>2471869</Content-Length>
+                        </Properties>
+                    </Blob>
+                </Blobs>
+                <NextMarker />
+            </EnumerationResults>"#,
+        );
+        let out: ListBlobsOutput = de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(
+            out.blobs
+                .blob
+                .iter()
+                .map(|v| (v.version_id.as_deref(), v.is_current_version))
+                .collect::<Vec<_>>(),
+            [
+                (Some("2022-03-20T11:29:03.1234567Z"), None),
+                (Some("2022-03-29T01:54:07.1234567Z"), Some(true))
+            ]
+        );
+    }
+
     /// This case is copied from real environment for testing
     /// quick-xml overlapped-lists features. By default, quick-xml
     /// can't deserialize content with overlapped-lists.
@@ -1063,6 +1453,32 @@ mod tests {
     }
 
     /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list?tabs=microsoft-entra-id
+    #[test]
+    fn test_insert_immutability_headers() {
+        let args = OpWrite::default()
+            .with_immutability_until(Timestamp::from_second(1_700_000_000).unwrap())
+            .with_legal_hold(true);
+        let req = AzblobCore::insert_immutability_headers(Request::put("/"), &args)
+            .body(())
+            .unwrap();
+
+        let headers = req.headers();
+        assert_eq!(
+            headers[constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE],
+            "Tue, 14 Nov 2023 22:13:20 GMT"
+        );
+        assert_eq!(
+            headers[constants::X_MS_IMMUTABILITY_POLICY_MODE],
+            "Unlocked"
+        );
+        assert_eq!(headers[constants::X_MS_LEGAL_HOLD], "true");
+
+        let req = AzblobCore::insert_immutability_headers(Request::put("/"), &OpWrite::default())
+            .body(())
+            .unwrap();
+        assert!(req.headers().is_empty());
+    }
+
     #[test]
     fn test_serialize_put_block_list_request() {
         let req = PutBlockListRequest {
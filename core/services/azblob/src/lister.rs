@@ -33,6 +33,7 @@ pub struct AzblobLister {
     path: String,
     delimiter: &'static str,
     limit: Option<usize>,
+    versions: bool,
 }
 
 impl AzblobLister {
@@ -42,6 +43,7 @@ impl AzblobLister {
         path: String,
         recursive: bool,
         limit: Option<usize>,
+        versions: bool,
     ) -> Self {
         let delimiter = if recursive { "" } else { "/" };
 
@@ -51,6 +53,7 @@ impl AzblobLister {
             path,
             delimiter,
             limit,
+            versions,
         }
     }
 }
@@ -65,6 +68,7 @@ impl oio::PageList for AzblobLister {
                 &ctx.token,
                 self.delimiter,
                 self.limit,
+                self.versions,
             )
             .await?;
 
@@ -102,7 +106,7 @@ impl oio::PageList for AzblobLister {
                 path = "/".to_string();
             }
 
-            let meta = Metadata::new(EntryMode::from_path(&path))
+            let mut meta = Metadata::new(EntryMode::from_path(&path))
                 // Keep fit with ETag header.
                 .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
                 .with_content_length(object.properties.content_length)
@@ -111,6 +115,11 @@ impl oio::PageList for AzblobLister {
                 .with_last_modified(Timestamp::parse_rfc2822(
                     object.properties.last_modified.as_str(),
                 )?);
+            if let Some(version_id) = &object.version_id {
+                meta.set_version(version_id);
+                // Azure only returns `IsCurrentVersion` for the current version.
+                meta.set_is_current(object.is_current_version.unwrap_or_default());
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
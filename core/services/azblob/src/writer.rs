@@ -16,6 +16,8 @@
 // under the License.
 
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 
 use http::StatusCode;
 use uuid::Uuid;
@@ -28,7 +30,16 @@ use opendal_core::*;
 
 const X_MS_BLOB_TYPE: &str = "x-ms-blob-type";
 
-pub type AzblobWriters = TwoWays<oio::BlockWriter<AzblobWriter>, oio::AppendWriter<AzblobWriter>>;
+/// Pages of page blobs are 512 bytes.
+const PAGE_SIZE: u64 = 512;
+/// The maximum size of pages could be written in one request.
+const MAX_PUT_PAGE_SIZE: u64 = 4 * 1024 * 1024;
+
+pub type AzblobWriters = ThreeWays<
+    oio::BlockWriter<AzblobWriter>,
+    oio::AppendWriter<AzblobWriter>,
+    oio::AppendWriter<AzblobPageWriter>,
+>;
 
 pub struct AzblobWriter {
     core: Arc<AzblobCore>,
@@ -127,6 +138,117 @@ impl oio::AppendWrite for AzblobWriter {
     }
 }
 
+/// Writer that writes pages of a page blob in place, used to write at an offset.
+pub struct AzblobPageWriter {
+    core: Arc<AzblobCore>,
+    ctx: OperationContext,
+
+    op: OpWrite,
+    path: String,
+    /// The current size of page blob.
+    size: AtomicU64,
+}
+
+impl AzblobPageWriter {
+    pub fn new(core: Arc<AzblobCore>, ctx: OperationContext, op: OpWrite, path: String) -> Self {
+        AzblobPageWriter {
+            core,
+            ctx,
+            op,
+            path,
+            size: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Append writes are used to write pages starting at the offset of op.
+impl oio::AppendWrite for AzblobPageWriter {
+    async fn offset(&self) -> Result<u64> {
+        let resp = self
+            .core
+            .azblob_get_blob_properties(&self.ctx, &self.path, &OpStat::default())
+            .await?;
+
+        let status = resp.status();
+
+        let size = match status {
+            StatusCode::OK => {
+                let headers = resp.headers();
+                let blob_type = headers.get(X_MS_BLOB_TYPE).and_then(|v| v.to_str().ok());
+                if blob_type != Some("PageBlob") {
+                    return Err(Error::new(
+                        ErrorKind::ConditionNotMatch,
+                        "the blob is not a page blob.",
+                    ));
+                }
+
+                parse_content_length(headers)?.unwrap_or_default()
+            }
+            StatusCode::NOT_FOUND => {
+                let resp = self
+                    .core
+                    .azblob_init_page_blob(&self.ctx, &self.path, 0, &self.op)
+                    .await?;
+
+                let status = resp.status();
+                match status {
+                    StatusCode::CREATED => 0,
+                    _ => {
+                        return Err(parse_error(resp));
+                    }
+                }
+            }
+            _ => return Err(parse_error(resp)),
+        };
+        self.size.store(size, Ordering::Relaxed);
+
+        Ok(self.op.offset().unwrap_or_default())
+    }
+
+    async fn append(&self, offset: u64, size: u64, body: Buffer) -> Result<Metadata> {
+        if offset % PAGE_SIZE != 0 || size % PAGE_SIZE != 0 {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "writes of page blob must be aligned to 512 bytes",
+            )
+            .with_context("offset", offset)
+            .with_context("size", size));
+        }
+
+        // Pages can only be written inside the blob, extend it first.
+        if offset + size > self.size.load(Ordering::Relaxed) {
+            let resp = self
+                .core
+                .azblob_resize_page_blob(&self.ctx, &self.path, offset + size)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+            self.size.store(offset + size, Ordering::Relaxed);
+        }
+
+        let mut meta = Metadata::default();
+        let mut written = 0;
+        while written < size {
+            let n = (size - written).min(MAX_PUT_PAGE_SIZE);
+            let body = body.slice(written as usize..(written + n) as usize);
+            let resp = self
+                .core
+                .azblob_put_page(&self.ctx, &self.path, offset + written, n, body)
+                .await?;
+
+            meta = AzblobWriter::parse_metadata(resp.headers())?;
+            let status = resp.status();
+            if status != StatusCode::CREATED {
+                return Err(parse_error(resp));
+            }
+            written += n;
+        }
+
+        Ok(meta)
+    }
+}
+
 impl oio::BlockWrite for AzblobWriter {
     async fn write_once(&self, size: u64, body: Buffer) -> Result<Metadata> {
         let resp = self
@@ -137,9 +259,12 @@ impl oio::BlockWrite for AzblobWriter {
         let status = resp.status();
 
         let mut meta = AzblobWriter::parse_metadata(resp.headers())?;
+        // Put blob returns the md5 of the whole blob, while put block list
+        // returns the md5 of the block list, so only trust it here.
         let md5 = parse_content_md5(resp.headers())?;
         if let Some(md5) = md5 {
             meta.set_content_md5(md5);
+            meta.set_checksum(Checksum::new(ChecksumAlgorithm::Md5, md5));
         }
         match status {
             StatusCode::CREATED | StatusCode::OK => Ok(meta),
@@ -181,3 +306,125 @@ impl oio::BlockWrite for AzblobWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use http::Request;
+    use http::Response;
+
+    use super::*;
+    use crate::Azblob;
+
+    /// Record write requests and reply as azblob with a blob of `blob` type
+    /// and size if it exists.
+    struct MockTransport {
+        blob: Option<(&'static str, u64)>,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpTransport for MockTransport {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let mut resp = Response::builder();
+            let status = match (req.method().as_str(), self.blob) {
+                ("HEAD", Some((blob_type, size))) => {
+                    resp = resp
+                        .header(X_MS_BLOB_TYPE, blob_type)
+                        .header(http::header::CONTENT_LENGTH, size);
+                    StatusCode::OK
+                }
+                ("HEAD", None) => StatusCode::NOT_FOUND,
+                (method, _) => {
+                    let query = req.uri().query().unwrap_or_default();
+                    let header = |name| {
+                        req.headers()
+                            .get(name)
+                            .map(|v| v.to_str().unwrap().to_string())
+                            .unwrap_or_default()
+                    };
+                    self.requests.lock().unwrap().push(format!(
+                        "{method} {query} {}{}{}",
+                        header(X_MS_BLOB_TYPE),
+                        header("x-ms-blob-content-length"),
+                        header("x-ms-range")
+                    ));
+                    if query == "comp=properties" {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::CREATED
+                    }
+                }
+            };
+
+            Ok(resp
+                .status(status)
+                .body(HttpBody::new(futures::stream::empty(), Some(0)))
+                .unwrap())
+        }
+    }
+
+    async fn write_at(
+        blob: Option<(&'static str, u64)>,
+        offset: u64,
+        size: usize,
+    ) -> Result<Vec<String>> {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let op = Operator::new(
+            Azblob::default()
+                .endpoint("https://account.blob.core.windows.net")
+                .account_name("account")
+                .account_key("YWNjb3VudGtleQ==")
+                .container("container"),
+        )?
+        .with_context(
+            OperationContext::new().with_http_transport(HttpTransporter::new(MockTransport {
+                blob,
+                requests: requests.clone(),
+            })),
+        );
+
+        op.write_with("blob", vec![0; size]).offset(offset).await?;
+
+        Ok(requests.lock().unwrap().clone())
+    }
+
+    #[tokio::test]
+    async fn test_write_at_offset() -> Result<()> {
+        // Writes inside the blob are sent as is.
+        assert_eq!(
+            write_at(Some(("PageBlob", 4096)), 1024, 1024).await?,
+            vec!["PUT comp=page bytes=1024-2047"]
+        );
+
+        // Missing blob is created, and extended before writing.
+        assert_eq!(
+            write_at(None, 512, 512).await?,
+            vec![
+                "PUT  PageBlob0",
+                "PUT comp=properties 1024",
+                "PUT comp=page bytes=512-1023"
+            ]
+        );
+
+        // Writes are split into pages of at most 4 MiB.
+        assert_eq!(
+            write_at(Some(("PageBlob", 8 << 20)), 0, 5 << 20).await?,
+            vec![
+                "PUT comp=page bytes=0-4194303",
+                "PUT comp=page bytes=4194304-5242879"
+            ]
+        );
+
+        let err = write_at(Some(("PageBlob", 4096)), 1024, 100)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let err = write_at(Some(("BlockBlob", 4096)), 0, 512)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        Ok(())
+    }
+}
@@ -34,6 +34,7 @@ use super::AZDLS_SCHEME;
 use super::config::AzdlsConfig;
 use super::core::AzdlsCore;
 use super::core::DIRECTORY;
+use super::core::parse_acl;
 use super::core::parse_error;
 use super::deleter::AzdlsDeleter;
 use super::lister::AzdlsLister;
@@ -335,14 +336,20 @@ impl Builder for AzdlsBuilder {
             write_with_user_metadata: true,
 
             create_dir: true,
+            create_dir_persistent: true,
 
             delete: true,
             delete_with_recursive: true,
 
             rename: true,
+            rename_is_atomic: true,
 
             list: true,
 
+            // Access control lists are only available with hierarchical namespace.
+            get_acl: self.config.enable_hns,
+            set_acl: self.config.enable_hns,
+
             shared: true,
 
             ..Default::default()
@@ -446,7 +453,8 @@ impl Service for AzdlsBackend {
                     ctx.executor().clone(),
                     AzdlsLazyPositionWriter::new(w),
                     args.concurrent(),
-                );
+                )
+                .with_adaptive_concurrency(args.adaptive_concurrency().cloned());
                 Ok(AzdlsWriters::One(w))
             }
         }?;
@@ -530,4 +538,30 @@ impl Service for AzdlsBackend {
             "operation is not supported",
         ))
     }
+
+    async fn get_acl(&self, ctx: &OperationContext, path: &str, _: OpGetAcl) -> Result<RpGetAcl> {
+        let resp = self.core.azdls_get_access_control(ctx, path).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpGetAcl::new(parse_acl(resp.headers())?)),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        let resp = self
+            .core
+            .azdls_set_access_control(ctx, path, args.acl())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetAcl::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
 }
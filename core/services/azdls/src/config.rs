@@ -60,7 +60,8 @@ pub struct AzdlsConfig {
     /// - default value: `https://login.microsoftonline.com`
     pub authority_host: Option<String>,
     /// Whether hierarchical namespace (HNS) is enabled for the storage account.
-    /// When enabled, recursive deletion can use pagination to avoid timeouts on large directories.
+    /// When enabled, recursive deletion can use pagination to avoid timeouts on large directories,
+    /// and access control lists can be managed by `get_acl` and `set_acl`.
     /// - default value: `false`
     pub enable_hns: bool,
 }
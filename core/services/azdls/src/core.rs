@@ -47,6 +47,10 @@ pub const FILE: &str = "file";
 // Format: `n1=base64(v1),n2=base64(v2),...`.
 // See https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/create
 const X_MS_PROPERTIES: &str = "x-ms-properties";
+const X_MS_OWNER: &str = "x-ms-owner";
+const X_MS_GROUP: &str = "x-ms-group";
+const X_MS_PERMISSIONS: &str = "x-ms-permissions";
+const X_MS_ACL: &str = "x-ms-acl";
 
 pub struct AzdlsCore {
     pub info: ServiceInfo,
@@ -417,6 +421,87 @@ impl AzdlsCore {
         }
     }
 
+    /// Get the owner, group, permissions and acl of the path.
+    ///
+    /// Requires the hierarchical namespace to be enabled.
+    pub async fn azdls_get_access_control(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path)
+            .trim_end_matches('/')
+            .to_string();
+
+        let url = format!(
+            "{}/{}/{}?action=getAccessControl",
+            self.endpoint,
+            self.filesystem,
+            percent_encode_path(&p)
+        );
+
+        let req = Request::head(&url)
+            .extension(Operation::GetAcl)
+            .extension(ServiceOperation("GetAccessControl"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    /// Set the owner, group, permissions or acl of the path.
+    ///
+    /// Requires the hierarchical namespace to be enabled.
+    pub async fn azdls_set_access_control(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        acl: &Acl,
+    ) -> Result<Response<Buffer>> {
+        // azdls rejects requests that set both of them.
+        if acl.permissions().is_some() && !acl.entries().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "azdls can't set permissions and acl entries at the same time",
+            ));
+        }
+
+        let p = build_abs_path(&self.root, path)
+            .trim_end_matches('/')
+            .to_string();
+
+        let url = format!(
+            "{}/{}/{}?action=setAccessControl",
+            self.endpoint,
+            self.filesystem,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::patch(&url).header(CONTENT_LENGTH, 0);
+        if let Some(owner) = acl.owner() {
+            req = req.header(X_MS_OWNER, owner);
+        }
+        if let Some(group) = acl.group() {
+            req = req.header(X_MS_GROUP, group);
+        }
+        if let Some(permissions) = acl.permissions() {
+            req = req.header(X_MS_PERMISSIONS, permissions);
+        }
+        if !acl.entries().is_empty() {
+            req = req.header(X_MS_ACL, Acl::format_entries(acl.entries()));
+        }
+
+        let req = req
+            .extension(Operation::SetAcl)
+            .extension(ServiceOperation("SetAccessControl"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
     pub async fn azdls_delete(
         &self,
         ctx: &OperationContext,
@@ -565,6 +650,23 @@ impl AzdlsCore {
     }
 }
 
+pub fn parse_acl(headers: &http::HeaderMap) -> Result<Acl> {
+    let mut acl = Acl::new();
+    if let Some(v) = parse_header_to_str(headers, X_MS_OWNER)? {
+        acl = acl.with_owner(v);
+    }
+    if let Some(v) = parse_header_to_str(headers, X_MS_GROUP)? {
+        acl = acl.with_group(v);
+    }
+    if let Some(v) = parse_header_to_str(headers, X_MS_PERMISSIONS)? {
+        acl = acl.with_permissions(v);
+    }
+    if let Some(v) = parse_header_to_str(headers, X_MS_ACL)? {
+        acl = acl.with_entries(Acl::parse_entries(v)?);
+    }
+    Ok(acl)
+}
+
 fn encode_user_metadata(metadata: &HashMap<String, String>) -> String {
     metadata
         .iter()
@@ -589,6 +691,31 @@ fn decode_user_metadata(value: &str) -> HashMap<String, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_acl() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(X_MS_OWNER, "$superuser".parse().unwrap());
+        headers.insert(X_MS_GROUP, "$superuser".parse().unwrap());
+        headers.insert(X_MS_PERMISSIONS, "rwxr-x---+".parse().unwrap());
+        headers.insert(
+            X_MS_ACL,
+            "user::rwx,user:4a9028cf-f779-4032-b09d-970ebe3cd31d:r-x,group::r-x,mask::r-x,other::---"
+                .parse()
+                .unwrap(),
+        );
+
+        let acl = parse_acl(&headers).unwrap();
+        assert_eq!(acl.owner(), Some("$superuser"));
+        assert_eq!(acl.group(), Some("$superuser"));
+        assert_eq!(acl.permissions(), Some("rwxr-x---+"));
+        assert_eq!(acl.entries().len(), 5);
+        assert_eq!(
+            acl.entries()[1].qualifier(),
+            Some("4a9028cf-f779-4032-b09d-970ebe3cd31d")
+        );
+        assert_eq!(acl.entries()[1].permissions(), "r-x");
+    }
+
     #[test]
     fn encode_then_decode_roundtrips() {
         let mut input = HashMap::new();
@@ -236,9 +236,11 @@ impl Builder for AzfileBuilder {
             read: true,
 
             write: true,
+            write_can_seek: true,
             write_with_user_metadata: true,
 
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
             rename: true,
 
@@ -359,7 +361,7 @@ impl Service for AzfileBackend {
                 args.clone(),
                 path.to_string(),
             );
-            let w = if args.append() {
+            let w = if args.append() || args.offset().is_some() {
                 AzfileWriters::Two(oio::AppendWriter::new(w))
             } else {
                 AzfileWriters::One(oio::OneShotWriter::new(w))
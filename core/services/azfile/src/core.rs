@@ -38,6 +38,10 @@ const X_MS_WRITE: &str = "x-ms-write";
 const X_MS_FILE_RENAME_SOURCE: &str = "x-ms-file-rename-source";
 const X_MS_CONTENT_LENGTH: &str = "x-ms-content-length";
 const X_MS_TYPE: &str = "x-ms-type";
+const X_MS_FILE_PERMISSION: &str = "x-ms-file-permission";
+const X_MS_FILE_ATTRIBUTES: &str = "x-ms-file-attributes";
+const X_MS_FILE_CREATION_TIME: &str = "x-ms-file-creation-time";
+const X_MS_FILE_LAST_WRITE_TIME: &str = "x-ms-file-last-write-time";
 const X_MS_FILE_RENAME_REPLACE_IF_EXISTS: &str = "x-ms-file-rename-replace-if-exists";
 pub const X_MS_META_PREFIX: &str = "x-ms-meta-";
 
@@ -218,6 +222,47 @@ impl AzfileCore {
         self.send(ctx, req).await
     }
 
+    /// Resize the file to `size`, the file will be filled with zeros if extended.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/set-file-properties
+    pub async fn azfile_set_file_size(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        size: u64,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path)
+            .trim_start_matches('/')
+            .to_string();
+        let url = format!(
+            "{}/{}/{}?comp=properties",
+            self.endpoint,
+            self.share_name,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = req.header(X_MS_CONTENT_LENGTH, size);
+        req = req.header(CONTENT_LENGTH, 0);
+
+        // Keep other properties unchanged.
+        req = req.header(X_MS_FILE_PERMISSION, "preserve");
+        req = req.header(X_MS_FILE_ATTRIBUTES, "preserve");
+        req = req.header(X_MS_FILE_CREATION_TIME, "preserve");
+        req = req.header(X_MS_FILE_LAST_WRITE_TIME, "preserve");
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("SetFileProperties"));
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
     pub async fn azfile_get_file_properties(
         &self,
         ctx: &OperationContext,
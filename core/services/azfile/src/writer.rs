@@ -16,6 +16,8 @@
 // under the License.
 
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 
 use http::StatusCode;
 
@@ -31,6 +33,8 @@ pub struct AzfileWriter {
     ctx: OperationContext,
     op: OpWrite,
     path: String,
+    /// The current size of file, used by append writes.
+    size: AtomicU64,
 }
 
 impl AzfileWriter {
@@ -40,6 +44,7 @@ impl AzfileWriter {
             ctx,
             op,
             path,
+            size: AtomicU64::new(0),
         }
     }
 
@@ -96,6 +101,8 @@ impl oio::OneShotWrite for AzfileWriter {
     }
 }
 
+/// Append writes are also used to write at an offset, which starts at the
+/// offset of op instead of the end of file.
 impl oio::AppendWrite for AzfileWriter {
     async fn offset(&self) -> Result<u64> {
         self.ensure_parent_dir_exists().await?;
@@ -107,13 +114,40 @@ impl oio::AppendWrite for AzfileWriter {
 
         let status = resp.status();
 
-        match status {
-            StatusCode::OK => Ok(parse_content_length(resp.headers())?.unwrap_or_default()),
-            _ => Err(parse_error(resp)),
-        }
+        let size = match status {
+            StatusCode::OK => parse_content_length(resp.headers())?.unwrap_or_default(),
+            StatusCode::NOT_FOUND if self.op.offset().is_some() => {
+                let resp = self
+                    .core
+                    .azfile_create_file(&self.ctx, &self.path, 0, &self.op)
+                    .await?;
+                match resp.status() {
+                    StatusCode::OK | StatusCode::CREATED => 0,
+                    _ => {
+                        return Err(parse_error(resp).with_operation("Backend::azfile_create_file"));
+                    }
+                }
+            }
+            _ => return Err(parse_error(resp)),
+        };
+        self.size.store(size, Ordering::Relaxed);
+
+        Ok(self.op.offset().unwrap_or(size))
     }
 
     async fn append(&self, offset: u64, size: u64, body: Buffer) -> Result<Metadata> {
+        // Ranges can only be written inside the file, extend it first.
+        if offset + size > self.size.load(Ordering::Relaxed) {
+            let resp = self
+                .core
+                .azfile_set_file_size(&self.ctx, &self.path, offset + size)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).with_operation("Backend::azfile_set_file_size"));
+            }
+            self.size.store(offset + size, Ordering::Relaxed);
+        }
+
         let resp = self
             .core
             .azfile_update(&self.ctx, &self.path, size, offset, body)
@@ -128,3 +162,120 @@ impl oio::AppendWrite for AzfileWriter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use http::Request;
+    use http::Response;
+
+    use super::*;
+    use crate::Azfile;
+
+    /// Record write requests and reply as an azure file service with a
+    /// file of `size` if it exists.
+    struct MockTransport {
+        size: Option<u64>,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpTransport for MockTransport {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let query = req.uri().query().unwrap_or_default();
+            let mut resp = Response::builder();
+            let status = match (req.method().as_str(), query) {
+                ("HEAD", "restype=directory") => StatusCode::OK,
+                ("HEAD", _) => match self.size {
+                    Some(size) => {
+                        resp = resp.header(http::header::CONTENT_LENGTH, size);
+                        StatusCode::OK
+                    }
+                    None => StatusCode::NOT_FOUND,
+                },
+                (method, query) => {
+                    let header = |name| {
+                        req.headers()
+                            .get(name)
+                            .map(|v| v.to_str().unwrap().to_string())
+                            .unwrap_or_default()
+                    };
+                    self.requests.lock().unwrap().push(format!(
+                        "{method} {query} {}{}",
+                        header("x-ms-content-length"),
+                        header("range")
+                    ));
+                    if query == "comp=properties" {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::CREATED
+                    }
+                }
+            };
+
+            Ok(resp
+                .status(status)
+                .body(HttpBody::new(futures::stream::empty(), Some(0)))
+                .unwrap())
+        }
+    }
+
+    async fn write_at(size: Option<u64>, offset: u64) -> Result<Vec<String>> {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let op = Operator::new(
+            Azfile::default()
+                .endpoint("https://account.file.core.windows.net")
+                .account_name("account")
+                .account_key("YWNjb3VudGtleQ==")
+                .share_name("share"),
+        )?
+        .with_context(
+            OperationContext::new().with_http_transport(HttpTransporter::new(MockTransport {
+                size,
+                requests: requests.clone(),
+            })),
+        );
+
+        let mut w = op.writer_with("file").offset(offset).await?;
+        w.write(vec![0; 512]).await?;
+        w.write(vec![0; 512]).await?;
+        w.close().await?;
+
+        Ok(requests.lock().unwrap().clone())
+    }
+
+    #[tokio::test]
+    async fn test_write_at_offset() -> Result<()> {
+        // Writes inside the file are sent as is.
+        assert_eq!(
+            write_at(Some(4096), 1024).await?,
+            vec![
+                "PUT comp=range bytes=1024-1535",
+                "PUT comp=range bytes=1536-2047"
+            ]
+        );
+
+        // Writes past the end of file extend it first.
+        assert_eq!(
+            write_at(Some(1024), 512).await?,
+            vec![
+                "PUT comp=range bytes=512-1023",
+                "PUT comp=properties 1536",
+                "PUT comp=range bytes=1024-1535"
+            ]
+        );
+
+        // Missing file is created before writing.
+        assert_eq!(
+            write_at(None, 512).await?,
+            vec![
+                "PUT  0",
+                "PUT comp=properties 1024",
+                "PUT comp=range bytes=512-1023",
+                "PUT comp=properties 1536",
+                "PUT comp=range bytes=1024-1535"
+            ]
+        );
+        Ok(())
+    }
+}
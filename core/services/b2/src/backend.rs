@@ -33,6 +33,7 @@ use super::core::parse_file_info;
 use super::deleter::B2Deleter;
 use super::lister::B2Lister;
 use super::reader::*;
+use super::writer::B2PartWriter;
 use super::writer::B2Writer;
 use super::writer::B2Writers;
 use opendal_core::raw::*;
@@ -104,6 +105,15 @@ impl B2Builder {
 
         self
     }
+
+    /// Set the size of each part in large file uploads.
+    ///
+    /// Default to the recommended part size of the account.
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.config.part_size = Some(part_size);
+
+        self
+    }
 }
 
 impl Builder for B2Builder {
@@ -207,6 +217,7 @@ impl Builder for B2Builder {
 
                 bucket: self.config.bucket.clone(),
                 bucket_id: self.config.bucket_id.clone(),
+                part_size: self.config.part_size,
             }),
         })
     }
@@ -275,11 +286,13 @@ impl Service for B2Backend {
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: B2Writers = {
             let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
             let writer = B2Writer::new(self.core.clone(), ctx.clone(), path, args);
 
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent);
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent)
+                .with_adaptive_concurrency(adaptive);
 
-            Ok(w)
+            Ok(B2PartWriter::new(self.core.clone(), ctx.clone(), w))
         }?;
 
         Ok(output)
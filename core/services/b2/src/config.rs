@@ -49,6 +49,13 @@ pub struct B2Config {
     ///
     /// required.
     pub bucket_id: String,
+    /// The size of each part in large file uploads.
+    ///
+    /// Must not be smaller than the `absoluteMinimumPartSize` returned by
+    /// `b2_authorize_account`.
+    ///
+    /// default to the `recommendedPartSize` returned by `b2_authorize_account`.
+    pub part_size: Option<usize>,
 }
 
 impl Debug for B2Config {
@@ -58,6 +65,7 @@ impl Debug for B2Config {
             .field("application_key_id", &self.application_key_id)
             .field("bucket_id", &self.bucket_id)
             .field("bucket", &self.bucket)
+            .field("part_size", &self.part_size)
             .finish_non_exhaustive()
     }
 }
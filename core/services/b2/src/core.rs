@@ -17,6 +17,7 @@
 
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::fmt::Write;
 use std::sync::Arc;
 
 use bytes::Buf;
@@ -27,9 +28,12 @@ use http::header;
 use mea::rwlock::RwLock;
 use serde::Deserialize;
 use serde::Serialize;
+use sha1::Digest;
+use sha1::Sha1;
 
 use self::constants::X_BZ_CONTENT_SHA1;
 use self::constants::X_BZ_FILE_NAME;
+use super::B2_SCHEME;
 use constants::X_BZ_PART_NUMBER;
 use opendal_core::raw::*;
 use opendal_core::*;
@@ -54,6 +58,8 @@ pub struct B2Core {
     pub bucket: String,
     /// The bucket id of this backend.
     pub bucket_id: String,
+    /// The part size of large file uploads set by user.
+    pub part_size: Option<usize>,
 }
 
 impl Debug for B2Core {
@@ -62,6 +68,7 @@ impl Debug for B2Core {
             .field("root", &self.root)
             .field("bucket", &self.bucket)
             .field("bucket_id", &self.bucket_id)
+            .field("part_size", &self.part_size)
             .finish_non_exhaustive()
     }
 }
@@ -115,6 +122,8 @@ impl B2Core {
                         authorization_token: token.authorization_token.clone(),
                         api_url: token.api_url.clone(),
                         download_url: token.download_url.clone(),
+                        recommended_part_size: token.recommended_part_size,
+                        absolute_minimum_part_size: token.absolute_minimum_part_size,
                         // This authorization token is valid for at most 24 hours.
                         expires_in: Timestamp::now() + Duration::from_secs(20 * 60 * 60),
                     };
@@ -126,6 +135,30 @@ impl B2Core {
             Ok(signer.auth_info.clone())
         }
     }
+
+    /// Get the part size of large file uploads.
+    ///
+    /// Uses the part size set by user or the recommended part size of the
+    /// account, which must not be smaller than the absolute minimum.
+    pub async fn part_size(&self, ctx: &OperationContext) -> Result<usize> {
+        let auth_info = self.get_auth_info(ctx).await?;
+
+        let part_size = self.part_size.unwrap_or(auth_info.recommended_part_size);
+        if part_size < auth_info.absolute_minimum_part_size {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "part_size is smaller than the absolute minimum part size of b2",
+            )
+            .with_context("service", B2_SCHEME)
+            .with_context("part_size", part_size.to_string())
+            .with_context(
+                "absolute_minimum_part_size",
+                auth_info.absolute_minimum_part_size.to_string(),
+            ));
+        }
+
+        Ok(part_size)
+    }
 }
 
 impl B2Core {
@@ -263,7 +296,7 @@ impl B2Core {
 
         req = req.header(header::AUTHORIZATION, resp.authorization_token);
 
-        req = req.header(X_BZ_CONTENT_SHA1, "do_not_verify");
+        req = req.header(X_BZ_CONTENT_SHA1, content_sha1(&body));
 
         if let Some(size) = size {
             req = req.header(header::CONTENT_LENGTH, size.to_string())
@@ -395,6 +428,7 @@ impl B2Core {
         file_id: &str,
         part_number: usize,
         size: u64,
+        sha1: &str,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
         let resp = self.get_upload_part_url(ctx, file_id).await?;
@@ -407,7 +441,9 @@ impl B2Core {
 
         req = req.header(header::AUTHORIZATION, resp.authorization_token);
 
-        req = req.header(X_BZ_CONTENT_SHA1, "do_not_verify");
+        // B2 verifies the part against this checksum, and the list of part
+        // checksums is required to finish the large file.
+        req = req.header(X_BZ_CONTENT_SHA1, sha1);
 
         let req = req
             .extension(Operation::Write)
@@ -655,6 +691,10 @@ pub struct AuthInfo {
     pub api_url: String,
     /// The base URL to use for downloading files.
     pub download_url: String,
+    /// The recommended part size of large files.
+    pub recommended_part_size: usize,
+    /// The smallest possible part size of large files, except the last one.
+    pub absolute_minimum_part_size: usize,
     /// The time when the authorization token expires.
     pub expires_in: Timestamp,
 }
@@ -669,6 +709,8 @@ impl Default for B2Signer {
                 authorization_token: String::new(),
                 api_url: String::new(),
                 download_url: String::new(),
+                recommended_part_size: 0,
+                absolute_minimum_part_size: 0,
                 expires_in: Timestamp::MIN,
             },
         }
@@ -705,6 +747,33 @@ pub struct AuthorizeAccountResponse {
     pub authorization_token: String,
     pub api_url: String,
     pub download_url: String,
+    #[serde(default = "default_recommended_part_size")]
+    pub recommended_part_size: usize,
+    #[serde(default = "default_absolute_minimum_part_size")]
+    pub absolute_minimum_part_size: usize,
+}
+
+fn default_recommended_part_size() -> usize {
+    100_000_000
+}
+
+fn default_absolute_minimum_part_size() -> usize {
+    5_000_000
+}
+
+/// Hex encoded SHA1 of the content, as required by `X-Bz-Content-Sha1`.
+pub(super) fn content_sha1(body: &Buffer) -> String {
+    let mut hasher = Sha1::new();
+    for bs in body.clone() {
+        hasher.update(&bs);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(40), |mut s, b| {
+            let _ = write!(s, "{b:02x}");
+            s
+        })
 }
 
 /// Response of [b2_get_upload_url](https://www.backblaze.com/apidocs/b2-get-upload-url).
@@ -801,16 +870,32 @@ pub(super) fn parse_file_info(file: &File) -> Metadata {
     // Parse user metadata from file_info
     // B2 stores user metadata with keys stripped of the "X-Bz-Info-" prefix
     // and values are URL-encoded
-    if !file.file_info.is_empty() {
-        let user_metadata: HashMap<String, String> = file
-            .file_info
-            .iter()
-            .map(|(k, v)| (k.to_lowercase(), percent_decode_path(v)))
-            .collect();
-        if !user_metadata.is_empty() {
-            metadata = metadata.with_user_metadata(user_metadata);
+    //
+    // Keys starting with `b2-` are reserved by B2 to hold standard headers
+    // instead of user metadata.
+    let mut user_metadata = HashMap::new();
+    for (k, v) in &file.file_info {
+        let k = k.to_lowercase();
+        let v = percent_decode_path(v);
+        match k.as_str() {
+            "b2-content-disposition" => {
+                metadata.set_content_disposition(&v);
+            }
+            "b2-content-encoding" => {
+                metadata.set_content_encoding(&v);
+            }
+            "b2-cache-control" => {
+                metadata.set_cache_control(&v);
+            }
+            _ if k.starts_with("b2-") => {}
+            _ => {
+                user_metadata.insert(k, v);
+            }
         }
     }
+    if !user_metadata.is_empty() {
+        metadata = metadata.with_user_metadata(user_metadata);
+    }
 
     metadata
 }
@@ -963,3 +1048,63 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_file_info() {
+        let file: File = serde_json::from_str(
+            r#"{
+                "contentLength": 3,
+                "contentType": "text/plain",
+                "fileName": "a.txt",
+                "fileInfo": {
+                    "Location": "Hello%20World",
+                    "b2-content-disposition": "attachment",
+                    "b2-expires": "never"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let meta = parse_file_info(&file);
+        assert_eq!(meta.content_disposition(), Some("attachment"));
+        assert_eq!(
+            meta.user_metadata(),
+            Some(&HashMap::from([(
+                "location".to_string(),
+                "Hello World".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_content_sha1() {
+        let body = Buffer::from(vec![
+            bytes::Bytes::from("hello, "),
+            bytes::Bytes::from("world"),
+        ]);
+        assert_eq!(
+            content_sha1(&body),
+            "b7e23ec29af22b0b4e41da31e868d57226121c84"
+        );
+    }
+
+    #[test]
+    fn test_parse_authorize_account_part_size() {
+        let resp: AuthorizeAccountResponse = serde_json::from_str(
+            r#"{
+                "authorizationToken": "token",
+                "apiUrl": "https://api001.backblazeb2.com",
+                "downloadUrl": "https://f001.backblazeb2.com",
+                "recommendedPartSize": 100000000,
+                "absoluteMinimumPartSize": 5000000
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(resp.recommended_part_size, 100_000_000);
+        assert_eq!(resp.absolute_minimum_part_size, 5_000_000);
+    }
+}
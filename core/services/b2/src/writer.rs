@@ -24,11 +24,84 @@ use super::core::B2Core;
 use super::core::StartLargeFileResponse;
 use super::core::UploadPartResponse;
 use super::core::UploadResponse;
+use super::core::content_sha1;
 use super::core::parse_error;
 use opendal_core::raw::*;
 use opendal_core::*;
 
-pub type B2Writers = oio::MultipartWriter<B2Writer>;
+pub type B2Writers = B2PartWriter<oio::MultipartWriter<B2Writer>>;
+
+/// Writer that splits data into parts of `part_size` bytes.
+///
+/// The upper writer only guarantees a lower bound on the size of each write,
+/// while b2 recommends a much larger part size for large files.
+///
+/// The part size is resolved from the account authorization on first write.
+pub struct B2PartWriter<W: oio::Write> {
+    core: Arc<B2Core>,
+    ctx: OperationContext,
+
+    inner: W,
+    part_size: Option<usize>,
+    buffer: oio::QueueBuf,
+}
+
+impl<W: oio::Write> B2PartWriter<W> {
+    pub fn new(core: Arc<B2Core>, ctx: OperationContext, inner: W) -> Self {
+        Self {
+            core,
+            ctx,
+            inner,
+            part_size: None,
+            buffer: oio::QueueBuf::new(),
+        }
+    }
+}
+
+impl<W: oio::Write> oio::Write for B2PartWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let part_size = match self.part_size {
+            Some(v) => v,
+            None => {
+                let v = self.core.part_size(&self.ctx).await?;
+                self.part_size = Some(v);
+                v
+            }
+        };
+
+        self.buffer.push(bs);
+
+        while self.buffer.len() >= part_size {
+            let mut buf = self.buffer.take().collect();
+            let part = buf.split_to(part_size);
+            if !buf.is_empty() {
+                self.buffer.push(buf);
+            }
+            self.inner.write(part).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        if !self.buffer.is_empty() {
+            let buf = self.buffer.take().collect();
+            self.inner.write(buf).await?;
+        }
+
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        self.inner.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.buffer.clear();
+        self.inner.suspend().await
+    }
+}
 
 pub struct B2Writer {
     core: Arc<B2Core>,
@@ -120,9 +193,11 @@ impl oio::MultipartWrite for B2Writer {
         // B2 requires part number must between [1..=10000]
         let part_number = part_number + 1;
 
+        let sha1 = content_sha1(&body);
+
         let resp = self
             .core
-            .upload_part(&self.ctx, upload_id, part_number, size, body)
+            .upload_part(&self.ctx, upload_id, part_number, size, &sha1, body)
             .await?;
 
         let status = resp.status();
@@ -134,11 +209,21 @@ impl oio::MultipartWrite for B2Writer {
                 let result: UploadPartResponse =
                     serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
 
+                if result.content_sha1 != sha1 {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "sha1 of uploaded part mismatched",
+                    )
+                    .with_context("part_number", part_number.to_string())
+                    .with_context("expected", sha1)
+                    .with_context("actual", result.content_sha1));
+                }
+
                 Ok(oio::MultipartPart {
-                    etag: result.content_sha1,
+                    etag: sha1,
                     part_number,
                     checksum: None,
-                    size: None,
+                    size: Some(size),
                 })
             }
             _ => Err(parse_error(resp)),
@@ -153,14 +238,13 @@ impl oio::MultipartWrite for B2Writer {
         let part_sha1_array = parts
             .iter()
             .map(|p| {
-                let binding = p.etag.clone();
-                let sha1 = binding.strip_prefix("unverified:");
-                let Some(sha1) = sha1 else {
-                    return "".to_string();
-                };
-                sha1.to_string()
+                p.etag
+                    .strip_prefix("unverified:")
+                    .unwrap_or(&p.etag)
+                    .to_string()
             })
             .collect();
+        let expected_size = parts.iter().map(|p| p.size).sum::<Option<u64>>();
 
         let resp = self
             .core
@@ -176,6 +260,18 @@ impl oio::MultipartWrite for B2Writer {
                 let result: UploadResponse =
                     serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
 
+                // Make sure the large file is assembled from all the parts we uploaded.
+                if let Some(expected_size) = expected_size {
+                    if result.content_length != expected_size {
+                        return Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "size of assembled large file mismatched",
+                        )
+                        .with_context("expected", expected_size.to_string())
+                        .with_context("actual", result.content_length.to_string()));
+                    }
+                }
+
                 let meta = Self::parse_body_into_meta(&self.path, result);
 
                 Ok(meta)
@@ -193,3 +289,85 @@ impl oio::MultipartWrite for B2Writer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use mea::rwlock::RwLock;
+
+    use super::super::B2_SCHEME;
+    use super::super::core::AuthInfo;
+    use super::super::core::B2Signer;
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct MockWriter {
+        parts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.parts.lock().unwrap().push(bs.len());
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::default())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn new_core(part_size: Option<usize>) -> Arc<B2Core> {
+        let signer = B2Signer {
+            application_key_id: "key_id".to_string(),
+            application_key: "key".to_string(),
+            auth_info: AuthInfo {
+                authorization_token: "token".to_string(),
+                api_url: String::new(),
+                download_url: String::new(),
+                recommended_part_size: 4,
+                absolute_minimum_part_size: 2,
+                expires_in: Timestamp::MAX,
+            },
+        };
+
+        Arc::new(B2Core {
+            info: ServiceInfo::new(B2_SCHEME, "/", "bucket"),
+            capability: Capability::default(),
+            signer: Arc::new(RwLock::new(signer)),
+            root: "/".to_string(),
+            bucket: "bucket".to_string(),
+            bucket_id: "bucket_id".to_string(),
+            part_size,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_write_with_recommended_part_size() -> Result<()> {
+        let mock = MockWriter::default();
+        let mut w = B2PartWriter::new(new_core(None), OperationContext::default(), mock.clone());
+
+        oio::Write::write(&mut w, Buffer::from(vec![0; 3])).await?;
+        oio::Write::write(&mut w, Buffer::from(vec![0; 7])).await?;
+        oio::Write::write(&mut w, Buffer::from(vec![0; 1])).await?;
+        oio::Write::close(&mut w).await?;
+
+        assert_eq!(*mock.parts.lock().unwrap(), vec![4, 4, 3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_with_too_small_part_size() {
+        let mock = MockWriter::default();
+        let mut w = B2PartWriter::new(new_core(Some(1)), OperationContext::default(), mock);
+
+        let err = oio::Write::write(&mut w, Buffer::from(vec![0; 3]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -0,0 +1,374 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use http::StatusCode;
+use log::debug;
+use mea::mutex::Mutex;
+
+use super::BOX_SCHEME;
+use super::config::BoxConfig;
+use super::core::*;
+use super::deleter::BoxDeleter;
+use super::lister::BoxLister;
+use super::reader::BoxReader;
+use super::writer::BoxWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// [Box](https://www.box.com/) backend support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct BoxBuilder {
+    pub(super) config: BoxConfig,
+}
+
+impl Debug for BoxBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BoxBuilder {
+    /// Set the root directory for box.
+    ///
+    /// Default to `/` if not set.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Access token is used for temporary access to the Box API.
+    ///
+    /// You can get a developer token from the [Box Developer Console](https://app.box.com/developers/console).
+    ///
+    /// NOTE: this token will be expired in 60 minutes.
+    /// If you are trying to use the Box service in a long time, please set a refresh_token instead.
+    pub fn access_token(mut self, access_token: &str) -> Self {
+        self.config.access_token = Some(access_token.to_string());
+        self
+    }
+
+    /// Refresh token is used for long term access to the Box API.
+    ///
+    /// You can get the refresh token via OAuth 2.0 Flow of Box.
+    ///
+    /// OpenDAL will use this refresh token to get a new access token when the old one is expired.
+    ///
+    /// NOTE: Box issues a new refresh token on every refresh and invalidates the old one.
+    /// The rotated token is only kept in memory, so the given token can't be reused after
+    /// the operator refreshed it.
+    pub fn refresh_token(mut self, refresh_token: &str) -> Self {
+        self.config.refresh_token = Some(refresh_token.to_string());
+        self
+    }
+
+    /// Set the client id for Box.
+    ///
+    /// This is required for OAuth 2.0 Flow to refresh the access token.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        self.config.client_id = Some(client_id.to_string());
+        self
+    }
+
+    /// Set the client secret for Box.
+    ///
+    /// This is required for OAuth 2.0 Flow to refresh the access token.
+    pub fn client_secret(mut self, client_secret: &str) -> Self {
+        self.config.client_secret = Some(client_secret.to_string());
+        self
+    }
+}
+
+impl Builder for BoxBuilder {
+    type Config = BoxConfig;
+
+    fn build(self) -> Result<impl Service> {
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        let signer = match (self.config.access_token, self.config.refresh_token) {
+            (Some(access_token), None) => BoxSigner {
+                access_token,
+                // We will never expire user specified token.
+                expires_in: Timestamp::MAX,
+                ..Default::default()
+            },
+            (None, Some(refresh_token)) => {
+                let client_id = self.config.client_id.ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "client_id must be set when refresh_token is set",
+                    )
+                    .with_context("service", BOX_SCHEME)
+                })?;
+                let client_secret = self.config.client_secret.ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "client_secret must be set when refresh_token is set",
+                    )
+                    .with_context("service", BOX_SCHEME)
+                })?;
+
+                BoxSigner {
+                    refresh_token,
+                    client_id,
+                    client_secret,
+                    ..Default::default()
+                }
+            }
+            (Some(_), Some(_)) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "access_token and refresh_token can not be set at the same time",
+                )
+                .with_context("service", BOX_SCHEME));
+            }
+            (None, None) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "access_token or refresh_token must be set",
+                )
+                .with_context("service", BOX_SCHEME));
+            }
+        };
+
+        Ok(BoxBackend {
+            core: Arc::new(BoxCore {
+                info: ServiceInfo::new(BOX_SCHEME, &root, ""),
+                capability: Capability {
+                    stat: true,
+
+                    read: true,
+
+                    write: true,
+                    write_can_empty: true,
+
+                    create_dir: true,
+                    create_dir_persistent: true,
+
+                    delete: true,
+
+                    list: true,
+                    list_with_limit: true,
+
+                    copy: true,
+
+                    rename: true,
+
+                    shared: true,
+
+                    ..Default::default()
+                },
+                root,
+                signer: Arc::new(Mutex::new(signer)),
+                folder_ids: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BoxBackend {
+    pub core: Arc<BoxCore>,
+}
+
+impl BoxBackend {
+    /// Remove the file at `path` if it exists, Box refuses to copy or move
+    /// onto an existing name.
+    async fn remove_existing_file(&self, ctx: &OperationContext, path: &str) -> Result<()> {
+        let item = match self.core.box_get_item(ctx, path).await {
+            Ok(item) => item,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        if item.is_folder() {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                format!("target is a directory: {path}"),
+            ));
+        }
+
+        let resp = self.core.box_delete(ctx, &item).await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+impl Service for BoxBackend {
+    type Reader = oio::StreamReader<BoxReader>;
+    type Writer = oio::OneShotWriter<BoxWriter>;
+    type Lister = oio::PageLister<BoxLister>;
+    type Deleter = oio::OneShotDeleter<BoxDeleter>;
+    type Copier = oio::OneShotCopier;
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        let path = build_abs_path(&self.core.root, path);
+        self.core.ensure_folder_id(ctx, &path).await?;
+
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let item = self.core.box_get_item(ctx, path).await?;
+
+        Ok(RpStat::new(item.parse_metadata()?))
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(BoxReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+            args,
+        )))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        Ok(oio::OneShotWriter::new(BoxWriter::new(
+            self.core.clone(),
+            ctx.clone(),
+            args,
+            path.to_string(),
+        )))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(BoxDeleter::new(
+            self.core.clone(),
+            ctx.clone(),
+        )))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        Ok(oio::PageLister::new(BoxLister::new(
+            self.core.clone(),
+            ctx.clone(),
+            path.to_string(),
+            args.limit(),
+        )))
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let backend = self.clone();
+        let ctx = ctx.clone();
+        let from = from.to_string();
+        let to = to.to_string();
+
+        Ok(oio::OneShotCopier::new(async move {
+            let core = &backend.core;
+            let item = core.box_get_item(&ctx, &from).await?;
+            if item.is_folder() {
+                return Err(Error::new(
+                    ErrorKind::IsADirectory,
+                    format!("source is a directory: {from}"),
+                ));
+            }
+
+            backend.remove_existing_file(&ctx, &to).await?;
+
+            let target = build_abs_path(&core.root, &to);
+            let parent_id = core.ensure_folder_id(&ctx, parent_of(&target)).await?;
+            let resp = core
+                .box_copy_file(&ctx, &item.id, &parent_id, get_basename(&target))
+                .await?;
+
+            match resp.status() {
+                StatusCode::CREATED | StatusCode::OK => Ok(Metadata::default()),
+                _ => Err(parse_error(resp)),
+            }
+        }))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        if from == to {
+            return Ok(RpRename::default());
+        }
+
+        let item = self.core.box_get_item(ctx, from).await?;
+        if !item.is_folder() {
+            self.remove_existing_file(ctx, to).await?;
+        }
+
+        let target = build_abs_path(&self.core.root, to);
+        let parent_id = self.core.ensure_folder_id(ctx, parent_of(&target)).await?;
+        let name = get_basename(&target).trim_end_matches('/');
+        let resp = self.core.box_move(ctx, &item, &parent_id, name).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                if item.is_folder() {
+                    self.core
+                        .invalidate_folder(&build_abs_path(&self.core.root, from))
+                        .await;
+                }
+                Ok(RpRename::default())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
@@ -0,0 +1,859 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bytes::Buf;
+use bytes::Bytes;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_TYPE;
+use mea::mutex::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+const BOX_API_ENDPOINT: &str = "https://api.box.com/2.0";
+const BOX_UPLOAD_ENDPOINT: &str = "https://upload.box.com/api/2.0";
+const BOX_TOKEN_ENDPOINT: &str = "https://api.box.com/oauth2/token";
+
+/// The id of the root folder of every Box account.
+pub const BOX_ROOT_FOLDER_ID: &str = "0";
+/// Fields required to build metadata from the items in a folder listing.
+const BOX_ITEM_FIELDS: &str = "type,id,name,size,modified_at,etag";
+/// The max page size of folder listing allowed by Box.
+pub const BOX_LIST_LIMIT: usize = 1000;
+/// Box keeps processing the uploaded parts after commit, wait at most this
+/// many rounds before giving up.
+const BOX_COMMIT_MAX_RETRIES: usize = 30;
+
+pub struct BoxCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub root: String,
+    pub signer: Arc<Mutex<BoxSigner>>,
+
+    /// Box addresses items by id only, cache the ids of resolved folders to
+    /// avoid walking the whole path on every request.
+    ///
+    /// Keys are absolute folder paths ending with `/`.
+    pub folder_ids: Mutex<HashMap<String, String>>,
+}
+
+impl Debug for BoxCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxCore")
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BoxCore {
+    pub async fn sign<T>(&self, ctx: &OperationContext, req: &mut Request<T>) -> Result<()> {
+        let mut signer = self.signer.lock().await;
+
+        // Access token is valid, use it directly.
+        if !signer.access_token.is_empty() && signer.expires_in > Timestamp::now() {
+            let value = format!("Bearer {}", signer.access_token)
+                .parse()
+                .expect("token must be valid header value");
+            req.headers_mut().insert(header::AUTHORIZATION, value);
+            return Ok(());
+        }
+
+        // Refresh invalid token.
+        let content = format!(
+            "grant_type=refresh_token&refresh_token={}&client_id={}&client_secret={}",
+            percent_encode_path(&signer.refresh_token),
+            percent_encode_path(&signer.client_id),
+            percent_encode_path(&signer.client_secret)
+        );
+        let bs = Bytes::from(content);
+
+        let request = Request::post(BOX_TOKEN_ENDPOINT)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(CONTENT_LENGTH, bs.len())
+            .body(Buffer::from(bs))
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(request).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let token: BoxTokenResponse = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+
+        // Update signer after token refreshed.
+        signer.access_token.clone_from(&token.access_token);
+        // Box rotates the refresh token on every use, the old one is invalid now.
+        if let Some(refresh_token) = token.refresh_token {
+            signer.refresh_token = refresh_token;
+        }
+
+        // Refresh it 2 minutes earlier.
+        signer.expires_in =
+            Timestamp::now() + Duration::from_secs(token.expires_in) - Duration::from_secs(120);
+
+        let value = format!("Bearer {}", token.access_token)
+            .parse()
+            .expect("token must be valid header value");
+        req.headers_mut().insert(header::AUTHORIZATION, value);
+
+        Ok(())
+    }
+
+    pub async fn send(
+        &self,
+        ctx: &OperationContext,
+        mut req: Request<Buffer>,
+    ) -> Result<Response<Buffer>> {
+        self.sign(ctx, &mut req).await?;
+        ctx.http_transport().send(req).await
+    }
+
+    /// Find the item named `name` in the given folder.
+    ///
+    /// Box has no API to look up a child by name, so we need to walk
+    /// through the folder listing.
+    pub async fn box_find_child(
+        &self,
+        ctx: &OperationContext,
+        folder_id: &str,
+        name: &str,
+    ) -> Result<Option<BoxItem>> {
+        let mut marker = String::new();
+
+        loop {
+            let resp = self
+                .box_list_folder(ctx, folder_id, BOX_LIST_LIMIT, &marker)
+                .await?;
+            match resp.status() {
+                StatusCode::OK => {}
+                // The folder has been removed by others.
+                StatusCode::NOT_FOUND => return Ok(None),
+                _ => return Err(parse_error(resp)),
+            }
+
+            let list: BoxItemList = serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+
+            if let Some(item) = list.entries.into_iter().find(|item| item.name == name) {
+                return Ok(Some(item));
+            }
+
+            match list.next_marker {
+                Some(v) if !v.is_empty() => marker = v,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    /// Resolve the id of the folder at the given absolute path.
+    ///
+    /// Returns `None` if any component of the path doesn't exist.
+    pub async fn resolve_folder_id(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Option<String>> {
+        let mut folder_id = BOX_ROOT_FOLDER_ID.to_string();
+        let mut current = String::new();
+
+        for name in path.split('/').filter(|v| !v.is_empty()) {
+            current.push_str(name);
+            current.push('/');
+
+            if let Some(id) = self.folder_ids.lock().await.get(&current) {
+                folder_id.clone_from(id);
+                continue;
+            }
+
+            match self.box_find_child(ctx, &folder_id, name).await? {
+                Some(item) if item.is_folder() => {
+                    self.folder_ids
+                        .lock()
+                        .await
+                        .insert(current.clone(), item.id.clone());
+                    folder_id = item.id;
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        Ok(Some(folder_id))
+    }
+
+    /// Make sure the folder at the given absolute path exists and return its id.
+    pub async fn ensure_folder_id(&self, ctx: &OperationContext, path: &str) -> Result<String> {
+        let mut folder_id = BOX_ROOT_FOLDER_ID.to_string();
+        let mut current = String::new();
+
+        for name in path.split('/').filter(|v| !v.is_empty()) {
+            current.push_str(name);
+            current.push('/');
+
+            if let Some(id) = self.folder_ids.lock().await.get(&current) {
+                folder_id.clone_from(id);
+                continue;
+            }
+
+            let id = match self.box_find_child(ctx, &folder_id, name).await? {
+                Some(item) if item.is_folder() => item.id,
+                Some(_) => {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        format!("it's not a directory {current}"),
+                    ));
+                }
+                None => self.box_create_folder(ctx, &folder_id, name).await?,
+            };
+
+            self.folder_ids
+                .lock()
+                .await
+                .insert(current.clone(), id.clone());
+            folder_id = id;
+        }
+
+        Ok(folder_id)
+    }
+
+    /// Drop the cached ids of the given folder and all folders inside it.
+    pub async fn invalidate_folder(&self, path: &str) {
+        let path = format!("{}/", path.trim_end_matches('/'));
+        self.folder_ids
+            .lock()
+            .await
+            .retain(|k, _| !k.starts_with(&path));
+    }
+
+    /// Get the item at the given path.
+    pub async fn box_get_item(&self, ctx: &OperationContext, path: &str) -> Result<BoxItem> {
+        let path = build_abs_path(&self.root, path);
+        if path.is_empty() {
+            return Ok(BoxItem {
+                item_type: "folder".to_string(),
+                id: BOX_ROOT_FOLDER_ID.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let not_found = || Error::new(ErrorKind::NotFound, format!("path not found: {path}"));
+
+        let parent_id = self
+            .resolve_folder_id(ctx, parent_of(&path))
+            .await?
+            .ok_or_else(not_found)?;
+        let name = get_basename(&path).trim_end_matches('/');
+
+        match self.box_find_child(ctx, &parent_id, name).await? {
+            Some(item) if path.ends_with('/') && !item.is_folder() => Err(not_found()),
+            Some(item) => Ok(item),
+            None => Err(not_found()),
+        }
+    }
+
+    pub async fn box_list_folder(
+        &self,
+        ctx: &OperationContext,
+        folder_id: &str,
+        limit: usize,
+        marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let mut url =
+            QueryPairsWriter::new(&format!("{BOX_API_ENDPOINT}/folders/{folder_id}/items"))
+                .push("fields", BOX_ITEM_FIELDS)
+                .push("limit", &limit.to_string())
+                .push("usemarker", "true");
+        if !marker.is_empty() {
+            url = url.push("marker", &percent_encode_path(marker));
+        }
+
+        let req = Request::get(url.finish())
+            .extension(Operation::List)
+            .extension(ServiceOperation("ListFolderItems"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn box_create_folder(
+        &self,
+        ctx: &OperationContext,
+        parent_id: &str,
+        name: &str,
+    ) -> Result<String> {
+        let body = json!({
+            "name": name,
+            "parent": { "id": parent_id },
+        });
+
+        let req = Request::post(format!("{BOX_API_ENDPOINT}/folders"))
+            .header(CONTENT_TYPE, "application/json")
+            .extension(Operation::CreateDir)
+            .extension(ServiceOperation("CreateFolder"))
+            .body(Buffer::from(body.to_string()))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::CREATED => {
+                let item: BoxItem = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                Ok(item.id)
+            }
+            // The folder has been created by others concurrently.
+            StatusCode::CONFLICT => match self.box_find_child(ctx, parent_id, name).await? {
+                Some(item) if item.is_folder() => Ok(item.id),
+                _ => Err(parse_error(resp)),
+            },
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    pub async fn box_download(
+        &self,
+        ctx: &OperationContext,
+        file_id: &str,
+        range: BytesRange,
+    ) -> Result<Response<HttpBody>> {
+        let mut req = Request::get(format!("{BOX_API_ENDPOINT}/files/{file_id}/content"));
+
+        if !range.is_full() {
+            req = req.header(header::RANGE, range.to_header());
+        }
+
+        let mut req = req
+            .extension(Operation::Read)
+            .extension(ServiceOperation("DownloadFile"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(ctx, &mut req).await?;
+        ctx.http_transport().fetch(req).await
+    }
+
+    /// Upload a file in one request.
+    ///
+    /// Upload a new version instead if `file_id` is given.
+    pub async fn box_upload(
+        &self,
+        ctx: &OperationContext,
+        parent_id: &str,
+        file_id: Option<&str>,
+        name: &str,
+        bs: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let (url, attributes) = match file_id {
+            Some(id) => (
+                format!("{BOX_UPLOAD_ENDPOINT}/files/{id}/content"),
+                json!({ "name": name }),
+            ),
+            None => (
+                format!("{BOX_UPLOAD_ENDPOINT}/files/content"),
+                json!({
+                    "name": name,
+                    "parent": { "id": parent_id },
+                }),
+            ),
+        };
+
+        // Box requires the attributes part to be sent before the file part.
+        let multipart = Multipart::new()
+            .part(FormDataPart::new("attributes").content(attributes.to_string()))
+            .part(
+                FormDataPart::new("file")
+                    .header(
+                        header::CONTENT_DISPOSITION,
+                        format!(
+                            "form-data; name=\"file\"; filename=\"{}\"",
+                            percent_encode_path(name)
+                        )
+                        .parse()
+                        .map_err(|_| {
+                            Error::new(ErrorKind::Unexpected, "file name is not a valid header")
+                        })?,
+                    )
+                    .content(bs),
+            );
+
+        let req = Request::post(url)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("UploadFile"));
+        let req = multipart.apply(req)?;
+
+        self.send(ctx, req).await
+    }
+
+    /// Create a chunked upload session for a file of `size` bytes.
+    ///
+    /// Upload a new version instead if `file_id` is given.
+    pub async fn box_create_upload_session(
+        &self,
+        ctx: &OperationContext,
+        parent_id: &str,
+        file_id: Option<&str>,
+        name: &str,
+        size: u64,
+    ) -> Result<BoxUploadSession> {
+        let (url, body) = match file_id {
+            Some(id) => (
+                format!("{BOX_UPLOAD_ENDPOINT}/files/{id}/upload_sessions"),
+                json!({
+                    "file_name": name,
+                    "file_size": size,
+                }),
+            ),
+            None => (
+                format!("{BOX_UPLOAD_ENDPOINT}/files/upload_sessions"),
+                json!({
+                    "folder_id": parent_id,
+                    "file_name": name,
+                    "file_size": size,
+                }),
+            ),
+        };
+
+        let req = Request::post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CreateUploadSession"))
+            .body(Buffer::from(body.to_string()))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Upload one part of the session.
+    ///
+    /// `digest` is the base64 encoded SHA-1 of the part.
+    pub async fn box_upload_part(
+        &self,
+        ctx: &OperationContext,
+        session_id: &str,
+        offset: u64,
+        total: u64,
+        digest: &str,
+        bs: Buffer,
+    ) -> Result<BoxUploadPart> {
+        let end = offset + bs.len() as u64 - 1;
+
+        let req = Request::put(format!(
+            "{BOX_UPLOAD_ENDPOINT}/files/upload_sessions/{session_id}"
+        ))
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(CONTENT_LENGTH, bs.len())
+        .header(
+            header::CONTENT_RANGE,
+            format!("bytes {offset}-{end}/{total}"),
+        )
+        .header("Digest", format!("sha={digest}"))
+        .extension(Operation::Write)
+        .extension(ServiceOperation("UploadPart"))
+        .body(bs)
+        .map_err(new_request_build_error)?;
+
+        let resp = self.send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                let part: BoxUploadPartResponse =
+                    serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+                Ok(part.part)
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Commit the session with all uploaded parts.
+    ///
+    /// `digest` is the base64 encoded SHA-1 of the whole file.
+    pub async fn box_commit_upload_session(
+        &self,
+        ctx: &OperationContext,
+        session_id: &str,
+        parts: &[BoxUploadPart],
+        digest: &str,
+    ) -> Result<BoxItem> {
+        let body =
+            serde_json::to_vec(&json!({ "parts": parts })).map_err(new_json_serialize_error)?;
+        let body = Bytes::from(body);
+
+        for _ in 0..BOX_COMMIT_MAX_RETRIES {
+            let req = Request::post(format!(
+                "{BOX_UPLOAD_ENDPOINT}/files/upload_sessions/{session_id}/commit"
+            ))
+            .header(CONTENT_TYPE, "application/json")
+            .header("Digest", format!("sha={digest}"))
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CommitUploadSession"))
+            .body(Buffer::from(body.clone()))
+            .map_err(new_request_build_error)?;
+
+            let resp = self.send(ctx, req).await?;
+            match resp.status() {
+                StatusCode::CREATED | StatusCode::OK => {
+                    let list: BoxItemList = serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+                    return list.entries.into_iter().next().ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "commit upload session returns no file",
+                        )
+                    });
+                }
+                // The parts are still being processed, retry after the given seconds.
+                StatusCode::ACCEPTED => {
+                    let secs = resp
+                        .headers()
+                        .get(header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .unwrap_or(1);
+                    tokio::time::sleep(Duration::from_secs(secs)).await;
+                }
+                _ => return Err(parse_error(resp)),
+            }
+        }
+
+        Err(Error::new(
+            ErrorKind::Unexpected,
+            "upload session is still being processed after commit",
+        )
+        .set_temporary())
+    }
+
+    pub async fn box_abort_upload_session(
+        &self,
+        ctx: &OperationContext,
+        session_id: &str,
+    ) -> Result<()> {
+        let req = Request::delete(format!(
+            "{BOX_UPLOAD_ENDPOINT}/files/upload_sessions/{session_id}"
+        ))
+        .extension(Operation::Write)
+        .extension(ServiceOperation("AbortUploadSession"))
+        .body(Buffer::new())
+        .map_err(new_request_build_error)?;
+
+        let resp = self.send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK | StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    pub async fn box_delete(
+        &self,
+        ctx: &OperationContext,
+        item: &BoxItem,
+    ) -> Result<Response<Buffer>> {
+        let url = if item.is_folder() {
+            format!("{BOX_API_ENDPOINT}/folders/{}", item.id)
+        } else {
+            format!("{BOX_API_ENDPOINT}/files/{}", item.id)
+        };
+
+        let req = Request::delete(url)
+            .extension(Operation::Delete)
+            .extension(ServiceOperation("DeleteItem"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn box_copy_file(
+        &self,
+        ctx: &OperationContext,
+        file_id: &str,
+        parent_id: &str,
+        name: &str,
+    ) -> Result<Response<Buffer>> {
+        let body = json!({
+            "name": name,
+            "parent": { "id": parent_id },
+        });
+
+        let req = Request::post(format!("{BOX_API_ENDPOINT}/files/{file_id}/copy"))
+            .header(CONTENT_TYPE, "application/json")
+            .extension(Operation::Copy)
+            .extension(ServiceOperation("CopyFile"))
+            .body(Buffer::from(body.to_string()))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    /// Move the item into `parent_id` with the new `name`.
+    pub async fn box_move(
+        &self,
+        ctx: &OperationContext,
+        item: &BoxItem,
+        parent_id: &str,
+        name: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = if item.is_folder() {
+            format!("{BOX_API_ENDPOINT}/folders/{}", item.id)
+        } else {
+            format!("{BOX_API_ENDPOINT}/files/{}", item.id)
+        };
+        let body = json!({
+            "name": name,
+            "parent": { "id": parent_id },
+        });
+
+        let req = Request::put(url)
+            .header(CONTENT_TYPE, "application/json")
+            .extension(Operation::Rename)
+            .extension(ServiceOperation("UpdateItem"))
+            .body(Buffer::from(body.to_string()))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+}
+
+/// Get the parent folder of an absolute path, the root is represented by an
+/// empty string.
+pub fn parent_of(path: &str) -> &str {
+    match get_parent(path) {
+        "/" => "",
+        v => v,
+    }
+}
+
+#[derive(Clone)]
+pub struct BoxSigner {
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+
+    pub access_token: String,
+    pub expires_in: Timestamp,
+}
+
+impl Default for BoxSigner {
+    fn default() -> Self {
+        BoxSigner {
+            refresh_token: String::new(),
+            client_id: String::new(),
+            client_secret: String::new(),
+
+            access_token: String::new(),
+            expires_in: Timestamp::MIN,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+struct BoxTokenResponse {
+    access_token: String,
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+/// The file or folder object returned by Box.
+///
+/// ref: <https://developer.box.com/reference/resources/file/>
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct BoxItem {
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub id: String,
+    pub name: String,
+    pub size: Option<u64>,
+    pub modified_at: Option<String>,
+    pub etag: Option<String>,
+}
+
+impl BoxItem {
+    pub fn is_folder(&self) -> bool {
+        self.item_type == "folder"
+    }
+
+    pub fn parse_metadata(&self) -> Result<Metadata> {
+        let mode = match self.item_type.as_str() {
+            "file" => EntryMode::FILE,
+            "folder" => EntryMode::DIR,
+            _ => EntryMode::Unknown,
+        };
+        let mut meta = Metadata::new(mode);
+
+        // Only files carry meaningful size and etag, folders report the
+        // total size of their content.
+        if mode == EntryMode::FILE {
+            if let Some(size) = self.size {
+                meta.set_content_length(size);
+            }
+            if let Some(etag) = &self.etag {
+                meta.set_etag(etag);
+            }
+        }
+        if let Some(modified_at) = &self.modified_at {
+            meta.set_last_modified(modified_at.parse::<Timestamp>()?);
+        }
+
+        Ok(meta)
+    }
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct BoxItemList {
+    pub entries: Vec<BoxItem>,
+    pub next_marker: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoxUploadSession {
+    pub id: String,
+    pub part_size: usize,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BoxUploadPart {
+    pub part_id: String,
+    pub offset: u64,
+    pub size: u64,
+    pub sha1: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BoxUploadPartResponse {
+    part: BoxUploadPart,
+}
+
+mod error {
+    use http::Response;
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use opendal_core::raw::*;
+    use opendal_core::*;
+
+    #[derive(Default, Debug, Deserialize)]
+    #[serde(default)]
+    struct BoxErrorResponse {
+        code: String,
+        message: String,
+    }
+
+    /// Parse error response into Error.
+    pub(crate) fn parse_error(resp: Response<Buffer>) -> Error {
+        let (parts, body) = resp.into_parts();
+        let bs = body.to_bytes();
+
+        let (kind, retryable) = match parts.status {
+            StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                (ErrorKind::PermissionDenied, false)
+            }
+            StatusCode::CONFLICT => (ErrorKind::AlreadyExists, false),
+            StatusCode::PRECONDITION_FAILED => (ErrorKind::ConditionNotMatch, false),
+            StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let message = match serde_json::from_slice::<BoxErrorResponse>(&bs) {
+            Ok(box_err) => format!("{}: {}", box_err.code, box_err.message),
+            Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+        };
+
+        let mut err = Error::new(kind, message);
+
+        err = with_error_response_context(err, parts);
+
+        if retryable {
+            err = err.set_temporary();
+        }
+
+        err
+    }
+}
+
+pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parent_of() {
+        assert_eq!(parent_of("a"), "");
+        assert_eq!(parent_of("a/"), "");
+        assert_eq!(parent_of("a/b"), "a/");
+        assert_eq!(parent_of("a/b/"), "a/");
+    }
+
+    #[test]
+    fn test_parse_item_metadata() -> Result<()> {
+        let item: BoxItem = serde_json::from_str(
+            r#"{
+                "type": "file",
+                "id": "12345",
+                "name": "test.txt",
+                "size": 629644,
+                "modified_at": "2012-12-12T10:53:43-08:00",
+                "etag": "1"
+            }"#,
+        )
+        .map_err(new_json_deserialize_error)?;
+
+        let meta = item.parse_metadata()?;
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), 629644);
+        assert_eq!(meta.etag(), Some("1"));
+        assert_eq!(
+            meta.last_modified(),
+            Some("2012-12-12T18:53:43Z".parse::<Timestamp>()?)
+        );
+
+        let item: BoxItem = serde_json::from_str(
+            r#"{"type": "folder", "id": "1", "name": "dir", "size": 42, "etag": "0"}"#,
+        )
+        .map_err(new_json_deserialize_error)?;
+
+        let meta = item.parse_metadata()?;
+        assert_eq!(meta.mode(), EntryMode::DIR);
+        assert_eq!(meta.etag(), None);
+
+        Ok(())
+    }
+}
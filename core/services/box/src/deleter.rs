@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use http::StatusCode;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct BoxDeleter {
+    core: Arc<BoxCore>,
+    ctx: OperationContext,
+}
+
+impl BoxDeleter {
+    pub fn new(core: Arc<BoxCore>, ctx: OperationContext) -> Self {
+        Self { core, ctx }
+    }
+}
+
+impl oio::OneShotDelete for BoxDeleter {
+    async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
+        let item = match self.core.box_get_item(&self.ctx, &path).await {
+            Ok(item) => item,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        // The root folder of the account can't be removed.
+        if item.id == BOX_ROOT_FOLDER_ID {
+            return Ok(());
+        }
+
+        let resp = self.core.box_delete(&self.ctx, &item).await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                if item.is_folder() {
+                    self.core
+                        .invalidate_folder(&build_abs_path(&self.core.root, &path))
+                        .await;
+                }
+                Ok(())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct BoxLister {
+    core: Arc<BoxCore>,
+    ctx: OperationContext,
+    path: String,
+    limit: Option<usize>,
+}
+
+impl BoxLister {
+    pub fn new(
+        core: Arc<BoxCore>,
+        ctx: OperationContext,
+        path: String,
+        limit: Option<usize>,
+    ) -> Self {
+        Self {
+            core,
+            ctx,
+            path,
+            limit,
+        }
+    }
+}
+
+impl oio::PageList for BoxLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let abs_path = build_abs_path(&self.core.root, &self.path);
+        let Some(folder_id) = self.core.resolve_folder_id(&self.ctx, &abs_path).await? else {
+            ctx.done = true;
+            return Ok(());
+        };
+
+        let limit = self.limit.unwrap_or(BOX_LIST_LIMIT).min(BOX_LIST_LIMIT);
+        let resp = self
+            .core
+            .box_list_folder(&self.ctx, &folder_id, limit, &ctx.token)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => {
+                ctx.done = true;
+                return Ok(());
+            }
+            _ => return Err(parse_error(resp)),
+        }
+
+        let list: BoxItemList = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+
+        // Include the current directory itself when handling the first page of the listing.
+        if ctx.token.is_empty() {
+            ctx.entries
+                .push_back(oio::Entry::new(&self.path, Metadata::new(EntryMode::DIR)));
+        }
+
+        let parent = if self.path == "/" { "" } else { &self.path };
+        for item in list.entries {
+            let meta = item.parse_metadata()?;
+            let path = match meta.mode() {
+                EntryMode::FILE => format!("{parent}{}", item.name),
+                EntryMode::DIR => {
+                    let path = format!("{parent}{}/", item.name);
+                    // Remember the folder ids we have seen to save lookups later.
+                    self.core
+                        .folder_ids
+                        .lock()
+                        .await
+                        .insert(build_abs_path(&self.core.root, &path), item.id);
+                    path
+                }
+                // Web links and other items have no content to read.
+                _ => continue,
+            };
+
+            ctx.entries.push_back(oio::Entry::with(path, meta));
+        }
+
+        match list.next_marker {
+            Some(marker) if !marker.is_empty() => ctx.token = marker,
+            _ => ctx.done = true,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use bytes::Buf;
+use http::StatusCode;
+use sha1::Digest;
+use sha1::Sha1;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct BoxWriter {
+    core: Arc<BoxCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl BoxWriter {
+    /// Box recommends chunked uploads for files larger than 50 MiB, and
+    /// refuses upload sessions for files smaller than 20 MiB.
+    const CHUNKED_UPLOAD_THRESHOLD: usize = 50 * 1024 * 1024;
+
+    pub fn new(core: Arc<BoxCore>, ctx: OperationContext, _: OpWrite, path: String) -> Self {
+        BoxWriter { core, ctx, path }
+    }
+}
+
+// Box requires upload sessions to specify the total size of the file.
+// While OpenDAL supports multiple writes, due to Box's limitation, we opt to
+// use `OneShotWrite` instead of `MultipartWrite`.
+impl oio::OneShotWrite for BoxWriter {
+    async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        let path = build_abs_path(&self.core.root, &self.path);
+        let name = get_basename(&path);
+        let parent_id = self
+            .core
+            .ensure_folder_id(&self.ctx, parent_of(&path))
+            .await?;
+
+        // Upload a new version if the file exists, Box rejects duplicated names.
+        let file_id = match self
+            .core
+            .box_find_child(&self.ctx, &parent_id, name)
+            .await?
+        {
+            Some(item) if item.is_folder() => {
+                return Err(Error::new(
+                    ErrorKind::IsADirectory,
+                    format!("path is a directory: {path}"),
+                ));
+            }
+            Some(item) => Some(item.id),
+            None => None,
+        };
+
+        let item = if bs.len() <= Self::CHUNKED_UPLOAD_THRESHOLD {
+            self.write_simple(&parent_id, file_id.as_deref(), name, bs)
+                .await?
+        } else {
+            self.write_chunked(&parent_id, file_id.as_deref(), name, bs)
+                .await?
+        };
+
+        item.parse_metadata()
+    }
+}
+
+impl BoxWriter {
+    async fn write_simple(
+        &self,
+        parent_id: &str,
+        file_id: Option<&str>,
+        name: &str,
+        bs: Buffer,
+    ) -> Result<BoxItem> {
+        let resp = self
+            .core
+            .box_upload(&self.ctx, parent_id, file_id, name, bs)
+            .await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let list: BoxItemList = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                list.entries
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| Error::new(ErrorKind::Unexpected, "upload file returns no file"))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn write_chunked(
+        &self,
+        parent_id: &str,
+        file_id: Option<&str>,
+        name: &str,
+        bs: Buffer,
+    ) -> Result<BoxItem> {
+        // Upload large files via sessions: https://developer.box.com/guides/uploads/chunked/
+        // 1. Create an upload session
+        // 2. Upload the bytes of each part with the size decided by Box
+        // 3. Commit the session with the digest of the whole file
+        let total = bs.len() as u64;
+        let session = self
+            .core
+            .box_create_upload_session(&self.ctx, parent_id, file_id, name, total)
+            .await?;
+
+        let result = self.upload_parts(&session, bs).await;
+        if result.is_err() {
+            // Best effort: the session will be expired by Box anyway.
+            let _ = self
+                .core
+                .box_abort_upload_session(&self.ctx, &session.id)
+                .await;
+        }
+        result
+    }
+
+    async fn upload_parts(&self, session: &BoxUploadSession, bs: Buffer) -> Result<BoxItem> {
+        let total = bs.len() as u64;
+        let bs = bs.to_bytes();
+
+        let mut hasher = Sha1::new();
+        let mut parts = Vec::new();
+        for (idx, chunk) in bs.chunks(session.part_size.max(1)).enumerate() {
+            hasher.update(chunk);
+
+            let digest = BASE64_STANDARD.encode(Sha1::digest(chunk));
+            let offset = (idx * session.part_size) as u64;
+            let part = self
+                .core
+                .box_upload_part(
+                    &self.ctx,
+                    &session.id,
+                    offset,
+                    total,
+                    &digest,
+                    Buffer::from(bs.slice_ref(chunk)),
+                )
+                .await?;
+            parts.push(part);
+        }
+
+        let digest = BASE64_STANDARD.encode(hasher.finalize());
+        self.core
+            .box_commit_upload_session(&self.ctx, &session.id, &parts, &digest)
+            .await
+    }
+}
@@ -0,0 +1,253 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use log::debug;
+
+use super::CEPHFS_SCHEME;
+use super::config::CephfsConfig;
+use super::core::CephfsCore;
+use super::core::Mount;
+use super::deleter::CephfsDeleter;
+use super::lister::CephfsLister;
+use super::reader::CephfsReader;
+use super::writer::CephfsWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct CephfsBuilder {
+    pub(super) config: CephfsConfig,
+}
+
+impl CephfsBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the path of the ceph config file, like `/etc/ceph/ceph.conf`.
+    ///
+    /// If neither this nor `mon_host` is set, libcephfs searches its default
+    /// config locations.
+    pub fn config_path(mut self, config_path: &str) -> Self {
+        if !config_path.is_empty() {
+            self.config.config_path = Some(config_path.to_string())
+        }
+        self
+    }
+
+    /// Set the monitor addresses of the cluster, like `10.0.0.1:6789,10.0.0.2:6789`.
+    pub fn mon_host(mut self, mon_host: &str) -> Self {
+        if !mon_host.is_empty() {
+            self.config.mon_host = Some(mon_host.to_string())
+        }
+        self
+    }
+
+    /// Set the client id to authenticate as, like `admin`.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        if !client_id.is_empty() {
+            self.config.client_id = Some(client_id.to_string())
+        }
+        self
+    }
+
+    /// Set the secret key of the client.
+    pub fn key(mut self, key: &str) -> Self {
+        if !key.is_empty() {
+            self.config.key = Some(key.to_string())
+        }
+        self
+    }
+
+    /// Set the name of the filesystem to mount.
+    ///
+    /// The cluster's default filesystem is used if not set.
+    pub fn fs_name(mut self, fs_name: &str) -> Self {
+        if !fs_name.is_empty() {
+            self.config.fs_name = Some(fs_name.to_string())
+        }
+        self
+    }
+}
+
+impl Builder for CephfsBuilder {
+    type Config = CephfsConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        let mut options = vec![];
+        if let Some(mon_host) = &self.config.mon_host {
+            options.push(("mon_host", mon_host.as_str()));
+        }
+        if let Some(key) = &self.config.key {
+            options.push(("key", key.as_str()));
+        }
+
+        let mount = Mount::connect(
+            self.config.client_id.as_deref(),
+            self.config.config_path.as_deref(),
+            &options,
+            self.config.fs_name.as_deref(),
+        )
+        .map_err(|e| e.with_context("service", CEPHFS_SCHEME))?;
+
+        // Create root dir if not exist.
+        mount.mkdirs(&root)?;
+
+        Ok(CephfsBackend {
+            core: Arc::new(CephfsCore {
+                info: ServiceInfo::new(CEPHFS_SCHEME, &root, ""),
+                root,
+                mount: Arc::new(mount),
+            }),
+        })
+    }
+}
+
+/// Backend for cephfs services.
+#[derive(Debug, Clone)]
+pub struct CephfsBackend {
+    core: Arc<CephfsCore>,
+}
+
+impl Service for CephfsBackend {
+    type Reader = oio::PositionReader<CephfsReader>;
+    type Writer = CephfsWriter;
+    type Lister = Option<CephfsLister>;
+    type Deleter = oio::OneShotDeleter<CephfsDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        Capability {
+            stat: true,
+
+            read: true,
+
+            write: true,
+            write_can_empty: true,
+            write_can_append: true,
+            write_can_multi: true,
+
+            create_dir: true,
+            create_dir_persistent: true,
+            delete: true,
+            delete_with_recursive: true,
+
+            list: true,
+
+            rename: true,
+            rename_is_atomic: true,
+
+            shared: true,
+
+            ..Default::default()
+        }
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        path: &str,
+        _: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.core.cephfs_create_dir(path).await?;
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let m = self.core.cephfs_stat(path).await?;
+        Ok(RpStat::new(m))
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::PositionReader::new(CephfsReader::new(
+            self.core.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, path: &str, op: OpWrite) -> Result<Self::Writer> {
+        Ok(CephfsWriter::new(self.core.clone(), path, op))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(CephfsDeleter::new(
+            self.core.clone(),
+        )))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, _: OpList) -> Result<Self::Lister> {
+        Ok(Some(CephfsLister::new(self.core.clone(), path)))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        self.core.cephfs_rename(from, to).await?;
+        Ok(RpRename::new())
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
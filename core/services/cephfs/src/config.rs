@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::CephfsBuilder;
+
+/// [CephFS](https://docs.ceph.com/en/latest/cephfs/) support via `libcephfs`.
+///
+/// Config for Cephfs services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct CephfsConfig {
+    /// work dir of this backend
+    pub root: Option<String>,
+    /// path to the ceph config file of this backend
+    pub config_path: Option<String>,
+    /// monitor addresses of this backend
+    pub mon_host: Option<String>,
+    /// client id of this backend, like `admin`
+    pub client_id: Option<String>,
+    /// secret key of the client
+    pub key: Option<String>,
+    /// name of the filesystem to mount
+    pub fs_name: Option<String>,
+}
+
+impl Debug for CephfsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CephfsConfig")
+            .field("root", &self.root)
+            .field("config_path", &self.config_path)
+            .field("mon_host", &self.mon_host)
+            .field("client_id", &self.client_id)
+            .field("fs_name", &self.fs_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for CephfsConfig {
+    type Builder = CephfsBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(authority) = uri.authority() {
+            map.insert("mon_host".to_string(), authority.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        CephfsBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_mon_host_and_root() {
+        let uri = OperatorUri::new(
+            "cephfs://10.0.0.1:6789/data?fs_name=cephfs",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = CephfsConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.mon_host.as_deref(), Some("10.0.0.1:6789"));
+        assert_eq!(cfg.root.as_deref(), Some("data"));
+        assert_eq!(cfg.fs_name.as_deref(), Some("cephfs"));
+    }
+}
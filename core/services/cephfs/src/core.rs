@@ -0,0 +1,419 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ffi::CString;
+use std::fmt::Debug;
+use std::io;
+use std::ptr;
+use std::sync::Arc;
+
+use libc::c_char;
+use libc::c_int;
+
+use super::ffi;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+const STATX_WANT: libc::c_uint =
+    ffi::CEPH_STATX_MODE | ffi::CEPH_STATX_SIZE | ffi::CEPH_STATX_MTIME;
+
+/// CephfsCore contains code that directly interacts with CephFS.
+#[derive(Clone)]
+pub struct CephfsCore {
+    pub info: ServiceInfo,
+    pub root: String,
+    pub mount: Arc<Mount>,
+}
+
+impl Debug for CephfsCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CephfsCore")
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CephfsCore {
+    /// Run a blocking libcephfs call on the blocking thread pool.
+    async fn call<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Arc<Mount>) -> Result<T> + Send + 'static,
+    {
+        let mount = self.mount.clone();
+        tokio::task::spawn_blocking(move || f(&mount))
+            .await
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "tokio task join failed").set_source(e)
+            })?
+    }
+
+    pub async fn cephfs_create_dir(&self, path: &str) -> Result<()> {
+        let p = build_rooted_abs_path(&self.root, path);
+        self.call(move |m| m.mkdirs(&p)).await
+    }
+
+    pub async fn cephfs_stat(&self, path: &str) -> Result<Metadata> {
+        let p = build_rooted_abs_path(&self.root, path);
+        let stx = self.call(move |m| m.statx(&p)).await?;
+        parse_statx(&stx)
+    }
+
+    pub async fn cephfs_open(&self, path: &str) -> Result<File> {
+        let p = build_rooted_abs_path(&self.root, path);
+        self.call(move |m| m.open(&p, libc::O_RDONLY)).await
+    }
+
+    /// Open `path` for writing, returning the file and the offset to write at.
+    ///
+    /// Missing parents are created. The file is truncated unless `append` is set.
+    pub async fn cephfs_open_write(&self, path: &str, append: bool) -> Result<(File, u64)> {
+        let p = build_rooted_abs_path(&self.root, path);
+        self.call(move |m| {
+            m.mkdirs(get_parent(&p))?;
+
+            let mut flags = libc::O_WRONLY | libc::O_CREAT;
+            if !append {
+                flags |= libc::O_TRUNC;
+            }
+            let f = m.open(&p, flags)?;
+            let offset = if append { m.statx(&p)?.stx_size } else { 0 };
+            Ok((f, offset))
+        })
+        .await
+    }
+
+    pub async fn cephfs_delete(&self, path: &str, recursive: bool) -> Result<()> {
+        let p = build_rooted_abs_path(&self.root, path);
+        self.call(move |m| {
+            let stx = match m.statx(&p) {
+                Ok(stx) => stx,
+                Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+                Err(err) => return Err(err),
+            };
+
+            if is_dir(&stx) {
+                if recursive {
+                    m.remove_dir_all(&p)
+                } else {
+                    m.rmdir(&p)
+                }
+            } else {
+                m.unlink(&p)
+            }
+        })
+        .await
+    }
+
+    pub async fn cephfs_list(&self, path: &str) -> Result<Option<Dir>> {
+        let p = build_rooted_abs_path(&self.root, path);
+        self.call(move |m| match m.opendir(&p) {
+            Ok(dir) => Ok(Some(dir)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        })
+        .await
+    }
+
+    /// CephFS renames are POSIX renames, which replace `to` in one step.
+    pub async fn cephfs_rename(&self, from: &str, to: &str) -> Result<()> {
+        let from = build_rooted_abs_path(&self.root, from);
+        let to = build_rooted_abs_path(&self.root, to);
+        self.call(move |m| {
+            m.statx(&from)?;
+            m.mkdirs(get_parent(&to))?;
+            m.rename(&from, &to)
+        })
+        .await
+    }
+}
+
+/// A mounted libcephfs client.
+pub struct Mount {
+    cmount: *mut ffi::ceph_mount_info,
+}
+
+/// # Safety
+///
+/// A libcephfs mount can be used from multiple threads at the same time.
+unsafe impl Send for Mount {}
+unsafe impl Sync for Mount {}
+
+impl Drop for Mount {
+    fn drop(&mut self) {
+        // Safety: `cmount` is created by `ceph_create` and released only here.
+        unsafe {
+            ffi::ceph_unmount(self.cmount);
+            ffi::ceph_release(self.cmount);
+        }
+    }
+}
+
+impl Mount {
+    /// Create a client from the given config and mount the filesystem at `/`.
+    pub fn connect(
+        client_id: Option<&str>,
+        config_path: Option<&str>,
+        options: &[(&str, &str)],
+        fs_name: Option<&str>,
+    ) -> Result<Self> {
+        let id = client_id.map(to_cstring).transpose()?;
+        let mut cmount = ptr::null_mut();
+        // Safety: `cmount` is a valid out pointer and `id` outlives the call.
+        check(unsafe {
+            ffi::ceph_create(&mut cmount, id.as_ref().map_or(ptr::null(), |v| v.as_ptr()))
+        })?;
+        let mount = Mount { cmount };
+
+        let config_path = config_path.map(to_cstring).transpose()?;
+        if config_path.is_some() || options.iter().all(|(k, _)| *k != "mon_host") {
+            // A null path makes libcephfs search its default locations.
+            check(unsafe {
+                ffi::ceph_conf_read_file(
+                    mount.cmount,
+                    config_path.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+                )
+            })?;
+        }
+        for (k, v) in options {
+            let (k, v) = (to_cstring(k)?, to_cstring(v)?);
+            check(unsafe { ffi::ceph_conf_set(mount.cmount, k.as_ptr(), v.as_ptr()) })?;
+        }
+        if let Some(fs_name) = fs_name {
+            let fs_name = to_cstring(fs_name)?;
+            check(unsafe { ffi::ceph_select_filesystem(mount.cmount, fs_name.as_ptr()) })?;
+        }
+
+        let root = to_cstring("/")?;
+        check(unsafe { ffi::ceph_mount(mount.cmount, root.as_ptr()) })?;
+        Ok(mount)
+    }
+
+    pub fn statx(&self, path: &str) -> Result<ffi::ceph_statx> {
+        let p = to_cstring(path)?;
+        // Safety: `ceph_statx` is plain old data.
+        let mut stx: ffi::ceph_statx = unsafe { std::mem::zeroed() };
+        check(unsafe {
+            ffi::ceph_statx(
+                self.cmount,
+                p.as_ptr(),
+                &mut stx,
+                STATX_WANT,
+                libc::AT_SYMLINK_NOFOLLOW as libc::c_uint,
+            )
+        })?;
+        Ok(stx)
+    }
+
+    pub fn mkdirs(&self, path: &str) -> Result<()> {
+        let p = to_cstring(path)?;
+        match check(unsafe { ffi::ceph_mkdirs(self.cmount, p.as_ptr(), 0o755) }) {
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => Ok(()),
+            res => res.map(|_| ()),
+        }
+    }
+
+    pub fn rmdir(&self, path: &str) -> Result<()> {
+        let p = to_cstring(path)?;
+        check(unsafe { ffi::ceph_rmdir(self.cmount, p.as_ptr()) })?;
+        Ok(())
+    }
+
+    pub fn unlink(&self, path: &str) -> Result<()> {
+        let p = to_cstring(path)?;
+        check(unsafe { ffi::ceph_unlink(self.cmount, p.as_ptr()) })?;
+        Ok(())
+    }
+
+    pub fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let (from, to) = (to_cstring(from)?, to_cstring(to)?);
+        check(unsafe { ffi::ceph_rename(self.cmount, from.as_ptr(), to.as_ptr()) })?;
+        Ok(())
+    }
+
+    pub fn remove_dir_all(self: &Arc<Self>, path: &str) -> Result<()> {
+        let mut dir = self.opendir(path)?;
+        while let Some((name, stx)) = dir.next_entry()? {
+            let child = format!("{}/{name}", path.trim_end_matches('/'));
+            if is_dir(&stx) {
+                self.remove_dir_all(&child)?;
+            } else {
+                self.unlink(&child)?;
+            }
+        }
+        drop(dir);
+        self.rmdir(path)
+    }
+
+    pub fn open(self: &Arc<Self>, path: &str, flags: c_int) -> Result<File> {
+        let p = to_cstring(path)?;
+        let fd = check(unsafe { ffi::ceph_open(self.cmount, p.as_ptr(), flags, 0o644) })?;
+        Ok(File {
+            mount: self.clone(),
+            fd,
+        })
+    }
+
+    pub fn opendir(self: &Arc<Self>, path: &str) -> Result<Dir> {
+        let p = to_cstring(path)?;
+        let mut dirp = ptr::null_mut();
+        check(unsafe { ffi::ceph_opendir(self.cmount, p.as_ptr(), &mut dirp) })?;
+        Ok(Dir {
+            mount: self.clone(),
+            dirp,
+        })
+    }
+}
+
+/// An open file descriptor, closed on drop.
+pub struct File {
+    mount: Arc<Mount>,
+    fd: c_int,
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ceph_close(self.mount.cmount, self.fd);
+        }
+    }
+}
+
+impl File {
+    /// Read into `buf` at `offset`, returning `0` at the end of file.
+    pub fn read_at(&self, buf: &mut [u8], offset: u64) -> Result<usize> {
+        let n = check(unsafe {
+            ffi::ceph_read(
+                self.mount.cmount,
+                self.fd,
+                buf.as_mut_ptr() as *mut c_char,
+                buf.len() as i64,
+                offset as i64,
+            )
+        })?;
+        Ok(n as usize)
+    }
+
+    pub fn write_at(&self, buf: &[u8], offset: u64) -> Result<usize> {
+        let n = check(unsafe {
+            ffi::ceph_write(
+                self.mount.cmount,
+                self.fd,
+                buf.as_ptr() as *const c_char,
+                buf.len() as i64,
+                offset as i64,
+            )
+        })?;
+        Ok(n as usize)
+    }
+
+    pub fn fsync(&self) -> Result<()> {
+        check(unsafe { ffi::ceph_fsync(self.mount.cmount, self.fd, 0) })?;
+        Ok(())
+    }
+}
+
+/// An open directory handle, closed on drop.
+pub struct Dir {
+    mount: Arc<Mount>,
+    dirp: *mut ffi::ceph_dir_result,
+}
+
+/// # Safety
+///
+/// The handle is only used by one thread at a time through `&mut Self`.
+unsafe impl Send for Dir {}
+unsafe impl Sync for Dir {}
+
+impl Drop for Dir {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ceph_closedir(self.mount.cmount, self.dirp);
+        }
+    }
+}
+
+impl Dir {
+    /// Return the next entry's name and stat, skipping `.` and `..`.
+    pub fn next_entry(&mut self) -> Result<Option<(String, ffi::ceph_statx)>> {
+        loop {
+            // Safety: both are plain old data filled in by libcephfs.
+            let mut de: libc::dirent = unsafe { std::mem::zeroed() };
+            let mut stx: ffi::ceph_statx = unsafe { std::mem::zeroed() };
+            let n = check(unsafe {
+                ffi::ceph_readdirplus_r(
+                    self.mount.cmount,
+                    self.dirp,
+                    &mut de,
+                    &mut stx,
+                    STATX_WANT,
+                    libc::AT_SYMLINK_NOFOLLOW as libc::c_uint,
+                    ptr::null_mut(),
+                )
+            })?;
+            if n == 0 {
+                return Ok(None);
+            }
+
+            // Safety: `d_name` is nul terminated.
+            let name = unsafe { std::ffi::CStr::from_ptr(de.d_name.as_ptr()) }
+                .to_string_lossy()
+                .into_owned();
+            if name != "." && name != ".." {
+                return Ok(Some((name, stx)));
+            }
+        }
+    }
+}
+
+pub fn is_dir(stx: &ffi::ceph_statx) -> bool {
+    (stx.stx_mode as libc::mode_t & libc::S_IFMT) == libc::S_IFDIR
+}
+
+pub fn parse_statx(stx: &ffi::ceph_statx) -> Result<Metadata> {
+    let mode = match stx.stx_mode as libc::mode_t & libc::S_IFMT {
+        libc::S_IFDIR => EntryMode::DIR,
+        libc::S_IFREG => EntryMode::FILE,
+        _ => EntryMode::Unknown,
+    };
+
+    let mut m = Metadata::new(mode);
+    if mode == EntryMode::FILE {
+        m.set_content_length(stx.stx_size);
+    }
+    m.set_last_modified(Timestamp::new(
+        stx.stx_mtime.tv_sec,
+        stx.stx_mtime.tv_nsec as i32,
+    )?);
+    Ok(m)
+}
+
+fn check(ret: c_int) -> Result<c_int> {
+    if ret < 0 {
+        Err(new_std_io_error(io::Error::from_raw_os_error(-ret)))
+    } else {
+        Ok(ret)
+    }
+}
+
+fn to_cstring(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|e| {
+        Error::new(ErrorKind::Unexpected, "path contains nul byte")
+            .with_context("input", s)
+            .set_source(e)
+    })
+}
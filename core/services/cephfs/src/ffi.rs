@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Minimal bindings to the parts of `libcephfs` used by this service.
+//!
+//! All functions return a negative errno on failure.
+
+#![allow(non_camel_case_types)]
+
+use libc::c_char;
+use libc::c_int;
+use libc::c_uint;
+use libc::dev_t;
+use libc::dirent;
+use libc::mode_t;
+use libc::timespec;
+
+pub const CEPH_STATX_MODE: c_uint = 0x0000_0001;
+pub const CEPH_STATX_MTIME: c_uint = 0x0000_0040;
+pub const CEPH_STATX_SIZE: c_uint = 0x0000_0200;
+
+#[repr(C)]
+pub struct ceph_mount_info {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct ceph_dir_result {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Inode {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct ceph_statx {
+    pub stx_mask: u32,
+    pub stx_blksize: u32,
+    pub stx_nlink: u32,
+    pub stx_uid: u32,
+    pub stx_gid: u32,
+    pub stx_mode: u16,
+    pub stx_ino: u64,
+    pub stx_size: u64,
+    pub stx_blocks: u64,
+    pub stx_dev: dev_t,
+    pub stx_rdev: dev_t,
+    pub stx_atime: timespec,
+    pub stx_ctime: timespec,
+    pub stx_mtime: timespec,
+    pub stx_btime: timespec,
+    pub stx_version: u64,
+}
+
+#[link(name = "cephfs")]
+unsafe extern "C" {
+    pub fn ceph_create(cmount: *mut *mut ceph_mount_info, id: *const c_char) -> c_int;
+    pub fn ceph_conf_read_file(cmount: *mut ceph_mount_info, path_list: *const c_char) -> c_int;
+    pub fn ceph_conf_set(
+        cmount: *mut ceph_mount_info,
+        option: *const c_char,
+        value: *const c_char,
+    ) -> c_int;
+    pub fn ceph_select_filesystem(cmount: *mut ceph_mount_info, fs_name: *const c_char) -> c_int;
+    pub fn ceph_mount(cmount: *mut ceph_mount_info, root: *const c_char) -> c_int;
+    pub fn ceph_unmount(cmount: *mut ceph_mount_info) -> c_int;
+    pub fn ceph_release(cmount: *mut ceph_mount_info) -> c_int;
+
+    pub fn ceph_statx(
+        cmount: *mut ceph_mount_info,
+        path: *const c_char,
+        stx: *mut ceph_statx,
+        want: c_uint,
+        flags: c_uint,
+    ) -> c_int;
+    pub fn ceph_mkdirs(cmount: *mut ceph_mount_info, path: *const c_char, mode: mode_t) -> c_int;
+    pub fn ceph_rmdir(cmount: *mut ceph_mount_info, path: *const c_char) -> c_int;
+    pub fn ceph_unlink(cmount: *mut ceph_mount_info, path: *const c_char) -> c_int;
+    pub fn ceph_rename(
+        cmount: *mut ceph_mount_info,
+        from: *const c_char,
+        to: *const c_char,
+    ) -> c_int;
+
+    pub fn ceph_open(
+        cmount: *mut ceph_mount_info,
+        path: *const c_char,
+        flags: c_int,
+        mode: mode_t,
+    ) -> c_int;
+    pub fn ceph_close(cmount: *mut ceph_mount_info, fd: c_int) -> c_int;
+    pub fn ceph_read(
+        cmount: *mut ceph_mount_info,
+        fd: c_int,
+        buf: *mut c_char,
+        size: i64,
+        offset: i64,
+    ) -> c_int;
+    pub fn ceph_write(
+        cmount: *mut ceph_mount_info,
+        fd: c_int,
+        buf: *const c_char,
+        size: i64,
+        offset: i64,
+    ) -> c_int;
+    pub fn ceph_fsync(cmount: *mut ceph_mount_info, fd: c_int, syncdataonly: c_int) -> c_int;
+
+    pub fn ceph_opendir(
+        cmount: *mut ceph_mount_info,
+        name: *const c_char,
+        dirpp: *mut *mut ceph_dir_result,
+    ) -> c_int;
+    pub fn ceph_readdirplus_r(
+        cmount: *mut ceph_mount_info,
+        dirp: *mut ceph_dir_result,
+        de: *mut dirent,
+        stx: *mut ceph_statx,
+        want: c_uint,
+        flags: c_uint,
+        out: *mut *mut Inode,
+    ) -> c_int;
+    pub fn ceph_closedir(cmount: *mut ceph_mount_info, dirp: *mut ceph_dir_result) -> c_int;
+}
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::CephfsCore;
+use super::core::is_dir;
+use super::core::parse_statx;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct CephfsLister {
+    core: Arc<CephfsCore>,
+    path: String,
+    dir: Option<super::core::Dir>,
+    started: bool,
+}
+
+impl CephfsLister {
+    pub fn new(core: Arc<CephfsCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            dir: None,
+            started: false,
+        }
+    }
+}
+
+impl oio::List for CephfsLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if !self.started {
+            self.started = true;
+            return match self.core.cephfs_list(&self.path).await? {
+                Some(dir) => {
+                    self.dir = Some(dir);
+                    Ok(Some(oio::Entry::new(
+                        &self.path,
+                        Metadata::new(EntryMode::DIR),
+                    )))
+                }
+                None => Ok(None),
+            };
+        }
+
+        let Some(mut dir) = self.dir.take() else {
+            return Ok(None);
+        };
+        let (dir, de) = tokio::task::spawn_blocking(move || {
+            let de = dir.next_entry();
+            (dir, de)
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "tokio task join failed").set_source(e))?;
+
+        let Some((name, stx)) = de? else {
+            return Ok(None);
+        };
+        self.dir = Some(dir);
+
+        let abs = build_rooted_abs_path(&self.core.root, &self.path);
+        let path = build_rel_path(&self.core.root, &format!("{abs}{name}"));
+
+        let entry = if is_dir(&stx) {
+            // Make sure we are returning the correct path.
+            oio::Entry::new(&format!("{path}/"), Metadata::new(EntryMode::DIR))
+        } else {
+            oio::Entry::new(&path, parse_statx(&stx)?)
+        };
+
+        Ok(Some(entry))
+    }
+}
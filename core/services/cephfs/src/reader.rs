@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::CephfsCore;
+use super::core::File;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct CephfsReader {
+    core: Arc<CephfsCore>,
+    path: String,
+}
+
+impl CephfsReader {
+    pub(super) fn new(core: Arc<CephfsCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::PositionRead for CephfsReader {
+    type Handle = Arc<File>;
+
+    async fn open(&self) -> Result<Self::Handle> {
+        let f = self.core.cephfs_open(&self.path).await?;
+        Ok(Arc::new(f))
+    }
+
+    async fn read_at(handle: &Self::Handle, offset: u64, size: usize) -> Result<Buffer> {
+        if size == 0 {
+            return Ok(Buffer::new());
+        }
+
+        let f = handle.clone();
+        let buf = tokio::task::spawn_blocking(move || {
+            let mut buf = vec![0; size];
+            let mut read = 0;
+            while read < size {
+                let n = f.read_at(&mut buf[read..], offset + read as u64)?;
+                if n == 0 {
+                    break;
+                }
+                read += n;
+            }
+            buf.truncate(read);
+            Ok::<_, Error>(Buffer::from(buf))
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "tokio task join failed").set_source(e))??;
+
+        Ok(buf)
+    }
+}
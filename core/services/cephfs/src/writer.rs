@@ -0,0 +1,110 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::CephfsCore;
+use super::core::File;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Writer that opens the file on first use and writes at a tracked offset.
+pub struct CephfsWriter {
+    core: Arc<CephfsCore>,
+    path: String,
+    op: OpWrite,
+    file: Option<Arc<File>>,
+    offset: u64,
+    written: u64,
+}
+
+impl CephfsWriter {
+    pub(super) fn new(core: Arc<CephfsCore>, path: &str, op: OpWrite) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            op,
+            file: None,
+            offset: 0,
+            written: 0,
+        }
+    }
+
+    async fn file(&mut self) -> Result<Arc<File>> {
+        if self.file.is_none() {
+            let (f, offset) = self
+                .core
+                .cephfs_open_write(&self.path, self.op.append())
+                .await?;
+            self.file = Some(Arc::new(f));
+            self.offset = offset;
+        }
+
+        Ok(self.file.clone().expect("file must be initialized"))
+    }
+}
+
+impl oio::Write for CephfsWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let f = self.file().await?;
+        let offset = self.offset;
+        let len = bs.len() as u64;
+
+        tokio::task::spawn_blocking(move || {
+            let mut pos = offset;
+            for chunk in bs {
+                let mut chunk = &chunk[..];
+                while !chunk.is_empty() {
+                    let n = f.write_at(chunk, pos)?;
+                    chunk = &chunk[n..];
+                    pos += n as u64;
+                }
+            }
+            Ok::<_, Error>(())
+        })
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "tokio task join failed").set_source(e))??;
+
+        self.offset += len;
+        self.written += len;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let f = self.file().await?;
+        tokio::task::spawn_blocking(move || f.fsync())
+            .await
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "tokio task join failed").set_source(e)
+            })??;
+        self.file = None;
+
+        let size = if self.op.append() {
+            self.offset
+        } else {
+            self.written
+        };
+        Ok(Metadata::default().with_content_length(size))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "CephfsWriter doesn't support abort",
+        ))
+    }
+}
@@ -0,0 +1,324 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use super::CLICKHOUSE_SCHEME;
+use super::config::ClickhouseConfig;
+use super::core::*;
+use super::deleter::ClickhouseDeleter;
+use super::lister::ClickhouseLister;
+use super::reader::ClickhouseReader;
+use super::writer::ClickhouseWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct ClickhouseBuilder {
+    pub(super) config: ClickhouseConfig,
+}
+
+impl Debug for ClickhouseBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClickhouseBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClickhouseBuilder {
+    /// Set the endpoint of the ClickHouse HTTP interface.
+    ///
+    /// default: "http://127.0.0.1:8123"
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Set the username of ClickHouse.
+    pub fn username(mut self, username: &str) -> Self {
+        if !username.is_empty() {
+            self.config.username = Some(username.to_string());
+        }
+        self
+    }
+
+    /// Set the password of ClickHouse.
+    pub fn password(mut self, password: &str) -> Self {
+        if !password.is_empty() {
+            self.config.password = Some(password.to_string());
+        }
+        self
+    }
+
+    /// Set the database of the table.
+    ///
+    /// default: "default"
+    pub fn database(mut self, database: &str) -> Self {
+        if !database.is_empty() {
+            self.config.database = Some(database.to_string());
+        }
+        self
+    }
+
+    /// Set the table to read/write.
+    ///
+    /// If not specified, it will return an error when building.
+    pub fn table(mut self, table: &str) -> Self {
+        if !table.is_empty() {
+            self.config.table = Some(table.to_string());
+        }
+        self
+    }
+
+    /// Set the key field name of the table.
+    ///
+    /// default: "key"
+    pub fn key_field(mut self, key_field: &str) -> Self {
+        if !key_field.is_empty() {
+            self.config.key_field = Some(key_field.to_string());
+        }
+        self
+    }
+
+    /// Set the value field name of the table.
+    ///
+    /// default: "value"
+    pub fn value_field(mut self, value_field: &str) -> Self {
+        if !value_field.is_empty() {
+            self.config.value_field = Some(value_field.to_string());
+        }
+        self
+    }
+
+    /// Set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for ClickhouseBuilder {
+    type Config = ClickhouseConfig;
+
+    fn build(self) -> Result<impl Service> {
+        let config = self.config;
+
+        let Some(table) = config.table else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "table is required")
+                .with_context("service", CLICKHOUSE_SCHEME));
+        };
+
+        let endpoint = config
+            .endpoint
+            .map(|v| v.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| "http://127.0.0.1:8123".to_string());
+
+        let authorization = match config.username {
+            Some(username) => Some(format_authorization_by_basic(
+                &username,
+                config.password.as_deref().unwrap_or_default(),
+            )?),
+            None => None,
+        };
+
+        let root = normalize_root(config.root.as_deref().unwrap_or("/"));
+
+        Ok(ClickhouseBackend::new(ClickhouseCore {
+            endpoint,
+            authorization,
+            database: config.database.unwrap_or_else(|| "default".to_string()),
+            table,
+            key_field: config.key_field.unwrap_or_else(|| "key".to_string()),
+            value_field: config.value_field.unwrap_or_else(|| "value".to_string()),
+        })
+        .with_normalized_root(root))
+    }
+}
+
+/// Backend for ClickHouse service.
+#[derive(Clone, Debug)]
+pub struct ClickhouseBackend {
+    pub(crate) core: Arc<ClickhouseCore>,
+    pub(crate) root: String,
+    pub(crate) info: ServiceInfo,
+    pub(crate) capability: Capability,
+}
+
+impl ClickhouseBackend {
+    pub fn new(core: ClickhouseCore) -> Self {
+        let info = ServiceInfo::new(CLICKHOUSE_SCHEME, "/", &core.table);
+        let capability = Capability {
+            read: true,
+            stat: true,
+            write: true,
+            write_can_empty: true,
+            delete: true,
+            list: true,
+            list_with_limit: true,
+            list_with_recursive: true,
+            shared: true,
+            ..Default::default()
+        };
+
+        Self {
+            core: Arc::new(core),
+            root: "/".to_string(),
+            info,
+            capability,
+        }
+    }
+
+    fn with_normalized_root(mut self, root: String) -> Self {
+        self.info = self.info.with_root(&root);
+        self.root = root;
+        self
+    }
+}
+
+impl Service for ClickhouseBackend {
+    type Reader = oio::StreamReader<ClickhouseReader>;
+    type Writer = ClickhouseWriter;
+    type Lister = oio::HierarchyLister<oio::PageLister<ClickhouseLister>>;
+    type Deleter = oio::OneShotDeleter<ClickhouseDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let p = build_abs_path(&self.root, path);
+
+        if p == build_abs_path(&self.root, "") {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+
+        match self.core.stat(ctx, &p).await? {
+            Some(size) => Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(size),
+            )),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                "key not found in clickhouse",
+            )),
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        let p = build_abs_path(&self.root, path);
+        Ok(oio::StreamReader::new(ClickhouseReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            p,
+        )))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
+        let p = build_abs_path(&self.root, path);
+        Ok(ClickhouseWriter::new(self.core.clone(), ctx.clone(), p))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(ClickhouseDeleter::new(
+            self.core.clone(),
+            ctx.clone(),
+            self.root.clone(),
+        )))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let l = ClickhouseLister::new(
+            self.core.clone(),
+            ctx.clone(),
+            self.root.clone(),
+            path,
+            args.limit(),
+        );
+        Ok(oio::HierarchyLister::new(
+            oio::PageLister::new(l),
+            path,
+            args.recursive(),
+        ))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
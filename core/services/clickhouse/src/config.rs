@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::ClickhouseBuilder;
+
+/// Config for [ClickHouse](https://clickhouse.com/) service support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ClickhouseConfig {
+    /// The endpoint of the ClickHouse HTTP interface.
+    ///
+    /// default is `http://127.0.0.1:8123`
+    pub endpoint: Option<String>,
+    /// The username to connect to ClickHouse.
+    pub username: Option<String>,
+    /// The password to connect to ClickHouse.
+    pub password: Option<String>,
+    /// The database of the table.
+    ///
+    /// default is `default`
+    pub database: Option<String>,
+    /// The table to store data.
+    pub table: Option<String>,
+    /// The key field name of the table.
+    ///
+    /// default is `key`
+    pub key_field: Option<String>,
+    /// The value field name of the table.
+    ///
+    /// default is `value`
+    pub value_field: Option<String>,
+    /// The working directory of OpenDAL.
+    pub root: Option<String>,
+}
+
+impl Debug for ClickhouseConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClickhouseConfig")
+            .field("endpoint", &self.endpoint)
+            .field("username", &self.username)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("key_field", &self.key_field)
+            .field("value_field", &self.value_field)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for ClickhouseConfig {
+    type Builder = ClickhouseBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(authority) = uri.authority() {
+            map.entry("endpoint".to_string())
+                .or_insert_with(|| format!("http://{authority}"));
+        }
+
+        // The path is `<database>/<table>/<root>`.
+        if let Some(path) = uri.root() {
+            let mut segments = path.splitn(3, '/');
+            for key in ["database", "table", "root"] {
+                match segments.next() {
+                    Some(v) if !v.is_empty() => {
+                        map.entry(key.to_string()).or_insert_with(|| v.to_string());
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        ClickhouseBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_database_table_and_root() {
+        let uri = OperatorUri::new(
+            "clickhouse://ch.example.com:8123/analytics/files/staging/daily",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = ClickhouseConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("http://ch.example.com:8123"));
+        assert_eq!(cfg.database.as_deref(), Some("analytics"));
+        assert_eq!(cfg.table.as_deref(), Some("files"));
+        assert_eq!(cfg.root.as_deref(), Some("staging/daily"));
+    }
+}
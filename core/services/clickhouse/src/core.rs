@@ -0,0 +1,358 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+#[derive(Clone)]
+pub struct ClickhouseCore {
+    pub endpoint: String,
+    pub authorization: Option<String>,
+
+    pub database: String,
+    pub table: String,
+    pub key_field: String,
+    pub value_field: String,
+}
+
+impl Debug for ClickhouseCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClickhouseCore")
+            .field("endpoint", &self.endpoint)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("key_field", &self.key_field)
+            .field("value_field", &self.value_field)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClickhouseCore {
+    fn table_ident(&self) -> String {
+        format!(
+            "{}.{}",
+            quote_identifier(&self.database),
+            quote_identifier(&self.table)
+        )
+    }
+
+    /// Build the url of a query, every `(name, value)` in `params` will be
+    /// bound to `{name:String}`.
+    fn query_url(&self, sql: &str, params: &[(&str, &str)]) -> String {
+        let mut writer = QueryPairsWriter::new(&format!("{}/", self.endpoint))
+            .push("database", &percent_encode_path(&self.database))
+            .push("query", &percent_encode_path(sql));
+        for (name, value) in params {
+            writer = writer.push(
+                &format!("param_{name}"),
+                &percent_encode_path(&escape_param(value)),
+            );
+        }
+        writer.finish()
+    }
+
+    fn query_request(
+        &self,
+        sql: &str,
+        params: &[(&str, &str)],
+        body: Buffer,
+        op: Operation,
+        service_operation: &'static str,
+    ) -> Result<Request<Buffer>> {
+        let mut req = Request::post(self.query_url(sql, params));
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req.header(header::CONTENT_LENGTH, body.len())
+            .extension(op)
+            .extension(ServiceOperation(service_operation))
+            .body(body)
+            .map_err(new_request_build_error)
+    }
+
+    /// Get the length of the value, returns `None` if the key doesn't exist.
+    pub async fn stat(&self, ctx: &OperationContext, path: &str) -> Result<Option<u64>> {
+        let sql = format!(
+            "SELECT length({}) FROM {} FINAL WHERE {} = {{path:String}} LIMIT 1 FORMAT TabSeparated",
+            quote_identifier(&self.value_field),
+            self.table_ident(),
+            quote_identifier(&self.key_field),
+        );
+        let req = self.query_request(
+            &sql,
+            &[("path", path)],
+            Buffer::new(),
+            Operation::Stat,
+            "Stat",
+        )?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let bs = resp.into_body().to_bytes();
+        let content = String::from_utf8_lossy(&bs);
+        let content = content.trim();
+        if content.is_empty() {
+            return Ok(None);
+        }
+        content.parse::<u64>().map(Some).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "failed to parse value length")
+                .with_context("content", content)
+                .set_source(err)
+        })
+    }
+
+    /// Read the value in given range, the range must be valid for current value.
+    pub async fn read(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        range: Option<(u64, u64)>,
+    ) -> Result<Response<HttpBody>> {
+        let value_field = quote_identifier(&self.value_field);
+        // `substring` in ClickHouse starts from 1.
+        let value = match range {
+            Some((offset, size)) => format!("substring({value_field}, {}, {size})", offset + 1),
+            None => value_field,
+        };
+        let sql = format!(
+            "SELECT {value} FROM {} FINAL WHERE {} = {{path:String}} LIMIT 1 FORMAT RawBLOB",
+            self.table_ident(),
+            quote_identifier(&self.key_field),
+        );
+        let req = self.query_request(
+            &sql,
+            &[("path", path)],
+            Buffer::new(),
+            Operation::Read,
+            "Read",
+        )?;
+
+        ctx.http_transport().fetch(req).await
+    }
+
+    /// Insert the value of key, the table is expected to use `ReplacingMergeTree`
+    /// so that the last inserted value wins.
+    pub async fn insert(&self, ctx: &OperationContext, path: &str, value: Buffer) -> Result<()> {
+        let sql = format!(
+            "INSERT INTO {} ({}, {}) SELECT {{path:String}}, value FROM input('value String') FORMAT RawBLOB",
+            self.table_ident(),
+            quote_identifier(&self.key_field),
+            quote_identifier(&self.value_field),
+        );
+        let req = self.query_request(&sql, &[("path", path)], value, Operation::Write, "Insert")?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// List keys starting with `prefix` and greater than `start_after` in
+    /// order, returns the key and the length of its value.
+    pub async fn list(
+        &self,
+        ctx: &OperationContext,
+        prefix: &str,
+        start_after: &str,
+        limit: usize,
+    ) -> Result<Vec<(String, u64)>> {
+        let key_field = quote_identifier(&self.key_field);
+        let sql = format!(
+            "SELECT {key_field}, length({}) FROM {} FINAL WHERE startsWith({key_field}, {{prefix:String}}) AND {key_field} > {{start_after:String}} ORDER BY {key_field} LIMIT {limit} FORMAT TabSeparated",
+            quote_identifier(&self.value_field),
+            self.table_ident(),
+        );
+        let req = self.query_request(
+            &sql,
+            &[("prefix", prefix), ("start_after", start_after)],
+            Buffer::new(),
+            Operation::List,
+            "List",
+        )?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let bs = resp.into_body().to_bytes();
+        let content = String::from_utf8_lossy(&bs);
+        content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_list_row)
+            .collect()
+    }
+
+    pub async fn delete(&self, ctx: &OperationContext, path: &str) -> Result<()> {
+        let sql = format!(
+            "DELETE FROM {} WHERE {} = {{path:String}}",
+            self.table_ident(),
+            quote_identifier(&self.key_field),
+        );
+        let req = self.query_request(
+            &sql,
+            &[("path", path)],
+            Buffer::new(),
+            Operation::Delete,
+            "Delete",
+        )?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+/// Quote identifier with backticks.
+fn quote_identifier(s: &str) -> String {
+    format!("`{}`", s.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+/// Query parameters are parsed in the escaped format of TabSeparated.
+fn escape_param(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+/// Reverse the escaping of TabSeparated output.
+fn unescape_tsv(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('0') => out.push('\0'),
+            Some('b') => out.push('\u{8}'),
+            Some('f') => out.push('\u{c}'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Parse a `key\tlength` row of the list query.
+fn parse_list_row(line: &str) -> Result<(String, u64)> {
+    let (key, size) = line.rsplit_once('\t').ok_or_else(|| {
+        Error::new(ErrorKind::Unexpected, "failed to parse list row").with_context("row", line)
+    })?;
+    let size = size.parse::<u64>().map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "failed to parse value length")
+            .with_context("row", line)
+            .set_source(err)
+    })?;
+    Ok((unescape_tsv(key), size))
+}
+
+/// Parse error response into Error.
+pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
+    let (parts, body) = resp.into_parts();
+    let bs = body.to_bytes();
+
+    let code = parts
+        .headers
+        .get("x-clickhouse-exception-code")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u32>().ok());
+
+    let (kind, retryable) = match (parts.status, code) {
+        // AUTHENTICATION_FAILED, ACCESS_DENIED and REQUIRED_PASSWORD
+        (_, Some(516 | 497 | 194)) => (ErrorKind::PermissionDenied, false),
+        // TIMEOUT_EXCEEDED, TOO_MANY_SIMULTANEOUS_QUERIES, SOCKET_TIMEOUT,
+        // NETWORK_ERROR and MEMORY_LIMIT_EXCEEDED
+        (_, Some(159 | 202 | 209 | 210 | 241)) => (ErrorKind::Unexpected, true),
+        (StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN, _) => {
+            (ErrorKind::PermissionDenied, false)
+        }
+        (
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT,
+            _,
+        ) => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let mut err = Error::new(kind, String::from_utf8_lossy(&bs));
+    err = with_error_response_context(err, parts);
+    if retryable {
+        err = err.set_temporary();
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_url() {
+        let core = ClickhouseCore {
+            endpoint: "http://127.0.0.1:8123".to_string(),
+            authorization: None,
+            database: "default".to_string(),
+            table: "files".to_string(),
+            key_field: "key".to_string(),
+            value_field: "value".to_string(),
+        };
+
+        let url = core.query_url("SELECT 1", &[("path", "dir/a\tb.csv")]);
+        assert_eq!(
+            url,
+            "http://127.0.0.1:8123/?database=default&query=SELECT%201&param_path=dir/a%5Ctb.csv"
+        );
+    }
+
+    #[test]
+    fn test_parse_list_row() {
+        assert_eq!(
+            parse_list_row("dir/a\\tb.csv\t42").unwrap(),
+            ("dir/a\tb.csv".to_string(), 42)
+        );
+        assert_eq!(
+            parse_list_row("dir/a\\\\b\t0").unwrap(),
+            ("dir/a\\b".to_string(), 0)
+        );
+        assert!(parse_list_row("dir/a").is_err());
+    }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("value"), "`value`");
+        assert_eq!(quote_identifier("a`b"), "`a\\`b`");
+    }
+}
@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use http::Response;
+use http::StatusCode;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct ClickhouseReader {
+    core: Arc<ClickhouseCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl ClickhouseReader {
+    pub(super) fn new(core: Arc<ClickhouseCore>, ctx: OperationContext, path: String) -> Self {
+        Self { core, ctx, path }
+    }
+}
+
+impl oio::StreamRead for ClickhouseReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        // Resolve the value length first so that we can validate the range
+        // and return the full metadata.
+        let Some(size) = self.core.stat(&self.ctx, &self.path).await? else {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "key not found in clickhouse",
+            ));
+        };
+
+        let content_range = range.to_content_range(size as usize)?;
+        let metadata = Metadata::new(EntryMode::FILE).with_content_length(size);
+        if content_range.is_empty() {
+            return Ok((
+                RpRead::new(metadata),
+                Box::new(Buffer::new()) as Box<dyn oio::ReadStreamDyn>,
+            ));
+        }
+
+        let query_range = (!range.is_full()).then(|| {
+            (
+                content_range.start as u64,
+                (content_range.end - content_range.start) as u64,
+            )
+        });
+        let resp = self.core.read(&self.ctx, &self.path, query_range).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok((
+                RpRead::new(metadata),
+                Box::new(resp.into_body()) as Box<dyn oio::ReadStreamDyn>,
+            )),
+            _ => {
+                let (part, mut body) = resp.into_parts();
+                let buf = body.to_buffer().await?;
+                Err(parse_error(Response::from_parts(part, buf)))
+            }
+        }
+    }
+}
@@ -147,6 +147,7 @@ impl Builder for CloudflareKvBuilder {
                 info: ServiceInfo::new(CLOUDFLARE_KV_SCHEME, &root, ""),
                 capability: Capability {
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     stat: true,
                     stat_with_if_match: true,
@@ -163,6 +164,7 @@ impl Builder for CloudflareKvBuilder {
                     write: true,
                     write_can_empty: true,
                     write_total_max_size: Some(25 * 1024 * 1024),
+                    write_with_ttl: true,
 
                     list: true,
                     list_with_limit: true,
@@ -239,7 +241,7 @@ impl Service for CloudflareKvBackend {
 
             // Set the directory entry
             self.core
-                .set(ctx, &current_path, Buffer::new(), cf_kv_metadata)
+                .set(ctx, &current_path, Buffer::new(), cf_kv_metadata, None)
                 .await?;
         }
 
@@ -380,10 +382,10 @@ impl Service for CloudflareKvBackend {
         Ok(output)
     }
 
-    fn write(&self, ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: oio::OneShotWriter<CloudflareWriter> = {
             let path = build_abs_path(&self.core.info.root(), path);
-            let writer = CloudflareWriter::new(self.core.clone(), ctx.clone(), path);
+            let writer = CloudflareWriter::new(self.core.clone(), ctx.clone(), path, args);
 
             let w = oio::OneShotWriter::new(writer);
 
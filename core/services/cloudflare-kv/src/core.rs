@@ -96,6 +96,7 @@ impl CloudflareKvCore {
         path: &str,
         value: Buffer,
         metadata: CfKvMetadata,
+        ttl: Option<Duration>,
     ) -> Result<Response<Buffer>> {
         let url = format!("{}/values/{}", self.url_prefix(), percent_encode_path(path));
 
@@ -112,7 +113,9 @@ impl CloudflareKvCore {
                     .content(serde_json::to_string(&metadata).map_err(new_json_serialize_error)?),
             );
 
-        if let Some(expiration_ttl) = self.expiration_ttl {
+        // Cloudflare KV rejects ttl shorter than 60 seconds, round it up.
+        let ttl = ttl.map(|v| v.max(Duration::from_secs(60)));
+        if let Some(expiration_ttl) = ttl.or(self.expiration_ttl) {
             multipart = multipart.part(
                 FormDataPart::new("expiration_ttl").content(expiration_ttl.as_secs().to_string()),
             );
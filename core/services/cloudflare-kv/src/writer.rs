@@ -29,11 +29,22 @@ pub struct CloudflareWriter {
     core: Arc<CloudflareKvCore>,
     ctx: OperationContext,
     path: String,
+    op: OpWrite,
 }
 
 impl CloudflareWriter {
-    pub fn new(core: Arc<CloudflareKvCore>, ctx: OperationContext, path: String) -> Self {
-        CloudflareWriter { core, ctx, path }
+    pub fn new(
+        core: Arc<CloudflareKvCore>,
+        ctx: OperationContext,
+        path: String,
+        op: OpWrite,
+    ) -> Self {
+        CloudflareWriter {
+            core,
+            ctx,
+            path,
+            op,
+        }
     }
 }
 
@@ -48,7 +59,13 @@ impl oio::OneShotWrite for CloudflareWriter {
 
         let resp = self
             .core
-            .set(&self.ctx, &self.path, bs, cf_kv_metadata.clone())
+            .set(
+                &self.ctx,
+                &self.path,
+                bs,
+                cf_kv_metadata.clone(),
+                self.op.ttl(),
+            )
             .await?;
 
         let status = resp.status();
@@ -89,12 +89,14 @@ impl Builder for CompfsBuilder {
                 write_can_empty: true,
                 write_can_multi: true,
                 create_dir: true,
+                create_dir_persistent: true,
                 delete: true,
 
                 list: true,
 
                 copy: true,
                 rename: true,
+                rename_is_atomic: true,
 
                 shared: true,
 
@@ -151,6 +151,28 @@ impl CosBuilder {
         self
     }
 
+    /// Set default storage class for this backend.
+    ///
+    /// Available values:
+    /// - `STANDARD`
+    /// - `STANDARD_IA`
+    /// - `ARCHIVE`
+    /// - `DEEP_ARCHIVE`
+    /// - `INTELLIGENT_TIERING`
+    /// - `MAZ_STANDARD`
+    /// - `MAZ_STANDARD_IA`
+    /// - `MAZ_INTELLIGENT_TIERING`
+    ///
+    /// Objects in `ARCHIVE` and `DEEP_ARCHIVE` must be restored before they
+    /// can be read.
+    pub fn default_storage_class(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.default_storage_class = Some(v.to_string())
+        }
+
+        self
+    }
+
     /// Disable config load so that opendal will not load config from
     /// environment.
     ///
@@ -201,6 +223,13 @@ impl Builder for CosBuilder {
         let endpoint = uri.host().unwrap().replace(&format!("//{bucket}."), "//");
         debug!("backend use endpoint {}", &endpoint);
 
+        let default_storage_class = match &self.config.default_storage_class {
+            None => None,
+            Some(v) => Some(
+                build_header_value(v).map_err(|err| err.with_context("key", "storage_class"))?,
+            ),
+        };
+
         let os_env = OsEnv;
         let ctx = Context::new()
             .with_file_read(TokioFileRead)
@@ -235,6 +264,8 @@ impl Builder for CosBuilder {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
             stat_with_version: true,
 
             read: true,
@@ -283,6 +314,8 @@ impl Builder for CosBuilder {
             presign_read: true,
             presign_write: true,
 
+            restore: true,
+
             shared: true,
 
             ..Default::default()
@@ -295,6 +328,7 @@ impl Builder for CosBuilder {
                 bucket: bucket.clone(),
                 root,
                 endpoint: format!("{}://{}.{}", &scheme, &bucket, &endpoint),
+                default_storage_class,
                 signer,
             }),
         })
@@ -355,6 +389,14 @@ impl Service for CosBackend {
                     }
                 }
 
+                // COS omits the header for objects in `STANDARD`.
+                let storage_class = parse_header_to_str(headers, constants::X_COS_STORAGE_CLASS)?;
+                meta.set_storage_class(storage_class.unwrap_or("STANDARD"));
+
+                if let Some(v) = parse_restore_status(headers, constants::X_COS_RESTORE)? {
+                    meta.set_restore_status(v);
+                }
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -380,11 +422,10 @@ impl Service for CosBackend {
             let w = if args.append() {
                 CosWriters::Two(oio::AppendWriter::new(writer))
             } else {
-                CosWriters::One(oio::MultipartWriter::new(
-                    ctx.executor().clone(),
-                    writer,
-                    args.concurrent(),
-                ))
+                CosWriters::One(
+                    oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent())
+                        .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                )
             };
 
             Ok(w)
@@ -466,6 +507,22 @@ impl Service for CosBackend {
         ))
     }
 
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let resp = self.core.cos_restore_object(ctx, path, &args).await?;
+
+        match resp.status() {
+            // `202 Accepted` starts a new restore, `200 OK` extends the days
+            // of an object that has been restored.
+            StatusCode::OK | StatusCode::ACCEPTED => Ok(RpRestore::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(
         &self,
         ctx: &OperationContext,
@@ -61,6 +61,18 @@ pub struct CosConfig {
     pub enable_versioning: bool,
     /// Disable config load so that opendal will not load config from
     pub disable_config_load: bool,
+    /// Default storage class for this backend.
+    ///
+    /// Available values:
+    /// - `STANDARD`
+    /// - `STANDARD_IA`
+    /// - `ARCHIVE`
+    /// - `DEEP_ARCHIVE`
+    /// - `INTELLIGENT_TIERING`
+    /// - `MAZ_STANDARD`
+    /// - `MAZ_STANDARD_IA`
+    /// - `MAZ_INTELLIGENT_TIERING`
+    pub default_storage_class: Option<String>,
 }
 
 impl Debug for CosConfig {
@@ -74,6 +86,7 @@ impl Debug for CosConfig {
                 &self.security_token.as_ref().map(|_| "<redacted>"),
             )
             .field("disable_config_load", &self.disable_config_load)
+            .field("default_storage_class", &self.default_storage_class)
             .finish_non_exhaustive()
     }
 }
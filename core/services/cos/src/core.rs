@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 
 use bytes::Bytes;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
 use http::header::CACHE_CONTROL;
@@ -43,6 +44,8 @@ pub mod constants {
     pub const COS_QUERY_VERSION_ID: &str = "versionId";
 
     pub const X_COS_VERSION_ID: &str = "x-cos-version-id";
+    pub const X_COS_STORAGE_CLASS: &str = "x-cos-storage-class";
+    pub const X_COS_RESTORE: &str = "x-cos-restore";
 }
 
 pub struct CosCore {
@@ -51,6 +54,7 @@ pub struct CosCore {
     pub bucket: String,
     pub root: String,
     pub endpoint: String,
+    pub default_storage_class: Option<HeaderValue>,
 
     pub signer: Signer<Credential>,
 }
@@ -217,6 +221,11 @@ impl CosCore {
             req = req.header("x-cos-forbid-overwrite", "true")
         }
 
+        // Set storage class header
+        if let Some(v) = &self.default_storage_class {
+            req = req.header(constants::X_COS_STORAGE_CLASS, v);
+        }
+
         // Set user metadata headers.
         if let Some(user_metadata) = args.user_metadata() {
             for (key, value) in user_metadata {
@@ -272,6 +281,14 @@ impl CosCore {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(IF_MODIFIED_SINCE, if_modified_since.format_http_date());
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
+        }
+
         let req = req
             .extension(Operation::Stat)
             .extension(ServiceOperation("HeadObject"));
@@ -347,6 +364,18 @@ impl CosCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        // Set storage class header
+        if let Some(v) = &self.default_storage_class {
+            req = req.header(constants::X_COS_STORAGE_CLASS, v);
+        }
+
+        // Set user metadata headers.
+        if let Some(user_metadata) = args.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("x-cos-meta-{key}"), value)
+            }
+        }
+
         let req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("AppendObject"));
@@ -388,6 +417,46 @@ impl CosCore {
         self.send(ctx, req).await
     }
 
+    /// Restore an archived object for the given days.
+    ///
+    /// ref: <https://www.tencentcloud.com/document/product/436/12633>
+    pub async fn cos_restore_object(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::post(&url);
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            // Days is required by COS.
+            days: args.days().unwrap_or(1),
+            cas_job_parameters: args.tier().map(|tier| CasJobParameters {
+                tier: tier.to_string(),
+            }),
+        })
+        .map_err(new_xml_serialize_error)?;
+        let req = req.header(CONTENT_LENGTH, content.len());
+        let req = req.header(CONTENT_TYPE, "application/xml");
+        let req = req.header("Content-MD5", format_content_md5(content.as_bytes()));
+
+        let req = req
+            .extension(Operation::Restore)
+            .extension(ServiceOperation("PostObjectRestore"));
+
+        let req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        let req = self.sign(ctx, req).await?;
+
+        self.send(ctx, req).await
+    }
+
     pub async fn cos_list_objects(
         &self,
         ctx: &OperationContext,
@@ -448,6 +517,11 @@ impl CosCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        // Set storage class header
+        if let Some(v) = &self.default_storage_class {
+            req = req.header(constants::X_COS_STORAGE_CLASS, v);
+        }
+
         // Set user metadata headers.
         if let Some(user_metadata) = args.user_metadata() {
             for (key, value) in user_metadata {
@@ -603,6 +677,21 @@ impl CosCore {
     }
 }
 
+/// Request of PostObjectRestore
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(rename = "CASJobParameters", skip_serializing_if = "Option::is_none")]
+    pub cas_job_parameters: Option<CasJobParameters>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CasJobParameters {
+    pub tier: String,
+}
+
 /// Result of CreateMultipartUpload
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -690,6 +779,7 @@ pub struct ListObjectsOutputContent {
     #[serde(rename = "ETag")]
     pub etag: Option<String>,
     pub size: u64,
+    pub storage_class: Option<String>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
@@ -720,6 +810,7 @@ pub struct ListObjectVersionsOutputVersion {
     pub last_modified: String,
     #[serde(rename = "ETag")]
     pub etag: Option<String>,
+    pub storage_class: Option<String>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
@@ -810,6 +901,13 @@ mod tests {
                 Some("\"a72e382246ac83e86bd203389849e71d\"".to_string()),
             ],
         );
+        assert_eq!(
+            out.contents
+                .iter()
+                .map(|v| v.storage_class.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("STANDARD"), Some("STANDARD")],
+        );
         assert_eq!(
             out.common_prefixes
                 .iter()
@@ -818,6 +916,29 @@ mod tests {
             ["hello", "world"],
         )
     }
+
+    #[test]
+    fn test_serialize_restore_request() {
+        let req = RestoreRequest {
+            days: 7,
+            cas_job_parameters: Some(CasJobParameters {
+                tier: "Expedited".to_string(),
+            }),
+        };
+        assert_eq!(
+            quick_xml::se::to_string(&req).expect("must success"),
+            "<RestoreRequest><Days>7</Days><CASJobParameters><Tier>Expedited</Tier></CASJobParameters></RestoreRequest>"
+        );
+
+        let req = RestoreRequest {
+            days: 1,
+            cas_job_parameters: None,
+        };
+        assert_eq!(
+            quick_xml::se::to_string(&req).expect("must success"),
+            "<RestoreRequest><Days>1</Days></RestoreRequest>"
+        );
+    }
 }
 
 mod error {
@@ -112,6 +112,9 @@ impl oio::PageList for CosLister {
                 meta.set_etag(&etag);
                 meta.set_content_md5(etag.trim_matches('"'));
             }
+            if let Some(storage_class) = object.storage_class {
+                meta = meta.with_storage_class(storage_class);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
@@ -229,6 +232,9 @@ impl oio::PageList for CosObjectVersionsLister {
                 meta.set_etag(&etag);
                 meta.set_content_md5(etag.trim_matches('"'));
             }
+            if let Some(storage_class) = version_object.storage_class {
+                meta = meta.with_storage_class(storage_class);
+            }
 
             let entry = oio::Entry::new(&path, meta);
             ctx.entries.push_back(entry);
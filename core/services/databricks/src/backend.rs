@@ -0,0 +1,344 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use http::StatusCode;
+use log::debug;
+use mea::mutex::Mutex;
+
+use super::DATABRICKS_SCHEME;
+use super::config::DatabricksConfig;
+use super::core::DatabricksCore;
+use super::core::DatabricksSigner;
+use super::core::parse_error;
+use super::deleter::DatabricksDeleter;
+use super::lister::DatabricksLister;
+use super::reader::DatabricksReader;
+use super::writer::DatabricksWriter;
+use super::writer::DatabricksWriters;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// [Databricks](https://docs.databricks.com/api/workspace/files) Files API support,
+/// which serves the files in Unity Catalog Volumes.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct DatabricksBuilder {
+    pub(super) config: DatabricksConfig,
+}
+
+impl Debug for DatabricksBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabricksBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DatabricksBuilder {
+    /// Set root of this backend, like `/Volumes/catalog/schema/volume`.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set endpoint of this backend.
+    ///
+    /// Endpoint must be full uri, e.g.
+    ///
+    /// - Azure: `https://adb-1234567890123456.78.azuredatabricks.net`
+    /// - Aws: `https://dbc-123a5678-90bc.cloud.databricks.com`
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.trim_end_matches('/').to_string())
+        };
+        self
+    }
+
+    /// Set the personal access token of this backend.
+    pub fn token(mut self, token: &str) -> Self {
+        if !token.is_empty() {
+            self.config.token = Some(token.to_string());
+        }
+        self
+    }
+
+    /// Set the client id of the service principal for OAuth M2M authentication.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        if !client_id.is_empty() {
+            self.config.client_id = Some(client_id.to_string());
+        }
+        self
+    }
+
+    /// Set the client secret of the service principal for OAuth M2M authentication.
+    pub fn client_secret(mut self, client_secret: &str) -> Self {
+        if !client_secret.is_empty() {
+            self.config.client_secret = Some(client_secret.to_string());
+        }
+        self
+    }
+}
+
+impl Builder for DatabricksBuilder {
+    type Config = DatabricksConfig;
+
+    /// Build a DatabricksBackend.
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        let Some(endpoint) = self.config.endpoint else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")
+                .with_operation("Builder::build")
+                .with_context("service", DATABRICKS_SCHEME));
+        };
+        debug!("backend use endpoint: {}", &endpoint);
+
+        let mut signer = DatabricksSigner::default();
+        match (
+            &self.config.token,
+            self.config.client_id,
+            self.config.client_secret,
+        ) {
+            (Some(_), _, _) => {}
+            (None, Some(client_id), Some(client_secret)) => {
+                signer.client_id = client_id;
+                signer.client_secret = client_secret;
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "token or client_id and client_secret is required",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", DATABRICKS_SCHEME));
+            }
+        }
+
+        Ok(DatabricksBackend {
+            core: Arc::new(DatabricksCore {
+                info: ServiceInfo::new(DATABRICKS_SCHEME, &root, ""),
+                capability: Capability {
+                    stat: true,
+
+                    read: true,
+
+                    write: true,
+                    write_can_empty: true,
+                    write_can_multi: true,
+                    // Parts are uploaded to the cloud storage with the same
+                    // limits as S3 multipart upload.
+                    write_multi_min_size: Some(5 * 1024 * 1024),
+                    write_multi_max_size: if cfg!(target_pointer_width = "64") {
+                        Some(5 * 1024 * 1024 * 1024)
+                    } else {
+                        Some(usize::MAX)
+                    },
+
+                    create_dir: true,
+                    create_dir_persistent: true,
+
+                    delete: true,
+
+                    list: true,
+                    list_with_limit: true,
+
+                    shared: true,
+
+                    ..Default::default()
+                },
+                root,
+                endpoint,
+                token: self.config.token,
+                signer: Arc::new(Mutex::new(signer)),
+            }),
+        })
+    }
+}
+
+/// Backend for Databricks service
+#[derive(Debug, Clone)]
+pub struct DatabricksBackend {
+    core: Arc<DatabricksCore>,
+}
+
+impl Service for DatabricksBackend {
+    type Reader = oio::StreamReader<DatabricksReader>;
+    type Writer = DatabricksWriters;
+    type Lister = oio::PageLister<DatabricksLister>;
+    type Deleter = oio::OneShotDeleter<DatabricksDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        _: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        let resp = self.core.databricks_create_directory(ctx, path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                Ok(RpCreateDir::default())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+
+        let resp = if path.ends_with('/') {
+            self.core
+                .databricks_get_directory_metadata(ctx, path)
+                .await?
+        } else {
+            self.core.databricks_get_file_metadata(ctx, path).await?
+        };
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(DatabricksReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let output: DatabricksWriters = {
+            let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
+            let writer = DatabricksWriter::new(self.core.clone(), ctx.clone(), path.to_string());
+
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent)
+                .with_adaptive_concurrency(adaptive);
+
+            Ok(w)
+        }?;
+
+        Ok(output)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(DatabricksDeleter::new(
+            self.core.clone(),
+            ctx.clone(),
+        )))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let l = DatabricksLister::new(self.core.clone(), ctx.clone(), path, args.limit());
+
+        Ok(oio::PageLister::new(l))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_requires_credential() {
+        let err = DatabricksBuilder::default()
+            .endpoint("https://dbc-123a5678-90bc.cloud.databricks.com")
+            .client_id("client_id")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        DatabricksBuilder::default()
+            .endpoint("https://dbc-123a5678-90bc.cloud.databricks.com")
+            .client_id("client_id")
+            .client_secret("client_secret")
+            .build()
+            .unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::DatabricksBuilder;
+
+/// Config for [Databricks Files API](https://docs.databricks.com/api/workspace/files) services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DatabricksConfig {
+    /// The root of this backend, like `/Volumes/catalog/schema/volume`.
+    ///
+    /// All operations will happen under this root.
+    pub root: Option<String>,
+    /// The endpoint of the databricks workspace.
+    pub endpoint: Option<String>,
+    /// The personal access token of databricks.
+    pub token: Option<String>,
+    /// The client id of the service principal, used by OAuth M2M authentication.
+    pub client_id: Option<String>,
+    /// The client secret of the service principal, used by OAuth M2M authentication.
+    pub client_secret: Option<String>,
+}
+
+impl Debug for DatabricksConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabricksConfig")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for DatabricksConfig {
+    type Builder = DatabricksBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(authority) = uri.authority() {
+            map.insert("endpoint".to_string(), format!("https://{authority}"));
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        DatabricksBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_and_root() {
+        let uri = OperatorUri::new(
+            "databricks://dbc-123a5678-90bc.cloud.databricks.com/Volumes/main/default/data",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = DatabricksConfig::from_uri(&uri).unwrap();
+        assert_eq!(
+            cfg.endpoint.as_deref(),
+            Some("https://dbc-123a5678-90bc.cloud.databricks.com")
+        );
+        assert_eq!(cfg.root.as_deref(), Some("Volumes/main/default/data"));
+    }
+}
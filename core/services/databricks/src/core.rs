@@ -0,0 +1,590 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Buf;
+use bytes::Bytes;
+use http::Request;
+use http::Response;
+use http::header;
+use mea::mutex::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The lifetime of presigned urls used by multipart upload.
+const UPLOAD_URL_EXPIRATION: Duration = Duration::from_secs(3600);
+
+pub struct DatabricksCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    /// The root of this core, like `/Volumes/catalog/schema/volume/`.
+    pub root: String,
+    /// The endpoint of the databricks workspace.
+    pub endpoint: String,
+    /// The personal access token, takes precedence over OAuth.
+    pub token: Option<String>,
+    pub signer: Arc<Mutex<DatabricksSigner>>,
+}
+
+impl Debug for DatabricksCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabricksCore")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DatabricksCore {
+    pub async fn sign<T>(&self, ctx: &OperationContext, req: &mut Request<T>) -> Result<()> {
+        if let Some(token) = &self.token {
+            let value = format_authorization_by_bearer(token)?;
+            req.headers_mut().insert(
+                header::AUTHORIZATION,
+                value.parse().map_err(|err| {
+                    Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "token is not a valid header value",
+                    )
+                    .set_source(err)
+                })?,
+            );
+            return Ok(());
+        }
+
+        let mut signer = self.signer.lock().await;
+
+        // Refresh the access token if it's missing or expired.
+        if signer.access_token.is_empty() || signer.expires_in <= Timestamp::now() {
+            let url = format!("{}/oidc/v1/token", self.endpoint);
+            let bs = Bytes::from_static(b"grant_type=client_credentials&scope=all-apis");
+
+            let request = Request::post(&url)
+                .header(
+                    header::AUTHORIZATION,
+                    format_authorization_by_basic(&signer.client_id, &signer.client_secret)?,
+                )
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .header(header::CONTENT_LENGTH, bs.len())
+                .body(Buffer::from(bs))
+                .map_err(new_request_build_error)?;
+
+            let resp = ctx.http_transport().send(request).await?;
+            if !resp.status().is_success() {
+                return Err(parse_error(resp));
+            }
+
+            let token: DatabricksTokenResponse = serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+
+            signer.access_token = token.access_token;
+            // Refresh it 2 minutes earlier.
+            signer.expires_in =
+                Timestamp::now() + Duration::from_secs(token.expires_in) - Duration::from_secs(120);
+        }
+
+        let value = format_authorization_by_bearer(&signer.access_token)?;
+        req.headers_mut().insert(
+            header::AUTHORIZATION,
+            value.parse().expect("token must be valid header value"),
+        );
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        ctx: &OperationContext,
+        mut req: Request<Buffer>,
+    ) -> Result<Response<Buffer>> {
+        self.sign(ctx, &mut req).await?;
+        ctx.http_transport().send(req).await
+    }
+
+    fn files_url(&self, path: &str) -> String {
+        let p = build_rooted_abs_path(&self.root, path);
+        format!(
+            "{}/api/2.0/fs/files{}",
+            self.endpoint,
+            percent_encode_path(&p)
+        )
+    }
+
+    fn directories_url(&self, path: &str) -> String {
+        let p = build_rooted_abs_path(&self.root, path);
+        format!(
+            "{}/api/2.0/fs/directories{}",
+            self.endpoint,
+            percent_encode_path(p.trim_end_matches('/'))
+        )
+    }
+
+    pub async fn databricks_download(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<HttpBody>> {
+        let mut req = Request::get(self.files_url(path));
+        if !range.is_full() {
+            req = req.header(header::RANGE, range.to_header());
+        }
+
+        let mut req = req
+            .extension(Operation::Read)
+            .extension(ServiceOperation("Download"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(ctx, &mut req).await?;
+        ctx.http_transport().fetch(req).await
+    }
+
+    pub async fn databricks_get_file_metadata(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::head(self.files_url(path))
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetMetadata"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_get_directory_metadata(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::head(self.directories_url(path))
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetDirectoryMetadata"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_create_directory(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::put(self.directories_url(path))
+            .extension(Operation::CreateDir)
+            .extension(ServiceOperation("CreateDirectory"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?overwrite=true", self.files_url(path));
+
+        let req = Request::put(url)
+            .header(header::CONTENT_TYPE, "application/octet-stream")
+            .header(header::CONTENT_LENGTH, size)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("Upload"))
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_delete_file(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::delete(self.files_url(path))
+            .extension(Operation::Delete)
+            .extension(ServiceOperation("Delete"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_delete_directory(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::delete(self.directories_url(path))
+            .extension(Operation::Delete)
+            .extension(ServiceOperation("DeleteDirectory"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_list_directory(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        page_token: &str,
+        limit: Option<usize>,
+    ) -> Result<Response<Buffer>> {
+        let mut url = QueryPairsWriter::new(&self.directories_url(path));
+        if !page_token.is_empty() {
+            url = url.push("page_token", &percent_encode_path(page_token));
+        }
+        if let Some(limit) = limit {
+            url = url.push("page_size", &limit.to_string());
+        }
+
+        let req = Request::get(url.finish())
+            .extension(Operation::List)
+            .extension(ServiceOperation("ListDirectoryContents"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_initiate_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?action=initiate-upload", self.files_url(path));
+
+        let req = Request::post(url)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("InitiateUpload"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_create_upload_part_url(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        session_token: &str,
+        part_number: usize,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}/api/2.0/fs/create-upload-part-urls", self.endpoint);
+        let body = json!({
+            "path": build_rooted_abs_path(&self.root, path),
+            "session_token": session_token,
+            "start_part_number": part_number,
+            "count": 1,
+            "expire_time": (Timestamp::now() + UPLOAD_URL_EXPIRATION).to_string(),
+        });
+
+        let req = Request::post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CreateUploadPartUrls"))
+            .body(Buffer::from(Bytes::from(body.to_string())))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    /// Send data to the presigned url, which is served by the cloud storage
+    /// and must not carry the databricks credentials.
+    pub async fn databricks_send_presigned(
+        &self,
+        ctx: &OperationContext,
+        method: http::Method,
+        url: &PresignedUrl,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let mut req = Request::builder()
+            .method(method)
+            .uri(&url.url)
+            .header(header::CONTENT_LENGTH, size);
+        for h in &url.headers {
+            req = req.header(&h.name, &h.value);
+        }
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("SendPresigned"))
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn databricks_complete_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        session_token: &str,
+        parts: Vec<CompletePart>,
+    ) -> Result<Response<Buffer>> {
+        let url = QueryPairsWriter::new(&self.files_url(path))
+            .push("action", "complete-upload")
+            .push("upload_type", "multipart")
+            .push("session_token", &percent_encode_path(session_token))
+            .finish();
+        let body = serde_json::to_vec(&CompleteUploadRequest { parts })
+            .map_err(new_json_serialize_error)?;
+
+        let req = Request::post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CompleteUpload"))
+            .body(Buffer::from(Bytes::from(body)))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
+    pub async fn databricks_create_abort_upload_url(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        session_token: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}/api/2.0/fs/create-abort-upload-url", self.endpoint);
+        let body = json!({
+            "path": build_rooted_abs_path(&self.root, path),
+            "session_token": session_token,
+            "expire_time": (Timestamp::now() + UPLOAD_URL_EXPIRATION).to_string(),
+        });
+
+        let req = Request::post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CreateAbortUploadUrl"))
+            .body(Buffer::from(Bytes::from(body.to_string())))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+}
+
+pub struct DatabricksSigner {
+    pub client_id: String,
+    pub client_secret: String,
+
+    pub access_token: String,
+    pub expires_in: Timestamp,
+}
+
+impl Default for DatabricksSigner {
+    fn default() -> Self {
+        DatabricksSigner {
+            client_id: String::new(),
+            client_secret: String::new(),
+
+            access_token: String::new(),
+            expires_in: Timestamp::MIN,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DatabricksTokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Deserialize)]
+pub struct InitiateUploadResponse {
+    /// Only returned by workspaces on AWS and GCP.
+    pub multipart_upload: Option<MultipartUpload>,
+}
+
+#[derive(Deserialize)]
+pub struct MultipartUpload {
+    pub session_token: String,
+}
+
+#[derive(Deserialize)]
+pub struct CreateUploadPartUrlsResponse {
+    pub upload_part_urls: Vec<PresignedUrl>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateAbortUploadUrlResponse {
+    pub abort_upload_url: PresignedUrl,
+}
+
+#[derive(Deserialize)]
+pub struct PresignedUrl {
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<PresignedHeader>,
+}
+
+#[derive(Deserialize)]
+pub struct PresignedHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize)]
+struct CompleteUploadRequest {
+    parts: Vec<CompletePart>,
+}
+
+#[derive(Serialize)]
+pub struct CompletePart {
+    pub part_number: usize,
+    pub etag: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct ListDirectoryResponse {
+    pub contents: Vec<DirectoryEntry>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DirectoryEntry {
+    pub path: String,
+    pub is_directory: bool,
+    pub file_size: Option<u64>,
+    pub last_modified: Option<i64>,
+}
+
+mod error {
+    use std::fmt::Debug;
+
+    use http::Response;
+    use http::StatusCode;
+    use serde::Deserialize;
+
+    use opendal_core::raw::*;
+    use opendal_core::*;
+
+    /// DatabricksError is the error returned by databricks service.
+    #[derive(Default, Deserialize)]
+    struct DatabricksError {
+        error_code: String,
+        message: String,
+    }
+
+    impl Debug for DatabricksError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("DatabricksError")
+                .field("error_code", &self.error_code)
+                // replace `\n` to ` ` for better reading.
+                .field("message", &self.message.replace('\n', " "))
+                .finish()
+        }
+    }
+
+    pub(crate) fn parse_error(resp: Response<Buffer>) -> Error {
+        let (parts, body) = resp.into_parts();
+        let bs = body.to_bytes();
+
+        let (kind, retryable) = match parts.status {
+            StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                (ErrorKind::PermissionDenied, false)
+            }
+            StatusCode::CONFLICT => (ErrorKind::AlreadyExists, false),
+            StatusCode::PRECONDITION_FAILED => (ErrorKind::ConditionNotMatch, false),
+            StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let message = match serde_json::from_slice::<DatabricksError>(&bs) {
+            Ok(err) => format!("{err:?}"),
+            Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+        };
+
+        let mut err = Error::new(kind, message);
+
+        err = with_error_response_context(err, parts);
+
+        if retryable {
+            err = err.set_temporary();
+        }
+
+        err
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use http::Response;
+
+        use super::*;
+
+        #[test]
+        fn test_parse_error() {
+            let resp = Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Buffer::from(
+                    r#"{"error_code":"NOT_FOUND","message":"The file being accessed is not found."}"#,
+                ))
+                .unwrap();
+
+            let err = parse_error(resp);
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+            assert!(err.to_string().contains("NOT_FOUND"));
+        }
+    }
+}
+
+pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_list_directory_response() {
+        let bs = r#"{
+            "contents": [
+                {"path": "/Volumes/main/default/data/dir", "is_directory": true, "name": "dir"},
+                {"path": "/Volumes/main/default/data/a.txt", "is_directory": false, "file_size": 42, "last_modified": 1700000000000, "name": "a.txt"}
+            ],
+            "next_page_token": "token"
+        }"#;
+
+        let resp: ListDirectoryResponse = serde_json::from_str(bs).unwrap();
+        assert_eq!(resp.contents.len(), 2);
+        assert!(resp.contents[0].is_directory);
+        assert_eq!(resp.contents[1].file_size, Some(42));
+        assert_eq!(resp.next_page_token.as_deref(), Some("token"));
+
+        let resp: ListDirectoryResponse = serde_json::from_str("{}").unwrap();
+        assert!(resp.contents.is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+
+use super::core::DatabricksCore;
+use super::core::ListDirectoryResponse;
+use super::core::parse_error;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct DatabricksLister {
+    core: Arc<DatabricksCore>,
+    ctx: OperationContext,
+    path: String,
+    limit: Option<usize>,
+}
+
+impl DatabricksLister {
+    pub fn new(
+        core: Arc<DatabricksCore>,
+        ctx: OperationContext,
+        path: &str,
+        limit: Option<usize>,
+    ) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+            limit,
+        }
+    }
+}
+
+impl oio::PageList for DatabricksLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let resp = self
+            .core
+            .databricks_list_directory(&self.ctx, &self.path, &ctx.token, self.limit)
+            .await?;
+
+        let status = resp.status();
+        if status != StatusCode::OK {
+            if status == StatusCode::NOT_FOUND {
+                ctx.done = true;
+                return Ok(());
+            }
+            return Err(parse_error(resp));
+        }
+
+        let output: ListDirectoryResponse = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+
+        match output.next_page_token {
+            Some(token) if !token.is_empty() => ctx.token = token,
+            _ => ctx.done = true,
+        }
+
+        for entry in output.contents {
+            let (path, mut meta) = if entry.is_directory {
+                (format!("{}/", entry.path), Metadata::new(EntryMode::DIR))
+            } else {
+                let mut meta = Metadata::new(EntryMode::FILE);
+                if let Some(size) = entry.file_size {
+                    meta.set_content_length(size);
+                }
+                (entry.path, meta)
+            };
+            if let Some(ms) = entry.last_modified {
+                meta.set_last_modified(Timestamp::from_millisecond(ms)?);
+            }
+
+            let path = build_rel_path(&self.core.root, &path);
+            ctx.entries.push_back(oio::Entry::new(&path, meta));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::Method;
+use http::StatusCode;
+
+use super::core::CompletePart;
+use super::core::CreateAbortUploadUrlResponse;
+use super::core::CreateUploadPartUrlsResponse;
+use super::core::DatabricksCore;
+use super::core::InitiateUploadResponse;
+use super::core::parse_error;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub type DatabricksWriters = oio::MultipartWriter<DatabricksWriter>;
+
+pub struct DatabricksWriter {
+    core: Arc<DatabricksCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl DatabricksWriter {
+    pub fn new(core: Arc<DatabricksCore>, ctx: OperationContext, path: String) -> Self {
+        DatabricksWriter { core, ctx, path }
+    }
+}
+
+impl oio::MultipartWrite for DatabricksWriter {
+    async fn write_once(&self, size: u64, body: Buffer) -> Result<Metadata> {
+        let resp = self
+            .core
+            .databricks_upload(&self.ctx, &self.path, size, body)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                Ok(Metadata::default())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        let resp = self
+            .core
+            .databricks_initiate_upload(&self.ctx, &self.path)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let resp: InitiateUploadResponse =
+                    serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+
+                match resp.multipart_upload {
+                    Some(upload) => Ok(upload.session_token),
+                    None => Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "multipart upload is not available in this workspace",
+                    )),
+                }
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<oio::MultipartPart> {
+        // Databricks requires part number starts from 1.
+        let part_number = part_number + 1;
+
+        let resp = self
+            .core
+            .databricks_create_upload_part_url(&self.ctx, &self.path, upload_id, part_number)
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let output: CreateUploadPartUrlsResponse =
+            serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+        let Some(url) = output.upload_part_urls.into_iter().next() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "databricks returned no upload part url",
+            ));
+        };
+
+        let resp = self
+            .core
+            .databricks_send_presigned(&self.ctx, Method::PUT, &url, size, body)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                Ok(oio::MultipartPart {
+                    part_number,
+                    etag,
+                    checksum: None,
+                    size: None,
+                })
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartPart],
+    ) -> Result<Metadata> {
+        let parts = parts
+            .iter()
+            .map(|p| CompletePart {
+                part_number: p.part_number,
+                etag: p.etag.clone(),
+            })
+            .collect();
+
+        let resp = self
+            .core
+            .databricks_complete_upload(&self.ctx, &self.path, upload_id, parts)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(Metadata::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        let resp = self
+            .core
+            .databricks_create_abort_upload_url(&self.ctx, &self.path, upload_id)
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let output: CreateAbortUploadUrlResponse =
+            serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+
+        let resp = self
+            .core
+            .databricks_send_presigned(
+                &self.ctx,
+                Method::DELETE,
+                &output.abort_upload_url,
+                0,
+                Buffer::new(),
+            )
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
@@ -110,6 +110,7 @@ impl Builder for DbfsBuilder {
 
             write: true,
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
             rename: true,
 
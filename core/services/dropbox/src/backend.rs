@@ -168,8 +168,10 @@ impl Builder for DropboxBuilder {
                     write: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     delete: true,
+                    delete_max_size: Some(DROPBOX_BATCH_MAX_SIZE),
 
                     list: true,
                     list_with_recursive: true,
@@ -198,7 +200,7 @@ impl Service for DropboxBackend {
     type Reader = oio::StreamReader<DropboxReader>;
     type Writer = oio::OneShotWriter<DropboxWriter>;
     type Lister = oio::PageLister<DropboxLister>;
-    type Deleter = oio::OneShotDeleter<DropboxDeleter>;
+    type Deleter = oio::BatchDeleter<DropboxDeleter>;
     type Copier = oio::OneShotCopier;
 
     fn info(&self) -> ServiceInfo {
@@ -300,11 +302,11 @@ impl Service for DropboxBackend {
     }
 
     fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
-        let output: oio::OneShotDeleter<DropboxDeleter> = {
-            Ok(oio::OneShotDeleter::new(DropboxDeleter::new(
-                self.core.clone(),
-                ctx.clone(),
-            )))
+        let output: oio::BatchDeleter<DropboxDeleter> = {
+            Ok(oio::BatchDeleter::new(
+                DropboxDeleter::new(self.core.clone(), ctx.clone()),
+                self.core.capability.delete_max_size,
+            ))
         }?;
 
         Ok(output)
@@ -361,19 +363,23 @@ impl Service for DropboxBackend {
         to: &str,
         _: OpRename,
     ) -> Result<RpRename> {
-        let resp = self.core.dropbox_move(ctx, from, to).await?;
-
-        let status = resp.status();
-
-        match status {
-            StatusCode::OK => Ok(RpRename::default()),
-            _ => {
-                let err = parse_error(resp);
-                match err.kind() {
-                    ErrorKind::NotFound => Ok(RpRename::default()),
-                    _ => Err(err),
-                }
-            }
+        // `move_v2` refuses to move folders with too many files, while `move_batch`
+        // moves them in an async job.
+        let entries = self
+            .core
+            .dropbox_move_batch(ctx, &[(from.to_string(), to.to_string())])
+            .await?;
+        let entry = entries.into_iter().next().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "invalid batch response, no entry returned",
+            )
+        })?;
+
+        match entry.into_result() {
+            Ok(()) => Ok(RpRename::default()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(RpRename::default()),
+            Err(err) => Err(err),
         }
     }
 
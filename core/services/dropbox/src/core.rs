@@ -33,6 +33,12 @@ use serde::Serialize;
 use opendal_core::raw::*;
 use opendal_core::*;
 
+/// The max number of entries accepted by `delete_batch`.
+pub const DROPBOX_BATCH_MAX_SIZE: usize = 1000;
+
+const MAX_CHECK_JOB_ATTEMPT: usize = 3600;
+const CHECK_JOB_WAIT_SECOND: u64 = 1;
+
 pub struct DropboxCore {
     pub info: ServiceInfo,
     pub capability: Capability,
@@ -198,6 +204,149 @@ impl DropboxCore {
         ctx.http_transport().send(request).await
     }
 
+    /// Delete multiple paths in one call, polling the async job until it completes.
+    pub async fn dropbox_delete_batch(
+        &self,
+        ctx: &OperationContext,
+        paths: &[String],
+    ) -> Result<Vec<DropboxBatchResultEntry>> {
+        let args = DropboxDeleteBatchArgs {
+            entries: paths
+                .iter()
+                .map(|path| DropboxDeleteArgs {
+                    path: self.build_path(path),
+                })
+                .collect(),
+        };
+
+        self.dropbox_batch(
+            ctx,
+            "https://api.dropboxapi.com/2/files/delete_batch",
+            "https://api.dropboxapi.com/2/files/delete_batch/check",
+            &args,
+            Operation::Delete,
+            "DeleteBatch",
+        )
+        .await
+    }
+
+    /// Move multiple paths in one call, polling the async job until it completes.
+    pub async fn dropbox_move_batch(
+        &self,
+        ctx: &OperationContext,
+        paths: &[(String, String)],
+    ) -> Result<Vec<DropboxBatchResultEntry>> {
+        let args = DropboxMoveBatchArgs {
+            entries: paths
+                .iter()
+                .map(|(from, to)| DropboxMoveArgs {
+                    from_path: self.build_path(from),
+                    to_path: self.build_path(to),
+                })
+                .collect(),
+            autorename: false,
+        };
+
+        self.dropbox_batch(
+            ctx,
+            "https://api.dropboxapi.com/2/files/move_batch_v2",
+            "https://api.dropboxapi.com/2/files/move_batch/check_v2",
+            &args,
+            Operation::Rename,
+            "MoveBatch",
+        )
+        .await
+    }
+
+    async fn dropbox_batch<T: Serialize>(
+        &self,
+        ctx: &OperationContext,
+        url: &str,
+        check_url: &str,
+        args: &T,
+        op: Operation,
+        service_op: &'static str,
+    ) -> Result<Vec<DropboxBatchResultEntry>> {
+        let mut resp = self
+            .dropbox_post_batch(ctx, url, args, op, service_op)
+            .await?;
+
+        for _attempt in 0..MAX_CHECK_JOB_ATTEMPT {
+            match resp.tag.as_str() {
+                "complete" => return Ok(resp.entries),
+                "async_job_id" | "in_progress" => {}
+                "failed" => {
+                    let summary = resp
+                        .failed
+                        .as_ref()
+                        .map(parse_dropbox_failure_summary)
+                        .unwrap_or_default();
+                    return Err(new_dropbox_batch_error(&summary));
+                }
+                tag => {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "unexpected status of dropbox batch job",
+                    )
+                    .with_context("tag", tag));
+                }
+            }
+
+            let async_job_id = resp.async_job_id.take().ok_or_else(|| {
+                Error::new(ErrorKind::Unexpected, "dropbox didn't return async job id")
+            })?;
+            if resp.tag == "in_progress" {
+                tokio::time::sleep(Duration::from_secs(CHECK_JOB_WAIT_SECOND)).await;
+            }
+
+            resp = self
+                .dropbox_post_batch(
+                    ctx,
+                    check_url,
+                    &DropboxBatchCheckArgs {
+                        async_job_id: async_job_id.clone(),
+                    },
+                    op,
+                    service_op,
+                )
+                .await?;
+            // `check` doesn't return the job id, keep it for the next attempt.
+            resp.async_job_id.get_or_insert(async_job_id);
+        }
+
+        Err(Error::new(
+            ErrorKind::Unexpected,
+            "Exceed checking dropbox batch job timeout",
+        ))
+    }
+
+    async fn dropbox_post_batch<T: Serialize>(
+        &self,
+        ctx: &OperationContext,
+        url: &str,
+        args: &T,
+        op: Operation,
+        service_op: &'static str,
+    ) -> Result<DropboxBatchResponse> {
+        let bs = Bytes::from(serde_json::to_string(args).map_err(new_json_serialize_error)?);
+
+        let mut request = Request::post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, bs.len())
+            .extension(op)
+            .extension(ServiceOperation(service_op))
+            .body(Buffer::from(bs))
+            .map_err(new_request_build_error)?;
+
+        self.sign(ctx, &mut request).await?;
+        let resp = ctx.http_transport().send(request).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        serde_json::from_reader(resp.into_body().reader()).map_err(new_json_deserialize_error)
+    }
+
     pub async fn dropbox_create_folder(
         &self,
         ctx: &OperationContext,
@@ -316,33 +465,6 @@ impl DropboxCore {
         ctx.http_transport().send(request).await
     }
 
-    pub async fn dropbox_move(
-        &self,
-        ctx: &OperationContext,
-        from: &str,
-        to: &str,
-    ) -> Result<Response<Buffer>> {
-        let url = "https://api.dropboxapi.com/2/files/move_v2".to_string();
-
-        let args = DropboxMoveArgs {
-            from_path: self.build_path(from),
-            to_path: self.build_path(to),
-        };
-
-        let bs = Bytes::from(serde_json::to_string(&args).map_err(new_json_serialize_error)?);
-
-        let mut request = Request::post(&url)
-            .header(CONTENT_TYPE, "application/json")
-            .header(CONTENT_LENGTH, bs.len())
-            .extension(Operation::Rename)
-            .extension(ServiceOperation("MoveFile"))
-            .body(Buffer::from(bs))
-            .map_err(new_request_build_error)?;
-
-        self.sign(ctx, &mut request).await?;
-        ctx.http_transport().send(request).await
-    }
-
     pub async fn dropbox_get_metadata(
         &self,
         ctx: &OperationContext,
@@ -424,6 +546,22 @@ struct DropboxDeleteArgs {
     path: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct DropboxDeleteBatchArgs {
+    entries: Vec<DropboxDeleteArgs>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct DropboxMoveBatchArgs {
+    entries: Vec<DropboxMoveArgs>,
+    autorename: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct DropboxBatchCheckArgs {
+    async_job_id: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 struct DropboxCreateFolderArgs {
     path: String,
@@ -529,6 +667,38 @@ pub struct DropboxListResponse {
     pub has_more: bool,
 }
 
+/// Response of batch calls and their `check`, which is either an async job or the
+/// complete result.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct DropboxBatchResponse {
+    #[serde(rename(deserialize = ".tag"))]
+    pub tag: String,
+    pub async_job_id: Option<String>,
+    pub entries: Vec<DropboxBatchResultEntry>,
+    pub failed: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct DropboxBatchResultEntry {
+    #[serde(rename(deserialize = ".tag"))]
+    pub tag: String,
+    pub failure: Option<serde_json::Value>,
+}
+
+impl DropboxBatchResultEntry {
+    /// Convert the entry into an error if it failed.
+    pub fn into_result(self) -> Result<()> {
+        match self.failure {
+            Some(failure) if self.tag == "failure" => Err(new_dropbox_batch_error(
+                &parse_dropbox_failure_summary(&failure),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
 mod error {
     use http::Response;
     use http::StatusCode;
@@ -579,6 +749,37 @@ mod error {
         err
     }
 
+    /// Build the error of a failed entry or job in batch calls.
+    pub fn new_dropbox_batch_error(summary: &str) -> Error {
+        let (kind, retryable) =
+            parse_dropbox_error_summary(summary).unwrap_or((ErrorKind::Unexpected, false));
+
+        let err =
+            Error::new(kind, "dropbox batch operation failed").with_context("summary", summary);
+        if retryable { err.set_temporary() } else { err }
+    }
+
+    /// Batch calls return failures as nested tagged unions instead of error summaries,
+    /// like `{".tag": "path_lookup", "path_lookup": {".tag": "not_found"}}`.
+    ///
+    /// Flatten them into the summary format, like `path_lookup/not_found`. The
+    /// `relocation_error` wrapper of move failures is skipped so that the summary is
+    /// the same as the one returned by `move_v2`.
+    pub fn parse_dropbox_failure_summary(failure: &serde_json::Value) -> String {
+        let mut tags = vec![];
+        let mut value = failure;
+        while let Some(tag) = value.get(".tag").and_then(|v| v.as_str()) {
+            if !(tags.is_empty() && tag == "relocation_error") {
+                tags.push(tag);
+            }
+            match value.get(tag) {
+                Some(v) => value = v,
+                None => break,
+            }
+        }
+        tags.join("/")
+    }
+
     /// We cannot get the error type from the response header when the status code is 409.
     /// Because Dropbox API v2 will put error summary in the response body,
     /// we need to parse it to get the correct error type and then error kind.
@@ -601,3 +802,49 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_batch_response() {
+        let bs = r#"{
+            ".tag": "complete",
+            "entries": [
+                {".tag": "success", "metadata": {".tag": "file", "name": "a"}},
+                {".tag": "failure", "failure": {".tag": "path_lookup", "path_lookup": {".tag": "not_found"}}},
+                {".tag": "failure", "failure": {".tag": "too_many_write_operations"}}
+            ]
+        }"#;
+        let resp: DropboxBatchResponse = serde_json::from_str(bs).unwrap();
+        assert_eq!(resp.tag, "complete");
+
+        let results: Vec<_> = resp.entries.into_iter().map(|v| v.into_result()).collect();
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        let err = results[2].as_ref().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+
+        let bs = r#"{".tag": "async_job_id", "async_job_id": "job"}"#;
+        let resp: DropboxBatchResponse = serde_json::from_str(bs).unwrap();
+        assert_eq!(resp.async_job_id.as_deref(), Some("job"));
+    }
+
+    #[test]
+    fn test_parse_dropbox_failure_summary() {
+        let failure = serde_json::json!({
+            ".tag": "relocation_error",
+            "relocation_error": {
+                ".tag": "from_lookup",
+                "from_lookup": {".tag": "not_found"}
+            }
+        });
+        assert_eq!(
+            parse_dropbox_failure_summary(&failure),
+            "from_lookup/not_found"
+        );
+    }
+}
@@ -21,6 +21,7 @@ use http::StatusCode;
 
 use super::core::parse_error;
 use super::core::*;
+use opendal_core::raw::oio::BatchDeleteResult;
 use opendal_core::raw::*;
 use opendal_core::*;
 
@@ -35,7 +36,7 @@ impl DropboxDeleter {
     }
 }
 
-impl oio::OneShotDelete for DropboxDeleter {
+impl oio::BatchDelete for DropboxDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let resp = self.core.dropbox_delete(&self.ctx, &path).await?;
 
@@ -52,4 +53,37 @@ impl oio::OneShotDelete for DropboxDeleter {
             }
         }
     }
+
+    async fn delete_batch(&self, batch: Vec<(String, OpDelete)>) -> Result<BatchDeleteResult> {
+        let paths: Vec<String> = batch.iter().map(|(path, _)| path.clone()).collect();
+        let entries = self.core.dropbox_delete_batch(&self.ctx, &paths).await?;
+
+        if batch.len() != entries.len() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "invalid batch response, paths and response entries don't match",
+            ));
+        }
+
+        let mut batched_result = BatchDeleteResult::default();
+
+        for (entry, (path, args)) in entries.into_iter().zip(batch) {
+            match entry.into_result() {
+                // deleting not existing files is ok
+                Ok(()) => batched_result.succeeded.push((path, args)),
+                Err(err) if err.kind() == ErrorKind::NotFound => {
+                    batched_result.succeeded.push((path, args))
+                }
+                Err(err) => batched_result.failed.push((path, args, err)),
+            }
+        }
+
+        // If no file is deleted, return directly.
+        if batched_result.succeeded.is_empty() {
+            let err = batched_result.failed.remove(0).2;
+            return Err(err);
+        }
+
+        Ok(batched_result)
+    }
 }
@@ -0,0 +1,333 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+
+use super::EC_SCHEME;
+use super::codec::ReedSolomon;
+use super::config::EcConfig;
+use super::core::EcCore;
+use super::deleter::EcDeleter;
+use super::lister::EcLister;
+use super::reader::EcReader;
+use super::writer::EcWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Erasure coded composite service striping files across child operators.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct EcBuilder {
+    pub(super) config: EcConfig,
+    pub(super) operators: Vec<Operator>,
+}
+
+impl Debug for EcBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EcBuilder")
+            .field("config", &self.config)
+            .field("operators", &self.operators.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl EcBuilder {
+    /// Set root path of ec backend.
+    ///
+    /// The root is applied on top of the root of every child operator.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the number of parity shards.
+    ///
+    /// Files stay readable while up to `parity_shards` child operators are
+    /// unavailable. Default to 1.
+    pub fn parity_shards(mut self, parity_shards: usize) -> Self {
+        self.config.parity_shards = Some(parity_shards);
+        self
+    }
+
+    /// Add a child operator to store one shard of every file.
+    ///
+    /// The order of operators is part of the layout and must stay the same
+    /// for existing data.
+    pub fn operator(mut self, op: Operator) -> Self {
+        self.operators.push(op);
+        self
+    }
+}
+
+impl Builder for EcBuilder {
+    type Config = EcConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let parity_shards = self.config.parity_shards.unwrap_or(1);
+        if self.operators.len() <= parity_shards {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "operators must be more than parity shards",
+            )
+            .with_context("service", EC_SCHEME)
+            .with_context("operators", self.operators.len())
+            .with_context("parity_shards", parity_shards));
+        }
+        let codec = ReedSolomon::new(self.operators.len() - parity_shards, parity_shards)
+            .map_err(|err| err.with_context("service", EC_SCHEME))?;
+
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        let info = ServiceInfo::new(EC_SCHEME, &root, "");
+        let capability = Capability {
+            stat: true,
+            read: true,
+            write: true,
+            write_can_empty: true,
+            create_dir: true,
+            delete: true,
+            list: true,
+            list_with_recursive: true,
+
+            shared: self
+                .operators
+                .iter()
+                .all(|op| op.info().capability().shared),
+
+            ..Default::default()
+        };
+
+        Ok(EcBackend {
+            core: Arc::new(EcCore {
+                info,
+                capability,
+                root,
+                operators: self.operators,
+                codec,
+            }),
+        })
+    }
+}
+
+/// EcBackend implements [`Service`] on top of erasure coded child operators.
+#[derive(Clone, Debug)]
+pub struct EcBackend {
+    core: Arc<EcCore>,
+}
+
+impl Service for EcBackend {
+    type Reader = oio::PositionReader<EcReader>;
+    type Writer = EcWriter;
+    type Lister = EcLister;
+    type Deleter = oio::OneShotDeleter<EcDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.core.create_dir(path).await?;
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _args: OpStat) -> Result<RpStat> {
+        if path.ends_with('/') {
+            return Ok(RpStat::new(self.core.stat_dir(path).await?));
+        }
+
+        let header = self.core.read_header(path).await?;
+        Ok(RpStat::new(
+            Metadata::new(EntryMode::FILE).with_content_length(header.content_length),
+        ))
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, _args: OpRead) -> Result<Self::Reader> {
+        Ok(oio::PositionReader::new(EcReader::new(
+            self.core.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, path: &str, _args: OpWrite) -> Result<Self::Writer> {
+        Ok(EcWriter::new(self.core.clone(), path))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(EcDeleter::new(self.core.clone())))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        Ok(EcLister::new(self.core.clone(), path, args.recursive()))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use opendal_core::services::Memory;
+
+    use super::*;
+
+    fn new_operator(children: &[Operator]) -> Operator {
+        let builder = children.iter().fold(
+            EcBuilder::default().root("/data").parity_shards(2),
+            |builder, op| builder.operator(op.clone()),
+        );
+        Operator::new(builder).unwrap()
+    }
+
+    fn new_children() -> Vec<Operator> {
+        (0..5)
+            .map(|_| Operator::new(Memory::default()).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read() -> Result<()> {
+        let children = new_children();
+        let op = new_operator(&children);
+
+        let content = b"hello, erasure coded world!".to_vec();
+        op.write("a/b", content.clone()).await?;
+
+        // Every shard holds a header and 9 bytes of 27.
+        for child in &children {
+            assert_eq!(child.stat("data/a/b").await?.content_length(), 16 + 9);
+        }
+
+        assert_eq!(op.stat("a/b").await?.content_length(), 27);
+        assert!(op.stat("a/").await?.mode().is_dir());
+        assert_eq!(op.read("a/b").await?.to_vec(), content);
+        assert_eq!(
+            op.read_with("a/b").range(5..20).await?.to_vec(),
+            &content[5..20]
+        );
+
+        op.write("empty", Vec::<u8>::new()).await?;
+        assert_eq!(op.read("empty").await?.len(), 0);
+
+        let paths: Vec<String> = op
+            .lister_with("")
+            .recursive(true)
+            .await?
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert!(paths.contains(&"a/b".to_string()), "{paths:?}");
+        assert!(paths.contains(&"empty".to_string()), "{paths:?}");
+
+        op.delete("a/b").await?;
+        for child in &children {
+            assert!(!child.exists("data/a/b").await?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_unavailable_shards() -> Result<()> {
+        let children = new_children();
+        let op = new_operator(&children);
+
+        let content = (0..1000u32).map(|v| v as u8).collect::<Vec<_>>();
+        op.write("file", content.clone()).await?;
+
+        children[0].delete("data/file").await?;
+        children[2].delete("data/file").await?;
+        assert_eq!(op.read("file").await?.to_vec(), content);
+        assert_eq!(
+            op.read_with("file").range(300..700).await?.to_vec(),
+            &content[300..700]
+        );
+
+        children[4].delete("data/file").await?;
+        let err = op.read("file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_requires_data_shards() {
+        let children = new_children();
+        let builder = children[..2]
+            .iter()
+            .fold(EcBuilder::default().parity_shards(2), |builder, op| {
+                builder.operator(op.clone())
+            });
+        let err = Operator::new(builder).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -0,0 +1,231 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Systematic Reed-Solomon codec over GF(2^8).
+//!
+//! Data shards are stored as is, parity shards are built from a Cauchy
+//! matrix so that any `data` shards out of `data + parity` are enough to
+//! rebuild the rest.
+
+use opendal_core::*;
+
+/// Log and exp tables of GF(2^8) with the polynomial `x^8 + x^4 + x^3 + x^2 + 1`.
+const TABLES: ([u8; 256], [u8; 512]) = {
+    let mut log = [0u8; 256];
+    let mut exp = [0u8; 512];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        exp[i + 255] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= 0x11d;
+        }
+        i += 1;
+    }
+    (log, exp)
+};
+
+fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let (log, exp) = &TABLES;
+    exp[log[a as usize] as usize + log[b as usize] as usize]
+}
+
+fn inv(a: u8) -> u8 {
+    debug_assert!(a != 0, "zero has no inverse");
+    let (log, exp) = &TABLES;
+    exp[255 - log[a as usize] as usize]
+}
+
+/// Invert a square matrix with Gauss-Jordan elimination.
+fn invert(mut m: Vec<Vec<u8>>) -> Option<Vec<Vec<u8>>> {
+    let n = m.len();
+    let mut out: Vec<Vec<u8>> = (0..n)
+        .map(|i| (0..n).map(|j| u8::from(i == j)).collect())
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| m[r][col] != 0)?;
+        m.swap(col, pivot);
+        out.swap(col, pivot);
+
+        let factor = inv(m[col][col]);
+        for j in 0..n {
+            m[col][j] = mul(m[col][j], factor);
+            out[col][j] = mul(out[col][j], factor);
+        }
+
+        for r in 0..n {
+            let f = m[r][col];
+            if r == col || f == 0 {
+                continue;
+            }
+            for j in 0..n {
+                m[r][j] ^= mul(f, m[col][j]);
+                out[r][j] ^= mul(f, out[col][j]);
+            }
+        }
+    }
+
+    Some(out)
+}
+
+/// Reed-Solomon codec with `data` data shards and `parity` parity shards.
+#[derive(Debug, Clone, Copy)]
+pub struct ReedSolomon {
+    data: usize,
+    parity: usize,
+}
+
+impl ReedSolomon {
+    pub fn new(data: usize, parity: usize) -> Result<Self> {
+        if data == 0 || data + parity > 256 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "shards must be at least one data shard and at most 256 in total",
+            )
+            .with_context("data_shards", data)
+            .with_context("parity_shards", parity));
+        }
+
+        Ok(Self { data, parity })
+    }
+
+    pub fn data_shards(&self) -> usize {
+        self.data
+    }
+
+    pub fn total_shards(&self) -> usize {
+        self.data + self.parity
+    }
+
+    /// Coefficient of data shard `col` in shard `row`.
+    fn coefficient(&self, row: usize, col: usize) -> u8 {
+        if row < self.data {
+            u8::from(row == col)
+        } else {
+            // Rows and columns never overlap, so the sum is never zero.
+            inv(row as u8 ^ col as u8)
+        }
+    }
+
+    /// Build parity shards of the given equally sized data shards.
+    pub fn encode(&self, data: &[&[u8]]) -> Vec<Vec<u8>> {
+        debug_assert_eq!(data.len(), self.data);
+        let size = data.first().map(|v| v.len()).unwrap_or_default();
+
+        (self.data..self.total_shards())
+            .map(|row| {
+                let mut parity = vec![0u8; size];
+                for (col, shard) in data.iter().enumerate() {
+                    let c = self.coefficient(row, col);
+                    for (p, b) in parity.iter_mut().zip(shard.iter()) {
+                        *p ^= mul(c, *b);
+                    }
+                }
+                parity
+            })
+            .collect()
+    }
+
+    /// Rebuild data shard `index` from `(index, content)` of available shards.
+    ///
+    /// All shards must have the same size, at least `data` of them are required.
+    pub fn reconstruct(&self, shards: &[(usize, &[u8])], index: usize) -> Result<Vec<u8>> {
+        debug_assert!(index < self.data, "only data shards can be reconstructed");
+
+        if let Some((_, v)) = shards.iter().find(|(i, _)| *i == index) {
+            return Ok(v.to_vec());
+        }
+        if shards.len() < self.data {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "not enough shards to reconstruct data",
+            )
+            .with_context("available", shards.len())
+            .with_context("required", self.data));
+        }
+
+        let shards = &shards[..self.data];
+        let matrix = shards
+            .iter()
+            .map(|(row, _)| {
+                (0..self.data)
+                    .map(|col| self.coefficient(*row, col))
+                    .collect()
+            })
+            .collect();
+        let inverted = invert(matrix)
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "shards are not independent"))?;
+
+        let size = shards[0].1.len();
+        let mut out = vec![0u8; size];
+        for (c, (_, shard)) in inverted[index].iter().zip(shards) {
+            for (o, b) in out.iter_mut().zip(shard.iter()) {
+                *o ^= mul(*c, *b);
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_inv() {
+        for a in 1..=255u8 {
+            assert_eq!(mul(a, inv(a)), 1, "{a}");
+        }
+        assert_eq!(mul(0, 7), 0);
+    }
+
+    #[test]
+    fn test_reconstruct_from_any_data_shards() {
+        let rs = ReedSolomon::new(3, 2).unwrap();
+        let data: Vec<&[u8]> = vec![b"hell", b"o wo", b"rld!"];
+        let parity = rs.encode(&data);
+        let all: Vec<&[u8]> = data
+            .iter()
+            .copied()
+            .chain(parity.iter().map(|v| v.as_slice()))
+            .collect();
+
+        for lost_a in 0..5 {
+            for lost_b in lost_a + 1..5 {
+                let available: Vec<(usize, &[u8])> = all
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .filter(|(i, _)| *i != lost_a && *i != lost_b)
+                    .collect();
+                for (index, expected) in data.iter().enumerate() {
+                    assert_eq!(rs.reconstruct(&available, index).unwrap(), *expected);
+                }
+            }
+        }
+
+        let err = rs.reconstruct(&[(3, all[3]), (4, all[4])], 0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
+}
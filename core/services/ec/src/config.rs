@@ -0,0 +1,68 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::EcBuilder;
+
+/// Config for the erasure coded composite service.
+///
+/// Child operators can't be described by config, they must be added with
+/// [`EcBuilder::operator`].
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct EcConfig {
+    /// root of this backend.
+    pub root: Option<String>,
+    /// Number of parity shards, the remaining child operators store data shards.
+    ///
+    /// default to 1.
+    pub parity_shards: Option<usize>,
+}
+
+impl Debug for EcConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EcConfig")
+            .field("root", &self.root)
+            .field("parity_shards", &self.parity_shards)
+            .finish()
+    }
+}
+
+impl opendal_core::Configurator for EcConfig {
+    type Builder = EcBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(root) = uri.root() {
+            map.insert("root".to_string(), root.to_string());
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        EcBuilder {
+            config: self,
+            operators: vec![],
+        }
+    }
+}
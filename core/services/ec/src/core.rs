@@ -0,0 +1,279 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use futures::future::join_all;
+use log::debug;
+
+use super::codec::ReedSolomon;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+const SHARD_MAGIC: &[u8; 4] = b"OEC1";
+/// Size of the header in front of every shard.
+pub const SHARD_HEADER_SIZE: u64 = 16;
+
+/// Header stored in front of every shard, so any shard describes the object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardHeader {
+    pub data_shards: u8,
+    pub parity_shards: u8,
+    pub index: u8,
+    pub content_length: u64,
+}
+
+impl ShardHeader {
+    pub fn encode(&self) -> [u8; SHARD_HEADER_SIZE as usize] {
+        let mut buf = [0u8; SHARD_HEADER_SIZE as usize];
+        buf[..4].copy_from_slice(SHARD_MAGIC);
+        buf[4] = self.data_shards;
+        buf[5] = self.parity_shards;
+        buf[6] = self.index;
+        buf[8..].copy_from_slice(&self.content_length.to_le_bytes());
+        buf
+    }
+
+    pub fn decode(bs: &[u8]) -> Result<Self> {
+        if bs.len() != SHARD_HEADER_SIZE as usize || &bs[..4] != SHARD_MAGIC {
+            return Err(Error::new(ErrorKind::Unexpected, "shard header is invalid"));
+        }
+
+        Ok(Self {
+            data_shards: bs[4],
+            parity_shards: bs[5],
+            index: bs[6],
+            content_length: u64::from_le_bytes(bs[8..].try_into().expect("must be 8 bytes")),
+        })
+    }
+}
+
+pub struct EcCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub root: String,
+    /// Child operators, the shard index is the position of operator.
+    pub operators: Vec<Operator>,
+    pub codec: ReedSolomon,
+}
+
+impl Debug for EcCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EcCore")
+            .field("root", &self.root)
+            .field("operators", &self.operators.len())
+            .field("codec", &self.codec)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EcCore {
+    /// Size of every shard of an object with `content_length` bytes.
+    pub fn shard_size(&self, content_length: u64) -> u64 {
+        content_length.div_ceil(self.codec.data_shards() as u64)
+    }
+
+    /// Read the header from the first available shard.
+    pub async fn read_header(&self, path: &str) -> Result<ShardHeader> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut last_err = None;
+        for (index, op) in self.operators.iter().enumerate() {
+            let err = match op.read_with(&p).range(0..SHARD_HEADER_SIZE).await {
+                Ok(bs) => match ShardHeader::decode(&bs.to_vec()) {
+                    Ok(header) => return self.check_header(header),
+                    Err(err) => err,
+                },
+                Err(err) => err,
+            };
+
+            debug!("ec read header of {p} from shard {index} failed: {err}");
+            // Keep the first error other than `NotFound` to report it.
+            match &last_err {
+                Some(e) if !is_not_found(e) => {}
+                _ => last_err = Some(err.with_context("shard", index)),
+            }
+        }
+
+        Err(last_err.expect("operators must not be empty"))
+    }
+
+    fn check_header(&self, header: ShardHeader) -> Result<ShardHeader> {
+        if header.data_shards as usize != self.codec.data_shards()
+            || header.data_shards as usize + header.parity_shards as usize
+                != self.codec.total_shards()
+        {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "shard layout doesn't match the configured shards",
+            )
+            .with_context("data_shards", header.data_shards)
+            .with_context("parity_shards", header.parity_shards));
+        }
+
+        Ok(header)
+    }
+
+    /// Read `range` of data shard `index`, rebuilding it from other shards
+    /// if it's unavailable.
+    pub async fn read_shard(&self, path: &str, index: usize, range: Range<u64>) -> Result<Buffer> {
+        let p = build_abs_path(&self.root, path);
+        let size = (range.end - range.start) as usize;
+        let range = SHARD_HEADER_SIZE + range.start..SHARD_HEADER_SIZE + range.end;
+
+        match self.operators[index]
+            .read_with(&p)
+            .range(range.clone())
+            .await
+        {
+            Ok(bs) if bs.len() == size => return Ok(bs),
+            Ok(bs) => debug!(
+                "ec read of {p} from shard {index} is short: expected {size}, got {}",
+                bs.len()
+            ),
+            Err(err) => debug!("ec read of {p} from shard {index} failed: {err}"),
+        }
+
+        let shards = join_all(
+            self.operators
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(i, op)| {
+                    let p = &p;
+                    let range = range.clone();
+                    async move { (i, op.read_with(p).range(range).await) }
+                }),
+        )
+        .await
+        .into_iter()
+        .filter_map(|(i, res)| match res {
+            Ok(bs) if bs.len() == size => Some((i, bs.to_vec())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+        let shards = shards
+            .iter()
+            .map(|(i, v)| (*i, v.as_slice()))
+            .collect::<Vec<_>>();
+        let data = self
+            .codec
+            .reconstruct(&shards, index)
+            .map_err(|err| err.with_context("path", &p).with_context("shard", index))?;
+        Ok(Buffer::from(data))
+    }
+
+    /// Split the content into shards and write them into every operator.
+    pub async fn write(&self, path: &str, content: Buffer) -> Result<Metadata> {
+        let p = build_abs_path(&self.root, path);
+        let content_length = content.len() as u64;
+        let shard_size = self.shard_size(content_length) as usize;
+
+        let mut content = content.to_vec();
+        content.resize(shard_size * self.codec.data_shards(), 0);
+        let data: Vec<&[u8]> = if shard_size == 0 {
+            vec![&[]; self.codec.data_shards()]
+        } else {
+            content.chunks(shard_size).collect()
+        };
+        let parity = self.codec.encode(&data);
+
+        let shards = data
+            .into_iter()
+            .chain(parity.iter().map(|v| v.as_slice()))
+            .enumerate()
+            .map(|(index, shard)| {
+                let header = ShardHeader {
+                    data_shards: self.codec.data_shards() as u8,
+                    parity_shards: (self.codec.total_shards() - self.codec.data_shards()) as u8,
+                    index: index as u8,
+                    content_length,
+                };
+                let mut bs = header.encode().to_vec();
+                bs.extend_from_slice(shard);
+                bs
+            })
+            .collect::<Vec<_>>();
+
+        let results = join_all(
+            self.operators
+                .iter()
+                .zip(shards)
+                .map(|(op, bs)| op.write(&p, bs)),
+        )
+        .await;
+        for (index, res) in results.into_iter().enumerate() {
+            res.map_err(|err| err.with_context("path", &p).with_context("shard", index))?;
+        }
+
+        Ok(Metadata::new(EntryMode::FILE).with_content_length(content_length))
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<()> {
+        let p = build_abs_path(&self.root, path);
+        let results = join_all(self.operators.iter().map(|op| op.delete(&p))).await;
+        for (index, res) in results.into_iter().enumerate() {
+            res.map_err(|err| err.with_context("shard", index))?;
+        }
+        Ok(())
+    }
+
+    pub async fn create_dir(&self, path: &str) -> Result<()> {
+        let p = build_abs_path(&self.root, path);
+        let results = join_all(self.operators.iter().map(|op| op.create_dir(&p))).await;
+        for (index, res) in results.into_iter().enumerate() {
+            res.map_err(|err| err.with_context("shard", index))?;
+        }
+        Ok(())
+    }
+
+    pub async fn stat_dir(&self, path: &str) -> Result<Metadata> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut last_err = None;
+        for op in &self.operators {
+            match op.stat(&p).await {
+                Ok(_) => return Ok(Metadata::new(EntryMode::DIR)),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.expect("operators must not be empty"))
+    }
+}
+
+fn is_not_found(err: &Error) -> bool {
+    err.kind() == ErrorKind::NotFound
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shard_header() {
+        let header = ShardHeader {
+            data_shards: 4,
+            parity_shards: 2,
+            index: 5,
+            content_length: 4096,
+        };
+        assert_eq!(ShardHeader::decode(&header.encode()).unwrap(), header);
+        assert!(ShardHeader::decode(b"invalid").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+
+use super::core::EcCore;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Lister lists entries from the first available child operator.
+///
+/// Every child holds a shard of every file, so the entries are the same
+/// among them except for the content length, which is left unset.
+pub struct EcLister {
+    core: Arc<EcCore>,
+    path: String,
+    recursive: bool,
+    lister: Option<Lister>,
+}
+
+impl EcLister {
+    pub fn new(core: Arc<EcCore>, path: &str, recursive: bool) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            recursive,
+            lister: None,
+        }
+    }
+
+    async fn lister(&mut self) -> Result<&mut Lister> {
+        if self.lister.is_none() {
+            let p = build_abs_path(&self.core.root, &self.path);
+
+            let mut last_err = None;
+            for op in &self.core.operators {
+                match op.lister_with(&p).recursive(self.recursive).await {
+                    Ok(lister) => {
+                        self.lister = Some(lister);
+                        break;
+                    }
+                    Err(err) => last_err = Some(err),
+                }
+            }
+            if let Some(err) = last_err.filter(|_| self.lister.is_none()) {
+                return Err(err);
+            }
+        }
+
+        Ok(self.lister.as_mut().expect("lister must be initialized"))
+    }
+}
+
+impl oio::List for EcLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let root = self.core.root.clone();
+        let Some(entry) = self.lister().await?.try_next().await? else {
+            return Ok(None);
+        };
+
+        let path = build_rel_path(&root, entry.path());
+        let mode = entry.metadata().mode();
+        Ok(Some(oio::Entry::new(&path, Metadata::new(mode))))
+    }
+}
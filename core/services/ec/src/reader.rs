@@ -0,0 +1,79 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::EcCore;
+use super::core::ShardHeader;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct EcReader {
+    core: Arc<EcCore>,
+    path: String,
+}
+
+impl EcReader {
+    pub(super) fn new(core: Arc<EcCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+pub struct EcReaderHandle {
+    core: Arc<EcCore>,
+    path: String,
+    header: ShardHeader,
+}
+
+impl oio::PositionRead for EcReader {
+    type Handle = EcReaderHandle;
+
+    async fn open(&self) -> Result<Self::Handle> {
+        let header = self.core.read_header(&self.path).await?;
+
+        Ok(EcReaderHandle {
+            core: self.core.clone(),
+            path: self.path.clone(),
+            header,
+        })
+    }
+
+    /// Read from the data shard containing `offset`, so at most one shard is
+    /// read or reconstructed for each call.
+    async fn read_at(handle: &Self::Handle, offset: u64, size: usize) -> Result<Buffer> {
+        let length = handle.header.content_length;
+        if size == 0 || offset >= length {
+            return Ok(Buffer::new());
+        }
+
+        let shard_size = handle.core.shard_size(length);
+        let index = offset / shard_size;
+        let shard_start = index * shard_size;
+        let shard_end = length.min(shard_start + shard_size);
+
+        let start = offset - shard_start;
+        let end = (shard_end - shard_start).min(start + size as u64);
+        handle
+            .core
+            .read_shard(&handle.path, index as usize, start..end)
+            .await
+    }
+}
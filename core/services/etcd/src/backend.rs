@@ -27,7 +27,7 @@ use super::config::EtcdConfig;
 use super::core::EtcdCore;
 use super::core::constants::DEFAULT_ETCD_ENDPOINTS;
 use super::deleter::EtcdDeleter;
-use super::lister::EtcdLazyLister;
+use super::lister::EtcdLister;
 use super::reader::*;
 use super::writer::EtcdWriter;
 use opendal_core::raw::*;
@@ -189,6 +189,9 @@ impl EtcdBackend {
             delete: true,
             stat: true,
             list: true,
+            list_with_limit: true,
+            list_with_start_after: true,
+            list_with_watch: true,
 
             shared: true,
 
@@ -206,7 +209,7 @@ impl EtcdBackend {
 impl Service for EtcdBackend {
     type Reader = oio::StreamReader<EtcdReader>;
     type Writer = EtcdWriter;
-    type Lister = oio::HierarchyLister<EtcdLazyLister>;
+    type Lister = oio::HierarchyLister<EtcdLister>;
     type Deleter = oio::OneShotDeleter<EtcdDeleter>;
     type Copier = ();
 
@@ -305,11 +308,12 @@ impl Service for EtcdBackend {
     }
 
     fn list(&self, _ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
-        let output: oio::HierarchyLister<EtcdLazyLister> = {
-            let lister = EtcdLazyLister::new(
+        let output: oio::HierarchyLister<EtcdLister> = {
+            let lister = EtcdLister::new(
                 self.core.clone(),
                 self.info.root().to_string(),
                 path.to_string(),
+                &args,
             );
             let lister = oio::HierarchyLister::new(lister, path, args.recursive());
             Ok(lister)
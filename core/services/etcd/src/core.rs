@@ -17,6 +17,10 @@
 
 use etcd_client::Client;
 use etcd_client::ConnectOptions;
+use etcd_client::GetOptions;
+use etcd_client::GetResponse;
+use etcd_client::WatchOptions;
+use etcd_client::WatchStream;
 use fastpool::ManageObject;
 use fastpool::ObjectStatus;
 use fastpool::bounded;
@@ -28,6 +32,7 @@ use opendal_core::{Buffer, Error, ErrorKind, Result};
 
 pub mod constants {
     pub const DEFAULT_ETCD_ENDPOINTS: &str = "http://127.0.0.1:2379";
+    pub const DEFAULT_LIST_LIMIT: usize = 1000;
 }
 
 #[derive(Clone)]
@@ -146,6 +151,53 @@ impl EtcdCore {
         let _ = client.delete(key, None).await.map_err(format_etcd_error)?;
         Ok(())
     }
+
+    /// Get up to `limit` keys in range `[start, end)` as of the given revision.
+    ///
+    /// Revision `0` means the latest revision.
+    pub async fn list(
+        &self,
+        start: Vec<u8>,
+        end: Vec<u8>,
+        limit: i64,
+        revision: i64,
+    ) -> Result<GetResponse> {
+        let mut client = self.conn().await?;
+        let options = GetOptions::new()
+            .with_range(end)
+            .with_keys_only()
+            .with_limit(limit)
+            .with_revision(revision);
+        client
+            .get(start, Some(options))
+            .await
+            .map_err(format_etcd_error)
+    }
+
+    /// Watch changes of keys in range `[start, end)` since the given revision.
+    pub async fn watch(&self, start: Vec<u8>, end: Vec<u8>, revision: i64) -> Result<WatchStream> {
+        let mut client = self.conn().await?;
+        let options = WatchOptions::new()
+            .with_range(end)
+            .with_start_revision(revision);
+        client
+            .watch(start, Some(options))
+            .await
+            .map_err(format_etcd_error)
+    }
+}
+
+/// Get the end of the key range that covers all keys starting with `prefix`.
+pub fn prefix_range_end(prefix: &[u8]) -> Vec<u8> {
+    for (i, v) in prefix.iter().enumerate().rev() {
+        if *v < 0xFF {
+            let mut end = prefix[..=i].to_vec();
+            end[i] = *v + 1;
+            return end;
+        }
+    }
+    // `\0` means all keys greater than or equal to the start key.
+    vec![0]
 }
 
 mod error {
@@ -161,3 +213,16 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_range_end() {
+        assert_eq!(prefix_range_end(b"a/b/"), b"a/b0".to_vec());
+        assert_eq!(prefix_range_end(b"a\xff"), b"b".to_vec());
+        assert_eq!(prefix_range_end(b"\xff\xff"), vec![0]);
+        assert_eq!(prefix_range_end(b""), vec![0]);
+    }
+}
@@ -15,99 +15,175 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
-use std::vec::IntoIter;
+
+use etcd_client::EventType;
+use etcd_client::WatchStream;
 
 use super::core::EtcdCore;
+use super::core::constants::DEFAULT_LIST_LIMIT;
+use super::core::format_etcd_error;
+use super::core::prefix_range_end;
 use opendal_core::raw::oio::Entry;
-use opendal_core::raw::{build_abs_path, build_rel_path, oio};
+use opendal_core::raw::{OpList, build_abs_path, build_rel_path, oio};
 use opendal_core::*;
 
+/// Lister that fetches keys in pages of range reads.
+///
+/// All pages are read at the revision of the first page. If watch is enabled,
+/// changes made after that revision are streamed once all pages are consumed.
 pub struct EtcdLister {
+    core: Arc<EtcdCore>,
     root: String,
-    path: String,
-    iter: IntoIter<String>,
+    prefix: String,
+    end: Vec<u8>,
+    limit: i64,
+    watch: bool,
+
+    /// Key to fetch the next page from, `None` once all pages are fetched.
+    next_key: Option<Vec<u8>>,
+    /// Revision the listing is served at, `0` before the first page is fetched.
+    revision: i64,
+    stream: Option<WatchStream>,
+    entries: VecDeque<Entry>,
 }
 
 impl EtcdLister {
-    pub async fn new(core: Arc<EtcdCore>, root: String, path: String) -> Result<Self> {
-        let abs_path = build_abs_path(&root, &path);
-
-        // Get all keys with the specified prefix
-        let mut client = core.conn().await?;
-        let get_options = Some(
-            etcd_client::GetOptions::new()
-                .with_prefix()
-                .with_keys_only(),
-        );
-        let resp = client
-            .get(abs_path.as_str(), get_options)
-            .await
-            .map_err(super::core::format_etcd_error)?;
-
-        // Collect all keys that match the prefix
-        let mut keys = Vec::new();
-        for kv in resp.kvs() {
-            let key = kv.key_str().map(String::from).map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
-                    .set_source(err)
-            })?;
-            keys.push(key);
+    pub fn new(core: Arc<EtcdCore>, root: String, path: String, args: &OpList) -> Self {
+        let prefix = build_abs_path(&root, &path);
+        let end = prefix_range_end(prefix.as_bytes());
+
+        let mut start = prefix.as_bytes().to_vec();
+        if let Some(start_after) = args.start_after() {
+            // Append `\0` to get the smallest key after `start_after`.
+            let mut key = build_abs_path(&root, start_after).into_bytes();
+            key.push(0);
+            start = start.max(key);
         }
 
-        Ok(Self {
+        Self {
+            core,
             root,
-            path: abs_path,
-            iter: keys.into_iter(),
-        })
+            prefix,
+            end,
+            limit: args.limit().unwrap_or(DEFAULT_LIST_LIMIT) as i64,
+            watch: args.watch(),
+
+            next_key: Some(start),
+            revision: 0,
+            stream: None,
+            entries: VecDeque::new(),
+        }
     }
-}
 
-impl oio::List for EtcdLister {
-    async fn next(&mut self) -> Result<Option<Entry>> {
-        for key in self.iter.by_ref() {
-            if key.starts_with(&self.path) {
-                let path = build_rel_path(&self.root, &key);
+    async fn next_page(&mut self, start: Vec<u8>) -> Result<()> {
+        let resp = self
+            .core
+            .list(start, self.end.clone(), self.limit, self.revision)
+            .await?;
+        if self.revision == 0 {
+            self.revision = resp.header().map(|h| h.revision()).unwrap_or_default();
+        }
 
-                let entry = Entry::new(&path, Metadata::new(EntryMode::from_path(&key)));
-                return Ok(Some(entry));
-            }
+        for kv in resp.kvs() {
+            let key = kv_key(kv.key_str())?;
+            let entry = Entry::new(
+                &build_rel_path(&self.root, key),
+                Metadata::new(EntryMode::from_path(key)),
+            );
+            self.entries.push_back(entry);
         }
 
-        Ok(None)
+        if resp.more() {
+            if let Some(kv) = resp.kvs().last() {
+                let mut key = kv.key().to_vec();
+                key.push(0);
+                self.next_key = Some(key);
+            }
+        }
+        Ok(())
     }
-}
 
-pub struct EtcdLazyLister {
-    core: Arc<EtcdCore>,
-    root: String,
-    path: String,
-    inner: Option<EtcdLister>,
-}
+    async fn next_events(&mut self) -> Result<()> {
+        if self.stream.is_none() {
+            let stream = self
+                .core
+                .watch(
+                    self.prefix.as_bytes().to_vec(),
+                    self.end.clone(),
+                    self.revision + 1,
+                )
+                .await?;
+            self.stream = Some(stream);
+        }
+        let stream = self.stream.as_mut().expect("stream must be initialized");
 
-impl EtcdLazyLister {
-    pub fn new(core: Arc<EtcdCore>, root: String, path: String) -> Self {
-        Self {
-            core,
-            root,
-            path,
-            inner: None,
+        let resp = match stream.message().await {
+            Ok(Some(resp)) => resp,
+            // Drop the stream so that the next call resumes watching from `revision`.
+            Ok(None) => {
+                self.stream = None;
+                return Err(
+                    Error::new(ErrorKind::Unexpected, "etcd watch stream closed").set_temporary(),
+                );
+            }
+            Err(err) => {
+                self.stream = None;
+                return Err(format_etcd_error(err));
+            }
+        };
+        if resp.canceled() {
+            self.stream = None;
+            return Err(Error::new(ErrorKind::Unexpected, "etcd watch canceled")
+                .with_context("reason", resp.cancel_reason())
+                .with_context("compact_revision", resp.compact_revision()));
         }
+
+        for event in resp.events() {
+            let Some(kv) = event.kv() else {
+                continue;
+            };
+            let key = kv_key(kv.key_str())?;
+            let mut metadata = Metadata::new(EntryMode::from_path(key));
+            match event.event_type() {
+                EventType::Put => {
+                    metadata.set_content_length(kv.value().len() as u64);
+                }
+                EventType::Delete => {
+                    metadata.set_is_deleted(true);
+                }
+            }
+            self.entries
+                .push_back(Entry::new(&build_rel_path(&self.root, key), metadata));
+            self.revision = self.revision.max(kv.mod_revision());
+        }
+        Ok(())
     }
 }
 
-impl oio::List for EtcdLazyLister {
+impl oio::List for EtcdLister {
     async fn next(&mut self) -> Result<Option<Entry>> {
-        if self.inner.is_none() {
-            self.inner = Some(
-                EtcdLister::new(self.core.clone(), self.root.clone(), self.path.clone()).await?,
-            );
-        }
+        loop {
+            if let Some(entry) = self.entries.pop_front() {
+                return Ok(Some(entry));
+            }
+
+            if let Some(start) = self.next_key.take() {
+                self.next_page(start).await?;
+                continue;
+            }
 
-        self.inner
-            .as_mut()
-            .expect("lister must be initialized")
-            .next()
-            .await
+            if !self.watch {
+                return Ok(None);
+            }
+            self.next_events().await?;
+        }
     }
 }
+
+fn kv_key(key: std::result::Result<&str, etcd_client::Error>) -> Result<&str> {
+    key.map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string").set_source(err)
+    })
+}
@@ -52,7 +52,7 @@ impl FsBuilder {
     ///
     /// # Notes
     ///
-    /// - When append is enabled, we will not use atomic write
+    /// - When append or offset is enabled, we will not use atomic write
     ///   to avoid data loss and performance issue.
     pub fn atomic_write_dir(mut self, dir: &str) -> Self {
         if !dir.is_empty() {
@@ -61,6 +61,26 @@ impl FsBuilder {
 
         self
     }
+
+    /// Set how symlinks are handled during list.
+    ///
+    /// Available values:
+    ///
+    /// - `link`: Return symlinks as [`EntryMode::LINK`] entries, with targets in
+    ///   [`Metadata::link_target`].
+    /// - `follow`: Return symlinks as the files or dirs they point to. Dangling
+    ///   links are skipped. Recursive list will walk into linked dirs, make sure
+    ///   there are no cycles.
+    /// - `skip`: Don't return symlinks.
+    ///
+    /// Default to `link`. `stat` and `read` always follow symlinks.
+    pub fn symlink_behavior(mut self, behavior: &str) -> Self {
+        if !behavior.is_empty() {
+            self.config.symlink_behavior = Some(behavior.to_string());
+        }
+
+        self
+    }
 }
 
 impl Builder for FsBuilder {
@@ -69,6 +89,8 @@ impl Builder for FsBuilder {
     fn build(self) -> Result<impl Service> {
         debug!("backend build started: {:?}", &self);
 
+        let symlink_behavior = SymlinkBehavior::try_from(&self.config.symlink_behavior)?;
+
         let root = match self.config.root.map(PathBuf::from) {
             Some(root) => Ok(root),
             None => Err(Error::new(
@@ -143,12 +165,14 @@ impl Builder for FsBuilder {
                     write: true,
                     write_can_empty: true,
                     write_can_append: true,
+                    write_can_seek: true,
                     write_can_multi: true,
                     write_with_if_not_exists: true,
                     #[cfg(unix)]
                     write_with_user_metadata: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
                     delete: true,
                     delete_with_recursive: true,
 
@@ -156,6 +180,7 @@ impl Builder for FsBuilder {
 
                     copy: true,
                     rename: true,
+                    rename_is_atomic: true,
 
                     shared: true,
 
@@ -163,6 +188,7 @@ impl Builder for FsBuilder {
                 },
                 root,
                 atomic_write_dir,
+                symlink_behavior,
                 buf_pool: oio::PooledBuf::new(16).with_initial_capacity(256 * 1024),
             }),
         })
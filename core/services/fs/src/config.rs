@@ -30,6 +30,11 @@ pub struct FsConfig {
 
     /// tmp dir for atomic write
     pub atomic_write_dir: Option<String>,
+
+    /// How to handle symlinks during list.
+    ///
+    /// Available values: `link`, `follow`, `skip`, default to `link`.
+    pub symlink_behavior: Option<String>,
 }
 
 impl opendal_core::Configurator for FsConfig {
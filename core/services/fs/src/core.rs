@@ -17,9 +17,12 @@
 
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
 
+use tokio::io::AsyncSeekExt;
+
 use opendal_core::raw::*;
 use opendal_core::*;
 
@@ -29,9 +32,39 @@ pub struct FsCore {
     pub capability: Capability,
     pub root: PathBuf,
     pub atomic_write_dir: Option<PathBuf>,
+    pub symlink_behavior: SymlinkBehavior,
     pub buf_pool: oio::PooledBuf,
 }
 
+/// How symlinks are handled during list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkBehavior {
+    /// Return symlinks as [`EntryMode::LINK`] entries with their targets.
+    Link,
+    /// Return symlinks as the entries they point to, dangling links are skipped.
+    Follow,
+    /// Don't return symlinks.
+    Skip,
+}
+
+impl TryFrom<&Option<String>> for SymlinkBehavior {
+    type Error = Error;
+
+    fn try_from(value: &Option<String>) -> Result<Self> {
+        match value.as_deref() {
+            None | Some("link") => Ok(SymlinkBehavior::Link),
+            Some("follow") => Ok(SymlinkBehavior::Follow),
+            Some("skip") => Ok(SymlinkBehavior::Skip),
+            Some(v) => Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "Invalid symlink behavior, available: `link`, `follow`, `skip`",
+            )
+            .with_context("service", super::FS_SCHEME)
+            .with_context("symlink_behavior", v)),
+        }
+    }
+}
+
 impl FsCore {
     /// Join a caller-supplied key onto a base directory while keeping the result
     /// confined to that base.
@@ -154,11 +187,17 @@ impl FsCore {
 
         if op.append() {
             open_options.append(true);
-        } else {
+        } else if op.offset().is_none() {
             open_options.truncate(true);
         }
 
-        let f = open_options.open(path).await.map_err(parse_error)?;
+        let mut f = open_options.open(path).await.map_err(parse_error)?;
+
+        if let Some(offset) = op.offset() {
+            f.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(new_std_io_error)?;
+        }
 
         Ok(f)
     }
@@ -23,8 +23,11 @@ use opendal_core::Metadata;
 use opendal_core::Result;
 use opendal_core::raw::*;
 
+use super::core::SymlinkBehavior;
+
 pub struct FsLister<P> {
     root: PathBuf,
+    symlink_behavior: SymlinkBehavior,
 
     current_path: Option<String>,
 
@@ -32,9 +35,10 @@ pub struct FsLister<P> {
 }
 
 impl<P> FsLister<P> {
-    pub fn new(root: &Path, path: &str, rd: P) -> Self {
+    pub fn new(root: &Path, path: &str, rd: P, symlink_behavior: SymlinkBehavior) -> Self {
         Self {
             root: root.to_owned(),
+            symlink_behavior,
             current_path: Some(path.to_string()),
             rd,
         }
@@ -82,23 +86,52 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
                 }
                 Err(e) => return Err(new_std_io_error(e)),
             };
-            let (path, mode) = if ft.is_dir() {
+
+            let mut link_target = None;
+            let de_metadata = if ft.is_symlink() {
+                match self.symlink_behavior {
+                    SymlinkBehavior::Skip => continue,
+                    SymlinkBehavior::Follow => match tokio::fs::metadata(&entry_path).await {
+                        Ok(m) => m,
+                        // Dangling links have nothing to follow.
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                        Err(e) => return Err(new_std_io_error(e)),
+                    },
+                    SymlinkBehavior::Link => {
+                        match tokio::fs::read_link(&entry_path).await {
+                            Ok(target) => link_target = Some(target),
+                            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                            Err(e) => return Err(new_std_io_error(e)),
+                        }
+                        match de.metadata().await {
+                            Ok(m) => m,
+                            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                            Err(e) => return Err(new_std_io_error(e)),
+                        }
+                    }
+                }
+            } else {
+                match de.metadata().await {
+                    Ok(de_metadata) => de_metadata,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                        // Entry can be deleted between readdir and metadata calls.
+                        continue;
+                    }
+                    Err(e) => return Err(new_std_io_error(e)),
+                }
+            };
+
+            let (path, mode) = if link_target.is_some() {
+                (&rel_path, EntryMode::LINK)
+            } else if de_metadata.is_dir() {
                 // Make sure we are returning the correct path.
                 (&format!("{rel_path}/"), EntryMode::DIR)
-            } else if ft.is_file() {
+            } else if de_metadata.is_file() {
                 (&rel_path, EntryMode::FILE)
             } else {
                 (&rel_path, EntryMode::Unknown)
             };
 
-            let de_metadata = match de.metadata().await {
-                Ok(de_metadata) => de_metadata,
-                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                    // Entry can be deleted between readdir and metadata calls.
-                    continue;
-                }
-                Err(e) => return Err(new_std_io_error(e)),
-            };
             let last_modified = match de_metadata.modified() {
                 Ok(v) => v,
                 Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
@@ -106,9 +139,12 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
                 }
                 Err(e) => return Err(new_std_io_error(e)),
             };
-            let metadata = Metadata::new(mode)
+            let mut metadata = Metadata::new(mode)
                 .with_content_length(de_metadata.len())
                 .with_last_modified(Timestamp::try_from(last_modified)?);
+            if let Some(target) = link_target {
+                metadata.set_link_target(&target.to_string_lossy());
+            }
 
             return Ok(Some(oio::Entry::new(path, metadata)));
         }
@@ -129,7 +165,7 @@ mod tests {
         std::fs::write(root.join("has\\slash"), b"data").unwrap();
 
         let rd = tokio::fs::read_dir(&root).await.unwrap();
-        let mut lister = FsLister::new(&root, "/", rd);
+        let mut lister = FsLister::new(&root, "/", rd, SymlinkBehavior::Link);
 
         let first = lister.next().await.unwrap().unwrap();
         assert_eq!(first.mode(), EntryMode::DIR);
@@ -144,4 +180,65 @@ mod tests {
 
         std::fs::remove_dir_all(&root).unwrap();
     }
+
+    async fn list_names(root: &Path, behavior: SymlinkBehavior) -> Vec<(String, Metadata)> {
+        let rd = tokio::fs::read_dir(root).await.unwrap();
+        let mut lister = FsLister::new(root, "/", rd, behavior);
+        let mut entries = vec![];
+        while let Some(e) = lister.next().await.unwrap() {
+            let (path, meta) = e.into_parts();
+            entries.push((path, meta));
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
+    #[tokio::test]
+    async fn list_with_symlink_behavior() {
+        let root = std::env::temp_dir().join("opendal-test-fs-lister-symlink");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("dir")).unwrap();
+        std::fs::write(root.join("file"), b"data").unwrap();
+        std::os::unix::fs::symlink("file", root.join("link")).unwrap();
+        std::os::unix::fs::symlink("dir", root.join("dir_link")).unwrap();
+        std::os::unix::fs::symlink("missing", root.join("dangling")).unwrap();
+
+        let entries = list_names(&root, SymlinkBehavior::Link).await;
+        let links: Vec<_> = entries
+            .iter()
+            .filter(|(_, m)| m.mode() == EntryMode::LINK)
+            .map(|(p, m)| (p.as_str(), m.link_target().unwrap()))
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                ("dangling", "missing"),
+                ("dir_link", "dir"),
+                ("link", "file")
+            ]
+        );
+
+        let entries = list_names(&root, SymlinkBehavior::Follow).await;
+        let paths: Vec<_> = entries
+            .iter()
+            .map(|(p, m)| (p.as_str(), m.mode()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("/", EntryMode::DIR),
+                ("dir/", EntryMode::DIR),
+                ("dir_link/", EntryMode::DIR),
+                ("file", EntryMode::FILE),
+                ("link", EntryMode::FILE),
+            ]
+        );
+        assert_eq!(entries[4].1.content_length(), 4);
+
+        let entries = list_names(&root, SymlinkBehavior::Skip).await;
+        let paths: Vec<_> = entries.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paths, vec!["/", "dir/", "file"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
 }
@@ -105,17 +105,17 @@ impl FsLazyWriter {
 
     async fn inner(&mut self) -> Result<&mut FsWriters> {
         if self.inner.is_none() {
-            let is_append = self.op.append();
+            // Writers that don't start from the beginning must write sequentially.
+            let is_sequential = self.op.append() || self.op.offset().is_some();
             let concurrent = self.op.concurrent();
             let writer = FsWriter::create(self.core.clone(), &self.path, self.op.clone()).await?;
-            let writer = if is_append {
+            let writer = if is_sequential {
                 FsWriters::One(writer)
             } else {
-                FsWriters::Two(oio::PositionWriter::new(
-                    self.executor.clone(),
-                    writer,
-                    concurrent,
-                ))
+                FsWriters::Two(
+                    oio::PositionWriter::new(self.executor.clone(), writer, concurrent)
+                        .with_adaptive_concurrency(self.op.adaptive_concurrency().cloned()),
+                )
             };
 
             self.inner = Some(writer);
@@ -159,7 +159,12 @@ impl oio::List for FsLazyLister {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         if self.inner.is_none() {
             self.inner = Some(match self.core.fs_list(&self.path).await? {
-                Some(rd) => Some(FsLister::new(&self.core.root, &self.path, rd)),
+                Some(rd) => Some(FsLister::new(
+                    &self.core.root,
+                    &self.path,
+                    rd,
+                    self.core.symlink_behavior,
+                )),
                 None => None,
             });
         }
@@ -72,9 +72,10 @@ impl FsWriter {
             ));
         }
 
-        // The only case we allow write in place is the file
-        // exists and users request for append writing.
-        let (f, temp_path) = if !(is_append && is_exist) {
+        // The only cases we allow write in place are the file exists and
+        // users request for append writing, or users request for writing
+        // at an offset which has to keep the existing content.
+        let (f, temp_path) = if !(is_append && is_exist) && op.offset().is_none() {
             core.fs_tempfile_write(path).await?
         } else {
             let f = core.fs_write(&target_path, &op).await?;
@@ -152,6 +152,7 @@ impl Builder for FtpBuilder {
 
             delete: true,
             create_dir: true,
+            create_dir_persistent: true,
 
             list: true,
 
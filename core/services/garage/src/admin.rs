@@ -0,0 +1,240 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header;
+use mea::once::OnceCell;
+use serde::Deserialize;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Client of Garage's admin API, used to create the bucket on first use.
+///
+/// Reference: <https://garagehq.deuxfleurs.fr/api/garage-admin-v1.html>
+pub struct GarageAdmin {
+    endpoint: String,
+    authorization: String,
+    bucket: String,
+    access_key_id: Option<String>,
+    ensured: OnceCell<()>,
+}
+
+impl std::fmt::Debug for GarageAdmin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GarageAdmin")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Deserialize)]
+struct BucketInfo {
+    id: String,
+}
+
+impl GarageAdmin {
+    pub fn new(
+        endpoint: &str,
+        admin_token: &str,
+        bucket: &str,
+        access_key_id: Option<&str>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            authorization: format!("Bearer {admin_token}"),
+            bucket: bucket.to_string(),
+            access_key_id: access_key_id.map(|v| v.to_string()),
+            ensured: OnceCell::new(),
+        }
+    }
+
+    /// Make sure the bucket exists and the access key is allowed to use it.
+    ///
+    /// Only the first successful call talks to the admin API.
+    pub async fn ensure_bucket(&self, ctx: &OperationContext, op: Operation) -> Result<()> {
+        self.ensured
+            .get_or_try_init(|| async {
+                let bucket_id = match self.get_bucket_id(ctx, op).await? {
+                    Some(id) => id,
+                    None => self.create_bucket(ctx, op).await?,
+                };
+                if let Some(access_key_id) = &self.access_key_id {
+                    self.allow_bucket_key(ctx, op, &bucket_id, access_key_id)
+                        .await?;
+                }
+                Ok(())
+            })
+            .await
+            .map(|_| ())
+    }
+
+    async fn get_bucket_id(&self, ctx: &OperationContext, op: Operation) -> Result<Option<String>> {
+        let url = QueryPairsWriter::new(&format!("{}/v1/bucket", self.endpoint))
+            .push("globalAlias", &percent_encode_path(&self.bucket))
+            .finish();
+        let req = Request::get(&url)
+            .header(header::AUTHORIZATION, &self.authorization)
+            .extension(op)
+            .extension(ServiceOperation("GetBucketInfo"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => parse_bucket_id(resp).map(Some),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn create_bucket(&self, ctx: &OperationContext, op: Operation) -> Result<String> {
+        let body = serde_json::json!({ "globalAlias": self.bucket });
+        let body = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+        let req = Request::post(format!("{}/v1/bucket", self.endpoint))
+            .header(header::AUTHORIZATION, &self.authorization)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(op)
+            .extension(ServiceOperation("CreateBucket"))
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => parse_bucket_id(resp),
+            // Someone else created the bucket in the meantime.
+            StatusCode::CONFLICT => self.get_bucket_id(ctx, op).await?.ok_or_else(|| {
+                Error::new(ErrorKind::Unexpected, "bucket exists but can't be found")
+                    .with_context("bucket", &self.bucket)
+            }),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn allow_bucket_key(
+        &self,
+        ctx: &OperationContext,
+        op: Operation,
+        bucket_id: &str,
+        access_key_id: &str,
+    ) -> Result<()> {
+        let body = serde_json::json!({
+            "bucketId": bucket_id,
+            "accessKeyId": access_key_id,
+            "permissions": {
+                "read": true,
+                "write": true,
+                "owner": false,
+            },
+        });
+        let body = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+        let req = Request::post(format!("{}/v1/bucket/allow", self.endpoint))
+            .header(header::AUTHORIZATION, &self.authorization)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(op)
+            .extension(ServiceOperation("AllowBucketKey"))
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+fn parse_bucket_id(resp: Response<Buffer>) -> Result<String> {
+    let bs = resp.into_body().to_bytes();
+    let info: BucketInfo = serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+    Ok(info.id)
+}
+
+/// Error returned by Garage's admin API.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct GarageAdminError {
+    code: String,
+    message: String,
+}
+
+/// Parse error response into Error.
+fn parse_error(resp: Response<Buffer>) -> Error {
+    let (parts, body) = resp.into_parts();
+    let bs = body.to_bytes();
+
+    let (kind, retryable) = match parts.status.as_u16() {
+        401 | 403 => (ErrorKind::PermissionDenied, false),
+        404 => (ErrorKind::NotFound, false),
+        409 => (ErrorKind::AlreadyExists, false),
+        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let message = serde_json::from_slice::<GarageAdminError>(&bs)
+        .map(|err| format!("{}: {}", err.code, err.message))
+        .unwrap_or_else(|_| String::from_utf8_lossy(&bs).into_owned());
+
+    let mut err = Error::new(kind, message);
+    err = with_error_response_context(err, parts);
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(
+                r#"{"code":"AccessDenied","message":"Forbidden: Invalid bearer token","region":"garage","path":"/v1/bucket"}"#,
+            ))
+            .unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(
+            err.to_string()
+                .contains("AccessDenied: Forbidden: Invalid bearer token")
+        );
+    }
+
+    #[test]
+    fn test_parse_bucket_id() {
+        let resp = Response::builder()
+            .status(StatusCode::OK)
+            .body(Buffer::from(
+                r#"{"id":"96470e0df00ec28807138daf01915cfda2bee8eccc91dea9558c0b4855b5bf95","globalAliases":["test"],"websiteAccess":false,"keys":[],"objects":0,"bytes":0}"#,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            parse_bucket_id(resp).unwrap(),
+            "96470e0df00ec28807138daf01915cfda2bee8eccc91dea9558c0b4855b5bf95"
+        );
+    }
+}
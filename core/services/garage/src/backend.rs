@@ -0,0 +1,394 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use opendal_service_s3::S3;
+
+use super::GARAGE_SCHEME;
+use super::admin::GarageAdmin;
+use super::config::GarageConfig;
+use super::ensure::EnsureBucket;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The region Garage uses unless `s3_region` is changed in `garage.toml`.
+const DEFAULT_REGION: &str = "garage";
+
+/// Garage service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct GarageBuilder {
+    pub(super) config: GarageConfig,
+}
+
+impl Debug for GarageBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GarageBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GarageBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set bucket name of this backend.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.config.bucket = bucket.to_string();
+
+        self
+    }
+
+    /// Set endpoint of Garage's S3 API, for example `http://127.0.0.1:3900`.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = endpoint.trim_end_matches('/').to_string();
+
+        self
+    }
+
+    /// Set region of this backend.
+    ///
+    /// Must match the `s3_region` in `garage.toml`, default to `garage`.
+    pub fn region(mut self, region: &str) -> Self {
+        if !region.is_empty() {
+            self.config.region = Some(region.to_string());
+        }
+
+        self
+    }
+
+    /// Set access_key_id of this backend.
+    pub fn access_key_id(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.access_key_id = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set secret_access_key of this backend.
+    pub fn secret_access_key(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.secret_access_key = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set endpoint of Garage's admin API, for example `http://127.0.0.1:3903`.
+    pub fn admin_endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.admin_endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set the admin token of Garage's admin API.
+    pub fn admin_token(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.admin_token = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Create the bucket via the admin API if it doesn't exist.
+    ///
+    /// Requires `admin_endpoint` and `admin_token`. The access key will be
+    /// granted read and write access to the bucket.
+    pub fn create_bucket(mut self) -> Self {
+        self.config.create_bucket = true;
+
+        self
+    }
+}
+
+impl Builder for GarageBuilder {
+    type Config = GarageConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {self:?}");
+
+        if self.config.bucket.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "bucket is empty")
+                .with_operation("Builder::build")
+                .with_context("service", GARAGE_SCHEME));
+        }
+        if self.config.endpoint.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")
+                .with_operation("Builder::build")
+                .with_context("service", GARAGE_SCHEME));
+        }
+        let region = self.config.region.as_deref().unwrap_or(DEFAULT_REGION);
+        debug!("backend use region: {region}");
+
+        let admin = if self.config.create_bucket {
+            let (Some(endpoint), Some(token)) =
+                (&self.config.admin_endpoint, &self.config.admin_token)
+            else {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "admin_endpoint and admin_token are required to create bucket",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", GARAGE_SCHEME));
+            };
+            Some(Arc::new(GarageAdmin::new(
+                endpoint,
+                token,
+                &self.config.bucket,
+                self.config.access_key_id.as_deref(),
+            )))
+        } else {
+            None
+        };
+
+        // Garage is always reached through an explicit endpoint and region,
+        // and must not pick up AWS credentials or config from the environment.
+        // No checksum algorithm is configured either, since Garage doesn't
+        // support S3's flexible checksum headers.
+        let mut builder = S3::default()
+            .bucket(&self.config.bucket)
+            .endpoint(&self.config.endpoint)
+            .region(region)
+            .disable_config_load()
+            .disable_ec2_metadata();
+        if let Some(root) = &self.config.root {
+            builder = builder.root(root);
+        }
+        if let Some(v) = &self.config.access_key_id {
+            builder = builder.access_key_id(v);
+        }
+        if let Some(v) = &self.config.secret_access_key {
+            builder = builder.secret_access_key(v);
+        }
+
+        let inner = Arc::new(builder.build()?) as Servicer;
+        let info = inner.info();
+        let info = ServiceInfo::new(GARAGE_SCHEME, info.root(), info.name());
+
+        let mut capability = inner.capability();
+        // Garage has no object versioning.
+        capability.stat_with_version = false;
+        capability.read_with_version = false;
+        capability.list_with_versions = false;
+        capability.list_with_deleted = false;
+        capability.delete_with_version = false;
+        capability.copy_with_source_version = false;
+        // Garage doesn't support conditional writes.
+        capability.write_with_if_match = false;
+        capability.write_with_if_not_exists = false;
+        capability.copy_with_if_match = false;
+        capability.copy_with_if_not_exists = false;
+        // Append relies on S3 Express One Zone.
+        capability.write_can_append = false;
+
+        Ok(GarageBackend {
+            inner,
+            info,
+            capability,
+            admin,
+        })
+    }
+}
+
+/// Backend for Garage services.
+///
+/// Garage speaks the S3 API, so all requests are delegated to the s3 service.
+#[derive(Debug, Clone)]
+pub struct GarageBackend {
+    inner: Servicer,
+    info: ServiceInfo,
+    capability: Capability,
+    admin: Option<Arc<GarageAdmin>>,
+}
+
+impl GarageBackend {
+    async fn ensure_bucket(&self, ctx: &OperationContext, op: Operation) -> Result<()> {
+        match &self.admin {
+            Some(admin) => admin.ensure_bucket(ctx, op).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Service for GarageBackend {
+    type Reader = EnsureBucket<oio::Reader>;
+    type Writer = EnsureBucket<oio::Writer>;
+    type Lister = EnsureBucket<oio::Lister>;
+    type Deleter = EnsureBucket<oio::Deleter>;
+    type Copier = EnsureBucket<oio::Copier>;
+
+    fn info(&self) -> ServiceInfo {
+        self.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.ensure_bucket(ctx, Operation::Stat).await?;
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let r = self.inner.read(ctx, path, args)?;
+        Ok(EnsureBucket::new(
+            self.admin.clone(),
+            ctx,
+            Operation::Read,
+            r,
+        ))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(EnsureBucket::new(
+            self.admin.clone(),
+            ctx,
+            Operation::Write,
+            w,
+        ))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        let d = self.inner.delete(ctx)?;
+        Ok(EnsureBucket::new(
+            self.admin.clone(),
+            ctx,
+            Operation::Delete,
+            d,
+        ))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let l = self.inner.list(ctx, path, args)?;
+        Ok(EnsureBucket::new(
+            self.admin.clone(),
+            ctx,
+            Operation::List,
+            l,
+        ))
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        let c = self.inner.copy(ctx, from, to, args, opts)?;
+        Ok(EnsureBucket::new(
+            self.admin.clone(),
+            ctx,
+            Operation::Copy,
+            c,
+        ))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.ensure_bucket(ctx, Operation::Presign).await?;
+        self.inner.presign(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_capability() {
+        let backend = GarageBuilder::default()
+            .bucket("test")
+            .endpoint("http://127.0.0.1:3900")
+            .access_key_id("id")
+            .secret_access_key("secret")
+            .build()
+            .unwrap();
+
+        let info = backend.info();
+        assert_eq!(info.scheme(), GARAGE_SCHEME);
+        assert_eq!(info.name().as_ref(), "test");
+
+        let cap = backend.capability();
+        assert!(!cap.read_with_version);
+        assert!(!cap.write_with_if_not_exists);
+        assert!(!cap.write_can_append);
+        assert!(cap.write_can_multi);
+        assert!(cap.presign);
+    }
+
+    #[test]
+    fn test_build_requires_endpoint() {
+        let err = GarageBuilder::default().bucket("test").build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_create_bucket_requires_admin() {
+        let err = GarageBuilder::default()
+            .bucket("test")
+            .endpoint("http://127.0.0.1:3900")
+            .admin_endpoint("http://127.0.0.1:3903")
+            .create_bucket()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::GarageBuilder;
+
+/// Config for Garage services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct GarageConfig {
+    /// root of this backend.
+    ///
+    /// All operations will happen under this root.
+    ///
+    /// default to `/` if not set.
+    pub root: Option<String>,
+    /// bucket name of this backend.
+    ///
+    /// required.
+    pub bucket: String,
+    /// endpoint of Garage's S3 API, for example `http://127.0.0.1:3900`.
+    ///
+    /// required.
+    pub endpoint: String,
+    /// region of this backend.
+    ///
+    /// Garage rejects signatures made for any region other than the
+    /// `s3_region` in its `garage.toml`.
+    ///
+    /// default to `garage` if not set.
+    pub region: Option<String>,
+    /// access_key_id of this backend.
+    pub access_key_id: Option<String>,
+    /// secret_access_key of this backend.
+    pub secret_access_key: Option<String>,
+    /// endpoint of Garage's admin API, for example `http://127.0.0.1:3903`.
+    ///
+    /// Required if `create_bucket` is enabled.
+    pub admin_endpoint: Option<String>,
+    /// The admin token of Garage's admin API.
+    ///
+    /// Required if `create_bucket` is enabled.
+    pub admin_token: Option<String>,
+    /// Create the bucket via the admin API if it doesn't exist.
+    ///
+    /// The bucket is created before the first operation, and `access_key_id`
+    /// is granted read and write access to it.
+    pub create_bucket: bool,
+}
+
+impl Debug for GarageConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GarageConfig")
+            .field("root", &self.root)
+            .field("bucket", &self.bucket)
+            .field("endpoint", &self.endpoint)
+            .field("region", &self.region)
+            .field("admin_endpoint", &self.admin_endpoint)
+            .field("create_bucket", &self.create_bucket)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for GarageConfig {
+    type Builder = GarageBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(name) = uri.name() {
+            map.insert("bucket".to_string(), name.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        GarageBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_extracts_bucket_root_and_admin_options() {
+        let uri = OperatorUri::new(
+            "garage://example-bucket/path/to/root?endpoint=http://127.0.0.1:3900&admin_endpoint=http://127.0.0.1:3903&create_bucket=true",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = GarageConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.bucket, "example-bucket");
+        assert_eq!(cfg.root.as_deref(), Some("path/to/root"));
+        assert_eq!(cfg.endpoint, "http://127.0.0.1:3900");
+        assert_eq!(cfg.admin_endpoint.as_deref(), Some("http://127.0.0.1:3903"));
+        assert!(cfg.create_bucket);
+    }
+}
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::admin::GarageAdmin;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Wraps the readers, writers and friends returned by the s3 service, so the
+/// bucket is created before they send their first request.
+pub struct EnsureBucket<T> {
+    admin: Option<Arc<GarageAdmin>>,
+    ctx: OperationContext,
+    op: Operation,
+    inner: T,
+}
+
+impl<T> EnsureBucket<T> {
+    pub fn new(
+        admin: Option<Arc<GarageAdmin>>,
+        ctx: &OperationContext,
+        op: Operation,
+        inner: T,
+    ) -> Self {
+        Self {
+            admin,
+            ctx: ctx.clone(),
+            op,
+            inner,
+        }
+    }
+
+    async fn ensure(&self) -> Result<()> {
+        match &self.admin {
+            Some(admin) => admin.ensure_bucket(&self.ctx, self.op).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: oio::Read> oio::Read for EnsureBucket<T> {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        self.ensure().await?;
+        self.inner.open(range).await
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        self.ensure().await?;
+        self.inner.read(range).await
+    }
+}
+
+impl<T: oio::Write> oio::Write for EnsureBucket<T> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.ensure().await?;
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.ensure().await?;
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.inner.suspend().await
+    }
+}
+
+impl<T: oio::List> oio::List for EnsureBucket<T> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        self.ensure().await?;
+        self.inner.next().await
+    }
+}
+
+impl<T: oio::Delete> oio::Delete for EnsureBucket<T> {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.ensure().await?;
+        self.inner.delete(path, args).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.ensure().await?;
+        self.inner.close().await
+    }
+}
+
+impl<T: oio::Copy> oio::Copy for EnsureBucket<T> {
+    async fn next(&mut self) -> Result<Option<usize>> {
+        self.ensure().await?;
+        self.inner.next().await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.ensure().await?;
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
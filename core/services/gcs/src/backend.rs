@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use http::StatusCode;
 use log::debug;
 use reqsign_core::Context;
 use reqsign_core::Env as _;
@@ -44,7 +45,9 @@ use super::core::parse_error;
 use super::core::*;
 use super::deleter::GcsDeleter;
 use super::lister::GcsLister;
+use super::lister::build_match_glob;
 use super::reader::*;
+use super::writer::GcsResumableWriter;
 use super::writer::GcsWriter;
 use super::writer::GcsWriters;
 use opendal_core::raw::*;
@@ -189,6 +192,16 @@ impl GcsBuilder {
         self
     }
 
+    /// Upload multipart writes with resumable upload sessions.
+    ///
+    /// Checkpoints returned by `Writer::suspend` will carry the session URI
+    /// as upload id, pass it to `write_with().checkpoint()` to resume the
+    /// upload on another worker.
+    pub fn enable_resumable_upload(mut self) -> Self {
+        self.config.enable_resumable_upload = true;
+        self
+    }
+
     /// Set the predefined acl for GCS.
     ///
     /// Available values are:
@@ -219,6 +232,38 @@ impl GcsBuilder {
         self
     }
 
+    /// Set the base64 encoded AES-256 customer-supplied encryption key (CSEK).
+    ///
+    /// All objects are written, read, and copied with this key, objects not
+    /// encrypted by it can't be accessed.
+    pub fn customer_encryption_key(mut self, key: &str) -> Self {
+        if !key.is_empty() {
+            self.config.customer_encryption_key = Some(key.to_string())
+        };
+        self
+    }
+
+    /// Set the Cloud KMS key (CMEK) used to encrypt written objects, like
+    /// `projects/my-project/locations/us/keyRings/my-ring/cryptoKeys/my-key`.
+    pub fn kms_key_name(mut self, name: &str) -> Self {
+        if !name.is_empty() {
+            self.config.kms_key_name = Some(name.to_string())
+        };
+        self
+    }
+
+    /// Set how `create_dir` handles directory marker objects.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` object so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    pub fn dir_marker(mut self, mode: &str) -> Self {
+        if !mode.is_empty() {
+            self.config.dir_marker = Some(mode.to_string())
+        };
+        self
+    }
+
     /// Skip signature will skip loading credentials and signing requests.
     ///
     /// This is typically used for buckets which are open to the public or GCS
@@ -260,7 +305,20 @@ impl Builder for GcsBuilder {
             ),
         }?;
 
-        // TODO: server side encryption
+        if self.config.customer_encryption_key.is_some() && self.config.kms_key_name.is_some() {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "customer_encryption_key and kms_key_name can't be used together",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", GCS_SCHEME));
+        }
+        let customer_encryption = self
+            .config
+            .customer_encryption_key
+            .as_deref()
+            .map(GcsCustomerEncryption::new)
+            .transpose()?;
 
         let endpoint = self
             .config
@@ -338,17 +396,32 @@ impl Builder for GcsBuilder {
             RequestSigner::new("storage").with_scope(&scope),
         );
 
+        let (create_dir, create_dir_persistent) = match self.config.dir_marker.as_deref() {
+            Some("force") => (true, true),
+            Some("skip") => (true, false),
+            None => (false, false),
+            v => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    format!("{v:?} is not a supported dir_marker."),
+                )
+                .with_context("service", GCS_SCHEME));
+            }
+        };
+
         let info = ServiceInfo::new(GCS_SCHEME, &root, bucket);
         let capability = Capability {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_version: true,
 
             read: true,
             read_with_suffix: true,
 
             read_with_if_match: true,
             read_with_if_none_match: true,
+            read_with_version: true,
 
             write: true,
             write_can_empty: true,
@@ -357,7 +430,12 @@ impl Builder for GcsBuilder {
             write_with_content_type: true,
             write_with_content_encoding: true,
             write_with_user_metadata: true,
+            write_with_checkpoint: true,
             write_with_if_not_exists: true,
+            write_with_if_match: true,
+
+            create_dir,
+            create_dir_persistent,
 
             // The min multipart size of Gcs is 5 MiB.
             //
@@ -374,6 +452,7 @@ impl Builder for GcsBuilder {
 
             delete: true,
             delete_max_size: Some(100),
+            delete_with_version: true,
 
             copy: true,
             copy_can_multi: true,
@@ -388,11 +467,15 @@ impl Builder for GcsBuilder {
             list_with_limit: true,
             list_with_start_after: true,
             list_with_recursive: true,
+            list_with_suffix: true,
+            list_with_glob: true,
+            list_with_versions: true,
 
             presign: true,
             presign_stat: true,
             presign_read: true,
             presign_write: true,
+            presign_multipart: true,
 
             shared: true,
 
@@ -410,7 +493,10 @@ impl Builder for GcsBuilder {
                 sign_ctx,
                 predefined_acl: self.config.predefined_acl.clone(),
                 default_storage_class: self.config.default_storage_class.clone(),
+                customer_encryption,
+                kms_key_name: self.config.kms_key_name.clone(),
                 skip_signature,
+                enable_resumable_upload: self.config.enable_resumable_upload,
             }),
         };
 
@@ -441,14 +527,36 @@ impl Service for GcsBackend {
 
     async fn create_dir(
         &self,
-        _ctx: &OperationContext,
-        _path: &str,
+        ctx: &OperationContext,
+        path: &str,
         _args: OpCreateDir,
     ) -> Result<RpCreateDir> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        if !self.core.capability.create_dir {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        // Without marker objects, the directory appears once it has children.
+        if !self.core.capability.create_dir_persistent {
+            return Ok(RpCreateDir::default());
+        }
+
+        let req = self.core.gcs_insert_object_request(
+            &gcs_percent_encode_path(path),
+            Some(0),
+            &OpWrite::default(),
+            None,
+            Buffer::new(),
+        )?;
+        let req = self.core.sign(ctx, req).await?;
+        let resp = self.core.send(ctx, req).await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpCreateDir::default()),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
@@ -479,12 +587,20 @@ impl Service for GcsBackend {
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: GcsWriters = {
             let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
+            let checkpoint = args.checkpoint().cloned();
             let w = GcsWriter::new(self.core.clone(), ctx.clone(), path, args);
+            if self.core.enable_resumable_upload {
+                return Ok(TwoWays::Two(GcsResumableWriter::new(w)));
+            }
+
             // Multipart uploads schedule work through the operation executor
             // supplied by the caller.
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), w, concurrent);
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), w, concurrent)
+                .with_checkpoint(checkpoint)
+                .with_adaptive_concurrency(adaptive);
 
-            Ok(w)
+            Ok(TwoWays::One(w))
         }?;
 
         Ok(output)
@@ -510,6 +626,8 @@ impl Service for GcsBackend {
                 args.recursive(),
                 args.limit(),
                 args.start_after(),
+                build_match_glob(&self.core.root, path, &args),
+                args.versions(),
             );
 
             Ok(oio::PageLister::new(l))
@@ -563,6 +681,27 @@ impl Service for GcsBackend {
                 self.core
                     .gcs_insert_object_xml_request(path, v, Buffer::new())
             }
+            PresignOperation::CreateMultipart(v) => {
+                // Presigned requests can't resolve the generation of `if_match`.
+                self.core.gcs_initiate_multipart_upload_request(
+                    path,
+                    v,
+                    v.if_not_exists().then_some("0"),
+                )
+            }
+            PresignOperation::UploadPart {
+                upload_id,
+                part_number,
+            } => self.core.gcs_upload_part_request(
+                path,
+                upload_id,
+                *part_number,
+                None,
+                Buffer::new(),
+            ),
+            PresignOperation::CompleteMultipart { upload_id } => self
+                .core
+                .gcs_complete_multipart_upload_request(path, upload_id, Buffer::new()),
             PresignOperation::Delete(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
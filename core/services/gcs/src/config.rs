@@ -79,6 +79,20 @@ pub struct GcsConfig {
     ///
     /// <!-- @group Behavior -->
     pub default_storage_class: Option<String>,
+    /// The base64 encoded AES-256 customer-supplied encryption key (CSEK).
+    ///
+    /// All objects are written, read, and copied with this key, objects not
+    /// encrypted by it can't be accessed.
+    ///
+    /// <!-- @group Encryption -->
+    pub customer_encryption_key: Option<String>,
+    /// The Cloud KMS key (CMEK) used to encrypt written objects, like
+    /// `projects/my-project/locations/us/keyRings/my-ring/cryptoKeys/my-key`.
+    ///
+    /// Can't be used together with `customer_encryption_key`.
+    ///
+    /// <!-- @group Encryption -->
+    pub kms_key_name: Option<String>,
     /// Skip signature will skip loading credentials and signing requests.
     ///
     /// <!-- @group Credentials -->
@@ -108,6 +122,24 @@ pub struct GcsConfig {
     ///
     /// <!-- @group Credentials -->
     pub token: Option<String>,
+    /// Upload multipart writes with resumable upload sessions instead of XML
+    /// multipart uploads.
+    ///
+    /// The session URI is exposed as the upload id of the checkpoint returned
+    /// by `Writer::suspend`, and could be resumed from other processes.
+    ///
+    /// <!-- @group Behavior -->
+    pub enable_resumable_upload: bool,
+    /// How `create_dir` handles directory marker objects.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` object so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    ///
+    /// By default, the marker object is written by `SimulateLayer`.
+    ///
+    /// <!-- @group Behavior -->
+    pub dir_marker: Option<String>,
 }
 
 impl Debug for GcsConfig {
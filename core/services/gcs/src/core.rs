@@ -19,15 +19,20 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Write;
 
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
 use bytes::Buf;
 use bytes::Bytes;
 use constants::*;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
 use http::header::HOST;
 use http::header::IF_MATCH;
@@ -38,10 +43,14 @@ use reqsign_core::{Context, Signer};
 use reqsign_google::Credential;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 
 use opendal_core::raw::*;
 use opendal_core::*;
 
+use super::GCS_SCHEME;
+
 pub mod constants {
     pub const GCS_REWRITE_MIN_CHUNK_SIZE: usize = 1024 * 1024;
     #[cfg(target_pointer_width = "64")]
@@ -54,6 +63,60 @@ pub mod constants {
     pub const X_GOOG_ACL: &str = "x-goog-acl";
     pub const X_GOOG_STORAGE_CLASS: &str = "x-goog-storage-class";
     pub const X_GOOG_META_PREFIX: &str = "x-goog-meta-";
+    pub const X_GOOG_HASH: &str = "x-goog-hash";
+    pub const X_GOOG_GENERATION: &str = "x-goog-generation";
+    pub const X_GOOG_IF_GENERATION_MATCH: &str = "x-goog-if-generation-match";
+    pub const X_GOOG_ENCRYPTION_ALGORITHM: &str = "x-goog-encryption-algorithm";
+    pub const X_GOOG_ENCRYPTION_KEY: &str = "x-goog-encryption-key";
+    pub const X_GOOG_ENCRYPTION_KEY_SHA256: &str = "x-goog-encryption-key-sha256";
+    pub const X_GOOG_ENCRYPTION_KMS_KEY_NAME: &str = "x-goog-encryption-kms-key-name";
+    pub const X_GOOG_COPY_SOURCE_ENCRYPTION_ALGORITHM: &str =
+        "x-goog-copy-source-encryption-algorithm";
+    pub const X_GOOG_COPY_SOURCE_ENCRYPTION_KEY: &str = "x-goog-copy-source-encryption-key";
+    pub const X_GOOG_COPY_SOURCE_ENCRYPTION_KEY_SHA256: &str =
+        "x-goog-copy-source-encryption-key-sha256";
+
+    /// The only algorithm supported by customer-supplied encryption keys.
+    pub const GCS_CUSTOMER_ENCRYPTION_ALGORITHM: &str = "AES256";
+}
+
+/// The customer-supplied encryption key (CSEK) sent along with requests.
+///
+/// Reference: <https://cloud.google.com/storage/docs/encryption/customer-supplied-keys>
+#[derive(Clone, Debug)]
+pub struct GcsCustomerEncryption {
+    key: HeaderValue,
+    key_sha256: HeaderValue,
+}
+
+impl GcsCustomerEncryption {
+    /// Build from the base64 encoded AES-256 key.
+    pub fn new(key: &str) -> Result<Self> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "customer_encryption_key must be a base64 encoded 256 bits key",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", GCS_SCHEME)
+        };
+
+        let raw = BASE64_STANDARD
+            .decode(key.trim())
+            .map_err(|err| invalid().set_source(err))?;
+        if raw.len() != 32 {
+            return Err(invalid());
+        }
+
+        let mut key = HeaderValue::from_str(&BASE64_STANDARD.encode(&raw))
+            .expect("base64 must be valid header value");
+        key.set_sensitive(true);
+        let mut key_sha256 = HeaderValue::from_str(&BASE64_STANDARD.encode(Sha256::digest(&raw)))
+            .expect("base64 must be valid header value");
+        key_sha256.set_sensitive(true);
+
+        Ok(Self { key, key_sha256 })
+    }
 }
 
 pub struct GcsCore {
@@ -68,8 +131,11 @@ pub struct GcsCore {
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
+    pub customer_encryption: Option<GcsCustomerEncryption>,
+    pub kms_key_name: Option<String>,
 
     pub skip_signature: bool,
+    pub enable_resumable_upload: bool,
 }
 
 impl Debug for GcsCore {
@@ -83,6 +149,43 @@ impl Debug for GcsCore {
 }
 
 impl GcsCore {
+    /// Insert the customer-supplied encryption key headers if configured.
+    pub fn insert_encryption_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.customer_encryption {
+            req = req
+                .header(
+                    X_GOOG_ENCRYPTION_ALGORITHM,
+                    GCS_CUSTOMER_ENCRYPTION_ALGORITHM,
+                )
+                .header(X_GOOG_ENCRYPTION_KEY, v.key.clone())
+                .header(X_GOOG_ENCRYPTION_KEY_SHA256, v.key_sha256.clone());
+        }
+        req
+    }
+
+    /// Insert the customer-supplied encryption key headers of the copy source if configured.
+    pub fn insert_copy_source_encryption_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.customer_encryption {
+            req = req
+                .header(
+                    X_GOOG_COPY_SOURCE_ENCRYPTION_ALGORITHM,
+                    GCS_CUSTOMER_ENCRYPTION_ALGORITHM,
+                )
+                .header(X_GOOG_COPY_SOURCE_ENCRYPTION_KEY, v.key.clone())
+                .header(
+                    X_GOOG_COPY_SOURCE_ENCRYPTION_KEY_SHA256,
+                    v.key_sha256.clone(),
+                );
+        }
+        req
+    }
+
     fn signer(&self, ctx: &OperationContext) -> Signer<Credential> {
         self.signer.clone().with_context(
             self.sign_ctx
@@ -162,13 +265,19 @@ impl GcsCore {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
-            "{}/storage/v1/b/{}/o/{}?alt=media",
+            "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             gcs_percent_encode_path(&p)
         );
 
-        let mut req = Request::get(&url);
+        let mut url = QueryPairsWriter::new(&url).push("alt", "media");
+        if let Some(version) = args.version() {
+            url = url.push("generation", &gcs_percent_encode_path(version));
+        }
+
+        let mut req = Request::get(url.finish());
+        req = self.insert_encryption_headers(req);
 
         if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
@@ -200,7 +309,12 @@ impl GcsCore {
 
         let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
 
-        let mut req = Request::get(&url);
+        let mut url = QueryPairsWriter::new(&url);
+        if let Some(version) = args.version() {
+            url = url.push("generation", &gcs_percent_encode_path(version));
+        }
+
+        let mut req = Request::get(url.finish());
 
         if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
@@ -247,6 +361,7 @@ impl GcsCore {
         path: &str,
         size: Option<u64>,
         op: &OpWrite,
+        if_generation_match: Option<&str>,
         body: Buffer,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
@@ -278,13 +393,23 @@ impl GcsCore {
         // Makes the operation conditional on whether the object's current generation
         // matches the given value. Setting to 0 makes the operation succeed only if
         // there are no live versions of the object.
-        if op.if_not_exists() {
-            write!(&mut url, "&ifGenerationMatch=0").unwrap();
+        if let Some(generation) = if_generation_match {
+            write!(&mut url, "&ifGenerationMatch={generation}").unwrap();
+        }
+
+        if let Some(kms_key_name) = &self.kms_key_name {
+            write!(
+                &mut url,
+                "&kmsKeyName={}",
+                gcs_percent_encode_path(kms_key_name)
+            )
+            .unwrap();
         }
 
         let mut req = Request::post(&url);
 
         req = req.header(CONTENT_LENGTH, size.unwrap_or_default());
+        req = self.insert_encryption_headers(req);
 
         if request_metadata.is_empty() {
             let req = req
@@ -320,7 +445,7 @@ impl GcsCore {
             multipart = multipart.part(media_part);
 
             let req = multipart.apply(
-                Request::post(url)
+                self.insert_encryption_headers(Request::post(url))
                     .extension(Operation::Write)
                     .extension(ServiceOperation("InsertObject")),
             )?;
@@ -387,7 +512,13 @@ impl GcsCore {
             gcs_percent_encode_path(&p)
         );
 
-        let mut req = Request::get(&url);
+        let mut url = QueryPairsWriter::new(&url);
+        if let Some(version) = args.version() {
+            url = url.push("generation", &gcs_percent_encode_path(version));
+        }
+
+        let mut req = Request::get(url.finish());
+        req = self.insert_encryption_headers(req);
 
         if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
@@ -416,7 +547,12 @@ impl GcsCore {
 
         let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
 
-        let mut req = Request::head(&url);
+        let mut url = QueryPairsWriter::new(&url);
+        if let Some(version) = args.version() {
+            url = url.push("generation", &gcs_percent_encode_path(version));
+        }
+
+        let mut req = Request::head(url.finish());
 
         if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
@@ -435,6 +571,56 @@ impl GcsCore {
         Ok(req)
     }
 
+    /// Resolve the generation precondition of a write.
+    ///
+    /// Uploads don't support `If-Match`, so `if_match` is resolved to the
+    /// generation of the object with that etag. The upload is then conditional
+    /// on that generation, which fails if the object is changed after the stat.
+    pub async fn gcs_write_generation_match(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        op: &OpWrite,
+    ) -> Result<Option<String>> {
+        if op.if_not_exists() {
+            return Ok(Some("0".to_string()));
+        }
+        let Some(if_match) = op.if_match() else {
+            return Ok(None);
+        };
+
+        let resp = self
+            .gcs_get_object_metadata(ctx, path, &OpStat::new())
+            .await?;
+        let meta = match resp.status() {
+            status if status.is_success() => {
+                Self::build_metadata_from_object_response(path, resp.into_body())?
+            }
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "object doesn't exist for if_match",
+                ));
+            }
+            _ => return Err(parse_error(resp)),
+        };
+
+        if meta.etag().map(|v| v.trim_matches('"')) != Some(if_match.trim_matches('"')) {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "etag of object doesn't match if_match",
+            )
+            .with_context("etag", meta.etag().unwrap_or_default()));
+        }
+
+        meta.version().map(|v| Some(v.to_string())).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "generation not present in returning response",
+            )
+        })
+    }
+
     pub async fn gcs_get_object_metadata(
         &self,
         ctx: &OperationContext,
@@ -452,14 +638,19 @@ impl GcsCore {
         &self,
         ctx: &OperationContext,
         path: &str,
+        args: &OpDelete,
     ) -> Result<Response<Buffer>> {
-        let req = self.gcs_delete_object_request(path)?;
+        let req = self.gcs_delete_object_request(path, args)?;
 
         let req = self.sign(ctx, req).await?;
         self.send(ctx, req).await
     }
 
-    pub fn gcs_delete_object_request(&self, path: &str) -> Result<Request<Buffer>> {
+    pub fn gcs_delete_object_request(
+        &self,
+        path: &str,
+        args: &OpDelete,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -469,7 +660,12 @@ impl GcsCore {
             gcs_percent_encode_path(&p)
         );
 
-        Request::delete(&url)
+        let mut url = QueryPairsWriter::new(&url);
+        if let Some(version) = args.version() {
+            url = url.push("generation", &gcs_percent_encode_path(version));
+        }
+
+        Request::delete(url.finish())
             .extension(Operation::Delete)
             .extension(ServiceOperation("DeleteObject"))
             .body(Buffer::new())
@@ -479,14 +675,14 @@ impl GcsCore {
     pub async fn gcs_delete_objects(
         &self,
         ctx: &OperationContext,
-        paths: Vec<String>,
+        batch: &[(String, OpDelete)],
     ) -> Result<Response<Buffer>> {
         let uri = format!("{}/batch/storage/v1", self.endpoint);
 
         let mut multipart = Multipart::new();
 
-        for (idx, path) in paths.iter().enumerate() {
-            let req = self.gcs_delete_object_request(path)?;
+        for (idx, (path, args)) in batch.iter().enumerate() {
+            let req = self.gcs_delete_object_request(path, args)?;
 
             multipart = multipart.part(
                 MixedPart::from_request(req).part_header("content-id".parse().unwrap(), idx.into()),
@@ -537,8 +733,18 @@ impl GcsCore {
         if let Some(token) = rewrite_token {
             url = url.push("rewriteToken", &gcs_percent_encode_path(token));
         }
+        if let Some(kms_key_name) = &self.kms_key_name {
+            url = url.push(
+                "destinationKmsKeyName",
+                &gcs_percent_encode_path(kms_key_name),
+            );
+        }
 
-        let req = Request::post(url.finish())
+        let mut req = Request::post(url.finish());
+        req = self.insert_encryption_headers(req);
+        req = self.insert_copy_source_encryption_headers(req);
+
+        let req = req
             .header(CONTENT_LENGTH, 0)
             .extension(Operation::Copy)
             .extension(ServiceOperation("RewriteObject"))
@@ -549,6 +755,7 @@ impl GcsCore {
         self.send(ctx, req).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn gcs_list_objects(
         &self,
         ctx: &OperationContext,
@@ -557,6 +764,8 @@ impl GcsCore {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<String>,
+        match_glob: Option<&str>,
+        versions: bool,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -575,6 +784,12 @@ impl GcsCore {
             let start_after = build_abs_path(&self.root, &start_after);
             url = url.push("startOffset", &gcs_percent_encode_path(&start_after));
         }
+        if let Some(match_glob) = match_glob {
+            url = url.push("matchGlob", &gcs_percent_encode_path(match_glob));
+        }
+        if versions {
+            url = url.push("versions", "true");
+        }
 
         if !page_token.is_empty() {
             // NOTE:
@@ -597,12 +812,12 @@ impl GcsCore {
         self.send(ctx, req).await
     }
 
-    pub async fn gcs_initiate_multipart_upload(
+    pub fn gcs_initiate_multipart_upload_request(
         &self,
-        ctx: &OperationContext,
         path: &str,
         op: &OpWrite,
-    ) -> Result<Response<Buffer>> {
+        if_generation_match: Option<&str>,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}/{}?uploads", self.endpoint, self.bucket, p);
@@ -642,23 +857,39 @@ impl GcsCore {
             }
         }
 
-        let req = builder
-            .body(Buffer::new())
-            .map_err(new_request_build_error)?;
+        if let Some(generation) = if_generation_match {
+            builder = builder.header(X_GOOG_IF_GENERATION_MATCH, generation);
+        }
+
+        if let Some(kms_key_name) = &self.kms_key_name {
+            builder = builder.header(X_GOOG_ENCRYPTION_KMS_KEY_NAME, kms_key_name);
+        }
+        builder = self.insert_encryption_headers(builder);
+
+        builder.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_initiate_multipart_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        op: &OpWrite,
+        if_generation_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let req = self.gcs_initiate_multipart_upload_request(path, op, if_generation_match)?;
 
         let req = self.sign(ctx, req).await?;
         self.send(ctx, req).await
     }
 
-    pub async fn gcs_upload_part(
+    pub fn gcs_upload_part_request(
         &self,
-        ctx: &OperationContext,
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -672,25 +903,43 @@ impl GcsCore {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+        req = self.insert_encryption_headers(req);
 
         let req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("UploadPart"));
 
-        let req = req.body(body).map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_upload_part(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let req = self.gcs_upload_part_request(path, upload_id, part_number, Some(size), body)?;
 
         let req = self.sign(ctx, req).await?;
         self.send(ctx, req).await
     }
 
-    pub async fn gcs_complete_multipart_upload(
+    /// Build the CompleteMultipartUpload request with the serialized parts list as body.
+    ///
+    /// An empty body leaves `Content-Length` unset so that the request can be presigned
+    /// before the parts list is known.
+    pub fn gcs_complete_multipart_upload_request(
         &self,
-        ctx: &OperationContext,
         path: &str,
         upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
-    ) -> Result<Response<Buffer>> {
+        body: Buffer,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -701,12 +950,12 @@ impl GcsCore {
             gcs_percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        let mut req = Request::post(&url);
 
-        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
-            .map_err(new_xml_serialize_error)?;
         // Make sure content length has been set to avoid post with chunked encoding.
-        let req = req.header(CONTENT_LENGTH, content.len());
+        if !body.is_empty() {
+            req = req.header(CONTENT_LENGTH, body.len());
+        }
         // Set content-type to `application/xml` to avoid mixed with form post.
         let req = req.header(CONTENT_TYPE, "application/xml");
 
@@ -714,9 +963,23 @@ impl GcsCore {
             .extension(Operation::Write)
             .extension(ServiceOperation("CompleteMultipartUpload"));
 
-        let req = req
-            .body(Buffer::from(Bytes::from(content)))
-            .map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_complete_multipart_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<CompleteMultipartUploadRequestPart>,
+    ) -> Result<Response<Buffer>> {
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
+            .map_err(new_xml_serialize_error)?;
+        let req = self.gcs_complete_multipart_upload_request(
+            path,
+            upload_id,
+            Buffer::from(Bytes::from(content)),
+        )?;
 
         let req = self.sign(ctx, req).await?;
         self.send(ctx, req).await
@@ -747,6 +1010,140 @@ impl GcsCore {
         self.send(ctx, req).await
     }
 
+    pub fn gcs_initiate_resumable_upload_request(
+        &self,
+        path: &str,
+        op: &OpWrite,
+        if_generation_match: Option<&str>,
+    ) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let request_metadata = InsertRequestMetadata {
+            storage_class: self.default_storage_class.as_deref(),
+            cache_control: op.cache_control(),
+            content_type: op.content_type(),
+            content_encoding: op.content_encoding(),
+            metadata: op.user_metadata(),
+        };
+
+        let mut url = format!(
+            "{}/upload/storage/v1/b/{}/o?uploadType=resumable&name={}",
+            self.endpoint,
+            self.bucket,
+            gcs_percent_encode_path(&p)
+        );
+
+        if let Some(acl) = &self.predefined_acl {
+            write!(&mut url, "&predefinedAcl={acl}").unwrap();
+        }
+
+        if let Some(generation) = if_generation_match {
+            write!(&mut url, "&ifGenerationMatch={generation}").unwrap();
+        }
+
+        if let Some(kms_key_name) = &self.kms_key_name {
+            write!(
+                &mut url,
+                "&kmsKeyName={}",
+                gcs_percent_encode_path(kms_key_name)
+            )
+            .unwrap();
+        }
+
+        let body = if request_metadata.is_empty() {
+            Buffer::new()
+        } else {
+            Buffer::from(
+                serde_json::to_vec(&request_metadata)
+                    .expect("metadata serialization should succeed"),
+            )
+        };
+
+        let mut req = Request::post(&url).header(CONTENT_LENGTH, body.len());
+        req = self.insert_encryption_headers(req);
+        if !body.is_empty() {
+            req = req.header(CONTENT_TYPE, "application/json; charset=UTF-8");
+        }
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("InitiateResumableUpload"));
+
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_initiate_resumable_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        op: &OpWrite,
+        if_generation_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let req = self.gcs_initiate_resumable_upload_request(path, op, if_generation_match)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    /// Build the request to upload a chunk starting at `offset` to the session.
+    ///
+    /// `total` is the size of the whole object, which must be set on the last
+    /// chunk to finish the upload.
+    pub fn gcs_upload_resumable_chunk_request(
+        &self,
+        session_uri: &str,
+        offset: u64,
+        total: Option<u64>,
+        body: Buffer,
+    ) -> Result<Request<Buffer>> {
+        let size = body.len() as u64;
+        let total = total.map_or("*".to_string(), |v| v.to_string());
+        let content_range = if size == 0 {
+            format!("bytes */{total}")
+        } else {
+            format!("bytes {}-{}/{total}", offset, offset + size - 1)
+        };
+
+        let req = self
+            .insert_encryption_headers(Request::put(session_uri))
+            .header(CONTENT_LENGTH, size)
+            .header(CONTENT_RANGE, content_range)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("UploadResumableChunk"));
+
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_upload_resumable_chunk(
+        &self,
+        ctx: &OperationContext,
+        session_uri: &str,
+        offset: u64,
+        total: Option<u64>,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let req = self.gcs_upload_resumable_chunk_request(session_uri, offset, total, body)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
+    pub async fn gcs_cancel_resumable_upload(
+        &self,
+        ctx: &OperationContext,
+        session_uri: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = Request::delete(session_uri)
+            .header(CONTENT_LENGTH, 0)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("CancelResumableUpload"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
     pub fn build_metadata_from_object_response(path: &str, data: Buffer) -> Result<Metadata> {
         let meta: GetObjectJsonResponse =
             serde_json::from_reader(data.reader()).map_err(new_json_deserialize_error)?;
@@ -762,6 +1159,13 @@ impl GetObjectJsonResponse {
         m.set_etag(&self.etag);
         m.set_content_md5(&self.md5_hash);
 
+        // Composite objects only have crc32c, so prefer it over md5.
+        if !self.crc32c.is_empty() {
+            m.set_checksum(Checksum::new(ChecksumAlgorithm::Crc32c, &self.crc32c));
+        } else if !self.md5_hash.is_empty() {
+            m.set_checksum(Checksum::new(ChecksumAlgorithm::Md5, &self.md5_hash));
+        }
+
         let size = self
             .size
             .parse::<u64>()
@@ -800,6 +1204,26 @@ impl GetObjectJsonResponse {
 }
 
 // https://cloud.google.com/storage/docs/xml-api/reference-headers#xgoogacl
+/// Parse checksum from `x-goog-hash` headers like `crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ==`.
+///
+/// GCS returns hashes of the whole object even for range requests.
+pub fn parse_goog_hash(headers: &http::HeaderMap) -> Option<Checksum> {
+    let mut md5 = None;
+    for value in headers.get_all(X_GOOG_HASH) {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        for hash in value.split(',') {
+            match hash.trim().split_once('=') {
+                Some(("crc32c", v)) => return Some(Checksum::new(ChecksumAlgorithm::Crc32c, v)),
+                Some(("md5", v)) => md5 = Some(Checksum::new(ChecksumAlgorithm::Md5, v)),
+                _ => {}
+            }
+        }
+    }
+    md5
+}
+
 fn predefined_acl_to_xml_header(predefined_acl: &str) -> Option<&'static str> {
     match predefined_acl {
         "projectPrivate" => Some("project-private"),
@@ -865,6 +1289,10 @@ pub struct ListResponseItem {
     pub md5_hash: String,
     pub updated: String,
     pub content_type: String,
+    /// Content generation of this object.
+    pub generation: String,
+    /// Set if this version is no longer the live version of the object.
+    pub time_deleted: Option<String>,
 }
 
 /// Result of CreateMultipartUpload
@@ -928,6 +1356,10 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"md5Hash": "fHcEH1vPwA6eTPqxuasXcg=="`
     md5_hash: String,
+    /// Content crc32c checksum
+    ///
+    /// For example: `"crc32c": "j/un9g=="`
+    crc32c: String,
     /// Content type of this object.
     ///
     /// For example: `"contentType": "image/png",`
@@ -952,6 +1384,27 @@ struct GetObjectJsonResponse {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_customer_encryption() {
+        let encryption =
+            GcsCustomerEncryption::new("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap();
+        assert_eq!(
+            encryption.key,
+            "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+        );
+        assert_eq!(
+            encryption.key_sha256,
+            "Yw3NKWbEM2aRElRIu7JbT/QSpJxzLbLIq8G4WBvXEN0="
+        );
+        assert!(encryption.key.is_sensitive());
+
+        // 128 bits key is not allowed.
+        let err = GcsCustomerEncryption::new("AAECAwQFBgcICQoLDA0ODw==").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        let err = GcsCustomerEncryption::new("not base64").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
     #[test]
     fn test_deserialize_get_object_json_response() {
         let content = r#"{
@@ -993,6 +1446,10 @@ mod tests {
             )
         );
         assert_eq!(meta.content_md5(), Some("fHcEH1vPwA6eTPqxuasXcg=="));
+        assert_eq!(
+            meta.checksum(),
+            Some(&Checksum::new(ChecksumAlgorithm::Crc32c, "j/un9g=="))
+        );
         assert_eq!(meta.etag(), Some("CKWasoTgyPkCEAE="));
         assert_eq!(meta.content_type(), Some("image/png"));
         assert_eq!(meta.content_encoding(), Some("br"));
@@ -1004,6 +1461,39 @@ mod tests {
         assert_eq!(meta.user_metadata(), Some(&metadata));
     }
 
+    #[test]
+    fn test_parse_goog_hash() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(parse_goog_hash(&headers), None);
+
+        headers.append(X_GOOG_HASH, "md5=Ojk9c3dhfxgoKVVHYwFbHQ==".parse().unwrap());
+        assert_eq!(
+            parse_goog_hash(&headers),
+            Some(Checksum::new(
+                ChecksumAlgorithm::Md5,
+                "Ojk9c3dhfxgoKVVHYwFbHQ=="
+            ))
+        );
+
+        headers.append(X_GOOG_HASH, "crc32c=n03x6A==".parse().unwrap());
+        assert_eq!(
+            parse_goog_hash(&headers),
+            Some(Checksum::new(ChecksumAlgorithm::Crc32c, "n03x6A=="))
+        );
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            X_GOOG_HASH,
+            "crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ=="
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            parse_goog_hash(&headers),
+            Some(Checksum::new(ChecksumAlgorithm::Crc32c, "n03x6A=="))
+        );
+    }
+
     #[test]
     fn test_deserialize_list_response() {
         let content = r#"
@@ -1071,9 +1561,48 @@ mod tests {
         assert_eq!(output.items[1].etag, "CIm0s4TgyPkCEAE=");
         assert_eq!(output.items[1].updated, "2022-08-15T11:33:34.886Z");
         assert_eq!(output.items[1].content_type, "image/png");
+        assert_eq!(output.items[1].generation, "1660563214883337");
+        assert_eq!(output.items[1].time_deleted, None);
         assert_eq!(output.prefixes, vec!["dir/", "test/"])
     }
 
+    #[test]
+    fn test_deserialize_list_response_with_versions() {
+        let content = r#"
+    {
+  "kind": "storage#objects",
+  "items": [
+    {
+      "kind": "storage#object",
+      "name": "1.png",
+      "generation": "1660563214863653",
+      "size": "56535",
+      "updated": "2022-08-15T11:33:34.866Z",
+      "timeDeleted": "2022-08-16T11:33:34.866Z"
+    },
+    {
+      "kind": "storage#object",
+      "name": "1.png",
+      "generation": "1660563214883337",
+      "size": "45506",
+      "updated": "2022-08-16T11:33:34.866Z"
+    }
+  ]
+}
+    "#;
+
+        let output: ListResponse =
+            serde_json::from_str(content).expect("JSON deserialize must succeed");
+        assert_eq!(output.items.len(), 2);
+        assert_eq!(output.items[0].generation, "1660563214863653");
+        assert_eq!(
+            output.items[0].time_deleted.as_deref(),
+            Some("2022-08-16T11:33:34.866Z")
+        );
+        assert_eq!(output.items[1].generation, "1660563214883337");
+        assert_eq!(output.items[1].time_deleted, None);
+    }
+
     #[test]
     fn test_deserialize_list_response_with_next_page_token() {
         let content = r#"
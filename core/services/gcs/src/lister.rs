@@ -37,10 +37,15 @@ pub struct GcsLister {
     /// Filter results to objects whose names are lexicographically
     /// **equal to or after** startOffset
     start_after: Option<String>,
+    /// Filter results to objects whose names match this glob pattern.
+    match_glob: Option<String>,
+    /// Include noncurrent versions of objects.
+    versions: bool,
 }
 
 impl GcsLister {
     /// Generate a new directory walker
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         core: Arc<GcsCore>,
         ctx: OperationContext,
@@ -48,6 +53,8 @@ impl GcsLister {
         recursive: bool,
         limit: Option<usize>,
         start_after: Option<&str>,
+        match_glob: Option<String>,
+        versions: bool,
     ) -> Self {
         let delimiter = if recursive { "" } else { "/" };
         Self {
@@ -58,6 +65,8 @@ impl GcsLister {
             delimiter,
             limit,
             start_after: start_after.map(String::from),
+            match_glob,
+            versions,
         }
     }
 }
@@ -77,6 +86,8 @@ impl oio::PageList for GcsLister {
                 } else {
                     None
                 },
+                self.match_glob.as_deref(),
+                self.versions,
             )
             .await?;
 
@@ -128,6 +139,12 @@ impl oio::PageList for GcsLister {
             }
 
             meta.set_last_modified(object.updated.parse::<Timestamp>()?);
+            if !object.generation.is_empty() {
+                meta.set_version(&object.generation);
+            }
+            if self.versions {
+                meta.set_is_current(object.time_deleted.is_none());
+            }
 
             let de = oio::Entry::with(path, meta);
 
@@ -137,3 +154,97 @@ impl oio::PageList for GcsLister {
         Ok(())
     }
 }
+
+/// Build the `matchGlob` parameter that pushes `suffix` and `glob` down to GCS.
+///
+/// GCS matches the glob against full object names, so the directory of the
+/// listed path is prepended. Returns `None` if the filter can't be expressed
+/// safely, the entries will still be filtered on the client side.
+pub(super) fn build_match_glob(root: &str, path: &str, args: &OpList) -> Option<String> {
+    let base = if path.ends_with('/') {
+        path
+    } else {
+        get_parent(path)
+    };
+    let base = build_abs_path(root, base);
+    if has_glob_meta(&base) {
+        return None;
+    }
+
+    if let Some(glob) = args.glob() {
+        return Some(format!("{base}{glob}"));
+    }
+    match args.suffix() {
+        Some(suffix) if !has_glob_meta(suffix) => Some(format!("{base}**{suffix}")),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_match_glob() {
+        let cases = vec![
+            ("no filter", "/", "dir/", OpList::new(), None),
+            (
+                "glob in root",
+                "/",
+                "/",
+                OpList::new().with_glob("*.csv"),
+                Some("*.csv"),
+            ),
+            (
+                "glob in dir",
+                "/root/",
+                "dir/",
+                OpList::new().with_glob("**/*.csv"),
+                Some("root/dir/**/*.csv"),
+            ),
+            (
+                "glob with prefix path",
+                "/",
+                "dir/part-",
+                OpList::new().with_glob("part-*"),
+                Some("dir/part-*"),
+            ),
+            (
+                "suffix",
+                "/",
+                "dir/",
+                OpList::new().with_suffix(".parquet"),
+                Some("dir/**.parquet"),
+            ),
+            (
+                "glob takes precedence",
+                "/",
+                "dir/",
+                OpList::new().with_suffix(".csv").with_glob("a*"),
+                Some("dir/a*"),
+            ),
+            (
+                "suffix with meta",
+                "/",
+                "dir/",
+                OpList::new().with_suffix("[1]"),
+                None,
+            ),
+            (
+                "base with meta",
+                "/",
+                "dir[1]/",
+                OpList::new().with_glob("*"),
+                None,
+            ),
+        ];
+
+        for (name, root, path, args, expected) in cases {
+            assert_eq!(
+                build_match_glob(root, path, &args).as_deref(),
+                expected,
+                "{name}"
+            );
+        }
+    }
+}
@@ -17,6 +17,7 @@
 
 use super::backend::*;
 use super::core::parse_error;
+use super::core::parse_goog_hash;
 use http::Response;
 use http::StatusCode;
 use opendal_core::raw::*;
@@ -61,10 +62,13 @@ impl oio::StreamRead for GcsReader {
         let status = resp.status();
 
         let (rp, stream) = match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => (
-                RpRead::new(parse_into_metadata(path, resp.headers())?),
-                resp.into_body(),
-            ),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                if let Some(v) = parse_goog_hash(resp.headers()) {
+                    meta.set_checksum(v);
+                }
+                (RpRead::new(meta), resp.into_body())
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -19,16 +19,26 @@ use std::sync::Arc;
 
 use bytes::Buf;
 use http::StatusCode;
+use http::header::RANGE;
 
 use super::core::CompleteMultipartUploadRequestPart;
 use super::core::GcsCore;
 use super::core::InitiateMultipartUploadResult;
+use super::core::constants::X_GOOG_GENERATION;
 use super::core::gcs_percent_encode_path;
 use super::core::parse_error;
+use super::core::parse_goog_hash;
 use opendal_core::raw::*;
 use opendal_core::*;
 
-pub type GcsWriters = oio::MultipartWriter<GcsWriter>;
+pub type GcsWriters = TwoWays<oio::MultipartWriter<GcsWriter>, GcsResumableWriter>;
+
+/// Chunks of resumable uploads must be multiple of 256 KiB except the last one.
+///
+/// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#chunked-upload>
+const RESUMABLE_CHUNK_ALIGN: usize = 256 * 1024;
+/// Buffered size to trigger a chunk upload of resumable uploads.
+const RESUMABLE_CHUNK_SIZE: usize = 32 * RESUMABLE_CHUNK_ALIGN;
 
 pub struct GcsWriter {
     core: Arc<GcsCore>,
@@ -51,10 +61,15 @@ impl GcsWriter {
 impl oio::MultipartWrite for GcsWriter {
     async fn write_once(&self, _: u64, body: Buffer) -> Result<Metadata> {
         let size = body.len() as u64;
+        let generation = self
+            .core
+            .gcs_write_generation_match(&self.ctx, &self.path, &self.op)
+            .await?;
         let req = self.core.gcs_insert_object_request(
             &gcs_percent_encode_path(&self.path),
             Some(size),
             &self.op,
+            generation.as_deref(),
             body,
         )?;
 
@@ -75,12 +90,17 @@ impl oio::MultipartWrite for GcsWriter {
     }
 
     async fn initiate_part(&self) -> Result<String> {
+        let generation = self
+            .core
+            .gcs_write_generation_match(&self.ctx, &self.path, &self.op)
+            .await?;
         let resp = self
             .core
             .gcs_initiate_multipart_upload(
                 &self.ctx,
                 &gcs_percent_encode_path(&self.path),
                 &self.op,
+                generation.as_deref(),
             )
             .await?;
 
@@ -154,7 +174,16 @@ impl oio::MultipartWrite for GcsWriter {
         // we don't extract metadata from `CompleteMultipartUploadResult`, since we only need the `ETag` from it.
         // However, the `ETag` differs from the `ETag` obtained through the `stat` operation.
         // refer to: https://cloud.google.com/storage/docs/metadata#etags
-        Ok(Metadata::default())
+        //
+        // The generation and hash of the composed object are returned in headers.
+        let mut meta = Metadata::new(EntryMode::FILE);
+        if let Some(generation) = parse_header_to_str(resp.headers(), X_GOOG_GENERATION)? {
+            meta.set_version(generation);
+        }
+        if let Some(checksum) = parse_goog_hash(resp.headers()) {
+            meta.set_checksum(checksum);
+        }
+        Ok(meta)
     }
 
     async fn abort_part(&self, upload_id: &str) -> Result<()> {
@@ -169,3 +198,288 @@ impl oio::MultipartWrite for GcsWriter {
         }
     }
 }
+
+/// Writer that uploads data through a resumable upload session.
+///
+/// Chunks are uploaded one by one in order. The session URI is used as the
+/// upload id of [`WriteCheckpoint`], so the upload could be resumed from
+/// another process.
+pub struct GcsResumableWriter {
+    w: GcsWriter,
+
+    session_uri: Option<String>,
+    /// Size that has been persisted by the session.
+    uploaded: u64,
+    buffer: oio::QueueBuf,
+}
+
+impl GcsResumableWriter {
+    pub fn new(w: GcsWriter) -> Self {
+        let (session_uri, uploaded) = match w.op.checkpoint() {
+            Some(v) => (Some(v.upload_id().to_string()), v.size()),
+            None => (None, 0),
+        };
+
+        GcsResumableWriter {
+            w,
+            session_uri,
+            uploaded,
+            buffer: oio::QueueBuf::new(),
+        }
+    }
+
+    async fn session_uri(&mut self) -> Result<String> {
+        if let Some(uri) = &self.session_uri {
+            return Ok(uri.clone());
+        }
+
+        let generation = self
+            .w
+            .core
+            .gcs_write_generation_match(&self.w.ctx, &self.w.path, &self.w.op)
+            .await?;
+        let resp = self
+            .w
+            .core
+            .gcs_initiate_resumable_upload(
+                &self.w.ctx,
+                &self.w.path,
+                &self.w.op,
+                generation.as_deref(),
+            )
+            .await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+
+        let uri = parse_location(resp.headers())?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "session uri not present in returning response",
+                )
+            })?
+            .to_string();
+        self.session_uri = Some(uri.clone());
+        Ok(uri)
+    }
+
+    /// Upload a chunk to the session, returns metadata if the upload is finished.
+    async fn upload_chunk(&mut self, body: Buffer, total: Option<u64>) -> Result<Option<Metadata>> {
+        let uri = self.session_uri().await?;
+        let size = body.len() as u64;
+
+        let resp = self
+            .w
+            .core
+            .gcs_upload_resumable_chunk(&self.w.ctx, &uri, self.uploaded, total, body)
+            .await?;
+
+        match resp.status() {
+            // GCS uses 308 to indicate that the upload is incomplete.
+            StatusCode::PERMANENT_REDIRECT => {
+                let persisted = parse_persisted_size(resp.headers())?;
+                if persisted != self.uploaded + size {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "resumable upload persisted unexpected size",
+                    )
+                    .with_context("expect", self.uploaded + size)
+                    .with_context("actual", persisted));
+                }
+                self.uploaded = persisted;
+                Ok(None)
+            }
+            StatusCode::OK | StatusCode::CREATED => {
+                self.uploaded += size;
+                let metadata =
+                    GcsCore::build_metadata_from_object_response(&self.w.path, resp.into_body())?;
+                Ok(Some(metadata))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+impl oio::Write for GcsResumableWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.buffer.push(bs);
+        if self.buffer.len() < RESUMABLE_CHUNK_SIZE {
+            return Ok(());
+        }
+
+        let size = self.buffer.len() - self.buffer.len() % RESUMABLE_CHUNK_ALIGN;
+        let mut buf = self.buffer.take().collect();
+        let body = buf.split_to(size);
+        self.buffer.push(buf);
+
+        match self.upload_chunk(body, None).await? {
+            None => Ok(()),
+            Some(_) => Err(Error::new(
+                ErrorKind::Unexpected,
+                "resumable upload finished before close",
+            )),
+        }
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let body: Buffer = self.buffer.take().collect();
+
+        // Small objects don't need a session.
+        if self.session_uri.is_none() {
+            return oio::MultipartWrite::write_once(&self.w, body.len() as u64, body).await;
+        }
+
+        let total = self.uploaded + body.len() as u64;
+        match self.upload_chunk(body, Some(total)).await? {
+            Some(metadata) => Ok(metadata),
+            None => Err(Error::new(
+                ErrorKind::Unexpected,
+                "resumable upload is not finished after close",
+            )),
+        }
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        let Some(uri) = self.session_uri.take() else {
+            return Ok(());
+        };
+
+        let resp = self
+            .w
+            .core
+            .gcs_cancel_resumable_upload(&self.w.ctx, &uri)
+            .await?;
+        match resp.status().as_u16() {
+            // gcs returns code 499 if cancel succeeds.
+            499 | 204 => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        let uri = self.session_uri().await?;
+
+        // Buffered data hasn't been persisted, callers will write it again
+        // after resuming.
+        self.buffer.clear();
+        Ok(WriteCheckpoint::new(uri, vec![], self.uploaded))
+    }
+}
+
+/// Parse the persisted size from the `Range` header like `bytes=0-42`.
+fn parse_persisted_size(headers: &http::HeaderMap) -> Result<u64> {
+    let Some(range) = parse_header_to_str(headers, RANGE)? else {
+        return Ok(0);
+    };
+
+    range
+        .strip_prefix("bytes=0-")
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(|v| v + 1)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "range header of resumable upload is invalid",
+            )
+            .with_context("range", range)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use http::Request;
+    use http::Response;
+    use http::header::CONTENT_RANGE;
+    use http::header::LOCATION;
+
+    use super::*;
+    use crate::Gcs;
+
+    const SESSION_URI: &str = "http://gcs.test/upload/session/1";
+
+    /// Serve a single resumable upload session.
+    #[derive(Clone, Default)]
+    struct MockGcs {
+        data: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl HttpTransport for MockGcs {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let uri = req.uri().to_string();
+            let mut resp = Response::builder();
+            let content = if uri.contains("uploadType=resumable") {
+                resp = resp.status(StatusCode::OK).header(LOCATION, SESSION_URI);
+                vec![]
+            } else if uri == SESSION_URI {
+                let range = req.headers()[CONTENT_RANGE].to_str().unwrap().to_string();
+                let (range, total) = range
+                    .strip_prefix("bytes ")
+                    .unwrap()
+                    .split_once('/')
+                    .unwrap();
+
+                let mut data = self.data.lock().unwrap();
+                if let Some((start, _)) = range.split_once('-') {
+                    assert_eq!(start.parse::<usize>().unwrap(), data.len());
+                    data.extend_from_slice(&req.body().to_vec());
+                }
+
+                if total == "*" {
+                    resp = resp
+                        .status(StatusCode::PERMANENT_REDIRECT)
+                        .header(RANGE, format!("bytes=0-{}", data.len() - 1));
+                    vec![]
+                } else {
+                    assert_eq!(total.parse::<usize>().unwrap(), data.len());
+                    resp = resp.status(StatusCode::OK);
+                    format!(r#"{{"name":"file","size":"{}"}}"#, data.len()).into_bytes()
+                }
+            } else {
+                panic!("unexpected request: {} {uri}", req.method());
+            };
+
+            let size = content.len() as u64;
+            let body = futures::stream::iter(vec![Ok(Buffer::from(content))]);
+            Ok(resp.body(HttpBody::new(body, Some(size))).unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_resumable_upload() -> Result<()> {
+        let mock = MockGcs::default();
+        let builder = Gcs::default()
+            .bucket("test")
+            .endpoint("http://gcs.test")
+            .skip_signature()
+            .enable_resumable_upload();
+        let op = Operator::new(builder)?.with_context(
+            OperationContext::new().with_http_transport(HttpTransporter::new(mock.clone())),
+        );
+
+        let content = (0..RESUMABLE_CHUNK_SIZE + RESUMABLE_CHUNK_ALIGN + 100)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+
+        let mut w = op.writer("file").await?;
+        w.write(content[..RESUMABLE_CHUNK_SIZE + 100].to_vec())
+            .await?;
+        let checkpoint = w.suspend().await?;
+        assert_eq!(checkpoint.upload_id(), SESSION_URI);
+        assert_eq!(checkpoint.size(), RESUMABLE_CHUNK_SIZE as u64);
+
+        // Resume with the session uri from another writer.
+        let checkpoint = WriteCheckpoint::new(SESSION_URI.to_string(), vec![], checkpoint.size());
+        let mut w = op.writer_with("file").checkpoint(checkpoint).await?;
+        w.write(content[RESUMABLE_CHUNK_SIZE..].to_vec()).await?;
+        let meta = w.close().await?;
+
+        assert_eq!(meta.content_length(), content.len() as u64);
+        assert_eq!(*mock.data.lock().unwrap(), content);
+        Ok(())
+    }
+}
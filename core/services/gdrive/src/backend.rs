@@ -110,6 +110,19 @@ impl GdriveBuilder {
         self.config.client_secret = Some(client_secret.to_string());
         self
     }
+
+    /// Set the shared drive id for GoogleDrive.
+    ///
+    /// When set, `root` is resolved inside the given shared drive instead of
+    /// the user's "My Drive".
+    pub fn drive_id(mut self, drive_id: &str) -> Self {
+        self.config.drive_id = if drive_id.is_empty() {
+            None
+        } else {
+            Some(drive_id.to_string())
+        };
+        self
+    }
 }
 
 impl Builder for GdriveBuilder {
@@ -132,6 +145,7 @@ impl Builder for GdriveBuilder {
             write: true,
 
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
             delete_with_recursive: true,
             rename: true,
@@ -193,8 +207,12 @@ impl Builder for GdriveBuilder {
                 info: accessor_info.clone(),
                 capability,
                 root,
+                drive_id: self.config.drive_id.clone(),
                 signer: signer.clone(),
-                path_index: GdrivePathIndex::new(GdrivePathQuery::new(signer)),
+                path_index: GdrivePathIndex::new(GdrivePathQuery::new(
+                    signer,
+                    self.config.drive_id,
+                )),
                 recent_entries: Mutex::default(),
             }),
         })
@@ -37,12 +37,17 @@ pub struct GdriveConfig {
     pub client_id: Option<String>,
     /// Client secret for gdrive.
     pub client_secret: Option<String>,
+    /// Shared drive id for gdrive.
+    ///
+    /// If not set, the user's "My Drive" will be used.
+    pub drive_id: Option<String>,
 }
 
 impl Debug for GdriveConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GdriveConfig")
             .field("root", &self.root)
+            .field("drive_id", &self.drive_id)
             .finish_non_exhaustive()
     }
 }
@@ -33,11 +33,16 @@ use super::path_index::GdrivePathIndex;
 use opendal_core::raw::*;
 use opendal_core::*;
 
+pub(crate) const GDRIVE_FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+pub(crate) const GDRIVE_SHORTCUT_MIME_TYPE: &str = "application/vnd.google-apps.shortcut";
+
 pub struct GdriveCore {
     pub info: ServiceInfo,
     pub capability: Capability,
 
     pub root: String,
+    /// The shared drive to operate on, `None` means the user's "My Drive".
+    pub drive_id: Option<String>,
 
     pub signer: Arc<Mutex<GdriveSigner>>,
 
@@ -54,6 +59,7 @@ impl Debug for GdriveCore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GdriveCore")
             .field("root", &self.root)
+            .field("drive_id", &self.drive_id)
             .finish()
     }
 }
@@ -241,13 +247,18 @@ impl GdriveCore {
     ) -> Result<Response<Buffer>> {
         // The file metadata in the Google Drive API is very complex.
         // For now, we only need the file id, name, mime type and modified time.
-        let mut req = Request::get(format!(
-            "https://www.googleapis.com/drive/v3/files/{file_id}?fields=id,name,mimeType,size,modifiedTime"
-        ))
-        .extension(Operation::Stat)
-        .extension(ServiceOperation("GetFile"))
-        .body(Buffer::new())
-        .map_err(new_request_build_error)?;
+        let url = format!("https://www.googleapis.com/drive/v3/files/{file_id}");
+        let url = QueryPairsWriter::new(&url).push(
+            "fields",
+            "id,name,mimeType,size,modifiedTime,shortcutDetails",
+        );
+        let url = with_drive_params(url, None);
+
+        let mut req = Request::get(url.finish())
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetFile"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
         self.sign(ctx, &mut req).await?;
 
         ctx.http_transport().send(req).await
@@ -282,9 +293,10 @@ impl GdriveCore {
             },
         };
 
-        let url: String = format!("https://www.googleapis.com/drive/v3/files/{path_id}?alt=media");
+        let url = format!("https://www.googleapis.com/drive/v3/files/{path_id}");
+        let url = with_drive_params(QueryPairsWriter::new(&url).push("alt", "media"), None);
 
-        let mut req = Request::get(&url)
+        let mut req = Request::get(url.finish())
             .extension(Operation::Read)
             .extension(ServiceOperation("DownloadFile"))
             .header(header::RANGE, range.to_header())
@@ -309,11 +321,12 @@ impl GdriveCore {
         url = url.push("q", &percent_encode_path(&q));
         url = url.push(
             "fields",
-            "nextPageToken,files(id,name,mimeType,size,modifiedTime,parents)",
+            "nextPageToken,files(id,name,mimeType,size,modifiedTime,parents,shortcutDetails)",
         );
         if !next_page_token.is_empty() {
             url = url.push("pageToken", next_page_token);
         };
+        url = with_drive_params(url, self.drive_id.as_deref());
 
         let mut req = Request::get(url.finish())
             .extension(Operation::List)
@@ -357,11 +370,12 @@ impl GdriveCore {
         // Include 'parents' field to know which directory each file belongs to
         url = url.push(
             "fields",
-            "nextPageToken,files(id,name,mimeType,size,modifiedTime,parents)",
+            "nextPageToken,files(id,name,mimeType,size,modifiedTime,parents,shortcutDetails)",
         );
         if !next_page_token.is_empty() {
             url = url.push("pageToken", next_page_token);
         };
+        url = with_drive_params(url, self.drive_id.as_deref());
 
         let mut req = Request::get(url.finish())
             .extension(Operation::List)
@@ -409,7 +423,8 @@ impl GdriveCore {
         });
 
         let url = format!("https://www.googleapis.com/drive/v3/files/{source_file_id}");
-        let mut req = Request::patch(url)
+        let url = with_drive_params(QueryPairsWriter::new(&url), None);
+        let mut req = Request::patch(url.finish())
             .extension(Operation::Rename)
             .extension(ServiceOperation("MoveFile"))
             .body(Buffer::from(Bytes::from(metadata.to_string())))
@@ -426,13 +441,14 @@ impl GdriveCore {
         file_id: &str,
     ) -> Result<Response<Buffer>> {
         let url = format!("https://www.googleapis.com/drive/v3/files/{file_id}");
+        let url = with_drive_params(QueryPairsWriter::new(&url), None);
 
         let body = serde_json::to_vec(&json!({
             "trashed": true
         }))
         .map_err(new_json_serialize_error)?;
 
-        let mut req = Request::patch(&url)
+        let mut req = Request::patch(url.finish())
             .extension(Operation::Delete)
             .extension(ServiceOperation("TrashFile"))
             .body(Buffer::from(Bytes::from(body)))
@@ -453,7 +469,9 @@ impl GdriveCore {
     ) -> Result<Response<Buffer>> {
         let parent = self.ensure_dir(ctx, get_parent(path)).await?;
 
-        let url = "https://www.googleapis.com/upload/drive/v3/files?uploadType=multipart";
+        let url = QueryPairsWriter::new("https://www.googleapis.com/upload/drive/v3/files")
+            .push("uploadType", "multipart");
+        let url = with_drive_params(url, None);
 
         let file_name = get_basename(path);
 
@@ -463,7 +481,7 @@ impl GdriveCore {
         }))
         .map_err(new_json_serialize_error)?;
 
-        let req = Request::post(url)
+        let req = Request::post(url.finish())
             .header("X-Upload-Content-Length", size)
             .extension(Operation::Write)
             .extension(ServiceOperation("UploadFile"));
@@ -505,10 +523,13 @@ impl GdriveCore {
         size: u64,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
-        let url =
-            format!("https://www.googleapis.com/upload/drive/v3/files/{file_id}?uploadType=media");
+        let url = format!("https://www.googleapis.com/upload/drive/v3/files/{file_id}");
+        let url = with_drive_params(
+            QueryPairsWriter::new(&url).push("uploadType", "media"),
+            None,
+        );
 
-        let mut req = Request::patch(url)
+        let mut req = Request::patch(url.finish())
             .header(header::CONTENT_TYPE, "application/octet-stream")
             .header(header::CONTENT_LENGTH, size)
             .header("X-Upload-Content-Length", size)
@@ -555,6 +576,7 @@ impl GdriveCore {
         self.trash_path_if_exists(ctx, &to_path).await?;
 
         let url = format!("https://www.googleapis.com/drive/v3/files/{from_file_id}/copy");
+        let url = with_drive_params(QueryPairsWriter::new(&url), None);
 
         let request_body = &json!({
             "name": to_name,
@@ -562,7 +584,7 @@ impl GdriveCore {
         });
         let body = Buffer::from(Bytes::from(request_body.to_string()));
 
-        let mut req = Request::post(&url)
+        let mut req = Request::post(url.finish())
             .extension(Operation::Copy)
             .extension(ServiceOperation("CopyFile"))
             .body(body)
@@ -571,6 +593,62 @@ impl GdriveCore {
 
         ctx.http_transport().send(req).await
     }
+
+    /// Resolve a shortcut returned by listing into the file it points to.
+    ///
+    /// The returned file keeps the shortcut's name and parents so that it
+    /// shows up at the shortcut's location. Returns `None` if the target of
+    /// the shortcut no longer exists.
+    pub async fn resolve_shortcut(
+        &self,
+        ctx: &OperationContext,
+        file: GdriveFile,
+    ) -> Result<Option<GdriveFile>> {
+        let Some(details) = file.shortcut_target() else {
+            return Ok(Some(file));
+        };
+
+        // Folders don't carry size or content, there is no need to fetch them.
+        if details.target_mime_type == GDRIVE_FOLDER_MIME_TYPE {
+            return Ok(Some(GdriveFile {
+                mime_type: details.target_mime_type.clone(),
+                id: details.target_id.clone(),
+                shortcut_details: None,
+                ..file
+            }));
+        }
+
+        let resp = self.gdrive_stat_by_id(ctx, &details.target_id).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let target: GdriveFile = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                Ok(Some(GdriveFile {
+                    name: file.name,
+                    parents: file.parents,
+                    ..target
+                }))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+/// Append the query parameters required to access items in shared drives.
+///
+/// `supportsAllDrives` is always set so that shortcut targets living in a
+/// shared drive are reachable from "My Drive" as well. List requests pass
+/// `drive_id` to scope the search to the given shared drive.
+fn with_drive_params(url: QueryPairsWriter, drive_id: Option<&str>) -> QueryPairsWriter {
+    let url = url.push("supportsAllDrives", "true");
+    match drive_id {
+        Some(drive_id) => url
+            .push("includeItemsFromAllDrives", "true")
+            .push("corpora", "drive")
+            .push("driveId", &percent_encode_path(drive_id)),
+        None => url,
+    }
 }
 
 #[derive(Clone)]
@@ -779,17 +857,19 @@ impl GdriveSigner {
 
 pub struct GdrivePathQuery {
     pub signer: Arc<Mutex<GdriveSigner>>,
+    pub drive_id: Option<String>,
 }
 
 impl GdrivePathQuery {
-    pub fn new(signer: Arc<Mutex<GdriveSigner>>) -> Self {
-        GdrivePathQuery { signer }
+    pub fn new(signer: Arc<Mutex<GdriveSigner>>, drive_id: Option<String>) -> Self {
+        GdrivePathQuery { signer, drive_id }
     }
 }
 
 impl crate::path_index::GdrivePathQueryer for GdrivePathQuery {
     async fn root(&self, _: &OperationContext) -> Result<String> {
-        Ok("root".to_string())
+        // The id of a shared drive is also the id of its root folder.
+        Ok(self.drive_id.clone().unwrap_or_else(|| "root".to_string()))
     }
 
     async fn query(
@@ -810,18 +890,21 @@ impl crate::path_index::GdrivePathQueryer for GdrivePathQuery {
             "trashed = false".to_string(),
         ];
         if name.ends_with('/') {
-            queries.push("mimeType = 'application/vnd.google-apps.folder'".to_string());
+            queries.push(format!(
+                "(mimeType = '{GDRIVE_FOLDER_MIME_TYPE}' or mimeType = '{GDRIVE_SHORTCUT_MIME_TYPE}')"
+            ));
         }
         let query = queries.join(" and ");
         let order_by = "modifiedTime desc,createdTime desc";
 
-        let url = format!(
-            "https://www.googleapis.com/drive/v3/files?q={}&orderBy={}&pageSize=1",
-            percent_encode_path(query.as_str()),
-            percent_encode_path(order_by)
-        );
+        let url = QueryPairsWriter::new("https://www.googleapis.com/drive/v3/files")
+            .push("q", &percent_encode_path(query.as_str()))
+            .push("orderBy", &percent_encode_path(order_by))
+            .push("pageSize", "1")
+            .push("fields", "files(id,name,mimeType,shortcutDetails)");
+        let url = with_drive_params(url, self.drive_id.as_deref());
 
-        let mut req = Request::get(&url)
+        let mut req = Request::get(url.finish())
             .extension(Operation::Stat)
             .extension(ServiceOperation("ListFiles"))
             .body(Buffer::new())
@@ -838,10 +921,23 @@ impl crate::path_index::GdrivePathQueryer for GdrivePathQuery {
                 let meta: GdriveFileList =
                     serde_json::from_reader(body.reader()).map_err(new_json_deserialize_error)?;
 
-                if let Some(f) = meta.files.first() {
-                    Ok(Some(f.id.clone()))
-                } else {
-                    Ok(None)
+                let Some(f) = meta.files.first() else {
+                    return Ok(None);
+                };
+
+                // Shortcuts are resolved transparently, so that paths under
+                // a folder shortcut point into the real folder.
+                match f.shortcut_target() {
+                    Some(details) => {
+                        if name.ends_with('/')
+                            && details.target_mime_type != GDRIVE_FOLDER_MIME_TYPE
+                        {
+                            Ok(None)
+                        } else {
+                            Ok(Some(details.target_id.clone()))
+                        }
+                    }
+                    None => Ok(Some(f.id.clone())),
                 }
             }
             _ => Err(parse_error(resp)),
@@ -854,7 +950,10 @@ impl crate::path_index::GdrivePathQueryer for GdrivePathQuery {
         parent_id: &str,
         name: &str,
     ) -> Result<String> {
-        let url = "https://www.googleapis.com/drive/v3/files";
+        let url = with_drive_params(
+            QueryPairsWriter::new("https://www.googleapis.com/drive/v3/files"),
+            None,
+        );
 
         let content = serde_json::to_vec(&json!({
             "name": name.trim_end_matches('/'),
@@ -864,7 +963,7 @@ impl crate::path_index::GdrivePathQueryer for GdrivePathQuery {
         }))
         .map_err(new_json_serialize_error)?;
 
-        let mut req = Request::post(url)
+        let mut req = Request::post(url.finish())
             .extension(Operation::CreateDir)
             .extension(ServiceOperation("CreateFolder"))
             .header(header::CONTENT_TYPE, "application/json")
@@ -911,6 +1010,27 @@ pub struct GdriveFile {
     // a file belongs to. This is needed for the OR query optimization.
     #[serde(default)]
     pub parents: Vec<String>,
+    // Only returned for shortcuts, which point to another file or folder.
+    #[serde(default)]
+    pub shortcut_details: Option<GdriveShortcutDetails>,
+}
+
+impl GdriveFile {
+    /// Returns the shortcut details if this file is a shortcut.
+    pub fn shortcut_target(&self) -> Option<&GdriveShortcutDetails> {
+        if self.mime_type != GDRIVE_SHORTCUT_MIME_TYPE {
+            return None;
+        }
+        self.shortcut_details.as_ref()
+    }
+}
+
+/// refer to https://developers.google.com/drive/api/guides/shortcuts
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GdriveShortcutDetails {
+    pub target_id: String,
+    pub target_mime_type: String,
 }
 
 /// refer to https://developers.google.com/drive/api/reference/rest/v3/files/list
@@ -933,8 +1053,9 @@ mod tests {
             info: info.clone(),
             capability: Capability::default(),
             root: "/".to_string(),
+            drive_id: None,
             signer: signer.clone(),
-            path_index: GdrivePathIndex::new(GdrivePathQuery::new(signer)),
+            path_index: GdrivePathIndex::new(GdrivePathQuery::new(signer, None)),
             recent_entries: Mutex::new(GdriveRecentState::default()),
         }
     }
@@ -1076,6 +1197,48 @@ mod tests {
             vec!["parent/dir/".to_string(), "parent/dir/file.txt".to_string()]
         );
     }
+
+    #[test]
+    fn test_with_drive_params() {
+        let url = QueryPairsWriter::new("https://www.googleapis.com/drive/v3/files");
+        assert_eq!(
+            with_drive_params(url, None).finish(),
+            "https://www.googleapis.com/drive/v3/files?supportsAllDrives=true"
+        );
+
+        let url = QueryPairsWriter::new("https://www.googleapis.com/drive/v3/files");
+        assert_eq!(
+            with_drive_params(url, Some("drive-id")).finish(),
+            "https://www.googleapis.com/drive/v3/files?supportsAllDrives=true&includeItemsFromAllDrives=true&corpora=drive&driveId=drive-id"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_folder_shortcut() {
+        let core = mock_gdrive_core();
+        let file: GdriveFile = serde_json::from_value(json!({
+            "id": "shortcut-id",
+            "name": "linked",
+            "mimeType": "application/vnd.google-apps.shortcut",
+            "parents": ["parent-id"],
+            "shortcutDetails": {
+                "targetId": "target-id",
+                "targetMimeType": "application/vnd.google-apps.folder"
+            }
+        }))
+        .unwrap();
+
+        let resolved = core
+            .resolve_shortcut(&OperationContext::new(), file)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.id, "target-id");
+        assert_eq!(resolved.name, "linked");
+        assert_eq!(resolved.mime_type, GDRIVE_FOLDER_MIME_TYPE);
+        assert_eq!(resolved.parents, vec!["parent-id".to_string()]);
+        assert!(resolved.shortcut_target().is_none());
+    }
 }
 
 mod error {
@@ -241,7 +241,10 @@ impl oio::PageList for GdriveLister {
             ctx.done = true;
         }
 
-        for mut file in decoded_response.files {
+        for file in decoded_response.files {
+            let Some(mut file) = self.core.resolve_shortcut(&self.ctx, file).await? else {
+                continue;
+            };
             if file.mime_type.as_str() == "application/vnd.google-apps.folder"
                 && !file.name.ends_with('/')
             {
@@ -683,7 +686,10 @@ impl GdriveFlatLister {
     }
 
     /// Process a single file from the API response
-    async fn process_file(&mut self, mut file: GdriveFile) -> Result<()> {
+    async fn process_file(&mut self, file: GdriveFile) -> Result<()> {
+        let Some(mut file) = self.core.resolve_shortcut(&self.ctx, file).await? else {
+            return Ok(());
+        };
         let is_dir = file.mime_type.as_str() == "application/vnd.google-apps.folder";
 
         if is_dir && !file.name.ends_with('/') {
@@ -717,7 +723,11 @@ impl GdriveFlatLister {
         }
 
         // If it's a directory, queue it for recursive listing if it can contain matching entries.
+        //
+        // Folder shortcuts may point to a folder that is already queued, such as
+        // one of their ancestors. Skip them to avoid listing forever.
         let should_traverse = is_dir
+            && !self.dir_id_to_path.contains_key(&file.id)
             && !matches!(recent_state, GdriveRecentPathState::Deleted)
             && self
                 .prefix
@@ -779,6 +789,7 @@ mod tests {
     use super::*;
     use crate::core::GdrivePathQuery;
     use crate::core::GdriveRecentState;
+    use crate::core::GdriveShortcutDetails;
     use crate::core::GdriveSigner;
     use crate::path_index::GdrivePathIndex;
 
@@ -790,8 +801,9 @@ mod tests {
             info: info.clone(),
             capability: Capability::default(),
             root: "/".to_string(),
+            drive_id: None,
             signer: signer.clone(),
-            path_index: GdrivePathIndex::new(GdrivePathQuery::new(signer)),
+            path_index: GdrivePathIndex::new(GdrivePathQuery::new(signer, None)),
             recent_entries: Mutex::new(GdriveRecentState::default()),
         })
     }
@@ -820,6 +832,7 @@ mod tests {
                 size: None,
                 modified_time: None,
                 parents: vec!["parent-id".to_string()],
+                shortcut_details: None,
             })
             .await
             .unwrap();
@@ -829,6 +842,47 @@ mod tests {
         assert!(lister.entry_buffer.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_process_file_traverses_folder_shortcut_once() {
+        let core = mock_gdrive_core();
+        let mut lister =
+            GdriveFlatLister::new("parent/".to_string(), core, mock_operation_context());
+        lister
+            .dir_id_to_path
+            .insert("parent-id".to_string(), "parent/".to_string());
+
+        let shortcut = |name: &str, target_id: &str| GdriveFile {
+            mime_type: "application/vnd.google-apps.shortcut".to_string(),
+            id: format!("{name}-id"),
+            name: name.to_string(),
+            size: None,
+            modified_time: None,
+            parents: vec!["parent-id".to_string()],
+            shortcut_details: Some(GdriveShortcutDetails {
+                target_id: target_id.to_string(),
+                target_mime_type: "application/vnd.google-apps.folder".to_string(),
+            }),
+        };
+
+        lister
+            .process_file(shortcut("linked", "target-id"))
+            .await
+            .unwrap();
+        // A shortcut back to the parent must not be traversed again.
+        lister
+            .process_file(shortcut("loop", "parent-id"))
+            .await
+            .unwrap();
+
+        let pending = lister
+            .pending_dirs
+            .iter()
+            .map(|dir| (dir.id.as_str(), dir.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(pending, vec![("target-id", "parent/linked/")]);
+        assert_eq!(lister.entry_buffer.len(), 2);
+    }
+
     #[test]
     fn test_apply_refreshed_batch_clears_page_token() {
         let core = mock_gdrive_core();
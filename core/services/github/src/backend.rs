@@ -127,6 +127,7 @@ impl Builder for GithubBuilder {
                     read_with_suffix: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     write: true,
                     write_can_empty: true,
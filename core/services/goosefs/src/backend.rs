@@ -279,6 +279,7 @@ impl Builder for GoosefsBuilder {
                     // Lance Dataset relies on this for manifest commit safety.
                     write_with_if_not_exists: true,
                     create_dir: true,
+                    create_dir_persistent: true,
                     delete: true,
                     list: true,
                     rename: true,
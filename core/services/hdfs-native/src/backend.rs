@@ -129,11 +129,13 @@ impl Builder for HdfsNativeBuilder {
                     write_can_append: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
                     delete: true,
 
                     list: true,
 
                     rename: true,
+                    rename_is_atomic: true,
 
                     shared: true,
 
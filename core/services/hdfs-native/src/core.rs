@@ -173,17 +173,13 @@ impl HdfsNativeCore {
                 if status.isdir {
                     return Err(Error::new(ErrorKind::IsADirectory, "path should be a file")
                         .with_context("input", &to_path));
-                } else {
-                    self.client
-                        .delete(&to_path, true)
-                        .await
-                        .map_err(parse_hdfs_error)?;
                 }
             }
             Err(err) => match &err {
                 HdfsError::FileNotFound(_) => {
+                    let parent = get_parent(&to_path);
                     self.client
-                        .create(&to_path, WriteOptions::default().create_parent(true))
+                        .mkdirs(parent, 0o777, true)
                         .await
                         .map_err(parse_hdfs_error)?;
                 }
@@ -191,6 +187,7 @@ impl HdfsNativeCore {
             },
         };
 
+        // Overwriting rename replaces an existing `to` atomically.
         self.client
             .rename(&from_path, &to_path, true)
             .await
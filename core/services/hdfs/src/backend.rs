@@ -165,12 +165,14 @@ impl Builder for HdfsBuilder {
                     write_can_append: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
                     delete: true,
                     delete_with_recursive: true,
 
                     list: true,
 
                     rename: true,
+                    rename_is_atomic: true,
 
                     shared: true,
 
@@ -273,9 +275,9 @@ impl Service for HdfsBackend {
         _ctx: &OperationContext,
         from: &str,
         to: &str,
-        _args: OpRename,
+        args: OpRename,
     ) -> Result<RpRename> {
-        self.core.hdfs_rename(from, to)?;
+        self.core.hdfs_rename(from, to, args.atomic())?;
         Ok(RpRename::new())
     }
 
@@ -146,7 +146,9 @@ impl HdfsCore {
         }
     }
 
-    pub fn hdfs_rename(&self, from: &str, to: &str) -> Result<()> {
+    /// libhdfs can't replace an existing file in one step, so `to` is removed
+    /// first unless `atomic` is set, in which case an existing `to` is an error.
+    pub fn hdfs_rename(&self, from: &str, to: &str, atomic: bool) -> Result<()> {
         let from_path = build_rooted_abs_path(&self.root, from);
         self.client.metadata(&from_path).map_err(new_std_io_error)?;
 
@@ -175,7 +177,13 @@ impl HdfsCore {
                     .map_err(new_std_io_error)?;
             }
             Ok(metadata) => {
-                if metadata.is_file() {
+                if metadata.is_file() && atomic {
+                    return Err(Error::new(
+                        ErrorKind::AlreadyExists,
+                        "path already exists and can't be replaced atomically",
+                    )
+                    .with_context("input", &to_path));
+                } else if metadata.is_file() {
                     self.client
                         .remove_file(&to_path)
                         .map_err(new_std_io_error)?;
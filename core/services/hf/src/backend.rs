@@ -220,21 +220,30 @@ impl Builder for HfBuilder {
         debug!("backend use download_mode: {:?}", download_mode);
 
         let info = ServiceInfo::new(HF_SCHEME, "", "");
+        let repo = HfRepo::new(repo_type, repo_id, Some(revision.clone()));
+        debug!("backend repo uri: {:?}", repo.uri(&root, ""));
+
+        // Buckets are not git-backed and have no revisions to address.
+        let versioned = !repo.is_bucket();
         let capability = Capability {
             stat: true,
+            stat_with_version: versioned,
             read: true,
+            read_with_version: versioned,
             write: token.is_some(),
+            write_can_append: token.is_some(),
+            write_with_if_match: token.is_some(),
+            write_with_if_none_match: token.is_some(),
+            write_with_if_not_exists: token.is_some(),
             delete: token.is_some(),
             delete_max_size: Some(100),
             list: true,
             list_with_recursive: true,
+            list_with_version: versioned,
             shared: true,
             ..Default::default()
         };
 
-        let repo = HfRepo::new(repo_type, repo_id, Some(revision.clone()));
-        debug!("backend repo uri: {:?}", repo.uri(&root, ""));
-
         Ok(HfBackend {
             core: Arc::new(HfCore::build(
                 info,
@@ -282,7 +291,7 @@ impl Service for HfBackend {
         ))
     }
 
-    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
         // Stat root always returns a DIR.
         if path == "/" {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
@@ -293,7 +302,7 @@ impl Service for HfBackend {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let info = self.core.path_info(ctx, path).await?;
+        let info = self.core.path_info_at(ctx, path, args.version()).await?;
         Ok(RpStat::new(info.metadata()?))
     }
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
@@ -311,23 +320,19 @@ impl Service for HfBackend {
 
     fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
         let output: oio::PageLister<HfLister> = {
-            let lister = HfLister::new(
-                self.core.clone(),
-                ctx.clone(),
-                path.to_string(),
-                args.recursive(),
-            );
+            let lister = HfLister::new(self.core.clone(), ctx.clone(), path.to_string(), &args);
             Ok(oio::PageLister::new(lister))
         }?;
 
         Ok(output)
     }
 
-    fn write(&self, ctx: &OperationContext, path: &str, _args: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         Ok(HfLazyWriter::new(
             self.core.clone(),
             ctx.clone(),
             path.to_string(),
+            &args,
         ))
     }
 
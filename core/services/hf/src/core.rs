@@ -204,6 +204,8 @@ impl PathInfo {
 
         if let Some(commit_info) = self.last_commit.as_ref() {
             meta.set_last_modified(commit_info.date.parse::<Timestamp>()?);
+            // Reading at the last commit that touched this path returns exactly this content.
+            meta.set_version(&commit_info.id);
         }
 
         if mode == EntryMode::FILE {
@@ -289,9 +291,9 @@ impl HfCore {
         endpoint: String,
         download_mode: HfDownloadMode,
     ) -> Result<Self> {
-        let xet_session = XetSessionBuilder::new().build().map_err(|err| {
-            Error::new(ErrorKind::Unexpected, "failed to create xet session").set_source(err)
-        })?;
+        let xet_session = XetSessionBuilder::new()
+            .build()
+            .map_err(|err| parse_xet_error(err, "failed to create xet session"))?;
 
         Ok(Self::new(
             info,
@@ -323,17 +325,11 @@ impl HfCore {
         let refresh_headers = self.xet_token_refresh_headers();
         self.xet_session
             .new_upload_commit()
-            .map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "failed to create xet upload commit")
-                    .set_source(err)
-            })?
+            .map_err(|err| parse_xet_error(err, "failed to create xet upload commit"))?
             .with_token_refresh_url(refresh_url, refresh_headers)
             .build()
             .await
-            .map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "failed to build xet upload commit")
-                    .set_source(err)
-            })
+            .map_err(|err| parse_xet_error(err, "failed to build xet upload commit"))
     }
 
     /// Create a new XET download stream group with token refresh configured.
@@ -344,23 +340,11 @@ impl HfCore {
         let refresh_headers = self.xet_token_refresh_headers();
         self.xet_session
             .new_download_stream_group()
-            .map_err(|err| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "failed to create download stream group",
-                )
-                .set_source(err)
-            })?
+            .map_err(|err| parse_xet_error(err, "failed to create download stream group"))?
             .with_token_refresh_url(refresh_url, refresh_headers)
             .build()
             .await
-            .map_err(|err| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "failed to build download stream group",
-                )
-                .set_source(err)
-            })
+            .map_err(|err| parse_xet_error(err, "failed to build download stream group"))
     }
 
     /// Build an authenticated HTTP request.
@@ -401,6 +385,17 @@ impl HfCore {
         self.repo.uri(&self.root, path)
     }
 
+    /// Build an [`HfUri`] that targets `version` instead of the configured revision.
+    ///
+    /// Falls back to [`uri`](Self::uri) when no version is given.
+    pub(super) fn uri_at(&self, path: &str, version: Option<&str>) -> HfUri {
+        let mut uri = self.uri(path);
+        if let Some(version) = version {
+            uri.repo.revision = Some(version.to_string());
+        }
+        uri
+    }
+
     /// Convert an operator-relative path to a repo-absolute path
     /// (no leading `/`) for use in commit/delete/batch payloads.
     pub(super) fn repo_path(&self, path: &str) -> String {
@@ -445,7 +440,17 @@ impl HfCore {
     }
 
     pub(super) async fn path_info(&self, ctx: &OperationContext, path: &str) -> Result<PathInfo> {
-        let uri = self.uri(path);
+        self.path_info_at(ctx, path, None).await
+    }
+
+    /// Fetch path info at the given revision, or the configured one when `None`.
+    pub(super) async fn path_info_at(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        version: Option<&str>,
+    ) -> Result<PathInfo> {
+        let uri = self.uri_at(path, version);
         let url = uri.paths_info_url(&self.endpoint);
         let form_body = format!("paths={}&expand=True", percent_encode_path(&uri.path));
 
@@ -473,11 +478,12 @@ impl HfCore {
         &self,
         ctx: &OperationContext,
         path: &str,
+        version: Option<&str>,
         range: BytesRange,
         mode: HfDownloadMode,
     ) -> Result<Response<HttpBody>> {
-        let uri = self.uri(path);
-        let url = uri.resolve_url(&self.endpoint, self.repo.revision());
+        let uri = self.uri_at(path, version);
+        let url = uri.resolve_url(&self.endpoint, uri.revision());
 
         let mut req = self.request(http::Method::GET, &url, Operation::Read, "Resolve")?;
 
@@ -499,7 +505,9 @@ impl HfCore {
             // (which may be a large HTML error page).
             let (parts, _) = resp.into_parts();
             let mut err = parse_error(parts);
-            if status == http::StatusCode::NOT_FOUND && self.path_info(ctx, path).await.is_ok() {
+            if status == http::StatusCode::NOT_FOUND
+                && self.path_info_at(ctx, path, version).await.is_ok()
+            {
                 err = err.set_temporary();
             }
             return Err(err);
@@ -752,6 +760,27 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_hf_path_info_url_with_version() -> Result<()> {
+        let (core, ctx, mock_client) = create_test_core(
+            HfRepoType::Dataset,
+            "test-org/test-dataset",
+            "main",
+            "https://huggingface.co",
+        );
+
+        core.path_info_at(&ctx, "data/file.csv", Some("refs/pr/1"))
+            .await?;
+
+        let url = mock_client.get_captured_url();
+        assert_eq!(
+            url,
+            "https://huggingface.co/api/datasets/test-org/test-dataset/paths-info/refs%2Fpr%2F1"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_hf_path_info_url_space() -> Result<()> {
         let (core, ctx, mock_client) = create_test_core(
@@ -775,6 +804,7 @@ mod tests {
 
 mod error {
     use http::StatusCode;
+    use xet::error::XetError;
 
     use opendal_core::raw::*;
     use opendal_core::*;
@@ -824,6 +854,28 @@ mod error {
         err
     }
 
+    /// Convert an error returned by the XET session into an OpenDAL error.
+    ///
+    /// Network failures and timeouts are marked as temporary so that
+    /// RetryLayer can resume an interrupted XET download from the last
+    /// received offset instead of failing the whole read.
+    pub(crate) fn parse_xet_error(err: XetError, message: &'static str) -> Error {
+        let (kind, retryable) = match &err {
+            XetError::Network(_) | XetError::Timeout(_) => (ErrorKind::Unexpected, true),
+            XetError::NotFound(_) => (ErrorKind::NotFound, false),
+            XetError::Authentication(_) => (ErrorKind::PermissionDenied, false),
+            XetError::Configuration(_) => (ErrorKind::ConfigInvalid, false),
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let mut err = Error::new(kind, message).set_source(err);
+        if retryable {
+            err = err.set_temporary();
+        }
+
+        err
+    }
+
     #[cfg(test)]
     mod test {
         use http::Response;
@@ -863,6 +915,45 @@ mod error {
             assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
             assert!(!err.is_temporary());
         }
+
+        #[test]
+        fn test_parse_xet_error() {
+            let cases = vec![
+                (
+                    XetError::Network("connection reset".to_string()),
+                    ErrorKind::Unexpected,
+                    true,
+                ),
+                (
+                    XetError::Timeout("read timed out".to_string()),
+                    ErrorKind::Unexpected,
+                    true,
+                ),
+                (
+                    XetError::NotFound("xorb".to_string()),
+                    ErrorKind::NotFound,
+                    false,
+                ),
+                (
+                    XetError::Authentication("token expired".to_string()),
+                    ErrorKind::PermissionDenied,
+                    false,
+                ),
+                (
+                    XetError::DataIntegrity("hash mismatch".to_string()),
+                    ErrorKind::Unexpected,
+                    false,
+                ),
+            ];
+
+            for (input, kind, temporary) in cases {
+                let name = input.to_string();
+                let err = parse_xet_error(input, "xet read error");
+
+                assert_eq!(err.kind(), kind, "{name}");
+                assert_eq!(err.is_temporary(), temporary, "{name}");
+            }
+        }
     }
 }
 
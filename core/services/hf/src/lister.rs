@@ -50,10 +50,14 @@ pub struct HfLister {
     /// When the original path didn't end with `/`, filter results to this prefix.
     prefix: Option<String>,
     recursive: bool,
+    /// Revision to list from; the configured revision is used when `None`.
+    version: Option<String>,
 }
 
 impl HfLister {
-    pub fn new(core: Arc<HfCore>, ctx: OperationContext, path: String, recursive: bool) -> Self {
+    pub fn new(core: Arc<HfCore>, ctx: OperationContext, path: String, args: &OpList) -> Self {
+        let recursive = args.recursive();
+        let version = args.version().map(|v| v.to_string());
         if path.is_empty() || path.ends_with('/') {
             Self {
                 core,
@@ -61,6 +65,7 @@ impl HfLister {
                 list_path: path,
                 prefix: None,
                 recursive,
+                version,
             }
         } else {
             // Prefix listing: list the parent directory and filter by prefix.
@@ -74,6 +79,7 @@ impl HfLister {
                 list_path: parent,
                 prefix: Some(path),
                 recursive,
+                version,
             }
         }
     }
@@ -84,7 +90,7 @@ impl HfLister {
         recursive: bool,
         cursor: Option<&str>,
     ) -> Result<FileTree> {
-        let uri = self.core.uri(path);
+        let uri = self.core.uri_at(path, self.version.as_deref());
         let url = uri.file_tree_url(&self.core.endpoint, recursive, cursor);
 
         let req = self
@@ -189,7 +195,7 @@ mod tests {
             "main",
             "https://huggingface.co",
         );
-        let lister = HfLister::new(Arc::new(core), ctx, String::new(), false);
+        let lister = HfLister::new(Arc::new(core), ctx, String::new(), &OpList::new());
         let mut page_ctx = oio::PageContext {
             done: false,
             token: String::new(),
@@ -210,4 +216,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_list_with_version() -> Result<()> {
+        let (core, ctx, mock_client) = create_test_core(
+            HfRepoType::Dataset,
+            "test-org/test-dataset",
+            "main",
+            "https://huggingface.co",
+        );
+        let args = OpList::new().with_recursive(true).with_version("dev");
+        let lister = HfLister::new(Arc::new(core), ctx, "data/".to_string(), &args);
+        let mut page_ctx = oio::PageContext {
+            done: false,
+            token: String::new(),
+            entries: VecDeque::new(),
+        };
+
+        lister.next_page(&mut page_ctx).await?;
+
+        assert_eq!(
+            mock_client.get_captured_url(),
+            "https://huggingface.co/api/datasets/test-org/test-dataset/tree/dev/data?expand=True&recursive=True"
+        );
+
+        Ok(())
+    }
 }
@@ -16,11 +16,11 @@
 // under the License.
 
 use super::backend::*;
-use super::core::{HfCore, XetFileResponse};
+use super::core::{HfCore, XetFileResponse, parse_xet_error};
 use bytes::Buf;
 use opendal_core::raw::*;
 use opendal_core::*;
-use xet::xet_session::{SessionError, XetDownloadStream, XetFileInfo};
+use xet::xet_session::{XetDownloadStream, XetFileInfo};
 
 pub enum HfReadStream {
     Http(HttpBody),
@@ -32,20 +32,29 @@ impl HfReadStream {
         core: &HfCore,
         ctx: &OperationContext,
         path: &str,
+        version: Option<&str>,
         range: BytesRange,
     ) -> Result<(RpRead, Self)> {
-        let resp = core.resolve(ctx, path, range, core.download_mode).await?;
+        let resp = core
+            .resolve(ctx, path, version, range, core.download_mode)
+            .await?;
         if resp.headers().contains_key("x-xet-hash") {
             let (_, mut body) = resp.into_parts();
             let buf = body.to_buffer().await?;
             let info: XetFileResponse =
                 serde_json::from_reader(buf.reader()).map_err(new_json_deserialize_error)?;
-            let metadata = Metadata::new(EntryMode::FILE).with_content_length(info.size);
+            let mut metadata = Metadata::new(EntryMode::FILE).with_content_length(info.size);
+            if let Some(version) = version {
+                metadata.set_version(version);
+            }
             let reader =
                 Self::try_new_xet(core, &XetFileInfo::new(info.hash, info.size), range).await?;
             Ok((RpRead::new(metadata), reader))
         } else {
-            let metadata = parse_into_metadata(path, resp.headers())?;
+            let mut metadata = parse_into_metadata(path, resp.headers())?;
+            if let Some(version) = version {
+                metadata.set_version(version);
+            }
             Ok((RpRead::new(metadata), Self::Http(resp.into_body())))
         }
     }
@@ -68,22 +77,12 @@ impl HfReadStream {
         let mut stream = group
             .download_stream(file_info.clone(), xet_range)
             .await
-            .map_err(|err| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "failed to create xet download stream",
-                )
-                .set_source(err)
-            })?;
+            .map_err(|err| parse_xet_error(err, "failed to create xet download stream"))?;
         stream.start();
         Ok(Self::Xet(stream))
     }
 }
 
-fn map_session_error(e: SessionError) -> Error {
-    Error::new(ErrorKind::Unexpected, "xet read error").set_source(e)
-}
-
 impl oio::ReadStream for HfReadStream {
     async fn read(&mut self) -> Result<Buffer> {
         match self {
@@ -91,7 +90,7 @@ impl oio::ReadStream for HfReadStream {
             Self::Xet(stream) => match stream.next().await {
                 Ok(Some(bytes)) => Ok(Buffer::from(bytes)),
                 Ok(None) => Ok(Buffer::new()),
-                Err(e) => Err(map_session_error(e)),
+                Err(e) => Err(parse_xet_error(e, "xet read error")),
             },
         }
     }
@@ -102,14 +101,16 @@ pub struct HfReader {
     backend: HfBackend,
     ctx: OperationContext,
     path: String,
+    version: Option<String>,
 }
 
 impl HfReader {
-    pub(super) fn new(backend: HfBackend, ctx: OperationContext, path: &str, _: OpRead) -> Self {
+    pub(super) fn new(backend: HfBackend, ctx: OperationContext, path: &str, args: OpRead) -> Self {
         Self {
             backend,
             ctx,
             path: path.to_string(),
+            version: args.version().map(|v| v.to_string()),
         }
     }
 }
@@ -118,7 +119,9 @@ impl oio::StreamRead for HfReader {
     async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
         let backend = &self.backend;
         let path = self.path.as_str();
-        let (rp, stream) = HfReadStream::try_new(&backend.core, &self.ctx, path, range).await?;
+        let version = self.version.as_deref();
+        let (rp, stream) =
+            HfReadStream::try_new(&backend.core, &self.ctx, path, version, range).await?;
         Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
     }
 }
@@ -146,7 +149,7 @@ mod tests {
         );
 
         let (_, mut reader) =
-            HfReadStream::try_new(&core, &ctx, "config.json", BytesRange::default()).await?;
+            HfReadStream::try_new(&core, &ctx, "config.json", None, BytesRange::default()).await?;
 
         assert_eq!(
             mock_client.get_captured_url(),
@@ -169,7 +172,7 @@ mod tests {
         );
 
         let (rp, mut reader) =
-            HfReadStream::try_new(&core, &ctx, "test.txt", BytesRange::default()).await?;
+            HfReadStream::try_new(&core, &ctx, "test.txt", None, BytesRange::default()).await?;
         let metadata = rp.metadata().expect("read metadata must be returned");
 
         assert_eq!(metadata.mode(), EntryMode::FILE);
@@ -182,6 +185,34 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_http_read_with_version_uses_revision() -> Result<()> {
+        let (core, ctx, mock_client) = create_test_core(
+            HfRepoType::Dataset,
+            "test-org/test-dataset",
+            "main",
+            "https://huggingface.co",
+        );
+
+        let (rp, _) = HfReadStream::try_new(
+            &core,
+            &ctx,
+            "data/train.csv",
+            Some("v1.0"),
+            BytesRange::default(),
+        )
+        .await?;
+
+        assert_eq!(
+            mock_client.get_captured_url(),
+            "https://huggingface.co/datasets/test-org/test-dataset/resolve/v1%2E0/data/train.csv"
+        );
+        let metadata = rp.metadata().expect("read metadata must be returned");
+        assert_eq!(metadata.version(), Some("v1.0"));
+
+        Ok(())
+    }
+
     /// Exercises the XET download code path against a public dataset known to
     /// have XET-stored files. Behavior tests cannot reliably cover this path
     /// because the test dataset may not contain any XET files.
@@ -229,7 +260,7 @@ mod tests {
         .await
         .expect("commit should succeed");
 
-        let (_, mut reader) = HfReadStream::try_new(&core, &ctx, path, BytesRange::default())
+        let (_, mut reader) = HfReadStream::try_new(&core, &ctx, path, None, BytesRange::default())
             .await
             .expect("reading non-XET file in Xet mode should succeed via HTTP fallback");
 
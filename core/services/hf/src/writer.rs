@@ -17,7 +17,9 @@
 
 use std::sync::Arc;
 
-use super::core::{BucketOperation, HfCore, LfsFile};
+use super::core::{BucketOperation, HfCore, LfsFile, parse_xet_error};
+use super::reader::HfReadStream;
+use opendal_core::raw::oio::ReadStream;
 use opendal_core::raw::*;
 use opendal_core::*;
 use xet::error::XetError;
@@ -40,23 +42,71 @@ pub struct HfLazyWriter {
     core: Arc<HfCore>,
     ctx: OperationContext,
     path: String,
+    append: bool,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_not_exists: bool,
     inner: Option<HfWriter>,
 }
 
 impl HfLazyWriter {
-    pub fn new(core: Arc<HfCore>, ctx: OperationContext, path: String) -> Self {
+    pub fn new(core: Arc<HfCore>, ctx: OperationContext, path: String, args: &OpWrite) -> Self {
         Self {
             core,
             ctx,
             path,
+            append: args.append(),
+            if_match: args.if_match().map(String::from),
+            if_none_match: args.if_none_match().map(String::from),
+            if_not_exists: args.if_not_exists(),
             inner: None,
         }
     }
 
+    /// Check write conditions against the current file before committing.
+    ///
+    /// Hugging Face commits have no per-file preconditions, so the conditions
+    /// are checked right before the commit is created.
+    async fn check_conditions(&self) -> Result<()> {
+        if self.if_match.is_none() && self.if_none_match.is_none() && !self.if_not_exists {
+            return Ok(());
+        }
+
+        let current = match self.core.path_info(&self.ctx, &self.path).await {
+            Ok(info) => Some(info.metadata()?),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+        let etag = current.as_ref().and_then(|v| v.etag());
+
+        let matched = match self.if_match.as_deref() {
+            None => true,
+            Some("*") => current.is_some(),
+            Some(v) => etag == Some(v),
+        };
+        let none_matched = match self.if_none_match.as_deref() {
+            _ if self.if_not_exists => current.is_none(),
+            None => true,
+            Some("*") => current.is_none(),
+            Some(v) => etag != Some(v),
+        };
+        if matched && none_matched {
+            return Ok(());
+        }
+
+        Err(
+            Error::new(ErrorKind::ConditionNotMatch, "write condition not match")
+                .with_context("etag", etag.unwrap_or_default()),
+        )
+    }
+
     async fn inner(&mut self) -> Result<&mut HfWriter> {
         if self.inner.is_none() {
-            let writer =
+            let mut writer =
                 HfWriter::try_new(self.core.clone(), self.ctx.clone(), self.path.clone()).await?;
+            if self.append {
+                writer.copy_existing().await?;
+            }
             self.inner = Some(writer);
         }
 
@@ -70,6 +120,7 @@ impl oio::Write for HfLazyWriter {
     }
 
     async fn close(&mut self) -> Result<Metadata> {
+        self.check_conditions().await?;
         self.inner().await?.close().await
     }
 
@@ -93,10 +144,7 @@ impl HfWriter {
         let stream = commit
             .upload_stream(None, Sha256Policy::Compute)
             .await
-            .map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "failed to start xet upload stream")
-                    .set_source(err)
-            })?;
+            .map_err(|err| parse_xet_error(err, "failed to start xet upload stream"))?;
         Ok(HfWriter {
             core,
             ctx,
@@ -107,6 +155,42 @@ impl HfWriter {
         })
     }
 
+    /// Stream the current content of the target file into the upload.
+    ///
+    /// Hugging Face has no native append: every commit replaces the whole
+    /// file. Append is emulated by re-uploading the existing content before
+    /// the new data. Xet deduplicates the unchanged chunks, so only the
+    /// appended tail is actually transferred to CAS.
+    async fn copy_existing(&mut self) -> Result<()> {
+        match self.core.path_info(&self.ctx, &self.path).await {
+            Ok(info) if info.entry_mode() == EntryMode::FILE => {}
+            Ok(_) => {
+                return Err(Error::new(
+                    ErrorKind::IsADirectory,
+                    "append target is not a file",
+                ));
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        }
+
+        let (_, mut stream) = HfReadStream::try_new(
+            &self.core,
+            &self.ctx,
+            &self.path,
+            None,
+            BytesRange::default(),
+        )
+        .await?;
+        loop {
+            let bs = stream.read().await?;
+            if bs.is_empty() {
+                return Ok(());
+            }
+            oio::Write::write(self, bs).await?;
+        }
+    }
+
     /// Finalize the XET upload and register the file with HF.
     ///
     /// 1. Commit data to XET CAS (may already be done by `stream.finish()`)
@@ -119,9 +203,7 @@ impl HfWriter {
         match self.xet_commit.commit().await {
             Ok(_) | Err(XetError::AlreadyCompleted) => {}
             Err(e) => {
-                return Err(
-                    Error::new(ErrorKind::Unexpected, "failed to commit xet upload").set_source(e),
-                );
+                return Err(parse_xet_error(e, "failed to commit xet upload"));
             }
         }
 
@@ -163,9 +245,12 @@ impl HfWriter {
 
 impl oio::Write for HfWriter {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
-        self.xet_stream.write(bs.to_bytes()).await.map_err(|err| {
-            Error::new(ErrorKind::Unexpected, "failed to write xet chunk").set_source(err)
-        })
+        self.xet_stream
+            .write(bs.to_bytes())
+            .await
+            // The upload stream can't replay a chunk that failed half way,
+            // so never let RetryLayer retry it on the same stream.
+            .map_err(|err| parse_xet_error(err, "failed to write xet chunk").set_persistent())
     }
 
     async fn close(&mut self) -> Result<Metadata> {
@@ -187,8 +272,7 @@ impl oio::Write for HfWriter {
                 }
                 Err(err) => {
                     return Err(
-                        Error::new(ErrorKind::Unexpected, "failed to finish xet upload")
-                            .set_source(err),
+                        parse_xet_error(err, "failed to finish xet upload").set_persistent()
                     );
                 }
             },
@@ -226,4 +310,28 @@ mod tests {
 
         let _ = op.delete(path).await;
     }
+
+    /// Append re-uploads the existing content followed by the new data.
+    /// Requires HF_OPENDAL_BUCKET and HF_OPENDAL_TOKEN.
+    #[tokio::test]
+    #[ignore]
+    async fn test_bucket_append_roundtrip() {
+        let op = testing_bucket_operator();
+        let path = "tests/bucket-append.txt";
+        let _ = op.delete(path).await;
+
+        op.write_with(path, b"hello, ".as_slice())
+            .append(true)
+            .await
+            .expect("first append should create the file");
+        op.write_with(path, b"world".as_slice())
+            .append(true)
+            .await
+            .expect("second append should succeed");
+
+        let data = op.read(path).await.expect("read should succeed");
+        assert_eq!(data.to_bytes().as_ref(), b"hello, world");
+
+        let _ = op.delete(path).await;
+    }
 }
@@ -98,6 +98,16 @@ impl HttpBuilder {
 
         self
     }
+
+    /// Enable reading multiple ranges within one request.
+    ///
+    /// [`Reader::fetch`] will send all merged ranges in one `Range` header and
+    /// parse the `multipart/byteranges` response. Only enable this if the
+    /// server supports multi-range requests.
+    pub fn enable_multi_range_read(mut self, enabled: bool) -> Self {
+        self.config.enable_multi_range_read = enabled;
+        self
+    }
 }
 
 impl Builder for HttpBuilder {
@@ -133,12 +143,17 @@ impl Builder for HttpBuilder {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
 
             read: true,
             read_with_suffix: true,
+            read_with_multi_range: self.config.enable_multi_range_read,
 
             read_with_if_match: true,
             read_with_if_none_match: true,
+            read_with_if_modified_since: true,
+            read_with_if_unmodified_since: true,
 
             presign: auth.is_none(),
             presign_read: auth.is_none(),
@@ -310,3 +325,82 @@ impl Service for HttpBackend {
         )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+    use http::Response;
+    use http::header;
+
+    use super::*;
+
+    const CONTENT: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    /// Serve `multipart/byteranges` for multi-range requests.
+    struct MockServer;
+
+    impl HttpTransport for MockServer {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let range = req.headers()[header::RANGE].to_str().unwrap().to_string();
+            let ranges = range
+                .strip_prefix("bytes=")
+                .unwrap()
+                .split(',')
+                .map(|v| {
+                    let (start, end) = v.split_once('-').unwrap();
+                    (
+                        start.parse::<usize>().unwrap(),
+                        end.parse::<usize>().unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(ranges.len(), 3, "all merged ranges must be sent at once");
+
+            let mut content = vec![];
+            for (start, end) in &ranges {
+                content.extend_from_slice(
+                    format!(
+                        "--bd\r\nContent-Range: bytes {start}-{end}/{}\r\n\r\n",
+                        CONTENT.len()
+                    )
+                    .as_bytes(),
+                );
+                content.extend_from_slice(&CONTENT[*start..=*end]);
+                content.extend_from_slice(b"\r\n");
+            }
+            content.extend_from_slice(b"--bd--\r\n");
+
+            let size = content.len() as u64;
+            let body = futures::stream::iter(vec![Ok(Buffer::from(content))]);
+            Ok(Response::builder()
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header(header::CONTENT_TYPE, "multipart/byteranges; boundary=bd")
+                .body(HttpBody::new(body, Some(size)))
+                .unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_multi_range() -> Result<()> {
+        let builder = HttpBuilder::default()
+            .endpoint("http://http.test")
+            .enable_multi_range_read(true);
+        let op = Operator::new(builder)?.with_context(
+            OperationContext::new().with_http_transport(HttpTransporter::new(MockServer)),
+        );
+
+        let reader = op.reader_with("file").gap(1).await?;
+        let bufs = reader.fetch(vec![0..2, 30..36, 10..12, 1..3]).await?;
+        let bufs = bufs.into_iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+        assert_eq!(
+            bufs,
+            vec![
+                b"01".to_vec(),
+                b"uvwxyz".to_vec(),
+                b"ab".to_vec(),
+                b"12".to_vec()
+            ]
+        );
+        Ok(())
+    }
+}
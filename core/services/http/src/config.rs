@@ -37,6 +37,11 @@ pub struct HttpConfig {
     pub token: Option<String>,
     /// root of this backend
     pub root: Option<String>,
+    /// Enable reading multiple ranges within one request.
+    ///
+    /// Only enable this if the server responds to multi-range requests
+    /// with `multipart/byteranges`, otherwise the whole file may be returned.
+    pub enable_multi_range_read: bool,
 }
 
 impl Debug for HttpConfig {
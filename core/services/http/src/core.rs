@@ -21,7 +21,9 @@ use http::Request;
 use http::Response;
 use http::header;
 use http::header::IF_MATCH;
+use http::header::IF_MODIFIED_SINCE;
 use http::header::IF_NONE_MATCH;
+use http::header::IF_UNMODIFIED_SINCE;
 
 use opendal_core::raw::*;
 use opendal_core::*;
@@ -55,6 +57,34 @@ impl HttpCore {
         path: &str,
         range: BytesRange,
         args: &OpRead,
+    ) -> Result<Request<Buffer>> {
+        let range = (!range.is_full()).then(|| range.to_header());
+        self.http_get_request_with_range(path, range, args)
+    }
+
+    /// Build a get request with all given ranges in one `Range` header.
+    pub fn http_get_ranges_request(
+        &self,
+        path: &str,
+        ranges: &[BytesRange],
+        args: &OpRead,
+    ) -> Result<Request<Buffer>> {
+        let range = format!(
+            "bytes={}",
+            ranges
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        self.http_get_request_with_range(path, Some(range), args)
+    }
+
+    fn http_get_request_with_range(
+        &self,
+        path: &str,
+        range: Option<String>,
+        args: &OpRead,
     ) -> Result<Request<Buffer>> {
         let p = build_rooted_abs_path(&self.root, path);
 
@@ -70,12 +100,20 @@ impl HttpCore {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(IF_MODIFIED_SINCE, if_modified_since.format_http_date());
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
+        }
+
         if let Some(auth) = &self.authorization {
             req = req.header(header::AUTHORIZATION, auth.clone())
         }
 
-        if !range.is_full() {
-            req = req.header(header::RANGE, range.to_header());
+        if let Some(range) = range {
+            req = req.header(header::RANGE, range);
         }
 
         let req = req
@@ -96,6 +134,17 @@ impl HttpCore {
         ctx.http_transport().fetch(req).await
     }
 
+    pub async fn http_get_ranges(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        ranges: &[BytesRange],
+        args: &OpRead,
+    ) -> Result<Response<Buffer>> {
+        let req = self.http_get_ranges_request(path, ranges, args)?;
+        ctx.http_transport().send(req).await
+    }
+
     pub fn http_head_request(&self, path: &str, args: &OpStat) -> Result<Request<Buffer>> {
         let p = build_rooted_abs_path(&self.root, path);
 
@@ -111,6 +160,14 @@ impl HttpCore {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(IF_MODIFIED_SINCE, if_modified_since.format_http_date());
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
+        }
+
         if let Some(auth) = &self.authorization {
             req = req.header(header::AUTHORIZATION, auth.clone())
         }
@@ -173,3 +230,41 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn core() -> HttpCore {
+        HttpCore {
+            info: ServiceInfo::new("http", "/", ""),
+            capability: Capability::default(),
+            endpoint: "https://example.com".to_string(),
+            root: "/".to_string(),
+            authorization: None,
+        }
+    }
+
+    #[test]
+    fn test_conditional_time_headers() {
+        let ts = Timestamp::from_second(1_700_000_000).unwrap();
+
+        let args = OpRead::new().with_if_modified_since(ts);
+        let req = core()
+            .http_get_request("file", BytesRange::default(), &args)
+            .unwrap();
+        assert_eq!(
+            req.headers().get(IF_MODIFIED_SINCE).unwrap(),
+            "Tue, 14 Nov 2023 22:13:20 GMT"
+        );
+        assert!(req.headers().get(IF_UNMODIFIED_SINCE).is_none());
+
+        let args = OpStat::new().with_if_unmodified_since(ts);
+        let req = core().http_head_request("file", &args).unwrap();
+        assert_eq!(
+            req.headers().get(IF_UNMODIFIED_SINCE).unwrap(),
+            "Tue, 14 Nov 2023 22:13:20 GMT"
+        );
+        assert!(req.headers().get(IF_MODIFIED_SINCE).is_none());
+    }
+}
@@ -69,4 +69,74 @@ impl oio::StreamRead for HttpReader {
 
         Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
     }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        if !self.backend.core.capability.read_with_multi_range {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "multi range read is not enabled",
+            ));
+        }
+
+        let path = self.path.as_str();
+        let resp = self
+            .backend
+            .core
+            .http_get_ranges(&self.ctx, path, &ranges, &self.args)
+            .await?;
+
+        // Collect the returned parts with their absolute offsets, servers
+        // are allowed to coalesce or reorder requested ranges.
+        let (rp, parts) = match resp.status() {
+            StatusCode::OK => {
+                let rp = RpRead::new(parse_into_metadata(path, resp.headers())?);
+                (rp, vec![(0, resp.into_body().to_bytes())])
+            }
+            StatusCode::PARTIAL_CONTENT => match parse_multipart_boundary(resp.headers())? {
+                Some(boundary) => {
+                    let boundary = boundary.trim_matches('"').to_string();
+                    let parts = parse_multipart_byteranges(&boundary, resp.into_body().to_bytes())?
+                        .into_iter()
+                        .filter_map(|(range, bs)| range.range().map(|v| (v.start, bs)))
+                        .collect();
+                    (RpRead::default(), parts)
+                }
+                None => {
+                    let range = parse_content_range(resp.headers())?
+                        .and_then(|v| v.range())
+                        .ok_or_else(|| {
+                            Error::new(
+                                ErrorKind::Unexpected,
+                                "partial content response doesn't contain content range",
+                            )
+                        })?;
+                    (
+                        RpRead::default(),
+                        vec![(range.start, resp.into_body().to_bytes())],
+                    )
+                }
+            },
+            _ => return Err(parse_error(resp)),
+        };
+
+        let buffers = ranges
+            .iter()
+            .map(|range| {
+                let offset = range.offset();
+                let end = offset + range.size().unwrap_or_default();
+                parts
+                    .iter()
+                    .find(|(start, bs)| *start <= offset && end <= start + bs.len() as u64)
+                    .map(|(start, bs)| {
+                        Buffer::from(bs.slice((offset - start) as usize..(end - start) as usize))
+                    })
+                    .ok_or_else(|| {
+                        Error::new(ErrorKind::Unexpected, "response doesn't contain range")
+                            .with_context("range", range.to_string())
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((rp, buffers))
+    }
 }
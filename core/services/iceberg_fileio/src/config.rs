@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::IcebergFileIOBuilder;
+
+/// Config for Iceberg REST catalog FileIO services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct IcebergFileIOConfig {
+    /// The base uri of the REST catalog, for example `https://catalog.example.com/api/catalog`.
+    ///
+    /// required.
+    pub catalog_uri: String,
+    /// The catalog prefix returned by the `/v1/config` endpoint, usually
+    /// the warehouse name.
+    pub prefix: Option<String>,
+    /// The namespace of the table, levels are separated by `.`.
+    ///
+    /// required.
+    pub namespace: String,
+    /// The name of the table.
+    ///
+    /// required.
+    pub table: String,
+    /// The location of the table, for example `s3://bucket/warehouse/db/table`.
+    ///
+    /// All operations will happen under this location.
+    ///
+    /// required.
+    pub location: String,
+    /// The bearer token used to authenticate against the catalog.
+    pub token: Option<String>,
+    /// The region of the bucket that holds the table.
+    pub region: Option<String>,
+    /// The endpoint of the object storage that holds the table.
+    pub endpoint: Option<String>,
+}
+
+impl Debug for IcebergFileIOConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IcebergFileIOConfig")
+            .field("catalog_uri", &self.catalog_uri)
+            .field("prefix", &self.prefix)
+            .field("namespace", &self.namespace)
+            .field("table", &self.table)
+            .field("location", &self.location)
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for IcebergFileIOConfig {
+    type Builder = IcebergFileIOBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(name) = uri.name() {
+            map.insert("namespace".to_string(), name.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("table".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        IcebergFileIOBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_extracts_namespace_and_table() {
+        let uri = OperatorUri::new(
+            "iceberg-fileio://db/events?catalog_uri=http://127.0.0.1:8181&location=s3://bucket/db/events",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = IcebergFileIOConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.namespace, "db");
+        assert_eq!(cfg.table, "events");
+        assert_eq!(cfg.catalog_uri, "http://127.0.0.1:8181");
+        assert_eq!(cfg.location, "s3://bucket/db/events");
+    }
+}
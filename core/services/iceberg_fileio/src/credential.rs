@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::Method;
+use http::Request;
+use http::StatusCode;
+use http::header::AUTHORIZATION;
+use log::debug;
+use reqsign_aws_v4::Credential;
+use reqsign_core::Context;
+use reqsign_core::Error;
+use reqsign_core::ProvideCredential;
+use reqsign_core::Result;
+use reqsign_core::time::Timestamp;
+use serde::Deserialize;
+
+/// Ask the catalog to vend credentials in the `loadTable` response.
+const X_ICEBERG_ACCESS_DELEGATION: &str = "X-Iceberg-Access-Delegation";
+
+const S3_ACCESS_KEY_ID: &str = "s3.access-key-id";
+const S3_SECRET_ACCESS_KEY: &str = "s3.secret-access-key";
+const S3_SESSION_TOKEN: &str = "s3.session-token";
+const S3_SESSION_TOKEN_EXPIRES_AT_MS: &str = "s3.session-token-expires-at-ms";
+
+/// Catalogs are not required to tell when vended credentials expire, reload
+/// them before the shortest STS session would run out.
+const DEFAULT_CREDENTIAL_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Provide S3 credentials vended by an Iceberg REST catalog.
+///
+/// Credentials are loaded from the `loadTable` endpoint of the table, the
+/// signer caches them and calls this provider again once they are about to
+/// expire.
+#[derive(Debug, Clone)]
+pub struct VendedCredentialProvider {
+    endpoint: String,
+    location: String,
+    token: Option<String>,
+}
+
+impl VendedCredentialProvider {
+    pub fn new(endpoint: String, location: String, token: Option<String>) -> Self {
+        Self {
+            endpoint,
+            location,
+            token,
+        }
+    }
+}
+
+impl ProvideCredential for VendedCredentialProvider {
+    type Credential = Credential;
+
+    async fn provide_credential(&self, ctx: &Context) -> Result<Option<Self::Credential>> {
+        debug!("loading vended credentials from {}", self.endpoint);
+
+        let mut req = Request::builder()
+            .method(Method::GET)
+            .uri(&self.endpoint)
+            .header(X_ICEBERG_ACCESS_DELEGATION, "vended-credentials");
+        if let Some(token) = &self.token {
+            req = req.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let req = req.body(Bytes::new()).map_err(|e| {
+            Error::request_invalid("failed to build loadTable request").with_source(e)
+        })?;
+
+        let resp = ctx.http_send(req).await.map_err(|e| {
+            Error::unexpected("failed to load table from catalog")
+                .with_source(e)
+                .set_retryable(true)
+        })?;
+
+        let status = resp.status();
+        if status != StatusCode::OK {
+            let body = String::from_utf8_lossy(resp.body());
+            let err = match status {
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    Error::permission_denied(format!("catalog denied loadTable: {body}"))
+                }
+                StatusCode::NOT_FOUND => {
+                    Error::config_invalid(format!("table not found in catalog: {body}"))
+                }
+                _ if status.is_server_error() => {
+                    Error::unexpected(format!("catalog returned {status}: {body}"))
+                        .set_retryable(true)
+                }
+                _ => Error::unexpected(format!("catalog returned {status}: {body}")),
+            };
+            return Err(err.with_context(format!("endpoint: {}", self.endpoint)));
+        }
+
+        let result: LoadTableResult = serde_json::from_slice(resp.body())
+            .map_err(|e| Error::unexpected("failed to parse loadTable response").with_source(e))?;
+
+        let Some(config) = result.vended_config(&self.location) else {
+            debug!("catalog didn't vend credentials for {}", self.location);
+            return Ok(None);
+        };
+
+        parse_credential(config)
+    }
+}
+
+/// The part of `LoadTableResult` that carries storage config.
+///
+/// Reference: <https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml>
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct LoadTableResult {
+    config: HashMap<String, String>,
+    storage_credentials: Vec<StorageCredential>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct StorageCredential {
+    prefix: String,
+    config: HashMap<String, String>,
+}
+
+impl LoadTableResult {
+    /// Pick the config holding credentials for the given location.
+    ///
+    /// `storage-credentials` takes precedence and the entry with the longest
+    /// matching prefix wins, older catalogs only return them in `config`.
+    pub fn vended_config(&self, location: &str) -> Option<&HashMap<String, String>> {
+        let matched = self
+            .storage_credentials
+            .iter()
+            .filter(|v| location.starts_with(&v.prefix))
+            .max_by_key(|v| v.prefix.len());
+        if let Some(v) = matched {
+            return Some(&v.config);
+        }
+
+        self.config
+            .contains_key(S3_ACCESS_KEY_ID)
+            .then_some(&self.config)
+    }
+}
+
+fn parse_credential(config: &HashMap<String, String>) -> Result<Option<Credential>> {
+    let (Some(ak), Some(sk)) = (
+        config.get(S3_ACCESS_KEY_ID),
+        config.get(S3_SECRET_ACCESS_KEY),
+    ) else {
+        return Ok(None);
+    };
+
+    let expires_in = match config.get(S3_SESSION_TOKEN_EXPIRES_AT_MS) {
+        Some(v) => {
+            let ms = v.parse::<i64>().map_err(|e| {
+                Error::unexpected("failed to parse vended credential expiration")
+                    .with_source(e)
+                    .with_context(format!("value: {v}"))
+            })?;
+            Timestamp::from_millisecond(ms)?
+        }
+        None => Timestamp::now() + DEFAULT_CREDENTIAL_TTL,
+    };
+
+    Ok(Some(Credential {
+        access_key_id: ak.clone(),
+        secret_access_key: sk.clone(),
+        session_token: config.get(S3_SESSION_TOKEN).cloned(),
+        expires_in: Some(expires_in),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vended_config_prefers_longest_prefix() {
+        let result: LoadTableResult = serde_json::from_str(
+            r#"{
+                "metadata-location": "s3://bucket/db/events/metadata/v1.metadata.json",
+                "config": {"s3.access-key-id": "table", "s3.secret-access-key": "table"},
+                "storage-credentials": [
+                    {"prefix": "s3://bucket/", "config": {"s3.access-key-id": "bucket"}},
+                    {"prefix": "s3://bucket/db/events", "config": {"s3.access-key-id": "events"}},
+                    {"prefix": "s3://other/", "config": {"s3.access-key-id": "other"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let config = result.vended_config("s3://bucket/db/events").unwrap();
+        assert_eq!(config[S3_ACCESS_KEY_ID], "events");
+        let config = result.vended_config("s3://bucket/db/users").unwrap();
+        assert_eq!(config[S3_ACCESS_KEY_ID], "bucket");
+        let config = result.vended_config("s3://another/db").unwrap();
+        assert_eq!(config[S3_ACCESS_KEY_ID], "table");
+    }
+
+    #[test]
+    fn test_parse_credential() {
+        let config = HashMap::from([
+            (S3_ACCESS_KEY_ID.to_string(), "ak".to_string()),
+            (S3_SECRET_ACCESS_KEY.to_string(), "sk".to_string()),
+            (S3_SESSION_TOKEN.to_string(), "token".to_string()),
+            (
+                S3_SESSION_TOKEN_EXPIRES_AT_MS.to_string(),
+                "1700000000000".to_string(),
+            ),
+        ]);
+
+        let cred = parse_credential(&config).unwrap().unwrap();
+        assert_eq!(cred.access_key_id, "ak");
+        assert_eq!(cred.secret_access_key, "sk");
+        assert_eq!(cred.session_token.as_deref(), Some("token"));
+        assert_eq!(
+            cred.expires_in,
+            Some(Timestamp::from_millisecond(1_700_000_000_000).unwrap())
+        );
+
+        assert!(parse_credential(&HashMap::new()).unwrap().is_none());
+    }
+}
@@ -45,9 +45,12 @@ use super::config::IpmfsConfig;
 ///
 /// This service can be used to:
 ///
+/// - [x] create_dir
 /// - [x] read
 /// - [x] write
+/// - [x] delete
 /// - [x] list
+/// - [x] rename
 /// - [ ] presign
 /// - [ ] blocking
 ///
@@ -55,6 +58,7 @@ use super::config::IpmfsConfig;
 ///
 /// - `root`: Set the work directory for backend
 /// - `endpoint`: Customizable endpoint setting
+/// - `pin`: Add written files via `/api/v0/add` and pin them
 ///
 /// You can refer to [`IpmfsBuilder`]'s docs for more information
 ///
@@ -112,6 +116,15 @@ impl IpmfsBuilder {
         };
         self
     }
+
+    /// Pin written files.
+    ///
+    /// Files will be added via `/api/v0/add` with pinning and then linked
+    /// into MFS, instead of being written via `/api/v0/files/write`.
+    pub fn pin(mut self, pin: bool) -> Self {
+        self.config.pin = pin;
+        self
+    }
 }
 
 impl Builder for IpmfsBuilder {
@@ -134,7 +147,9 @@ impl Builder for IpmfsBuilder {
             read: true,
 
             write: true,
+            create_dir: true,
             delete: true,
+            rename: true,
 
             list: true,
 
@@ -149,6 +164,7 @@ impl Builder for IpmfsBuilder {
             capability,
             root: root.to_string(),
             endpoint: endpoint.to_string(),
+            pin: self.config.pin,
         });
 
         Ok(IpmfsBackend { core })
@@ -216,6 +232,9 @@ impl Service for IpmfsBackend {
 
                 let mut meta = Metadata::new(mode);
                 meta.set_content_length(res.size);
+                if !res.hash.is_empty() {
+                    meta.set_etag(&res.hash);
+                }
 
                 Ok(RpStat::new(meta))
             }
@@ -283,15 +302,19 @@ impl Service for IpmfsBackend {
 
     async fn rename(
         &self,
-        _ctx: &OperationContext,
-        _from: &str,
-        _to: &str,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
         _args: OpRename,
     ) -> Result<RpRename> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        self.core.ensure_parent(ctx, to).await?;
+
+        let resp = self.core.ipmfs_mv(ctx, from, to).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpRename::default()),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn presign(
@@ -314,4 +337,6 @@ struct IpfsStatResponse {
     size: u64,
     #[serde(rename = "Type")]
     file_type: String,
+    #[serde(rename = "Hash")]
+    hash: String,
 }
@@ -29,6 +29,15 @@ pub struct IpmfsConfig {
     pub root: Option<String>,
     /// Endpoint for ipfs.
     pub endpoint: Option<String>,
+    /// Pin written files.
+    ///
+    /// Files are added via `/api/v0/add` with pinning instead of
+    /// `/api/v0/files/write`, so they are kept by the node's garbage
+    /// collector and announced to the network even if removed from MFS
+    /// later.
+    ///
+    /// default to `false` if not set.
+    pub pin: bool,
 }
 
 impl opendal_core::Configurator for IpmfsConfig {
@@ -63,7 +72,7 @@ mod tests {
     #[test]
     fn from_uri_sets_endpoint_and_root() {
         let uri = OperatorUri::new(
-            "ipmfs://localhost:5001/mfs/path",
+            "ipmfs://localhost:5001/mfs/path?pin=true",
             Vec::<(String, String)>::new(),
         )
         .unwrap();
@@ -71,5 +80,6 @@ mod tests {
         let cfg = IpmfsConfig::from_uri(&uri).unwrap();
         assert_eq!(cfg.endpoint.as_deref(), Some("http://localhost:5001"));
         assert_eq!(cfg.root.as_deref(), Some("mfs/path"));
+        assert!(cfg.pin);
     }
 }
@@ -20,6 +20,7 @@ use std::fmt::Write;
 
 use http::Request;
 use http::Response;
+use http::StatusCode;
 
 use opendal_core::raw::*;
 use opendal_core::*;
@@ -29,6 +30,7 @@ pub struct IpmfsCore {
     pub capability: Capability,
     pub root: String,
     pub endpoint: String,
+    pub pin: bool,
 }
 
 impl Debug for IpmfsCore {
@@ -36,6 +38,7 @@ impl Debug for IpmfsCore {
         f.debug_struct("IpmfsCore")
             .field("root", &self.root)
             .field("endpoint", &self.endpoint)
+            .field("pin", &self.pin)
             .finish_non_exhaustive()
     }
 }
@@ -88,11 +91,15 @@ impl IpmfsCore {
     pub async fn ipmfs_rm(&self, ctx: &OperationContext, path: &str) -> Result<Response<Buffer>> {
         let p = build_rooted_abs_path(&self.root, path);
 
-        let url = format!(
+        // Directories can only be removed with `recursive`.
+        let mut url = format!(
             "{}/api/v0/files/rm?arg={}",
             self.endpoint,
             percent_encode_path(&p)
         );
+        if path.ends_with('/') {
+            url.push_str("&recursive=true");
+        }
 
         let req = Request::post(url)
             .extension(Operation::Delete)
@@ -168,6 +175,71 @@ impl IpmfsCore {
 
         ctx.http_transport().send(req).await
     }
+
+    /// Add the content to ipfs, pin it and link it into MFS at `path`.
+    ///
+    /// The parent of `path` must exist and `path` itself must not.
+    pub async fn ipmfs_add(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/api/v0/add?pin=true&to-files={}",
+            self.endpoint,
+            percent_encode_path(&p)
+        );
+
+        let multipart = Multipart::new().part(FormDataPart::new("file").content(body));
+
+        let req: http::request::Builder = Request::post(url)
+            .extension(Operation::Write)
+            .extension(ServiceOperation("Add"));
+        let req = multipart.apply(req)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ipmfs_mv(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+    ) -> Result<Response<Buffer>> {
+        let from = build_rooted_abs_path(&self.root, from);
+        let to = build_rooted_abs_path(&self.root, to);
+
+        let url = format!(
+            "{}/api/v0/files/mv?arg={}&arg={}",
+            self.endpoint,
+            percent_encode_path(&from),
+            percent_encode_path(&to)
+        );
+
+        let req = Request::post(url)
+            .extension(Operation::Rename)
+            .extension(ServiceOperation("Mv"));
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    /// Create the parent directory of `path` if it's not the root.
+    pub async fn ensure_parent(&self, ctx: &OperationContext, path: &str) -> Result<()> {
+        let parent = get_parent(path);
+        if parent == "/" {
+            return Ok(());
+        }
+
+        let resp = self.ipmfs_mkdir(ctx, parent).await?;
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
 }
 
 mod error {
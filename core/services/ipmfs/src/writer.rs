@@ -17,7 +17,9 @@
 
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::StatusCode;
+use serde::Deserialize;
 
 use super::core::IpmfsCore;
 use super::core::parse_error;
@@ -36,8 +38,45 @@ impl IpmfsWriter {
     }
 }
 
+impl IpmfsWriter {
+    /// Add the content via `/api/v0/add` so that it's pinned.
+    ///
+    /// `add` refuses to link over an existing entry, so the old file is
+    /// removed first.
+    async fn add_once(&self, bs: Buffer) -> Result<Metadata> {
+        self.core.ensure_parent(&self.ctx, &self.path).await?;
+
+        let resp = self.core.ipmfs_rm(&self.ctx, &self.path).await?;
+        if resp.status() != StatusCode::OK {
+            let err = parse_error(resp);
+            if err.kind() != ErrorKind::NotFound {
+                return Err(err);
+            }
+        }
+
+        let resp = self.core.ipmfs_add(&self.ctx, &self.path, bs).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let added: IpfsAddResponse = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                let mut meta = Metadata::default();
+                if !added.hash.is_empty() {
+                    meta.set_etag(&added.hash);
+                }
+                Ok(meta)
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
 impl oio::OneShotWrite for IpmfsWriter {
     async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        if self.core.pin {
+            return self.add_once(bs).await;
+        }
+
         let resp = self.core.ipmfs_write(&self.ctx, &self.path, bs).await?;
 
         let status = resp.status();
@@ -48,3 +87,10 @@ impl oio::OneShotWrite for IpmfsWriter {
         }
     }
 }
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+struct IpfsAddResponse {
+    #[serde(rename = "Hash")]
+    hash: String,
+}
@@ -0,0 +1,354 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use mea::mutex::Mutex;
+use mea::once::OnceCell;
+
+use super::KAFKA_SCHEME;
+use super::config::KafkaConfig;
+use super::core::*;
+use super::deleter::KafkaDeleter;
+use super::lister::KafkaLister;
+use super::reader::KafkaReader;
+use super::writer::KafkaWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Kafka brokers reject messages larger than 1 MiB by default.
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+/// Kafka topic as append-only object store.
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct KafkaBuilder {
+    pub(super) config: KafkaConfig,
+}
+
+impl KafkaBuilder {
+    /// Set the bootstrap brokers of the Kafka cluster, separated by `,`.
+    pub fn brokers(mut self, brokers: &str) -> Self {
+        if !brokers.is_empty() {
+            self.config.brokers = Some(brokers.to_string());
+        }
+        self
+    }
+
+    /// Set the topic that stores object content.
+    pub fn topic(mut self, topic: &str) -> Self {
+        if !topic.is_empty() {
+            self.config.topic = Some(topic.to_string());
+        }
+        self
+    }
+
+    /// Set the compacted topic that maps paths to offsets.
+    ///
+    /// default is `{topic}-index`
+    pub fn index_topic(mut self, topic: &str) -> Self {
+        if !topic.is_empty() {
+            self.config.index_topic = Some(topic.to_string());
+        }
+        self
+    }
+
+    /// Set the partition of both topics to use.
+    pub fn partition(mut self, partition: i32) -> Self {
+        self.config.partition = Some(partition);
+        self
+    }
+
+    /// Set the client id reported to the brokers.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        if !client_id.is_empty() {
+            self.config.client_id = Some(client_id.to_string());
+        }
+        self
+    }
+
+    /// Set the max size of an object.
+    ///
+    /// It must not exceed the `max.message.bytes` of the topic.
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.config.max_message_size = Some(size);
+        self
+    }
+
+    /// Set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for KafkaBuilder {
+    type Config = KafkaConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let brokers: Vec<String> = self
+            .config
+            .brokers
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect();
+        if brokers.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "brokers is required")
+                .with_operation("Builder::build")
+                .with_context("service", KAFKA_SCHEME));
+        }
+
+        let topic = match self.config.topic.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "topic is required")
+                    .with_operation("Builder::build")
+                    .with_context("service", KAFKA_SCHEME));
+            }
+        };
+        let index_topic = self
+            .config
+            .index_topic
+            .clone()
+            .unwrap_or_else(|| format!("{topic}-index"));
+        if index_topic == topic {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "index_topic must be different from topic",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", KAFKA_SCHEME));
+        }
+
+        let root = normalize_root(
+            self.config
+                .root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        let core = KafkaCore {
+            brokers,
+            topic,
+            index_topic,
+            partition: self.config.partition.unwrap_or_default(),
+            client_id: self.config.client_id.clone(),
+            max_message_size: self
+                .config
+                .max_message_size
+                .unwrap_or(DEFAULT_MAX_MESSAGE_SIZE),
+            clients: OnceCell::new(),
+            index: Mutex::new(KafkaIndex::default()),
+        };
+
+        debug!("backend build finished: {:?}", &core);
+        Ok(KafkaBackend::new(core, root))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KafkaBackend {
+    pub(crate) core: Arc<KafkaCore>,
+    pub(crate) root: String,
+    pub(crate) info: ServiceInfo,
+    pub(crate) capability: Capability,
+}
+
+impl KafkaBackend {
+    fn new(core: KafkaCore, root: String) -> Self {
+        let info = ServiceInfo::new(KAFKA_SCHEME, &root, &core.topic);
+        let capability = Capability {
+            read: true,
+
+            write: true,
+            write_can_empty: true,
+            write_with_content_type: true,
+            write_total_max_size: Some(core.max_message_size),
+
+            delete: true,
+            stat: true,
+            list: true,
+            list_with_recursive: true,
+            shared: true,
+            ..Default::default()
+        };
+
+        Self {
+            core: Arc::new(core),
+            root,
+            info,
+            capability,
+        }
+    }
+}
+
+impl Service for KafkaBackend {
+    type Reader = oio::StreamReader<KafkaReader>;
+    type Writer = KafkaWriter;
+    type Lister = oio::HierarchyLister<KafkaLister>;
+    type Deleter = oio::OneShotDeleter<KafkaDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let p = build_abs_path(&self.root, path);
+        if p == build_abs_path(&self.root, "") {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+
+        let index = self.core.refresh().await?;
+        if let Some(entry) = index.get(&p) {
+            return Ok(RpStat::new(entry.metadata()));
+        }
+        if p.ends_with('/') && index.has_prefix(&p) {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+        Err(Error::new(ErrorKind::NotFound, "key not found in kafka"))
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        let p = build_abs_path(&self.root, path);
+        Ok(oio::StreamReader::new(KafkaReader::new(
+            self.core.clone(),
+            p,
+            args,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let p = build_abs_path(&self.root, path);
+        Ok(KafkaWriter::new(self.core.clone(), p, args))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(KafkaDeleter::new(
+            self.core.clone(),
+            self.root.clone(),
+        )))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let lister = KafkaLister::new(self.core.clone(), self.root.clone(), path.to_string());
+        Ok(oio::HierarchyLister::new(lister, path, args.recursive()))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let backend = KafkaBuilder::default()
+            .brokers("127.0.0.1:9092, 127.0.0.1:9093")
+            .topic("objects")
+            .build()
+            .unwrap();
+
+        let info = backend.info();
+        assert_eq!(info.scheme(), KAFKA_SCHEME);
+        assert_eq!(info.name().as_ref(), "objects");
+        assert_eq!(
+            backend.capability().write_total_max_size,
+            Some(DEFAULT_MAX_MESSAGE_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_same_index_topic() {
+        let err = KafkaBuilder::default()
+            .brokers("127.0.0.1:9092")
+            .topic("objects")
+            .index_topic("objects")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
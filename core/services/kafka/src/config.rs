@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::KafkaBuilder;
+
+/// Config for Kafka services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct KafkaConfig {
+    /// The bootstrap brokers of the Kafka cluster, separated by `,`.
+    ///
+    /// e.g. "127.0.0.1:9092,127.0.0.1:9093"
+    pub brokers: Option<String>,
+    /// The topic that stores object content.
+    ///
+    /// required.
+    pub topic: Option<String>,
+    /// The compacted topic that maps paths to offsets in `topic`.
+    ///
+    /// default is `{topic}-index`
+    pub index_topic: Option<String>,
+    /// The partition of both topics to use.
+    ///
+    /// default is 0
+    pub partition: Option<i32>,
+    /// The client id reported to the brokers.
+    pub client_id: Option<String>,
+    /// The max size of an object, must not exceed the `max.message.bytes` of
+    /// the topic.
+    ///
+    /// default is 1 MiB
+    pub max_message_size: Option<usize>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    pub root: Option<String>,
+}
+
+impl Debug for KafkaConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaConfig")
+            .field("brokers", &self.brokers)
+            .field("topic", &self.topic)
+            .field("index_topic", &self.index_topic)
+            .field("partition", &self.partition)
+            .field("client_id", &self.client_id)
+            .field("max_message_size", &self.max_message_size)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+impl opendal_core::Configurator for KafkaConfig {
+    type Builder = KafkaBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(authority) = uri.authority() {
+            map.entry("brokers".to_string())
+                .or_insert_with(|| authority.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        KafkaBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_brokers_topic_and_root() {
+        let uri = OperatorUri::new(
+            "kafka://127.0.0.1:9092/archive/logs?topic=objects&partition=2",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = KafkaConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.brokers.as_deref(), Some("127.0.0.1:9092"));
+        assert_eq!(cfg.topic.as_deref(), Some("objects"));
+        assert_eq!(cfg.partition, Some(2));
+        assert_eq!(cfg.root.as_deref(), Some("archive/logs"));
+    }
+}
@@ -0,0 +1,381 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use mea::mutex::Mutex;
+use mea::mutex::MutexGuard;
+use mea::once::OnceCell;
+use rskafka::chrono::DateTime;
+use rskafka::client::ClientBuilder;
+use rskafka::client::partition::Compression;
+use rskafka::client::partition::OffsetAt;
+use rskafka::client::partition::PartitionClient;
+use rskafka::client::partition::UnknownTopicHandling;
+use rskafka::record::Record;
+use serde::Deserialize;
+use serde::Serialize;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The max bytes fetched per request while catching up the index topic.
+const INDEX_FETCH_MAX_BYTES: i32 = 1024 * 1024;
+/// Extra bytes fetched on top of the object size to hold the record batch header.
+const RECORD_OVERHEAD: usize = 64 * 1024;
+
+pub struct KafkaCore {
+    pub brokers: Vec<String>,
+    pub topic: String,
+    pub index_topic: String,
+    pub partition: i32,
+    pub client_id: Option<String>,
+    pub max_message_size: usize,
+
+    pub clients: OnceCell<KafkaClients>,
+    pub index: Mutex<KafkaIndex>,
+}
+
+impl Debug for KafkaCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaCore")
+            .field("brokers", &self.brokers)
+            .field("topic", &self.topic)
+            .field("index_topic", &self.index_topic)
+            .field("partition", &self.partition)
+            .field("max_message_size", &self.max_message_size)
+            .finish_non_exhaustive()
+    }
+}
+
+pub struct KafkaClients {
+    data: PartitionClient,
+    index: PartitionClient,
+}
+
+impl KafkaCore {
+    async fn clients(&self) -> Result<&KafkaClients> {
+        self.clients
+            .get_or_try_init(|| async {
+                let mut builder = ClientBuilder::new(self.brokers.clone())
+                    .max_message_size(self.max_message_size + RECORD_OVERHEAD);
+                if let Some(client_id) = &self.client_id {
+                    builder = builder.client_id(client_id.as_str());
+                }
+                let client = builder.build().await.map_err(parse_kafka_error)?;
+
+                let data = client
+                    .partition_client(&self.topic, self.partition, UnknownTopicHandling::Error)
+                    .await
+                    .map_err(parse_kafka_error)?;
+                let index = client
+                    .partition_client(
+                        &self.index_topic,
+                        self.partition,
+                        UnknownTopicHandling::Error,
+                    )
+                    .await
+                    .map_err(parse_kafka_error)?;
+                Ok(KafkaClients { data, index })
+            })
+            .await
+    }
+
+    /// Catch up with the index topic and return the up to date index.
+    pub async fn refresh(&self) -> Result<MutexGuard<'_, KafkaIndex>> {
+        let clients = self.clients().await?;
+        let mut index = self.index.lock().await;
+
+        let mut offset = match index.next_offset {
+            Some(offset) => offset,
+            // Records before the earliest offset have been compacted away.
+            None => clients
+                .index
+                .get_offset(OffsetAt::Earliest)
+                .await
+                .map_err(parse_kafka_error)?,
+        };
+
+        loop {
+            let (records, high_watermark) = clients
+                .index
+                .fetch_records(offset, 1..INDEX_FETCH_MAX_BYTES, 0)
+                .await
+                .map_err(parse_kafka_error)?;
+            if records.is_empty() {
+                break;
+            }
+
+            for r in records {
+                if let Some(key) = r.record.key {
+                    let key = String::from_utf8(key).map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "index key is not valid utf-8")
+                            .set_source(err)
+                    })?;
+                    index.apply(key, r.record.value.as_deref())?;
+                }
+                offset = r.offset + 1;
+            }
+            if offset >= high_watermark {
+                break;
+            }
+        }
+
+        index.next_offset = Some(offset);
+        Ok(index)
+    }
+
+    pub async fn get(&self, path: &str) -> Result<Option<(IndexEntry, Buffer)>> {
+        let Some(entry) = self.refresh().await?.get(path) else {
+            return Ok(None);
+        };
+
+        let clients = self.clients().await?;
+        let max_bytes = (entry.size as usize + RECORD_OVERHEAD).min(i32::MAX as usize) as i32;
+        let (records, _) = clients
+            .data
+            .fetch_records(entry.offset, 1..max_bytes, 0)
+            .await
+            .map_err(parse_kafka_error)?;
+
+        let Some(record) = records.into_iter().find(|r| r.offset == entry.offset) else {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "object has been removed from the topic",
+            )
+            .with_context("offset", entry.offset.to_string()));
+        };
+        if record.record.key.as_deref() != Some(path.as_bytes()) {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "record key doesn't match the path")
+                    .with_context("offset", entry.offset.to_string()),
+            );
+        }
+
+        let value = record.record.value.unwrap_or_default();
+        Ok(Some((entry, Buffer::from(value))))
+    }
+
+    pub async fn set(
+        &self,
+        path: &str,
+        value: Buffer,
+        content_type: Option<&str>,
+    ) -> Result<IndexEntry> {
+        let clients = self.clients().await?;
+        let now = Timestamp::now().into_inner().as_millisecond();
+        let size = value.len() as u64;
+
+        let offsets = clients
+            .data
+            .produce(
+                vec![new_record(path, Some(value.to_vec()), now)],
+                Compression::NoCompression,
+            )
+            .await
+            .map_err(parse_kafka_error)?;
+        let offset = offsets
+            .first()
+            .copied()
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "produce returned no offset"))?;
+
+        let entry = IndexEntry {
+            offset,
+            size,
+            last_modified: now,
+            content_type: content_type.map(|v| v.to_string()),
+        };
+        let bs = serde_json::to_vec(&entry).map_err(new_json_serialize_error)?;
+        clients
+            .index
+            .produce(
+                vec![new_record(path, Some(bs), now)],
+                Compression::NoCompression,
+            )
+            .await
+            .map_err(parse_kafka_error)?;
+
+        self.index
+            .lock()
+            .await
+            .entries
+            .insert(path.to_string(), entry.clone());
+        Ok(entry)
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<()> {
+        let clients = self.clients().await?;
+        let now = Timestamp::now().into_inner().as_millisecond();
+
+        // A tombstone lets compaction drop the path from the index topic.
+        clients
+            .index
+            .produce(
+                vec![new_record(path, None, now)],
+                Compression::NoCompression,
+            )
+            .await
+            .map_err(parse_kafka_error)?;
+
+        self.index.lock().await.entries.remove(path);
+        Ok(())
+    }
+}
+
+fn new_record(key: &str, value: Option<Vec<u8>>, timestamp: i64) -> Record {
+    Record {
+        key: Some(key.as_bytes().to_vec()),
+        value,
+        headers: BTreeMap::new(),
+        timestamp: DateTime::from_timestamp_millis(timestamp).unwrap_or_default(),
+    }
+}
+
+/// The value stored in the index topic for each path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// The offset of the record in the data topic.
+    pub offset: i64,
+    pub size: u64,
+    /// Milliseconds since unix epoch.
+    pub last_modified: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+impl IndexEntry {
+    pub fn metadata(&self) -> Metadata {
+        let mut meta = Metadata::new(EntryMode::FILE).with_content_length(self.size);
+        if let Ok(ts) = Timestamp::from_millisecond(self.last_modified) {
+            meta = meta.with_last_modified(ts);
+        }
+        if let Some(v) = &self.content_type {
+            meta = meta.with_content_type(v.clone());
+        }
+        meta
+    }
+}
+
+/// The in memory view of the index topic.
+#[derive(Debug, Default)]
+pub struct KafkaIndex {
+    /// The next offset to consume from the index topic, `None` if not loaded yet.
+    next_offset: Option<i64>,
+    entries: BTreeMap<String, IndexEntry>,
+}
+
+impl KafkaIndex {
+    /// Apply a record of the index topic, `None` value is a tombstone.
+    fn apply(&mut self, key: String, value: Option<&[u8]>) -> Result<()> {
+        match value {
+            Some(bs) => {
+                let entry: IndexEntry =
+                    serde_json::from_slice(bs).map_err(new_json_deserialize_error)?;
+                self.entries.insert(key, entry);
+            }
+            None => {
+                self.entries.remove(&key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, path: &str) -> Option<IndexEntry> {
+        self.entries.get(path).cloned()
+    }
+
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.entries
+            .range(prefix.to_string()..)
+            .next()
+            .is_some_and(|(k, _)| k.starts_with(prefix))
+    }
+
+    pub fn list(&self, prefix: &str) -> Vec<(String, Metadata)> {
+        self.entries
+            .range(prefix.to_string()..)
+            .take_while(|(k, _)| k.starts_with(prefix))
+            .map(|(k, v)| (k.clone(), v.metadata()))
+            .collect()
+    }
+}
+
+pub fn parse_kafka_error(err: rskafka::client::error::Error) -> Error {
+    use rskafka::client::error::Error as KafkaError;
+
+    let temporary = matches!(
+        err,
+        KafkaError::Connection(_) | KafkaError::Request(_) | KafkaError::Timeout
+    );
+    Error::new(ErrorKind::Unexpected, "kafka error")
+        .with_temporary(temporary)
+        .set_source(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(offset: i64) -> Vec<u8> {
+        serde_json::to_vec(&IndexEntry {
+            offset,
+            size: 4,
+            last_modified: 0,
+            content_type: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_index_apply() {
+        let mut index = KafkaIndex::default();
+        index.apply("a/b".to_string(), Some(&entry(1))).unwrap();
+        index.apply("a/c".to_string(), Some(&entry(2))).unwrap();
+        index.apply("b".to_string(), Some(&entry(3))).unwrap();
+        // Later records win.
+        index.apply("a/b".to_string(), Some(&entry(4))).unwrap();
+        // Tombstones remove the path.
+        index.apply("b".to_string(), None).unwrap();
+
+        assert_eq!(index.get("a/b").unwrap().offset, 4);
+        assert!(index.get("b").is_none());
+        assert!(index.has_prefix("a/"));
+        assert!(!index.has_prefix("b"));
+
+        let paths: Vec<_> = index.list("a/").into_iter().map(|(k, _)| k).collect();
+        assert_eq!(paths, vec!["a/b", "a/c"]);
+    }
+
+    #[test]
+    fn test_index_entry_metadata() {
+        let entry = IndexEntry {
+            offset: 1,
+            size: 13,
+            last_modified: 1_700_000_000_000,
+            content_type: Some("text/plain".to_string()),
+        };
+
+        let meta = entry.metadata();
+        assert_eq!(meta.content_length(), 13);
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        assert_eq!(
+            meta.last_modified(),
+            Some(Timestamp::from_millisecond(1_700_000_000_000).unwrap())
+        );
+    }
+}
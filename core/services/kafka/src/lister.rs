@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use super::core::KafkaCore;
+
+pub struct KafkaLister {
+    core: Arc<KafkaCore>,
+    root: String,
+    path: String,
+    iter: Option<IntoIter<(String, Metadata)>>,
+}
+
+impl KafkaLister {
+    pub fn new(core: Arc<KafkaCore>, root: String, path: String) -> Self {
+        let path = build_abs_path(&root, &path);
+        Self {
+            core,
+            root,
+            path,
+            iter: None,
+        }
+    }
+}
+
+impl oio::List for KafkaLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if self.iter.is_none() {
+            let entries = self.core.refresh().await?.list(&self.path);
+            self.iter = Some(entries.into_iter());
+        }
+
+        let Some(iter) = self.iter.as_mut() else {
+            return Ok(None);
+        };
+        Ok(iter.next().map(|(key, metadata)| {
+            let path = build_rel_path(&self.root, &key);
+            oio::Entry::new(&path, metadata)
+        }))
+    }
+}
@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct KafkaReader {
+    core: Arc<KafkaCore>,
+    path: String,
+}
+
+impl KafkaReader {
+    pub(super) fn new(core: Arc<KafkaCore>, path: String, _: OpRead) -> Self {
+        Self { core, path }
+    }
+}
+
+impl oio::StreamRead for KafkaReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let Some((entry, bs)) = self.core.get(&self.path).await? else {
+            return Err(Error::new(ErrorKind::NotFound, "key not found in kafka"));
+        };
+
+        let content = bs.slice(range.to_content_range(bs.len())?);
+        Ok((
+            RpRead::new(entry.metadata()),
+            Box::new(content) as Box<dyn oio::ReadStreamDyn>,
+        ))
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct KafkaWriter {
+    core: Arc<KafkaCore>,
+    path: String,
+    op: OpWrite,
+    buffer: oio::QueueBuf,
+}
+
+impl KafkaWriter {
+    pub fn new(core: Arc<KafkaCore>, path: String, op: OpWrite) -> Self {
+        Self {
+            core,
+            path,
+            op,
+            buffer: oio::QueueBuf::new(),
+        }
+    }
+}
+
+impl oio::Write for KafkaWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.buffer.push(bs);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let buf = self.buffer.clone().collect();
+        // Objects are stored as a single message.
+        if buf.len() > self.core.max_message_size {
+            return Err(
+                Error::new(ErrorKind::Unsupported, "object exceeds max message size")
+                    .with_context("size", buf.len().to_string())
+                    .with_context("max_message_size", self.core.max_message_size.to_string()),
+            );
+        }
+
+        let entry = self
+            .core
+            .set(&self.path, buf, self.op.content_type())
+            .await?;
+        Ok(entry.metadata())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        Ok(())
+    }
+}
@@ -148,6 +148,7 @@ impl Builder for KoofrBuilder {
                     stat: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     read: true,
                     read_with_suffix: true,
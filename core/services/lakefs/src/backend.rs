@@ -25,7 +25,10 @@ use opendal_core::*;
 
 use super::LAKEFS_SCHEME;
 use super::config::LakefsConfig;
+use super::core::LakefsCommitListResponse;
 use super::core::LakefsCore;
+use super::core::LakefsLocation;
+use super::core::LakefsRef;
 use super::core::LakefsStatus;
 use super::core::parse_error;
 use super::deleter::LakefsDeleter;
@@ -78,6 +81,8 @@ impl LakefsBuilder {
     /// For example, branch can be:
     /// - main
     /// - 1d0c4eb
+    ///
+    /// This requires `repository` to be set.
     pub fn branch(mut self, branch: &str) -> Self {
         if !branch.is_empty() {
             self.config.branch = Some(branch.to_string());
@@ -97,7 +102,8 @@ impl LakefsBuilder {
 
     /// Set the repository of this backend.
     ///
-    /// This is required.
+    /// If not set, paths are addressed as `repo/branch/path`, which lists
+    /// repositories at `/` and branches at `repo/`.
     pub fn repository(mut self, repository: &str) -> Self {
         if !repository.is_empty() {
             self.config.repository = Some(repository.to_string());
@@ -121,19 +127,21 @@ impl Builder for LakefsBuilder {
         }?;
         debug!("backend use endpoint: {:?}", &endpoint);
 
-        let repository = match &self.config.repository {
-            Some(repository) => Ok(repository.clone()),
-            None => Err(Error::new(ErrorKind::ConfigInvalid, "repository is empty")
+        let (repository, branch) = match (self.config.repository, self.config.branch) {
+            (Some(repository), branch) => {
+                (Some(repository), Some(branch.unwrap_or("main".to_string())))
+            }
+            (None, None) => (None, None),
+            (None, Some(_)) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "branch can't be set without repository",
+                )
                 .with_operation("Builder::build")
-                .with_context("service", LAKEFS_SCHEME)),
-        }?;
-        debug!("backend use repository: {}", &repository);
-
-        let branch = match &self.config.branch {
-            Some(branch) => branch.clone(),
-            None => "main".to_string(),
+                .with_context("service", LAKEFS_SCHEME));
+            }
         };
-        debug!("backend use branch: {}", &branch);
+        debug!("backend use repository: {repository:?}, branch: {branch:?}");
 
         let root = normalize_root(&self.config.root.unwrap_or_default());
         debug!("backend use root: {}", &root);
@@ -157,11 +165,13 @@ impl Builder for LakefsBuilder {
                 info: ServiceInfo::new(LAKEFS_SCHEME, "", ""),
                 capability: Capability {
                     stat: true,
+                    stat_with_version: true,
 
                     list: true,
 
                     read: true,
                     read_with_suffix: true,
+                    read_with_version: true,
                     write: true,
                     delete: true,
                     copy: true,
@@ -212,17 +222,55 @@ impl Service for LakefsBackend {
         ))
     }
 
-    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
         // Stat root always returns a DIR.
         if path == "/" {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let resp = self.core.get_object_metadata(ctx, path).await?;
+        let (repository, reference, key) = match self.core.locate(path) {
+            LakefsLocation::Root => return Ok(RpStat::new(Metadata::new(EntryMode::DIR))),
+            LakefsLocation::Repository(repository) => {
+                let resp = self.core.get_repository(ctx, &repository).await?;
+                return match resp.status() {
+                    StatusCode::OK => Ok(RpStat::new(Metadata::new(EntryMode::DIR))),
+                    _ => Err(parse_error(resp)),
+                };
+            }
+            LakefsLocation::Object {
+                repository,
+                reference,
+                key,
+            } => (repository, reference, key),
+        };
+        let key = key.trim_end_matches('/');
+        let reference = args.version().unwrap_or(&reference);
+
+        // A branch is a DIR whose version is its head commit.
+        if key.is_empty() && self.core.repository.is_none() {
+            let resp = self.core.get_branch(ctx, &repository, reference).await?;
+            return match resp.status() {
+                StatusCode::OK => {
+                    let branch: LakefsRef = serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+                    let mut meta = Metadata::new(EntryMode::DIR);
+                    if let Some(commit_id) = branch.commit_id {
+                        meta.set_version(&commit_id);
+                    }
+                    Ok(RpStat::new(meta))
+                }
+                _ => Err(parse_error(resp)),
+            };
+        }
+
+        let resp = self
+            .core
+            .get_object_metadata(ctx, &repository, reference, key)
+            .await?;
 
         let status = resp.status();
 
-        match status {
+        let mut meta = match status {
             StatusCode::OK => {
                 let bs = resp.into_body();
 
@@ -230,12 +278,34 @@ impl Service for LakefsBackend {
                     serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
 
                 // Use the helper function to parse LakefsStatus into Metadata
-                let meta = LakefsCore::parse_lakefs_status_into_metadata(&decoded_response);
+                LakefsCore::parse_lakefs_status_into_metadata(&decoded_response)
+            }
+            _ => return Err(parse_error(resp)),
+        };
 
-                Ok(RpStat::new(meta))
+        // Map the latest commit that touched this object to its version.
+        match args.version() {
+            Some(version) => {
+                meta.set_version(version);
+            }
+            None => {
+                let resp = self
+                    .core
+                    .get_last_commit(ctx, &repository, reference, key)
+                    .await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+                let commits: LakefsCommitListResponse =
+                    serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+                if let Some(commit) = commits.results.first() {
+                    meta.set_version(&commit.id);
+                }
             }
-            _ => Err(parse_error(resp)),
         }
+
+        Ok(RpStat::new(meta))
     }
     fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
         let output: oio::StreamReader<LakefsReader> = {
@@ -267,13 +337,12 @@ impl Service for LakefsBackend {
         Ok(output)
     }
 
-    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
         let output: oio::OneShotWriter<LakefsWriter> = {
             Ok(oio::OneShotWriter::new(LakefsWriter::new(
                 self.core.clone(),
                 ctx.clone(),
                 path.to_string(),
-                args,
             )))
         }?;
 
@@ -305,7 +374,25 @@ impl Service for LakefsBackend {
         let to = to.to_string();
 
         Ok(oio::OneShotCopier::new(async move {
-            let resp = core.copy_object(&ctx, &from, &to).await?;
+            let (repository, src_ref, src_key) = core.locate_object(&from)?;
+            let (dest_repository, dest_branch, dest_key) = core.locate_object(&to)?;
+            if repository != dest_repository {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "copy across repositories is not supported",
+                ));
+            }
+
+            let resp = core
+                .copy_object(
+                    &ctx,
+                    &repository,
+                    &src_ref,
+                    &src_key,
+                    &dest_branch,
+                    &dest_key,
+                )
+                .await?;
             let status = resp.status();
 
             match status {
@@ -340,3 +427,118 @@ impl Service for LakefsBackend {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+    use http::Request;
+    use http::Response;
+
+    use super::*;
+
+    /// Serve canned JSON responses by request url.
+    struct MockLakefs {
+        responses: HashMap<String, String>,
+    }
+
+    impl HttpTransport for MockLakefs {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let (status, content) = match self.responses.get(&req.uri().to_string()) {
+                Some(content) => (StatusCode::OK, content.clone().into_bytes()),
+                None => (
+                    StatusCode::NOT_FOUND,
+                    br#"{"message":"not found"}"#.to_vec(),
+                ),
+            };
+
+            let size = content.len() as u64;
+            let body = futures::stream::iter(vec![Ok(Buffer::from(content))]);
+            Ok(Response::builder()
+                .status(status)
+                .body(HttpBody::new(body, Some(size)))
+                .unwrap())
+        }
+    }
+
+    fn new_operator() -> Operator {
+        let api = "http://lakefs.test/api/v1";
+        let pagination = r#"{"has_more":false,"max_per_page":1000,"next_offset":"","results":1}"#;
+        let responses = HashMap::from([
+            (
+                format!("{api}/repositories?"),
+                format!(r#"{{"pagination":{pagination},"results":[{{"id":"sample"}}]}}"#),
+            ),
+            (
+                format!("{api}/repositories/sample/branches?"),
+                format!(
+                    r#"{{"pagination":{pagination},"results":[{{"id":"main","commit_id":"c1"}}]}}"#
+                ),
+            ),
+            (
+                format!("{api}/repositories/sample/branches/main"),
+                r#"{"id":"main","commit_id":"c1"}"#.to_string(),
+            ),
+            (
+                format!("{api}/repositories/sample/refs/main/objects/stat?path=data/a.txt"),
+                r#"{"path":"data/a.txt","path_type":"object","physical_address":"s3://x","checksum":"abc","size_bytes":5,"mtime":1700000000}"#.to_string(),
+            ),
+            (
+                format!(
+                    "{api}/repositories/sample/refs/main/commits?objects=data/a.txt&amount=1&limit=true"
+                ),
+                r#"{"pagination":{"has_more":false,"max_per_page":1,"next_offset":"","results":1},"results":[{"id":"c0","parents":[]}]}"#.to_string(),
+            ),
+            (
+                format!("{api}/repositories/sample/refs/main/objects/ls?&prefix=data/&delimiter=/"),
+                format!(
+                    r#"{{"pagination":{pagination},"results":[{{"path":"data/a.txt","path_type":"object","physical_address":"s3://x","checksum":"abc","size_bytes":5,"mtime":1700000000}}]}}"#
+                ),
+            ),
+        ]);
+
+        let builder = LakefsBuilder::default()
+            .endpoint("http://lakefs.test")
+            .username("user")
+            .password("pass");
+        Operator::new(builder).unwrap().with_context(
+            OperationContext::new()
+                .with_http_transport(HttpTransporter::new(MockLakefs { responses })),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_branches_as_paths() -> Result<()> {
+        let op = new_operator();
+
+        let list = |path: &'static str| {
+            let op = op.clone();
+            async move {
+                op.lister(path)
+                    .await?
+                    .map_ok(|e| e.path().to_string())
+                    .try_collect::<Vec<_>>()
+                    .await
+            }
+        };
+        assert_eq!(list("").await?, vec!["sample/"]);
+        assert_eq!(list("sample/").await?, vec!["sample/main/"]);
+        assert_eq!(
+            list("sample/main/data/").await?,
+            vec!["sample/main/data/a.txt"]
+        );
+
+        let meta = op.stat("sample/main/").await?;
+        assert!(meta.mode().is_dir());
+        assert_eq!(meta.version(), Some("c1"));
+
+        let meta = op.stat("sample/main/data/a.txt").await?;
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(meta.version(), Some("c0"));
+
+        let err = op.stat("sample/dev/data/a.txt").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+}
@@ -29,8 +29,10 @@ pub struct LakefsCore {
     pub info: ServiceInfo,
     pub capability: Capability,
     pub endpoint: String,
-    pub repository: String,
-    pub branch: String,
+    /// The repository of this backend, or `None` if paths start with `repo/branch/`.
+    pub repository: Option<String>,
+    /// The branch of this backend, always set together with `repository`.
+    pub branch: Option<String>,
     pub root: String,
     pub username: String,
     pub password: String,
@@ -49,28 +51,103 @@ impl Debug for LakefsCore {
     }
 }
 
+/// The place a path points to inside lakeFS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum LakefsLocation {
+    /// The list of all repositories.
+    Root,
+    /// A repository, which contains branches.
+    Repository(String),
+    /// An object or a prefix of objects under `reference` of `repository`.
+    ///
+    /// `key` is empty for the reference itself.
+    Object {
+        repository: String,
+        reference: String,
+        key: String,
+    },
+}
+
 impl LakefsCore {
+    /// Resolve `path` into a location.
+    ///
+    /// With a configured repository every path is a key in the configured
+    /// branch, otherwise the first two segments select the repository and
+    /// the branch.
+    pub(super) fn locate(&self, path: &str) -> LakefsLocation {
+        let p = build_abs_path(&self.root, path);
+
+        if let (Some(repository), Some(branch)) = (&self.repository, &self.branch) {
+            return LakefsLocation::Object {
+                repository: repository.clone(),
+                reference: branch.clone(),
+                key: p,
+            };
+        }
+
+        let mut segments = p.splitn(3, '/');
+        let repository = segments.next().unwrap_or_default();
+        if repository.is_empty() {
+            return LakefsLocation::Root;
+        }
+        match segments.next() {
+            None | Some("") => LakefsLocation::Repository(repository.to_string()),
+            Some(branch) => LakefsLocation::Object {
+                repository: repository.to_string(),
+                reference: branch.to_string(),
+                key: segments.next().unwrap_or_default().to_string(),
+            },
+        }
+    }
+
+    /// Resolve `path` into `(repository, reference, key)` of an object.
+    pub(super) fn locate_object(&self, path: &str) -> Result<(String, String, String)> {
+        match self.locate(path) {
+            LakefsLocation::Object {
+                repository,
+                reference,
+                key,
+            } if !key.trim_end_matches('/').is_empty() => {
+                Ok((repository, reference, key.trim_end_matches('/').to_string()))
+            }
+            _ => Err(Error::new(
+                ErrorKind::IsADirectory,
+                "path must point to an object inside a branch",
+            )
+            .with_context("path", path)),
+        }
+    }
+
+    /// Build the absolute path of `key` in `reference` of `repository`.
+    pub(super) fn object_abs_path(&self, repository: &str, reference: &str, key: &str) -> String {
+        if self.repository.is_some() {
+            key.to_string()
+        } else {
+            format!("{repository}/{reference}/{key}")
+        }
+    }
+
+    fn request(&self, req: http::request::Builder) -> Result<http::request::Builder> {
+        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
+        Ok(req.header(header::AUTHORIZATION, auth_header_content))
+    }
+
     pub async fn get_object_metadata(
         &self,
         ctx: &OperationContext,
-        path: &str,
+        repository: &str,
+        reference: &str,
+        key: &str,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path)
-            .trim_end_matches('/')
-            .to_string();
-
         let url = format!(
             "{}/api/v1/repositories/{}/refs/{}/objects/stat?path={}",
             self.endpoint,
-            self.repository,
-            self.branch,
-            percent_encode_path(&p)
+            percent_encode_path(repository),
+            percent_encode_path(reference),
+            percent_encode_path(key)
         );
 
-        let mut req = Request::get(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let req = self.request(Request::get(&url))?;
         // Inject operation to the request.
         let req = req
             .extension(Operation::Read)
@@ -83,26 +160,20 @@ impl LakefsCore {
     pub async fn get_object_content(
         &self,
         ctx: &OperationContext,
-        path: &str,
+        repository: &str,
+        reference: &str,
+        key: &str,
         range: BytesRange,
-        _args: &OpRead,
     ) -> Result<Response<HttpBody>> {
-        let p = build_abs_path(&self.root, path)
-            .trim_end_matches('/')
-            .to_string();
-
         let url = format!(
             "{}/api/v1/repositories/{}/refs/{}/objects?path={}",
             self.endpoint,
-            self.repository,
-            self.branch,
-            percent_encode_path(&p)
+            percent_encode_path(repository),
+            percent_encode_path(reference),
+            percent_encode_path(key)
         );
 
-        let mut req = Request::get(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let mut req = self.request(Request::get(&url))?;
 
         if !range.is_full() {
             req = req.header(header::RANGE, range.to_header());
@@ -116,23 +187,26 @@ impl LakefsCore {
         ctx.http_transport().fetch(req).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_objects(
         &self,
         ctx: &OperationContext,
-        path: &str,
+        repository: &str,
+        reference: &str,
+        prefix: &str,
         delimiter: &str,
         amount: &Option<usize>,
         after: Option<String>,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path);
-
         let mut url = format!(
             "{}/api/v1/repositories/{}/refs/{}/objects/ls?",
-            self.endpoint, self.repository, self.branch
+            self.endpoint,
+            percent_encode_path(repository),
+            percent_encode_path(reference)
         );
 
-        if !p.is_empty() {
-            url.push_str(&format!("&prefix={}", percent_encode_path(&p)));
+        if !prefix.is_empty() {
+            url.push_str(&format!("&prefix={}", percent_encode_path(prefix)));
         }
 
         if !delimiter.is_empty() {
@@ -144,13 +218,10 @@ impl LakefsCore {
         }
 
         if let Some(after) = after {
-            url.push_str(&format!("&after={after}"));
+            url.push_str(&format!("&after={}", percent_encode_path(&after)));
         }
 
-        let mut req = Request::get(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let req = self.request(Request::get(&url))?;
         // Inject operation to the request.
         let req = req
             .extension(Operation::Read)
@@ -160,29 +231,134 @@ impl LakefsCore {
         ctx.http_transport().send(req).await
     }
 
+    pub async fn get_repository(
+        &self,
+        ctx: &OperationContext,
+        repository: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/api/v1/repositories/{}",
+            self.endpoint,
+            percent_encode_path(repository)
+        );
+
+        let req = self.request(Request::get(&url))?;
+        // Inject operation to the request.
+        let req = req
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetRepository"));
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn get_branch(
+        &self,
+        ctx: &OperationContext,
+        repository: &str,
+        branch: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/api/v1/repositories/{}/branches/{}",
+            self.endpoint,
+            percent_encode_path(repository),
+            percent_encode_path(branch)
+        );
+
+        let req = self.request(Request::get(&url))?;
+        // Inject operation to the request.
+        let req = req
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetBranch"));
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    /// List repositories, or branches of `repository` if it's set.
+    pub async fn list_refs(
+        &self,
+        ctx: &OperationContext,
+        repository: Option<&str>,
+        amount: &Option<usize>,
+        after: Option<String>,
+    ) -> Result<Response<Buffer>> {
+        let (mut url, operation) = match repository {
+            None => (
+                format!("{}/api/v1/repositories?", self.endpoint),
+                "ListRepositories",
+            ),
+            Some(repository) => (
+                format!(
+                    "{}/api/v1/repositories/{}/branches?",
+                    self.endpoint,
+                    percent_encode_path(repository)
+                ),
+                "ListBranches",
+            ),
+        };
+
+        if let Some(amount) = amount {
+            url.push_str(&format!("&amount={amount}"));
+        }
+
+        if let Some(after) = after {
+            url.push_str(&format!("&after={}", percent_encode_path(&after)));
+        }
+
+        let req = self.request(Request::get(&url))?;
+        // Inject operation to the request.
+        let req = req
+            .extension(Operation::List)
+            .extension(ServiceOperation(operation));
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    /// Get the latest commit in `reference` that touched `key`.
+    pub async fn get_last_commit(
+        &self,
+        ctx: &OperationContext,
+        repository: &str,
+        reference: &str,
+        key: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/api/v1/repositories/{}/refs/{}/commits?objects={}&amount=1&limit=true",
+            self.endpoint,
+            percent_encode_path(repository),
+            percent_encode_path(reference),
+            percent_encode_path(key)
+        );
+
+        let req = self.request(Request::get(&url))?;
+        // Inject operation to the request.
+        let req = req
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("LogCommits"));
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
     pub async fn upload_object(
         &self,
         ctx: &OperationContext,
-        path: &str,
-        _args: &OpWrite,
+        repository: &str,
+        branch: &str,
+        key: &str,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path)
-            .trim_end_matches('/')
-            .to_string();
-
         let url = format!(
             "{}/api/v1/repositories/{}/branches/{}/objects?path={}",
             self.endpoint,
-            self.repository,
-            self.branch,
-            percent_encode_path(&p)
+            percent_encode_path(repository),
+            percent_encode_path(branch),
+            percent_encode_path(key)
         );
 
-        let mut req = Request::post(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let req = self.request(Request::post(&url))?;
         // Inject operation to the request.
         let req = req
             .extension(Operation::Write)
@@ -195,25 +371,19 @@ impl LakefsCore {
     pub async fn delete_object(
         &self,
         ctx: &OperationContext,
-        path: &str,
-        _args: &OpDelete,
+        repository: &str,
+        branch: &str,
+        key: &str,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path)
-            .trim_end_matches('/')
-            .to_string();
-
         let url = format!(
             "{}/api/v1/repositories/{}/branches/{}/objects?path={}",
             self.endpoint,
-            self.repository,
-            self.branch,
-            percent_encode_path(&p)
+            percent_encode_path(repository),
+            percent_encode_path(branch),
+            percent_encode_path(key)
         );
 
-        let mut req = Request::delete(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let req = self.request(Request::delete(&url))?;
         // Inject operation to the request.
         let req = req
             .extension(Operation::Delete)
@@ -223,38 +393,33 @@ impl LakefsCore {
         ctx.http_transport().send(req).await
     }
 
+    /// Copy `src_key` in `src_ref` to `dest_key` in `dest_branch` of the same repository.
     pub async fn copy_object(
         &self,
         ctx: &OperationContext,
-        path: &str,
-        dest: &str,
+        repository: &str,
+        src_ref: &str,
+        src_key: &str,
+        dest_branch: &str,
+        dest_key: &str,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path)
-            .trim_end_matches('/')
-            .to_string();
-        let d = build_abs_path(&self.root, dest)
-            .trim_end_matches('/')
-            .to_string();
-
         let url = format!(
             "{}/api/v1/repositories/{}/branches/{}/objects/copy?dest_path={}",
             self.endpoint,
-            self.repository,
-            self.branch,
-            percent_encode_path(&d)
+            percent_encode_path(repository),
+            percent_encode_path(dest_branch),
+            percent_encode_path(dest_key)
         );
 
-        let mut req = Request::post(&url);
-
-        let auth_header_content = format_authorization_by_basic(&self.username, &self.password)?;
-        req = req.header(header::AUTHORIZATION, auth_header_content);
+        let mut req = self.request(Request::post(&url))?;
         req = req.header(header::CONTENT_TYPE, "application/json");
         let mut map = HashMap::new();
-        map.insert("src_path", p);
+        map.insert("src_path", src_key);
+        map.insert("src_ref", src_ref);
 
         let req = req
             // Inject operation to the request.
-            .extension(Operation::Delete)
+            .extension(Operation::Copy)
             .extension(ServiceOperation("CopyObject"))
             .body(serde_json::to_vec(&map).unwrap().into())
             .map_err(new_request_build_error)?;
@@ -314,6 +479,31 @@ pub(super) struct LakefsListResponse {
     pub results: Vec<LakefsStatus>,
 }
 
+/// A repository or a branch, both only need their id here.
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub(super) struct LakefsRef {
+    pub id: String,
+    /// The head commit, only returned for branches.
+    #[serde(default)]
+    pub commit_id: Option<String>,
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub(super) struct LakefsRefListResponse {
+    pub pagination: Pagination,
+    pub results: Vec<LakefsRef>,
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub(super) struct LakefsCommit {
+    pub id: String,
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub(super) struct LakefsCommitListResponse {
+    pub results: Vec<LakefsCommit>,
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 pub(super) struct Pagination {
     pub has_more: bool,
@@ -400,3 +590,51 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_core(repository: Option<&str>, root: &str) -> LakefsCore {
+        LakefsCore {
+            info: ServiceInfo::new("lakefs", root, ""),
+            capability: Capability::default(),
+            endpoint: "http://lakefs.test".to_string(),
+            repository: repository.map(String::from),
+            branch: repository.map(|_| "main".to_string()),
+            root: root.to_string(),
+            username: String::new(),
+            password: String::new(),
+        }
+    }
+
+    fn object(repository: &str, reference: &str, key: &str) -> LakefsLocation {
+        LakefsLocation::Object {
+            repository: repository.to_string(),
+            reference: reference.to_string(),
+            key: key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_locate() {
+        let core = new_core(Some("sample"), "/data/");
+        assert_eq!(core.locate("a/b"), object("sample", "main", "data/a/b"));
+
+        let core = new_core(None, "/");
+        assert_eq!(core.locate(""), LakefsLocation::Root);
+        assert_eq!(
+            core.locate("sample/"),
+            LakefsLocation::Repository("sample".to_string())
+        );
+        assert_eq!(core.locate("sample/dev/"), object("sample", "dev", ""));
+        assert_eq!(
+            core.locate("sample/dev/a/b"),
+            object("sample", "dev", "a/b")
+        );
+        assert!(core.locate_object("sample/dev/").is_err());
+
+        let core = new_core(None, "/sample/");
+        assert_eq!(core.locate("dev/a"), object("sample", "dev", "a"));
+    }
+}
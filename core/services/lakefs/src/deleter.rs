@@ -36,13 +36,20 @@ impl LakefsDeleter {
 }
 
 impl oio::OneShotDelete for LakefsDeleter {
-    async fn delete_once(&self, path: String, args: OpDelete) -> Result<()> {
+    async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         // This would delete the bucket, do not perform
         if self.core.root == "/" && path == "/" {
             return Ok(());
         }
+        // Repositories and branches are never deleted.
+        let Ok((repository, branch, key)) = self.core.locate_object(&path) else {
+            return Ok(());
+        };
 
-        let resp = self.core.delete_object(&self.ctx, &path, &args).await?;
+        let resp = self
+            .core
+            .delete_object(&self.ctx, &repository, &branch, &key)
+            .await?;
 
         let status = resp.status();
 
@@ -23,6 +23,8 @@ use opendal_core::*;
 
 use super::core::LakefsCore;
 use super::core::LakefsListResponse;
+use super::core::LakefsLocation;
+use super::core::LakefsRefListResponse;
 use super::core::parse_error;
 
 pub struct LakefsLister {
@@ -57,19 +59,35 @@ impl LakefsLister {
 
 impl oio::PageList for LakefsLister {
     async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        // start after should only be set for the first page.
+        let after = if ctx.token.is_empty() {
+            self.after.clone()
+        } else {
+            Some(ctx.token.clone())
+        };
+
+        let (repository, reference, prefix) = match self.core.locate(&self.path) {
+            LakefsLocation::Root => return self.next_refs_page(ctx, None, after).await,
+            LakefsLocation::Repository(repository) => {
+                return self.next_refs_page(ctx, Some(&repository), after).await;
+            }
+            LakefsLocation::Object {
+                repository,
+                reference,
+                key,
+            } => (repository, reference, key),
+        };
+
         let response = self
             .core
             .list_objects(
                 &self.ctx,
-                &self.path,
+                &repository,
+                &reference,
+                &prefix,
                 self.delimiter,
                 &self.amount,
-                // start after should only be set for the first page.
-                if ctx.token.is_empty() {
-                    self.after.clone()
-                } else {
-                    None
-                },
+                after,
             )
             .await?;
 
@@ -84,7 +102,11 @@ impl oio::PageList for LakefsLister {
         let decoded_response: LakefsListResponse =
             serde_json::from_reader(bytes.reader()).map_err(new_json_deserialize_error)?;
 
-        ctx.done = true;
+        if decoded_response.pagination.has_more {
+            ctx.token = decoded_response.pagination.next_offset;
+        } else {
+            ctx.done = true;
+        }
 
         for status in decoded_response.results {
             let entry_type = match status.path_type.as_str() {
@@ -110,6 +132,60 @@ impl oio::PageList for LakefsLister {
             } else {
                 status.path.clone()
             };
+            let path = self.core.object_abs_path(&repository, &reference, &path);
+
+            ctx.entries.push_back(oio::Entry::new(
+                &build_rel_path(&self.core.root, &path),
+                meta,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl LakefsLister {
+    /// List repositories, or branches of `repository`, as dirs.
+    async fn next_refs_page(
+        &self,
+        ctx: &mut oio::PageContext,
+        repository: Option<&str>,
+        after: Option<String>,
+    ) -> Result<()> {
+        if self.delimiter.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "recursive list is only supported inside a branch",
+            ));
+        }
+
+        let response = self
+            .core
+            .list_refs(&self.ctx, repository, &self.amount, after)
+            .await?;
+        if !response.status().is_success() {
+            return Err(parse_error(response));
+        }
+
+        let decoded_response: LakefsRefListResponse =
+            serde_json::from_reader(response.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+
+        if decoded_response.pagination.has_more {
+            ctx.token = decoded_response.pagination.next_offset;
+        } else {
+            ctx.done = true;
+        }
+
+        for r in decoded_response.results {
+            let path = match repository {
+                None => format!("{}/", r.id),
+                Some(repository) => format!("{repository}/{}/", r.id),
+            };
+            let mut meta = Metadata::new(EntryMode::DIR);
+            if let Some(commit_id) = &r.commit_id {
+                meta.set_version(commit_id);
+            }
 
             ctx.entries.push_back(oio::Entry::new(
                 &build_rel_path(&self.core.root, &path),
@@ -50,10 +50,11 @@ impl oio::StreamRead for LakefsReader {
     async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
         let backend = &self.backend;
         let path = self.path.as_str();
-        let args = self.args.clone();
+        let (repository, reference, key) = backend.core.locate_object(path)?;
+        let reference = self.args.version().unwrap_or(&reference);
         let resp = backend
             .core
-            .get_object_content(&self.ctx, path, range, &args)
+            .get_object_content(&self.ctx, &repository, reference, &key, range)
             .await?;
 
         let status = resp.status();
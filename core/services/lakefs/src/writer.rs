@@ -29,26 +29,21 @@ use super::core::parse_error;
 pub struct LakefsWriter {
     core: Arc<LakefsCore>,
     ctx: OperationContext,
-    op: OpWrite,
     path: String,
 }
 
 impl LakefsWriter {
-    pub fn new(core: Arc<LakefsCore>, ctx: OperationContext, path: String, op: OpWrite) -> Self {
-        LakefsWriter {
-            core,
-            ctx,
-            path,
-            op,
-        }
+    pub fn new(core: Arc<LakefsCore>, ctx: OperationContext, path: String) -> Self {
+        LakefsWriter { core, ctx, path }
     }
 }
 
 impl oio::OneShotWrite for LakefsWriter {
     async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        let (repository, branch, key) = self.core.locate_object(&self.path)?;
         let resp = self
             .core
-            .upload_object(&self.ctx, &self.path, &self.op, bs)
+            .upload_object(&self.ctx, &repository, &branch, &key, bs)
             .await?;
 
         let status = resp.status();
@@ -68,8 +63,10 @@ impl oio::OneShotWrite for LakefsWriter {
                     }
                     Err(_) => {
                         // Upload response doesn't contain ObjectStats, fetch via stat API
-                        let stat_resp =
-                            self.core.get_object_metadata(&self.ctx, &self.path).await?;
+                        let stat_resp = self
+                            .core
+                            .get_object_metadata(&self.ctx, &repository, &branch, &key)
+                            .await?;
 
                         match stat_resp.status() {
                             StatusCode::OK => {
@@ -195,6 +195,7 @@ impl MemcachedBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_with_ttl: true,
             delete: true,
             shared: true,
             ..Default::default()
@@ -269,10 +270,10 @@ impl Service for MemcachedBackend {
         Ok(output)
     }
 
-    fn write(&self, _ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, _ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: MemcachedWriter = {
             let p = build_abs_path(&self.root, path);
-            Ok(MemcachedWriter::new(self.core.clone(), p))
+            Ok(MemcachedWriter::new(self.core.clone(), p, args))
         }?;
 
         Ok(output)
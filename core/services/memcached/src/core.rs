@@ -203,16 +203,14 @@ impl MemcachedCore {
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set the value of key, `ttl` takes precedence over the default ttl.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut conn = self.conn().await?;
 
         conn.set(
             &percent_encode_path(key),
             &value.to_vec(),
-            // Set expiration to 0 if ttl not set.
-            self.default_ttl
-                .map(|v| v.as_secs() as u32)
-                .unwrap_or_default(),
+            expiration(ttl.or(self.default_ttl)),
         )
         .await
     }
@@ -223,3 +221,36 @@ impl MemcachedCore {
         conn.delete(&percent_encode_path(key)).await
     }
 }
+
+/// Memcached treats expiration longer than 30 days as an absolute unix timestamp.
+const MAX_RELATIVE_EXPIRATION: u64 = 60 * 60 * 24 * 30;
+
+/// Convert ttl into the expiration of memcached, `0` means never expire.
+fn expiration(ttl: Option<Duration>) -> u32 {
+    let Some(ttl) = ttl else {
+        return 0;
+    };
+    // Round up to one second, `0` would keep the item forever.
+    let secs = ttl.as_secs().max(1);
+    if secs <= MAX_RELATIVE_EXPIRATION {
+        secs as u32
+    } else {
+        (Timestamp::now().into_inner().as_second() as u64 + secs) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiration() {
+        assert_eq!(expiration(None), 0);
+        assert_eq!(expiration(Some(Duration::from_millis(10))), 1);
+        assert_eq!(expiration(Some(Duration::from_secs(3600))), 3600);
+
+        let now = Timestamp::now().into_inner().as_second() as u32;
+        let ttl = MAX_RELATIVE_EXPIRATION as u32 + 1;
+        assert!(expiration(Some(Duration::from_secs(ttl as u64))) >= now + ttl);
+    }
+}
@@ -17,6 +17,7 @@
 
 use std::sync::Arc;
 
+use opendal_core::raw::OpWrite;
 use opendal_core::raw::oio;
 use opendal_core::*;
 
@@ -25,14 +26,16 @@ use super::core::*;
 pub struct MemcachedWriter {
     core: Arc<MemcachedCore>,
     path: String,
+    op: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl MemcachedWriter {
-    pub fn new(core: Arc<MemcachedCore>, path: String) -> Self {
+    pub fn new(core: Arc<MemcachedCore>, path: String, op: OpWrite) -> Self {
         Self {
             core,
             path,
+            op,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -47,7 +50,7 @@ impl oio::Write for MemcachedWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
         let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
+        self.core.set(&self.path, buf, self.op.ttl()).await?;
 
         let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
         Ok(meta)
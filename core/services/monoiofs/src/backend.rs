@@ -49,13 +49,32 @@ impl MonoiofsBuilder {
         };
         self
     }
+
+    /// Set the number of worker threads.
+    ///
+    /// Every worker thread runs a monoio runtime with its own io_uring, raising
+    /// it allows more operations to be in flight on fast disks.
+    ///
+    /// Default to 1.
+    pub fn worker_threads(mut self, worker_threads: usize) -> Self {
+        self.config.worker_threads = Some(worker_threads);
+        self
+    }
+
+    /// Set the number of io_uring submission queue entries on each worker.
+    ///
+    /// Default to 1024.
+    pub fn io_uring_entries(mut self, entries: u32) -> Self {
+        self.config.io_uring_entries = Some(entries);
+        self
+    }
 }
 
 impl Builder for MonoiofsBuilder {
     type Config = MonoiofsConfig;
 
     fn build(self) -> Result<impl Service> {
-        let root = self.config.root.map(PathBuf::from).ok_or(
+        let root = self.config.root.clone().map(PathBuf::from).ok_or(
             Error::new(ErrorKind::ConfigInvalid, "root is not specified")
                 .with_operation("Builder::build"),
         )?;
@@ -78,8 +97,22 @@ impl Builder for MonoiofsBuilder {
             .with_context("root", root.to_string_lossy())
             .set_source(e)
         })?;
-        let worker_threads = 1; // TODO: test concurrency and default to available_parallelism and bind cpu
-        let io_uring_entries = 1024;
+        // TODO: test concurrency and default to available_parallelism and bind cpu
+        let worker_threads = self.config.worker_threads.unwrap_or(1);
+        if worker_threads == 0 {
+            return Err(
+                Error::new(ErrorKind::ConfigInvalid, "worker_threads must be positive")
+                    .with_operation("Builder::build"),
+            );
+        }
+        let io_uring_entries = self.config.io_uring_entries.unwrap_or(1024);
+        if io_uring_entries == 0 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "io_uring_entries must be positive",
+            )
+            .with_operation("Builder::build"));
+        }
         Ok(MonoiofsBackend {
             core: Arc::new(MonoiofsCore::new(root, worker_threads, io_uring_entries)),
         })
@@ -32,6 +32,14 @@ pub struct MonoiofsConfig {
     ///
     /// Builder::build will return error if not set.
     pub root: Option<String>,
+    /// The number of worker threads, each running its own io_uring.
+    ///
+    /// Default to 1.
+    pub worker_threads: Option<usize>,
+    /// The number of entries of the io_uring submission queue on each worker.
+    ///
+    /// Default to 1024.
+    pub io_uring_entries: Option<u32>,
 }
 
 impl Configurator for MonoiofsConfig {
@@ -79,7 +79,9 @@ impl MonoiofsCore {
 
                 delete: true,
                 rename: true,
+                rename_is_atomic: true,
                 create_dir: true,
+                create_dir_persistent: true,
                 copy: true,
                 shared: true,
                 ..Default::default()
@@ -0,0 +1,317 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use http::StatusCode;
+use log::debug;
+
+use super::NETLIFY_BLOBS_SCHEME;
+use super::config::NetlifyBlobsConfig;
+use super::core::NetlifyBlobsCore;
+use super::core::parse_blob_metadata;
+use super::core::parse_error;
+use super::deleter::NetlifyBlobsDeleter;
+use super::lister::NetlifyBlobsLister;
+use super::reader::NetlifyBlobsReader;
+use super::writer::NetlifyBlobsWriter;
+use super::writer::NetlifyBlobsWriters;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+const DEFAULT_ENDPOINT: &str = "https://api.netlify.com";
+
+/// [Netlify Blobs](https://docs.netlify.com/blobs/overview/) services support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct NetlifyBlobsBuilder {
+    pub(super) config: NetlifyBlobsConfig,
+}
+
+impl Debug for NetlifyBlobsBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetlifyBlobsBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NetlifyBlobsBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the API endpoint of netlify.
+    ///
+    /// Default to `https://api.netlify.com`.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.trim_end_matches('/').to_string())
+        };
+
+        self
+    }
+
+    /// Set the id of the site which owns the store.
+    ///
+    /// It is required.
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.config.site_id = site_id.to_string();
+
+        self
+    }
+
+    /// Set the name of the store.
+    ///
+    /// It is required.
+    pub fn store(mut self, store: &str) -> Self {
+        self.config.store = store.to_string();
+
+        self
+    }
+
+    /// Netlify personal access token.
+    ///
+    /// It is required.
+    pub fn token(mut self, token: &str) -> Self {
+        if !token.is_empty() {
+            self.config.token = Some(token.to_string());
+        }
+        self
+    }
+}
+
+impl Builder for NetlifyBlobsBuilder {
+    type Config = NetlifyBlobsConfig;
+
+    /// Builds the backend and returns the result of NetlifyBlobsBackend.
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let root = normalize_root(&self.config.root.clone().unwrap_or_default());
+        debug!("backend use root {}", &root);
+
+        if self.config.site_id.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "site_id is empty")
+                .with_operation("Builder::build")
+                .with_context("service", NETLIFY_BLOBS_SCHEME));
+        }
+
+        if self.config.store.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "store is empty")
+                .with_operation("Builder::build")
+                .with_context("service", NETLIFY_BLOBS_SCHEME));
+        }
+        let store = normalize_store(&self.config.store);
+        debug!("backend use store {}", &store);
+
+        let Some(token) = self.config.token.clone() else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "token is empty")
+                .with_operation("Builder::build")
+                .with_context("service", NETLIFY_BLOBS_SCHEME));
+        };
+
+        let endpoint = self
+            .config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+
+        Ok(NetlifyBlobsBackend {
+            core: Arc::new(NetlifyBlobsCore {
+                info: ServiceInfo::new(NETLIFY_BLOBS_SCHEME, &root, &self.config.store),
+                capability: Capability {
+                    stat: true,
+
+                    read: true,
+
+                    write: true,
+                    write_can_empty: true,
+                    write_with_user_metadata: true,
+
+                    list: true,
+                    list_with_recursive: true,
+
+                    delete: true,
+
+                    shared: true,
+
+                    ..Default::default()
+                },
+                root,
+                endpoint,
+                site_id: self.config.site_id.clone(),
+                store,
+                token,
+            }),
+        })
+    }
+}
+
+/// Stores created by `getStore(name)` are prefixed by `site:`.
+fn normalize_store(store: &str) -> String {
+    if store.starts_with("site:") || store.starts_with("deploy:") {
+        store.to_string()
+    } else {
+        format!("site:{store}")
+    }
+}
+
+/// Backend for Netlify Blobs services.
+#[derive(Debug, Clone)]
+pub struct NetlifyBlobsBackend {
+    core: Arc<NetlifyBlobsCore>,
+}
+
+impl Service for NetlifyBlobsBackend {
+    type Reader = oio::StreamReader<NetlifyBlobsReader>;
+    type Writer = NetlifyBlobsWriters;
+    type Lister = oio::PageLister<NetlifyBlobsLister>;
+    type Deleter = oio::OneShotDeleter<NetlifyBlobsDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _args: OpStat) -> Result<RpStat> {
+        let resp = self.core.netlify_head_blob(ctx, path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_blob_metadata(path, resp.headers()).map(RpStat::new),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, _args: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(NetlifyBlobsReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = NetlifyBlobsWriter::new(self.core.clone(), ctx.clone(), args, path.to_string());
+
+        Ok(oio::OneShotWriter::new(w))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(NetlifyBlobsDeleter::new(
+            self.core.clone(),
+            ctx.clone(),
+        )))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let l = NetlifyBlobsLister::new(self.core.clone(), ctx.clone(), path, args.recursive());
+
+        Ok(oio::PageLister::new(l))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_store() {
+        assert_eq!(normalize_store("uploads"), "site:uploads");
+        assert_eq!(normalize_store("site:uploads"), "site:uploads");
+        assert_eq!(normalize_store("deploy:abc"), "deploy:abc");
+    }
+
+    #[test]
+    fn test_build_requires_site_id() {
+        let err = NetlifyBlobsBuilder::default()
+            .store("uploads")
+            .token("token")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
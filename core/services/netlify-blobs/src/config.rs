@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::NetlifyBlobsBuilder;
+
+/// Config for Netlify Blobs services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct NetlifyBlobsConfig {
+    /// root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub root: Option<String>,
+    /// The API endpoint of netlify.
+    ///
+    /// default to `https://api.netlify.com`.
+    pub endpoint: Option<String>,
+    /// The id of the site which owns the store.
+    pub site_id: String,
+    /// The name of the store.
+    ///
+    /// Names prefixed with `site:` or `deploy:` are used as is, others are
+    /// treated as site-wide stores like `getStore(name)` does.
+    pub store: String,
+    /// The personal access token of netlify.
+    pub token: Option<String>,
+}
+
+impl Debug for NetlifyBlobsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetlifyBlobsConfig")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .field("site_id", &self.site_id)
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for NetlifyBlobsConfig {
+    type Builder = NetlifyBlobsBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(name) = uri.name() {
+            map.insert("store".to_string(), name.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        NetlifyBlobsBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_store_and_root() {
+        let uri = OperatorUri::new(
+            "netlify-blobs://uploads/images?site_id=site-id",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = NetlifyBlobsConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.store, "uploads");
+        assert_eq!(cfg.root.as_deref(), Some("images"));
+        assert_eq!(cfg.site_id, "site-id");
+    }
+}
@@ -0,0 +1,404 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use bytes::Buf;
+use http::Method;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header;
+use http::request;
+use serde::Deserialize;
+
+use self::constants::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub(super) mod constants {
+    // https://github.com/netlify/blobs/blob/main/src/metadata.ts
+    // netlify-blobs-metadata carries the metadata when talking to the API.
+    pub const NETLIFY_BLOBS_METADATA: &str = "netlify-blobs-metadata";
+    // x-amz-meta-user carries the metadata when talking to the signed url,
+    // and is returned by the API while stat.
+    pub const X_AMZ_META_USER: &str = "x-amz-meta-user";
+    // Metadata is encoded as base64 json with this prefix.
+    pub const METADATA_BASE64_PREFIX: &str = "b64;";
+    // Asking the API to return a signed url instead of the content.
+    pub const SIGNED_URL_ACCEPT: &str = "application/json;type=signed-url";
+}
+
+#[derive(Clone)]
+pub struct NetlifyBlobsCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    /// The root of this core.
+    pub root: String,
+    /// The API endpoint of netlify.
+    pub endpoint: String,
+    /// The id of site.
+    pub site_id: String,
+    /// The full name of store, like `site:uploads`.
+    pub store: String,
+    /// Netlify personal access token.
+    pub token: String,
+}
+
+impl Debug for NetlifyBlobsCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetlifyBlobsCore")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .field("site_id", &self.site_id)
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NetlifyBlobsCore {
+    fn sign(&self, req: request::Builder) -> request::Builder {
+        req.header(header::AUTHORIZATION, format!("Bearer {}", self.token))
+    }
+
+    fn store_url(&self) -> String {
+        format!(
+            "{}/api/v1/blobs/{}/{}",
+            self.endpoint,
+            percent_encode_path(&self.site_id),
+            percent_encode_path(&self.store)
+        )
+    }
+
+    fn blob_url(&self, path: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+
+        format!("{}/{}", self.store_url(), percent_encode_path(&p))
+    }
+
+    /// Ask the API for a signed url to read or write the blob.
+    ///
+    /// Only reading and writing go through signed urls, the API handles
+    /// other requests directly.
+    async fn signed_url(
+        &self,
+        ctx: &OperationContext,
+        method: Method,
+        path: &str,
+        metadata: Option<&str>,
+        op: Operation,
+    ) -> Result<String> {
+        let mut req = Request::builder()
+            .method(method)
+            .uri(self.blob_url(path))
+            .header(header::ACCEPT, SIGNED_URL_ACCEPT);
+
+        if let Some(metadata) = metadata {
+            req = req.header(NETLIFY_BLOBS_METADATA, metadata);
+        }
+
+        let req = self
+            .sign(req)
+            .extension(op)
+            .extension(ServiceOperation("GetSignedUrl"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body();
+                let resp: SignedUrlResponse =
+                    serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+                Ok(resp.url)
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    pub async fn netlify_get_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<HttpBody>> {
+        let url = self
+            .signed_url(ctx, Method::GET, path, None, Operation::Read)
+            .await?;
+
+        let mut req = Request::get(url);
+
+        if !range.is_full() {
+            req = req.header(header::RANGE, range.to_header());
+        }
+
+        let req = req
+            .extension(Operation::Read)
+            .extension(ServiceOperation("GetBlob"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().fetch(req).await
+    }
+
+    pub async fn netlify_put_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpWrite,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let metadata = match args.user_metadata() {
+            Some(v) => Some(encode_metadata(v)?),
+            None => None,
+        };
+
+        let url = self
+            .signed_url(
+                ctx,
+                Method::PUT,
+                path,
+                metadata.as_deref(),
+                Operation::Write,
+            )
+            .await?;
+
+        let mut req = Request::put(url).header(header::CONTENT_LENGTH, body.len());
+
+        if let Some(metadata) = &metadata {
+            req = req.header(X_AMZ_META_USER, metadata);
+        }
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("PutBlob"))
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn netlify_head_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = self
+            .sign(Request::head(self.blob_url(path)))
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("HeadBlob"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn netlify_delete_blob(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let req = self
+            .sign(Request::delete(self.blob_url(path)))
+            .extension(Operation::Delete)
+            .extension(ServiceOperation("DeleteBlob"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn netlify_list_blobs(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        recursive: bool,
+        cursor: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = QueryPairsWriter::new(&self.store_url());
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !recursive {
+            url = url.push("directories", "true");
+        }
+        if !cursor.is_empty() {
+            url = url.push("cursor", &percent_encode_path(cursor));
+        }
+
+        let req = self
+            .sign(Request::get(url.finish()))
+            .extension(Operation::List)
+            .extension(ServiceOperation("ListBlobs"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+}
+
+/// Encode user metadata into the format used by netlify blobs.
+pub fn encode_metadata(metadata: &HashMap<String, String>) -> Result<String> {
+    let bs = serde_json::to_vec(metadata).map_err(new_json_serialize_error)?;
+    Ok(format!(
+        "{METADATA_BASE64_PREFIX}{}",
+        BASE64_STANDARD.encode(bs)
+    ))
+}
+
+/// Decode metadata returned by netlify blobs into user metadata.
+///
+/// Metadata values can be any json, non-string values are kept in their json form.
+pub fn decode_metadata(value: &str) -> Result<HashMap<String, String>> {
+    let Some(encoded) = value.strip_prefix(METADATA_BASE64_PREFIX) else {
+        return Ok(HashMap::new());
+    };
+
+    let bs = BASE64_STANDARD.decode(encoded).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "metadata is not valid base64")
+            .with_context("metadata", value)
+            .set_source(err)
+    })?;
+    let metadata: HashMap<String, serde_json::Value> =
+        serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+
+    Ok(metadata
+        .into_iter()
+        .map(|(k, v)| match v {
+            serde_json::Value::String(v) => (k, v),
+            v => (k, v.to_string()),
+        })
+        .collect())
+}
+
+pub fn parse_blob_metadata(path: &str, headers: &http::HeaderMap) -> Result<Metadata> {
+    let mut md = parse_into_metadata(path, headers)?;
+
+    if let Some(v) = parse_header_to_str(headers, X_AMZ_META_USER)? {
+        let user_metadata = decode_metadata(v)?;
+        if !user_metadata.is_empty() {
+            md = md.with_user_metadata(user_metadata);
+        }
+    }
+
+    Ok(md)
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+pub struct SignedUrlResponse {
+    pub url: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ListResponse {
+    pub blobs: Vec<ListBlob>,
+    pub directories: Vec<String>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ListBlob {
+    pub etag: String,
+    pub key: String,
+    pub last_modified: String,
+    pub size: u64,
+}
+
+/// Parse error response into Error.
+///
+/// Netlify returns errors as plain text.
+pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
+    let (parts, body) = resp.into_parts();
+    let bs = body.to_bytes();
+
+    let (kind, retryable) = match parts.status.as_u16() {
+        401 | 403 => (ErrorKind::PermissionDenied, false),
+        404 => (ErrorKind::NotFound, false),
+        412 => (ErrorKind::ConditionNotMatch, false),
+        429 => (ErrorKind::RateLimited, true),
+        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let mut err = Error::new(kind, String::from_utf8_lossy(&bs));
+
+    err = with_error_response_context(err, parts);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        let metadata = HashMap::from([("location".to_string(), "Hello World".to_string())]);
+
+        let encoded = encode_metadata(&metadata).unwrap();
+        assert!(encoded.starts_with("b64;"));
+        assert_eq!(decode_metadata(&encoded).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_decode_metadata_with_json_values() {
+        // {"count":1,"name":"a"}
+        let metadata = decode_metadata("b64;eyJjb3VudCI6MSwibmFtZSI6ImEifQ==").unwrap();
+        assert_eq!(
+            metadata,
+            HashMap::from([
+                ("count".to_string(), "1".to_string()),
+                ("name".to_string(), "a".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_list_response() {
+        let resp: ListResponse = serde_json::from_str(
+            r#"{
+                "blobs": [
+                    {
+                        "etag": "\"abc\"",
+                        "key": "images/a.png",
+                        "last_modified": "2024-01-01T00:00:00.000Z",
+                        "size": 3
+                    }
+                ],
+                "directories": ["images/raw"],
+                "next_cursor": "next"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.blobs[0].key, "images/a.png");
+        assert_eq!(resp.blobs[0].size, 3);
+        assert_eq!(resp.directories, vec!["images/raw".to_string()]);
+        assert_eq!(resp.next_cursor.as_deref(), Some("next"));
+    }
+}
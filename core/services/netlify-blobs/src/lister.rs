@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+
+use super::core::ListResponse;
+use super::core::NetlifyBlobsCore;
+use super::core::parse_error;
+use opendal_core::raw::oio::Entry;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct NetlifyBlobsLister {
+    core: Arc<NetlifyBlobsCore>,
+    ctx: OperationContext,
+
+    path: String,
+    recursive: bool,
+}
+
+impl NetlifyBlobsLister {
+    pub(super) fn new(
+        core: Arc<NetlifyBlobsCore>,
+        ctx: OperationContext,
+        path: &str,
+        recursive: bool,
+    ) -> Self {
+        NetlifyBlobsLister {
+            core,
+            ctx,
+            path: path.to_string(),
+            recursive,
+        }
+    }
+}
+
+impl oio::PageList for NetlifyBlobsLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let resp = self
+            .core
+            .netlify_list_blobs(&self.ctx, &self.path, self.recursive, &ctx.token)
+            .await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let bs = resp.into_body();
+        let output: ListResponse =
+            serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+        match output.next_cursor {
+            Some(cursor) if !cursor.is_empty() => ctx.token = cursor,
+            _ => ctx.done = true,
+        }
+
+        for dir in output.directories {
+            let dir = if dir.ends_with('/') {
+                dir
+            } else {
+                format!("{dir}/")
+            };
+            let path = build_rel_path(&self.core.root, &dir);
+
+            ctx.entries
+                .push_back(Entry::new(&path, Metadata::new(EntryMode::DIR)));
+        }
+
+        for blob in output.blobs {
+            let path = build_rel_path(&self.core.root, &blob.key);
+
+            if path == self.path {
+                continue;
+            }
+
+            let mut md = Metadata::new(EntryMode::FILE)
+                .with_content_length(blob.size)
+                .with_etag(blob.etag);
+            if !blob.last_modified.is_empty() {
+                md.set_last_modified(blob.last_modified.parse::<Timestamp>()?);
+            }
+
+            ctx.entries.push_back(Entry::new(&path, md));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use log::debug;
+
+use super::NFS_SCHEME;
+use super::config::NfsConfig;
+use super::core::Manager;
+use super::core::NfsCore;
+use super::core::split_endpoint;
+use super::core::split_parent;
+use super::core::to_metadata;
+use super::deleter::NfsDeleter;
+use super::lister::NfsLister;
+use super::reader::NfsReader;
+use super::writer::NfsWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct NfsBuilder {
+    pub(super) config: NfsConfig,
+}
+
+impl NfsBuilder {
+    /// set endpoint for nfs backend.
+    ///
+    /// The format is `host[:port]`, the port is the portmapper port and default to `111`.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.to_string())
+        };
+
+        self
+    }
+
+    /// set export path to mount for nfs backend.
+    ///
+    /// Default to `/`.
+    pub fn export(mut self, export: &str) -> Self {
+        self.config.export = if export.is_empty() {
+            None
+        } else {
+            Some(export.to_string())
+        };
+
+        self
+    }
+
+    /// set root path for nfs backend.
+    ///
+    /// The root is relative to the export and will be created if not exists.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// set uid sent in AUTH_UNIX credentials for nfs backend.
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.config.uid = Some(uid);
+        self
+    }
+
+    /// set gid sent in AUTH_UNIX credentials for nfs backend.
+    pub fn gid(mut self, gid: u32) -> Self {
+        self.config.gid = Some(gid);
+        self
+    }
+
+    /// set port of the mount service, skipping the portmapper lookup.
+    pub fn mount_port(mut self, port: u16) -> Self {
+        self.config.mount_port = Some(port);
+        self
+    }
+
+    /// set port of the nfs service, skipping the portmapper lookup.
+    pub fn nfs_port(mut self, port: u16) -> Self {
+        self.config.nfs_port = Some(port);
+        self
+    }
+
+    /// Connect from an unprivileged local port.
+    ///
+    /// Most servers only accept connections from privileged ports (below 1024),
+    /// which requires running as root. Enable this if the export is configured
+    /// with the `insecure` option.
+    pub fn insecure(mut self) -> Self {
+        self.config.insecure = true;
+        self
+    }
+}
+
+impl Builder for NfsBuilder {
+    type Config = NfsConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("nfs backend build started: {:?}", &self);
+        let endpoint = match self.config.endpoint.clone() {
+            Some(v) => v,
+            None => return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")),
+        };
+        split_endpoint(&endpoint)?;
+
+        let export = match self.config.export.as_deref() {
+            Some(v) => format!("/{}", v.trim_matches('/')),
+            None => "/".to_string(),
+        };
+
+        let root = normalize_root(self.config.root.as_deref().unwrap_or_default());
+
+        let info = ServiceInfo::new(NFS_SCHEME, root.as_str(), export.as_str());
+        let capability = Capability {
+            stat: true,
+
+            read: true,
+            read_with_suffix: true,
+
+            write: true,
+            write_can_empty: true,
+            write_can_multi: true,
+            write_can_append: true,
+            write_with_if_not_exists: true,
+
+            create_dir: true,
+            create_dir_persistent: true,
+            delete: true,
+
+            list: true,
+
+            rename: true,
+            rename_is_atomic: true,
+
+            shared: true,
+
+            ..Default::default()
+        };
+
+        let core = Arc::new(NfsCore::new(
+            info,
+            capability,
+            Manager {
+                endpoint,
+                export,
+                root,
+                uid: self.config.uid.unwrap_or_default(),
+                gid: self.config.gid.unwrap_or_default(),
+                mount_port: self.config.mount_port,
+                nfs_port: self.config.nfs_port,
+                insecure: self.config.insecure,
+            },
+        ));
+
+        debug!("nfs backend finished: {:?}", &self);
+        Ok(NfsBackend { core })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NfsBackend {
+    pub core: Arc<NfsCore>,
+}
+
+impl Service for NfsBackend {
+    type Reader = oio::StreamReader<NfsReader>;
+    type Writer = NfsWriter;
+    type Lister = NfsLister;
+    type Deleter = oio::OneShotDeleter<NfsDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        path: &str,
+        _: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        let mut client = self.core.connect().await?;
+        client.create_dir_all(path).await?;
+
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let mut client = self.core.connect().await?;
+        let (_, attr) = client.resolve(path).await?;
+
+        let meta = to_metadata(&attr);
+        if path.ends_with('/') && !meta.is_dir() {
+            return Err(Error::new(ErrorKind::NotFound, "file is not a directory"));
+        }
+
+        Ok(RpStat::new(meta))
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(NfsReader::new(
+            self.core.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, path: &str, op: OpWrite) -> Result<Self::Writer> {
+        Ok(NfsWriter::new(self.core.clone(), path, op))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(NfsDeleter::new(self.core.clone())))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, _: OpList) -> Result<Self::Lister> {
+        Ok(NfsLister::new(self.core.clone(), path))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        let mut client = self.core.connect().await?;
+
+        let (from_dir, from_name) = client.resolve_parent(from).await?;
+        let (to_parent, to_name) = split_parent(to);
+        let to_dir = client.create_dir_all(to_parent).await?;
+        client
+            .rename(&from_dir, from_name, &to_dir, to_name)
+            .await?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::NfsBuilder;
+
+/// Config for NFS service support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct NfsConfig {
+    /// endpoint of this backend, in the form of `host[:port]`.
+    ///
+    /// The port is the portmapper port, default to `111`.
+    pub endpoint: Option<String>,
+    /// export path on the server to mount, default to `/`.
+    pub export: Option<String>,
+    /// root of this backend, relative to the export.
+    pub root: Option<String>,
+    /// uid sent in AUTH_UNIX credentials, default to `0`.
+    pub uid: Option<u32>,
+    /// gid sent in AUTH_UNIX credentials, default to `0`.
+    pub gid: Option<u32>,
+    /// port of the mount service, looked up from portmapper if not set.
+    pub mount_port: Option<u16>,
+    /// port of the nfs service, looked up from portmapper if not set.
+    pub nfs_port: Option<u16>,
+    /// Connect from an unprivileged local port.
+    ///
+    /// Most servers only accept privileged ports (below 1024), which requires
+    /// root. Enable this for exports configured with the `insecure` option.
+    pub insecure: bool,
+}
+
+impl Debug for NfsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NfsConfig")
+            .field("endpoint", &self.endpoint)
+            .field("export", &self.export)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for NfsConfig {
+    type Builder = NfsBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(authority) = uri.authority() {
+            map.insert("endpoint".to_string(), authority.to_string());
+        }
+
+        if let Some(export) = uri.root() {
+            map.insert("export".to_string(), format!("/{export}"));
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        NfsBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_and_export() {
+        let uri = OperatorUri::new(
+            "nfs://nfs.example.com:2049/srv/data",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = NfsConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("nfs.example.com:2049"));
+        assert_eq!(cfg.export.as_deref(), Some("/srv/data"));
+        assert_eq!(cfg.root, None);
+    }
+
+    #[test]
+    fn from_uri_applies_connection_overrides() {
+        let uri = OperatorUri::new(
+            "nfs://10.0.0.1/export",
+            vec![
+                ("root".to_string(), "datasets".to_string()),
+                ("uid".to_string(), "1000".to_string()),
+                ("gid".to_string(), "1000".to_string()),
+                ("insecure".to_string(), "true".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let cfg = NfsConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("10.0.0.1"));
+        assert_eq!(cfg.root.as_deref(), Some("datasets"));
+        assert_eq!(cfg.uid, Some(1000));
+        assert_eq!(cfg.gid, Some(1000));
+        assert!(cfg.insecure);
+    }
+}
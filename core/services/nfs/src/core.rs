@@ -0,0 +1,707 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use fastpool::ManageObject;
+use fastpool::ObjectStatus;
+use fastpool::bounded;
+use log::debug;
+use nfs3_client::Nfs3Client;
+use nfs3_client::Nfs3ConnectionBuilder;
+use nfs3_client::nfs3_types::nfs3::COMMIT3args;
+use nfs3_client::nfs3_types::nfs3::CREATE3args;
+use nfs3_client::nfs3_types::nfs3::FSINFO3args;
+use nfs3_client::nfs3_types::nfs3::GETATTR3args;
+use nfs3_client::nfs3_types::nfs3::LOOKUP3args;
+use nfs3_client::nfs3_types::nfs3::MKDIR3args;
+use nfs3_client::nfs3_types::nfs3::Nfs3Option;
+use nfs3_client::nfs3_types::nfs3::Nfs3Result;
+use nfs3_client::nfs3_types::nfs3::READ3args;
+use nfs3_client::nfs3_types::nfs3::READDIRPLUS3args;
+use nfs3_client::nfs3_types::nfs3::READDIRPLUS3resok;
+use nfs3_client::nfs3_types::nfs3::REMOVE3args;
+use nfs3_client::nfs3_types::nfs3::RENAME3args;
+use nfs3_client::nfs3_types::nfs3::RMDIR3args;
+use nfs3_client::nfs3_types::nfs3::SETATTR3args;
+use nfs3_client::nfs3_types::nfs3::WRITE3args;
+use nfs3_client::nfs3_types::nfs3::cookieverf3;
+use nfs3_client::nfs3_types::nfs3::createhow3;
+use nfs3_client::nfs3_types::nfs3::diropargs3;
+use nfs3_client::nfs3_types::nfs3::fattr3;
+use nfs3_client::nfs3_types::nfs3::filename3;
+use nfs3_client::nfs3_types::nfs3::ftype3;
+use nfs3_client::nfs3_types::nfs3::nfs_fh3;
+use nfs3_client::nfs3_types::nfs3::nfsstat3;
+use nfs3_client::nfs3_types::nfs3::sattr3;
+use nfs3_client::nfs3_types::nfs3::stable_how;
+use nfs3_client::nfs3_types::nfs3::writeverf3;
+use nfs3_client::nfs3_types::rpc::auth_unix;
+use nfs3_client::nfs3_types::rpc::opaque_auth;
+use nfs3_client::nfs3_types::xdr_codec::Opaque;
+use nfs3_client::tokio::TokioConnector;
+use nfs3_client::tokio::TokioIo;
+use opendal_core::raw::*;
+use opendal_core::*;
+use tokio::net::TcpStream;
+
+/// Default port of the portmapper service.
+const PORTMAP_PORT: u16 = 111;
+
+/// Size used when the server doesn't report its preferred transfer size.
+const DEFAULT_TRANSFER_SIZE: u32 = 64 * 1024;
+
+pub struct NfsCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub endpoint: String,
+    pub root: String,
+    client: Arc<bounded::Pool<Manager>>,
+}
+
+impl Debug for NfsCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NfsCore")
+            .field("endpoint", &self.endpoint)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NfsCore {
+    pub fn new(info: ServiceInfo, capability: Capability, manager: Manager) -> Self {
+        let endpoint = manager.endpoint.clone();
+        let root = manager.root.clone();
+        let client = bounded::Pool::new(bounded::PoolConfig::new(64), manager);
+
+        NfsCore {
+            info,
+            capability,
+            endpoint,
+            root,
+            client,
+        }
+    }
+
+    pub async fn connect(&self) -> Result<bounded::Object<Manager>> {
+        let fut = self.client.get();
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_secs(10)) => {
+                Err(Error::new(ErrorKind::Unexpected, "connection request: timeout").set_temporary())
+            }
+            result = fut => result,
+        }
+    }
+}
+
+pub struct Manager {
+    pub endpoint: String,
+    pub export: String,
+    pub root: String,
+    pub uid: u32,
+    pub gid: u32,
+    pub mount_port: Option<u16>,
+    pub nfs_port: Option<u16>,
+    pub insecure: bool,
+}
+
+impl ManageObject for Manager {
+    type Object = NfsConnection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Self::Object, Self::Error> {
+        let addr = resolve_endpoint(&self.endpoint).await?;
+        let credential = opaque_auth::auth_unix(&auth_unix {
+            stamp: 0,
+            machinename: Opaque::borrowed(b"opendal"),
+            uid: self.uid,
+            gid: self.gid,
+            gids: vec![self.gid],
+        });
+
+        let mut builder =
+            Nfs3ConnectionBuilder::new(TokioConnector, addr.ip().to_string(), &self.export)
+                .portmapper_port(addr.port())
+                .connect_from_privileged_port(!self.insecure)
+                .credential(credential);
+        if let Some(port) = self.mount_port {
+            builder = builder.mount_port(port);
+        }
+        if let Some(port) = self.nfs_port {
+            builder = builder.nfs3_port(port);
+        }
+
+        let conn = builder.mount().await.map_err(parse_nfs_error)?;
+        let export_root = conn.root_nfs_fh3();
+        // Unmounting is advisory in NFSv3, the file handle stays valid without
+        // the mount connection.
+        let mut client = conn.into_nfs3_client();
+
+        let fsinfo = client
+            .fsinfo(&FSINFO3args {
+                fsroot: export_root.clone(),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        let (rtmax, wtmax) = match fsinfo {
+            Nfs3Result::Ok(ok) => (ok.rtmax, ok.wtmax),
+            Nfs3Result::Err((stat, _)) => return Err(parse_nfs_status(stat)),
+        };
+
+        let mut conn = NfsConnection {
+            client,
+            root: export_root,
+            rtmax: if rtmax == 0 {
+                DEFAULT_TRANSFER_SIZE
+            } else {
+                rtmax
+            },
+            wtmax: if wtmax == 0 {
+                DEFAULT_TRANSFER_SIZE
+            } else {
+                wtmax
+            },
+        };
+        conn.root = conn.create_dir_all(&self.root).await?;
+
+        debug!(
+            "nfs connection created at {}:{}{}",
+            self.endpoint, self.export, self.root
+        );
+        Ok(conn)
+    }
+
+    // Check if connect valid by pinging the server.
+    async fn is_recyclable(
+        &self,
+        o: &mut Self::Object,
+        _: &ObjectStatus,
+    ) -> Result<(), Self::Error> {
+        o.client.null().await.map_err(parse_nfs_error)
+    }
+}
+
+/// A connection to the nfs service with the file handle of the backend root.
+///
+/// All paths accepted by this connection are relative to the backend root.
+pub struct NfsConnection {
+    client: Nfs3Client<TokioIo<TcpStream>>,
+    root: nfs_fh3,
+    /// Max size of a single read request.
+    pub rtmax: u32,
+    /// Max size of a single write request.
+    pub wtmax: u32,
+}
+
+impl NfsConnection {
+    pub async fn getattr(&mut self, fh: &nfs_fh3) -> Result<fattr3> {
+        let res = self
+            .client
+            .getattr(&GETATTR3args { object: fh.clone() })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => Ok(ok.obj_attributes),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    pub async fn lookup(&mut self, dir: &nfs_fh3, name: &str) -> Result<(nfs_fh3, fattr3)> {
+        let res = self
+            .client
+            .lookup(&LOOKUP3args {
+                what: diropargs(dir, name),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => {
+                let attr = match ok.obj_attributes {
+                    Nfs3Option::Some(attr) => attr,
+                    Nfs3Option::None => self.getattr(&ok.object).await?,
+                };
+                Ok((ok.object, attr))
+            }
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    /// Resolve the file handle and attributes of given path.
+    pub async fn resolve(&mut self, path: &str) -> Result<(nfs_fh3, fattr3)> {
+        let mut fh = self.root.clone();
+        let mut attr = None;
+        for name in path.split('/').filter(|v| !v.is_empty()) {
+            let (next, next_attr) = self.lookup(&fh, name).await?;
+            fh = next;
+            attr = Some(next_attr);
+        }
+
+        let attr = match attr {
+            Some(attr) => attr,
+            None => self.getattr(&fh).await?,
+        };
+        Ok((fh, attr))
+    }
+
+    /// Resolve the parent dir of given path, returns the dir handle and the
+    /// name of the path inside it.
+    pub async fn resolve_parent<'a>(&mut self, path: &'a str) -> Result<(nfs_fh3, &'a str)> {
+        let (parent, name) = split_parent(path);
+        let (fh, attr) = self.resolve(parent).await?;
+        if attr.type_ != ftype3::NF3DIR {
+            return Err(Error::new(
+                ErrorKind::NotADirectory,
+                "parent path is not a directory",
+            ));
+        }
+        Ok((fh, name))
+    }
+
+    /// Create all dirs of given path if not exist, returns the handle of the last dir.
+    pub async fn create_dir_all(&mut self, path: &str) -> Result<nfs_fh3> {
+        let mut fh = self.root.clone();
+        for name in path.split('/').filter(|v| !v.is_empty()) {
+            fh = match self.lookup(&fh, name).await {
+                Ok((next, attr)) if attr.type_ == ftype3::NF3DIR => next,
+                Ok(_) => {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "path component is not a directory",
+                    )
+                    .with_context("name", name));
+                }
+                Err(err) if err.kind() == ErrorKind::NotFound => self.mkdir(&fh, name).await?,
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(fh)
+    }
+
+    async fn mkdir(&mut self, dir: &nfs_fh3, name: &str) -> Result<nfs_fh3> {
+        let res = self
+            .client
+            .mkdir(&MKDIR3args {
+                where_: diropargs(dir, name),
+                attributes: sattr3 {
+                    mode: Nfs3Option::Some(0o755),
+                    ..Default::default()
+                },
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => match ok.obj {
+                Nfs3Option::Some(fh) => Ok(fh),
+                Nfs3Option::None => Ok(self.lookup(dir, name).await?.0),
+            },
+            // The dir could be created by others at the same time.
+            Nfs3Result::Err((nfsstat3::NFS3ERR_EXIST, _)) => match self.lookup(dir, name).await? {
+                (fh, attr) if attr.type_ == ftype3::NF3DIR => Ok(fh),
+                _ => Err(Error::new(
+                    ErrorKind::NotADirectory,
+                    "path component is not a directory",
+                )
+                .with_context("name", name)),
+            },
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    /// Create an empty file, truncating it if it already exists.
+    ///
+    /// If `if_not_exists` is set, fails with `ConditionNotMatch` instead.
+    pub async fn create(
+        &mut self,
+        dir: &nfs_fh3,
+        name: &str,
+        if_not_exists: bool,
+    ) -> Result<nfs_fh3> {
+        let attributes = sattr3 {
+            mode: Nfs3Option::Some(0o644),
+            size: Nfs3Option::Some(0),
+            ..Default::default()
+        };
+        let how = if if_not_exists {
+            createhow3::GUARDED(attributes)
+        } else {
+            createhow3::UNCHECKED(attributes)
+        };
+
+        let res = self
+            .client
+            .create(&CREATE3args {
+                where_: diropargs(dir, name),
+                how,
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => match ok.obj {
+                Nfs3Option::Some(fh) => Ok(fh),
+                Nfs3Option::None => Ok(self.lookup(dir, name).await?.0),
+            },
+            Nfs3Result::Err((nfsstat3::NFS3ERR_EXIST, _)) if if_not_exists => Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "file already exists",
+            )),
+            // Some servers reject `UNCHECKED` create for existing files, truncate it instead.
+            Nfs3Result::Err((nfsstat3::NFS3ERR_EXIST, _)) => {
+                let (fh, attr) = self.lookup(dir, name).await?;
+                if attr.type_ == ftype3::NF3DIR {
+                    return Err(Error::new(
+                        ErrorKind::IsADirectory,
+                        "cannot write to a directory",
+                    ));
+                }
+                self.truncate(&fh).await?;
+                Ok(fh)
+            }
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    async fn truncate(&mut self, fh: &nfs_fh3) -> Result<()> {
+        let res = self
+            .client
+            .setattr(&SETATTR3args {
+                object: fh.clone(),
+                new_attributes: sattr3 {
+                    size: Nfs3Option::Some(0),
+                    ..Default::default()
+                },
+                guard: Nfs3Option::None,
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(_) => Ok(()),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    /// Read at most `count` bytes from `offset`, returns the data and whether
+    /// the end of file has been reached.
+    pub async fn read(&mut self, fh: &nfs_fh3, offset: u64, count: u32) -> Result<(Buffer, bool)> {
+        let res = self
+            .client
+            .read(&READ3args {
+                file: fh.clone(),
+                offset,
+                count,
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => Ok((Buffer::from(ok.data.into_owned()), ok.eof)),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    /// Write data at `offset` without waiting for it to reach stable storage.
+    ///
+    /// Returns the number of bytes written and the write verifier that must
+    /// match the one returned by [`NfsConnection::commit`].
+    pub async fn write(
+        &mut self,
+        fh: &nfs_fh3,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<(usize, writeverf3)> {
+        let res = self
+            .client
+            .write(&WRITE3args {
+                file: fh.clone(),
+                offset,
+                count: data.len() as u32,
+                stable: stable_how::UNSTABLE,
+                data: Opaque::borrowed(data),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => Ok((ok.count as usize, ok.verf)),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    /// Flush all unstable writes of the file to stable storage.
+    pub async fn commit(&mut self, fh: &nfs_fh3) -> Result<writeverf3> {
+        let res = self
+            .client
+            .commit(&COMMIT3args {
+                file: fh.clone(),
+                offset: 0,
+                count: 0,
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => Ok(ok.verf),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    pub async fn remove(&mut self, dir: &nfs_fh3, name: &str) -> Result<()> {
+        let res = self
+            .client
+            .remove(&REMOVE3args {
+                object: diropargs(dir, name),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(_) => Ok(()),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    pub async fn rmdir(&mut self, dir: &nfs_fh3, name: &str) -> Result<()> {
+        let res = self
+            .client
+            .rmdir(&RMDIR3args {
+                object: diropargs(dir, name),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(_) => Ok(()),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    pub async fn rename(
+        &mut self,
+        from_dir: &nfs_fh3,
+        from: &str,
+        to_dir: &nfs_fh3,
+        to: &str,
+    ) -> Result<()> {
+        let res = self
+            .client
+            .rename(&RENAME3args {
+                from: diropargs(from_dir, from),
+                to: diropargs(to_dir, to),
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(_) => Ok(()),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+
+    pub async fn readdirplus(
+        &mut self,
+        dir: &nfs_fh3,
+        cookie: u64,
+        cookieverf: cookieverf3,
+    ) -> Result<READDIRPLUS3resok<'static>> {
+        let res = self
+            .client
+            .readdirplus(&READDIRPLUS3args {
+                dir: dir.clone(),
+                cookie,
+                cookieverf,
+                dircount: 16 * 1024,
+                maxcount: self.rtmax,
+            })
+            .await
+            .map_err(parse_nfs_error)?;
+        match res {
+            Nfs3Result::Ok(ok) => Ok(ok),
+            Nfs3Result::Err((stat, _)) => Err(parse_nfs_status(stat)),
+        }
+    }
+}
+
+fn diropargs<'a>(dir: &nfs_fh3, name: &'a str) -> diropargs3<'a> {
+    diropargs3 {
+        dir: dir.clone(),
+        name: filename3::from(name.as_bytes()),
+    }
+}
+
+/// Split path into its parent and the last component.
+pub fn split_parent(path: &str) -> (&str, &str) {
+    let path = path.trim_end_matches('/');
+    path.rsplit_once('/').unwrap_or(("", path))
+}
+
+/// Split endpoint into host and portmapper port.
+pub fn split_endpoint(endpoint: &str) -> Result<(&str, u16)> {
+    let invalid = || {
+        Error::new(ErrorKind::ConfigInvalid, "endpoint is invalid")
+            .with_context("endpoint", endpoint)
+    };
+    let parse_port = |port: &str| port.parse::<u16>().map_err(|_| invalid());
+
+    if let Some(rest) = endpoint.strip_prefix('[') {
+        let (host, rest) = rest.split_once(']').ok_or_else(invalid)?;
+        let port = match rest.strip_prefix(':') {
+            Some(port) => parse_port(port)?,
+            None if rest.is_empty() => PORTMAP_PORT,
+            None => return Err(invalid()),
+        };
+        return Ok((host, port));
+    }
+
+    match endpoint.rsplit_once(':') {
+        Some((host, port)) if !host.contains(':') => Ok((host, parse_port(port)?)),
+        // Bare IPv6 address without port.
+        Some(_) => Ok((endpoint, PORTMAP_PORT)),
+        None => Ok((endpoint, PORTMAP_PORT)),
+    }
+}
+
+async fn resolve_endpoint(endpoint: &str) -> Result<SocketAddr> {
+    let (host, port) = split_endpoint(endpoint)?;
+    tokio::net::lookup_host((host, port))
+        .await
+        .map_err(new_std_io_error)?
+        .next()
+        .ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "endpoint resolved to no address")
+                .with_context("endpoint", endpoint)
+        })
+}
+
+mod error {
+    use nfs3_client::error::Error as NfsClientError;
+    use nfs3_client::error::RpcError;
+    use nfs3_client::nfs3_types::mount::mountstat3;
+    use nfs3_client::nfs3_types::nfs3::nfsstat3;
+
+    use opendal_core::Error;
+    use opendal_core::ErrorKind;
+
+    pub fn parse_nfs_error(e: NfsClientError) -> Error {
+        let (kind, temporary) = match &e {
+            NfsClientError::NfsError(stat) => return parse_nfs_status(*stat),
+            NfsClientError::Io(_) => (ErrorKind::Unexpected, true),
+            NfsClientError::MountError(stat) => match stat {
+                mountstat3::MNT3ERR_NOENT | mountstat3::MNT3ERR_NOTDIR => {
+                    (ErrorKind::ConfigInvalid, false)
+                }
+                mountstat3::MNT3ERR_PERM | mountstat3::MNT3ERR_ACCES => {
+                    (ErrorKind::PermissionDenied, false)
+                }
+                mountstat3::MNT3ERR_NOTSUPP => (ErrorKind::Unsupported, false),
+                _ => (ErrorKind::Unexpected, false),
+            },
+            NfsClientError::Rpc(RpcError::Auth) => (ErrorKind::PermissionDenied, false),
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let mut err = Error::new(kind, "nfs error").set_source(e);
+        if temporary {
+            err = err.set_temporary();
+        }
+        err
+    }
+
+    pub fn parse_nfs_status(stat: nfsstat3) -> Error {
+        let (kind, temporary) = match stat {
+            nfsstat3::NFS3ERR_NOENT => (ErrorKind::NotFound, false),
+            nfsstat3::NFS3ERR_PERM | nfsstat3::NFS3ERR_ACCES | nfsstat3::NFS3ERR_ROFS => {
+                (ErrorKind::PermissionDenied, false)
+            }
+            nfsstat3::NFS3ERR_EXIST => (ErrorKind::AlreadyExists, false),
+            nfsstat3::NFS3ERR_ISDIR => (ErrorKind::IsADirectory, false),
+            nfsstat3::NFS3ERR_NOTDIR => (ErrorKind::NotADirectory, false),
+            nfsstat3::NFS3ERR_NOTSUPP => (ErrorKind::Unsupported, false),
+            // Handles are resolved from paths again on retry.
+            nfsstat3::NFS3ERR_STALE
+            | nfsstat3::NFS3ERR_BADHANDLE
+            | nfsstat3::NFS3ERR_JUKEBOX
+            | nfsstat3::NFS3ERR_SERVERFAULT => (ErrorKind::Unexpected, true),
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let mut err = Error::new(kind, format!("nfs error: {stat:?}"));
+        if temporary {
+            err = err.set_temporary();
+        }
+        err
+    }
+}
+
+pub(super) use error::*;
+
+mod utils {
+    use std::time::SystemTime;
+
+    use nfs3_client::nfs3_types::nfs3::fattr3;
+    use nfs3_client::nfs3_types::nfs3::ftype3;
+
+    use opendal_core::EntryMode;
+    use opendal_core::Metadata;
+    use opendal_core::raw::Timestamp;
+
+    pub fn to_metadata(attr: &fattr3) -> Metadata {
+        let mode = match attr.type_ {
+            ftype3::NF3REG => EntryMode::FILE,
+            ftype3::NF3DIR => EntryMode::DIR,
+            _ => EntryMode::Unknown,
+        };
+
+        let mut metadata = Metadata::new(mode);
+        if mode.is_file() {
+            metadata.set_content_length(attr.size);
+        }
+        if let Ok(m) = Timestamp::try_from(SystemTime::from(&attr.mtime)) {
+            metadata.set_last_modified(m);
+        }
+
+        metadata
+    }
+}
+
+pub(super) use utils::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_endpoint() {
+        let cases = [
+            ("nfs.example.com", Some(("nfs.example.com", 111))),
+            ("nfs.example.com:2049", Some(("nfs.example.com", 2049))),
+            ("10.0.0.1:111", Some(("10.0.0.1", 111))),
+            ("fe80::1", Some(("fe80::1", 111))),
+            ("[fe80::1]", Some(("fe80::1", 111))),
+            ("[fe80::1]:2049", Some(("fe80::1", 2049))),
+            ("nfs.example.com:abc", None),
+            ("[fe80::1]2049", None),
+        ];
+
+        for (endpoint, expected) in cases {
+            let actual = split_endpoint(endpoint).ok();
+            assert_eq!(actual, expected, "endpoint: {endpoint}");
+        }
+    }
+
+    #[test]
+    fn test_split_parent() {
+        assert_eq!(split_parent("a"), ("", "a"));
+        assert_eq!(split_parent("a/b/c"), ("a/b", "c"));
+        assert_eq!(split_parent("a/b/"), ("a", "b"));
+    }
+}
@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use nfs3_client::nfs3_types::nfs3::Nfs3Option;
+use nfs3_client::nfs3_types::nfs3::cookieverf3;
+use nfs3_client::nfs3_types::nfs3::ftype3;
+use nfs3_client::nfs3_types::nfs3::nfs_fh3;
+
+use super::core::NfsCore;
+use super::core::to_metadata;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct NfsLister {
+    core: Arc<NfsCore>,
+    path: String,
+
+    /// Handle of the listing dir, `None` before the first page is fetched.
+    dir: Option<nfs_fh3>,
+    cookie: u64,
+    cookieverf: cookieverf3,
+    done: bool,
+    entries: VecDeque<oio::Entry>,
+}
+
+impl NfsLister {
+    pub(super) fn new(core: Arc<NfsCore>, path: &str) -> Self {
+        let path = if path == "/" { "" } else { path };
+
+        Self {
+            core,
+            path: path.to_string(),
+            dir: None,
+            cookie: 0,
+            cookieverf: cookieverf3::default(),
+            done: false,
+            entries: VecDeque::new(),
+        }
+    }
+
+    async fn next_page(&mut self) -> Result<()> {
+        let mut conn = self.core.connect().await?;
+
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let (fh, attr) = match conn.resolve(&self.path).await {
+                    Ok(v) => v,
+                    Err(err)
+                        if matches!(err.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) =>
+                    {
+                        self.done = true;
+                        return Ok(());
+                    }
+                    Err(err) => return Err(err),
+                };
+                if attr.type_ != ftype3::NF3DIR {
+                    self.done = true;
+                    return Ok(());
+                }
+
+                let path = if self.path.is_empty() {
+                    "/"
+                } else {
+                    self.path.as_str()
+                };
+                self.entries
+                    .push_back(oio::Entry::new(path, to_metadata(&attr)));
+                self.dir = Some(fh.clone());
+                fh
+            }
+        };
+
+        let res = conn.readdirplus(&dir, self.cookie, self.cookieverf).await?;
+        self.cookieverf = res.cookieverf;
+        self.done = res.reply.eof;
+
+        for entry in res.reply.entries.0 {
+            self.cookie = entry.cookie;
+
+            let name = std::str::from_utf8(entry.name.as_ref()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "file name is not valid utf-8")
+                    .with_context("dir", &self.path)
+                    .set_source(err)
+            })?;
+            if name == "." || name == ".." {
+                continue;
+            }
+
+            let attr = match entry.name_attributes {
+                Nfs3Option::Some(attr) => attr,
+                Nfs3Option::None => match conn.lookup(&dir, name).await {
+                    Ok((_, attr)) => attr,
+                    // The entry has been removed after listing.
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                },
+            };
+
+            let path = if attr.type_ == ftype3::NF3DIR {
+                format!("{}{}/", self.path, name)
+            } else {
+                format!("{}{}", self.path, name)
+            };
+            self.entries
+                .push_back(oio::Entry::new(&path, to_metadata(&attr)));
+        }
+
+        Ok(())
+    }
+}
+
+impl oio::List for NfsLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            if let Some(entry) = self.entries.pop_front() {
+                return Ok(Some(entry));
+            }
+            if self.done {
+                return Ok(None);
+            }
+
+            self.next_page().await?;
+        }
+    }
+}
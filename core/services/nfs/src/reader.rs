@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use fastpool::bounded;
+use nfs3_client::nfs3_types::nfs3::ftype3;
+use nfs3_client::nfs3_types::nfs3::nfs_fh3;
+
+use super::core::Manager;
+use super::core::NfsCore;
+use super::core::to_metadata;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct NfsReadStream {
+    conn: bounded::Object<Manager>,
+    fh: nfs_fh3,
+    offset: u64,
+    end: u64,
+}
+
+impl oio::ReadStream for NfsReadStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.offset >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        let count = (self.end - self.offset).min(self.conn.rtmax as u64) as u32;
+        let (bs, eof) = self.conn.read(&self.fh, self.offset, count).await?;
+
+        self.offset += bs.len() as u64;
+        if eof || bs.is_empty() {
+            self.end = self.offset;
+        }
+        Ok(bs)
+    }
+}
+
+/// Reader returned by this backend.
+pub struct NfsReader {
+    core: Arc<NfsCore>,
+    path: String,
+}
+
+impl NfsReader {
+    pub(super) fn new(core: Arc<NfsCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::StreamRead for NfsReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let mut conn = self.core.connect().await?;
+
+        let (fh, attr) = conn.resolve(&self.path).await?;
+        if attr.type_ == ftype3::NF3DIR {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "cannot read a directory",
+            ));
+        }
+
+        let size = attr.size;
+        let range = range.to_content_range(size as usize)?;
+
+        let rp = RpRead::new(to_metadata(&attr));
+        let stream = NfsReadStream {
+            conn,
+            fh,
+            offset: range.start as u64,
+            end: range.end as u64,
+        };
+
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+    }
+}
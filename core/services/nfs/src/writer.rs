@@ -0,0 +1,164 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use fastpool::bounded;
+use nfs3_client::nfs3_types::nfs3::ftype3;
+use nfs3_client::nfs3_types::nfs3::nfs_fh3;
+use nfs3_client::nfs3_types::nfs3::writeverf3;
+
+use super::core::Manager;
+use super::core::NfsCore;
+use super::core::split_parent;
+use super::core::to_metadata;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+struct NfsFile {
+    conn: bounded::Object<Manager>,
+    dir: nfs_fh3,
+    fh: nfs_fh3,
+    offset: u64,
+    /// Verifier returned by the first unstable write, the server changes it
+    /// after restart which means uncommitted data may be lost.
+    verf: Option<writeverf3>,
+}
+
+pub struct NfsWriter {
+    core: Arc<NfsCore>,
+    path: String,
+    op: OpWrite,
+    file: Option<NfsFile>,
+}
+
+impl NfsWriter {
+    pub(super) fn new(core: Arc<NfsCore>, path: &str, op: OpWrite) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            op,
+            file: None,
+        }
+    }
+
+    async fn file(&mut self) -> Result<&mut NfsFile> {
+        if self.file.is_none() {
+            let mut conn = self.core.connect().await?;
+
+            let (parent, name) = split_parent(&self.path);
+            let dir = conn.create_dir_all(parent).await?;
+
+            let (fh, offset) = if self.op.append() {
+                match conn.lookup(&dir, name).await {
+                    Ok((_, attr)) if attr.type_ == ftype3::NF3DIR => {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "cannot append to a directory",
+                        ));
+                    }
+                    Ok((fh, attr)) => (fh, attr.size),
+                    Err(err) if err.kind() == ErrorKind::NotFound => {
+                        (conn.create(&dir, name, false).await?, 0)
+                    }
+                    Err(err) => return Err(err),
+                }
+            } else {
+                (conn.create(&dir, name, self.op.if_not_exists()).await?, 0)
+            };
+
+            self.file = Some(NfsFile {
+                conn,
+                dir,
+                fh,
+                offset,
+                verf: None,
+            });
+        }
+
+        Ok(self.file.as_mut().expect("file must be initialized"))
+    }
+}
+
+impl oio::Write for NfsWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let file = self.file().await?;
+
+        let bs = bs.to_bytes();
+        let mut data = bs.as_ref();
+        while !data.is_empty() {
+            let size = data.len().min(file.conn.wtmax as usize);
+            let (n, verf) = file
+                .conn
+                .write(&file.fh, file.offset, &data[..size])
+                .await?;
+            if n == 0 {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "nfs server accepted no data for write",
+                ));
+            }
+            if file.verf.is_some_and(|v| v != verf) {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "write verifier changed, nfs server may have restarted and lost data",
+                ));
+            }
+            file.verf = Some(verf);
+
+            file.offset += n as u64;
+            data = &data[n..];
+        }
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let file = self.file().await?;
+
+        if let Some(verf) = file.verf {
+            if file.conn.commit(&file.fh).await? != verf {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "write verifier changed, nfs server may have restarted and lost data",
+                ));
+            }
+        }
+
+        let attr = file.conn.getattr(&file.fh).await?;
+        Ok(to_metadata(&attr))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if self.op.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "NfsWriter doesn't support abort for append",
+            ));
+        }
+
+        let Some(mut file) = self.file.take() else {
+            return Ok(());
+        };
+        let (_, name) = split_parent(&self.path);
+        match file.conn.remove(&file.dir, name).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -200,12 +200,16 @@ impl Builder for ObsBuilder {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
 
             read: true,
             read_with_suffix: true,
 
             read_with_if_match: true,
             read_with_if_none_match: true,
+            read_with_if_modified_since: true,
+            read_with_if_unmodified_since: true,
 
             write: true,
             write_can_empty: true,
@@ -349,11 +353,10 @@ impl Service for ObsBackend {
             let w = if args.append() {
                 ObsWriters::Two(oio::AppendWriter::new(writer))
             } else {
-                ObsWriters::One(oio::MultipartWriter::new(
-                    ctx.executor().clone(),
-                    writer,
-                    args.concurrent(),
-                ))
+                ObsWriters::One(
+                    oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent())
+                        .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                )
             };
 
             Ok(w)
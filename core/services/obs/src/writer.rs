@@ -86,7 +86,7 @@ impl oio::MultipartWrite for ObsWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .obs_initiate_multipart_upload(&self.ctx, &self.path, self.op.content_type())
+            .obs_initiate_multipart_upload(&self.ctx, &self.path, &self.op)
             .await?;
 
         let status = resp.status();
@@ -147,6 +147,7 @@ impl Builder for OnedriveBuilder {
 
             delete: true,
             create_dir: true,
+            create_dir_persistent: true,
 
             list: true,
             list_with_limit: true,
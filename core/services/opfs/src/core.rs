@@ -37,6 +37,7 @@ impl OpfsCore {
             list: true,
 
             create_dir: true,
+            create_dir_persistent: true,
 
             write: true,
             write_can_empty: true,
@@ -112,6 +112,18 @@ impl OssBuilder {
         self
     }
 
+    /// Enable transfer acceleration for this backend.
+    ///
+    /// Requests will be sent to `oss-accelerate.aliyuncs.com` instead of `endpoint`.
+    /// The scheme of `endpoint` is kept if it's set. Transfer acceleration must be
+    /// enabled on the bucket first and only works with the `virtual` addressing style.
+    ///
+    /// - If presign_endpoint is not set, presigned urls will use the accelerate endpoint too.
+    pub fn enable_accelerate(mut self) -> Self {
+        self.config.enable_accelerate = true;
+        self
+    }
+
     /// Deprecated: OSS versioning capability is enabled by default.
     #[deprecated(
         since = "0.57.0",
@@ -187,6 +199,16 @@ impl OssBuilder {
         self
     }
 
+    /// Build the endpoint for transfer acceleration, keep the scheme of the
+    /// configured endpoint if it's set.
+    fn accelerate_endpoint(endpoint: &Option<String>) -> String {
+        let scheme = match endpoint.as_deref() {
+            Some(ep) if ep.starts_with("http://") => "http",
+            _ => "https",
+        };
+        format!("{scheme}://{OSS_ACCELERATE_HOST}")
+    }
+
     /// preprocess the endpoint option
     fn parse_endpoint(
         &self,
@@ -382,6 +404,8 @@ impl OssBuilder {
     }
 }
 
+const OSS_ACCELERATE_HOST: &str = "oss-accelerate.aliyuncs.com";
+
 enum AddressingStyle {
     Path,
     Cname,
@@ -429,11 +453,20 @@ impl Builder for OssBuilder {
 
         // Retrieve endpoint and host by parsing the endpoint option and bucket. If presign_endpoint is not
         // set, take endpoint as default presign_endpoint.
-        let (endpoint, host) = self.parse_endpoint(
-            &self.config.endpoint,
-            bucket,
-            (&self.config.addressing_style).try_into()?,
-        )?;
+        let addressing_style: AddressingStyle = (&self.config.addressing_style).try_into()?;
+        let endpoint = if self.config.enable_accelerate {
+            if !matches!(addressing_style, AddressingStyle::Virtual) {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "transfer acceleration only supports the virtual addressing style",
+                )
+                .with_context("service", OSS_SCHEME));
+            }
+            Some(Self::accelerate_endpoint(&self.config.endpoint))
+        } else {
+            self.config.endpoint.clone()
+        };
+        let (endpoint, host) = self.parse_endpoint(&endpoint, bucket, addressing_style)?;
         debug!("backend use bucket {}, endpoint: {}", &bucket, &endpoint);
 
         let presign_endpoint = if self.config.presign_endpoint.is_some() {
@@ -554,6 +587,8 @@ impl Builder for OssBuilder {
             stat: true,
             stat_with_if_match: true,
             stat_with_if_none_match: true,
+            stat_with_if_modified_since: true,
+            stat_with_if_unmodified_since: true,
             stat_with_version: true,
 
             read: true,
@@ -607,6 +642,8 @@ impl Builder for OssBuilder {
             presign_read: true,
             presign_write: true,
 
+            restore: true,
+
             shared: true,
 
             ..Default::default()
@@ -678,6 +715,10 @@ impl Service for OssBackend {
                     meta.set_version(v);
                 }
 
+                if let Some(v) = parse_restore_status(headers, constants::X_OSS_RESTORE)? {
+                    meta.set_restore_status(v);
+                }
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -703,11 +744,10 @@ impl Service for OssBackend {
             let w = if args.append() {
                 OssWriters::Two(oio::AppendWriter::new(writer))
             } else {
-                OssWriters::One(oio::MultipartWriter::new(
-                    ctx.executor().clone(),
-                    writer,
-                    args.concurrent(),
-                ))
+                OssWriters::One(
+                    oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent())
+                        .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                )
             };
 
             Ok(w)
@@ -789,6 +829,20 @@ impl Service for OssBackend {
         ))
     }
 
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let resp = self.core.oss_restore_object(ctx, path, &args).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::ACCEPTED => Ok(RpRestore::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(
         &self,
         ctx: &OperationContext,
@@ -827,3 +881,44 @@ impl Service for OssBackend {
         )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accelerate_endpoint() {
+        let builder = OssBuilder::default().bucket("example").enable_accelerate();
+
+        let cases = [
+            (None, "https://example.oss-accelerate.aliyuncs.com"),
+            (
+                Some("http://oss-cn-hangzhou.aliyuncs.com".to_string()),
+                "http://example.oss-accelerate.aliyuncs.com",
+            ),
+            (
+                Some("oss-cn-hangzhou.aliyuncs.com".to_string()),
+                "https://example.oss-accelerate.aliyuncs.com",
+            ),
+        ];
+        for (endpoint, expected) in cases {
+            let endpoint = OssBuilder::accelerate_endpoint(&endpoint);
+            let (endpoint, host) = builder
+                .parse_endpoint(&Some(endpoint), "example", AddressingStyle::Virtual)
+                .unwrap();
+            assert_eq!(endpoint, expected);
+            assert_eq!(host, "example.oss-accelerate.aliyuncs.com");
+        }
+    }
+
+    #[test]
+    fn test_accelerate_requires_virtual_style() {
+        let err = OssBuilder::default()
+            .bucket("example")
+            .addressing_style("path")
+            .enable_accelerate()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
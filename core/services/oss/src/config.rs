@@ -42,6 +42,10 @@ pub struct OssConfig {
     pub addressing_style: Option<String>,
     /// Pre sign addressing style for oss.
     pub presign_addressing_style: Option<String>,
+    /// Enable transfer acceleration for oss.
+    ///
+    /// Requests will be sent to `oss-accelerate.aliyuncs.com` instead of `endpoint`.
+    pub enable_accelerate: bool,
 
     /// Deprecated: OSS versioning capability is enabled by default.
     #[deprecated(
@@ -125,6 +129,7 @@ impl Debug for OssConfig {
             .field("root", &self.root)
             .field("bucket", &self.bucket)
             .field("endpoint", &self.endpoint)
+            .field("enable_accelerate", &self.enable_accelerate)
             .field("skip_signature", &self.skip_signature)
             .finish_non_exhaustive()
     }
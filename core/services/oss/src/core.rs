@@ -57,6 +57,8 @@ pub mod constants {
     pub const OSS_QUERY_VERSION_ID: &str = "versionId";
 
     pub const X_OSS_META_PREFIX: &str = "x-oss-meta-";
+
+    pub const X_OSS_RESTORE: &str = "x-oss-restore";
 }
 
 pub struct OssCore {
@@ -207,6 +209,14 @@ impl OssCore {
             req = req.header(X_OSS_FORBID_OVERWRITE, "true");
         }
 
+        self.insert_user_metadata_headers(req, args)
+    }
+
+    fn insert_user_metadata_headers(
+        &self,
+        mut req: http::request::Builder,
+        args: &OpWrite,
+    ) -> Result<http::request::Builder> {
         if let Some(user_metadata) = args.user_metadata() {
             for (key, value) in user_metadata {
                 // before insert user defined metadata header, add prefix to the header name
@@ -438,6 +448,14 @@ impl OssCore {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
+        if let Some(if_modified_since) = args.if_modified_since() {
+            req = req.header(IF_MODIFIED_SINCE, if_modified_since.format_http_date());
+        }
+
+        if let Some(if_unmodified_since) = args.if_unmodified_since() {
+            req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
+        }
+
         let req = req
             .extension(Operation::Stat)
             .extension(ServiceOperation("HeadObject"));
@@ -649,6 +667,43 @@ impl OssCore {
         self.send(ctx, req).await
     }
 
+    pub async fn oss_restore_object(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::post(&url);
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            days: args.days().unwrap_or(1),
+            job_parameters: args.tier().map(|tier| JobParameters {
+                tier: tier.to_string(),
+            }),
+        })
+        .map_err(new_xml_serialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        let req = req.header(CONTENT_LENGTH, content.len());
+        // Set content-type to `application/xml` to avoid mixed with form post.
+        let req = req.header(CONTENT_TYPE, "application/xml");
+        // Set content-md5 as required by API.
+        let req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let req = req
+            .extension(Operation::Restore)
+            .extension(ServiceOperation("RestoreObject"));
+
+        let req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+        let req = self.sign(ctx, req).await?;
+        self.send(ctx, req).await
+    }
+
     fn get_endpoint(&self, is_presign: bool) -> &str {
         if is_presign {
             &self.presign_endpoint
@@ -657,34 +712,31 @@ impl OssCore {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub async fn oss_initiate_upload(
         &self,
         ctx: &OperationContext,
         path: &str,
-        content_type: Option<&str>,
-        content_disposition: Option<&str>,
-        cache_control: Option<&str>,
-        content_encoding: Option<&str>,
+        args: &OpWrite,
         is_presign: bool,
     ) -> Result<Response<Buffer>> {
         let path = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
         let url = format!("{}/{}?uploads", endpoint, percent_encode_path(&path));
         let mut req = Request::post(&url);
-        if let Some(mime) = content_type {
+        if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime);
         }
-        if let Some(disposition) = content_disposition {
+        if let Some(disposition) = args.content_disposition() {
             req = req.header(CONTENT_DISPOSITION, disposition);
         }
-        if let Some(cache_control) = cache_control {
+        if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control);
         }
-        if let Some(encoding) = content_encoding {
+        if let Some(encoding) = args.content_encoding() {
             req = req.header(CONTENT_ENCODING, encoding);
         }
         req = self.insert_sse_headers(req);
+        req = self.insert_user_metadata_headers(req, args)?;
 
         let req = req
             .extension(Operation::Write)
@@ -811,6 +863,21 @@ pub struct DeleteObjectsRequestObject {
     pub version_id: Option<String>,
 }
 
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_parameters: Option<JobParameters>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct JobParameters {
+    pub tier: String,
+}
+
 /// Result of DeleteObjects.
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
@@ -1011,6 +1078,31 @@ mod tests {
         assert_eq!("oss-example", out.bucket);
     }
 
+    /// This example is from https://www.alibabacloud.com/help/en/oss/developer-reference/restoreobject
+    #[test]
+    fn test_serialize_restore_request() {
+        let req = RestoreRequest {
+            days: 2,
+            job_parameters: Some(JobParameters {
+                tier: "Standard".to_string(),
+            }),
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<RestoreRequest>
+  <Days>2</Days>
+  <JobParameters>
+    <Tier>Standard</Tier>
+  </JobParameters>
+</RestoreRequest>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
     #[test]
     fn test_serialize_complete_multipart_upload_request() {
         let req = CompleteMultipartUploadRequest {
@@ -71,7 +71,11 @@ impl oio::MultipartWrite for OssWriter {
 
         let resp = self.core.send(&self.ctx, req).await?;
 
-        let meta = Self::parse_metadata(resp.headers())?;
+        let mut meta = Self::parse_metadata(resp.headers())?;
+        // Put object returns the md5 of the whole object.
+        if let Some(md5) = meta.content_md5().map(|v| v.to_string()) {
+            meta.set_checksum(Checksum::new(ChecksumAlgorithm::Md5, md5));
+        }
         let status = resp.status();
 
         match status {
@@ -83,15 +87,7 @@ impl oio::MultipartWrite for OssWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .oss_initiate_upload(
-                &self.ctx,
-                &self.path,
-                self.op.content_type(),
-                self.op.content_disposition(),
-                self.op.cache_control(),
-                self.op.content_encoding(),
-                false,
-            )
+            .oss_initiate_upload(&self.ctx, &self.path, &self.op, false)
             .await?;
 
         let status = resp.status();
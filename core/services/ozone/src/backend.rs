@@ -0,0 +1,347 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+use log::debug;
+
+use super::OZONE_SCHEME;
+use super::config::OzoneConfig;
+use super::core::OzoneCore;
+use super::core::parse_error;
+use super::core::parse_file_status;
+use super::deleter::OzoneDeleter;
+use super::lister::OzoneLister;
+use super::message::BooleanResp;
+use super::message::FileStatusWrapper;
+use super::reader::OzoneReader;
+use super::writer::OzoneWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+const OZONE_DEFAULT_ENDPOINT: &str = "http://127.0.0.1:14000";
+
+/// [Apache Ozone](https://ozone.apache.org/) service support via its HttpFS REST API.
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct OzoneBuilder {
+    pub(super) config: OzoneConfig,
+}
+
+impl OzoneBuilder {
+    /// Set the endpoint of the Ozone HttpFS gateway.
+    ///
+    /// default: "http://127.0.0.1:14000"
+    ///
+    /// If user inputs endpoint without scheme, we will prepend `http://` to it.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Set the volume of this backend.
+    ///
+    /// If not specified, it will return an error when building.
+    pub fn volume(mut self, volume: &str) -> Self {
+        if !volume.is_empty() {
+            self.config.volume = Some(volume.to_string());
+        }
+        self
+    }
+
+    /// Set the bucket of this backend.
+    ///
+    /// If not specified, it will return an error when building.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        if !bucket.is_empty() {
+            self.config.bucket = Some(bucket.to_string());
+        }
+        self
+    }
+
+    /// Set the working directory inside the bucket, all operations will be
+    /// performed under it.
+    ///
+    /// default: "/"
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the username used by simple authentication.
+    pub fn user_name(mut self, user_name: &str) -> Self {
+        if !user_name.is_empty() {
+            self.config.user_name = Some(user_name.to_string());
+        }
+        self
+    }
+
+    /// Set the delegation token of this backend.
+    ///
+    /// # Note
+    ///
+    /// The delegation token is sent along with the username if both are set.
+    pub fn delegation(mut self, delegation: &str) -> Self {
+        if !delegation.is_empty() {
+            self.config.delegation = Some(delegation.to_string());
+        }
+        self
+    }
+}
+
+impl Builder for OzoneBuilder {
+    type Config = OzoneConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("start building backend: {self:?}");
+
+        let config = self.config;
+
+        let Some(volume) = config.volume else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "volume is required")
+                .with_context("service", OZONE_SCHEME));
+        };
+        let Some(bucket) = config.bucket else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "bucket is required")
+                .with_context("service", OZONE_SCHEME));
+        };
+
+        let endpoint = match config.endpoint {
+            Some(endpoint) if endpoint.starts_with("http") => endpoint,
+            Some(endpoint) => format!("http://{endpoint}"),
+            None => OZONE_DEFAULT_ENDPOINT.to_string(),
+        };
+        debug!("backend use endpoint {endpoint}");
+
+        let root = normalize_root(config.root.as_deref().unwrap_or("/"));
+        debug!("backend use root {root}");
+
+        let info = ServiceInfo::new(OZONE_SCHEME, &root, format!("{volume}/{bucket}"));
+        let capability = Capability {
+            stat: true,
+
+            read: true,
+
+            write: true,
+            write_can_empty: true,
+            write_with_content_type: true,
+            write_with_if_not_exists: true,
+
+            create_dir: true,
+            create_dir_persistent: true,
+            delete: true,
+
+            list: true,
+
+            rename: true,
+
+            shared: true,
+
+            ..Default::default()
+        };
+
+        Ok(OzoneBackend {
+            core: Arc::new(OzoneCore {
+                info,
+                capability,
+                root,
+                endpoint,
+                volume,
+                bucket,
+                user_name: config.user_name,
+                auth: config.delegation.map(|dt| format!("delegation={dt}")),
+            }),
+        })
+    }
+}
+
+/// Backend for Apache Ozone service.
+#[derive(Debug, Clone)]
+pub struct OzoneBackend {
+    core: Arc<OzoneCore>,
+}
+
+impl Service for OzoneBackend {
+    type Reader = oio::StreamReader<OzoneReader>;
+    type Writer = oio::OneShotWriter<OzoneWriter>;
+    type Lister = oio::PageLister<OzoneLister>;
+    type Deleter = oio::OneShotDeleter<OzoneDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        _: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        let resp = self.core.ozone_mkdirs(ctx, path).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                let resp = serde_json::from_reader::<_, BooleanResp>(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                if resp.boolean {
+                    Ok(RpCreateDir::default())
+                } else {
+                    Err(Error::new(ErrorKind::Unexpected, "ozone create dir failed"))
+                }
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let resp = self.core.ozone_get_file_status(ctx, path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let status =
+                    serde_json::from_reader::<_, FileStatusWrapper>(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?
+                        .file_status;
+                let meta = parse_file_status(&status)?;
+
+                // Ozone addresses keys and directories in the same way, so
+                // we need to make sure `dir/` never matches a key.
+                if path.ends_with('/') && meta.is_file() {
+                    return Err(Error::new(ErrorKind::NotFound, "key is not a directory"));
+                }
+
+                Ok(RpStat::new(meta))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(OzoneReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        Ok(oio::OneShotWriter::new(OzoneWriter::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+            args,
+        )))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(OzoneDeleter::new(
+            self.core.clone(),
+            ctx.clone(),
+        )))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        if args.recursive() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "ozone doesn't support list with recursive",
+            ));
+        }
+
+        Ok(oio::PageLister::new(OzoneLister::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+        )))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        // Ozone requires the parent of destination to exist.
+        let parent = get_parent(to);
+        if parent != "/" {
+            self.create_dir(ctx, parent, OpCreateDir::new()).await?;
+        }
+
+        // HttpFS's RENAME refuses to overwrite, remove the destination first.
+        let resp = self.core.ozone_delete(ctx, to).await?;
+        if !matches!(resp.status(), StatusCode::OK | StatusCode::NOT_FOUND) {
+            return Err(parse_error(resp));
+        }
+
+        let resp = self.core.ozone_rename(ctx, from, to).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let resp = serde_json::from_reader::<_, BooleanResp>(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                if resp.boolean {
+                    Ok(RpRename::default())
+                } else {
+                    Err(Error::new(ErrorKind::Unexpected, "ozone rename failed"))
+                }
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::OzoneBuilder;
+
+/// Config for [Apache Ozone](https://ozone.apache.org/) service support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct OzoneConfig {
+    /// The endpoint of the Ozone HttpFS gateway.
+    ///
+    /// default is `http://127.0.0.1:14000`
+    pub endpoint: Option<String>,
+    /// The volume that holds the bucket.
+    pub volume: Option<String>,
+    /// The bucket to store keys in.
+    pub bucket: Option<String>,
+    /// The working directory inside the bucket.
+    pub root: Option<String>,
+    /// Name of the user for simple authentication.
+    pub user_name: Option<String>,
+    /// Delegation token for ozone.
+    pub delegation: Option<String>,
+}
+
+impl Debug for OzoneConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OzoneConfig")
+            .field("endpoint", &self.endpoint)
+            .field("volume", &self.volume)
+            .field("bucket", &self.bucket)
+            .field("root", &self.root)
+            .field("user_name", &self.user_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for OzoneConfig {
+    type Builder = OzoneBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(authority) = uri.authority() {
+            map.entry("endpoint".to_string())
+                .or_insert_with(|| format!("http://{authority}"));
+        }
+
+        // The path is `<volume>/<bucket>/<root>`.
+        if let Some(path) = uri.root() {
+            let mut segments = path.splitn(3, '/');
+            for key in ["volume", "bucket", "root"] {
+                match segments.next() {
+                    Some(v) if !v.is_empty() => {
+                        map.entry(key.to_string()).or_insert_with(|| v.to_string());
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        OzoneBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_volume_bucket_and_root() {
+        let uri = OperatorUri::new(
+            "ozone://om.example.com:14000/vol1/bucket1/staging/daily",
+            vec![("user_name".to_string(), "hadoop".to_string())],
+        )
+        .unwrap();
+
+        let cfg = OzoneConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("http://om.example.com:14000"));
+        assert_eq!(cfg.volume.as_deref(), Some("vol1"));
+        assert_eq!(cfg.bucket.as_deref(), Some("bucket1"));
+        assert_eq!(cfg.root.as_deref(), Some("staging/daily"));
+        assert_eq!(cfg.user_name.as_deref(), Some("hadoop"));
+    }
+
+    #[test]
+    fn from_uri_prefers_explicit_options() {
+        let uri = OperatorUri::new(
+            "ozone://om.example.com:14000/vol1",
+            vec![("bucket".to_string(), "bucket2".to_string())],
+        )
+        .unwrap();
+
+        let cfg = OzoneConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.volume.as_deref(), Some("vol1"));
+        assert_eq!(cfg.bucket.as_deref(), Some("bucket2"));
+        assert!(cfg.root.is_none());
+    }
+}
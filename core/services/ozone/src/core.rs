@@ -0,0 +1,308 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_TYPE;
+use serde::Deserialize;
+
+use super::message::FileStatus;
+use super::message::FileStatusType;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct OzoneCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub root: String,
+    pub endpoint: String,
+    pub volume: String,
+    pub bucket: String,
+    pub user_name: Option<String>,
+    pub auth: Option<String>,
+}
+
+impl Debug for OzoneCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OzoneCore")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .field("volume", &self.volume)
+            .field("bucket", &self.bucket)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OzoneCore {
+    /// Build the ozone filesystem path of given path: `/<volume>/<bucket>/<root><path>`.
+    ///
+    /// Trailing slashes are removed since ozone addresses directories
+    /// and keys in the same way.
+    fn ozone_path(&self, path: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+        format!("/{}/{}/{}", self.volume, self.bucket, p)
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    fn ozone_url(&self, path: &str, op: &str) -> String {
+        let mut url = format!(
+            "{}/webhdfs/v1{}?op={op}",
+            self.endpoint,
+            percent_encode_path(&self.ozone_path(path)),
+        );
+        if let Some(user) = &self.user_name {
+            url += &format!("&user.name={}", percent_encode_path(user));
+        }
+        if let Some(auth) = &self.auth {
+            url += &format!("&{auth}");
+        }
+        url
+    }
+
+    pub async fn ozone_get_file_status(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = self.ozone_url(path, "GETFILESTATUS");
+
+        let req = Request::get(&url)
+            .extension(Operation::Stat)
+            .extension(ServiceOperation("GetFileStatus"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ozone_open(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<HttpBody>> {
+        let mut url = self.ozone_url(path, "OPEN");
+        if !range.is_full() {
+            url += &format!("&offset={}", range.offset());
+            if let Some(size) = range.size() {
+                url += &format!("&length={size}")
+            }
+        }
+
+        let req = Request::get(&url)
+            .extension(Operation::Read)
+            .extension(ServiceOperation("Open"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().fetch(req).await
+    }
+
+    /// Create a key with given content.
+    ///
+    /// HttpFS accepts the data in the first request when `data=true` is set,
+    /// so there is no redirect to a datanode like native WebHDFS.
+    pub async fn ozone_create(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpWrite,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let mut url = self.ozone_url(path, "CREATE");
+        url += &format!("&overwrite={}&data=true", !args.if_not_exists());
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, body.len())
+            .header(
+                CONTENT_TYPE,
+                args.content_type().unwrap_or("application/octet-stream"),
+            )
+            .extension(Operation::Write)
+            .extension(ServiceOperation("Create"))
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ozone_mkdirs(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = self.ozone_url(path, "MKDIRS");
+
+        let req = Request::put(&url)
+            .extension(Operation::CreateDir)
+            .extension(ServiceOperation("Mkdirs"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ozone_rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+    ) -> Result<Response<Buffer>> {
+        let mut url = self.ozone_url(from, "RENAME");
+        url += &format!("&destination={}", percent_encode_path(&self.ozone_path(to)));
+
+        let req = Request::put(&url)
+            .extension(Operation::Rename)
+            .extension(ServiceOperation("Rename"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ozone_delete(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let mut url = self.ozone_url(path, "DELETE");
+        url += "&recursive=false";
+
+        let req = Request::delete(&url)
+            .extension(Operation::Delete)
+            .extension(ServiceOperation("Delete"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+
+    pub async fn ozone_list_status(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = self.ozone_url(path, "LISTSTATUS");
+
+        let req = Request::get(&url)
+            .extension(Operation::List)
+            .extension(ServiceOperation("ListStatus"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        ctx.http_transport().send(req).await
+    }
+}
+
+/// Convert ozone's file status into metadata.
+pub(super) fn parse_file_status(status: &FileStatus) -> Result<Metadata> {
+    let meta = match status.ty {
+        FileStatusType::Directory => Metadata::new(EntryMode::DIR),
+        FileStatusType::File => Metadata::new(EntryMode::FILE)
+            .with_content_length(status.length)
+            .with_last_modified(Timestamp::from_millisecond(status.modification_time)?),
+    };
+    Ok(meta)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OzoneErrorWrapper {
+    remote_exception: OzoneError,
+}
+
+/// OzoneError is the error message returned by ozone HttpFS gateway.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OzoneError {
+    exception: String,
+    message: String,
+}
+
+pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
+    let (parts, body) = resp.into_parts();
+    let bs = body.to_bytes();
+
+    let (mut kind, mut retryable) = match parts.status {
+        StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let message = match serde_json::from_slice::<OzoneErrorWrapper>(&bs) {
+        Ok(wrapper) => {
+            let err = wrapper.remote_exception;
+            match err.exception.as_str() {
+                "FileNotFoundException" => (kind, retryable) = (ErrorKind::NotFound, false),
+                "FileAlreadyExistsException" => {
+                    (kind, retryable) = (ErrorKind::ConditionNotMatch, false)
+                }
+                "AccessControlException" => {
+                    (kind, retryable) = (ErrorKind::PermissionDenied, false)
+                }
+                _ => {}
+            }
+            format!("{}: {}", err.exception, err.message)
+        }
+        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    };
+
+    let mut err = Error::new(kind, message);
+
+    err = with_error_response_context(err, parts);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let body = r#"
+{
+  "RemoteException": {
+    "exception": "FileAlreadyExistsException",
+    "javaClassName": "org.apache.hadoop.fs.FileAlreadyExistsException",
+    "message": "File already exists: /vol1/bucket1/file"
+  }
+}
+"#;
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(body))
+            .unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        assert!(!err.is_temporary());
+    }
+}
@@ -0,0 +1,49 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use http::StatusCode;
+
+use super::core::OzoneCore;
+use super::core::parse_error;
+use opendal_core::raw::oio;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct OzoneDeleter {
+    core: Arc<OzoneCore>,
+    ctx: OperationContext,
+}
+
+impl OzoneDeleter {
+    pub fn new(core: Arc<OzoneCore>, ctx: OperationContext) -> Self {
+        Self { core, ctx }
+    }
+}
+
+impl oio::OneShotDelete for OzoneDeleter {
+    async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
+        let resp = self.core.ozone_delete(&self.ctx, &path).await?;
+
+        // Ozone returns `{"boolean": false}` if the key doesn't exist.
+        match resp.status() {
+            StatusCode::OK | StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
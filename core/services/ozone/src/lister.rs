@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+
+use super::core::OzoneCore;
+use super::core::parse_error;
+use super::core::parse_file_status;
+use super::message::FileStatusesWrapper;
+use opendal_core::raw::oio;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct OzoneLister {
+    core: Arc<OzoneCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl OzoneLister {
+    pub fn new(core: Arc<OzoneCore>, ctx: OperationContext, path: &str) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::PageList for OzoneLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let resp = self.core.ozone_list_status(&self.ctx, &self.path).await?;
+
+        // HttpFS returns the whole directory in one response.
+        ctx.done = true;
+
+        let statuses = match resp.status() {
+            StatusCode::OK => {
+                serde_json::from_reader::<_, FileStatusesWrapper>(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?
+                    .file_statuses
+                    .file_status
+            }
+            StatusCode::NOT_FOUND => return Ok(()),
+            _ => return Err(parse_error(resp)),
+        };
+
+        let dir = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        ctx.entries
+            .push_back(oio::Entry::new(dir, Metadata::new(EntryMode::DIR)));
+
+        for status in statuses {
+            let meta = parse_file_status(&status)?;
+            let mut path = format!("{}{}", self.path, status.path_suffix);
+            if meta.is_dir() {
+                path.push('/');
+            }
+            ctx.entries.push_back(oio::Entry::new(&path, meta));
+        }
+
+        Ok(())
+    }
+}
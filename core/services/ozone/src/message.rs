@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Ozone HttpFS response messages
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub(super) struct BooleanResp {
+    pub boolean: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct FileStatusWrapper {
+    pub file_status: FileStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct FileStatusesWrapper {
+    pub file_statuses: FileStatuses,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct FileStatuses {
+    pub file_status: Vec<FileStatus>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FileStatus {
+    pub length: u64,
+    pub modification_time: i64,
+
+    pub path_suffix: String,
+    #[serde(rename = "type")]
+    pub ty: FileStatusType,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub(super) enum FileStatusType {
+    Directory,
+    #[default]
+    File,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_status() {
+        let json = r#"
+{
+  "FileStatus": {
+    "accessTime": 1700000000000,
+    "blockSize": 268435456,
+    "group": "hadoop",
+    "length": 1024,
+    "modificationTime": 1700000000123,
+    "owner": "hadoop",
+    "pathSuffix": "",
+    "permission": "666",
+    "replication": 3,
+    "type": "FILE"
+  }
+}
+"#;
+        let status: FileStatusWrapper = serde_json::from_str(json).expect("must success");
+        assert_eq!(status.file_status.length, 1024);
+        assert_eq!(status.file_status.modification_time, 1700000000123);
+        assert_eq!(status.file_status.ty, FileStatusType::File);
+    }
+
+    #[test]
+    fn test_list_status() {
+        let json = r#"
+{
+  "FileStatuses": {
+    "FileStatus": [
+      {
+        "length": 24930,
+        "modificationTime": 1700000000000,
+        "pathSuffix": "a.parquet",
+        "type": "FILE"
+      },
+      {
+        "length": 0,
+        "modificationTime": 1700000001000,
+        "pathSuffix": "logs",
+        "type": "DIRECTORY"
+      }
+    ]
+  }
+}
+"#;
+        let statuses = serde_json::from_str::<FileStatusesWrapper>(json)
+            .expect("must success")
+            .file_statuses
+            .file_status;
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].path_suffix, "a.parquet");
+        assert_eq!(statuses[0].ty, FileStatusType::File);
+        assert_eq!(statuses[1].path_suffix, "logs");
+        assert_eq!(statuses[1].ty, FileStatusType::Directory);
+    }
+}
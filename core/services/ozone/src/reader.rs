@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use http::Response;
+use http::StatusCode;
+
+use super::core::OzoneCore;
+use super::core::parse_error;
+use opendal_core::raw::oio;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct OzoneReader {
+    core: Arc<OzoneCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl OzoneReader {
+    pub fn new(core: Arc<OzoneCore>, ctx: OperationContext, path: &str) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::StreamRead for OzoneReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let resp = self.core.ozone_open(&self.ctx, &self.path, range).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((
+                RpRead::default(),
+                Box::new(resp.into_body()) as Box<dyn oio::ReadStreamDyn>,
+            )),
+            _ => {
+                let (part, mut body) = resp.into_parts();
+                let buf = body.to_buffer().await?;
+                Err(parse_error(Response::from_parts(part, buf)))
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use http::StatusCode;
+
+use super::core::OzoneCore;
+use super::core::parse_error;
+use opendal_core::raw::oio;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct OzoneWriter {
+    core: Arc<OzoneCore>,
+    ctx: OperationContext,
+    path: String,
+    op: OpWrite,
+}
+
+impl OzoneWriter {
+    pub fn new(core: Arc<OzoneCore>, ctx: OperationContext, path: &str, op: OpWrite) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+            op,
+        }
+    }
+}
+
+impl oio::OneShotWrite for OzoneWriter {
+    async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        let size = bs.len() as u64;
+        let resp = self
+            .core
+            .ozone_create(&self.ctx, &self.path, &self.op, bs)
+            .await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                Ok(Metadata::new(EntryMode::FILE).with_content_length(size))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
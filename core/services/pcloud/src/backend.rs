@@ -142,6 +142,7 @@ impl Builder for PcloudBuilder {
                     stat: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     read: true,
                     read_with_suffix: true,
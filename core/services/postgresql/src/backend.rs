@@ -24,11 +24,16 @@ use super::POSTGRESQL_SCHEME;
 use super::config::PostgresqlConfig;
 use super::core::*;
 use super::deleter::PostgresqlDeleter;
+use super::lister::PostgresqlLister;
 use super::reader::*;
+use super::writer::PostgresqlBlobWriter;
 use super::writer::PostgresqlWriter;
 use opendal_core::raw::*;
 use opendal_core::*;
 
+/// The default size of chunks in blob mode.
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
 /// [PostgreSQL](https://www.postgresql.org/) services support.
 #[doc = include_str!("docs.md")]
 #[derive(Debug, Default)]
@@ -94,6 +99,23 @@ impl PostgresqlBuilder {
         }
         self
     }
+
+    /// Enable blob mode, which stores every object as chunks in rows of
+    /// `("path", "chunk_no", "data")`.
+    ///
+    /// Blob mode supports streaming read and write, list, copy and rename.
+    pub fn blob_mode(mut self, enabled: bool) -> Self {
+        self.config.blob_mode = enabled;
+        self
+    }
+
+    /// Set the size of every chunk in blob mode.
+    ///
+    /// Default to 1 MiB if not specified.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.config.chunk_size = Some(chunk_size);
+        self
+    }
 }
 
 impl Builder for PostgresqlBuilder {
@@ -131,6 +153,18 @@ impl Builder for PostgresqlBuilder {
             .value_field
             .unwrap_or_else(|| "value".to_string());
 
+        let chunk_size = match self.config.chunk_size {
+            Some(0) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "chunk_size must be greater than 0",
+                )
+                .with_context("service", POSTGRESQL_SCHEME));
+            }
+            Some(v) => v,
+            None => DEFAULT_CHUNK_SIZE,
+        };
+
         let root = normalize_root(self.config.root.unwrap_or_else(|| "/".to_string()).as_str());
 
         Ok(PostgresqlBackend::new(PostgresqlCore {
@@ -139,6 +173,7 @@ impl Builder for PostgresqlBuilder {
             table,
             key_field,
             value_field,
+            chunk_size: self.config.blob_mode.then_some(chunk_size),
         })
         .with_normalized_root(root))
     }
@@ -156,14 +191,31 @@ pub struct PostgresqlBackend {
 impl PostgresqlBackend {
     pub fn new(core: PostgresqlCore) -> Self {
         let info = ServiceInfo::new(POSTGRESQL_SCHEME, "/", &core.table);
-        let capability = Capability {
-            read: true,
-            stat: true,
-            write: true,
-            write_can_empty: true,
-            delete: true,
-            shared: true,
-            ..Default::default()
+        let capability = if core.chunk_size.is_some() {
+            Capability {
+                read: true,
+                stat: true,
+                write: true,
+                write_can_empty: true,
+                write_can_multi: true,
+                delete: true,
+                list: true,
+                list_with_recursive: true,
+                copy: true,
+                rename: true,
+                shared: true,
+                ..Default::default()
+            }
+        } else {
+            Capability {
+                read: true,
+                stat: true,
+                write: true,
+                write_can_empty: true,
+                delete: true,
+                shared: true,
+                ..Default::default()
+            }
         };
 
         Self {
@@ -183,10 +235,10 @@ impl PostgresqlBackend {
 
 impl Service for PostgresqlBackend {
     type Reader = oio::StreamReader<PostgresqlReader>;
-    type Writer = PostgresqlWriter;
-    type Lister = ();
+    type Writer = TwoWays<PostgresqlWriter, PostgresqlBlobWriter>;
+    type Lister = oio::HierarchyLister<oio::PageLister<PostgresqlLister>>;
     type Deleter = oio::OneShotDeleter<PostgresqlDeleter>;
-    type Copier = ();
+    type Copier = oio::OneShotCopier;
 
     fn info(&self) -> ServiceInfo {
         self.info.clone()
@@ -213,6 +265,27 @@ impl Service for PostgresqlBackend {
 
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+        } else if self.core.chunk_size.is_some() {
+            if p.ends_with('/') {
+                return if self.core.blob_dir_exists(&p).await? {
+                    Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+                } else {
+                    Err(Error::new(
+                        ErrorKind::NotFound,
+                        "dir not found in postgresql",
+                    ))
+                };
+            }
+
+            match self.core.blob_stat(&p).await? {
+                Some(meta) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(meta.size),
+                )),
+                None => Err(Error::new(
+                    ErrorKind::NotFound,
+                    "blob not found in postgresql",
+                )),
+            }
         } else {
             let bs = self.core.get(&p).await?;
             match bs {
@@ -239,9 +312,16 @@ impl Service for PostgresqlBackend {
     }
 
     fn write(&self, _ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
-        let output: PostgresqlWriter = {
+        let output = {
             let p = build_abs_path(&self.root, path);
-            Ok(PostgresqlWriter::new(self.core.clone(), p))
+            match self.core.chunk_size {
+                Some(chunk_size) => Ok(TwoWays::Two(PostgresqlBlobWriter::new(
+                    self.core.clone(),
+                    p,
+                    chunk_size,
+                ))),
+                None => Ok(TwoWays::One(PostgresqlWriter::new(self.core.clone(), p))),
+            }
         }?;
 
         Ok(output)
@@ -258,38 +338,66 @@ impl Service for PostgresqlBackend {
         Ok(output)
     }
 
-    fn list(&self, _ctx: &OperationContext, _path: &str, _args: OpList) -> Result<Self::Lister> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+    fn list(&self, _ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        if self.core.chunk_size.is_none() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        let output = {
+            let l = PostgresqlLister::new(self.core.clone(), self.root.clone(), path, args.limit());
+            let l = oio::HierarchyLister::new(oio::PageLister::new(l), path, args.recursive());
+            Ok(l)
+        }?;
+
+        Ok(output)
     }
 
     fn copy(
         &self,
         _ctx: &OperationContext,
-        _from: &str,
-        _to: &str,
+        from: &str,
+        to: &str,
         _args: OpCopy,
         _opts: OpCopier,
     ) -> Result<Self::Copier> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        if self.core.chunk_size.is_none() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        let core = self.core.clone();
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+
+        Ok(oio::OneShotCopier::new(async move {
+            core.blob_copy(&from, &to).await?;
+            Ok(Metadata::default())
+        }))
     }
 
     async fn rename(
         &self,
         _ctx: &OperationContext,
-        _from: &str,
-        _to: &str,
+        from: &str,
+        to: &str,
         _args: OpRename,
     ) -> Result<RpRename> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        if self.core.chunk_size.is_none() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+        self.core.blob_rename(&from, &to).await?;
+        Ok(RpRename::new())
     }
 
     async fn presign(
@@ -304,3 +412,30 @@ impl Service for PostgresqlBackend {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> PostgresqlBuilder {
+        PostgresqlBuilder::default()
+            .connection_string("postgresql://user@localhost/db")
+            .table("blobs")
+    }
+
+    #[test]
+    fn build_with_blob_mode() {
+        let srv = builder().build().unwrap();
+        assert!(!srv.capability().list);
+
+        let srv = builder().blob_mode(true).chunk_size(4096).build().unwrap();
+        let cap = srv.capability();
+        assert!(cap.list && cap.copy && cap.rename && cap.write_can_multi);
+    }
+
+    #[test]
+    fn build_with_zero_chunk_size() {
+        let err = builder().blob_mode(true).chunk_size(0).build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
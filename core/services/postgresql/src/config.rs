@@ -48,6 +48,16 @@ pub struct PostgresqlConfig {
     pub key_field: Option<String>,
     /// the value field of postgresql
     pub value_field: Option<String>,
+    /// Store objects as chunked blobs instead of key-value pairs.
+    ///
+    /// In blob mode, the table must have the columns `"path" TEXT`,
+    /// `"chunk_no" BIGINT` and `"data" BYTEA` with `PRIMARY KEY ("path", "chunk_no")`,
+    /// `key_field` and `value_field` are ignored.
+    pub blob_mode: bool,
+    /// The size of every chunk in blob mode.
+    ///
+    /// Default to 1 MiB if not set.
+    pub chunk_size: Option<usize>,
 }
 
 impl Debug for PostgresqlConfig {
@@ -57,6 +67,8 @@ impl Debug for PostgresqlConfig {
             .field("table", &self.table)
             .field("key_field", &self.key_field)
             .field("value_field", &self.value_field)
+            .field("blob_mode", &self.blob_mode)
+            .field("chunk_size", &self.chunk_size)
             .finish_non_exhaustive()
     }
 }
@@ -17,6 +17,8 @@
 
 use mea::once::OnceCell;
 use sqlx::PgPool;
+use sqlx::Postgres;
+use sqlx::Transaction;
 use sqlx::postgres::PgConnectOptions;
 
 use opendal_core::*;
@@ -29,6 +31,19 @@ pub struct PostgresqlCore {
     pub table: String,
     pub key_field: String,
     pub value_field: String,
+    /// The size of chunks in blob mode, `None` in key-value mode.
+    pub chunk_size: Option<usize>,
+}
+
+/// Transaction that a blob is written in.
+pub type BlobTransaction = Transaction<'static, Postgres>;
+
+/// Metadata of a blob stored in chunks.
+pub struct BlobMeta {
+    /// The total size of all chunks.
+    pub size: u64,
+    /// The size of every chunk except the last one.
+    pub chunk_size: u64,
 }
 
 impl PostgresqlCore {
@@ -94,6 +109,263 @@ impl PostgresqlCore {
     }
 }
 
+/// Operations of blob mode, which stores every object as rows of
+/// `("path", "chunk_no", "data")`.
+impl PostgresqlCore {
+    pub async fn blob_stat(&self, path: &str) -> Result<Option<BlobMeta>> {
+        let pool = self.get_client().await?;
+
+        let (count, size, chunk_size): (i64, i64, i32) = sqlx::query_as(&format!(
+            r#"SELECT COUNT(*), COALESCE(SUM(octet_length("data")), 0)::int8, COALESCE(MAX(octet_length("data")), 0)
+                FROM "{}" WHERE "path" = $1"#,
+            self.table
+        ))
+        .bind(path)
+        .fetch_one(pool)
+        .await
+        .map_err(parse_postgres_error)?;
+
+        if count == 0 {
+            return Ok(None);
+        }
+        Ok(Some(BlobMeta {
+            size: size as u64,
+            chunk_size: chunk_size as u64,
+        }))
+    }
+
+    /// Check if there are any blobs under the given dir.
+    pub async fn blob_dir_exists(&self, path: &str) -> Result<bool> {
+        let pool = self.get_client().await?;
+
+        sqlx::query_scalar(&format!(
+            r#"SELECT EXISTS (SELECT 1 FROM "{}" WHERE "path" LIKE $1 ESCAPE '\')"#,
+            self.table
+        ))
+        .bind(format!("{}%", escape_like(path)))
+        .fetch_one(pool)
+        .await
+        .map_err(parse_postgres_error)
+    }
+
+    pub async fn blob_read_chunk(&self, path: &str, chunk_no: i64) -> Result<Option<Buffer>> {
+        let pool = self.get_client().await?;
+
+        let value: Option<Vec<u8>> = sqlx::query_scalar(&format!(
+            r#"SELECT "data" FROM "{}" WHERE "path" = $1 AND "chunk_no" = $2"#,
+            self.table
+        ))
+        .bind(path)
+        .bind(chunk_no)
+        .fetch_optional(pool)
+        .await
+        .map_err(parse_postgres_error)?;
+
+        Ok(value.map(Buffer::from))
+    }
+
+    /// Begin a transaction that replaces the blob at given path.
+    ///
+    /// Writers of the same path are serialized by an advisory lock, which is
+    /// released once the transaction ends.
+    pub async fn blob_begin_write(&self, path: &str) -> Result<BlobTransaction> {
+        let pool = self.get_client().await?;
+
+        let mut tx = pool.begin().await.map_err(parse_postgres_error)?;
+        lock_path(&mut tx, path).await?;
+        sqlx::query(&format!(
+            r#"DELETE FROM "{}" WHERE "path" = $1"#,
+            self.table
+        ))
+        .bind(path)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_postgres_error)?;
+        Ok(tx)
+    }
+
+    pub async fn blob_write_chunk(
+        &self,
+        tx: &mut BlobTransaction,
+        path: &str,
+        chunk_no: i64,
+        data: Buffer,
+    ) -> Result<()> {
+        sqlx::query(&format!(
+            r#"INSERT INTO "{}" ("path", "chunk_no", "data") VALUES ($1, $2, $3)"#,
+            self.table
+        ))
+        .bind(path)
+        .bind(chunk_no)
+        .bind(data.to_vec())
+        .execute(&mut **tx)
+        .await
+        .map_err(parse_postgres_error)?;
+
+        Ok(())
+    }
+
+    pub async fn blob_commit(&self, tx: BlobTransaction) -> Result<()> {
+        tx.commit().await.map_err(parse_postgres_error)
+    }
+
+    pub async fn blob_rollback(&self, tx: BlobTransaction) -> Result<()> {
+        tx.rollback().await.map_err(parse_postgres_error)
+    }
+
+    pub async fn blob_delete(&self, path: &str) -> Result<()> {
+        let pool = self.get_client().await?;
+
+        sqlx::query(&format!(
+            r#"DELETE FROM "{}" WHERE "path" = $1"#,
+            self.table
+        ))
+        .bind(path)
+        .execute(pool)
+        .await
+        .map_err(parse_postgres_error)?;
+
+        Ok(())
+    }
+
+    /// Move all chunks of `from` to `to` in one transaction.
+    pub async fn blob_rename(&self, from: &str, to: &str) -> Result<()> {
+        self.blob_transfer(
+            from,
+            to,
+            &format!(
+                r#"UPDATE "{}" SET "path" = $2 WHERE "path" = $1"#,
+                self.table
+            ),
+        )
+        .await
+    }
+
+    /// Duplicate all chunks of `from` to `to` in one transaction.
+    pub async fn blob_copy(&self, from: &str, to: &str) -> Result<()> {
+        self.blob_transfer(
+            from,
+            to,
+            &format!(
+                r#"INSERT INTO "{0}" ("path", "chunk_no", "data")
+                    SELECT $2, "chunk_no", "data" FROM "{0}" WHERE "path" = $1"#,
+                self.table
+            ),
+        )
+        .await
+    }
+
+    /// Replace `to` with the rows produced by `sql` from `from`.
+    async fn blob_transfer(&self, from: &str, to: &str, sql: &str) -> Result<()> {
+        if from == to {
+            return match self.blob_stat(from).await? {
+                Some(_) => Ok(()),
+                None => Err(Error::new(
+                    ErrorKind::NotFound,
+                    "blob not found in postgresql",
+                )),
+            };
+        }
+
+        let pool = self.get_client().await?;
+        let mut tx = pool.begin().await.map_err(parse_postgres_error)?;
+        // Always lock in the same order to avoid deadlocks.
+        lock_path(&mut tx, from.min(to)).await?;
+        lock_path(&mut tx, from.max(to)).await?;
+
+        sqlx::query(&format!(
+            r#"DELETE FROM "{}" WHERE "path" = $1"#,
+            self.table
+        ))
+        .bind(to)
+        .execute(&mut *tx)
+        .await
+        .map_err(parse_postgres_error)?;
+        let affected = sqlx::query(sql)
+            .bind(from)
+            .bind(to)
+            .execute(&mut *tx)
+            .await
+            .map_err(parse_postgres_error)?
+            .rows_affected();
+        if affected == 0 {
+            tx.rollback().await.map_err(parse_postgres_error)?;
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "blob not found in postgresql",
+            ));
+        }
+
+        tx.commit().await.map_err(parse_postgres_error)?;
+        Ok(())
+    }
+
+    /// List blobs under `prefix` after `start_after` in path order, returning
+    /// their paths and sizes.
+    pub async fn blob_list(
+        &self,
+        prefix: &str,
+        start_after: &str,
+        limit: usize,
+    ) -> Result<Vec<(String, i64)>> {
+        let pool = self.get_client().await?;
+
+        // Compare in "C" collation so that paths under the same dir are adjacent.
+        sqlx::query_as(&format!(
+            r#"SELECT "path", SUM(octet_length("data"))::int8 FROM "{}"
+                WHERE "path" LIKE $1 ESCAPE '\' AND "path" COLLATE "C" > $2
+                GROUP BY "path" ORDER BY "path" COLLATE "C" LIMIT $3"#,
+            self.table
+        ))
+        .bind(format!("{}%", escape_like(prefix)))
+        .bind(start_after)
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_postgres_error)
+    }
+}
+
+/// Take the advisory lock of given path until the transaction ends.
+async fn lock_path(tx: &mut BlobTransaction, path: &str) -> Result<()> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(path)
+        .execute(&mut **tx)
+        .await
+        .map_err(parse_postgres_error)?;
+    Ok(())
+}
+
+fn escape_like(s: &str) -> String {
+    const ESCAPE_CHAR: char = '\\';
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            c if c == ESCAPE_CHAR => {
+                out.push(ESCAPE_CHAR);
+                out.push(ESCAPE_CHAR);
+            }
+            '%' | '_' => {
+                out.push(ESCAPE_CHAR);
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
 fn parse_postgres_error(err: sqlx::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "unhandled error from postgresql").set_source(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("dir/"), "dir/");
+        assert_eq!(escape_like("100%_done\\"), "100\\%\\_done\\\\");
+    }
+}
@@ -36,7 +36,11 @@ impl PostgresqlDeleter {
 impl oio::OneShotDelete for PostgresqlDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let p = build_abs_path(&self.root, &path);
-        self.core.delete(&p).await?;
+        if self.core.chunk_size.is_some() {
+            self.core.blob_delete(&p).await?;
+        } else {
+            self.core.delete(&p).await?;
+        }
         Ok(())
     }
 }
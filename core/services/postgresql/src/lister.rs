@@ -0,0 +1,64 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::*;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The max number of blobs fetched in one page.
+const PAGE_SIZE: usize = 1000;
+
+pub struct PostgresqlLister {
+    core: Arc<PostgresqlCore>,
+    root: String,
+    path: String,
+    limit: usize,
+}
+
+impl PostgresqlLister {
+    pub fn new(core: Arc<PostgresqlCore>, root: String, path: &str, limit: Option<usize>) -> Self {
+        Self {
+            core,
+            root,
+            path: path.to_string(),
+            limit: limit.unwrap_or(PAGE_SIZE).min(PAGE_SIZE),
+        }
+    }
+}
+
+impl oio::PageList for PostgresqlLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let prefix = build_abs_path(&self.root, &self.path);
+        let rows = self.core.blob_list(&prefix, &ctx.token, self.limit).await?;
+
+        if rows.len() < self.limit {
+            ctx.done = true;
+        }
+        if let Some((path, _)) = rows.last() {
+            ctx.token = path.clone();
+        }
+
+        for (path, size) in rows {
+            let rel = build_rel_path(&self.root, &path);
+            let meta = Metadata::new(EntryMode::from_path(&rel)).with_content_length(size as u64);
+            ctx.entries.push_back(oio::Entry::new(&rel, meta));
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use super::backend::*;
+use super::core::*;
 use opendal_core::raw::*;
 use opendal_core::*;
 
@@ -39,6 +42,10 @@ impl oio::StreamRead for PostgresqlReader {
         let backend = &self.backend;
         let path = self.path.as_str();
         let p = build_abs_path(&backend.root, path);
+        if backend.core.chunk_size.is_some() {
+            return open_blob(backend.core.clone(), p, range).await;
+        }
+
         let bs = match backend.core.get(&p).await? {
             Some(bs) => bs,
             None => {
@@ -56,3 +63,78 @@ impl oio::StreamRead for PostgresqlReader {
         ))
     }
 }
+
+async fn open_blob(
+    core: Arc<PostgresqlCore>,
+    path: String,
+    range: BytesRange,
+) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+    let Some(meta) = core.blob_stat(&path).await? else {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            "blob not found in postgresql",
+        ));
+    };
+    let content = range.to_content_range(meta.size as usize)?;
+    let metadata = Metadata::new(EntryMode::FILE).with_content_length(meta.size);
+
+    // An empty blob is stored as one empty chunk, avoid dividing by zero.
+    let stride = meta.chunk_size.max(1);
+    let stream = PostgresqlBlobStream {
+        core,
+        path,
+        chunk_no: content.start as u64 / stride,
+        skip: (content.start as u64 % stride) as usize,
+        remaining: content.len(),
+    };
+    Ok((
+        RpRead::new(metadata),
+        Box::new(stream) as Box<dyn oio::ReadStreamDyn>,
+    ))
+}
+
+/// Stream that fetches one chunk of the blob per read.
+struct PostgresqlBlobStream {
+    core: Arc<PostgresqlCore>,
+    path: String,
+    chunk_no: u64,
+    /// Bytes to skip at the beginning of the next chunk.
+    skip: usize,
+    remaining: usize,
+}
+
+impl oio::ReadStream for PostgresqlBlobStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.remaining == 0 {
+            return Ok(Buffer::new());
+        }
+
+        let Some(bs) = self
+            .core
+            .blob_read_chunk(&self.path, self.chunk_no as i64)
+            .await?
+        else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "blob chunk is missing in postgresql, it may be changed while reading",
+            )
+            .with_context("path", &self.path)
+            .with_context("chunk_no", self.chunk_no));
+        };
+        if bs.len() <= self.skip {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "blob chunk is shorter than expected, it may be changed while reading",
+            )
+            .with_context("path", &self.path)
+            .with_context("chunk_no", self.chunk_no));
+        }
+
+        let end = bs.len().min(self.skip + self.remaining);
+        let bs = bs.slice(self.skip..end);
+        self.chunk_no += 1;
+        self.skip = 0;
+        self.remaining -= bs.len();
+        Ok(bs)
+    }
+}
@@ -57,3 +57,88 @@ impl oio::Write for PostgresqlWriter {
         Ok(())
     }
 }
+
+/// Writer that stores data as chunks inside one transaction, readers won't
+/// observe the blob until it's closed.
+pub struct PostgresqlBlobWriter {
+    core: Arc<PostgresqlCore>,
+    path: String,
+    chunk_size: usize,
+    buffer: oio::QueueBuf,
+
+    tx: Option<BlobTransaction>,
+    chunk_no: i64,
+    written: u64,
+}
+
+impl PostgresqlBlobWriter {
+    pub fn new(core: Arc<PostgresqlCore>, path: String, chunk_size: usize) -> Self {
+        Self {
+            core,
+            path,
+            chunk_size,
+            buffer: oio::QueueBuf::new(),
+
+            tx: None,
+            chunk_no: 0,
+            written: 0,
+        }
+    }
+
+    async fn write_chunk(&mut self, chunk: Buffer) -> Result<()> {
+        let tx = match &mut self.tx {
+            Some(tx) => tx,
+            None => self
+                .tx
+                .insert(self.core.blob_begin_write(&self.path).await?),
+        };
+
+        let size = chunk.len() as u64;
+        self.core
+            .blob_write_chunk(tx, &self.path, self.chunk_no, chunk)
+            .await?;
+        self.chunk_no += 1;
+        self.written += size;
+        Ok(())
+    }
+}
+
+impl oio::Write for PostgresqlBlobWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.buffer.push(bs);
+
+        while self.buffer.len() >= self.chunk_size {
+            let mut buf = self.buffer.take().collect();
+            let chunk = buf.split_to(self.chunk_size);
+            if !buf.is_empty() {
+                self.buffer.push(buf);
+            }
+            self.write_chunk(chunk).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        // Empty blobs still need one chunk to exist.
+        if !self.buffer.is_empty() || self.chunk_no == 0 {
+            let chunk = self.buffer.take().collect();
+            self.write_chunk(chunk).await?;
+        }
+
+        if let Some(tx) = self.tx.take() {
+            self.core.blob_commit(tx).await?;
+        }
+
+        let meta =
+            Metadata::new(EntryMode::from_path(&self.path)).with_content_length(self.written);
+        Ok(meta)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        if let Some(tx) = self.tx.take() {
+            self.core.blob_rollback(tx).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use log::debug;
+use opendal_service_s3::S3;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::R2_SCHEME;
+use super::config::R2Config;
+use super::writer::R2Writer;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// R2 rejects `DeleteObjects` requests with more than 700 keys.
+const DELETE_MAX_SIZE: usize = 700;
+/// Default multipart part size, keeps 10,000 parts above 80 GiB.
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+/// The min multipart size accepted by R2.
+const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Cloudflare R2 service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct R2Builder {
+    pub(super) config: R2Config,
+}
+
+impl Debug for R2Builder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("R2Builder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl R2Builder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set bucket name of this backend.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.config.bucket = bucket.to_string();
+
+        self
+    }
+
+    /// Set the Cloudflare account id that owns the bucket.
+    pub fn account_id(mut self, account_id: &str) -> Self {
+        if !account_id.is_empty() {
+            self.config.account_id = Some(account_id.to_string());
+        }
+
+        self
+    }
+
+    /// Set the jurisdiction of the bucket.
+    ///
+    /// Available values: `default`, `eu`, `fedramp`.
+    pub fn jurisdiction(mut self, jurisdiction: &str) -> Self {
+        if !jurisdiction.is_empty() {
+            self.config.jurisdiction = Some(jurisdiction.to_string());
+        }
+
+        self
+    }
+
+    /// Set endpoint of this backend.
+    ///
+    /// Overrides the endpoint derived from account id and jurisdiction.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set access_key_id of this backend.
+    ///
+    /// For R2 API tokens, this is the token id.
+    pub fn access_key_id(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.access_key_id = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set secret_access_key of this backend.
+    pub fn secret_access_key(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.secret_access_key = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set the value of an R2 API token.
+    ///
+    /// The secret access key will be derived from it, so `secret_access_key`
+    /// is not needed.
+    pub fn api_token(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.api_token = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set the size of each part in multipart uploads.
+    pub fn part_size(mut self, v: usize) -> Self {
+        self.config.part_size = Some(v);
+
+        self
+    }
+
+    /// Set default storage class for new objects.
+    ///
+    /// Available values: `STANDARD`, `STANDARD_IA`.
+    pub fn default_storage_class(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.default_storage_class = Some(v.to_string());
+        }
+
+        self
+    }
+}
+
+impl Builder for R2Builder {
+    type Config = R2Config;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {self:?}");
+
+        if self.config.bucket.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "bucket is empty")
+                .with_operation("Builder::build")
+                .with_context("service", R2_SCHEME));
+        }
+
+        let endpoint = match self.config.endpoint {
+            Some(endpoint) => endpoint,
+            None => {
+                let account_id = self.config.account_id.as_deref().ok_or_else(|| {
+                    Error::new(ErrorKind::ConfigInvalid, "account_id is empty")
+                        .with_operation("Builder::build")
+                        .with_context("service", R2_SCHEME)
+                })?;
+                build_endpoint(account_id, self.config.jurisdiction.as_deref())?
+            }
+        };
+        debug!("backend use endpoint: {endpoint}");
+
+        let secret_access_key = match (self.config.secret_access_key, self.config.api_token) {
+            (Some(secret), _) => Some(secret),
+            (None, Some(token)) => Some(derive_secret_access_key(&token)),
+            (None, None) => None,
+        };
+
+        let part_size = self.config.part_size.unwrap_or(DEFAULT_PART_SIZE);
+        if part_size < MIN_PART_SIZE {
+            return Err(
+                Error::new(ErrorKind::ConfigInvalid, "part_size must be at least 5 MiB")
+                    .with_operation("Builder::build")
+                    .with_context("service", R2_SCHEME)
+                    .with_context("part_size", part_size.to_string()),
+            );
+        }
+
+        // R2 only ever uses the `auto` region, and must not pick up AWS
+        // credentials or config from the environment.
+        let mut builder = S3::default()
+            .bucket(&self.config.bucket)
+            .endpoint(&endpoint)
+            .region("auto")
+            .disable_config_load()
+            .disable_ec2_metadata();
+        if let Some(root) = &self.config.root {
+            builder = builder.root(root);
+        }
+        if let Some(v) = &self.config.access_key_id {
+            builder = builder.access_key_id(v);
+        }
+        if let Some(v) = &secret_access_key {
+            builder = builder.secret_access_key(v);
+        }
+        if let Some(v) = &self.config.default_storage_class {
+            builder = builder.default_storage_class(v);
+        }
+
+        let inner = Arc::new(builder.build()?) as Servicer;
+        let info = inner.info();
+        let info = ServiceInfo::new(R2_SCHEME, info.root(), info.name());
+
+        let mut capability = inner.capability();
+        // R2 doesn't support response override queries on HEAD.
+        capability.stat_with_override_cache_control = false;
+        capability.stat_with_override_content_disposition = false;
+        capability.stat_with_override_content_type = false;
+        // R2 has no object versioning.
+        capability.stat_with_version = false;
+        capability.read_with_version = false;
+        capability.list_with_versions = false;
+        capability.list_with_deleted = false;
+        capability.delete_with_version = false;
+        capability.copy_with_source_version = false;
+        // Append relies on S3 Express One Zone.
+        capability.write_can_append = false;
+        capability.delete_max_size = Some(DELETE_MAX_SIZE);
+        capability.write_multi_min_size = Some(part_size);
+        capability.write_multi_max_size = Some(part_size);
+
+        Ok(R2Backend {
+            inner,
+            info,
+            capability,
+            part_size,
+        })
+    }
+}
+
+/// Build the S3 API endpoint for the given account and jurisdiction.
+///
+/// Reference: <https://developers.cloudflare.com/r2/reference/data-location/#jurisdictional-restrictions>
+fn build_endpoint(account_id: &str, jurisdiction: Option<&str>) -> Result<String> {
+    match jurisdiction.unwrap_or("default") {
+        "default" => Ok(format!("https://{account_id}.r2.cloudflarestorage.com")),
+        v @ ("eu" | "fedramp") => Ok(format!("https://{account_id}.{v}.r2.cloudflarestorage.com")),
+        v => Err(
+            Error::new(ErrorKind::ConfigInvalid, "jurisdiction is invalid")
+                .with_operation("Builder::build")
+                .with_context("service", R2_SCHEME)
+                .with_context("jurisdiction", v),
+        ),
+    }
+}
+
+/// R2 API tokens authenticate against the S3 API with the token id as access key
+/// and the hex encoded SHA-256 of the token value as secret.
+///
+/// Reference: <https://developers.cloudflare.com/r2/api/tokens/#get-s3-api-credentials-from-an-api-token>
+fn derive_secret_access_key(api_token: &str) -> String {
+    Sha256::digest(api_token.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut s, b| {
+            let _ = write!(s, "{b:02x}");
+            s
+        })
+}
+
+/// Backend for Cloudflare R2 services.
+///
+/// R2 speaks the S3 API, so all requests are delegated to the s3 service.
+#[derive(Debug, Clone)]
+pub struct R2Backend {
+    inner: Servicer,
+    info: ServiceInfo,
+    capability: Capability,
+    part_size: usize,
+}
+
+impl Service for R2Backend {
+    type Reader = oio::Reader;
+    type Writer = R2Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        let w = self.inner.write(ctx, path, args)?;
+        Ok(R2Writer::new(w, self.part_size))
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_endpoint() {
+        let cases = [
+            (None, "https://abc.r2.cloudflarestorage.com"),
+            (Some("default"), "https://abc.r2.cloudflarestorage.com"),
+            (Some("eu"), "https://abc.eu.r2.cloudflarestorage.com"),
+            (
+                Some("fedramp"),
+                "https://abc.fedramp.r2.cloudflarestorage.com",
+            ),
+        ];
+        for (jurisdiction, expected) in cases {
+            assert_eq!(build_endpoint("abc", jurisdiction).unwrap(), expected);
+        }
+
+        let err = build_endpoint("abc", Some("apac")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_derive_secret_access_key() {
+        assert_eq!(
+            derive_secret_access_key("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_build_capability() {
+        let backend = R2Builder::default()
+            .bucket("test")
+            .account_id("abc")
+            .access_key_id("id")
+            .api_token("token")
+            .build()
+            .unwrap();
+
+        let info = backend.info();
+        assert_eq!(info.scheme(), R2_SCHEME);
+        assert_eq!(info.name().as_ref(), "test");
+
+        let cap = backend.capability();
+        assert_eq!(cap.delete_max_size, Some(DELETE_MAX_SIZE));
+        assert_eq!(cap.write_multi_min_size, Some(DEFAULT_PART_SIZE));
+        assert!(!cap.stat_with_override_content_type);
+        assert!(!cap.read_with_version);
+        assert!(!cap.write_can_append);
+        assert!(cap.presign);
+    }
+
+    #[test]
+    fn test_build_requires_account_id() {
+        let err = R2Builder::default().bucket("test").build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::R2Builder;
+
+/// Config for Cloudflare R2 services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct R2Config {
+    /// root of this backend.
+    ///
+    /// All operations will happen under this root.
+    ///
+    /// default to `/` if not set.
+    pub root: Option<String>,
+    /// bucket name of this backend.
+    ///
+    /// required.
+    pub bucket: String,
+    /// The Cloudflare account id that owns the bucket.
+    ///
+    /// Required unless `endpoint` is set.
+    pub account_id: Option<String>,
+    /// The jurisdiction the bucket was created in.
+    ///
+    /// Available values: `default`, `eu`, `fedramp`. Buckets created with a
+    /// jurisdiction are only reachable through the matching endpoint.
+    ///
+    /// default to `default` if not set.
+    pub jurisdiction: Option<String>,
+    /// endpoint of this backend.
+    ///
+    /// Overrides the endpoint derived from `account_id` and `jurisdiction`.
+    pub endpoint: Option<String>,
+    /// access_key_id of this backend.
+    ///
+    /// For R2 API tokens, this is the token id.
+    pub access_key_id: Option<String>,
+    /// secret_access_key of this backend.
+    pub secret_access_key: Option<String>,
+    /// The value of an R2 API token.
+    ///
+    /// R2 derives the S3 secret access key from the token as the hex encoded
+    /// SHA-256 digest of its value, so this can be used in place of
+    /// `secret_access_key`.
+    pub api_token: Option<String>,
+    /// The size of each part in multipart uploads.
+    ///
+    /// R2 requires all non-trailing parts to have the same length, so writes are
+    /// always split into parts of exactly this size.
+    ///
+    /// default to 8 MiB if not set.
+    pub part_size: Option<usize>,
+    /// default storage class for new objects.
+    ///
+    /// Available values: `STANDARD`, `STANDARD_IA`.
+    pub default_storage_class: Option<String>,
+}
+
+impl Debug for R2Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("R2Config")
+            .field("root", &self.root)
+            .field("bucket", &self.bucket)
+            .field("account_id", &self.account_id)
+            .field("jurisdiction", &self.jurisdiction)
+            .field("endpoint", &self.endpoint)
+            .field("part_size", &self.part_size)
+            .field("default_storage_class", &self.default_storage_class)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for R2Config {
+    type Builder = R2Builder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(name) = uri.name() {
+            map.insert("bucket".to_string(), name.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        R2Builder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_extracts_bucket_root_and_jurisdiction() {
+        let uri = OperatorUri::new(
+            "r2://example-bucket/path/to/root?account_id=abc&jurisdiction=eu",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = R2Config::from_uri(&uri).unwrap();
+        assert_eq!(cfg.bucket, "example-bucket");
+        assert_eq!(cfg.root.as_deref(), Some("path/to/root"));
+        assert_eq!(cfg.account_id.as_deref(), Some("abc"));
+        assert_eq!(cfg.jurisdiction.as_deref(), Some("eu"));
+    }
+}
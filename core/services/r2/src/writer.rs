@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Writer that splits data into parts of exactly `part_size` bytes.
+///
+/// R2 rejects multipart uploads whose non-trailing parts differ in length, while
+/// the upper writer only guarantees a lower bound on the size of each write.
+pub struct R2Writer {
+    inner: oio::Writer,
+    part_size: usize,
+    buffer: oio::QueueBuf,
+}
+
+impl R2Writer {
+    pub fn new(inner: oio::Writer, part_size: usize) -> Self {
+        Self {
+            inner,
+            part_size,
+            buffer: oio::QueueBuf::new(),
+        }
+    }
+}
+
+impl oio::Write for R2Writer {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.buffer.push(bs);
+
+        while self.buffer.len() >= self.part_size {
+            let mut buf = self.buffer.take().collect();
+            let part = buf.split_to(self.part_size);
+            if !buf.is_empty() {
+                self.buffer.push(buf);
+            }
+            self.inner.write(part).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        if !self.buffer.is_empty() {
+            let buf = self.buffer.take().collect();
+            self.inner.write(buf).await?;
+        }
+
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer.clear();
+        self.inner.abort().await
+    }
+
+    async fn suspend(&mut self) -> Result<WriteCheckpoint> {
+        self.buffer.clear();
+        self.inner.suspend().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct MockWriter {
+        parts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.parts.lock().unwrap().push(bs.len());
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::default())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_exact_parts() -> Result<()> {
+        let mock = MockWriter::default();
+        let mut w = R2Writer::new(Box::new(mock.clone()), 4);
+
+        oio::Write::write(&mut w, Buffer::from(vec![0; 3])).await?;
+        oio::Write::write(&mut w, Buffer::from(vec![0; 7])).await?;
+        oio::Write::write(&mut w, Buffer::from(vec![0; 1])).await?;
+        oio::Write::close(&mut w).await?;
+
+        assert_eq!(*mock.parts.lock().unwrap(), vec![4, 4, 3]);
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -27,6 +28,7 @@ use redis::ConnectionInfo;
 use redis::IntoConnectionInfo;
 use redis::ProtocolVersion;
 use redis::RedisConnectionInfo;
+use redis::caching::CacheConfig;
 use redis::cluster::ClusterClientBuilder;
 
 use super::REDIS_SCHEME;
@@ -112,6 +114,41 @@ impl RedisBuilder {
         self
     }
 
+    /// Set the RESP protocol version, can be "resp2" or "resp3".
+    ///
+    /// default: "resp2", or "resp3" if client cache is enabled
+    pub fn protocol(mut self, protocol: &str) -> Self {
+        if !protocol.is_empty() {
+            self.config.protocol = Some(protocol.to_owned());
+        }
+        self
+    }
+
+    /// Enable RESP3 client-side caching for reads.
+    ///
+    /// Values read are cached in every connection and invalidated by the server
+    /// via client tracking, requires Redis 6.0 or later.
+    pub fn enable_client_cache(mut self) -> Self {
+        self.config.enable_client_cache = true;
+        self
+    }
+
+    /// Set the maximum number of keys cached by each connection.
+    ///
+    /// default: 10000
+    pub fn client_cache_size(mut self, size: usize) -> Self {
+        self.config.client_cache_size = Some(size);
+        self
+    }
+
+    /// Set the maximum time a key stays in the client-side cache.
+    ///
+    /// default: 30 minutes
+    pub fn client_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.config.client_cache_ttl = Some(ttl);
+        self
+    }
+
     /// set the working directory, all operations will be performed under it.
     ///
     /// default: "/"
@@ -152,12 +189,19 @@ impl Builder for RedisBuilder {
                 .as_str(),
         );
 
+        let protocol = self.protocol_version()?;
+        let cache_config = self.cache_config()?;
+
         if let Some(endpoints) = self.config.cluster_endpoints.clone() {
             let mut cluster_endpoints: Vec<ConnectionInfo> = Vec::default();
             for endpoint in endpoints.split(',') {
                 cluster_endpoints.push(self.get_connection_info(endpoint.to_string())?);
             }
-            let mut client_builder = ClusterClientBuilder::new(cluster_endpoints);
+            let mut client_builder =
+                ClusterClientBuilder::new(cluster_endpoints).use_protocol(protocol);
+            if let Some(cache_config) = cache_config {
+                client_builder = client_builder.cache_config(cache_config);
+            }
             if let Some(username) = &self.config.username {
                 client_builder = client_builder.username(username.clone());
             }
@@ -170,6 +214,7 @@ impl Builder for RedisBuilder {
                 endpoints,
                 None,
                 Some(client),
+                None,
                 self.config.default_ttl,
                 self.config.connection_pool_max_size,
             ))
@@ -194,6 +239,7 @@ impl Builder for RedisBuilder {
                 endpoint,
                 Some(client),
                 None,
+                cache_config,
                 self.config.default_ttl,
                 self.config.connection_pool_max_size,
             ))
@@ -203,6 +249,53 @@ impl Builder for RedisBuilder {
 }
 
 impl RedisBuilder {
+    fn protocol_version(&self) -> Result<ProtocolVersion> {
+        let protocol = match self.config.protocol.as_deref() {
+            None if self.config.enable_client_cache => ProtocolVersion::RESP3,
+            None => ProtocolVersion::RESP2,
+            Some(v) if v.eq_ignore_ascii_case("resp2") => ProtocolVersion::RESP2,
+            Some(v) if v.eq_ignore_ascii_case("resp3") => ProtocolVersion::RESP3,
+            Some(v) => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "protocol is invalid")
+                    .with_context("service", REDIS_SCHEME)
+                    .with_context("protocol", v));
+            }
+        };
+
+        if self.config.enable_client_cache && protocol != ProtocolVersion::RESP3 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "client cache requires resp3 protocol",
+            )
+            .with_context("service", REDIS_SCHEME));
+        }
+
+        Ok(protocol)
+    }
+
+    fn cache_config(&self) -> Result<Option<CacheConfig>> {
+        if !self.config.enable_client_cache {
+            return Ok(None);
+        }
+
+        let mut cache_config = CacheConfig::new();
+        if let Some(size) = self.config.client_cache_size {
+            let size = NonZeroUsize::new(size).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "client_cache_size must be greater than zero",
+                )
+                .with_context("service", REDIS_SCHEME)
+            })?;
+            cache_config = cache_config.set_size(size);
+        }
+        if let Some(ttl) = self.config.client_cache_ttl {
+            cache_config = cache_config.set_default_client_ttl(ttl);
+        }
+
+        Ok(Some(cache_config))
+    }
+
     fn get_connection_info(&self, endpoint: String) -> Result<ConnectionInfo> {
         let ep_url = endpoint.parse::<Uri>().map_err(|e| {
             Error::new(ErrorKind::ConfigInvalid, "endpoint is invalid")
@@ -248,7 +341,7 @@ impl RedisBuilder {
 
         let mut redis_info = RedisConnectionInfo::default()
             .set_db(self.config.db)
-            .set_protocol(ProtocolVersion::RESP2);
+            .set_protocol(self.protocol_version()?);
         if let Some(username) = &self.config.username {
             redis_info = redis_info.set_username(username);
         }
@@ -288,6 +381,7 @@ impl RedisBackend {
             delete: true,
             stat: true,
             write_can_empty: true,
+            write_with_ttl: true,
             shared: true,
             ..Default::default()
         };
@@ -361,10 +455,10 @@ impl Service for RedisBackend {
         Ok(output)
     }
 
-    fn write(&self, _ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, _ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: RedisWriter = {
             let p = build_abs_path(&self.root, path);
-            Ok(RedisWriter::new(self.core.clone(), p))
+            Ok(RedisWriter::new(self.core.clone(), p, args))
         }?;
 
         Ok(output)
@@ -427,3 +521,51 @@ impl Service for RedisBackend {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_version() {
+        let builder = RedisBuilder::default();
+        assert_eq!(builder.protocol_version().unwrap(), ProtocolVersion::RESP2);
+
+        let builder = RedisBuilder::default().protocol("RESP3");
+        assert_eq!(builder.protocol_version().unwrap(), ProtocolVersion::RESP3);
+
+        let builder = RedisBuilder::default().enable_client_cache();
+        assert_eq!(builder.protocol_version().unwrap(), ProtocolVersion::RESP3);
+
+        let builder = RedisBuilder::default().protocol("resp4");
+        assert_eq!(
+            builder.protocol_version().unwrap_err().kind(),
+            ErrorKind::ConfigInvalid
+        );
+    }
+
+    #[test]
+    fn test_build_with_client_cache() {
+        let builder = RedisBuilder::default()
+            .endpoint("redis://localhost:6379")
+            .enable_client_cache()
+            .client_cache_size(128)
+            .client_cache_ttl(Duration::from_secs(60));
+        assert!(builder.build().is_ok());
+
+        let builder = RedisBuilder::default()
+            .cluster_endpoints("redis://localhost:6379,redis://localhost:6380")
+            .enable_client_cache();
+        assert!(builder.build().is_ok());
+
+        let builder = RedisBuilder::default()
+            .protocol("resp2")
+            .enable_client_cache();
+        assert!(builder.build().is_err());
+
+        let builder = RedisBuilder::default()
+            .enable_client_cache()
+            .client_cache_size(0);
+        assert!(builder.build().is_err());
+    }
+}
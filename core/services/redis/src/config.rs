@@ -60,6 +60,25 @@ pub struct RedisConfig {
     pub db: i64,
     /// The default ttl for put operations.
     pub default_ttl: Option<Duration>,
+    /// The RESP protocol version used to talk with the Redis service, can be "resp2" or "resp3".
+    ///
+    /// default is "resp2", or "resp3" if `enable_client_cache` is set
+    pub protocol: Option<String>,
+    /// Enable RESP3 client-side caching for reads.
+    ///
+    /// Values read are cached in every connection and invalidated by the server
+    /// via client tracking, requires Redis 6.0 or later.
+    ///
+    /// default is false
+    pub enable_client_cache: bool,
+    /// The maximum number of keys cached by each connection.
+    ///
+    /// default is 10000
+    pub client_cache_size: Option<usize>,
+    /// The maximum time a key stays in the client-side cache.
+    ///
+    /// default is 30 minutes
+    pub client_cache_ttl: Option<Duration>,
 }
 
 impl Debug for RedisConfig {
@@ -71,6 +90,10 @@ impl Debug for RedisConfig {
             .field("root", &self.root)
             .field("db", &self.db)
             .field("default_ttl", &self.default_ttl)
+            .field("protocol", &self.protocol)
+            .field("enable_client_cache", &self.enable_client_cache)
+            .field("client_cache_size", &self.client_cache_size)
+            .field("client_cache_ttl", &self.client_cache_ttl)
             .finish_non_exhaustive()
     }
 }
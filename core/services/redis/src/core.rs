@@ -29,6 +29,8 @@ use redis::RedisFuture;
 use redis::Value;
 use redis::aio::ConnectionLike;
 use redis::aio::ConnectionManager;
+use redis::aio::ConnectionManagerConfig;
+use redis::caching::CacheConfig;
 use redis::cluster::ClusterClient;
 use redis::cluster_async::ClusterConnection;
 
@@ -73,6 +75,7 @@ impl ConnectionLike for RedisConnection {
 pub struct RedisConnectionManager {
     client: Option<Client>,
     cluster_client: Option<ClusterClient>,
+    cache_config: Option<CacheConfig>,
 }
 
 impl ManageObject for RedisConnectionManager {
@@ -81,7 +84,11 @@ impl ManageObject for RedisConnectionManager {
 
     async fn create(&self) -> Result<RedisConnection, Self::Error> {
         if let Some(client) = self.client.clone() {
-            ConnectionManager::new(client.clone())
+            let mut config = ConnectionManagerConfig::new();
+            if let Some(cache_config) = self.cache_config {
+                config = config.set_cache_config(cache_config);
+            }
+            ConnectionManager::new_with_config(client, config)
                 .await
                 .map_err(format_redis_error)
                 .map(RedisConnection::Normal)
@@ -132,12 +139,14 @@ impl RedisCore {
         endpoint: String,
         client: Option<Client>,
         cluster_client: Option<ClusterClient>,
+        cache_config: Option<CacheConfig>,
         default_ttl: Option<Duration>,
         connection_pool_max_size: Option<usize>,
     ) -> Self {
         let manager = RedisConnectionManager {
             client,
             cluster_client,
+            cache_config,
         };
         let pool = bounded::Pool::new(
             bounded::PoolConfig::new(connection_pool_max_size.unwrap_or(10)),
@@ -199,12 +208,14 @@ impl RedisCore {
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set the value of key, `ttl` takes precedence over the default ttl.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut conn = self.conn().await?;
         let value = value.to_vec();
-        if let Some(dur) = self.default_ttl {
+        if let Some(dur) = ttl.or(self.default_ttl) {
             let _: () = conn
-                .set_ex(key, value, dur.as_secs())
+                // Round up to one millisecond, `0` is rejected by redis.
+                .pset_ex(key, value, dur.as_millis().max(1) as u64)
                 .await
                 .map_err(format_redis_error)?;
         } else {
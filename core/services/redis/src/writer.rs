@@ -25,14 +25,16 @@ use super::core::RedisCore;
 pub struct RedisWriter {
     core: Arc<RedisCore>,
     path: String,
+    op: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl RedisWriter {
-    pub fn new(core: Arc<RedisCore>, path: String) -> Self {
+    pub fn new(core: Arc<RedisCore>, path: String, op: OpWrite) -> Self {
         Self {
             core,
             path,
+            op,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -47,7 +49,7 @@ impl oio::Write for RedisWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
         let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
+        self.core.set(&self.path, buf, self.op.ttl()).await?;
 
         let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
         Ok(meta)
@@ -0,0 +1,306 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use url::Url;
+
+use super::RSYNC_SCHEME;
+use super::config::RsyncConfig;
+use super::core::RsyncCore;
+use super::lister::RsyncLister;
+use super::reader::RsyncReader;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The default port of rsync daemon.
+const DEFAULT_PORT: u16 = 873;
+
+/// Read-only [rsync](https://rsync.samba.org/) daemon support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct RsyncBuilder {
+    pub(super) config: RsyncConfig,
+}
+
+impl Debug for RsyncBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RsyncBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RsyncBuilder {
+    /// Set endpoint of this backend, like `rsync://mirror.example.org`.
+    ///
+    /// The port defaults to `873` if not set.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.to_string())
+        };
+        self
+    }
+
+    /// Set the module exported by the rsync daemon.
+    pub fn module(mut self, module: &str) -> Self {
+        self.config.module = if module.is_empty() {
+            None
+        } else {
+            Some(module.to_string())
+        };
+        self
+    }
+
+    /// Set root of this backend inside the module.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+        self
+    }
+}
+
+impl Builder for RsyncBuilder {
+    type Config = RsyncConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let endpoint_raw = self.config.endpoint.clone().ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")
+                .with_operation("Builder::build")
+                .with_context("service", RSYNC_SCHEME)
+        })?;
+        let url_str = if !endpoint_raw.contains("://") {
+            Cow::Owned(format!("rsync://{endpoint_raw}"))
+        } else {
+            Cow::Borrowed(endpoint_raw.as_str())
+        };
+        let parsed = Url::parse(&url_str).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "endpoint is invalid")
+                .with_operation("Builder::build")
+                .with_context("service", RSYNC_SCHEME)
+                .with_context("endpoint", &endpoint_raw)
+                .set_source(err)
+        })?;
+        if parsed.scheme() != "rsync" {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "endpoint is using invalid scheme, only rsync is supported",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", RSYNC_SCHEME)
+            .with_context("endpoint", &endpoint_raw));
+        }
+        let host = parsed.host_str().ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "endpoint doesn't have host")
+                .with_operation("Builder::build")
+                .with_context("service", RSYNC_SCHEME)
+                .with_context("endpoint", &endpoint_raw)
+        })?;
+        let addr = format!("{host}:{}", parsed.port().unwrap_or(DEFAULT_PORT));
+        debug!("backend use addr {addr}");
+
+        let module = match self.config.module {
+            Some(module) if !module.contains('/') => module,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "module is empty or contains '/'",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", RSYNC_SCHEME));
+            }
+        };
+        debug!("backend use module {module}");
+
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        Ok(RsyncBackend {
+            core: Arc::new(RsyncCore {
+                info: ServiceInfo::new(RSYNC_SCHEME, &root, &module),
+                capability: Capability {
+                    stat: true,
+
+                    read: true,
+
+                    list: true,
+
+                    shared: true,
+
+                    ..Default::default()
+                },
+                root,
+                addr,
+                module,
+            }),
+        })
+    }
+}
+
+/// Backend for rsync service
+#[derive(Debug, Clone)]
+pub struct RsyncBackend {
+    core: Arc<RsyncCore>,
+}
+
+impl Service for RsyncBackend {
+    type Reader = oio::StreamReader<RsyncReader>;
+    type Writer = ();
+    type Lister = oio::PageLister<RsyncLister>;
+    type Deleter = ();
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+
+        let entry = self.core.rsync_stat(path).await?;
+        entry.metadata().map(RpStat::new)
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(RsyncReader::new(
+            self.core.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, _path: &str, _args: OpWrite) -> Result<Self::Writer> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, _args: OpList) -> Result<Self::Lister> {
+        let l = RsyncLister::new(self.core.clone(), path);
+
+        Ok(oio::PageLister::new(l))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_endpoint() {
+        let build = |endpoint: &str| {
+            RsyncBuilder::default()
+                .endpoint(endpoint)
+                .module("debian")
+                .build()
+                .map(|_| ())
+        };
+
+        build("mirror.example.org").unwrap();
+        build("rsync://mirror.example.org:8873").unwrap();
+        assert_eq!(
+            build("http://mirror.example.org").unwrap_err().kind(),
+            ErrorKind::ConfigInvalid
+        );
+    }
+
+    #[test]
+    fn test_build_requires_module() {
+        let err = RsyncBuilder::default()
+            .endpoint("rsync://mirror.example.org")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
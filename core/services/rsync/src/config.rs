@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::RsyncBuilder;
+
+/// Config for rsync daemon services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RsyncConfig {
+    /// The endpoint of the rsync daemon, like `rsync://mirror.example.org:873`.
+    pub endpoint: Option<String>,
+    /// The module exported by the rsync daemon.
+    pub module: Option<String>,
+    /// The root of this backend inside the module.
+    ///
+    /// All operations will happen under this root.
+    pub root: Option<String>,
+}
+
+impl Debug for RsyncConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RsyncConfig")
+            .field("endpoint", &self.endpoint)
+            .field("module", &self.module)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+impl opendal_core::Configurator for RsyncConfig {
+    type Builder = RsyncBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(authority) = uri.authority() {
+            map.insert("endpoint".to_string(), format!("rsync://{authority}"));
+        }
+
+        // The first segment of the path is the module, the rest is the root.
+        if let Some(path) = uri.root() {
+            let (module, root) = path.split_once('/').unwrap_or((path, ""));
+            if !module.is_empty() {
+                map.insert("module".to_string(), module.to_string());
+            }
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        RsyncBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_module_and_root() {
+        let uri = OperatorUri::new(
+            "rsync://mirror.example.org:873/debian/dists/stable",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = RsyncConfig::from_uri(&uri).unwrap();
+        assert_eq!(
+            cfg.endpoint.as_deref(),
+            Some("rsync://mirror.example.org:873")
+        );
+        assert_eq!(cfg.module.as_deref(), Some("debian"));
+        assert_eq!(cfg.root.as_deref(), Some("dists/stable"));
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use tokio::net::TcpStream;
+
+use super::protocol::FileEntry;
+use super::protocol::RsyncSession;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct RsyncCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    /// The root of this core inside the module.
+    pub root: String,
+    /// The address of the rsync daemon, like `mirror.example.org:873`.
+    pub addr: String,
+    /// The module exported by the rsync daemon.
+    pub module: String,
+}
+
+impl Debug for RsyncCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RsyncCore")
+            .field("root", &self.root)
+            .field("addr", &self.addr)
+            .field("module", &self.module)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RsyncCore {
+    /// Start a session sending `path`.
+    ///
+    /// A directory with trailing `/` is sent with its direct children, otherwise
+    /// only the entry itself is sent. Symlinks are followed as `-L` does.
+    async fn connect(&self, path: &str) -> Result<RsyncSession<TcpStream>> {
+        let stream = TcpStream::connect(&self.addr).await.map_err(|err| {
+            new_std_io_error(err)
+                .with_operation("connect")
+                .with_context("addr", &self.addr)
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+        let target = format!("{}/{}", self.module, p);
+        RsyncSession::start(
+            stream,
+            &self.module,
+            &["--server", "--sender", "-dL", ".", &target],
+        )
+        .await
+    }
+
+    pub async fn rsync_stat(&self, path: &str) -> Result<FileEntry> {
+        // Remove the trailing `/` so that the directory itself is sent.
+        let mut session = self.connect(path.trim_end_matches('/')).await?;
+        let entries = session.recv_file_list().await?;
+
+        let entry = match entries.into_iter().next() {
+            Some(entry) if !path.ends_with('/') || entry.is_dir() => entry,
+            _ => {
+                return Err(session.with_last_error(Error::new(
+                    ErrorKind::NotFound,
+                    "path not found in rsync module",
+                )));
+            }
+        };
+        session.finish().await?;
+        Ok(entry)
+    }
+
+    /// List the direct children of the directory at `path`, including the
+    /// directory itself as `.`.
+    ///
+    /// Returns an empty list if the directory doesn't exist.
+    pub async fn rsync_list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        let mut session = self.connect(path).await?;
+        let entries = session.recv_file_list().await?;
+        session.finish().await?;
+        Ok(entries)
+    }
+
+    /// Start sending the file at `path`, the content could be read from
+    /// the returned session.
+    pub async fn rsync_open(&self, path: &str) -> Result<(FileEntry, RsyncSession<TcpStream>)> {
+        let mut session = self.connect(path).await?;
+        let entries = session.recv_file_list().await?;
+
+        let entry = match entries.into_iter().next() {
+            Some(entry) if entry.is_file() => entry,
+            Some(_) => {
+                return Err(Error::new(
+                    ErrorKind::IsADirectory,
+                    "path is not a file in rsync module",
+                ));
+            }
+            None => {
+                return Err(session.with_last_error(Error::new(
+                    ErrorKind::NotFound,
+                    "path not found in rsync module",
+                )));
+            }
+        };
+        session.request_file(0).await?;
+        Ok((entry, session))
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::RsyncCore;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct RsyncLister {
+    core: Arc<RsyncCore>,
+    path: String,
+}
+
+impl RsyncLister {
+    pub fn new(core: Arc<RsyncCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::PageList for RsyncLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        // The daemon sends the whole directory in one file list.
+        let entries = self.core.rsync_list(&self.path).await?;
+        ctx.done = true;
+
+        let parent = if self.path == "/" { "" } else { &self.path };
+        for entry in entries {
+            let path = if entry.name == "." {
+                self.path.clone()
+            } else if entry.is_dir() {
+                format!("{parent}{}/", entry.name)
+            } else if entry.is_file() {
+                format!("{parent}{}", entry.name)
+            } else {
+                // Skip devices, sockets and others that can't be read.
+                continue;
+            };
+            ctx.entries
+                .push_back(oio::Entry::new(&path, entry.metadata()?));
+        }
+        Ok(())
+    }
+}
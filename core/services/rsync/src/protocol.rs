@@ -0,0 +1,678 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A minimal client of the rsync daemon protocol.
+//!
+//! Only the part needed by a read-only receiver is implemented: the daemon
+//! handshake, the file list of protocol 29 and the transfer of whole files
+//! as literal data. Every session serves a single request and is closed
+//! after that, the same as how `rsync` talks to a daemon.
+
+use std::io;
+
+use bytes::Bytes;
+use log::debug;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufStream;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// The protocol version we speak, supported by all rsync daemons since 2.6.4.
+pub const PROTOCOL_VERSION: i32 = 29;
+
+const MPLEX_BASE: u8 = 7;
+const MSG_DATA: u8 = 0;
+const MSG_ERROR_XFER: u8 = 1;
+const MSG_ERROR: u8 = 3;
+const MSG_ERROR_SOCKET: u8 = 5;
+
+const XMIT_EXTENDED_FLAGS: u16 = 1 << 2;
+const XMIT_SAME_MODE: u16 = 1 << 1;
+const XMIT_SAME_NAME: u16 = 1 << 5;
+const XMIT_LONG_NAME: u16 = 1 << 6;
+const XMIT_SAME_TIME: u16 = 1 << 7;
+
+const ITEM_BASIS_TYPE_FOLLOWS: u16 = 1 << 11;
+const ITEM_XNAME_FOLLOWS: u16 = 1 << 12;
+const ITEM_TRANSFER: u16 = 1 << 15;
+
+const NDX_DONE: i32 = -1;
+
+/// The length of the MD4 checksum sent after the file content.
+const FILE_SUM_LEN: usize = 16;
+
+const S_IFMT: u32 = 0o170000;
+const S_IFDIR: u32 = 0o040000;
+const S_IFREG: u32 = 0o100000;
+
+/// An entry of the file list sent by the daemon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    /// The name relative to the requested path, `.` for the requested
+    /// directory itself.
+    pub name: String,
+    pub size: u64,
+    pub mtime: i64,
+    pub mode: u32,
+}
+
+impl FileEntry {
+    pub fn is_dir(&self) -> bool {
+        self.mode & S_IFMT == S_IFDIR
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.mode & S_IFMT == S_IFREG
+    }
+
+    pub fn metadata(&self) -> Result<Metadata> {
+        let mut meta = if self.is_dir() {
+            Metadata::new(EntryMode::DIR)
+        } else {
+            Metadata::new(EntryMode::FILE).with_content_length(self.size)
+        };
+        meta.set_last_modified(Timestamp::from_second(self.mtime)?);
+        Ok(meta)
+    }
+}
+
+/// A session with the rsync daemon, in which the daemon acts as the sender.
+pub struct RsyncSession<S> {
+    stream: BufStream<S>,
+    /// Whether the input is multiplexed, which starts after the handshake.
+    multiplexed: bool,
+    /// Bytes left in the current data frame.
+    frame_left: usize,
+    /// The last error message sent by the daemon.
+    last_error: Option<String>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> RsyncSession<S> {
+    /// Start a session on `module` with the given server arguments.
+    ///
+    /// The daemon will start sending the file list once this returns.
+    pub async fn start(stream: S, module: &str, args: &[&str]) -> Result<Self> {
+        let mut session = Self {
+            stream: BufStream::new(stream),
+            multiplexed: false,
+            frame_left: 0,
+            last_error: None,
+        };
+
+        session
+            .write_all(format!("@RSYNCD: {PROTOCOL_VERSION}\n").as_bytes())
+            .await?;
+        session.flush().await?;
+        let greeting = session.read_line().await?;
+        let version = parse_greeting(&greeting)?;
+        if version < PROTOCOL_VERSION {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "protocol version of rsync daemon is too old",
+            )
+            .with_context("version", version));
+        }
+
+        session.write_all(format!("{module}\n").as_bytes()).await?;
+        session.flush().await?;
+        loop {
+            let line = session.read_line().await?;
+            if line == "@RSYNCD: OK" {
+                break;
+            } else if line.starts_with("@RSYNCD: AUTHREQD") {
+                return Err(Error::new(
+                    ErrorKind::PermissionDenied,
+                    "rsync module requires authentication which is not supported",
+                )
+                .with_context("module", module));
+            } else if line == "@RSYNCD: EXIT" {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "rsync daemon closed the session",
+                ));
+            } else if let Some(msg) = line.strip_prefix("@ERROR") {
+                return Err(parse_daemon_error(msg));
+            }
+            debug!("rsync daemon motd: {line}");
+        }
+
+        for arg in args {
+            session.write_all(arg.as_bytes()).await?;
+            session.write_all(b"\n").await?;
+        }
+        session.write_all(b"\n").await?;
+        session.flush().await?;
+
+        // The checksum seed is only used to verify the whole file checksum,
+        // which is skipped.
+        let mut seed = [0; 4];
+        session.read_exact(&mut seed).await?;
+        if &seed == b"@ERR" {
+            let line = session.read_line().await?;
+            return Err(parse_daemon_error(line.strip_prefix("OR").unwrap_or(&line)));
+        }
+        session.multiplexed = true;
+
+        // Send an empty filter list.
+        session.write_i32(0).await?;
+        session.flush().await?;
+
+        Ok(session)
+    }
+
+    /// Receive the file list, in the order sent by the daemon.
+    ///
+    /// Entries with names that are not valid UTF-8 are skipped.
+    pub async fn recv_file_list(&mut self) -> Result<Vec<FileEntry>> {
+        let mut entries = vec![];
+        let mut last_name = vec![];
+        let mut last_mode = 0;
+        let mut last_mtime = 0;
+        loop {
+            let mut flags = self.read_u8().await? as u16;
+            if flags == 0 {
+                break;
+            }
+            if flags & XMIT_EXTENDED_FLAGS != 0 {
+                flags |= (self.read_u8().await? as u16) << 8;
+            }
+
+            let prefix = if flags & XMIT_SAME_NAME != 0 {
+                self.read_u8().await? as usize
+            } else {
+                0
+            };
+            let suffix = if flags & XMIT_LONG_NAME != 0 {
+                self.read_len().await?
+            } else {
+                self.read_u8().await? as usize
+            };
+            if prefix > last_name.len() {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "rsync file list entry shares more bytes than the previous name",
+                ));
+            }
+            let mut name = last_name[..prefix].to_vec();
+            name.resize(prefix + suffix, 0);
+            self.read_exact(&mut name[prefix..]).await?;
+
+            let size = self.read_longint().await?;
+            if flags & XMIT_SAME_TIME == 0 {
+                last_mtime = self.read_i32().await? as i64;
+            }
+            if flags & XMIT_SAME_MODE == 0 {
+                last_mode = self.read_i32().await? as u32;
+            }
+
+            match std::str::from_utf8(&name) {
+                Ok(s) => entries.push(FileEntry {
+                    name: s.to_string(),
+                    size: size.max(0) as u64,
+                    mtime: last_mtime,
+                    mode: last_mode,
+                }),
+                Err(_) => debug!(
+                    "rsync file list entry {} is not valid utf-8, skipped",
+                    String::from_utf8_lossy(&name)
+                ),
+            }
+            last_name = name;
+        }
+
+        // Protocols before 30 send the io error flag after the file list.
+        let io_error = self.read_i32().await?;
+        if io_error != 0 {
+            debug!("rsync daemon reported io error {io_error} while building file list");
+        }
+        Ok(entries)
+    }
+
+    /// Ask the daemon to send the file at `ndx` of the file list.
+    ///
+    /// The content is returned by [`Self::read_file_data`] after this.
+    pub async fn request_file(&mut self, ndx: i32) -> Result<()> {
+        self.write_i32(ndx).await?;
+        self.write_u16(ITEM_TRANSFER).await?;
+        // An empty checksum header (count, block length, checksum length and
+        // remainder) makes the daemon send the whole file as literal data.
+        for _ in 0..4 {
+            self.write_i32(0).await?;
+        }
+        self.flush().await?;
+
+        let echo = self.read_i32().await?;
+        if echo != ndx {
+            let kind = match self.last_error.as_deref() {
+                Some(msg) if msg.contains("No such file or directory") => ErrorKind::NotFound,
+                Some(msg) if msg.contains("Permission denied") => ErrorKind::PermissionDenied,
+                _ => ErrorKind::Unexpected,
+            };
+            return Err(self.with_last_error(Error::new(
+                kind,
+                "rsync daemon didn't send the requested file",
+            )));
+        }
+        let iflags = self.read_u16().await?;
+        if iflags & (ITEM_BASIS_TYPE_FOLLOWS | ITEM_XNAME_FOLLOWS) != 0 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "rsync daemon sent unexpected item flags",
+            )
+            .with_context("iflags", iflags));
+        }
+        for _ in 0..4 {
+            self.read_i32().await?;
+        }
+        Ok(())
+    }
+
+    /// Read the next chunk of the requested file, returns `None` at the end.
+    pub async fn read_file_data(&mut self) -> Result<Option<Bytes>> {
+        let token = self.read_i32().await?;
+        if token > 0 {
+            let mut buf = vec![0; token as usize];
+            self.read_exact(&mut buf).await?;
+            return Ok(Some(Bytes::from(buf)));
+        }
+        if token < 0 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "rsync daemon sent a block match without checksums",
+            ));
+        }
+
+        let mut sum = [0; FILE_SUM_LEN];
+        self.read_exact(&mut sum).await?;
+        Ok(None)
+    }
+
+    /// Finish the session gracefully so that the daemon doesn't log an error.
+    pub async fn finish(mut self) -> Result<()> {
+        // Protocol 29 has three phases, and a final goodbye after the stats.
+        for _ in 0..4 {
+            self.write_i32(NDX_DONE).await?;
+        }
+        self.flush().await?;
+        for _ in 0..3 {
+            let ndx = self.read_i32().await?;
+            if ndx != NDX_DONE {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "rsync daemon sent unexpected file index while finishing",
+                )
+                .with_context("ndx", ndx));
+            }
+        }
+        // Total read, total written, total size, file list build and transfer time.
+        for _ in 0..5 {
+            self.read_longint().await?;
+        }
+        Ok(())
+    }
+
+    /// Attach the last error message sent by the daemon to `err`.
+    pub fn with_last_error(&self, err: Error) -> Error {
+        match &self.last_error {
+            Some(msg) => err.with_context("daemon_error", msg),
+            None => err,
+        }
+    }
+
+    fn new_io_error(&self, err: io::Error) -> Error {
+        self.with_last_error(new_std_io_error(err))
+    }
+
+    async fn read_line(&mut self) -> Result<String> {
+        let mut buf = vec![];
+        let n = self
+            .stream
+            .read_until(b'\n', &mut buf)
+            .await
+            .map_err(|err| self.new_io_error(err))?;
+        if n == 0 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "rsync daemon closed the connection during handshake",
+            )
+            .set_temporary());
+        }
+        let line = String::from_utf8_lossy(&buf);
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    /// Read exactly `buf.len()` bytes of data, demultiplexing the messages
+    /// sent by the daemon.
+    async fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        if !self.multiplexed {
+            return self.read_raw(buf).await;
+        }
+
+        let mut filled = 0;
+        while filled < buf.len() {
+            if self.frame_left == 0 {
+                self.read_frame_header().await?;
+                continue;
+            }
+            let n = self.frame_left.min(buf.len() - filled);
+            self.read_raw(&mut buf[filled..filled + n]).await?;
+            filled += n;
+            self.frame_left -= n;
+        }
+        Ok(())
+    }
+
+    async fn read_raw(&mut self, buf: &mut [u8]) -> Result<()> {
+        match self.stream.read_exact(buf).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(self.new_io_error(err)),
+        }
+    }
+
+    /// Read frame headers until a data frame, handling the messages in between.
+    async fn read_frame_header(&mut self) -> Result<()> {
+        loop {
+            let mut header = [0; 4];
+            self.read_raw(&mut header).await?;
+            let header = u32::from_le_bytes(header);
+            let tag = (header >> 24) as u8;
+            let len = (header & 0xFFFFFF) as usize;
+            if tag < MPLEX_BASE {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "rsync daemon sent invalid multiplexed header",
+                )
+                .with_context("header", header));
+            }
+
+            let code = tag - MPLEX_BASE;
+            if code == MSG_DATA {
+                self.frame_left = len;
+                return Ok(());
+            }
+
+            let mut msg = vec![0; len];
+            self.read_raw(&mut msg).await?;
+            let msg = String::from_utf8_lossy(&msg);
+            let msg = msg.trim_end();
+            debug!("rsync daemon sent message {code}: {msg}");
+            if matches!(code, MSG_ERROR_XFER | MSG_ERROR | MSG_ERROR_SOCKET) {
+                self.last_error = Some(msg.to_string());
+            }
+        }
+    }
+
+    async fn read_u8(&mut self) -> Result<u8> {
+        let mut buf = [0; 1];
+        self.read_exact(&mut buf).await?;
+        Ok(buf[0])
+    }
+
+    async fn read_u16(&mut self) -> Result<u16> {
+        let mut buf = [0; 2];
+        self.read_exact(&mut buf).await?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    async fn read_i32(&mut self) -> Result<i32> {
+        let mut buf = [0; 4];
+        self.read_exact(&mut buf).await?;
+        Ok(i32::from_le_bytes(buf))
+    }
+
+    async fn read_len(&mut self) -> Result<usize> {
+        let len = self.read_i32().await?;
+        usize::try_from(len).map_err(|_| {
+            Error::new(ErrorKind::Unexpected, "rsync daemon sent negative length")
+                .with_context("len", len)
+        })
+    }
+
+    /// Read an int64 which is sent as an int32 if it fits.
+    async fn read_longint(&mut self) -> Result<i64> {
+        let v = self.read_i32().await?;
+        if v != -1 {
+            return Ok(v as i64);
+        }
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf).await?;
+        Ok(i64::from_le_bytes(buf))
+    }
+
+    async fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        match self.stream.write_all(buf).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(self.new_io_error(err)),
+        }
+    }
+
+    async fn write_u16(&mut self, v: u16) -> Result<()> {
+        self.write_all(&v.to_le_bytes()).await
+    }
+
+    async fn write_i32(&mut self, v: i32) -> Result<()> {
+        self.write_all(&v.to_le_bytes()).await
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        match self.stream.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(self.new_io_error(err)),
+        }
+    }
+}
+
+/// Parse the protocol version from greeting like `@RSYNCD: 31.0 sha512 md5`.
+fn parse_greeting(line: &str) -> Result<i32> {
+    let version = line
+        .strip_prefix("@RSYNCD: ")
+        .map(|s| {
+            s.split(|c: char| !c.is_ascii_digit())
+                .next()
+                .unwrap_or_default()
+        })
+        .and_then(|s| s.parse().ok());
+
+    version.ok_or_else(|| {
+        Error::new(ErrorKind::Unexpected, "rsync daemon sent invalid greeting")
+            .with_context("greeting", line)
+    })
+}
+
+/// Parse the error sent by the daemon like `@ERROR: Unknown module 'foo'`.
+fn parse_daemon_error(msg: &str) -> Error {
+    let msg = msg.trim_start_matches(':').trim();
+    let (kind, retryable) = if msg.starts_with("Unknown module") {
+        (ErrorKind::ConfigInvalid, false)
+    } else if msg.contains("access denied") || msg.contains("auth failed") {
+        (ErrorKind::PermissionDenied, false)
+    } else if msg.starts_with("max connections") {
+        (ErrorKind::RateLimited, true)
+    } else {
+        (ErrorKind::Unexpected, false)
+    };
+
+    let mut err = Error::new(kind, msg);
+    if retryable {
+        err = err.set_temporary();
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MSG_INFO: u8 = 2;
+
+    fn frame(code: u8, data: &[u8]) -> Vec<u8> {
+        let header = ((MPLEX_BASE + code) as u32) << 24 | data.len() as u32;
+        let mut buf = header.to_le_bytes().to_vec();
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    fn file_list() -> Vec<u8> {
+        let mut buf = vec![];
+        // `.` with mode and mtime.
+        buf.push(0x01);
+        buf.push(1);
+        buf.extend_from_slice(b".");
+        buf.extend_from_slice(&4096i32.to_le_bytes());
+        buf.extend_from_slice(&1700000000i32.to_le_bytes());
+        buf.extend_from_slice(&0o040755i32.to_le_bytes());
+        // `a.txt` with long name, same time and a 64-bit size.
+        buf.push((XMIT_LONG_NAME | XMIT_SAME_TIME) as u8);
+        buf.extend_from_slice(&5i32.to_le_bytes());
+        buf.extend_from_slice(b"a.txt");
+        buf.extend_from_slice(&(-1i32).to_le_bytes());
+        buf.extend_from_slice(&5_000_000_000i64.to_le_bytes());
+        buf.extend_from_slice(&0o100644i32.to_le_bytes());
+        // `a.md5` sharing the prefix, mode and mtime with `a.txt`.
+        buf.push((XMIT_SAME_NAME | XMIT_SAME_MODE | XMIT_SAME_TIME) as u8);
+        buf.push(2);
+        buf.push(3);
+        buf.extend_from_slice(b"md5");
+        buf.extend_from_slice(&3i32.to_le_bytes());
+        // End of file list and io error flag.
+        buf.push(0);
+        buf.extend_from_slice(&0i32.to_le_bytes());
+        buf
+    }
+
+    #[test]
+    fn test_parse_greeting() {
+        assert_eq!(parse_greeting("@RSYNCD: 31.0 sha512 md5").unwrap(), 31);
+        assert_eq!(parse_greeting("@RSYNCD: 29").unwrap(), 29);
+        assert!(parse_greeting("SSH-2.0-OpenSSH_9.6").is_err());
+    }
+
+    #[test]
+    fn test_parse_daemon_error() {
+        let cases = [
+            (": Unknown module 'foo'", ErrorKind::ConfigInvalid),
+            (
+                ": access denied to foo from 127.0.0.1",
+                ErrorKind::PermissionDenied,
+            ),
+            (
+                ": max connections (10) reached -- try again later",
+                ErrorKind::RateLimited,
+            ),
+            (": chroot failed", ErrorKind::Unexpected),
+        ];
+        for (msg, kind) in cases {
+            assert_eq!(parse_daemon_error(msg).kind(), kind, "{msg}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session() {
+        let mut input = b"@RSYNCD: 31.0 sha512 md5\nWelcome\n@RSYNCD: OK\n".to_vec();
+        input.extend_from_slice(&42i32.to_le_bytes());
+        // Split the file list across frames with messages in between.
+        let flist = file_list();
+        input.extend(frame(MSG_DATA, &flist[..7]));
+        input.extend(frame(MSG_INFO, b"building file list\n"));
+        input.extend(frame(MSG_DATA, &[]));
+        input.extend(frame(MSG_DATA, &flist[7..]));
+
+        let mut data = vec![];
+        data.extend_from_slice(&2i32.to_le_bytes());
+        data.extend_from_slice(&ITEM_TRANSFER.to_le_bytes());
+        data.extend_from_slice(&[0; 16]);
+        data.extend_from_slice(&3i32.to_le_bytes());
+        data.extend_from_slice(b"abc");
+        data.extend_from_slice(&0i32.to_le_bytes());
+        data.extend_from_slice(&[0; FILE_SUM_LEN]);
+        for _ in 0..3 {
+            data.extend_from_slice(&NDX_DONE.to_le_bytes());
+        }
+        for _ in 0..5 {
+            data.extend_from_slice(&0i32.to_le_bytes());
+        }
+        input.extend(frame(MSG_DATA, &data));
+
+        let (client, mut server) = tokio::io::duplex(64 * 1024);
+        server.write_all(&input).await.unwrap();
+
+        let mut session = RsyncSession::start(client, "mod", &["--server", "mod/dir/"])
+            .await
+            .unwrap();
+        let entries = session.recv_file_list().await.unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                FileEntry {
+                    name: ".".to_string(),
+                    size: 4096,
+                    mtime: 1700000000,
+                    mode: 0o040755,
+                },
+                FileEntry {
+                    name: "a.txt".to_string(),
+                    size: 5_000_000_000,
+                    mtime: 1700000000,
+                    mode: 0o100644,
+                },
+                FileEntry {
+                    name: "a.md5".to_string(),
+                    size: 3,
+                    mtime: 1700000000,
+                    mode: 0o100644,
+                },
+            ]
+        );
+
+        session.request_file(2).await.unwrap();
+        assert_eq!(
+            session.read_file_data().await.unwrap().as_deref(),
+            Some(&b"abc"[..])
+        );
+        assert_eq!(session.read_file_data().await.unwrap(), None);
+        session.finish().await.unwrap();
+
+        let mut output = vec![];
+        server.read_to_end(&mut output).await.unwrap();
+        let mut expected = b"@RSYNCD: 29\nmod\n--server\nmod/dir/\n\n".to_vec();
+        expected.extend_from_slice(&0i32.to_le_bytes());
+        expected.extend_from_slice(&2i32.to_le_bytes());
+        expected.extend_from_slice(&ITEM_TRANSFER.to_le_bytes());
+        expected.extend_from_slice(&[0; 16]);
+        for _ in 0..4 {
+            expected.extend_from_slice(&NDX_DONE.to_le_bytes());
+        }
+        assert_eq!(output, expected);
+    }
+
+    #[tokio::test]
+    async fn test_session_auth_required() {
+        let (client, mut server) = tokio::io::duplex(1024);
+        server
+            .write_all(b"@RSYNCD: 31.0\n@RSYNCD: AUTHREQD challenge\n")
+            .await
+            .unwrap();
+
+        let err = RsyncSession::start(client, "mod", &[]).await.err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+}
@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use tokio::net::TcpStream;
+
+use super::core::RsyncCore;
+use super::protocol::RsyncSession;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct RsyncReader {
+    core: Arc<RsyncCore>,
+    path: String,
+}
+
+impl RsyncReader {
+    pub(super) fn new(core: Arc<RsyncCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::StreamRead for RsyncReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let (entry, session) = self.core.rsync_open(&self.path).await?;
+        let content = range.to_content_range(entry.size as usize)?;
+
+        let stream = RsyncStream {
+            session: Some(session),
+            skip: content.start,
+            remaining: content.len(),
+            to_end: content.end as u64 == entry.size,
+        };
+        Ok((
+            RpRead::new(entry.metadata()?),
+            Box::new(stream) as Box<dyn oio::ReadStreamDyn>,
+        ))
+    }
+}
+
+/// Stream of the file content sent by the daemon.
+///
+/// rsync always sends the file from the beginning, so the bytes before the
+/// range are skipped.
+struct RsyncStream {
+    session: Option<RsyncSession<TcpStream>>,
+    /// Bytes to skip before the range.
+    skip: usize,
+    remaining: usize,
+    /// Whether the range ends at the end of the file.
+    to_end: bool,
+}
+
+impl oio::ReadStream for RsyncStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.remaining == 0 {
+            // Finish the session only if the whole file has been received,
+            // otherwise it's cheaper to drop the connection.
+            if let Some(mut session) = self.session.take() {
+                if self.to_end {
+                    while session.read_file_data().await?.is_some() {}
+                    session.finish().await?;
+                }
+            }
+            return Ok(Buffer::new());
+        }
+
+        let Some(session) = self.session.as_mut() else {
+            return Ok(Buffer::new());
+        };
+        loop {
+            let Some(bs) = session.read_file_data().await? else {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "file sent by rsync daemon is shorter than expected, it may be changed while reading",
+                ));
+            };
+            if bs.len() <= self.skip {
+                self.skip -= bs.len();
+                continue;
+            }
+
+            let end = bs.len().min(self.skip + self.remaining);
+            let bs = bs.slice(self.skip..end);
+            self.skip = 0;
+            self.remaining -= bs.len();
+            return Ok(Buffer::from(bs));
+        }
+    }
+}
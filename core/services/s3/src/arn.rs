@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use opendal_core::*;
+
+use crate::S3_SCHEME;
+
+/// The kind of resource an S3 ARN bucket points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum S3ArnKind {
+    /// `arn:{partition}:s3:{region}:{account}:accesspoint/{name}`
+    AccessPoint,
+    /// `arn:{partition}:s3-object-lambda:{region}:{account}:accesspoint/{name}`
+    ObjectLambda,
+}
+
+/// An access point or Object Lambda access point ARN used as bucket name.
+///
+/// ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/using-access-points.html>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3Arn {
+    pub kind: S3ArnKind,
+    pub partition: String,
+    pub region: String,
+    pub account_id: String,
+    pub name: String,
+}
+
+impl S3Arn {
+    /// Parse the given bucket as an ARN.
+    ///
+    /// Returns `Ok(None)` if the bucket is not an ARN at all, and an error if
+    /// it's an ARN that we can't send requests to.
+    pub fn parse(bucket: &str) -> Result<Option<Self>> {
+        if !bucket.starts_with("arn:") {
+            return Ok(None);
+        }
+
+        let invalid = |msg: &str| {
+            Error::new(ErrorKind::ConfigInvalid, msg.to_string())
+                .with_operation("Builder::build")
+                .with_context("service", S3_SCHEME)
+                .with_context("bucket", bucket)
+        };
+
+        let parts: Vec<&str> = bucket.splitn(6, ':').collect();
+        let [_, partition, service, region, account_id, resource] = parts[..] else {
+            return Err(invalid("bucket arn is malformed"));
+        };
+
+        let kind = match service {
+            "s3" => S3ArnKind::AccessPoint,
+            "s3-object-lambda" => S3ArnKind::ObjectLambda,
+            _ => return Err(invalid("bucket arn must be an s3 or s3-object-lambda arn")),
+        };
+        if partition_domain(partition).is_none() {
+            return Err(invalid("bucket arn partition is not supported"));
+        }
+        // Multi-Region access points don't carry a region and require SigV4A.
+        if region.is_empty() {
+            return Err(invalid("bucket arn without region is not supported"));
+        }
+        if account_id.len() != 12 || !account_id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("bucket arn account id must be 12 digits"));
+        }
+
+        // Both `accesspoint/{name}` and `accesspoint:{name}` are valid.
+        let name = resource
+            .strip_prefix("accesspoint/")
+            .or_else(|| resource.strip_prefix("accesspoint:"))
+            .ok_or_else(|| invalid("bucket arn must point to an access point"))?;
+        if name.is_empty()
+            || !name
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        {
+            return Err(invalid("bucket arn access point name is invalid"));
+        }
+
+        Ok(Some(Self {
+            kind,
+            partition: partition.to_string(),
+            region: region.to_string(),
+            account_id: account_id.to_string(),
+            name: name.to_string(),
+        }))
+    }
+
+    /// The service name used to sign requests to this access point.
+    pub fn signing_name(&self) -> &'static str {
+        match self.kind {
+            S3ArnKind::AccessPoint => "s3",
+            S3ArnKind::ObjectLambda => "s3-object-lambda",
+        }
+    }
+
+    /// The endpoint of this access point, like
+    /// `https://{name}-{account_id}.s3-accesspoint.{region}.amazonaws.com`.
+    pub fn endpoint(&self) -> String {
+        let service = match self.kind {
+            S3ArnKind::AccessPoint => "s3-accesspoint",
+            S3ArnKind::ObjectLambda => "s3-object-lambda",
+        };
+        let domain = partition_domain(&self.partition).expect("partition must be valid");
+
+        format!(
+            "https://{}-{}.{service}.{}.{domain}",
+            self.name, self.account_id, self.region
+        )
+    }
+}
+
+fn partition_domain(partition: &str) -> Option<&'static str> {
+    match partition {
+        "aws" | "aws-us-gov" => Some("amazonaws.com"),
+        "aws-cn" => Some("amazonaws.com.cn"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_arn() {
+        assert_eq!(S3Arn::parse("my-bucket").unwrap(), None);
+
+        let cases = vec![
+            (
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap",
+                "s3",
+                "https://my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com",
+            ),
+            (
+                "arn:aws-cn:s3:cn-north-1:123456789012:accesspoint:my-ap",
+                "s3",
+                "https://my-ap-123456789012.s3-accesspoint.cn-north-1.amazonaws.com.cn",
+            ),
+            (
+                "arn:aws:s3-object-lambda:us-east-1:123456789012:accesspoint/my-olap",
+                "s3-object-lambda",
+                "https://my-olap-123456789012.s3-object-lambda.us-east-1.amazonaws.com",
+            ),
+        ];
+        for (bucket, signing_name, endpoint) in cases {
+            let arn = S3Arn::parse(bucket).unwrap().unwrap();
+            assert_eq!(arn.signing_name(), signing_name, "{bucket}");
+            assert_eq!(arn.endpoint(), endpoint, "{bucket}");
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_arn() {
+        let cases = vec![
+            "arn:aws:s3",
+            "arn:aws:s3:::my-bucket",
+            "arn:aws:s3::123456789012:accesspoint/my-mrap",
+            "arn:aws:s3:us-west-2:1234:accesspoint/my-ap",
+            "arn:aws:s3:us-west-2:123456789012:outpost/my-outpost",
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/My_AP",
+            "arn:aws:sqs:us-west-2:123456789012:accesspoint/my-ap",
+            "arn:unknown:s3:us-west-2:123456789012:accesspoint/my-ap",
+        ];
+        for bucket in cases {
+            let err = S3Arn::parse(bucket).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "{bucket}");
+        }
+    }
+}
@@ -24,6 +24,7 @@ use std::sync::LazyLock;
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
 use constants::X_AMZ_META_PREFIX;
+use constants::X_AMZ_RESTORE;
 use constants::X_AMZ_VERSION_ID;
 use http::StatusCode;
 use log::debug;
@@ -43,12 +44,18 @@ use reqsign_file_read_tokio::TokioFileRead;
 use url::Url;
 
 use crate::S3_SCHEME;
+use crate::arn::S3Arn;
+use crate::arn::S3ArnKind;
 use crate::config::S3Config;
 use crate::copier::S3Copiers;
 use crate::copier::new_s3_copier;
+use crate::core::ChecksumAlgorithm;
 use crate::core::parse_error;
 use crate::core::*;
 use crate::deleter::S3Deleter;
+use crate::express::S3_EXPRESS_SIGNING_NAME;
+use crate::express::S3ExpressBucket;
+use crate::express::S3ExpressSessionProvider;
 use crate::lister::S3ListerV1;
 use crate::lister::S3ListerV2;
 use crate::lister::S3Listers;
@@ -105,6 +112,10 @@ impl S3Builder {
     }
 
     /// Set bucket name of this backend.
+    ///
+    /// Access point and Object Lambda access point ARNs like
+    /// `arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point` are
+    /// accepted too, requests will be sent to the access point instead.
     pub fn bucket(mut self, bucket: &str) -> Self {
         self.config.bucket = bucket.to_string();
 
@@ -422,6 +433,19 @@ impl S3Builder {
         self
     }
 
+    /// Set the account id expected to own the bucket.
+    ///
+    /// OpenDAL will send requests with `x-amz-expected-bucket-owner` header, so that
+    /// requests to a bucket owned by another account are rejected.
+    pub fn expected_bucket_owner(mut self, account_id: &str) -> Self {
+        self.config.expected_bucket_owner = if account_id.is_empty() {
+            None
+        } else {
+            Some(account_id.to_string())
+        };
+        self
+    }
+
     /// Disable load credential from ec2 metadata.
     ///
     /// This option is used to disable the default behavior of opendal
@@ -575,6 +599,17 @@ impl S3Builder {
         self
     }
 
+    /// Set how `create_dir` handles directory marker objects.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` object so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    pub fn dir_marker(mut self, mode: &str) -> Self {
+        self.config.dir_marker = Some(mode.to_string());
+
+        self
+    }
+
     /// Deprecated: S3 write with If-Match capability is enabled by default.
     #[deprecated(
         since = "0.57.0",
@@ -719,6 +754,22 @@ impl S3Builder {
         self.config.default_acl = Some(acl.to_string());
         self
     }
+
+    /// Enable ensure bucket so that OpenDAL will create the bucket on first
+    /// use if it doesn't exist.
+    ///
+    /// This is meant for test environments like minio.
+    pub fn ensure_bucket(mut self) -> Self {
+        self.config.ensure_bucket = true;
+        self
+    }
+
+    /// Set the days after which objects under root expire in the bucket
+    /// created by [`S3Builder::ensure_bucket`].
+    pub fn ensure_bucket_expiration_days(mut self, days: u32) -> Self {
+        self.config.ensure_bucket_expiration_days = Some(days);
+        self
+    }
 }
 
 impl Builder for S3Builder {
@@ -750,6 +801,39 @@ impl Builder for S3Builder {
             )
         }?;
         debug!("backend use bucket {}", &bucket);
+        let arn = S3Arn::parse(bucket)?;
+        let express = match arn {
+            Some(_) => None,
+            None => S3ExpressBucket::parse(bucket),
+        };
+        // Directory buckets only support ListObjectsV2.
+        if express.is_some() && config.disable_list_objects_v2 {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "directory buckets don't support disable_list_objects_v2",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", S3_SCHEME)
+            .with_context("bucket", bucket));
+        }
+
+        if config.ensure_bucket && (arn.is_some() || express.is_some()) {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "ensure_bucket doesn't support access points or directory buckets",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", S3_SCHEME)
+            .with_context("bucket", bucket));
+        }
+        if config.ensure_bucket_expiration_days.is_some() && !config.ensure_bucket {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "ensure_bucket_expiration_days requires ensure_bucket",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", S3_SCHEME));
+        }
 
         let default_storage_class = match &config.default_storage_class {
             None => None,
@@ -798,6 +882,14 @@ impl Builder for S3Builder {
                 })?),
             };
 
+        let expected_bucket_owner = match &config.expected_bucket_owner {
+            None => None,
+            Some(v) => Some(
+                build_header_value(v)
+                    .map_err(|err| err.with_context("key", "expected_bucket_owner"))?,
+            ),
+        };
+
         let checksum_algorithm = match config.checksum_algorithm.as_deref() {
             Some("crc32c") => Some(ChecksumAlgorithm::Crc32c),
             Some("md5") => Some(ChecksumAlgorithm::Md5),
@@ -810,9 +902,23 @@ impl Builder for S3Builder {
             }
         };
 
+        let (create_dir, create_dir_persistent) = match config.dir_marker.as_deref() {
+            Some("force") => (true, true),
+            Some("skip") => (true, false),
+            None => (false, false),
+            v => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    format!("{v:?} is not a supported dir_marker."),
+                ));
+            }
+        };
+
         // Determine the region
         let region = if let Some(ref v) = config.region {
             v.to_string()
+        } else if let Some(arn) = &arn {
+            arn.region.clone()
         } else {
             std::env::var("AWS_REGION")
                 .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
@@ -826,8 +932,22 @@ impl Builder for S3Builder {
                 })?
         };
         debug!("backend use region: {region}");
+        // Access points can only be reached in their own region.
+        if let Some(arn) = &arn {
+            if arn.region != region {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "region doesn't match the region of bucket arn",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", S3_SCHEME)
+                .with_context("region", &region)
+                .with_context("bucket", bucket));
+            }
+        }
 
-        if config.endpoint.is_none() && !config.disable_config_load {
+        // Endpoints from env are meant for buckets, not access points.
+        if config.endpoint.is_none() && !config.disable_config_load && arn.is_none() {
             let endpoint_from_env = std::env::var("AWS_ENDPOINT_URL")
                 .or_else(|_| std::env::var("AWS_ENDPOINT"))
                 .or_else(|_| std::env::var("AWS_S3_ENDPOINT"))
@@ -839,7 +959,17 @@ impl Builder for S3Builder {
         }
 
         // Building endpoint.
-        let endpoint = Self::build_endpoint(&config, &region);
+        let endpoint = match (&arn, &config.endpoint) {
+            // Custom endpoints of access points (like PrivateLink) address
+            // the access point already, use them as is.
+            (Some(_), Some(endpoint)) if endpoint.starts_with("http") => endpoint.to_string(),
+            (Some(_), Some(endpoint)) => format!("https://{endpoint}"),
+            (Some(arn), None) => arn.endpoint(),
+            (None, endpoint) => match (&express, endpoint) {
+                (Some(express), None) => express.endpoint(bucket, &region),
+                _ => Self::build_endpoint(&config, &region),
+            },
+        };
         debug!("backend use endpoint: {endpoint}");
 
         // The base signer context only carries local config readers. HTTP
@@ -902,131 +1032,232 @@ impl Builder for S3Builder {
         }
 
         // Replace provider if user provide their own.
-        let provider = if let Some(credential_providers) = credential_providers {
+        let mut provider = if let Some(credential_providers) = credential_providers {
             credential_providers
         } else {
             provider
         };
 
+        // Directory buckets are accessed with session credentials created by
+        // the loaded credentials.
+        if express.is_some() {
+            provider = ProvideCredentialChain::new()
+                .push(S3ExpressSessionProvider::new(&endpoint, &region, provider));
+        }
+
         // Create request signer for S3
-        let request_signer = AwsV4Signer::new("s3", &region);
+        let signing_name = match (&arn, &express) {
+            (Some(arn), _) => arn.signing_name(),
+            (None, Some(_)) => S3_EXPRESS_SIGNING_NAME,
+            (None, None) => "s3",
+        };
+        let request_signer = AwsV4Signer::new(signing_name, &region);
 
         // Create the signer
         let signer = Signer::new(ctx, provider, request_signer);
 
-        Ok(S3Backend {
-            core: Arc::new(S3Core {
-                info: ServiceInfo::new(S3_SCHEME, &root, bucket),
-                capability: Capability {
-                    stat: true,
-                    stat_with_if_match: true,
-                    stat_with_if_none_match: true,
-                    stat_with_if_modified_since: true,
-                    stat_with_if_unmodified_since: true,
-                    stat_with_override_cache_control: true,
-                    stat_with_override_content_disposition: true,
-                    stat_with_override_content_type: true,
-                    stat_with_version: true,
-
-                    read: true,
-                    read_with_if_match: true,
-                    read_with_if_none_match: true,
-                    read_with_if_modified_since: true,
-                    read_with_if_unmodified_since: true,
-                    read_with_override_cache_control: true,
-                    read_with_override_content_disposition: true,
-                    read_with_override_content_type: true,
-                    read_with_version: true,
-                    read_with_suffix: true,
-
-                    write: true,
-                    write_can_empty: true,
-                    write_can_multi: true,
-                    write_can_append: true,
-
-                    write_with_cache_control: true,
-                    write_with_content_type: true,
-                    write_with_content_disposition: true,
-                    write_with_content_encoding: true,
-                    write_with_if_match: true,
-                    write_with_if_not_exists: true,
-                    write_with_user_metadata: true,
-
-                    // The min multipart size of S3 is 5 MiB.
-                    //
-                    // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
-                    write_multi_min_size: Some(5 * 1024 * 1024),
-                    // The max multipart size of S3 is 5 GiB.
-                    //
-                    // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
-                    write_multi_max_size: if cfg!(target_pointer_width = "64") {
-                        Some(5 * 1024 * 1024 * 1024)
-                    } else {
-                        Some(usize::MAX)
-                    },
-                    // S3 allows at most 10,000 parts and 5 GiB for each part.
-                    //
-                    // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
-                    write_total_max_size: if cfg!(target_pointer_width = "64") {
-                        Some(10_000 * 5 * 1024 * 1024 * 1024)
-                    } else {
-                        None
-                    },
-
-                    delete: true,
-                    delete_max_size: Some(DEFAULT_BATCH_MAX_OPERATIONS),
-                    delete_with_version: true,
-
-                    copy: true,
-                    copy_can_multi: true,
-                    copy_with_if_not_exists: true,
-                    copy_with_if_match: true,
-                    copy_with_source_version: true,
-                    // The min multipart size of S3 is 5 MiB.
-                    //
-                    // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
-                    copy_multi_min_size: Some(5 * 1024 * 1024),
-                    // The max multipart size of S3 is 5 GiB.
-                    //
-                    // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
-                    copy_multi_max_size: if cfg!(target_pointer_width = "64") {
-                        Some(5 * 1024 * 1024 * 1024)
-                    } else {
-                        Some(usize::MAX)
-                    },
-
-                    list: true,
-                    list_with_limit: true,
-                    list_with_start_after: true,
-                    list_with_recursive: true,
-                    list_with_versions: true,
-                    list_with_deleted: true,
-
-                    presign: true,
-                    presign_stat: true,
-                    presign_read: true,
-                    presign_write: true,
-
-                    shared: true,
-
-                    ..Default::default()
+        let ensure_bucket = config
+            .ensure_bucket
+            .then(|| S3EnsureBucket::new(&region, config.ensure_bucket_expiration_days));
+
+        let mut core = S3Core {
+            info: ServiceInfo::new(S3_SCHEME, &root, bucket),
+            capability: Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                stat_with_if_modified_since: true,
+                stat_with_if_unmodified_since: true,
+                stat_with_override_cache_control: true,
+                stat_with_override_content_disposition: true,
+                stat_with_override_content_type: true,
+                stat_with_version: true,
+
+                read: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                read_with_if_modified_since: true,
+                read_with_if_unmodified_since: true,
+                read_with_override_cache_control: true,
+                read_with_override_content_disposition: true,
+                read_with_override_content_type: true,
+                read_with_version: true,
+                read_with_suffix: true,
+
+                write: true,
+                write_can_empty: true,
+                write_can_multi: true,
+                write_can_append: true,
+
+                write_with_cache_control: true,
+                write_with_content_type: true,
+                write_with_content_disposition: true,
+                write_with_content_encoding: true,
+                write_with_if_match: true,
+                write_with_if_not_exists: true,
+                write_with_user_metadata: true,
+                write_with_checkpoint: true,
+
+                create_dir,
+                create_dir_persistent,
+
+                // The min multipart size of S3 is 5 MiB.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                write_multi_min_size: Some(5 * 1024 * 1024),
+                // The max multipart size of S3 is 5 GiB.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                write_multi_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(5 * 1024 * 1024 * 1024)
+                } else {
+                    Some(usize::MAX)
+                },
+                // S3 allows at most 10,000 parts and 5 GiB for each part.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                write_total_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(10_000 * 5 * 1024 * 1024 * 1024)
+                } else {
+                    None
+                },
+
+                delete: true,
+                delete_max_size: Some(DEFAULT_BATCH_MAX_OPERATIONS),
+                delete_with_version: true,
+
+                copy: true,
+                copy_can_multi: true,
+                copy_with_if_not_exists: true,
+                copy_with_if_match: true,
+                copy_with_source_version: true,
+                // The min multipart size of S3 is 5 MiB.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                copy_multi_min_size: Some(5 * 1024 * 1024),
+                // The max multipart size of S3 is 5 GiB.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                copy_multi_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(5 * 1024 * 1024 * 1024)
+                } else {
+                    Some(usize::MAX)
                 },
-                bucket: bucket.to_string(),
-                endpoint,
-                root,
-                server_side_encryption,
-                server_side_encryption_aws_kms_key_id,
-                server_side_encryption_customer_algorithm,
-                server_side_encryption_customer_key,
-                server_side_encryption_customer_key_md5,
-                default_storage_class,
-                skip_signature: config.skip_signature,
-                disable_list_objects_v2: config.disable_list_objects_v2,
-                enable_request_payer: config.enable_request_payer,
-                signer,
-                checksum_algorithm,
-                default_acl: config.default_acl,
-            }),
+
+                list: true,
+                list_with_limit: true,
+                list_with_start_after: true,
+                list_with_recursive: true,
+                list_with_versions: true,
+                list_with_deleted: true,
+
+                presign: true,
+                presign_stat: true,
+                presign_read: true,
+                presign_write: true,
+                presign_multipart: true,
+
+                restore: true,
+
+                shared: true,
+
+                ..Default::default()
+            },
+            bucket: bucket.to_string(),
+            endpoint,
+            root,
+            server_side_encryption,
+            server_side_encryption_aws_kms_key_id,
+            server_side_encryption_customer_algorithm,
+            server_side_encryption_customer_key,
+            server_side_encryption_customer_key_md5,
+            default_storage_class,
+            skip_signature: config.skip_signature,
+            disable_list_objects_v2: config.disable_list_objects_v2,
+            enable_request_payer: config.enable_request_payer,
+            expected_bucket_owner,
+            signer,
+            checksum_algorithm,
+            default_acl: config.default_acl,
+            ensure_bucket,
+        };
+
+        // Object Lambda access points only serve GET, HEAD and LIST requests.
+        //
+        // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/olap-writing-lambda.html>
+        if arn.as_ref().map(|v| v.kind) == Some(S3ArnKind::ObjectLambda) {
+            core.capability = Capability {
+                write: false,
+                write_can_empty: false,
+                write_can_multi: false,
+                write_can_append: false,
+                write_with_cache_control: false,
+                write_with_content_type: false,
+                write_with_content_disposition: false,
+                write_with_content_encoding: false,
+                write_with_if_match: false,
+                write_with_if_not_exists: false,
+                write_with_user_metadata: false,
+                write_with_checkpoint: false,
+                write_multi_min_size: None,
+                write_multi_max_size: None,
+                write_total_max_size: None,
+
+                create_dir: false,
+                create_dir_persistent: false,
+
+                delete: false,
+                delete_max_size: None,
+                delete_with_version: false,
+
+                copy: false,
+                copy_can_multi: false,
+                copy_with_if_not_exists: false,
+                copy_with_if_match: false,
+                copy_with_source_version: false,
+                copy_multi_min_size: None,
+                copy_multi_max_size: None,
+
+                list_with_versions: false,
+                list_with_deleted: false,
+
+                presign_write: false,
+                presign_multipart: false,
+
+                ..core.capability
+            };
+        }
+
+        // Directory buckets don't support versioning, archive storage classes and
+        // `start-after`, and require session tokens which can't be carried by
+        // presigned urls.
+        //
+        // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/s3-express-differences.html>
+        if express.is_some() {
+            core.capability = Capability {
+                stat_with_version: false,
+                read_with_version: false,
+                delete_with_version: false,
+                copy_with_source_version: false,
+
+                list_with_start_after: false,
+                list_with_versions: false,
+                list_with_deleted: false,
+
+                presign: false,
+                presign_stat: false,
+                presign_read: false,
+                presign_write: false,
+                presign_multipart: false,
+
+                restore: false,
+
+                ..core.capability
+            };
+        }
+
+        Ok(S3Backend {
+            core: Arc::new(core),
         })
     }
 }
@@ -1054,14 +1285,31 @@ impl Service for S3Backend {
 
     async fn create_dir(
         &self,
-        _ctx: &OperationContext,
-        _path: &str,
+        ctx: &OperationContext,
+        path: &str,
         _args: OpCreateDir,
     ) -> Result<RpCreateDir> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        if !self.core.capability.create_dir {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation is not supported",
+            ));
+        }
+
+        // Without marker objects, the directory appears once it has children.
+        if !self.core.capability.create_dir_persistent {
+            return Ok(RpCreateDir::default());
+        }
+
+        let req =
+            self.core
+                .s3_put_object_request(path, Some(0), &OpWrite::default(), Buffer::new())?;
+        let resp = self.core.send(ctx, req).await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpCreateDir::default()),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
@@ -1083,6 +1331,14 @@ impl Service for S3Backend {
                     meta.set_version(v);
                 }
 
+                if let Some(v) = parse_checksum(headers)? {
+                    meta.set_checksum(v);
+                }
+
+                if let Some(v) = parse_restore_status(headers, X_AMZ_RESTORE)? {
+                    meta.set_restore_status(v);
+                }
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -1110,11 +1366,11 @@ impl Service for S3Backend {
             } else {
                 // Multipart uploads schedule work through the operation
                 // executor supplied by the caller.
-                S3Writers::One(oio::MultipartWriter::new(
-                    ctx.executor().clone(),
-                    writer,
-                    args.concurrent(),
-                ))
+                S3Writers::One(
+                    oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent())
+                        .with_checkpoint(args.checkpoint().cloned())
+                        .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                )
             };
 
             Ok(w)
@@ -1194,12 +1450,30 @@ impl Service for S3Backend {
         ))
     }
 
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        let resp = self.core.s3_restore_object(ctx, path, &args).await?;
+
+        match resp.status() {
+            // `202 Accepted` starts a new restore, `200 OK` extends the days
+            // of an object that has been restored.
+            StatusCode::OK | StatusCode::ACCEPTED => Ok(RpRestore::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(
         &self,
         ctx: &OperationContext,
         path: &str,
         args: OpPresign,
     ) -> Result<RpPresign> {
+        self.core.ensure_bucket(ctx, Operation::Presign).await?;
+
         let (expire, op) = args.into_parts();
         // We will not send this request out, just for signing.
         let req = match op {
@@ -1209,6 +1483,28 @@ impl Service for S3Backend {
                 self.core
                     .s3_put_object_request(path, None, &v, Buffer::new())
             }
+            PresignOperation::CreateMultipart(v) => {
+                self.core.s3_initiate_multipart_upload_request(path, &v)
+            }
+            PresignOperation::UploadPart {
+                upload_id,
+                part_number,
+            } => self.core.s3_upload_part_request(
+                path,
+                &upload_id,
+                part_number,
+                None,
+                Buffer::new(),
+                None,
+            ),
+            PresignOperation::CompleteMultipart { upload_id } => {
+                self.core.s3_complete_multipart_upload_request(
+                    path,
+                    &upload_id,
+                    Buffer::new(),
+                    &OpWrite::default(),
+                )
+            }
             PresignOperation::Delete(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
@@ -1289,6 +1585,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_with_ensure_bucket() {
+        let build = |bucket: &str| {
+            S3Builder::default()
+                .bucket(bucket)
+                .region("us-west-2")
+                .disable_config_load()
+                .disable_ec2_metadata()
+                .ensure_bucket()
+                .ensure_bucket_expiration_days(1)
+                .build()
+        };
+
+        assert!(build("test").is_ok());
+        assert_eq!(
+            build("test--usw2-az1--x-s3").unwrap_err().kind(),
+            ErrorKind::ConfigInvalid
+        );
+
+        let err = S3Builder::default()
+            .bucket("test")
+            .region("us-west-2")
+            .ensure_bucket_expiration_days(1)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_build_with_arn_bucket() {
+        let build = |bucket: &str, region: Option<&str>| {
+            let mut b = S3Builder::default()
+                .bucket(bucket)
+                .skip_signature()
+                .disable_config_load()
+                .disable_ec2_metadata();
+            if let Some(region) = region {
+                b = b.region(region);
+            }
+            b.build()
+        };
+        async fn presign_read(backend: &impl Service) -> String {
+            let args = OpPresign::new(OpRead::default(), Duration::from_secs(3600));
+            backend
+                .presign(&OperationContext::new(), "test.txt", args)
+                .await
+                .expect("presign")
+                .into_presigned_request()
+                .uri()
+                .to_string()
+        }
+
+        let backend =
+            build("arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap", None).expect("build");
+        assert_eq!(
+            presign_read(&backend).await,
+            "https://my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com/test.txt"
+        );
+        assert!(backend.capability().write);
+
+        let backend = build(
+            "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/my-olap",
+            Some("us-west-2"),
+        )
+        .expect("build");
+        assert_eq!(
+            presign_read(&backend).await,
+            "https://my-olap-123456789012.s3-object-lambda.us-west-2.amazonaws.com/test.txt"
+        );
+        assert!(backend.capability().read);
+        assert!(!backend.capability().write);
+        assert!(!backend.capability().delete);
+
+        let err = build(
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap",
+            Some("us-east-1"),
+        )
+        .expect_err("region mismatch must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_build_with_directory_bucket() {
+        let builder = || {
+            S3Builder::default()
+                .bucket("my-bucket--usw2-az1--x-s3")
+                .region("us-west-2")
+                .disable_config_load()
+                .disable_ec2_metadata()
+        };
+
+        let cap = builder().build().expect("build").capability();
+        assert!(cap.read && cap.write && cap.list);
+        assert!(!cap.read_with_version);
+        assert!(!cap.list_with_start_after);
+        assert!(!cap.presign);
+        assert!(!cap.restore);
+
+        let err = builder()
+            .disable_list_objects_v2()
+            .build()
+            .expect_err("list objects v1 must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
     #[tokio::test]
     async fn test_detect_region() {
         let cases = vec![
@@ -1361,4 +1762,63 @@ mod tests {
             "application/json"
         );
     }
+
+    #[tokio::test]
+    async fn test_presign_multipart() {
+        let backend = S3Builder::default()
+            .bucket("test")
+            .region("us-east-1")
+            .endpoint("http://127.0.0.1:9000")
+            .skip_signature()
+            .disable_config_load()
+            .disable_ec2_metadata()
+            .build()
+            .expect("build");
+        let ctx = OperationContext::new();
+
+        let cases = vec![
+            (
+                PresignOperation::CreateMultipart(OpWrite::default()),
+                http::Method::POST,
+                "http://127.0.0.1:9000/test/test.txt?uploads",
+            ),
+            (
+                PresignOperation::UploadPart {
+                    upload_id: "abc".to_string(),
+                    part_number: 2,
+                },
+                http::Method::PUT,
+                "http://127.0.0.1:9000/test/test.txt?partNumber=2&uploadId=abc",
+            ),
+            (
+                PresignOperation::CompleteMultipart {
+                    upload_id: "abc".to_string(),
+                },
+                http::Method::POST,
+                "http://127.0.0.1:9000/test/test.txt?uploadId=abc",
+            ),
+        ];
+
+        for (op, method, uri) in cases {
+            let presigned = backend
+                .presign(
+                    &ctx,
+                    "test.txt",
+                    OpPresign::new(op, Duration::from_secs(3600)),
+                )
+                .await
+                .expect("presign")
+                .into_presigned_request();
+
+            assert_eq!(presigned.method(), method);
+            assert_eq!(presigned.uri().to_string(), uri);
+            assert!(
+                presigned
+                    .header()
+                    .get(http::header::CONTENT_LENGTH)
+                    .is_none(),
+                "{uri}"
+            );
+        }
+    }
 }
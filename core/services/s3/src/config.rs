@@ -43,7 +43,7 @@ pub struct S3Config {
     pub root: Option<String>,
     /// bucket name of this backend.
     ///
-    /// required.
+    /// required. Access point and Object Lambda access point ARNs are accepted too.
     ///
     /// <!-- @group General -->
     /// <!-- @example my-bucket -->
@@ -276,6 +276,16 @@ pub struct S3Config {
     /// <!-- @group Behavior -->
     #[serde(alias = "aws_checksum_algorithm")]
     pub checksum_algorithm: Option<String>,
+    /// How `create_dir` handles directory marker objects.
+    ///
+    /// Available options:
+    /// - "force": write an empty `path/` object so the directory is listed even when empty.
+    /// - "skip": write nothing, so the directory only exists while it has children.
+    ///
+    /// By default, the marker object is written by `SimulateLayer`.
+    ///
+    /// <!-- @group Behavior -->
+    pub dir_marker: Option<String>,
     /// Deprecated: S3 write with If-Match capability is enabled by default.
     ///
     /// <!-- @group Deprecated -->
@@ -307,11 +317,36 @@ pub struct S3Config {
     #[serde(alias = "aws_request_payer", alias = "request_payer")]
     pub enable_request_payer: bool,
 
+    /// The account id expected to own the bucket.
+    ///
+    /// If set, every request carries `x-amz-expected-bucket-owner`, and S3 rejects it
+    /// with `403 Forbidden` if the bucket is owned by another account.
+    ///
+    /// <!-- @group Behavior -->
+    #[serde(alias = "aws_expected_bucket_owner")]
+    pub expected_bucket_owner: Option<String>,
+
     /// Default ACL for new objects.
     /// Note that some s3 services like minio do not support this option.
     ///
     /// <!-- @group Behavior -->
     pub default_acl: Option<String>,
+
+    /// Create the bucket on first use if it doesn't exist.
+    ///
+    /// The bucket is created in the configured region. This is meant for test
+    /// environments like minio, and doesn't work with access points or
+    /// directory buckets.
+    ///
+    /// <!-- @group Behavior -->
+    pub ensure_bucket: bool,
+    /// Expire objects under root after given days in the bucket created by
+    /// `ensure_bucket`.
+    ///
+    /// Existing buckets are left untouched.
+    ///
+    /// <!-- @group Behavior -->
+    pub ensure_bucket_expiration_days: Option<u32>,
 }
 
 impl Debug for S3Config {
@@ -413,7 +448,8 @@ mod tests {
             "endpoint_url": "https://s3.amazonaws.com",
             "virtual_hosted_style_request": true,
             "aws_checksum_algorithm": "crc32c",
-            "request_payer": true
+            "request_payer": true,
+            "aws_expected_bucket_owner": "111122223333"
         }"#;
 
         let config: S3Config = serde_json::from_str(json).unwrap();
@@ -426,6 +462,10 @@ mod tests {
         assert!(config.enable_virtual_host_style);
         assert_eq!(config.checksum_algorithm, Some("crc32c".to_string()));
         assert!(config.enable_request_payer);
+        assert_eq!(
+            config.expected_bucket_owner,
+            Some("111122223333".to_string())
+        );
     }
 
     #[test]
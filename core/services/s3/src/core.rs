@@ -21,6 +21,7 @@ use std::fmt::Write;
 
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
+use bytes::Buf;
 use bytes::Bytes;
 use constants::X_AMZ_META_PREFIX;
 use crc_fast::CrcAlgorithm;
@@ -28,6 +29,7 @@ use crc_fast::Digest as CrcDigest;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_ENCODING;
@@ -39,6 +41,7 @@ use http::header::IF_MATCH;
 use http::header::IF_MODIFIED_SINCE;
 use http::header::IF_NONE_MATCH;
 use http::header::IF_UNMODIFIED_SINCE;
+use mea::once::OnceCell;
 use reqsign_aws_v4::Credential;
 use reqsign_core::{Context, Signer};
 use serde::Deserialize;
@@ -53,6 +56,8 @@ pub mod constants {
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_REQUEST_PAYER: (&str, &str) = ("x-amz-request-payer", "requester");
+    pub const X_AMZ_EXPECTED_BUCKET_OWNER: &str = "x-amz-expected-bucket-owner";
+    pub const X_AMZ_SOURCE_EXPECTED_BUCKET_OWNER: &str = "x-amz-source-expected-bucket-owner";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
         "x-amz-server-side-encryption-customer-algorithm";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY: &str =
@@ -75,8 +80,14 @@ pub mod constants {
     pub const X_AMZ_META_PREFIX: &str = "x-amz-meta-";
 
     pub const X_AMZ_VERSION_ID: &str = "x-amz-version-id";
+    pub const X_AMZ_RESTORE: &str = "x-amz-restore";
     pub const X_AMZ_OBJECT_SIZE: &str = "x-amz-object-size";
 
+    pub const X_AMZ_CHECKSUM_MODE: &str = "x-amz-checksum-mode";
+    pub const X_AMZ_CHECKSUM_TYPE: &str = "x-amz-checksum-type";
+    pub const X_AMZ_CHECKSUM_CRC32C: &str = "x-amz-checksum-crc32c";
+    pub const X_AMZ_CHECKSUM_SHA256: &str = "x-amz-checksum-sha256";
+
     pub const X_AMZ_ACL: &str = "x-amz-acl";
 
     pub const RESPONSE_CONTENT_DISPOSITION: &str = "response-content-disposition";
@@ -102,10 +113,36 @@ pub struct S3Core {
     pub skip_signature: bool,
     pub disable_list_objects_v2: bool,
     pub enable_request_payer: bool,
+    pub expected_bucket_owner: Option<HeaderValue>,
     pub default_acl: Option<String>,
 
     pub signer: Signer<Credential>,
     pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    pub ensure_bucket: Option<S3EnsureBucket>,
+}
+
+/// State of creating the bucket on first use.
+pub struct S3EnsureBucket {
+    location_constraint: Option<String>,
+    expiration_days: Option<u32>,
+    ensured: OnceCell<()>,
+}
+
+impl S3EnsureBucket {
+    pub fn new(region: &str, expiration_days: Option<u32>) -> Self {
+        // Buckets in `us-east-1` must be created without location constraint,
+        // and `auto` is the pseudo region of services like R2.
+        let location_constraint = match region {
+            "us-east-1" | "auto" => None,
+            v => Some(v.to_string()),
+        };
+
+        Self {
+            location_constraint,
+            expiration_days,
+            ensured: OnceCell::new(),
+        }
+    }
 }
 
 pub(crate) struct S3UploadPartCopyRequest<'a> {
@@ -125,6 +162,34 @@ fn format_crc32c_iter(body: Buffer) -> String {
     BASE64_STANDARD.encode(crc.to_be_bytes())
 }
 
+/// Parse the full object checksum returned with `x-amz-checksum-mode: ENABLED`.
+///
+/// Composite checksums of multipart uploads (like `xxxx-3`) are checksums of
+/// part checksums, which can't be used to verify the content.
+pub fn parse_checksum(headers: &http::HeaderMap) -> Result<Option<Checksum>> {
+    if parse_header_to_str(headers, constants::X_AMZ_CHECKSUM_TYPE)? == Some("COMPOSITE") {
+        return Ok(None);
+    }
+
+    for (name, algorithm) in [
+        (
+            constants::X_AMZ_CHECKSUM_CRC32C,
+            opendal_core::ChecksumAlgorithm::Crc32c,
+        ),
+        (
+            constants::X_AMZ_CHECKSUM_SHA256,
+            opendal_core::ChecksumAlgorithm::Sha256,
+        ),
+    ] {
+        match parse_header_to_str(headers, name)? {
+            Some(v) if !v.contains('-') => return Ok(Some(Checksum::new(algorithm, v))),
+            _ => continue,
+        }
+    }
+
+    Ok(None)
+}
+
 impl Debug for S3Core {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("S3Core")
@@ -145,6 +210,18 @@ impl S3Core {
         )
     }
 
+    /// Build the `x-amz-copy-source` of given absolute path.
+    ///
+    /// Objects in access points are addressed by
+    /// `{access_point_arn}/object/{key}` instead of `{bucket}/{key}`.
+    fn copy_source(&self, abs_path: &str) -> String {
+        if self.bucket.starts_with("arn:") {
+            format!("{}/object/{}", self.bucket, percent_encode_path(abs_path))
+        } else {
+            format!("{}/{}", self.bucket, percent_encode_path(abs_path))
+        }
+    }
+
     pub async fn sign_query<T>(
         &self,
         ctx: &OperationContext,
@@ -178,6 +255,18 @@ impl S3Core {
         &self,
         ctx: &OperationContext,
         req: Request<Buffer>,
+    ) -> Result<Response<Buffer>> {
+        if let Some(op) = req.extensions().get::<Operation>() {
+            self.ensure_bucket(ctx, *op).await?;
+        }
+
+        self.sign_and_send(ctx, req).await
+    }
+
+    async fn sign_and_send(
+        &self,
+        ctx: &OperationContext,
+        req: Request<Buffer>,
     ) -> Result<Response<Buffer>> {
         if self.skip_signature {
             return ctx.http_transport().send(req).await;
@@ -208,6 +297,10 @@ impl S3Core {
         ctx: &OperationContext,
         req: Request<Buffer>,
     ) -> Result<Response<HttpBody>> {
+        if let Some(op) = req.extensions().get::<Operation>() {
+            self.ensure_bucket(ctx, *op).await?;
+        }
+
         if self.skip_signature {
             return ctx.http_transport().fetch(req).await;
         }
@@ -232,6 +325,138 @@ impl S3Core {
             .await
     }
 
+    /// Make sure the bucket exists if `ensure_bucket` is enabled.
+    ///
+    /// Only the first successful call talks to the service.
+    pub async fn ensure_bucket(&self, ctx: &OperationContext, op: Operation) -> Result<()> {
+        let Some(ensure) = &self.ensure_bucket else {
+            return Ok(());
+        };
+
+        ensure
+            .ensured
+            .get_or_try_init(|| async {
+                if self.s3_head_bucket(ctx, op).await? {
+                    return Ok(());
+                }
+                let created = self
+                    .s3_create_bucket(ctx, op, ensure.location_constraint.as_deref())
+                    .await?;
+                if let (true, Some(days)) = (created, ensure.expiration_days) {
+                    self.s3_put_bucket_lifecycle(ctx, op, days).await?;
+                }
+                Ok(())
+            })
+            .await
+            .map(|_| ())
+    }
+
+    /// Return whether the bucket exists.
+    async fn s3_head_bucket(&self, ctx: &OperationContext, op: Operation) -> Result<bool> {
+        let mut req = Request::head(format!("{}/", self.endpoint));
+
+        req = self.insert_expected_bucket_owner_header(req, false);
+
+        let req = req
+            .extension(op)
+            .extension(ServiceOperation("HeadBucket"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let resp = self.sign_and_send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Create the bucket, return whether it's created by this call.
+    async fn s3_create_bucket(
+        &self,
+        ctx: &OperationContext,
+        op: Operation,
+        location_constraint: Option<&str>,
+    ) -> Result<bool> {
+        let mut req = Request::put(format!("{}/", self.endpoint));
+
+        let content = match location_constraint {
+            Some(v) => quick_xml::se::to_string(&CreateBucketConfiguration {
+                location_constraint: v.to_string(),
+            })
+            .map_err(new_xml_serialize_error)?,
+            None => String::new(),
+        };
+
+        req = req.header(CONTENT_LENGTH, content.len());
+        if !content.is_empty() {
+            req = req.header(CONTENT_TYPE, "application/xml");
+        }
+
+        let req = req
+            .extension(op)
+            .extension(ServiceOperation("CreateBucket"))
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.sign_and_send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(true),
+            // Someone else (or we ourselves) created the bucket in the meantime.
+            StatusCode::CONFLICT => {
+                let s3_err: S3Error =
+                    quick_xml::de::from_reader(resp.body().clone().reader()).unwrap_or_default();
+                if s3_err.code == "BucketAlreadyOwnedByYou" {
+                    Ok(false)
+                } else {
+                    Err(parse_error(resp))
+                }
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Expire objects under root after given days.
+    async fn s3_put_bucket_lifecycle(
+        &self,
+        ctx: &OperationContext,
+        op: Operation,
+        days: u32,
+    ) -> Result<()> {
+        let mut req = Request::put(format!("{}/?lifecycle", self.endpoint));
+
+        let content = quick_xml::se::to_string(&LifecycleConfiguration {
+            rule: vec![LifecycleRule {
+                id: "opendal-expiration".to_string(),
+                filter: LifecycleRuleFilter {
+                    prefix: self.root.trim_start_matches('/').to_string(),
+                },
+                status: "Enabled".to_string(),
+                expiration: LifecycleExpiration { days },
+            }],
+        })
+        .map_err(new_xml_serialize_error)?;
+
+        req = req.header(CONTENT_LENGTH, content.len());
+        req = req.header(CONTENT_TYPE, "application/xml");
+        // Set content-md5 as required by API.
+        req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        req = self.insert_expected_bucket_owner_header(req, false);
+
+        let req = req
+            .extension(op)
+            .extension(ServiceOperation("PutBucketLifecycleConfiguration"))
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.sign_and_send(ctx, req).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     /// # Note
     ///
     /// header like X_AMZ_SERVER_SIDE_ENCRYPTION doesn't need to set while
@@ -385,6 +610,30 @@ impl S3Core {
         }
         req
     }
+
+    /// Insert expected bucket owner headers.
+    ///
+    /// - If `is_copy` is true, the source bucket will be checked as well.
+    pub fn insert_expected_bucket_owner_header(
+        &self,
+        mut req: http::request::Builder,
+        is_copy: bool,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.expected_bucket_owner {
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_EXPECTED_BUCKET_OWNER),
+                v,
+            );
+            // Copy sources always live in the same bucket.
+            if is_copy {
+                req = req.header(
+                    HeaderName::from_static(constants::X_AMZ_SOURCE_EXPECTED_BUCKET_OWNER),
+                    v,
+                );
+            }
+        }
+        req
+    }
 }
 
 impl S3Core {
@@ -447,6 +696,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Inject operation to the request.
         req = req
@@ -525,8 +775,14 @@ impl S3Core {
             req = req.header(IF_UNMODIFIED_SINCE, if_unmodified_since.format_http_date());
         }
 
+        // Ask s3 to return the full object checksum for verification.
+        if args.verify_checksum() {
+            req = req.header(constants::X_AMZ_CHECKSUM_MODE, "ENABLED");
+        }
+
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         // TODO: how will this work with presign?
@@ -570,6 +826,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -615,6 +872,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -642,7 +900,20 @@ impl S3Core {
         path: &str,
         args: OpStat,
     ) -> Result<Response<Buffer>> {
-        let req = self.s3_head_object_request(path, args)?;
+        let checksum = args.checksum();
+        let mut req = self.s3_head_object_request(path, args)?;
+        // Ask s3 to return the full object checksum only if requested, it
+        // requires `kms:Decrypt` for SSE-KMS objects and some s3 compatible
+        // services reject this header.
+        //
+        // This is not part of `s3_head_object_request` so that presigned
+        // requests are not required to carry this header.
+        if checksum {
+            req.headers_mut().insert(
+                constants::X_AMZ_CHECKSUM_MODE,
+                HeaderValue::from_static("ENABLED"),
+            );
+        }
         self.send(ctx, req).await
     }
 
@@ -674,6 +945,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             // Inject operation to the request.
@@ -695,7 +967,7 @@ impl S3Core {
         let from = build_abs_path(&self.root, from);
         let to = build_abs_path(&self.root, to);
 
-        let source = format!("{}/{}", self.bucket, percent_encode_path(&from));
+        let source = self.copy_source(&from);
         let source = if let Some(version) = args.source_version() {
             QueryPairsWriter::new(&source)
                 .push(
@@ -759,6 +1031,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, true);
 
         let req = req
             // Inject operation to the request.
@@ -800,6 +1073,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             // Inject operation to the request.
@@ -852,6 +1126,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             // Inject operation to the request.
@@ -863,12 +1138,11 @@ impl S3Core {
         self.send(ctx, req).await
     }
 
-    pub async fn s3_initiate_multipart_upload(
+    pub fn s3_initiate_multipart_upload_request(
         &self,
-        ctx: &OperationContext,
         path: &str,
         args: &OpWrite,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
@@ -910,6 +1184,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -931,7 +1206,16 @@ impl S3Core {
             .extension(Operation::Write)
             .extension(ServiceOperation("CreateMultipartUpload"));
 
-        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn s3_initiate_multipart_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let req = self.s3_initiate_multipart_upload_request(path, args)?;
 
         self.send(ctx, req).await
     }
@@ -959,6 +1243,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -979,7 +1264,7 @@ impl S3Core {
         let from = build_abs_path(&self.root, input.from);
         let to = build_abs_path(&self.root, input.to);
 
-        let source = format!("{}/{}", self.bucket, percent_encode_path(&from));
+        let source = self.copy_source(&from);
         let source = if let Some(version) = input.source_version {
             QueryPairsWriter::new(&source)
                 .push(
@@ -1042,6 +1327,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, true);
 
         let req = req
             .extension(Operation::Copy)
@@ -1059,7 +1345,7 @@ impl S3Core {
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
         checksum: Option<String>,
     ) -> Result<Request<Buffer>> {
@@ -1075,10 +1361,13 @@ impl S3Core {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
@@ -1099,14 +1388,17 @@ impl S3Core {
         Ok(req)
     }
 
-    pub async fn s3_complete_multipart_upload(
+    /// Build the CompleteMultipartUpload request with the serialized parts list as body.
+    ///
+    /// An empty body leaves `Content-Length` unset so that the request can be presigned
+    /// before the parts list is known.
+    pub fn s3_complete_multipart_upload_request(
         &self,
-        ctx: &OperationContext,
         path: &str,
         upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
+        body: Buffer,
         args: &OpWrite,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -1121,10 +1413,10 @@ impl S3Core {
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
-        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
-            .map_err(new_xml_serialize_error)?;
         // Make sure content length has been set to avoid post with chunked encoding.
-        req = req.header(CONTENT_LENGTH, content.len());
+        if !body.is_empty() {
+            req = req.header(CONTENT_LENGTH, body.len());
+        }
         // Set content-type to `application/xml` to avoid mixed with form post.
         req = req.header(CONTENT_TYPE, "application/xml");
 
@@ -1138,15 +1430,32 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Inject operation to the request.
         req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("CompleteMultipartUpload"));
 
-        let req = req
-            .body(Buffer::from(Bytes::from(content)))
-            .map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn s3_complete_multipart_upload(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<CompleteMultipartUploadRequestPart>,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
+            .map_err(new_xml_serialize_error)?;
+        let req = self.s3_complete_multipart_upload_request(
+            path,
+            upload_id,
+            Buffer::from(Bytes::from(content)),
+            args,
+        )?;
 
         self.send(ctx, req).await
     }
@@ -1189,6 +1498,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             .extension(Operation::Copy)
@@ -1219,6 +1529,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             // Inject operation to the request.
@@ -1250,6 +1561,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             .extension(Operation::Copy)
@@ -1290,6 +1602,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         // Inject operation to the request.
         req = req
@@ -1303,6 +1616,52 @@ impl S3Core {
         self.send(ctx, req).await
     }
 
+    /// Restore an archived object, such as objects in `GLACIER` or `DEEP_ARCHIVE`.
+    ///
+    /// ref: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_RestoreObject.html>
+    pub async fn s3_restore_object(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::post(&url);
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            // Days is required to restore objects in archive storage classes.
+            days: args.days().unwrap_or(1),
+            glacier_job_parameters: args.tier().map(|tier| GlacierJobParameters {
+                tier: tier.to_string(),
+            }),
+        })
+        .map_err(new_xml_serialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        req = req.header(CONTENT_LENGTH, content.len());
+        // Set content-type to `application/xml` to avoid mixed with form post.
+        req = req.header(CONTENT_TYPE, "application/xml");
+        req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
+
+        // Inject operation to the request.
+        req = req
+            .extension(Operation::Restore)
+            .extension(ServiceOperation("RestoreObject"));
+
+        let req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.send(ctx, req).await
+    }
+
     pub async fn s3_list_object_versions(
         &self,
         ctx: &OperationContext,
@@ -1343,6 +1702,7 @@ impl S3Core {
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
+        req = self.insert_expected_bucket_owner_header(req, false);
 
         let req = req
             // Inject operation to the request.
@@ -1362,6 +1722,42 @@ pub struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Request of CreateBucket.
+#[derive(Default, Debug, Serialize)]
+#[serde(rename = "CreateBucketConfiguration", rename_all = "PascalCase")]
+pub struct CreateBucketConfiguration {
+    pub location_constraint: String,
+}
+
+/// Request of PutBucketLifecycleConfiguration.
+#[derive(Default, Debug, Serialize)]
+#[serde(rename = "LifecycleConfiguration", rename_all = "PascalCase")]
+pub struct LifecycleConfiguration {
+    pub rule: Vec<LifecycleRule>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleRule {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub filter: LifecycleRuleFilter,
+    pub status: String,
+    pub expiration: LifecycleExpiration,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleRuleFilter {
+    pub prefix: String,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleExpiration {
+    pub days: u32,
+}
+
 /// Request of CompleteMultipartUploadRequest
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
@@ -1453,6 +1849,21 @@ pub struct DeleteObjectsRequestObject {
     pub version_id: Option<String>,
 }
 
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glacier_job_parameters: Option<GlacierJobParameters>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GlacierJobParameters {
+    pub tier: String,
+}
+
 /// Result of DeleteObjects.
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
@@ -1583,11 +1994,49 @@ impl Display for ChecksumAlgorithm {
 
 #[cfg(test)]
 mod tests {
-    use bytes::Buf;
-    use bytes::Bytes;
-
     use super::*;
 
+    #[test]
+    fn test_parse_checksum() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(parse_checksum(&headers).unwrap(), None);
+
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_SHA256,
+            HeaderValue::from_static("3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8="),
+        );
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_CRC32C,
+            HeaderValue::from_static("TVUQaA=="),
+        );
+        assert_eq!(
+            parse_checksum(&headers).unwrap(),
+            Some(Checksum::new(
+                opendal_core::ChecksumAlgorithm::Crc32c,
+                "TVUQaA=="
+            ))
+        );
+
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_TYPE,
+            HeaderValue::from_static("COMPOSITE"),
+        );
+        assert_eq!(parse_checksum(&headers).unwrap(), None);
+
+        headers.remove(constants::X_AMZ_CHECKSUM_TYPE);
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_CRC32C,
+            HeaderValue::from_static("Njm6Qw==-3"),
+        );
+        assert_eq!(
+            parse_checksum(&headers).unwrap(),
+            Some(Checksum::new(
+                opendal_core::ChecksumAlgorithm::Sha256,
+                "3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8="
+            ))
+        );
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateMultipartUpload.html#API_CreateMultipartUpload_Examples
     #[test]
     fn test_deserialize_initiate_multipart_upload_result() {
@@ -1740,6 +2189,88 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_serialize_create_bucket_configuration() {
+        let req = CreateBucketConfiguration {
+            location_constraint: "eu-west-1".to_string(),
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<CreateBucketConfiguration><LocationConstraint>eu-west-1</LocationConstraint></CreateBucketConfiguration>"#
+        )
+    }
+
+    #[test]
+    fn test_serialize_lifecycle_configuration() {
+        let req = LifecycleConfiguration {
+            rule: vec![LifecycleRule {
+                id: "opendal-expiration".to_string(),
+                filter: LifecycleRuleFilter {
+                    prefix: "tmp/".to_string(),
+                },
+                status: "Enabled".to_string(),
+                expiration: LifecycleExpiration { days: 1 },
+            }],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<LifecycleConfiguration>
+             <Rule>
+               <ID>opendal-expiration</ID>
+               <Filter><Prefix>tmp/</Prefix></Filter>
+               <Status>Enabled</Status>
+               <Expiration><Days>1</Days></Expiration>
+             </Rule>
+             </LifecycleConfiguration>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
+    #[test]
+    fn test_ensure_bucket_location_constraint() {
+        let cases = vec![
+            ("us-east-1", None),
+            ("auto", None),
+            ("eu-west-1", Some("eu-west-1")),
+        ];
+
+        for (region, expected) in cases {
+            let ensure = S3EnsureBucket::new(region, None);
+            assert_eq!(ensure.location_constraint.as_deref(), expected, "{region}");
+        }
+    }
+
+    #[test]
+    fn test_serialize_restore_request() {
+        let req = RestoreRequest {
+            days: 2,
+            glacier_job_parameters: Some(GlacierJobParameters {
+                tier: "Bulk".to_string(),
+            }),
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<RestoreRequest>
+             <Days>2</Days>
+             <GlacierJobParameters>
+               <Tier>Bulk</Tier>
+             </GlacierJobParameters>
+             </RestoreRequest>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
     #[test]
     fn test_deserialize_delete_objects_result() {
@@ -2096,6 +2627,10 @@ mod error {
             // Although the status code is 404, NoSuchBucket is
             // a config invalid error, and it's not retryable from OpenDAL.
             "NoSuchBucket" => Some((ErrorKind::ConfigInvalid, false)),
+            // > The requested bucket name is not available.
+            //
+            // The bucket is owned by someone else, retrying won't help.
+            "BucketAlreadyExists" => Some((ErrorKind::AlreadyExists, false)),
             // > Your socket connection to the server was not read from
             // > or written to within the timeout period."
             //
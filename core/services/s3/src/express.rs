@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use http::Method;
+use http::Request;
+use log::debug;
+use reqsign_aws_v4::Credential;
+use reqsign_aws_v4::RequestSigner as AwsV4Signer;
+use reqsign_core::Context;
+use reqsign_core::ProvideCredential;
+use reqsign_core::ProvideCredentialChain;
+use reqsign_core::SignRequest;
+use serde::Deserialize;
+
+/// The service name used to sign requests to directory buckets.
+pub const S3_EXPRESS_SIGNING_NAME: &str = "s3express";
+
+/// A directory bucket of S3 Express One Zone.
+///
+/// Directory buckets are named like `{base_name}--{zone_id}--x-s3`.
+///
+/// ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/directory-bucket-naming-rules.html>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3ExpressBucket {
+    pub zone_id: String,
+}
+
+impl S3ExpressBucket {
+    /// Parse the given bucket as a directory bucket.
+    ///
+    /// Returns `None` if the bucket is a general purpose bucket.
+    pub fn parse(bucket: &str) -> Option<Self> {
+        let base = bucket.strip_suffix("--x-s3")?;
+        let (name, zone_id) = base.rsplit_once("--")?;
+        if name.is_empty() || zone_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            zone_id: zone_id.to_string(),
+        })
+    }
+
+    /// The zonal endpoint of this bucket, like
+    /// `https://{bucket}.s3express-{zone_id}.{region}.amazonaws.com`.
+    pub fn endpoint(&self, bucket: &str, region: &str) -> String {
+        format!(
+            "https://{bucket}.s3express-{}.{region}.amazonaws.com",
+            self.zone_id
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CreateSessionOutput {
+    credentials: SessionCredentials,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SessionCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: String,
+}
+
+/// Provide session credentials of a directory bucket via `CreateSession`.
+///
+/// `CreateSession` is signed with the credentials loaded by `base`. Sessions
+/// expire after 5 minutes, the signer caches them and calls this provider again
+/// before they run out.
+///
+/// ref: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateSession.html>
+#[derive(Debug)]
+pub struct S3ExpressSessionProvider {
+    endpoint: String,
+    signer: AwsV4Signer,
+    base: ProvideCredentialChain<Credential>,
+}
+
+impl S3ExpressSessionProvider {
+    pub fn new(endpoint: &str, region: &str, base: ProvideCredentialChain<Credential>) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            signer: AwsV4Signer::new(S3_EXPRESS_SIGNING_NAME, region),
+            base,
+        }
+    }
+}
+
+impl ProvideCredential for S3ExpressSessionProvider {
+    type Credential = Credential;
+
+    async fn provide_credential(&self, ctx: &Context) -> reqsign_core::Result<Option<Credential>> {
+        let Some(base) = self.base.provide_credential(ctx).await? else {
+            return Err(reqsign_core::Error::credential_invalid(
+                "no credential found to create s3 express session",
+            ));
+        };
+
+        let url = format!("{}/?session", self.endpoint);
+        debug!("creating s3 express session: {url}");
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(&url)
+            .header("x-amz-create-session-mode", "ReadWrite")
+            .body(Bytes::new())
+            .map_err(|e| {
+                reqsign_core::Error::unexpected("failed to build create session request")
+                    .with_source(e)
+            })?;
+        let (mut parts, body) = req.into_parts();
+        self.signer
+            .sign_request(ctx, &mut parts, Some(&base), None)
+            .await?;
+
+        let resp = ctx.http_send(Request::from_parts(parts, body)).await?;
+        if !resp.status().is_success() {
+            return Err(reqsign_core::Error::unexpected(format!(
+                "create s3 express session failed with status {}: {}",
+                resp.status(),
+                String::from_utf8_lossy(resp.body())
+            )));
+        }
+
+        parse_create_session_output(resp.body()).map(Some)
+    }
+}
+
+fn parse_create_session_output(bs: &[u8]) -> reqsign_core::Result<Credential> {
+    let output: CreateSessionOutput = quick_xml::de::from_reader(bs).map_err(|e| {
+        reqsign_core::Error::unexpected("failed to parse create session output").with_source(e)
+    })?;
+    let creds = output.credentials;
+    let expires_in = creds.expiration.parse().map_err(|e| {
+        reqsign_core::Error::unexpected("failed to parse s3 express session expiration")
+            .with_source(e)
+    })?;
+
+    Ok(Credential {
+        access_key_id: creds.access_key_id,
+        secret_access_key: creds.secret_access_key,
+        session_token: Some(creds.session_token),
+        expires_in: Some(expires_in),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bucket() {
+        assert_eq!(S3ExpressBucket::parse("my-bucket"), None);
+        assert_eq!(S3ExpressBucket::parse("--usw2-az1--x-s3"), None);
+        assert_eq!(S3ExpressBucket::parse("my-bucket--x-s3"), None);
+
+        let bucket = S3ExpressBucket::parse("my-bucket--usw2-az1--x-s3").unwrap();
+        assert_eq!(bucket.zone_id, "usw2-az1");
+        assert_eq!(
+            bucket.endpoint("my-bucket--usw2-az1--x-s3", "us-west-2"),
+            "https://my-bucket--usw2-az1--x-s3.s3express-usw2-az1.us-west-2.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn test_parse_create_session_output() {
+        let bs = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CreateSessionResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Credentials>
+    <SessionToken>token</SessionToken>
+    <SecretAccessKey>secret</SecretAccessKey>
+    <AccessKeyId>access</AccessKeyId>
+    <Expiration>2024-01-01T00:05:00Z</Expiration>
+  </Credentials>
+</CreateSessionResult>"#;
+
+        let cred = parse_create_session_output(bs.as_bytes()).unwrap();
+        assert_eq!(cred.access_key_id, "access");
+        assert_eq!(cred.secret_access_key, "secret");
+        assert_eq!(cred.session_token.as_deref(), Some("token"));
+        assert_eq!(
+            cred.expires_in,
+            Some("2024-01-01T00:05:00Z".parse().unwrap())
+        );
+    }
+}
@@ -19,11 +19,13 @@
 //! Amazon S3 service implementation for Apache OpenDAL.
 #![deny(missing_docs)]
 
+mod arn;
 mod backend;
 mod config;
 mod copier;
 mod core;
 mod deleter;
+mod express;
 mod lister;
 mod reader;
 mod writer;
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::backend::*;
+use crate::core::parse_checksum;
 use crate::core::parse_error;
 use http::Response;
 use http::StatusCode;
@@ -55,10 +56,13 @@ impl oio::StreamRead for S3Reader {
 
         let status = resp.status();
         let (rp, stream) = match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => (
-                RpRead::new(parse_into_metadata(path, resp.headers())?),
-                resp.into_body(),
-            ),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                if let Some(v) = parse_checksum(resp.headers())? {
+                    meta.set_checksum(v);
+                }
+                (RpRead::new(meta), resp.into_body())
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
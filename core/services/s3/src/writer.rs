@@ -22,6 +22,7 @@ use constants::X_AMZ_OBJECT_SIZE;
 use constants::X_AMZ_VERSION_ID;
 use http::StatusCode;
 
+use crate::core::ChecksumAlgorithm;
 use crate::core::S3Error;
 use crate::core::from_s3_error;
 use crate::core::parse_error;
@@ -62,6 +63,9 @@ impl S3Writer {
         {
             meta.set_content_length(value);
         }
+        if let Some(checksum) = parse_checksum(headers)? {
+            meta.set_checksum(checksum);
+        }
         Ok(meta)
     }
 }
@@ -121,7 +125,7 @@ impl oio::MultipartWrite for S3Writer {
             &self.path,
             upload_id,
             part_number,
-            size,
+            Some(size),
             body,
             checksum.clone(),
         )?;
@@ -158,6 +158,7 @@ impl Builder for SeafileBuilder {
                 info: ServiceInfo::new(SEAFILE_SCHEME, &root, ""),
                 capability: Capability {
                     create_dir: true,
+                    create_dir_persistent: true,
                     stat: true,
 
                     read: true,
@@ -25,6 +25,7 @@ use openssh::KnownHosts;
 use super::SFTP_SCHEME;
 use super::config::SftpConfig;
 use super::core::SftpCore;
+use super::core::SymlinkBehavior;
 use super::core::is_sftp_protocol_error;
 use super::core::parse_sftp_error;
 use super::core::to_metadata;
@@ -110,6 +111,49 @@ impl SftpBuilder {
         self
     }
 
+    /// Sets the maximum number of connections managed by the pool.
+    ///
+    /// Defaults to 64.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `max_size` is 0.
+    #[must_use]
+    pub fn connection_pool_max_size(mut self, max_size: usize) -> Self {
+        assert!(max_size > 0, "max_size must be greater than zero!");
+        self.config.connection_pool_max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the interval to send keepalive messages to the server (`ssh -o ServerAliveInterval`).
+    ///
+    /// Keepalive keeps idle connections in the pool from being dropped by the server
+    /// or firewalls. Any sub-second remainder will be ignored.
+    pub fn keepalive_interval(mut self, interval: Duration) -> Self {
+        self.config.keepalive_interval = Some(interval);
+        self
+    }
+
+    /// Set how symlinks are handled during list.
+    ///
+    /// Available values:
+    ///
+    /// - `link`: Return symlinks as [`EntryMode::LINK`] entries, with targets in
+    ///   [`Metadata::link_target`].
+    /// - `follow`: Return symlinks as the files or dirs they point to. Dangling
+    ///   links are skipped. Recursive list will walk into linked dirs, make sure
+    ///   there are no cycles.
+    /// - `skip`: Don't return symlinks.
+    ///
+    /// Default to `link`. `stat` and `read` always follow symlinks.
+    pub fn symlink_behavior(mut self, behavior: &str) -> Self {
+        if !behavior.is_empty() {
+            self.config.symlink_behavior = Some(behavior.to_string());
+        }
+
+        self
+    }
+
     /// Deprecated: SFTP copy capability is enabled by default.
     #[deprecated(
         since = "0.57.0",
@@ -125,6 +169,7 @@ impl Builder for SftpBuilder {
 
     fn build(self) -> Result<impl Service> {
         debug!("sftp backend build started: {:?}", &self);
+        let symlink_behavior = SymlinkBehavior::try_from(&self.config.symlink_behavior)?;
         let endpoint = match self.config.endpoint.clone() {
             Some(v) => v,
             None => return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")),
@@ -169,6 +214,7 @@ impl Builder for SftpBuilder {
             write_with_if_not_exists: true,
 
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
 
             list: true,
@@ -190,6 +236,9 @@ impl Builder for SftpBuilder {
             user,
             self.config.key.clone(),
             known_hosts_strategy,
+            self.config.connection_pool_max_size,
+            self.config.keepalive_interval,
+            symlink_behavior,
         ));
 
         debug!("sftp backend finished: {:?}", &self);
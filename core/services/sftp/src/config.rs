@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 
+use opendal_core::raw::*;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -37,6 +38,18 @@ pub struct SftpConfig {
     pub key: Option<String>,
     /// known_hosts_strategy of this backend
     pub known_hosts_strategy: Option<String>,
+    /// The maximum number of connections allowed.
+    ///
+    /// default is 64
+    pub connection_pool_max_size: Option<usize>,
+    /// The interval to send keepalive messages to the server.
+    ///
+    /// default is disabled
+    pub keepalive_interval: Option<Duration>,
+    /// How to handle symlinks during list.
+    ///
+    /// Available values: `link`, `follow`, `skip`, default to `link`.
+    pub symlink_behavior: Option<String>,
     /// Deprecated: SFTP copy capability is enabled by default.
     #[deprecated(
         since = "0.57.0",
@@ -50,6 +63,8 @@ impl Debug for SftpConfig {
         f.debug_struct("SftpConfig")
             .field("endpoint", &self.endpoint)
             .field("root", &self.root)
+            .field("connection_pool_max_size", &self.connection_pool_max_size)
+            .field("keepalive_interval", &self.keepalive_interval)
             .finish_non_exhaustive()
     }
 }
@@ -102,6 +117,7 @@ mod tests {
                 ("user".to_string(), "alice".to_string()),
                 ("key".to_string(), "/home/alice/.ssh/id_rsa".to_string()),
                 ("known_hosts_strategy".to_string(), "accept".to_string()),
+                ("connection_pool_max_size".to_string(), "8".to_string()),
             ],
         )
         .unwrap();
@@ -111,5 +127,6 @@ mod tests {
         assert_eq!(cfg.user.as_deref(), Some("alice"));
         assert_eq!(cfg.key.as_deref(), Some("/home/alice/.ssh/id_rsa"));
         assert_eq!(cfg.known_hosts_strategy.as_deref(), Some("accept"));
+        assert_eq!(cfg.connection_pool_max_size, Some(8));
     }
 }
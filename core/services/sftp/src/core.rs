@@ -33,9 +33,39 @@ pub struct SftpCore {
     pub capability: Capability,
     pub endpoint: String,
     pub root: String,
+    pub symlink_behavior: SymlinkBehavior,
     client: Arc<bounded::Pool<Manager>>,
 }
 
+/// How symlinks are handled during list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkBehavior {
+    /// Return symlinks as [`EntryMode::LINK`] entries with their targets.
+    Link,
+    /// Return symlinks as the entries they point to, dangling links are skipped.
+    Follow,
+    /// Don't return symlinks.
+    Skip,
+}
+
+impl TryFrom<&Option<String>> for SymlinkBehavior {
+    type Error = Error;
+
+    fn try_from(value: &Option<String>) -> Result<Self> {
+        match value.as_deref() {
+            None | Some("link") => Ok(SymlinkBehavior::Link),
+            Some("follow") => Ok(SymlinkBehavior::Follow),
+            Some("skip") => Ok(SymlinkBehavior::Skip),
+            Some(v) => Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "Invalid symlink behavior, available: `link`, `follow`, `skip`",
+            )
+            .with_context("service", super::SFTP_SCHEME)
+            .with_context("symlink_behavior", v)),
+        }
+    }
+}
+
 impl Debug for SftpCore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SftpCore")
@@ -46,6 +76,7 @@ impl Debug for SftpCore {
 }
 
 impl SftpCore {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         info: ServiceInfo,
         capability: Capability,
@@ -54,15 +85,19 @@ impl SftpCore {
         user: Option<String>,
         key: Option<String>,
         known_hosts_strategy: KnownHosts,
+        connection_pool_max_size: Option<usize>,
+        keepalive_interval: Option<Duration>,
+        symlink_behavior: SymlinkBehavior,
     ) -> Self {
         let client = bounded::Pool::new(
-            bounded::PoolConfig::new(64),
+            bounded::PoolConfig::new(connection_pool_max_size.unwrap_or(64)),
             Manager {
                 endpoint: endpoint.clone(),
                 root: root.clone(),
                 user,
                 key,
                 known_hosts_strategy,
+                keepalive_interval,
             },
         );
 
@@ -71,6 +106,7 @@ impl SftpCore {
             capability,
             endpoint,
             root,
+            symlink_behavior,
             client,
         }
     }
@@ -96,6 +132,7 @@ pub struct Manager {
     user: Option<String>,
     key: Option<String>,
     known_hosts_strategy: KnownHosts,
+    keepalive_interval: Option<Duration>,
 }
 
 impl ManageObject for Manager {
@@ -115,6 +152,10 @@ impl ManageObject for Manager {
 
         session.known_hosts_check(self.known_hosts_strategy.clone());
 
+        if let Some(interval) = self.keepalive_interval {
+            session.server_alive_interval(interval);
+        }
+
         let session = session
             .connect(&self.endpoint)
             .await
@@ -148,6 +189,9 @@ impl ManageObject for Manager {
     }
 
     // Check if connect valid by checking the root path.
+    //
+    // Connections dropped by the server fail this check, and the pool will create
+    // a new connection to replace them.
     async fn is_recyclable(
         &self,
         o: &mut Self::Object,
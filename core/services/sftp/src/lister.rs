@@ -19,26 +19,69 @@ use std::pin::Pin;
 
 use futures::StreamExt;
 use openssh_sftp_client::fs::DirEntry;
+use openssh_sftp_client::fs::Fs;
 use openssh_sftp_client::fs::ReadDir;
 
+use super::core::SymlinkBehavior;
+use super::core::is_not_found;
 use super::core::parse_sftp_error;
 use super::core::to_metadata;
+use opendal_core::EntryMode;
 use opendal_core::Result;
 use opendal_core::raw::oio;
 use opendal_core::raw::oio::Entry;
 
 pub struct SftpLister {
     dir: Pin<Box<ReadDir>>,
+    /// Used to resolve symlinks, its cwd is the root of the backend.
+    fs: Fs,
     prefix: String,
+    symlink_behavior: SymlinkBehavior,
 }
 
 impl SftpLister {
-    pub fn new(dir: ReadDir, path: String) -> Self {
+    pub fn new(dir: ReadDir, fs: Fs, path: String, symlink_behavior: SymlinkBehavior) -> Self {
         let prefix = if path == "/" { "".to_owned() } else { path };
 
         SftpLister {
             dir: Box::pin(dir),
+            fs,
             prefix,
+            symlink_behavior,
+        }
+    }
+
+    /// Map a symlink entry by the configured behavior, `None` means the entry
+    /// should be skipped.
+    async fn map_symlink(&mut self, value: DirEntry) -> Result<Option<Entry>> {
+        let name = value.filename().to_str().unwrap();
+        let rel_path = format!("./{}{}", self.prefix, name);
+
+        match self.symlink_behavior {
+            SymlinkBehavior::Skip => Ok(None),
+            SymlinkBehavior::Follow => {
+                let meta = match self.fs.metadata(&rel_path).await {
+                    Ok(meta) => to_metadata(meta),
+                    // Dangling links have nothing to follow.
+                    Err(e) if is_not_found(&e) => return Ok(None),
+                    Err(e) => return Err(parse_sftp_error(e)),
+                };
+                let suffix = if meta.is_dir() { "/" } else { "" };
+                let path = format!("{}{}{}", self.prefix, name, suffix);
+                Ok(Some(Entry::new(&path, meta)))
+            }
+            SymlinkBehavior::Link => {
+                let target = match self.fs.read_link(&rel_path).await {
+                    Ok(target) => target,
+                    Err(e) if is_not_found(&e) => return Ok(None),
+                    Err(e) => return Err(parse_sftp_error(e)),
+                };
+                let mut meta = to_metadata(value.metadata());
+                meta.set_mode(EntryMode::LINK)
+                    .set_link_target(&target.to_string_lossy());
+                let path = format!("{}{}", self.prefix, name);
+                Ok(Some(Entry::new(&path, meta)))
+            }
         }
     }
 }
@@ -63,6 +106,11 @@ impl oio::List for SftpLister {
                             path = "/";
                         }
                         return Ok(Some(Entry::new(path, to_metadata(e.metadata()))));
+                    } else if e.file_type().is_some_and(|ft| ft.is_symlink()) {
+                        match self.map_symlink(e).await? {
+                            Some(entry) => return Ok(Some(entry)),
+                            None => continue,
+                        }
                     } else {
                         return Ok(Some(map_entry(self.prefix.as_str(), e)));
                     }
@@ -72,7 +120,6 @@ impl oio::List for SftpLister {
         }
     }
 }
-
 fn map_entry(prefix: &str, value: DirEntry) -> Entry {
     let path = format!(
         "{}{}{}",
@@ -245,7 +245,12 @@ impl oio::List for SftpLazyLister {
             let file_path = format!("./{}", self.path);
 
             self.inner = Some(match fs.open_dir(&file_path).await {
-                Ok(dir) => Some(SftpLister::new(dir.read_dir(), self.path.clone())),
+                Ok(dir) => Some(SftpLister::new(
+                    dir.read_dir(),
+                    fs,
+                    self.path.clone(),
+                    self.backend.core.symlink_behavior,
+                )),
                 Err(e) if is_not_found(&e) => None,
                 Err(e) => return Err(parse_sftp_error(e)),
             });
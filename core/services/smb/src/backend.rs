@@ -0,0 +1,322 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use log::debug;
+use smb::ClientConfig;
+use smb::UncPath;
+
+use super::SMB_SCHEME;
+use super::config::SmbConfig;
+use super::core::SmbCore;
+use super::core::split_endpoint;
+use super::deleter::SmbDeleter;
+use super::lister::SmbLister;
+use super::reader::SmbReader;
+use super::writer::SmbWriter;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct SmbBuilder {
+    pub(super) config: SmbConfig,
+}
+
+impl SmbBuilder {
+    /// set endpoint for smb backend.
+    ///
+    /// The format is `host[:port]`, the port is default to `445`.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.to_string())
+        };
+
+        self
+    }
+
+    /// set share name to connect for smb backend.
+    pub fn share(mut self, share: &str) -> Self {
+        self.config.share = if share.is_empty() {
+            None
+        } else {
+            Some(share.to_string())
+        };
+
+        self
+    }
+
+    /// set root path for smb backend.
+    ///
+    /// The root is relative to the share.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// set user for smb backend.
+    pub fn user(mut self, user: &str) -> Self {
+        self.config.user = if user.is_empty() {
+            None
+        } else {
+            Some(user.to_string())
+        };
+
+        self
+    }
+
+    /// set password for smb backend.
+    pub fn password(mut self, password: &str) -> Self {
+        self.config.password = if password.is_empty() {
+            None
+        } else {
+            Some(password.to_string())
+        };
+
+        self
+    }
+
+    /// set domain of the user for smb backend.
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.config.domain = if domain.is_empty() {
+            None
+        } else {
+            Some(domain.to_string())
+        };
+
+        self
+    }
+
+    /// set authentication method for smb backend.
+    ///
+    /// available values:
+    /// - ntlm (default)
+    /// - kerberos (requires the `kerberos` feature)
+    pub fn auth_method(mut self, method: &str) -> Self {
+        self.config.auth_method = if method.is_empty() {
+            None
+        } else {
+            Some(method.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for SmbBuilder {
+    type Config = SmbConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("smb backend build started: {:?}", &self);
+        let endpoint = match self.config.endpoint.clone() {
+            Some(v) => v,
+            None => return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")),
+        };
+        let (host, port) = split_endpoint(&endpoint)?;
+
+        let share = match self.config.share.as_deref().map(|v| v.trim_matches('/')) {
+            Some(v) if !v.is_empty() => v.to_string(),
+            _ => return Err(Error::new(ErrorKind::ConfigInvalid, "share is empty")),
+        };
+        let unc = UncPath::new(host)
+            .and_then(|v| v.with_share(&share))
+            .map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "endpoint or share is invalid")
+                    .with_context("endpoint", &endpoint)
+                    .with_context("share", &share)
+                    .set_source(err)
+            })?;
+
+        let user = match (self.config.domain.as_deref(), self.config.user.as_deref()) {
+            (_, None) => return Err(Error::new(ErrorKind::ConfigInvalid, "user is empty")),
+            (Some(domain), Some(user)) => format!("{domain}\\{user}"),
+            (None, Some(user)) => user.to_string(),
+        };
+        let password = self.config.password.clone().unwrap_or_default();
+
+        let mut config = ClientConfig::default();
+        config.connection.port = port;
+        match self
+            .config
+            .auth_method
+            .as_deref()
+            .map(|v| v.to_lowercase())
+            .as_deref()
+        {
+            None | Some("ntlm") => {
+                config.connection.auth_methods.ntlm = true;
+                config.connection.auth_methods.kerberos = false;
+            }
+            Some("kerberos") if cfg!(feature = "kerberos") => {
+                config.connection.auth_methods.ntlm = false;
+                config.connection.auth_methods.kerberos = true;
+            }
+            Some("kerberos") => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "kerberos auth requires the kerberos feature to be enabled",
+                ));
+            }
+            Some(v) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    format!("unknown auth method: {v}"),
+                ));
+            }
+        }
+
+        let root = normalize_root(self.config.root.as_deref().unwrap_or_default());
+
+        let info = ServiceInfo::new(SMB_SCHEME, root.as_str(), share.as_str());
+        let capability = Capability {
+            stat: true,
+
+            read: true,
+
+            write: true,
+            write_can_empty: true,
+            write_can_multi: true,
+            write_can_append: true,
+            write_with_if_not_exists: true,
+
+            create_dir: true,
+            create_dir_persistent: true,
+            delete: true,
+
+            list: true,
+
+            rename: true,
+
+            shared: true,
+
+            ..Default::default()
+        };
+
+        let core = Arc::new(SmbCore::new(
+            info, capability, endpoint, root, unc, user, password, config,
+        ));
+
+        debug!("smb backend finished: {:?}", &self);
+        Ok(SmbBackend { core })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SmbBackend {
+    pub core: Arc<SmbCore>,
+}
+
+impl Service for SmbBackend {
+    type Reader = oio::StreamReader<SmbReader>;
+    type Writer = SmbWriter;
+    type Lister = SmbLister;
+    type Deleter = oio::OneShotDeleter<SmbDeleter>;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        path: &str,
+        _: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.core.create_dir_all(path).await?;
+
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, _ctx: &OperationContext, path: &str, _: OpStat) -> Result<RpStat> {
+        let meta = self.core.stat(path).await?;
+        if path.ends_with('/') && !meta.is_dir() {
+            return Err(Error::new(ErrorKind::NotFound, "file is not a directory"));
+        }
+
+        Ok(RpStat::new(meta))
+    }
+
+    fn read(&self, _ctx: &OperationContext, path: &str, _: OpRead) -> Result<Self::Reader> {
+        Ok(oio::StreamReader::new(SmbReader::new(
+            self.core.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, path: &str, op: OpWrite) -> Result<Self::Writer> {
+        Ok(SmbWriter::new(self.core.clone(), path, op))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(oio::OneShotDeleter::new(SmbDeleter::new(self.core.clone())))
+    }
+
+    fn list(&self, _ctx: &OperationContext, path: &str, _: OpList) -> Result<Self::Lister> {
+        Ok(SmbLister::new(self.core.clone(), path))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        self.core.rename(from, to).await?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::SmbBuilder;
+
+/// Config for SMB service support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct SmbConfig {
+    /// endpoint of this backend, in the form of `host[:port]`.
+    ///
+    /// The port is default to `445`.
+    pub endpoint: Option<String>,
+    /// share name on the server to connect.
+    pub share: Option<String>,
+    /// root of this backend, relative to the share.
+    pub root: Option<String>,
+    /// user of this backend.
+    pub user: Option<String>,
+    /// password of this backend.
+    pub password: Option<String>,
+    /// domain of the user, only used by NTLM authentication.
+    pub domain: Option<String>,
+    /// authentication method of this backend, default to `ntlm`.
+    ///
+    /// available values:
+    /// - ntlm
+    /// - kerberos (requires the `kerberos` feature)
+    pub auth_method: Option<String>,
+}
+
+impl Debug for SmbConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmbConfig")
+            .field("endpoint", &self.endpoint)
+            .field("share", &self.share)
+            .field("root", &self.root)
+            .field("user", &self.user)
+            .field("domain", &self.domain)
+            .field("auth_method", &self.auth_method)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for SmbConfig {
+    type Builder = SmbBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(authority) = uri.authority() {
+            map.insert("endpoint".to_string(), authority.to_string());
+        }
+
+        // The first segment of the path is the share, the rest is the root.
+        if let Some(path) = uri.root() {
+            let (share, root) = path.split_once('/').unwrap_or((path, ""));
+            if !share.is_empty() {
+                map.insert("share".to_string(), share.to_string());
+            }
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        SmbBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_sets_endpoint_share_and_root() {
+        let uri = OperatorUri::new(
+            "smb://fileserver.example.com:1445/public/team/data",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = SmbConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("fileserver.example.com:1445"));
+        assert_eq!(cfg.share.as_deref(), Some("public"));
+        assert_eq!(cfg.root.as_deref(), Some("team/data"));
+    }
+
+    #[test]
+    fn from_uri_applies_auth_overrides() {
+        let uri = OperatorUri::new(
+            "smb://10.0.0.1/public",
+            vec![
+                ("user".to_string(), "alice".to_string()),
+                ("password".to_string(), "secret".to_string()),
+                ("domain".to_string(), "CORP".to_string()),
+                ("auth_method".to_string(), "kerberos".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let cfg = SmbConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.endpoint.as_deref(), Some("10.0.0.1"));
+        assert_eq!(cfg.share.as_deref(), Some("public"));
+        assert_eq!(cfg.root, None);
+        assert_eq!(cfg.user.as_deref(), Some("alice"));
+        assert_eq!(cfg.password.as_deref(), Some("secret"));
+        assert_eq!(cfg.domain.as_deref(), Some("CORP"));
+        assert_eq!(cfg.auth_method.as_deref(), Some("kerberos"));
+    }
+}
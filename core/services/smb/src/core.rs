@@ -0,0 +1,512 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use log::debug;
+use smb::Client;
+use smb::ClientConfig;
+use smb::Directory;
+use smb::File;
+use smb::FileAccessMask;
+use smb::FileAttributes;
+use smb::FileBasicInformation;
+use smb::FileCreateArgs;
+use smb::FileDirectoryInformation;
+use smb::FileDispositionInformation;
+use smb::FileRenameInformation2;
+use smb::FileStandardInformation;
+use smb::Resource;
+use smb::UncPath;
+use smb::packets::smb2::CreateDisposition;
+use smb::packets::smb2::CreateOptions;
+use tokio::sync::OnceCell;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Size of a single read or write request.
+///
+/// Servers advertise their max read and write size during negotiation, 64 KiB
+/// is the minimum that every SMB2 server must accept.
+pub const IO_CHUNK_SIZE: usize = 64 * 1024;
+
+pub struct SmbCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub endpoint: String,
+    pub root: String,
+
+    client: Client,
+    share: UncPath,
+    user: String,
+    password: String,
+    connected: OnceCell<()>,
+}
+
+impl Debug for SmbCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmbCore")
+            .field("endpoint", &self.endpoint)
+            .field("share", &self.share)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SmbCore {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        info: ServiceInfo,
+        capability: Capability,
+        endpoint: String,
+        root: String,
+        share: UncPath,
+        user: String,
+        password: String,
+        config: ClientConfig,
+    ) -> Self {
+        SmbCore {
+            info,
+            capability,
+            endpoint,
+            root,
+            client: Client::new(config),
+            share,
+            user,
+            password,
+            connected: OnceCell::new(),
+        }
+    }
+
+    /// Connect to the share and create the root if not connected yet.
+    ///
+    /// The client keeps the session and tree alive and reconnects them on
+    /// demand, so we only need to connect once.
+    async fn connect(&self) -> Result<()> {
+        self.connected
+            .get_or_try_init(|| async {
+                self.client
+                    .share_connect(&self.share, &self.user, self.password.clone())
+                    .await
+                    .map_err(parse_smb_error)?;
+                self.mkdir_all("/", &self.root).await?;
+                debug!("smb share connected at {}{}", self.share, self.root);
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Build the unc path of given absolute path.
+    fn unc_path(&self, abs_path: &str) -> UncPath {
+        let p = abs_path.trim_matches('/').replace('/', "\\");
+        if p.is_empty() {
+            self.share.clone().with_no_path()
+        } else {
+            self.share.clone().with_path(&p)
+        }
+    }
+
+    async fn create_at(&self, abs_path: &str, args: &FileCreateArgs) -> Result<Resource> {
+        self.client
+            .create_file(&self.unc_path(abs_path), args)
+            .await
+            .map_err(parse_smb_error)
+    }
+
+    async fn create(&self, path: &str, args: &FileCreateArgs) -> Result<Resource> {
+        self.connect().await?;
+        self.create_at(&build_rooted_abs_path(&self.root, path), args)
+            .await
+    }
+
+    /// Create all dirs of `path` inside `base` if not exist.
+    async fn mkdir_all(&self, base: &str, path: &str) -> Result<()> {
+        let mut current = base.to_string();
+        for name in path.split('/').filter(|v| !v.is_empty()) {
+            current.push_str(name);
+            current.push('/');
+
+            let args = FileCreateArgs {
+                disposition: CreateDisposition::OpenIf,
+                attributes: FileAttributes::new().with_directory(true),
+                options: CreateOptions::new().with_directory_file(true),
+                desired_access: FileAccessMask::new().with_file_read_attributes(true),
+            };
+            match self.create_at(&current, &args).await {
+                Ok(Resource::Directory(d)) => d.close().await.map_err(parse_smb_error)?,
+                Ok(_) => {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "path component is not a directory",
+                    )
+                    .with_context("name", name));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn stat(&self, path: &str) -> Result<Metadata> {
+        let args = FileCreateArgs::make_open_existing(
+            FileAccessMask::new().with_file_read_attributes(true),
+        );
+        let resource = self.create(path, &args).await?;
+        let handle = match &resource {
+            Resource::File(f) => &**f,
+            Resource::Directory(d) => &**d,
+            Resource::Pipe(_) => {
+                return Err(Error::new(ErrorKind::Unsupported, "path is a pipe"));
+            }
+        };
+
+        let res = async {
+            let basic = handle
+                .query_info::<FileBasicInformation>()
+                .await
+                .map_err(parse_smb_error)?;
+            let standard = handle
+                .query_info::<FileStandardInformation>()
+                .await
+                .map_err(parse_smb_error)?;
+            Ok(to_metadata(
+                basic.file_attributes.directory(),
+                standard.end_of_file,
+                *basic.last_write_time,
+            ))
+        }
+        .await;
+        handle.close().await.map_err(parse_smb_error)?;
+
+        res
+    }
+
+    pub async fn open_file(&self, path: &str) -> Result<File> {
+        let args = FileCreateArgs {
+            options: CreateOptions::new().with_non_directory_file(true),
+            ..FileCreateArgs::make_open_existing(FileAccessMask::new().with_generic_read(true))
+        };
+        match self.create(path, &args).await? {
+            Resource::File(f) => Ok(f),
+            _ => Err(Error::new(ErrorKind::IsADirectory, "path is not a file")),
+        }
+    }
+
+    /// Create a file for writing, the file will be truncated if it exists.
+    ///
+    /// - If `if_not_exists` is set, fails with `ConditionNotMatch` if file exists.
+    /// - If `append` is set, the file will be opened without truncating.
+    pub async fn create_file(&self, path: &str, if_not_exists: bool, append: bool) -> Result<File> {
+        let (parent, _) = split_parent(path);
+        self.create_dir_all(parent).await?;
+
+        let disposition = if if_not_exists {
+            CreateDisposition::Create
+        } else if append {
+            CreateDisposition::OpenIf
+        } else {
+            CreateDisposition::OverwriteIf
+        };
+        let args = FileCreateArgs {
+            disposition,
+            attributes: FileAttributes::new(),
+            options: CreateOptions::new().with_non_directory_file(true),
+            desired_access: FileAccessMask::new()
+                .with_generic_read(true)
+                .with_generic_write(true)
+                .with_delete(true),
+        };
+
+        match self.create(path, &args).await {
+            Ok(Resource::File(f)) => Ok(f),
+            Ok(_) => Err(Error::new(
+                ErrorKind::IsADirectory,
+                "cannot write to a directory",
+            )),
+            Err(err) if if_not_exists && err.kind() == ErrorKind::AlreadyExists => {
+                Err(Error::new(ErrorKind::ConditionNotMatch, "file already exists").set_source(err))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Create all dirs of given path if not exist.
+    pub async fn create_dir_all(&self, path: &str) -> Result<()> {
+        self.connect().await?;
+        self.mkdir_all(&self.root, path).await
+    }
+
+    /// List all entries of given dir, returns `None` if dir not exists.
+    pub async fn list(&self, path: &str) -> Result<Option<Vec<oio::Entry>>> {
+        let args = FileCreateArgs {
+            options: CreateOptions::new().with_directory_file(true),
+            ..FileCreateArgs::make_open_existing(FileAccessMask::new().with_generic_read(true))
+        };
+        let dir = match self.create(path, &args).await {
+            Ok(Resource::Directory(d)) => Arc::new(d),
+            Ok(_) => return Ok(None),
+            Err(err) if matches!(err.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => {
+                return Ok(None);
+            }
+            Err(err) => return Err(err),
+        };
+
+        let res = list_dir(&dir, path).await;
+        dir.close().await.map_err(parse_smb_error)?;
+
+        res.map(Some)
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<()> {
+        let options = if path.ends_with('/') {
+            CreateOptions::new().with_directory_file(true)
+        } else {
+            CreateOptions::new().with_non_directory_file(true)
+        };
+        let args = FileCreateArgs {
+            options,
+            ..FileCreateArgs::make_open_existing(FileAccessMask::new().with_delete(true))
+        };
+        let resource = self.create(path, &args).await?;
+        let handle = match &resource {
+            Resource::File(f) => &**f,
+            Resource::Directory(d) => &**d,
+            Resource::Pipe(_) => {
+                return Err(Error::new(ErrorKind::Unsupported, "path is a pipe"));
+            }
+        };
+
+        let res = handle
+            .set_info(FileDispositionInformation::default())
+            .await
+            .map_err(parse_smb_error);
+        handle.close().await.map_err(parse_smb_error)?;
+
+        res
+    }
+
+    pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let (parent, _) = split_parent(to);
+        self.create_dir_all(parent).await?;
+
+        let args = FileCreateArgs::make_open_existing(FileAccessMask::new().with_delete(true));
+        let resource = self.create(from, &args).await?;
+        let handle = match &resource {
+            Resource::File(f) => &**f,
+            Resource::Directory(d) => &**d,
+            Resource::Pipe(_) => {
+                return Err(Error::new(ErrorKind::Unsupported, "path is a pipe"));
+            }
+        };
+
+        // The new name is relative to the share root.
+        let to = self.unc_path(&build_rooted_abs_path(&self.root, to));
+        let res = handle
+            .set_info(FileRenameInformation2 {
+                replace_if_exists: true.into(),
+                root_directory: 0,
+                file_name: to.path().unwrap_or_default().into(),
+            })
+            .await
+            .map_err(parse_smb_error);
+        handle.close().await.map_err(parse_smb_error)?;
+
+        res
+    }
+}
+
+async fn list_dir(dir: &Arc<Directory>, path: &str) -> Result<Vec<oio::Entry>> {
+    let mut stream = Directory::query::<FileDirectoryInformation>(dir, "*")
+        .await
+        .map_err(parse_smb_error)?;
+
+    let mut entries = vec![];
+    while let Some(info) = stream.next().await {
+        let info = info.map_err(parse_smb_error)?;
+
+        let name = info.file_name.to_string();
+        if name == "." || name == ".." {
+            continue;
+        }
+
+        let is_dir = info.file_attributes.directory();
+        let path = if is_dir {
+            format!("{path}{name}/")
+        } else {
+            format!("{path}{name}")
+        };
+        let meta = to_metadata(is_dir, info.end_of_file, *info.last_write_time);
+        entries.push(oio::Entry::new(&path, meta));
+    }
+
+    Ok(entries)
+}
+
+/// Split path into its parent and the last component.
+pub fn split_parent(path: &str) -> (&str, &str) {
+    let path = path.trim_end_matches('/');
+    path.rsplit_once('/').unwrap_or(("", path))
+}
+
+/// Split endpoint into host and optional port.
+pub fn split_endpoint(endpoint: &str) -> Result<(&str, Option<u16>)> {
+    match endpoint.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port.parse::<u16>().map_err(|_| {
+                Error::new(ErrorKind::ConfigInvalid, "endpoint is invalid")
+                    .with_context("endpoint", endpoint)
+            })?;
+            Ok((host, Some(port)))
+        }
+        None => Ok((endpoint, None)),
+    }
+}
+
+mod error {
+    use smb::Error as SmbError;
+    use smb::packets::smb2::Status;
+
+    use opendal_core::Error;
+    use opendal_core::ErrorKind;
+
+    pub fn parse_smb_error(e: SmbError) -> Error {
+        let (kind, temporary) = match &e {
+            SmbError::ReceivedErrorMessage(status, _)
+            | SmbError::UnexpectedMessageStatus(status) => parse_smb_status(*status),
+            SmbError::MissingPermissions(_) => (ErrorKind::PermissionDenied, false),
+            SmbError::IoError(_) | SmbError::NotConnected | SmbError::OperationTimeout(..) => {
+                (ErrorKind::Unexpected, true)
+            }
+            _ => (ErrorKind::Unexpected, false),
+        };
+
+        let mut err = Error::new(kind, "smb error").set_source(e);
+        if temporary {
+            err = err.set_temporary();
+        }
+        err
+    }
+
+    /// `STATUS_NOT_A_DIRECTORY`, not defined by smb-rs.
+    const STATUS_NOT_A_DIRECTORY: u32 = 0xC000_0103;
+
+    fn parse_smb_status(status: u32) -> (ErrorKind, bool) {
+        match status {
+            Status::U32_OBJECT_NAME_NOT_FOUND | Status::U32_OBJECT_PATH_NOT_FOUND => {
+                (ErrorKind::NotFound, false)
+            }
+            Status::U32_ACCESS_DENIED | Status::U32_LOGON_FAILURE => {
+                (ErrorKind::PermissionDenied, false)
+            }
+            Status::U32_OBJECT_NAME_COLLISION => (ErrorKind::AlreadyExists, false),
+            Status::U32_FILE_IS_A_DIRECTORY => (ErrorKind::IsADirectory, false),
+            STATUS_NOT_A_DIRECTORY => (ErrorKind::NotADirectory, false),
+            Status::U32_NOT_SUPPORTED | Status::U32_NOT_IMPLEMENTED => {
+                (ErrorKind::Unsupported, false)
+            }
+            // The typo comes from smb-rs.
+            Status::U32_SHARING_VILOATION
+            | Status::U32_IO_TIMEOUT
+            | Status::U32_NETWORK_NAME_DELETED
+            | Status::U32_USER_SESSION_DELETED
+            | Status::U32_NETWORK_SESSION_EXPIRED => (ErrorKind::Unexpected, true),
+            _ => (ErrorKind::Unexpected, false),
+        }
+    }
+}
+
+pub(super) use error::*;
+
+mod utils {
+    use opendal_core::EntryMode;
+    use opendal_core::Metadata;
+    use opendal_core::raw::Timestamp;
+
+    /// Seconds between the FILETIME epoch (1601-01-01) and the unix epoch.
+    const FILETIME_UNIX_EPOCH_SECS: i64 = 11_644_473_600;
+    /// FILETIME is counted in 100-nanosecond intervals.
+    const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+
+    /// Build metadata from file info, `last_write_time` is a FILETIME value.
+    pub fn to_metadata(is_dir: bool, size: u64, last_write_time: u64) -> Metadata {
+        let mut metadata = if is_dir {
+            Metadata::new(EntryMode::DIR)
+        } else {
+            let mut m = Metadata::new(EntryMode::FILE);
+            m.set_content_length(size);
+            m
+        };
+
+        // Zero means the time is not available.
+        if last_write_time != 0 {
+            let second =
+                (last_write_time / FILETIME_TICKS_PER_SEC) as i64 - FILETIME_UNIX_EPOCH_SECS;
+            let nanosecond = (last_write_time % FILETIME_TICKS_PER_SEC * 100) as i32;
+            if let Ok(m) = Timestamp::new(second, nanosecond) {
+                metadata.set_last_modified(m);
+            }
+        }
+
+        metadata
+    }
+}
+
+pub(super) use utils::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_endpoint() {
+        let cases = [
+            ("smb.example.com", Some(("smb.example.com", None))),
+            (
+                "smb.example.com:1445",
+                Some(("smb.example.com", Some(1445))),
+            ),
+            ("10.0.0.1:445", Some(("10.0.0.1", Some(445)))),
+            ("smb.example.com:abc", None),
+        ];
+
+        for (endpoint, expected) in cases {
+            let actual = split_endpoint(endpoint).ok();
+            assert_eq!(actual, expected, "endpoint: {endpoint}");
+        }
+    }
+
+    #[test]
+    fn test_to_metadata() {
+        // 2021-01-01T00:00:00Z
+        let time = (1_609_459_200 + 11_644_473_600) * 10_000_000;
+        let meta = to_metadata(false, 42, time);
+        assert!(meta.is_file());
+        assert_eq!(meta.content_length(), 42);
+        assert_eq!(
+            meta.last_modified().map(|t| t.into_inner().as_second()),
+            Some(1_609_459_200)
+        );
+
+        let meta = to_metadata(true, 4096, 0);
+        assert!(meta.is_dir());
+        assert_eq!(meta.last_modified(), None);
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use super::core::SmbCore;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Lister returned by this backend.
+///
+/// smb-rs doesn't expose the paging of directory queries, all entries are
+/// fetched at the first call of `next`.
+pub struct SmbLister {
+    core: Arc<SmbCore>,
+    path: String,
+    entries: Option<VecDeque<oio::Entry>>,
+}
+
+impl SmbLister {
+    pub(super) fn new(core: Arc<SmbCore>, path: &str) -> Self {
+        let path = if path == "/" { "" } else { path };
+
+        Self {
+            core,
+            path: path.to_string(),
+            entries: None,
+        }
+    }
+}
+
+impl oio::List for SmbLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if self.entries.is_none() {
+            let mut entries = VecDeque::new();
+            if let Some(children) = self.core.list(&self.path).await? {
+                let path = if self.path.is_empty() {
+                    "/"
+                } else {
+                    self.path.as_str()
+                };
+                entries.push_back(oio::Entry::new(path, Metadata::new(EntryMode::DIR)));
+                entries.extend(children);
+            }
+            self.entries = Some(entries);
+        }
+
+        Ok(self.entries.as_mut().and_then(|v| v.pop_front()))
+    }
+}
@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use smb::File;
+use smb::GetLen;
+
+use super::core::IO_CHUNK_SIZE;
+use super::core::SmbCore;
+use super::core::parse_smb_error;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct SmbReadStream {
+    file: Option<File>,
+    offset: u64,
+    end: u64,
+}
+
+impl oio::ReadStream for SmbReadStream {
+    async fn read(&mut self) -> Result<Buffer> {
+        let Some(file) = &self.file else {
+            return Ok(Buffer::new());
+        };
+
+        if self.offset < self.end {
+            let mut buf = vec![0; (self.end - self.offset).min(IO_CHUNK_SIZE as u64) as usize];
+            let n = file
+                .read_block(&mut buf, self.offset, false)
+                .await
+                .map_err(new_std_io_error)?;
+            if n > 0 {
+                buf.truncate(n);
+                self.offset += n as u64;
+                return Ok(Buffer::from(buf));
+            }
+        }
+
+        // Close the file as soon as all data has been read.
+        if let Some(file) = self.file.take() {
+            file.close().await.map_err(parse_smb_error)?;
+        }
+        Ok(Buffer::new())
+    }
+}
+
+/// Reader returned by this backend.
+pub struct SmbReader {
+    core: Arc<SmbCore>,
+    path: String,
+}
+
+impl SmbReader {
+    pub(super) fn new(core: Arc<SmbCore>, path: &str) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl oio::StreamRead for SmbReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        let file = self.core.open_file(&self.path).await?;
+
+        let size = file.get_len().await.map_err(parse_smb_error)?;
+        let range = match range.to_content_range(size as usize) {
+            Ok(range) => range,
+            Err(err) => {
+                file.close().await.map_err(parse_smb_error)?;
+                return Err(err);
+            }
+        };
+
+        let rp = RpRead::new(Metadata::new(EntryMode::FILE).with_content_length(size));
+        let stream = SmbReadStream {
+            file: Some(file),
+            offset: range.start as u64,
+            end: range.end as u64,
+        };
+
+        Ok((rp, Box::new(stream) as Box<dyn oio::ReadStreamDyn>))
+    }
+}
@@ -0,0 +1,116 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use smb::File;
+use smb::GetLen;
+
+use super::core::IO_CHUNK_SIZE;
+use super::core::SmbCore;
+use super::core::parse_smb_error;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct SmbWriter {
+    core: Arc<SmbCore>,
+    path: String,
+    op: OpWrite,
+    file: Option<(File, u64)>,
+}
+
+impl SmbWriter {
+    pub(super) fn new(core: Arc<SmbCore>, path: &str, op: OpWrite) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            op,
+            file: None,
+        }
+    }
+
+    async fn file(&mut self) -> Result<&mut (File, u64)> {
+        if self.file.is_none() {
+            let file = self
+                .core
+                .create_file(&self.path, self.op.if_not_exists(), self.op.append())
+                .await?;
+            let offset = if self.op.append() {
+                file.get_len().await.map_err(parse_smb_error)?
+            } else {
+                0
+            };
+            self.file = Some((file, offset));
+        }
+
+        Ok(self.file.as_mut().expect("file must be initialized"))
+    }
+}
+
+impl oio::Write for SmbWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let (file, offset) = self.file().await?;
+
+        let bs = bs.to_bytes();
+        let mut data = bs.as_ref();
+        while !data.is_empty() {
+            let size = data.len().min(IO_CHUNK_SIZE);
+            let n = file
+                .write_block(&data[..size], *offset)
+                .await
+                .map_err(new_std_io_error)?;
+            if n == 0 {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "smb server accepted no data for write",
+                ));
+            }
+
+            *offset += n as u64;
+            data = &data[n..];
+        }
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        self.file().await?;
+        let (file, offset) = self.file.take().expect("file must be initialized");
+
+        file.close().await.map_err(parse_smb_error)?;
+        Ok(Metadata::new(EntryMode::FILE).with_content_length(offset))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if self.op.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "SmbWriter doesn't support abort for append",
+            ));
+        }
+
+        let Some((file, _)) = self.file.take() else {
+            return Ok(());
+        };
+        file.close().await.map_err(parse_smb_error)?;
+        match self.core.delete(&self.path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
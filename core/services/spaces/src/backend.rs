@@ -0,0 +1,418 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use http::Request;
+use log::debug;
+use opendal_service_s3::S3;
+
+use super::SPACES_SCHEME;
+use super::config::SpacesConfig;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Spaces ignores the signing region, but clients are expected to use `us-east-1`.
+///
+/// Reference: <https://docs.digitalocean.com/products/spaces/how-to/use-aws-sdks/>
+const SIGNING_REGION: &str = "us-east-1";
+
+/// DigitalOcean Spaces service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct SpacesBuilder {
+    pub(super) config: SpacesConfig,
+}
+
+impl Debug for SpacesBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpacesBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpacesBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set bucket name of this backend.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.config.bucket = bucket.to_string();
+
+        self
+    }
+
+    /// Set the datacenter region of the bucket, like `nyc3` or `fra1`.
+    pub fn region(mut self, region: &str) -> Self {
+        if !region.is_empty() {
+            self.config.region = Some(region.to_string());
+        }
+
+        self
+    }
+
+    /// Set endpoint of this backend.
+    ///
+    /// Overrides the endpoint derived from region.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set access_key_id of this backend.
+    pub fn access_key_id(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.access_key_id = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set secret_access_key of this backend.
+    pub fn secret_access_key(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.secret_access_key = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Presign reads as unsigned URLs through the Spaces CDN.
+    ///
+    /// The CDN must be enabled for the bucket and the objects must be publicly
+    /// readable, otherwise the returned URLs will be rejected.
+    pub fn enable_cdn(mut self) -> Self {
+        self.config.enable_cdn = true;
+
+        self
+    }
+
+    /// Set the CDN endpoint used for presigned reads, like a custom domain.
+    pub fn cdn_endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.cdn_endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+}
+
+impl Builder for SpacesBuilder {
+    type Config = SpacesConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {self:?}");
+
+        if self.config.bucket.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "bucket is empty")
+                .with_operation("Builder::build")
+                .with_context("service", SPACES_SCHEME));
+        }
+
+        let region = self.config.region.as_deref();
+        let endpoint = match (self.config.endpoint, region) {
+            (Some(endpoint), _) => endpoint,
+            (None, Some(region)) => format!("https://{region}.digitaloceanspaces.com"),
+            (None, None) => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "region is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", SPACES_SCHEME));
+            }
+        };
+        debug!("backend use endpoint: {endpoint}");
+
+        let cdn_endpoint = if self.config.enable_cdn {
+            let cdn_endpoint = match (self.config.cdn_endpoint, region) {
+                (Some(endpoint), _) => endpoint,
+                (None, Some(region)) => format!(
+                    "https://{}.{region}.cdn.digitaloceanspaces.com",
+                    self.config.bucket
+                ),
+                (None, None) => {
+                    return Err(Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "cdn_endpoint is required when region is not set",
+                    )
+                    .with_operation("Builder::build")
+                    .with_context("service", SPACES_SCHEME));
+                }
+            };
+            debug!("backend use cdn endpoint: {cdn_endpoint}");
+            Some(cdn_endpoint)
+        } else {
+            None
+        };
+
+        // Spaces doesn't implement the flexible checksum headers, so no
+        // checksum algorithm is set, and AWS config from the environment
+        // must not override the region or credentials.
+        let mut builder = S3::default()
+            .bucket(&self.config.bucket)
+            .endpoint(&endpoint)
+            .region(SIGNING_REGION)
+            .disable_config_load()
+            .disable_ec2_metadata();
+        if let Some(root) = &self.config.root {
+            builder = builder.root(root);
+        }
+        if let Some(v) = &self.config.access_key_id {
+            builder = builder.access_key_id(v);
+        }
+        if let Some(v) = &self.config.secret_access_key {
+            builder = builder.secret_access_key(v);
+        }
+
+        let inner = Arc::new(builder.build()?) as Servicer;
+        let info = inner.info();
+        let info = ServiceInfo::new(SPACES_SCHEME, info.root(), info.name());
+
+        let mut capability = inner.capability();
+        // Spaces doesn't support conditional writes and copies.
+        capability.write_with_if_match = false;
+        capability.write_with_if_not_exists = false;
+        capability.copy_with_if_match = false;
+        capability.copy_with_if_not_exists = false;
+        // Append relies on S3 Express One Zone.
+        capability.write_can_append = false;
+        // Spaces has no archive storage classes to restore from.
+        capability.restore = false;
+
+        Ok(SpacesBackend {
+            inner,
+            info,
+            capability,
+            cdn_endpoint,
+        })
+    }
+}
+
+/// Backend for DigitalOcean Spaces services.
+///
+/// Spaces speaks the S3 API, so all requests are delegated to the s3 service.
+#[derive(Debug, Clone)]
+pub struct SpacesBackend {
+    inner: Servicer,
+    info: ServiceInfo,
+    capability: Capability,
+    cdn_endpoint: Option<String>,
+}
+
+impl SpacesBackend {
+    /// Build an unsigned CDN url for the given path.
+    fn cdn_url(&self, endpoint: &str, path: &str) -> String {
+        let p = build_abs_path(&self.info.root(), path);
+        format!("{endpoint}/{}", percent_encode_path(&p))
+    }
+}
+
+impl Service for SpacesBackend {
+    type Reader = oio::Reader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        // Spaces rejects `x-amz-checksum-mode`.
+        let args = args.with_checksum(false);
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        // Spaces rejects `x-amz-checksum-mode`, checksums can't be verified.
+        let args = args.with_verify_checksum(false);
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        match (&self.cdn_endpoint, args.operation()) {
+            (Some(endpoint), PresignOperation::Read(range, _)) => {
+                let mut req = Request::get(self.cdn_url(endpoint, path));
+                if !range.is_full() {
+                    req = req.header(http::header::RANGE, range.to_header());
+                }
+
+                let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+                // We don't need this request anymore, consume
+                let (parts, _) = req.into_parts();
+
+                Ok(RpPresign::new(PresignedRequest::new(
+                    parts.method,
+                    parts.uri,
+                    parts.headers,
+                )))
+            }
+            _ => self.inner.presign(ctx, path, args).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_build_capability() {
+        let backend = SpacesBuilder::default()
+            .bucket("test")
+            .region("nyc3")
+            .access_key_id("id")
+            .secret_access_key("secret")
+            .build()
+            .unwrap();
+
+        let info = backend.info();
+        assert_eq!(info.scheme(), SPACES_SCHEME);
+        assert_eq!(info.name().as_ref(), "test");
+
+        let cap = backend.capability();
+        assert!(!cap.write_with_if_match);
+        assert!(!cap.write_with_if_not_exists);
+        assert!(!cap.write_can_append);
+        assert!(!cap.restore);
+        assert!(cap.presign);
+    }
+
+    #[test]
+    fn test_build_requires_region() {
+        let err = SpacesBuilder::default().bucket("test").build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_presign_read_with_cdn() {
+        let backend = SpacesBuilder::default()
+            .bucket("test")
+            .region("fra1")
+            .root("/path/to")
+            .access_key_id("id")
+            .secret_access_key("secret")
+            .enable_cdn()
+            .build()
+            .unwrap();
+
+        let ctx = OperationContext::default();
+        let args = OpPresign::new(
+            PresignOperation::Read(BytesRange::default(), OpRead::default()),
+            Duration::from_secs(3600),
+        );
+        let req = backend
+            .presign(&ctx, "hello world.txt", args)
+            .await
+            .unwrap()
+            .into_presigned_request();
+        assert_eq!(req.method(), http::Method::GET);
+        assert_eq!(
+            req.uri().to_string(),
+            "https://test.fra1.cdn.digitaloceanspaces.com/path/to/hello%20world.txt"
+        );
+    }
+
+    #[test]
+    fn test_build_cdn_requires_region_or_endpoint() {
+        let err = SpacesBuilder::default()
+            .bucket("test")
+            .endpoint("https://spaces.example.com")
+            .enable_cdn()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        assert!(
+            SpacesBuilder::default()
+                .bucket("test")
+                .endpoint("https://spaces.example.com")
+                .enable_cdn()
+                .cdn_endpoint("https://cdn.example.com/")
+                .build()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::SpacesBuilder;
+
+/// Config for DigitalOcean Spaces services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct SpacesConfig {
+    /// root of this backend.
+    ///
+    /// All operations will happen under this root.
+    ///
+    /// default to `/` if not set.
+    pub root: Option<String>,
+    /// bucket name of this backend.
+    ///
+    /// required.
+    pub bucket: String,
+    /// The datacenter region the bucket was created in, like `nyc3` or `fra1`.
+    ///
+    /// Required unless `endpoint` is set.
+    pub region: Option<String>,
+    /// endpoint of this backend.
+    ///
+    /// Overrides the endpoint derived from `region`.
+    pub endpoint: Option<String>,
+    /// access_key_id of this backend.
+    pub access_key_id: Option<String>,
+    /// secret_access_key of this backend.
+    pub secret_access_key: Option<String>,
+    /// Presign reads as unsigned URLs through the Spaces CDN.
+    ///
+    /// Only useful for objects that are publicly readable.
+    pub enable_cdn: bool,
+    /// The CDN endpoint used when `enable_cdn` is set, like a custom domain.
+    ///
+    /// default to `https://{bucket}.{region}.cdn.digitaloceanspaces.com` if not set.
+    pub cdn_endpoint: Option<String>,
+}
+
+impl Debug for SpacesConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpacesConfig")
+            .field("root", &self.root)
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("enable_cdn", &self.enable_cdn)
+            .field("cdn_endpoint", &self.cdn_endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl opendal_core::Configurator for SpacesConfig {
+    type Builder = SpacesBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+
+        if let Some(name) = uri.name() {
+            map.insert("bucket".to_string(), name.to_string());
+        }
+
+        if let Some(root) = uri.root() {
+            if !root.is_empty() {
+                map.insert("root".to_string(), root.to_string());
+            }
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        SpacesBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    #[test]
+    fn from_uri_extracts_bucket_root_and_region() {
+        let uri = OperatorUri::new(
+            "spaces://example-bucket/path/to/root?region=fra1&enable_cdn=true",
+            Vec::<(String, String)>::new(),
+        )
+        .unwrap();
+
+        let cfg = SpacesConfig::from_uri(&uri).unwrap();
+        assert_eq!(cfg.bucket, "example-bucket");
+        assert_eq!(cfg.root.as_deref(), Some("path/to/root"));
+        assert_eq!(cfg.region.as_deref(), Some("fra1"));
+        assert!(cfg.enable_cdn);
+    }
+}
@@ -184,6 +184,7 @@ impl SqliteBackend {
             read: true,
             write: true,
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
             stat: true,
             write_can_empty: true,
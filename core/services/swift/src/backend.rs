@@ -32,6 +32,11 @@ use super::writer::SwiftWriter;
 use opendal_core::raw::*;
 use opendal_core::*;
 
+/// Default size of SLO segments.
+const DEFAULT_SEGMENT_SIZE: usize = 5 * 1024 * 1024;
+/// Swift rejects objects larger than 5 GiB, including segments.
+const MAX_SEGMENT_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
 /// [OpenStack Swift](https://docs.openstack.org/api-ref/object-store/#)'s REST API support.
 /// For more information about swift-compatible services, refer to [Compatible Services](#compatible-services).
 #[doc = include_str!("docs.md")]
@@ -119,6 +124,18 @@ impl SwiftBuilder {
         }
         self
     }
+
+    /// Set the segment size for Static Large Objects, in bytes.
+    ///
+    /// Writes larger than this are uploaded as segments under `.segments/` and
+    /// stitched together by an SLO manifest. Swift allows 1000 segments per
+    /// manifest by default, so raise this to write objects larger than 5 GiB.
+    ///
+    /// Defaults to 5 MiB, and can be up to 5 GiB.
+    pub fn segment_size(mut self, size: usize) -> Self {
+        self.config.segment_size = Some(size);
+        self
+    }
 }
 
 impl Builder for SwiftBuilder {
@@ -166,6 +183,17 @@ impl Builder for SwiftBuilder {
             None => TempUrlHashAlgorithm::Sha256,
         };
 
+        let segment_size = self.config.segment_size.unwrap_or(DEFAULT_SEGMENT_SIZE);
+        if segment_size == 0 || segment_size as u64 > MAX_SEGMENT_SIZE {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "segment_size must be between 1 byte and 5 GiB",
+            )
+            .with_context("service", SWIFT_SCHEME)
+            .with_context("segment_size", segment_size));
+        }
+        debug!("backend use segment size: {segment_size}");
+
         Ok(SwiftBackend {
             core: Arc::new(SwiftCore {
                 info: ServiceInfo::new(SWIFT_SCHEME, &root, ""),
@@ -186,12 +214,12 @@ impl Builder for SwiftBuilder {
                     write: true,
                     write_can_empty: true,
                     write_can_multi: true,
-                    write_multi_min_size: Some(5 * 1024 * 1024),
-                    write_multi_max_size: if cfg!(target_pointer_width = "64") {
-                        Some(5 * 1024 * 1024 * 1024)
-                    } else {
-                        Some(usize::MAX)
-                    },
+                    // Segments are written at the configured size unless users
+                    // ask for larger chunks.
+                    write_multi_min_size: Some(segment_size),
+                    write_multi_max_size: Some(
+                        usize::try_from(MAX_SEGMENT_SIZE).unwrap_or(usize::MAX),
+                    ),
                     write_with_content_type: true,
                     write_with_content_disposition: true,
                     write_with_content_encoding: true,
@@ -292,13 +320,15 @@ impl Service for SwiftBackend {
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: oio::MultipartWriter<SwiftWriter> = {
             let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
             let writer = SwiftWriter::new(
                 self.core.clone(),
                 ctx.clone(),
                 args.clone(),
                 path.to_string(),
             );
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent);
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent)
+                .with_adaptive_concurrency(adaptive);
 
             Ok(w)
         }?;
@@ -405,3 +435,26 @@ impl Service for SwiftBackend {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_with_segment_size() {
+        let builder = || {
+            SwiftBuilder::default()
+                .endpoint("http://127.0.0.1:8080/v1/AUTH_test")
+                .container("test")
+        };
+
+        let srv = builder().segment_size(100 * 1024 * 1024).build().unwrap();
+        assert_eq!(
+            srv.capability().write_multi_min_size,
+            Some(100 * 1024 * 1024)
+        );
+
+        let err = builder().segment_size(0).build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
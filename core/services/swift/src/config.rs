@@ -48,6 +48,13 @@ pub struct SwiftConfig {
     /// The cluster must have the chosen algorithm in its
     /// `tempurl.allowed_digests` (check `GET /info`).
     pub temp_url_hash_algorithm: Option<String>,
+    /// The size of segments for Static Large Objects, in bytes.
+    ///
+    /// Objects larger than this are uploaded as segments and stitched together
+    /// by an SLO manifest. Swift allows 1000 segments per manifest by default
+    /// (`max_manifest_segments`), so this limits the largest object to
+    /// `segment_size * 1000`. Defaults to 5 MiB, and can be up to 5 GiB.
+    pub segment_size: Option<usize>,
 }
 
 impl Debug for SwiftConfig {
@@ -56,6 +63,7 @@ impl Debug for SwiftConfig {
             .field("endpoint", &self.endpoint)
             .field("container", &self.container)
             .field("root", &self.root)
+            .field("segment_size", &self.segment_size)
             .finish_non_exhaustive()
     }
 }
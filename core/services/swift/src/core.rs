@@ -124,7 +124,14 @@ impl Debug for SwiftCore {
     }
 }
 
+/// Container-level prefix that holds the segments of large objects.
+pub const SEGMENTS_PREFIX: &str = ".segments/";
+
 impl SwiftCore {
+    /// Delete an object.
+    ///
+    /// `multipart-manifest=delete` removes the segments together with an SLO
+    /// manifest, and is ignored by Swift for other objects.
     pub async fn swift_delete(
         &self,
         ctx: &OperationContext,
@@ -133,7 +140,7 @@ impl SwiftCore {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
-            "{}/{}/{}",
+            "{}/{}/{}?multipart-manifest=delete",
             &self.endpoint,
             &self.container,
             percent_encode_path(&p)
@@ -421,7 +428,7 @@ impl SwiftCore {
     pub fn slo_segment_path(&self, path: &str, upload_id: &str, part_number: usize) -> String {
         let abs = build_abs_path(&self.root, path);
         format!(
-            ".segments/{}{}/{:08}",
+            "{SEGMENTS_PREFIX}{}{}/{:08}",
             abs.trim_end_matches('/'),
             upload_id,
             part_number
@@ -521,7 +528,11 @@ impl SwiftCore {
         // We can't use multipart-manifest=delete because we haven't created
         // the manifest yet (abort happens before complete).
         let abs = build_abs_path(&self.root, path);
-        let prefix = format!(".segments/{}{}/", abs.trim_end_matches('/'), upload_id);
+        let prefix = format!(
+            "{SEGMENTS_PREFIX}{}{}/",
+            abs.trim_end_matches('/'),
+            upload_id
+        );
 
         // List all segments with this prefix.
         let url = QueryPairsWriter::new(&format!("{}/{}/", &self.endpoint, &self.container))
@@ -677,6 +688,21 @@ pub struct BulkDeleteResponse {
     pub response_body: Option<String>,
 }
 
+/// Split the `swift_bytes` parameter out of a listed content type.
+///
+/// Swift reports the content type of a large object manifest as
+/// `<content_type>;swift_bytes=<size>`, where `size` is the total size of its
+/// segments while `bytes` is the size of the manifest itself.
+pub fn parse_swift_bytes(content_type: &str) -> (&str, Option<u64>) {
+    match content_type.rsplit_once(";swift_bytes=") {
+        Some((content_type, size)) => match size.parse() {
+            Ok(size) => (content_type, Some(size)),
+            Err(_) => (content_type, None),
+        },
+        None => (content_type, None),
+    }
+}
+
 /// Entry in an SLO manifest JSON array.
 ///
 /// Reference: <https://docs.openstack.org/swift/latest/overview_large_objects.html>
@@ -796,6 +822,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_swift_bytes_test() {
+        assert_eq!(
+            parse_swift_bytes("application/octet-stream;swift_bytes=10737418240"),
+            ("application/octet-stream", Some(10737418240))
+        );
+        assert_eq!(parse_swift_bytes("text/plain"), ("text/plain", None));
+        assert_eq!(
+            parse_swift_bytes("text/plain;swift_bytes=abc"),
+            ("text/plain", None)
+        );
+    }
+
     #[test]
     fn temp_url_sha1_signature_format() {
         let algo = TempUrlHashAlgorithm::Sha1;
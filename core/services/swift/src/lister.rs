@@ -87,6 +87,15 @@ impl oio::PageList for SwiftLister {
         }
 
         for status in decoded_response {
+            let name = match &status {
+                ListOpResponse::Subdir { subdir } => subdir,
+                ListOpResponse::FileInfo { name, .. } => name,
+            };
+            // Segments of large objects are an implementation detail of writes.
+            if name.starts_with(SEGMENTS_PREFIX) {
+                continue;
+            }
+
             let entry: oio::Entry = match status {
                 ListOpResponse::Subdir { subdir } => {
                     let mut path = build_rel_path(self.core.root.as_str(), subdir.as_str());
@@ -120,7 +129,11 @@ impl oio::PageList for SwiftLister {
                     meta.set_last_modified(last_modified.parse::<Timestamp>()?);
 
                     if let Some(content_type) = content_type {
-                        meta.set_content_type(content_type.as_str());
+                        let (content_type, size) = parse_swift_bytes(&content_type);
+                        if let Some(size) = size {
+                            meta.set_content_length(size);
+                        }
+                        meta.set_content_type(content_type);
                     }
 
                     oio::Entry::with(path, meta)
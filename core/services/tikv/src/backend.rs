@@ -46,6 +46,17 @@ impl TikvBuilder {
         self
     }
 
+    /// Set the API used to access TiKV, either `raw` (default) or `txn`.
+    ///
+    /// In `txn` mode, every write is committed in its own transaction and
+    /// conditional writes like `if_not_exists` are supported.
+    pub fn mode(mut self, mode: &str) -> Self {
+        if !mode.is_empty() {
+            self.config.mode = Some(mode.to_string())
+        }
+        self
+    }
+
     /// Set the insecure connection to TiKV.
     pub fn insecure(mut self) -> Self {
         self.config.insecure = true;
@@ -98,12 +109,33 @@ impl Builder for TikvBuilder {
                 Error::new(ErrorKind::ConfigInvalid, "invalid tls configuration")
                     .with_context("service", TIKV_SCHEME)
                     .with_context("endpoints", format!("{endpoints:?}")),
-            )?;
+            );
         }
 
+        // mTLS requires all of the CA, certificate and key.
+        let tls_paths = [
+            &self.config.ca_path,
+            &self.config.cert_path,
+            &self.config.key_path,
+        ];
+        if !self.config.insecure && !tls_paths.iter().all(|v| v.is_some()) {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "ca_path, cert_path and key_path must be set unless insecure is enabled",
+            )
+            .with_context("service", TIKV_SCHEME)
+            .with_context("endpoints", format!("{endpoints:?}")));
+        }
+
+        let mode = match &self.config.mode {
+            Some(v) => TikvMode::parse(v)?,
+            None => TikvMode::default(),
+        };
+
         Ok(TikvBackend::new(TikvCore {
             client: OnceCell::new(),
             endpoints,
+            mode,
             insecure: self.config.insecure,
             ca_path: self.config.ca_path.clone(),
             cert_path: self.config.cert_path.clone(),
@@ -129,6 +161,7 @@ impl TikvBackend {
             stat: true,
             write: true,
             write_can_empty: true,
+            write_with_if_not_exists: core.mode == TikvMode::Txn,
             delete: true,
             shared: true,
             ..Default::default()
@@ -197,10 +230,10 @@ impl Service for TikvBackend {
         Ok(output)
     }
 
-    fn write(&self, _ctx: &OperationContext, path: &str, _: OpWrite) -> Result<Self::Writer> {
+    fn write(&self, _ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: TikvWriter = {
             let p = build_abs_path(&self.root, path);
-            Ok(TikvWriter::new(self.core.clone(), p))
+            Ok(TikvWriter::new(self.core.clone(), p, args))
         }?;
 
         Ok(output)
@@ -29,6 +29,11 @@ use super::backend::TikvBuilder;
 pub struct TikvConfig {
     /// network address of the TiKV service.
     pub endpoints: Option<Vec<String>>,
+    /// The API used to access TiKV, either `raw` (default) or `txn`.
+    ///
+    /// In `txn` mode, every write is committed in its own transaction and
+    /// conditional writes like `if_not_exists` are supported.
+    pub mode: Option<String>,
     /// whether using insecure connection to TiKV
     pub insecure: bool,
     /// certificate authority file path
@@ -43,6 +48,7 @@ impl Debug for TikvConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TikvConfig")
             .field("endpoints", &self.endpoints)
+            .field("mode", &self.mode)
             .field("insecure", &self.insecure)
             .field("ca_path", &self.ca_path)
             .field("cert_path", &self.cert_path)
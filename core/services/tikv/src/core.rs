@@ -20,15 +20,45 @@ use std::fmt::Debug;
 use mea::once::OnceCell;
 use tikv_client::Config;
 use tikv_client::RawClient;
+use tikv_client::TransactionClient;
+use tikv_client::TransactionOptions;
 
 use super::TIKV_SCHEME;
 use opendal_core::*;
 
+/// The API used to access TiKV.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TikvMode {
+    /// Raw key-value API, every request is applied on its own.
+    #[default]
+    Raw,
+    /// Transactional API, writes are committed atomically.
+    Txn,
+}
+
+impl TikvMode {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "raw" => Ok(Self::Raw),
+            "txn" => Ok(Self::Txn),
+            other => Err(
+                Error::new(ErrorKind::ConfigInvalid, format!("unknown mode: {other}"))
+                    .with_context("service", TIKV_SCHEME),
+            ),
+        }
+    }
+}
+
+pub enum TikvClient {
+    Raw(RawClient),
+    Txn(TransactionClient),
+}
+
 /// TikvCore holds the configuration and client for interacting with TiKV.
-#[derive(Clone)]
 pub struct TikvCore {
-    pub client: OnceCell<RawClient>,
+    pub client: OnceCell<TikvClient>,
     pub endpoints: Vec<String>,
+    pub mode: TikvMode,
     pub insecure: bool,
     pub ca_path: Option<String>,
     pub cert_path: Option<String>,
@@ -39,69 +69,146 @@ impl Debug for TikvCore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TikvCore")
             .field("endpoints", &self.endpoints)
+            .field("mode", &self.mode)
             .field("insecure", &self.insecure)
             .finish()
     }
 }
 
 impl TikvCore {
-    async fn get_connection(&self) -> Result<&RawClient> {
+    fn config(&self) -> Result<Config> {
+        if self.insecure {
+            return Ok(Config::default());
+        }
+
+        if let Some(ca_path) = self.ca_path.as_ref()
+            && let Some(key_path) = self.key_path.as_ref()
+            && let Some(cert_path) = self.cert_path.as_ref()
+        {
+            return Ok(Config::default().with_security(ca_path, cert_path, key_path));
+        }
+
+        Err(
+            Error::new(ErrorKind::ConfigInvalid, "invalid configuration")
+                .with_context("service", TIKV_SCHEME)
+                .with_context("endpoints", format!("{:?}", self.endpoints)),
+        )
+    }
+
+    async fn get_connection(&self) -> Result<&TikvClient> {
         self.client
             .get_or_try_init(|| async {
-                if self.insecure {
-                    return RawClient::new(self.endpoints.clone())
-                        .await
-                        .map_err(parse_tikv_config_error);
-                }
-
-                if let Some(ca_path) = self.ca_path.as_ref()
-                    && let Some(key_path) = self.key_path.as_ref()
-                    && let Some(cert_path) = self.cert_path.as_ref()
-                {
-                    let config = Config::default().with_security(ca_path, cert_path, key_path);
-                    return RawClient::new_with_config(self.endpoints.clone(), config)
+                let config = self.config()?;
+                match self.mode {
+                    TikvMode::Raw => RawClient::new_with_config(self.endpoints.clone(), config)
                         .await
-                        .map_err(parse_tikv_config_error);
+                        .map(TikvClient::Raw),
+                    TikvMode::Txn => {
+                        TransactionClient::new_with_config(self.endpoints.clone(), config)
+                            .await
+                            .map(TikvClient::Txn)
+                    }
                 }
-
-                Err(
-                    Error::new(ErrorKind::ConfigInvalid, "invalid configuration")
-                        .with_context("service", TIKV_SCHEME)
-                        .with_context("endpoints", format!("{:?}", self.endpoints)),
-                )
+                .map_err(parse_tikv_config_error)
             })
             .await
     }
 
     pub async fn get(&self, path: &str) -> Result<Option<Buffer>> {
-        let result = self
-            .get_connection()
-            .await?
-            .get(path.to_owned())
-            .await
-            .map_err(parse_tikv_error)?;
+        let result = match self.get_connection().await? {
+            TikvClient::Raw(client) => client.get(path.to_owned()).await,
+            TikvClient::Txn(client) => {
+                // Reads don't need to commit, use a snapshot at the latest timestamp.
+                let ts = client.current_timestamp().await.map_err(parse_tikv_error)?;
+                let mut snapshot =
+                    client.snapshot(ts, TransactionOptions::new_optimistic().read_only());
+                snapshot.get(path.to_owned()).await
+            }
+        }
+        .map_err(parse_tikv_error)?;
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, path: &str, value: Buffer) -> Result<()> {
-        self.get_connection()
-            .await?
-            .put(path.to_owned(), value.to_vec())
-            .await
-            .map_err(parse_tikv_error)
+    /// Set the value of given path.
+    ///
+    /// If `if_not_exists` is true, the write fails with [`ErrorKind::ConditionNotMatch`]
+    /// if the path exists. This is only supported in [`TikvMode::Txn`].
+    pub async fn set(&self, path: &str, value: Buffer, if_not_exists: bool) -> Result<()> {
+        match self.get_connection().await? {
+            TikvClient::Raw(client) => client
+                .put(path.to_owned(), value.to_vec())
+                .await
+                .map_err(parse_tikv_error),
+            TikvClient::Txn(client) => {
+                let mut txn = client.begin_optimistic().await.map_err(parse_tikv_error)?;
+                let res = if if_not_exists {
+                    txn.insert(path.to_owned(), value.to_vec()).await
+                } else {
+                    txn.put(path.to_owned(), value.to_vec()).await
+                };
+                if let Err(err) = res {
+                    let _ = txn.rollback().await;
+                    return Err(parse_tikv_error(err));
+                }
+
+                match txn.commit().await {
+                    Ok(_) => Ok(()),
+                    Err(err) if if_not_exists && is_key_already_exist(&err) => Err(Error::new(
+                        ErrorKind::ConditionNotMatch,
+                        "key already exists, doesn't match the condition if_not_exists",
+                    )
+                    .set_source(err)),
+                    Err(err) => Err(parse_tikv_error(err)),
+                }
+            }
+        }
     }
 
     pub async fn delete(&self, path: &str) -> Result<()> {
-        self.get_connection()
-            .await?
-            .delete(path.to_owned())
-            .await
-            .map_err(parse_tikv_error)
+        match self.get_connection().await? {
+            TikvClient::Raw(client) => client
+                .delete(path.to_owned())
+                .await
+                .map_err(parse_tikv_error),
+            TikvClient::Txn(client) => {
+                let mut txn = client.begin_optimistic().await.map_err(parse_tikv_error)?;
+                if let Err(err) = txn.delete(path.to_owned()).await {
+                    let _ = txn.rollback().await;
+                    return Err(parse_tikv_error(err));
+                }
+                txn.commit().await.map_err(parse_tikv_error)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Check if the error is caused by inserting a key which already exists.
+fn is_key_already_exist(e: &tikv_client::Error) -> bool {
+    match e {
+        tikv_client::Error::KeyError(err) => err.already_exist.is_some(),
+        tikv_client::Error::MultipleKeyErrors(errs) | tikv_client::Error::ExtractedErrors(errs) => {
+            errs.iter().any(is_key_already_exist)
+        }
+        _ => false,
+    }
+}
+
+fn is_write_conflict(e: &tikv_client::Error) -> bool {
+    match e {
+        tikv_client::Error::KeyError(err) => err.conflict.is_some() || !err.retryable.is_empty(),
+        tikv_client::Error::MultipleKeyErrors(errs) | tikv_client::Error::ExtractedErrors(errs) => {
+            errs.iter().any(is_write_conflict)
+        }
+        _ => false,
     }
 }
 
 fn parse_tikv_error(e: tikv_client::Error) -> Error {
-    Error::new(ErrorKind::Unexpected, "error from tikv").set_source(e)
+    // Conflicting transactions can succeed once retried.
+    let temporary = is_write_conflict(&e);
+    let err = Error::new(ErrorKind::Unexpected, "error from tikv").set_source(e);
+    if temporary { err.set_temporary() } else { err }
 }
 
 fn parse_tikv_config_error(e: tikv_client::Error) -> Error {
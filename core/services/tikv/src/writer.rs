@@ -19,19 +19,22 @@ use std::sync::Arc;
 
 use super::core::*;
 use opendal_core::raw::oio;
+use opendal_core::raw::*;
 use opendal_core::*;
 
 pub struct TikvWriter {
     core: Arc<TikvCore>,
     path: String,
+    args: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl TikvWriter {
-    pub fn new(core: Arc<TikvCore>, path: String) -> Self {
+    pub fn new(core: Arc<TikvCore>, path: String, args: OpWrite) -> Self {
         Self {
             core,
             path,
+            args,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -46,7 +49,9 @@ impl oio::Write for TikvWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
         let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
+        self.core
+            .set(&self.path, buf, self.args.if_not_exists())
+            .await?;
 
         let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
         Ok(meta)
@@ -0,0 +1,380 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use mea::once::OnceCell;
+
+use super::TORRENT_SCHEME;
+use super::config::TorrentConfig;
+use super::core::TorrentCore;
+use super::lister::TorrentLister;
+use super::reader::TorrentReader;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Read-only [BitTorrent](https://www.bittorrent.org/) service support via web seeds.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct TorrentBuilder {
+    pub(super) config: TorrentConfig,
+}
+
+impl Debug for TorrentBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TorrentBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TorrentBuilder {
+    /// Set the http(s) url of the `.torrent` metainfo file.
+    ///
+    /// For example: `https://example.com/dataset.torrent`
+    pub fn torrent(mut self, torrent: &str) -> Self {
+        self.config.torrent = if torrent.is_empty() {
+            None
+        } else {
+            Some(torrent.to_string())
+        };
+
+        self
+    }
+
+    /// Set comma separated web seeds to read from, instead of the
+    /// `url-list` in the torrent.
+    ///
+    /// For example: `https://mirror-a.example.com/,https://mirror-b.example.com/`
+    pub fn web_seeds(mut self, web_seeds: &str) -> Self {
+        self.config.web_seeds = if web_seeds.is_empty() {
+            None
+        } else {
+            Some(web_seeds.to_string())
+        };
+
+        self
+    }
+
+    /// Set root path of torrent backend.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for TorrentBuilder {
+    type Config = TorrentConfig;
+
+    fn build(self) -> Result<impl Service> {
+        debug!("backend build started: {:?}", &self);
+
+        let torrent = match &self.config.torrent {
+            Some(v) if v.starts_with("http://") || v.starts_with("https://") => v.clone(),
+            Some(_) => {
+                return Err(
+                    Error::new(ErrorKind::ConfigInvalid, "torrent must be an http(s) url")
+                        .with_context("service", TORRENT_SCHEME),
+                );
+            }
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "torrent is empty")
+                    .with_context("service", TORRENT_SCHEME));
+            }
+        };
+
+        let root = normalize_root(&self.config.root.unwrap_or_default());
+        debug!("backend use root {root}");
+
+        let web_seeds = self
+            .config
+            .web_seeds
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .collect();
+
+        let info = ServiceInfo::new(TORRENT_SCHEME, &root, &torrent);
+        let capability = Capability {
+            stat: true,
+            read: true,
+            list: true,
+            list_with_recursive: true,
+
+            shared: true,
+
+            ..Default::default()
+        };
+
+        Ok(TorrentBackend {
+            core: Arc::new(TorrentCore {
+                info,
+                capability,
+                root,
+                torrent,
+                web_seeds,
+                metainfo: OnceCell::new(),
+            }),
+        })
+    }
+}
+
+/// TorrentBackend implements [`Service`] for files inside a torrent.
+#[derive(Clone, Debug)]
+pub struct TorrentBackend {
+    pub(crate) core: Arc<TorrentCore>,
+}
+
+impl Service for TorrentBackend {
+    type Reader = oio::PositionReader<TorrentReader>;
+    type Writer = ();
+    type Lister = oio::HierarchyLister<TorrentLister>;
+    type Deleter = ();
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.core.info.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.core.capability
+    }
+
+    async fn create_dir(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, _args: OpStat) -> Result<RpStat> {
+        let meta = self.core.metainfo(ctx).await?;
+        let p = build_abs_path(&self.core.root, path);
+
+        if p.is_empty() || (p.ends_with('/') && meta.is_dir(&p)) {
+            return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
+        }
+
+        match meta.file(&p) {
+            Some(file) => Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(file.length),
+            )),
+            None => Err(Error::new(ErrorKind::NotFound, "file not found in torrent")),
+        }
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, _args: OpRead) -> Result<Self::Reader> {
+        Ok(oio::PositionReader::new(TorrentReader::new(
+            self.core.clone(),
+            ctx.clone(),
+            path,
+        )))
+    }
+
+    fn write(&self, _ctx: &OperationContext, _path: &str, _args: OpWrite) -> Result<Self::Writer> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    fn delete(&self, _ctx: &OperationContext) -> Result<Self::Deleter> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        let lister = TorrentLister::new(self.core.clone(), ctx.clone(), path);
+        Ok(oio::HierarchyLister::new(lister, path, args.recursive()))
+    }
+
+    fn copy(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpCopy,
+        _opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn rename(
+        &self,
+        _ctx: &OperationContext,
+        _from: &str,
+        _to: &str,
+        _args: OpRename,
+    ) -> Result<RpRename> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    async fn presign(
+        &self,
+        _ctx: &OperationContext,
+        _path: &str,
+        _args: OpPresign,
+    ) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+    use http::Request;
+    use http::Response;
+    use http::StatusCode;
+    use sha1::Digest;
+    use sha1::Sha1;
+
+    use super::*;
+    use crate::metainfo::tests::multi_file_torrent;
+
+    /// Serve files at given urls with support of range requests.
+    struct MockWebSeed {
+        files: HashMap<String, Vec<u8>>,
+    }
+
+    impl HttpTransport for MockWebSeed {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let (status, content) = match self.files.get(&req.uri().to_string()) {
+                None => (StatusCode::NOT_FOUND, vec![]),
+                Some(content) => match req.headers().get(http::header::RANGE) {
+                    None => (StatusCode::OK, content.clone()),
+                    Some(range) => {
+                        let range = range.to_str().unwrap().trim_start_matches("bytes=");
+                        let (start, end) = range.split_once('-').unwrap();
+                        let start: usize = start.parse().unwrap();
+                        let end: usize = end.parse().unwrap();
+                        (StatusCode::PARTIAL_CONTENT, content[start..=end].to_vec())
+                    }
+                },
+            };
+
+            let size = content.len() as u64;
+            let body = futures::stream::iter(vec![Ok(Buffer::from(content))]);
+            Ok(Response::builder()
+                .status(status)
+                .body(HttpBody::new(body, Some(size)))
+                .unwrap())
+        }
+    }
+
+    fn new_operator(web_seeds: &str) -> Operator {
+        // Pieces of 4 bytes: `hell`, `o` with padding, `worl` and `d!`.
+        let content = b"hello\0\0\0world!";
+        let pieces: Vec<u8> = content
+            .chunks(4)
+            .flat_map(|v| Sha1::digest(v).to_vec())
+            .collect();
+        let torrent =
+            multi_file_torrent(4, &[("x/a", 5, ""), ("pad", 3, "p"), ("y", 6, "")], &pieces);
+
+        let files = HashMap::from([
+            ("http://seed.test/data.torrent".to_string(), torrent),
+            ("http://seed.test/a/data/x/a".to_string(), b"hello".to_vec()),
+            ("http://seed.test/a/data/y".to_string(), b"world!".to_vec()),
+            ("http://bad.test/data/y".to_string(), b"WORLD!".to_vec()),
+        ]);
+
+        let builder = TorrentBuilder::default()
+            .torrent("http://seed.test/data.torrent")
+            .web_seeds(web_seeds);
+        Operator::new(builder).unwrap().with_context(
+            OperationContext::new()
+                .with_http_transport(HttpTransporter::new(MockWebSeed { files })),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_stat_and_list() -> Result<()> {
+        let op = new_operator("");
+
+        assert_eq!(op.stat("data/y").await?.content_length(), 6);
+        assert!(op.stat("data/x/").await?.mode().is_dir());
+        let err = op.stat("data/pad").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let paths: Vec<String> = op
+            .lister_with("")
+            .recursive(true)
+            .await?
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(paths, vec!["data/x/a", "data/y"]);
+
+        let paths: Vec<String> = op
+            .lister("data/")
+            .await?
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await?;
+        assert_eq!(paths, vec!["data/x/", "data/y"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read() -> Result<()> {
+        let op = new_operator("");
+
+        assert_eq!(op.read("data/x/a").await?.to_vec(), b"hello");
+        assert_eq!(op.read("data/y").await?.to_vec(), b"world!");
+        assert_eq!(op.read_with("data/y").range(2..5).await?.to_vec(), b"rld");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_skips_corrupted_web_seed() -> Result<()> {
+        let op = new_operator("http://bad.test/,http://seed.test/a/");
+        assert_eq!(op.read("data/y").await?.to_vec(), b"world!");
+
+        let op = new_operator("http://bad.test/");
+        let err = op.read("data/y").await.unwrap_err();
+        assert!(err.to_string().contains("piece hash mismatch"), "{err}");
+        Ok(())
+    }
+}
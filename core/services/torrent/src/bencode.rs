@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+
+use opendal_core::*;
+
+/// A bencoded value borrowing from the input.
+///
+/// ref: <https://www.bittorrent.org/beps/bep_0003.html#bencoding>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value<'a> {
+    Int(i64),
+    Bytes(&'a [u8]),
+    List(Vec<Value<'a>>),
+    Dict(BTreeMap<&'a [u8], Value<'a>>),
+}
+
+impl<'a> Value<'a> {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&'a [u8]> {
+        match self {
+            Value::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        self.as_bytes().and_then(|v| std::str::from_utf8(v).ok())
+    }
+
+    pub fn as_list(&self) -> Option<&[Value<'a>]> {
+        match self {
+            Value::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value<'a>> {
+        match self {
+            Value::Dict(v) => v.get(key.as_bytes()),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a bencoded value, the whole input must be consumed.
+pub fn decode(input: &[u8]) -> Result<Value<'_>> {
+    let mut decoder = Decoder { input, pos: 0 };
+    let value = decoder.value(0)?;
+    if decoder.pos != input.len() {
+        return Err(decoder.error("trailing data after value"));
+    }
+    Ok(value)
+}
+
+/// Nesting deeper than this is never seen in real torrent files.
+const MAX_DEPTH: usize = 64;
+
+struct Decoder<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn error(&self, msg: &str) -> Error {
+        Error::new(
+            ErrorKind::Unexpected,
+            format!("invalid bencode data: {msg}"),
+        )
+        .with_context("offset", self.pos)
+    }
+
+    fn peek(&self) -> Result<u8> {
+        self.input
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error("unexpected end of input"))
+    }
+
+    /// Read until `end`, and skip the `end` itself.
+    fn until(&mut self, end: u8) -> Result<&'a str> {
+        let len = self.input[self.pos..]
+            .iter()
+            .position(|b| *b == end)
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        let bs = &self.input[self.pos..self.pos + len];
+        self.pos += len + 1;
+        std::str::from_utf8(bs).map_err(|_| self.error("invalid number"))
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value<'a>> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deep"));
+        }
+
+        match self.peek()? {
+            b'i' => {
+                self.pos += 1;
+                let v = self.until(b'e')?;
+                v.parse()
+                    .map(Value::Int)
+                    .map_err(|_| self.error("invalid integer"))
+            }
+            b'l' => {
+                self.pos += 1;
+                let mut list = Vec::new();
+                while self.peek()? != b'e' {
+                    list.push(self.value(depth + 1)?);
+                }
+                self.pos += 1;
+                Ok(Value::List(list))
+            }
+            b'd' => {
+                self.pos += 1;
+                let mut dict = BTreeMap::new();
+                while self.peek()? != b'e' {
+                    let key = self.bytes()?;
+                    let value = self.value(depth + 1)?;
+                    dict.insert(key, value);
+                }
+                self.pos += 1;
+                Ok(Value::Dict(dict))
+            }
+            b'0'..=b'9' => self.bytes().map(Value::Bytes),
+            _ => Err(self.error("unexpected token")),
+        }
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len: usize = self
+            .until(b':')?
+            .parse()
+            .map_err(|_| self.error("invalid string length"))?;
+        if self.input.len() - self.pos < len {
+            return Err(self.error("unexpected end of input"));
+        }
+        let bs = &self.input[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let v = decode(b"d3:bar4:spam3:fooi42e4:listl1:ai-1eee").unwrap();
+        assert_eq!(v.get("bar").and_then(|v| v.as_str()), Some("spam"));
+        assert_eq!(v.get("foo").and_then(|v| v.as_int()), Some(42));
+        assert_eq!(
+            v.get("list").and_then(|v| v.as_list()),
+            Some(&[Value::Bytes(b"a"), Value::Int(-1)][..])
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        for input in [
+            &b""[..],
+            b"i42",
+            b"ixe",
+            b"5:abc",
+            b"l1:a",
+            b"d1:ae",
+            b"i1ei2e",
+            b"x",
+        ] {
+            assert!(decode(input).is_err(), "{input:?}");
+        }
+    }
+}
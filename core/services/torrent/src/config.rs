@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::backend::TorrentBuilder;
+
+/// Config for the read-only BitTorrent service.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct TorrentConfig {
+    /// The http(s) url of the `.torrent` metainfo file.
+    ///
+    /// required.
+    pub torrent: Option<String>,
+    /// Comma separated web seeds to use instead of the `url-list` of the torrent.
+    pub web_seeds: Option<String>,
+    /// root of this backend.
+    pub root: Option<String>,
+}
+
+impl Debug for TorrentConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TorrentConfig")
+            .field("torrent", &self.torrent)
+            .field("web_seeds", &self.web_seeds)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+impl opendal_core::Configurator for TorrentConfig {
+    type Builder = TorrentBuilder;
+
+    fn from_uri(uri: &opendal_core::OperatorUri) -> opendal_core::Result<Self> {
+        let mut map = uri.options().clone();
+        if let Some(root) = uri.root() {
+            map.insert("root".to_string(), root.to_string());
+        }
+
+        Self::from_iter(map)
+    }
+
+    fn into_builder(self) -> Self::Builder {
+        TorrentBuilder { config: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal_core::Configurator;
+    use opendal_core::OperatorUri;
+
+    use super::*;
+
+    #[test]
+    fn from_uri_sets_root_and_options() {
+        let uri = OperatorUri::new(
+            "torrent:///data/train",
+            vec![(
+                "torrent".to_string(),
+                "https://example.com/dataset.torrent".to_string(),
+            )],
+        )
+        .unwrap();
+
+        let cfg = TorrentConfig::from_uri(&uri).unwrap();
+        assert_eq!(
+            cfg.torrent.as_deref(),
+            Some("https://example.com/dataset.torrent")
+        );
+        assert_eq!(cfg.root.as_deref(), Some("data/train"));
+    }
+}
@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use http::Request;
+use http::StatusCode;
+use http::header;
+use mea::once::OnceCell;
+use sha1::Digest;
+use sha1::Sha1;
+
+use crate::metainfo::Metainfo;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+pub struct TorrentCore {
+    pub info: ServiceInfo,
+    pub capability: Capability,
+    pub root: String,
+    pub torrent: String,
+    /// Web seeds configured by user, override the ones in metainfo.
+    pub web_seeds: Vec<String>,
+
+    pub metainfo: OnceCell<Metainfo>,
+}
+
+impl Debug for TorrentCore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TorrentCore")
+            .field("torrent", &self.torrent)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TorrentCore {
+    /// Fetch and parse the metainfo file once.
+    pub async fn metainfo(&self, ctx: &OperationContext) -> Result<&Metainfo> {
+        self.metainfo
+            .get_or_try_init(async || {
+                let req = Request::get(&self.torrent)
+                    .extension(Operation::Stat)
+                    .extension(ServiceOperation("GetMetainfo"))
+                    .body(Buffer::new())
+                    .map_err(new_request_build_error)?;
+
+                let resp = ctx.http_transport().send(req).await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp).with_context("torrent", &self.torrent));
+                }
+
+                Metainfo::parse(&resp.into_body().to_bytes())
+                    .map_err(|err| err.with_context("torrent", &self.torrent))
+            })
+            .await
+    }
+
+    fn web_seeds<'a>(&'a self, meta: &'a Metainfo) -> Result<&'a [String]> {
+        let seeds = if self.web_seeds.is_empty() {
+            &meta.web_seeds
+        } else {
+            &self.web_seeds
+        };
+        if seeds.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "torrent has no web seeds to read from",
+            )
+            .with_context("torrent", &self.torrent));
+        }
+        Ok(seeds)
+    }
+
+    /// Fetch piece at `index` and verify it against the piece hash.
+    ///
+    /// Web seeds are tried in order until one of them returns valid data.
+    pub async fn fetch_piece(&self, ctx: &OperationContext, index: usize) -> Result<Buffer> {
+        let meta = self.metainfo(ctx).await?;
+        let expected = meta.pieces.get(index).ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "piece index out of range")
+                .with_context("piece", index)
+        })?;
+
+        let mut last_err = None;
+        for seed in self.web_seeds(meta)? {
+            let err = match self.fetch_piece_from(ctx, meta, seed, index).await {
+                Ok(piece) if sha1_of(&piece) == *expected => return Ok(piece),
+                Ok(_) => Error::new(ErrorKind::Unexpected, "piece hash mismatch"),
+                Err(err) => err,
+            };
+            last_err = Some(
+                err.with_context("piece", index)
+                    .with_context("web_seed", seed),
+            );
+        }
+
+        Err(last_err.expect("web seeds must not be empty"))
+    }
+
+    async fn fetch_piece_from(
+        &self,
+        ctx: &OperationContext,
+        meta: &Metainfo,
+        seed: &str,
+        index: usize,
+    ) -> Result<Buffer> {
+        let piece = meta.piece_range(index);
+
+        // A piece could span many files, fetch the overlapped part of each.
+        let mut bufs = Vec::new();
+        for file in &meta.files {
+            let start = piece.start.max(file.offset);
+            let end = piece.end.min(file.offset + file.length);
+            if start >= end {
+                continue;
+            }
+            let size = end - start;
+
+            if file.padding {
+                bufs.push(Buffer::from(vec![0; size as usize]));
+                continue;
+            }
+
+            let url = meta.web_seed_url(seed, file);
+            let range = BytesRange::new(start - file.offset, Some(size));
+            let req = Request::get(&url)
+                .header(header::RANGE, range.to_header())
+                .extension(Operation::Read)
+                .extension(ServiceOperation("GetPiece"))
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?;
+
+            let resp = ctx.http_transport().send(req).await?;
+            let buf = match resp.status() {
+                StatusCode::PARTIAL_CONTENT => resp.into_body(),
+                // Some web seeds ignore range requests and return the whole file.
+                StatusCode::OK => {
+                    let from = range.offset() as usize;
+                    let body = resp.into_body();
+                    if body.len() < from + size as usize {
+                        return Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "web seed returned a short file",
+                        )
+                        .with_context("url", url));
+                    }
+                    body.slice(from..from + size as usize)
+                }
+                _ => return Err(parse_error(resp).with_context("url", url)),
+            };
+            bufs.push(buf);
+        }
+
+        Ok(bufs.into_iter().flatten().collect())
+    }
+}
+
+fn sha1_of(buf: &Buffer) -> [u8; 20] {
+    let mut hasher = Sha1::new();
+    buf.clone().for_each(|b| hasher.update(&b));
+    hasher.finalize().into()
+}
+
+pub(super) fn parse_error(resp: http::Response<Buffer>) -> Error {
+    let (parts, body) = resp.into_parts();
+    let bs = body.to_bytes();
+
+    let (kind, retryable) = match parts.status {
+        StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+        StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
+        StatusCode::RANGE_NOT_SATISFIABLE => (ErrorKind::RangeNotSatisfied, false),
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let message = String::from_utf8_lossy(&bs);
+    let mut err = Error::new(kind, message);
+    err = with_error_response_context(err, parts);
+    if retryable {
+        err = err.set_temporary();
+    }
+    err
+}
@@ -0,0 +1,39 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+//! BitTorrent service implementation for Apache OpenDAL.
+#![deny(missing_docs)]
+
+mod backend;
+mod bencode;
+mod config;
+mod core;
+mod lister;
+mod metainfo;
+mod reader;
+
+pub use backend::TorrentBuilder as Torrent;
+pub use config::TorrentConfig;
+
+/// Default scheme for torrent service.
+pub const TORRENT_SCHEME: &str = "torrent";
+
+/// Register this service into the given registry.
+pub fn register_torrent_service(registry: &opendal_core::OperatorRegistry) {
+    registry.register::<Torrent>(TORRENT_SCHEME);
+}
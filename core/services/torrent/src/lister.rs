@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::core::TorrentCore;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Flat lister of all files under a path, the metainfo is fetched on first
+/// call.
+pub struct TorrentLister {
+    core: Arc<TorrentCore>,
+    ctx: OperationContext,
+    path: String,
+    index: usize,
+}
+
+impl TorrentLister {
+    pub(super) fn new(core: Arc<TorrentCore>, ctx: OperationContext, path: &str) -> Self {
+        let path = build_abs_path(&core.root, path);
+        Self {
+            core,
+            ctx,
+            path,
+            index: 0,
+        }
+    }
+}
+
+impl oio::List for TorrentLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let meta = self.core.metainfo(&self.ctx).await?;
+
+        while let Some(file) = meta.files.get(self.index) {
+            self.index += 1;
+            if file.padding || !file.path.starts_with(&self.path) {
+                continue;
+            }
+
+            let path = build_rel_path(&self.core.root, &file.path);
+            let metadata = Metadata::new(EntryMode::FILE).with_content_length(file.length);
+            return Ok(Some(oio::Entry::new(&path, metadata)));
+        }
+
+        Ok(None)
+    }
+}
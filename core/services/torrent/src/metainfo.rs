@@ -0,0 +1,308 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+use crate::bencode;
+use crate::bencode::Value;
+
+/// The parts of a v1 torrent metainfo file needed to read its content.
+///
+/// ref: <https://www.bittorrent.org/beps/bep_0003.html#metainfo-files>
+#[derive(Debug)]
+pub struct Metainfo {
+    pub piece_length: u64,
+    pub pieces: Vec<[u8; 20]>,
+    /// Files in the order they are laid out in pieces.
+    pub files: Vec<TorrentFile>,
+    /// Web seeds from `url-list`.
+    ///
+    /// ref: <https://www.bittorrent.org/beps/bep_0019.html>
+    pub web_seeds: Vec<String>,
+    /// Single file torrents have no `files` list, and their web seeds can
+    /// point to the file directly.
+    pub single_file: bool,
+}
+
+#[derive(Debug)]
+pub struct TorrentFile {
+    /// Path of file like `{name}/dir/file`, or just `{name}` for single
+    /// file torrents.
+    pub path: String,
+    pub length: u64,
+    /// Offset of this file in the content of the whole torrent.
+    pub offset: u64,
+    /// Padding files only exist to align files to pieces and are all zeros.
+    ///
+    /// ref: <https://www.bittorrent.org/beps/bep_0047.html>
+    pub padding: bool,
+}
+
+fn invalid(msg: &str) -> Error {
+    Error::new(
+        ErrorKind::Unexpected,
+        format!("torrent metainfo is invalid: {msg}"),
+    )
+}
+
+/// Path segments must not escape or collapse the torrent directory.
+fn check_segment(segment: &str) -> Result<&str> {
+    if segment.is_empty() || segment == "." || segment == ".." || segment.contains('/') {
+        return Err(invalid("file path is invalid").with_context("segment", segment));
+    }
+    Ok(segment)
+}
+
+fn length(v: Option<&Value>) -> Result<u64> {
+    v.and_then(|v| v.as_int())
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or_else(|| invalid("length is missing or negative"))
+}
+
+impl Metainfo {
+    pub fn parse(bs: &[u8]) -> Result<Self> {
+        let root = bencode::decode(bs)?;
+        let info = root.get("info").ok_or_else(|| invalid("info is missing"))?;
+
+        let name = info
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| invalid("name is missing"))
+            .and_then(check_segment)?
+            .to_string();
+
+        let piece_length = length(info.get("piece length"))?;
+        if piece_length == 0 {
+            return Err(invalid("piece length is zero"));
+        }
+
+        let pieces = info
+            .get("pieces")
+            .and_then(|v| v.as_bytes())
+            .ok_or_else(|| invalid("pieces is missing"))?;
+        if pieces.len() % 20 != 0 {
+            return Err(invalid("pieces is not a list of sha1 hashes"));
+        }
+        let pieces: Vec<[u8; 20]> = pieces
+            .chunks_exact(20)
+            .map(|v| v.try_into().expect("chunk must be 20 bytes"))
+            .collect();
+
+        let mut files = Vec::new();
+        let mut offset = 0;
+        let single_file = match info.get("files") {
+            None => {
+                let length = length(info.get("length"))?;
+                files.push(TorrentFile {
+                    path: name.clone(),
+                    length,
+                    offset,
+                    padding: false,
+                });
+                offset += length;
+                true
+            }
+            Some(list) => {
+                let list = list
+                    .as_list()
+                    .ok_or_else(|| invalid("files is not a list"))?;
+                for file in list {
+                    let length = length(file.get("length"))?;
+                    let segments = file
+                        .get("path")
+                        .and_then(|v| v.as_list())
+                        .filter(|v| !v.is_empty())
+                        .ok_or_else(|| invalid("file path is missing"))?;
+
+                    let mut path = name.clone();
+                    for segment in segments {
+                        let segment = segment
+                            .as_str()
+                            .ok_or_else(|| invalid("file path is not utf-8"))?;
+                        path.push('/');
+                        path.push_str(check_segment(segment)?);
+                    }
+
+                    let padding = file
+                        .get("attr")
+                        .and_then(|v| v.as_bytes())
+                        .is_some_and(|v| v.contains(&b'p'));
+
+                    files.push(TorrentFile {
+                        path,
+                        length,
+                        offset,
+                        padding,
+                    });
+                    offset += length;
+                }
+                false
+            }
+        };
+
+        if pieces.len() as u64 != offset.div_ceil(piece_length) {
+            return Err(invalid("pieces doesn't match the total length"));
+        }
+
+        // `url-list` could be either a list or a single url.
+        let web_seeds = match root.get("url-list") {
+            Some(Value::List(list)) => list
+                .iter()
+                .filter_map(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string())
+                .collect(),
+            Some(v) => v
+                .as_str()
+                .filter(|v| !v.is_empty())
+                .map(|v| vec![v.to_string()])
+                .unwrap_or_default(),
+            None => vec![],
+        };
+
+        Ok(Self {
+            piece_length,
+            pieces,
+            files,
+            web_seeds,
+            single_file,
+        })
+    }
+
+    /// Get the file at given path, padding files are not visible.
+    pub fn file(&self, path: &str) -> Option<&TorrentFile> {
+        self.files.iter().find(|f| !f.padding && f.path == path)
+    }
+
+    /// Returns true if given path is a dir containing files, like `{name}/dir/`.
+    pub fn is_dir(&self, path: &str) -> bool {
+        self.files
+            .iter()
+            .any(|f| !f.padding && f.path.starts_with(path))
+    }
+
+    /// Get the range of piece at `index` in the content of whole torrent.
+    pub fn piece_range(&self, index: usize) -> Range<u64> {
+        let total = self.files.last().map_or(0, |f| f.offset + f.length);
+        let start = index as u64 * self.piece_length;
+        start..(start + self.piece_length).min(total)
+    }
+
+    /// Build the url of given file on a web seed.
+    pub fn web_seed_url(&self, seed: &str, file: &TorrentFile) -> String {
+        if self.single_file && !seed.ends_with('/') {
+            return seed.to_string();
+        }
+
+        let seed = seed.trim_end_matches('/');
+        format!("{seed}/{}", percent_encode_path(&file.path))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Build a bencoded multi file torrent named `data` with given
+    /// `(path, length, attr)` and concatenated piece hashes.
+    pub(crate) fn multi_file_torrent(
+        piece_length: u64,
+        files: &[(&str, u64, &str)],
+        pieces: &[u8],
+    ) -> Vec<u8> {
+        let mut bs = b"d8:url-listl19:http://seed.test/a/e4:infod5:filesl".to_vec();
+        for (path, length, attr) in files {
+            bs.extend(format!("d4:attr{}:{attr}6:lengthi{length}e4:pathl", attr.len()).bytes());
+            for segment in path.split('/') {
+                bs.extend(format!("{}:{segment}", segment.len()).bytes());
+            }
+            bs.extend(b"ee");
+        }
+        bs.extend(
+            format!(
+                "e4:name4:data12:piece lengthi{piece_length}e6:pieces{}:",
+                pieces.len()
+            )
+            .bytes(),
+        );
+        bs.extend(pieces);
+        bs.extend(b"ee");
+        bs
+    }
+
+    #[test]
+    fn test_parse_single_file() {
+        let mut bs = b"d8:url-list16:http://seed.test4:infod6:lengthi10e4:name5:a.bin12:piece lengthi4e6:pieces60:".to_vec();
+        bs.extend([0; 60]);
+        bs.extend(b"ee");
+
+        let meta = Metainfo::parse(&bs).unwrap();
+        assert!(meta.single_file);
+        assert_eq!(meta.web_seeds, vec!["http://seed.test"]);
+        let file = meta.file("a.bin").unwrap();
+        assert_eq!(file.length, 10);
+        assert_eq!(meta.piece_range(2), 8..10);
+        assert_eq!(
+            meta.web_seed_url("http://seed.test", file),
+            "http://seed.test"
+        );
+        assert_eq!(
+            meta.web_seed_url("http://seed.test/", file),
+            "http://seed.test/a.bin"
+        );
+    }
+
+    #[test]
+    fn test_parse_multi_file() {
+        let bs = multi_file_torrent(
+            4,
+            &[("x/hello world", 3, ""), ("pad", 1, "p"), ("y", 5, "x")],
+            &[0; 60],
+        );
+
+        let meta = Metainfo::parse(&bs).unwrap();
+        assert!(!meta.single_file);
+        assert_eq!(meta.web_seeds, vec!["http://seed.test/a/"]);
+
+        assert!(meta.file("data/pad").is_none());
+        let file = meta.file("data/y").unwrap();
+        assert_eq!((file.offset, file.length), (4, 5));
+        assert!(meta.is_dir("data/x/"));
+        assert!(!meta.is_dir("data/z/"));
+
+        let file = meta.file("data/x/hello world").unwrap();
+        assert_eq!(
+            meta.web_seed_url("http://seed.test/a", file),
+            "http://seed.test/a/data/x/hello%20world"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (files, pieces) in [
+            (vec![("a", 3, "")], 2),
+            (vec![("../a", 3, "")], 1),
+            (vec![("a//b", 3, "")], 1),
+        ] {
+            let bs = multi_file_torrent(4, &files, &vec![0; pieces * 20]);
+            assert!(Metainfo::parse(&bs).is_err(), "{files:?}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use super::core::TorrentCore;
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Reader returned by this backend.
+pub struct TorrentReader {
+    core: Arc<TorrentCore>,
+    ctx: OperationContext,
+    path: String,
+}
+
+impl TorrentReader {
+    pub(super) fn new(core: Arc<TorrentCore>, ctx: OperationContext, path: &str) -> Self {
+        Self {
+            core,
+            ctx,
+            path: path.to_string(),
+        }
+    }
+}
+
+pub struct TorrentReaderHandle {
+    core: Arc<TorrentCore>,
+    ctx: OperationContext,
+    /// Offset of the file in the content of whole torrent.
+    offset: u64,
+    length: u64,
+    /// The last fetched piece, sequential reads hit the same piece many times.
+    piece: Mutex<Option<(usize, Buffer)>>,
+}
+
+impl TorrentReaderHandle {
+    async fn piece(&self, index: usize) -> Result<Buffer> {
+        if let Some((cached, buf)) = &*self.piece.lock().expect("lock must succeed") {
+            if *cached == index {
+                return Ok(buf.clone());
+            }
+        }
+
+        let buf = self.core.fetch_piece(&self.ctx, index).await?;
+        *self.piece.lock().expect("lock must succeed") = Some((index, buf.clone()));
+        Ok(buf)
+    }
+}
+
+impl oio::PositionRead for TorrentReader {
+    type Handle = TorrentReaderHandle;
+
+    async fn open(&self) -> Result<Self::Handle> {
+        let meta = self.core.metainfo(&self.ctx).await?;
+        let p = build_abs_path(&self.core.root, &self.path);
+        let file = meta
+            .file(&p)
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "file not found in torrent"))?;
+
+        Ok(TorrentReaderHandle {
+            core: self.core.clone(),
+            ctx: self.ctx.clone(),
+            offset: file.offset,
+            length: file.length,
+            piece: Mutex::new(None),
+        })
+    }
+
+    /// Read from the piece containing `offset`, so at most one piece is
+    /// fetched for each call.
+    async fn read_at(handle: &Self::Handle, offset: u64, size: usize) -> Result<Buffer> {
+        if size == 0 || offset >= handle.length {
+            return Ok(Buffer::new());
+        }
+
+        let meta = handle.core.metainfo(&handle.ctx).await?;
+        let start = handle.offset + offset;
+        let end = handle.offset + handle.length.min(offset + size as u64);
+
+        let index = (start / meta.piece_length) as usize;
+        let piece_range = meta.piece_range(index);
+        let piece = handle.piece(index).await?;
+
+        let from = (start - piece_range.start) as usize;
+        let to = (end.min(piece_range.end) - piece_range.start) as usize;
+        Ok(piece.slice(from..to))
+    }
+}
@@ -342,7 +342,8 @@ impl Service for TosBackend {
         let output: oio::MultipartWriter<TosWriter> = {
             let writer = TosWriter::new(self.core.clone(), ctx.clone(), path, args.clone());
 
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent());
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, args.concurrent())
+                .with_adaptive_concurrency(args.adaptive_concurrency().cloned());
 
             Ok(w)
         }?;
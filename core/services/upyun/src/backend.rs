@@ -143,6 +143,7 @@ impl Builder for UpyunBuilder {
                     stat: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     read: true,
                     read_with_suffix: true,
@@ -240,9 +241,11 @@ impl Service for UpyunBackend {
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: UpyunWriters = {
             let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
             let writer = UpyunWriter::new(self.core.clone(), ctx.clone(), args, path.to_string());
 
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent);
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent)
+                .with_adaptive_concurrency(adaptive);
 
             Ok(w)
         }?;
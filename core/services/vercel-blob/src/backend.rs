@@ -107,6 +107,8 @@ impl Builder for VercelBlobBuilder {
                     write_can_empty: true,
                     write_can_multi: true,
                     write_multi_min_size: Some(5 * 1024 * 1024),
+                    write_with_content_type: true,
+                    write_with_cache_control: true,
 
                     copy: true,
 
@@ -192,10 +194,12 @@ impl Service for VercelBlobBackend {
     fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
         let output: VercelBlobWriters = {
             let concurrent = args.concurrent();
+            let adaptive = args.adaptive_concurrency().cloned();
             let writer =
                 VercelBlobWriter::new(self.core.clone(), ctx.clone(), args, path.to_string());
 
-            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent);
+            let w = oio::MultipartWriter::new(ctx.executor().clone(), writer, concurrent)
+                .with_adaptive_concurrency(adaptive);
 
             Ok(w)
         }?;
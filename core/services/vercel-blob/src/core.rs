@@ -36,6 +36,8 @@ pub(super) mod constants {
     // https://github.com/vercel/storage/blob/main/packages/blob/src/put.ts#L16
     // x-content-type specifies the MIME type of the file being uploaded.
     pub const X_VERCEL_BLOB_CONTENT_TYPE: &str = "x-content-type";
+    // x-cache-control-max-age specifies the max-age of cache control in seconds.
+    pub const X_VERCEL_BLOB_CACHE_CONTROL_MAX_AGE: &str = "x-cache-control-max-age";
     // x-add-random-suffix specifying whether to  add a random suffix to the pathname
     // Default value is 1, which means to add a random suffix.
     // Set it to 0 to disable the random suffix.
@@ -146,6 +148,13 @@ impl VercelBlobCore {
             req = req.header(X_VERCEL_BLOB_CONTENT_TYPE, mime)
         }
 
+        if let Some(cache_control) = args.cache_control() {
+            req = req.header(
+                X_VERCEL_BLOB_CACHE_CONTROL_MAX_AGE,
+                parse_cache_control_max_age(cache_control)?,
+            )
+        }
+
         let req = self.sign(req);
 
         // Set body
@@ -329,6 +338,13 @@ impl VercelBlobCore {
             req = req.header(X_VERCEL_BLOB_CONTENT_TYPE, mime);
         };
 
+        if let Some(cache_control) = args.cache_control() {
+            req = req.header(
+                X_VERCEL_BLOB_CACHE_CONTROL_MAX_AGE,
+                parse_cache_control_max_age(cache_control)?,
+            );
+        }
+
         // Set body
         let req = req
             .extension(Operation::Write)
@@ -423,9 +439,27 @@ pub fn parse_blob(blob: &Blob) -> Result<Metadata> {
     md.set_content_length(blob.size);
     md.set_last_modified(blob.uploaded_at.parse::<Timestamp>()?);
     md.set_content_disposition(&blob.content_disposition);
+    if let Some(cache_control) = blob.cache_control.clone() {
+        md.set_cache_control(&cache_control);
+    }
     Ok(md)
 }
 
+/// Vercel Blob only supports the `max-age` directive of cache control.
+fn parse_cache_control_max_age(cache_control: &str) -> Result<u64> {
+    cache_control
+        .split(',')
+        .filter_map(|v| v.trim().strip_prefix("max-age="))
+        .find_map(|v| v.parse().ok())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "vercel blob only supports max-age of cache control",
+            )
+            .with_context("cache_control", cache_control)
+        })
+}
+
 fn resolve_blob(blobs: Vec<Blob>, path: String) -> String {
     for blob in blobs {
         if blob.pathname == path {
@@ -452,6 +486,8 @@ pub struct Blob {
     pub uploaded_at: String,
     pub content_disposition: String,
     pub content_type: Option<String>,
+    #[serde(default)]
+    pub cache_control: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -572,3 +608,21 @@ mod error {
 }
 
 pub(super) use error::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cache_control_max_age() {
+        assert_eq!(parse_cache_control_max_age("max-age=3600").unwrap(), 3600);
+        assert_eq!(
+            parse_cache_control_max_age("public, max-age=60").unwrap(),
+            60
+        );
+        assert_eq!(
+            parse_cache_control_max_age("no-cache").unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+    }
+}
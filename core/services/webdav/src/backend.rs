@@ -204,8 +204,10 @@ impl Builder for WebdavBuilder {
                 write: true,
                 write_can_empty: true,
                 write_with_user_metadata: true,
+                write_with_lock: true,
 
                 create_dir: true,
+                create_dir_persistent: true,
                 delete: true,
 
                 copy: true,
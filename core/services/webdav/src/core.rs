@@ -80,6 +80,34 @@ static HEADER_DESTINATION: &str = "Destination";
 ///
 /// reference: [RFC4918: 10.6.  Overwrite Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.6)
 static HEADER_OVERWRITE: &str = "Overwrite";
+/// The header to submit state tokens of the resource.
+///
+/// The If request header carries the lock token acquired by LOCK, so the
+/// server allows the request to modify the locked resource.
+///
+/// reference: [RFC4918: 10.4.  If Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.4)
+static HEADER_IF: &str = "If";
+/// The header to carry the lock token.
+///
+/// It's returned by a successful LOCK and is required by UNLOCK to
+/// identify the lock to be removed.
+///
+/// reference: [RFC4918: 10.5.  Lock-Token Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.5)
+static HEADER_LOCK_TOKEN: &str = "Lock-Token";
+/// The header to specify the timeout of the lock.
+///
+/// reference: [RFC4918: 10.7.  Timeout Request Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.7)
+static HEADER_TIMEOUT: &str = "Timeout";
+
+/// The request to acquire an exclusive write lock.
+///
+/// rfc4918 9.10: creating a lock on a resource
+static LOCK_REQUEST: &str = r#"<?xml version="1.0" encoding="utf-8" ?><D:lockinfo xmlns:D="DAV:"><D:lockscope><D:exclusive/></D:lockscope><D:locktype><D:write/></D:locktype><D:owner>opendal</D:owner></D:lockinfo>"#;
+/// The timeout of the lock acquired around writes.
+///
+/// The lock is released by UNLOCK once the write is done, the timeout only
+/// makes sure the lock won't be held forever if we failed to release it.
+static LOCK_TIMEOUT: &str = "Second-600";
 
 pub struct WebdavCore {
     pub info: ServiceInfo,
@@ -201,6 +229,7 @@ impl WebdavCore {
         path: &str,
         size: Option<u64>,
         args: &OpWrite,
+        lock_token: Option<&str>,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
         let path = build_rooted_abs_path(&self.root, path);
@@ -224,6 +253,10 @@ impl WebdavCore {
             req = req.header(header::CONTENT_DISPOSITION, v)
         }
 
+        if let Some(token) = lock_token {
+            req = req.header(HEADER_IF, format!("(<{token}>)"))
+        }
+
         let req = req
             .extension(Operation::Write)
             .extension(ServiceOperation("Put"))
@@ -246,6 +279,7 @@ impl WebdavCore {
         ctx: &OperationContext,
         path: &str,
         user_metadata: &HashMap<String, String>,
+        lock_token: Option<&str>,
     ) -> Result<Response<Buffer>> {
         let path = build_rooted_abs_path(&self.root, path);
         let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
@@ -256,6 +290,9 @@ impl WebdavCore {
         if let Some(auth) = &self.authorization {
             req = req.header(header::AUTHORIZATION, auth);
         }
+        if let Some(token) = lock_token {
+            req = req.header(HEADER_IF, format!("(<{token}>)"));
+        }
 
         // Build the PROPPATCH XML request body using configured namespace
         let proppatch_body = build_proppatch_request(
@@ -273,6 +310,71 @@ impl WebdavCore {
         ctx.http_transport().send(req).await
     }
 
+    /// Acquire an exclusive write lock on the resource and return the lock token.
+    ///
+    /// # Reference
+    /// - [RFC4918: 9.10 LOCK Method](https://datatracker.ietf.org/doc/html/rfc4918#section-9.10)
+    pub async fn webdav_lock(&self, ctx: &OperationContext, path: &str) -> Result<String> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let mut req = Request::builder().method("LOCK").uri(&url);
+
+        req = req.header(header::CONTENT_TYPE, "application/xml; charset=utf-8");
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+        req = req.header(HEADER_DEPTH, "0");
+        req = req.header(HEADER_TIMEOUT, LOCK_TIMEOUT);
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("Lock"))
+            .body(Buffer::from(Bytes::from(LOCK_REQUEST)))
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::CREATED => parse_lock_token(resp.headers()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Release the lock identified by the given lock token.
+    ///
+    /// # Reference
+    /// - [RFC4918: 9.11 UNLOCK Method](https://datatracker.ietf.org/doc/html/rfc4918#section-9.11)
+    pub async fn webdav_unlock(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        lock_token: &str,
+    ) -> Result<()> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let mut req = Request::builder().method("UNLOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+        req = req.header(HEADER_LOCK_TOKEN, format!("<{lock_token}>"));
+
+        let req = req
+            .extension(Operation::Write)
+            .extension(ServiceOperation("Unlock"))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let resp = ctx.http_transport().send(req).await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     pub async fn webdav_delete(
         &self,
         ctx: &OperationContext,
@@ -568,6 +670,33 @@ pub fn build_proppatch_request(
     String::from_utf8(writer.into_inner().into_inner()).expect("valid utf8")
 }
 
+/// Parse the lock token from the `Lock-Token` header of a LOCK response.
+///
+/// The header value is a Coded-URL like `<opaquelocktoken:xxx>`, the angle
+/// brackets are stripped from the returned token.
+pub fn parse_lock_token(headers: &http::HeaderMap) -> Result<String> {
+    let value = parse_header_to_str(headers, HEADER_LOCK_TOKEN)?.ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "lock token is missing in LOCK response",
+        )
+    })?;
+
+    let token = value
+        .trim()
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim();
+    if token.is_empty() {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            "lock token is empty in LOCK response",
+        ));
+    }
+
+    Ok(token.to_string())
+}
+
 /// Parse user metadata from the raw XML response using quick-xml Reader.
 ///
 /// This function extracts properties in the specified namespace
@@ -872,6 +1001,27 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_lock_token() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            HEADER_LOCK_TOKEN,
+            "<opaquelocktoken:e71d4fae-5dec-22d6-fea5-00a0c91e6be4>"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            parse_lock_token(&headers).unwrap(),
+            "opaquelocktoken:e71d4fae-5dec-22d6-fea5-00a0c91e6be4"
+        );
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(HEADER_LOCK_TOKEN, "<>".parse().unwrap());
+        assert!(parse_lock_token(&headers).is_err());
+
+        assert!(parse_lock_token(&http::HeaderMap::new()).is_err());
+    }
+
     fn new_propstat(status: &str, getcontentlength: Option<&str>) -> Propstat {
         Propstat {
             status: status.to_string(),
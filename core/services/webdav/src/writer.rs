@@ -55,20 +55,18 @@ impl WebdavWriter {
 
         Ok(metadata)
     }
-}
-
-impl oio::OneShotWrite for WebdavWriter {
-    async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
-        // Ensure parent path exists unless disabled for servers that don't support PROPFIND.
-        if !self.core.disable_create_dir {
-            self.core
-                .webdav_mkcol(&self.ctx, get_parent(&self.path))
-                .await?;
-        }
 
+    async fn put(&self, bs: Buffer, lock_token: Option<&str>) -> Result<Metadata> {
         let resp = self
             .core
-            .webdav_put(&self.ctx, &self.path, Some(bs.len() as u64), &self.op, bs)
+            .webdav_put(
+                &self.ctx,
+                &self.path,
+                Some(bs.len() as u64),
+                &self.op,
+                lock_token,
+                bs,
+            )
             .await?;
 
         let status = resp.status();
@@ -81,7 +79,7 @@ impl oio::OneShotWrite for WebdavWriter {
                 if let Some(user_metadata) = self.op.user_metadata() {
                     let proppatch_resp = self
                         .core
-                        .webdav_proppatch(&self.ctx, &self.path, user_metadata)
+                        .webdav_proppatch(&self.ctx, &self.path, user_metadata, lock_token)
                         .await?;
 
                     let proppatch_status = proppatch_resp.status();
@@ -102,3 +100,27 @@ impl oio::OneShotWrite for WebdavWriter {
         }
     }
 }
+
+impl oio::OneShotWrite for WebdavWriter {
+    async fn write_once(&self, bs: Buffer) -> Result<Metadata> {
+        // Ensure parent path exists unless disabled for servers that don't support PROPFIND.
+        if !self.core.disable_create_dir {
+            self.core
+                .webdav_mkcol(&self.ctx, get_parent(&self.path))
+                .await?;
+        }
+
+        if !self.op.lock() {
+            return self.put(bs, None).await;
+        }
+
+        let token = self.core.webdav_lock(&self.ctx, &self.path).await?;
+        let res = self.put(bs, Some(&token)).await;
+        // Always release the lock, but keep the write error if both failed.
+        let unlock = self.core.webdav_unlock(&self.ctx, &self.path, &token).await;
+
+        let metadata = res?;
+        unlock?;
+        Ok(metadata)
+    }
+}
@@ -176,10 +176,14 @@ impl Builder for WebhdfsBuilder {
             write_can_multi: atomic_write_dir.is_some(),
 
             create_dir: true,
+            create_dir_persistent: true,
             delete: true,
 
             list: true,
 
+            rename: true,
+            rename_is_atomic: true,
+
             shared: true,
 
             ..Default::default()
@@ -341,11 +345,10 @@ impl Service for WebhdfsBackend {
             let w = if args.append() {
                 WebhdfsWriters::Two(oio::AppendWriter::new(w))
             } else {
-                WebhdfsWriters::One(oio::BlockWriter::new(
-                    ctx.executor().clone(),
-                    w,
-                    args.concurrent(),
-                ))
+                WebhdfsWriters::One(
+                    oio::BlockWriter::new(ctx.executor().clone(), w, args.concurrent())
+                        .with_adaptive_concurrency(args.adaptive_concurrency().cloned()),
+                )
             };
 
             Ok(w)
@@ -398,15 +401,34 @@ impl Service for WebhdfsBackend {
 
     async fn rename(
         &self,
-        _ctx: &OperationContext,
-        _from: &str,
-        _to: &str,
-        _args: OpRename,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        _: OpRename,
     ) -> Result<RpRename> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "operation is not supported",
-        ))
+        // Overwriting rename requires the parent of `to` to exist.
+        let resp = self.core.webhdfs_create_dir(ctx, get_parent(to)).await?;
+        if !matches!(resp.status(), StatusCode::CREATED | StatusCode::OK) {
+            return Err(parse_error(resp));
+        }
+
+        let resp = self.core.webhdfs_rename_object(ctx, from, to).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                // Gateways without `rename2` support fall back to plain rename,
+                // which reports failures with `{"boolean": false}`.
+                let bs = resp.into_body();
+                if let Ok(resp) = serde_json::from_reader::<_, BooleanResp>(bs.reader()) {
+                    if !resp.boolean {
+                        return Err(Error::new(ErrorKind::Unexpected, "webhdfs rename failed")
+                            .with_context("from", from)
+                            .with_context("to", to));
+                    }
+                }
+                Ok(RpRename::default())
+            }
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn presign(
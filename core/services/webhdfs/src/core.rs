@@ -153,8 +153,10 @@ impl WebhdfsCore {
         let from = build_abs_path(&self.root, from);
         let to = build_rooted_abs_path(&self.root, to);
 
+        // `renameoptions=OVERWRITE` makes namenode use `rename2`, which
+        // replaces an existing `to` atomically instead of failing.
         let mut url = format!(
-            "{}/webhdfs/v1/{}?op=RENAME&destination={}",
+            "{}/webhdfs/v1/{}?op=RENAME&destination={}&renameoptions=OVERWRITE",
             self.endpoint,
             percent_encode_path(&from),
             percent_encode_path(&to)
@@ -167,6 +169,7 @@ impl WebhdfsCore {
         }
 
         let req = Request::put(&url)
+            .extension(ServiceOperation("Rename"))
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
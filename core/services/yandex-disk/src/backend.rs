@@ -100,6 +100,7 @@ impl Builder for YandexDiskBuilder {
                     stat: true,
 
                     create_dir: true,
+                    create_dir_persistent: true,
 
                     read: true,
                     read_with_suffix: true,
@@ -76,9 +76,17 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-b2")]
     opendal_service_b2::register_b2_service(registry);
 
+    #[cfg(feature = "services-box")]
+    opendal_service_box::register_box_service(registry);
+
     #[cfg(feature = "services-cacache")]
     opendal_service_cacache::register_cacache_service(registry);
 
+    #[cfg(feature = "services-cephfs")]
+    opendal_service_cephfs::register_cephfs_service(registry);
+
+    #[cfg(feature = "services-clickhouse")]
+    opendal_service_clickhouse::register_clickhouse_service(registry);
     #[cfg(feature = "services-cloudflare-kv")]
     opendal_service_cloudflare_kv::register_cloudflare_kv_service(registry);
 
@@ -91,6 +99,9 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-d1")]
     opendal_service_d1::register_d1_service(registry);
 
+    #[cfg(feature = "services-databricks")]
+    opendal_service_databricks::register_databricks_service(registry);
+
     #[cfg(feature = "services-dashmap")]
     opendal_service_dashmap::register_dashmap_service(registry);
 
@@ -100,6 +111,9 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-dropbox")]
     opendal_service_dropbox::register_dropbox_service(registry);
 
+    #[cfg(feature = "services-ec")]
+    opendal_service_ec::register_ec_service(registry);
+
     #[cfg(feature = "services-etcd")]
     opendal_service_etcd::register_etcd_service(registry);
 
@@ -115,6 +129,9 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-ftp")]
     opendal_service_ftp::register_ftp_service(registry);
 
+    #[cfg(feature = "services-garage")]
+    opendal_service_garage::register_garage_service(registry);
+
     #[cfg(feature = "services-gcs")]
     opendal_service_gcs::register_gcs_service(registry);
 
@@ -145,12 +162,18 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-hf")]
     opendal_service_hf::register_hf_service(registry);
 
+    #[cfg(feature = "services-iceberg-fileio")]
+    opendal_service_iceberg_fileio::register_iceberg_fileio_service(registry);
+
     #[cfg(feature = "services-ipfs")]
     opendal_service_ipfs::register_ipfs_service(registry);
 
     #[cfg(feature = "services-ipmfs")]
     opendal_service_ipmfs::register_ipmfs_service(registry);
 
+    #[cfg(feature = "services-kafka")]
+    opendal_service_kafka::register_kafka_service(registry);
+
     #[cfg(feature = "services-koofr")]
     opendal_service_koofr::register_koofr_service(registry);
 
@@ -175,6 +198,12 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-mysql")]
     opendal_service_mysql::register_mysql_service(registry);
 
+    #[cfg(feature = "services-netlify-blobs")]
+    opendal_service_netlify_blobs::register_netlify_blobs_service(registry);
+
+    #[cfg(feature = "services-nfs")]
+    opendal_service_nfs::register_nfs_service(registry);
+
     #[cfg(feature = "services-obs")]
     opendal_service_obs::register_obs_service(registry);
 
@@ -183,6 +212,8 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
 
     #[cfg(feature = "services-oss")]
     opendal_service_oss::register_oss_service(registry);
+    #[cfg(feature = "services-ozone")]
+    opendal_service_ozone::register_ozone_service(registry);
 
     #[cfg(feature = "services-pcloud")]
     opendal_service_pcloud::register_pcloud_service(registry);
@@ -193,12 +224,18 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-postgresql")]
     opendal_service_postgresql::register_postgresql_service(registry);
 
+    #[cfg(feature = "services-r2")]
+    opendal_service_r2::register_r2_service(registry);
+
     #[cfg(feature = "services-redb")]
     opendal_service_redb::register_redb_service(registry);
 
     #[cfg(feature = "services-rocksdb")]
     opendal_service_rocksdb::register_rocksdb_service(registry);
 
+    #[cfg(feature = "services-rsync")]
+    opendal_service_rsync::register_rsync_service(registry);
+
     #[cfg(feature = "services-s3")]
     opendal_service_s3::register_s3_service(registry);
 
@@ -210,6 +247,11 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
 
     #[cfg(feature = "services-sled")]
     opendal_service_sled::register_sled_service(registry);
+    #[cfg(any(feature = "services-smb", feature = "services-smb-kerberos"))]
+    opendal_service_smb::register_smb_service(registry);
+
+    #[cfg(feature = "services-spaces")]
+    opendal_service_spaces::register_spaces_service(registry);
 
     #[cfg(feature = "services-sqlite")]
     opendal_service_sqlite::register_sqlite_service(registry);
@@ -223,6 +265,9 @@ fn init_default_registry_inner(registry: &OperatorRegistry) {
     #[cfg(feature = "services-tikv")]
     opendal_service_tikv::register_tikv_service(registry);
 
+    #[cfg(feature = "services-torrent")]
+    opendal_service_torrent::register_torrent_service(registry);
+
     #[cfg(feature = "services-tos")]
     opendal_service_tos::register_tos_service(registry);
 
@@ -272,8 +317,14 @@ pub mod services {
     pub use opendal_service_azfile::*;
     #[cfg(feature = "services-b2")]
     pub use opendal_service_b2::*;
+    #[cfg(feature = "services-box")]
+    pub use opendal_service_box::*;
     #[cfg(feature = "services-cacache")]
     pub use opendal_service_cacache::*;
+    #[cfg(feature = "services-cephfs")]
+    pub use opendal_service_cephfs::*;
+    #[cfg(feature = "services-clickhouse")]
+    pub use opendal_service_clickhouse::*;
     #[cfg(feature = "services-cloudflare-kv")]
     pub use opendal_service_cloudflare_kv::*;
     #[cfg(feature = "services-compfs")]
@@ -284,10 +335,14 @@ pub mod services {
     pub use opendal_service_d1::*;
     #[cfg(feature = "services-dashmap")]
     pub use opendal_service_dashmap::*;
+    #[cfg(feature = "services-databricks")]
+    pub use opendal_service_databricks::*;
     #[cfg(feature = "services-dbfs")]
     pub use opendal_service_dbfs::*;
     #[cfg(feature = "services-dropbox")]
     pub use opendal_service_dropbox::*;
+    #[cfg(feature = "services-ec")]
+    pub use opendal_service_ec::*;
     #[cfg(feature = "services-etcd")]
     pub use opendal_service_etcd::*;
     #[cfg(feature = "services-foundationdb")]
@@ -298,6 +353,8 @@ pub mod services {
     pub use opendal_service_fs::*;
     #[cfg(feature = "services-ftp")]
     pub use opendal_service_ftp::*;
+    #[cfg(feature = "services-garage")]
+    pub use opendal_service_garage::*;
     #[cfg(feature = "services-gcs")]
     pub use opendal_service_gcs::*;
     #[cfg(feature = "services-gdrive")]
@@ -318,10 +375,14 @@ pub mod services {
     pub use opendal_service_hf::*;
     #[cfg(feature = "services-http")]
     pub use opendal_service_http::*;
+    #[cfg(feature = "services-iceberg-fileio")]
+    pub use opendal_service_iceberg_fileio::*;
     #[cfg(feature = "services-ipfs")]
     pub use opendal_service_ipfs::*;
     #[cfg(feature = "services-ipmfs")]
     pub use opendal_service_ipmfs::*;
+    #[cfg(feature = "services-kafka")]
+    pub use opendal_service_kafka::*;
     #[cfg(feature = "services-koofr")]
     pub use opendal_service_koofr::*;
     #[cfg(feature = "services-lakefs")]
@@ -338,6 +399,10 @@ pub mod services {
     pub use opendal_service_monoiofs::*;
     #[cfg(feature = "services-mysql")]
     pub use opendal_service_mysql::*;
+    #[cfg(feature = "services-netlify-blobs")]
+    pub use opendal_service_netlify_blobs::*;
+    #[cfg(feature = "services-nfs")]
+    pub use opendal_service_nfs::*;
     #[cfg(feature = "services-obs")]
     pub use opendal_service_obs::*;
     #[cfg(feature = "services-onedrive")]
@@ -346,18 +411,24 @@ pub mod services {
     pub use opendal_service_opfs::*;
     #[cfg(feature = "services-oss")]
     pub use opendal_service_oss::*;
+    #[cfg(feature = "services-ozone")]
+    pub use opendal_service_ozone::*;
     #[cfg(feature = "services-pcloud")]
     pub use opendal_service_pcloud::*;
     #[cfg(feature = "services-persy")]
     pub use opendal_service_persy::*;
     #[cfg(feature = "services-postgresql")]
     pub use opendal_service_postgresql::*;
+    #[cfg(feature = "services-r2")]
+    pub use opendal_service_r2::*;
     #[cfg(feature = "services-redb")]
     pub use opendal_service_redb::*;
     #[cfg(feature = "services-redis")]
     pub use opendal_service_redis::*;
     #[cfg(feature = "services-rocksdb")]
     pub use opendal_service_rocksdb::*;
+    #[cfg(feature = "services-rsync")]
+    pub use opendal_service_rsync::*;
     #[cfg(feature = "services-s3")]
     pub use opendal_service_s3::*;
     #[cfg(feature = "services-seafile")]
@@ -366,6 +437,10 @@ pub mod services {
     pub use opendal_service_sftp::*;
     #[cfg(feature = "services-sled")]
     pub use opendal_service_sled::*;
+    #[cfg(any(feature = "services-smb", feature = "services-smb-kerberos"))]
+    pub use opendal_service_smb::*;
+    #[cfg(feature = "services-spaces")]
+    pub use opendal_service_spaces::*;
     #[cfg(feature = "services-sqlite")]
     pub use opendal_service_sqlite::*;
     #[cfg(feature = "services-surrealdb")]
@@ -374,6 +449,8 @@ pub mod services {
     pub use opendal_service_swift::*;
     #[cfg(feature = "services-tikv")]
     pub use opendal_service_tikv::*;
+    #[cfg(feature = "services-torrent")]
+    pub use opendal_service_torrent::*;
     #[cfg(feature = "services-tos")]
     pub use opendal_service_tos::*;
     #[cfg(feature = "services-upyun")]
@@ -395,36 +472,60 @@ pub mod layers {
     pub use opendal_core::layers::*;
     #[cfg(feature = "layers-async-backtrace")]
     pub use opendal_layer_async_backtrace::*;
+    #[cfg(feature = "layers-audit")]
+    pub use opendal_layer_audit::*;
     #[cfg(feature = "layers-await-tree")]
     pub use opendal_layer_await_tree::*;
+    #[cfg(feature = "layers-cache")]
+    pub use opendal_layer_cache::*;
     #[cfg(feature = "layers-capability-check")]
     pub use opendal_layer_capability_check::*;
+    #[cfg(feature = "layers-cas")]
+    pub use opendal_layer_cas::*;
     #[cfg(feature = "layers-chaos")]
     pub use opendal_layer_chaos::*;
+    #[cfg(feature = "layers-circuit-breaker")]
+    pub use opendal_layer_circuit_breaker::*;
     #[cfg(feature = "layers-concurrent-limit")]
     pub use opendal_layer_concurrent_limit::*;
+    #[cfg(feature = "layers-decompress")]
+    pub use opendal_layer_decompress::*;
+    #[cfg(feature = "layers-dedup")]
+    pub use opendal_layer_dedup::*;
     #[cfg(all(target_os = "linux", feature = "layers-dtrace"))]
     pub use opendal_layer_dtrace::*;
+    #[cfg(feature = "layers-encryption")]
+    pub use opendal_layer_encryption::*;
     #[cfg(feature = "layers-fastmetrics")]
     pub use opendal_layer_fastmetrics::*;
     #[cfg(feature = "layers-fastrace")]
     pub use opendal_layer_fastrace::*;
     #[cfg(feature = "layers-foyer")]
     pub use opendal_layer_foyer::*;
+    #[cfg(feature = "layers-hook")]
+    pub use opendal_layer_hook::*;
     #[cfg(feature = "layers-hotpath")]
     pub use opendal_layer_hotpath::*;
     #[cfg(feature = "layers-immutable-index")]
     pub use opendal_layer_immutable_index::*;
     #[cfg(feature = "layers-logging")]
     pub use opendal_layer_logging::*;
+    #[cfg(feature = "layers-manifest")]
+    pub use opendal_layer_manifest::*;
+    #[cfg(feature = "layers-meta-cache")]
+    pub use opendal_layer_meta_cache::*;
     #[cfg(feature = "layers-metrics")]
     pub use opendal_layer_metrics::*;
     #[cfg(feature = "layers-mime-guess")]
     pub use opendal_layer_mime_guess::*;
+    #[cfg(feature = "layers-mirror")]
+    pub use opendal_layer_mirror::*;
     #[cfg(feature = "layers-otel-metrics")]
     pub use opendal_layer_otelmetrics::*;
     #[cfg(feature = "layers-otel-trace")]
     pub use opendal_layer_oteltrace::*;
+    #[cfg(feature = "layers-prefetch")]
+    pub use opendal_layer_prefetch::*;
     #[cfg(feature = "layers-prometheus")]
     pub use opendal_layer_prometheus::*;
     #[cfg(feature = "layers-prometheus-client")]
@@ -433,8 +534,12 @@ pub mod layers {
     pub use opendal_layer_retry::*;
     #[cfg(feature = "layers-route")]
     pub use opendal_layer_route::*;
+    #[cfg(feature = "layers-snapshot")]
+    pub use opendal_layer_snapshot::*;
     #[cfg(feature = "layers-tail-cut")]
     pub use opendal_layer_tail_cut::*;
+    #[cfg(feature = "layers-tenant")]
+    pub use opendal_layer_tenant::*;
     #[cfg(feature = "layers-throttle")]
     pub use opendal_layer_throttle::*;
     #[cfg(feature = "layers-timeout")]
@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Layer that counts requests reaching the wrapped service.
+///
+/// Apply it below the layer under test to check how many requests that layer
+/// sends to the service. All operations are forwarded as is.
+#[derive(Debug, Clone, Default)]
+pub struct CountLayer {
+    counts: Arc<Counts>,
+    read_delay: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct Counts {
+    readers: AtomicUsize,
+    reads: AtomicUsize,
+    completed_reads: AtomicUsize,
+    inflight_reads: AtomicUsize,
+    max_inflight_reads: AtomicUsize,
+    stats: AtomicUsize,
+    lists: AtomicUsize,
+}
+
+impl CountLayer {
+    /// Create a new `CountLayer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delay every bounded read, so that concurrent reads overlap.
+    pub fn with_read_delay(mut self, delay: Duration) -> Self {
+        self.read_delay = Some(delay);
+        self
+    }
+
+    /// Number of readers created by `read`.
+    pub fn readers(&self) -> usize {
+        self.counts.readers.load(Ordering::SeqCst)
+    }
+
+    /// Number of `open` and `read` requests sent by readers.
+    pub fn reads(&self) -> usize {
+        self.counts.reads.load(Ordering::SeqCst)
+    }
+
+    /// Number of bounded reads that have finished.
+    pub fn completed_reads(&self) -> usize {
+        self.counts.completed_reads.load(Ordering::SeqCst)
+    }
+
+    /// Number of bounded reads in flight.
+    pub fn inflight_reads(&self) -> usize {
+        self.counts.inflight_reads.load(Ordering::SeqCst)
+    }
+
+    /// Max number of bounded reads that have been in flight at the same time.
+    pub fn max_inflight_reads(&self) -> usize {
+        self.counts.max_inflight_reads.load(Ordering::SeqCst)
+    }
+
+    /// Number of `stat` requests.
+    pub fn stats(&self) -> usize {
+        self.counts.stats.load(Ordering::SeqCst)
+    }
+
+    /// Number of listers created by `list`.
+    pub fn lists(&self) -> usize {
+        self.counts.lists.load(Ordering::SeqCst)
+    }
+}
+
+impl Layer for CountLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(CountService {
+            inner,
+            layer: self.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct CountService {
+    inner: Servicer,
+    layer: CountLayer,
+}
+
+impl Service for CountService {
+    type Reader = CountReader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type Deleter = oio::Deleter;
+    type Copier = oio::Copier;
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        self.inner.capability()
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.layer.counts.stats.fetch_add(1, Ordering::SeqCst);
+        self.inner.stat(ctx, path, args).await
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.layer.counts.readers.fetch_add(1, Ordering::SeqCst);
+        Ok(CountReader {
+            inner: self.inner.read(ctx, path, args)?,
+            layer: self.layer.clone(),
+        })
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        self.inner.write(ctx, path, args)
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        self.inner.delete(ctx)
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.layer.counts.lists.fetch_add(1, Ordering::SeqCst);
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+        opts: OpCopier,
+    ) -> Result<Self::Copier> {
+        self.inner.copy(ctx, from, to, args, opts)
+    }
+
+    async fn rename(
+        &self,
+        ctx: &OperationContext,
+        from: &str,
+        to: &str,
+        args: OpRename,
+    ) -> Result<RpRename> {
+        self.inner.rename(ctx, from, to, args).await
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+
+    async fn get_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpGetAcl,
+    ) -> Result<RpGetAcl> {
+        self.inner.get_acl(ctx, path, args).await
+    }
+
+    async fn set_acl(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpSetAcl,
+    ) -> Result<RpSetAcl> {
+        self.inner.set_acl(ctx, path, args).await
+    }
+
+    async fn restore(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpRestore,
+    ) -> Result<RpRestore> {
+        self.inner.restore(ctx, path, args).await
+    }
+}
+
+struct CountReader {
+    inner: oio::Reader,
+    layer: CountLayer,
+}
+
+impl oio::Read for CountReader {
+    async fn open(&self, range: BytesRange) -> Result<(RpRead, Box<dyn oio::ReadStreamDyn>)> {
+        self.layer.counts.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner.open_dyn(range).await
+    }
+
+    async fn read(&self, range: BytesRange) -> Result<(RpRead, Buffer)> {
+        let counts = &self.layer.counts;
+        counts.reads.fetch_add(1, Ordering::SeqCst);
+        let inflight = counts.inflight_reads.fetch_add(1, Ordering::SeqCst) + 1;
+        counts
+            .max_inflight_reads
+            .fetch_max(inflight, Ordering::SeqCst);
+        if let Some(delay) = self.layer.read_delay {
+            tokio::time::sleep(delay).await;
+        }
+        let result = self.inner.read_dyn(range).await;
+        counts.completed_reads.fetch_add(1, Ordering::SeqCst);
+        counts.inflight_reads.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+
+    async fn read_ranges(&self, ranges: Vec<BytesRange>) -> Result<(RpRead, Vec<Buffer>)> {
+        self.layer.counts.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner.read_ranges_dyn(ranges).await
+    }
+}
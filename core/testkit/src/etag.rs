@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use opendal_core::raw::*;
+use opendal_core::*;
+
+/// Layer that assigns etags to written objects and checks `if_match`.
+///
+/// Every successful write gives the object a new etag, which is returned by
+/// `stat` and checked against `if_match` of `stat` and `read`. Objects that
+/// don't have an etag yet are forwarded to the wrapped service as is, so a
+/// removed object still returns `NotFound`.
+///
+/// The wrapped service must ignore `if_match`, so apply the layer directly on
+/// the built service:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use opendal_core::raw::*;
+/// # use opendal_core::services::Memory;
+/// # use opendal_core::*;
+/// # use opendal_testkit::EtagLayer;
+/// # fn test() -> Result<()> {
+/// let srv: Servicer = Arc::new(Memory::default().build()?);
+/// let op = Operator::from_parts(OperationContext::default(), srv).layer(EtagLayer::new());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Copy and rename would need to carry etags over, they are not supported.
+#[derive(Debug, Clone, Default)]
+pub struct EtagLayer {
+    etags: Arc<Etags>,
+}
+
+#[derive(Debug, Default)]
+struct Etags {
+    next: AtomicU64,
+    paths: Mutex<HashMap<String, String>>,
+}
+
+impl Etags {
+    fn get(&self, path: &str) -> Option<String> {
+        self.paths.lock().unwrap().get(path).cloned()
+    }
+
+    fn renew(&self, path: &str) -> String {
+        let etag = format!("\"{}\"", self.next.fetch_add(1, Ordering::SeqCst) + 1);
+        self.paths
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), etag.clone());
+        etag
+    }
+
+    fn remove(&self, path: &str) {
+        self.paths.lock().unwrap().remove(path);
+    }
+
+    fn check(&self, path: &str, if_match: Option<&str>) -> Result<()> {
+        match (if_match, self.get(path)) {
+            (Some(expected), Some(etag)) if expected != etag => Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "etag doesn't match",
+            )
+            .with_context("path", path)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl EtagLayer {
+    /// Create a new `EtagLayer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Layer for EtagLayer {
+    fn apply_service(&self, inner: Servicer) -> Servicer {
+        Arc::new(EtagService {
+            inner,
+            etags: self.etags.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct EtagService {
+    inner: Servicer,
+    etags: Arc<Etags>,
+}
+
+fn unsupported() -> Error {
+    Error::new(
+        ErrorKind::Unsupported,
+        "operation is not supported by EtagLayer",
+    )
+}
+
+impl Service for EtagService {
+    type Reader = oio::Reader;
+    type Writer = EtagWriter;
+    type Lister = oio::Lister;
+    type Deleter = EtagDeleter;
+    type Copier = ();
+
+    fn info(&self) -> ServiceInfo {
+        self.inner.info()
+    }
+
+    fn capability(&self) -> Capability {
+        let mut cap = self.inner.capability();
+        cap.stat_with_if_match = true;
+        cap.read_with_if_match = true;
+        cap.copy = false;
+        cap.rename = false;
+        cap
+    }
+
+    async fn create_dir(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpCreateDir,
+    ) -> Result<RpCreateDir> {
+        self.inner.create_dir(ctx, path, args).await
+    }
+
+    async fn stat(&self, ctx: &OperationContext, path: &str, args: OpStat) -> Result<RpStat> {
+        self.etags.check(path, args.if_match())?;
+        let rp = self.inner.stat(ctx, path, args).await?;
+        Ok(match self.etags.get(path) {
+            Some(etag) => RpStat::new(rp.into_metadata().with_etag(etag)),
+            None => rp,
+        })
+    }
+
+    fn read(&self, ctx: &OperationContext, path: &str, args: OpRead) -> Result<Self::Reader> {
+        self.etags.check(path, args.if_match())?;
+        self.inner.read(ctx, path, args)
+    }
+
+    fn write(&self, ctx: &OperationContext, path: &str, args: OpWrite) -> Result<Self::Writer> {
+        Ok(EtagWriter {
+            inner: self.inner.write(ctx, path, args)?,
+            path: path.to_string(),
+            etags: self.etags.clone(),
+        })
+    }
+
+    fn delete(&self, ctx: &OperationContext) -> Result<Self::Deleter> {
+        Ok(EtagDeleter {
+            inner: self.inner.delete(ctx)?,
+            etags: self.etags.clone(),
+            paths: Vec::new(),
+        })
+    }
+
+    fn list(&self, ctx: &OperationContext, path: &str, args: OpList) -> Result<Self::Lister> {
+        self.inner.list(ctx, path, args)
+    }
+
+    fn copy(
+        &self,
+        _: &OperationContext,
+        _: &str,
+        _: &str,
+        _: OpCopy,
+        _: OpCopier,
+    ) -> Result<Self::Copier> {
+        Err(unsupported())
+    }
+
+    async fn rename(
+        &self,
+        _: &OperationContext,
+        _: &str,
+        _: &str,
+        _: OpRename,
+    ) -> Result<RpRename> {
+        Err(unsupported())
+    }
+
+    async fn presign(
+        &self,
+        ctx: &OperationContext,
+        path: &str,
+        args: OpPresign,
+    ) -> Result<RpPresign> {
+        self.inner.presign(ctx, path, args).await
+    }
+}
+
+struct EtagWriter {
+    inner: oio::Writer,
+    path: String,
+    etags: Arc<Etags>,
+}
+
+impl oio::Write for EtagWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        let meta = self.inner.close().await?;
+        Ok(meta.with_etag(self.etags.renew(&self.path)))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+struct EtagDeleter {
+    inner: oio::Deleter,
+    etags: Arc<Etags>,
+    /// Paths are only deleted once `close` succeeded.
+    paths: Vec<String>,
+}
+
+impl oio::Delete for EtagDeleter {
+    async fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args).await?;
+        self.paths.push(path.to_string());
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await?;
+        for path in self.paths.drain(..) {
+            self.etags.remove(&path);
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,12 @@
 
 //! Test harness and utilities for Apache OpenDAL.
 
+mod count;
+pub use count::CountLayer;
+
+mod etag;
+pub use etag::EtagLayer;
+
 mod read;
 pub use read::ReadAction;
 pub use read::ReadChecker;
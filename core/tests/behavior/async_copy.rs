@@ -39,6 +39,22 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
         ))
     }
 
+    if cap.read && cap.write {
+        tests.extend(async_trials!(
+            op,
+            test_copy_from_other_operator,
+            test_copy_from_same_operator
+        ))
+    }
+
+    if cap.read && cap.write && cap.list {
+        tests.extend(async_trials!(
+            op,
+            test_copy_dir_recursive,
+            test_copy_dir_recursive_into_itself
+        ))
+    }
+
     if cap.read && cap.write && cap.copy && cap.copy_can_multi {
         tests.extend(async_trials!(
             op,
@@ -260,6 +276,109 @@ pub async fn test_copy_nested(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Copy between different operators should stream the content.
+pub async fn test_copy_from_other_operator(op: Operator) -> Result<()> {
+    let other = Operator::new(services::Memory::default())?;
+
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().capability());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    other
+        .copy_from_with(&op, &source_path, &target_path)
+        .concurrent(2)
+        .await?;
+    let target_content = other.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        sha256_digest(target_content),
+        sha256_digest(&source_content),
+        "read content must match"
+    );
+
+    // Copy back from the memory operator.
+    let back_path = uuid::Uuid::new_v4().to_string();
+    op.copy_from(&other, &target_path, &back_path).await?;
+    let back_content = op.read(&back_path).await?.to_bytes();
+    assert_eq!(
+        sha256_digest(back_content),
+        sha256_digest(&source_content),
+        "read content must match"
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&back_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy from the same operator should work with or without native copy.
+pub async fn test_copy_from_same_operator(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().capability());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    op.copy_from(&op.clone(), &source_path, &target_path)
+        .await?;
+    let target_content = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        sha256_digest(target_content),
+        sha256_digest(&source_content),
+        "read content must match"
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy a directory recursively should keep the relative paths.
+pub async fn test_copy_dir_recursive(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+    let files = ["a", "b/c", "b/d/e"];
+
+    let mut contents = Vec::with_capacity(files.len());
+    for file in files {
+        let (content, _) = gen_bytes(op.info().capability());
+        op.write(&format!("{source_dir}{file}"), content.clone())
+            .await?;
+        contents.push(content);
+    }
+
+    op.copy_with(&source_dir, &target_dir)
+        .recursive(true)
+        .concurrent(2)
+        .await?;
+
+    for (file, content) in files.iter().zip(contents) {
+        let target_content = op
+            .read(&format!("{target_dir}{file}"))
+            .await
+            .expect("read must succeed")
+            .to_bytes();
+        assert_eq!(sha256_digest(target_content), sha256_digest(&content));
+    }
+
+    op.delete_with(&source_dir).recursive(true).await?;
+    op.delete_with(&target_dir).recursive(true).await?;
+    Ok(())
+}
+
+/// Copy a directory recursively into itself should fail.
+pub async fn test_copy_dir_recursive_into_itself(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{source_dir}{}/", uuid::Uuid::new_v4());
+
+    let err = op
+        .copy_with(&source_dir, &target_dir)
+        .recursive(true)
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+    Ok(())
+}
+
 /// Copy to a exist path should overwrite successfully.
 pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     let source_path = uuid::Uuid::new_v4().to_string();
@@ -37,7 +37,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_delete_with_version,
             test_delete_with_not_existing_version,
             test_batch_delete,
-            test_batch_delete_with_version
+            test_batch_delete_with_version,
+            test_delete_batch
         ));
         if cap.delete_with_recursive {
             tests.extend(async_trials!(op, test_delete_with_recursive_basic));
@@ -414,3 +415,33 @@ pub async fn test_batch_delete_with_version(op: Operator) -> Result<()> {
 
     Ok(())
 }
+
+/// Delete batch should report the result of every path in input order.
+pub async fn test_delete_batch(op: Operator) -> Result<()> {
+    let mut cap = op.info().capability();
+    if cap.delete_max_size.unwrap_or(1) > 1 {
+        cap.delete_max_size = Some(2);
+    }
+    let op = op.layer(CapabilityOverrideLayer::new(move |_| cap));
+
+    let mut files = Vec::new();
+    for _ in 0..5 {
+        let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+        op.write(path.as_str(), content)
+            .await
+            .expect("write must succeed");
+        files.push(path);
+    }
+    files.insert(2, uuid::Uuid::new_v4().to_string());
+
+    let results = op
+        .delete_batch_options(files.clone(), options::DeleteBatchOptions { concurrent: 2 })
+        .await?;
+    assert_eq!(results.len(), files.len());
+    for (path, result) in files.iter().zip(results) {
+        result.unwrap_or_else(|err| panic!("delete {path} must succeed: {err}"));
+        assert!(!op.exists(path).await?, "{path} should be removed");
+    }
+
+    Ok(())
+}
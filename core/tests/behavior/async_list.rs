@@ -42,6 +42,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_nested_dir,
             test_list_dir_with_file_path,
             test_list_with_start_after,
+            test_list_resume_with_next_token,
+            test_list_with_suffix,
+            test_list_with_glob,
+            test_list_with_regex,
             test_list_non_exist_dir_with_recursive,
             test_list_dir_with_recursive,
             test_list_dir_with_recursive_no_trailing_slash,
@@ -437,6 +441,181 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List resumed from next token should return the remaining entries exactly once.
+pub async fn test_list_resume_with_next_token(op: Operator) -> Result<()> {
+    if !op.info().capability().list_with_start_after {
+        return Ok(());
+    }
+
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    for name in ["a-file", "b-dir/x", "b-dir/y", "c-file"] {
+        op.write(&format!("{dir}{name}"), "content").await?;
+    }
+
+    let expected = vec![
+        format!("{dir}a-file"),
+        format!("{dir}b-dir/"),
+        format!("{dir}c-file"),
+    ];
+
+    let mut actual = vec![];
+    let mut lister = op.lister(dir).await?;
+    while let Some(o) = lister.try_next().await? {
+        if o.path() != dir {
+            actual.push(o.path().to_string());
+        }
+        // Checkpoint right after the common prefix of `b-dir/`.
+        if o.path() == expected[1] {
+            break;
+        }
+    }
+    let token = lister.next_token().expect("token must exist").to_string();
+    drop(lister);
+
+    let mut lister = op.lister_with(dir).start_after(&token).await?;
+    while let Some(o) = lister.try_next().await? {
+        actual.push(o.path().to_string());
+    }
+    assert_eq!(lister.next_token(), Some(expected[2].as_str()));
+
+    assert_eq!(expected, actual);
+
+    op.delete_with(dir).recursive(true).await?;
+
+    Ok(())
+}
+
+/// List with suffix should only return entries ending with the suffix.
+pub async fn test_list_with_suffix(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+
+    for name in ["a.parquet", "b.csv", "c.parquet", "sub/d.parquet"] {
+        op.write(&format!("{dir}{name}"), "content").await?;
+    }
+
+    let mut actual: Vec<String> = op
+        .list_with(dir)
+        .suffix(".parquet")
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![format!("{dir}a.parquet"), format!("{dir}c.parquet")]
+    );
+
+    let mut actual: Vec<String> = op
+        .list_with(dir)
+        .suffix(".parquet")
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![
+            format!("{dir}a.parquet"),
+            format!("{dir}c.parquet"),
+            format!("{dir}sub/d.parquet"),
+        ]
+    );
+
+    op.delete_with(dir).recursive(true).await?;
+    Ok(())
+}
+
+/// List with glob should only return entries matching the pattern.
+pub async fn test_list_with_glob(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+
+    for name in ["a.csv", "b.json", "c.txt", "sub/d.csv", "sub/e.txt"] {
+        op.write(&format!("{dir}{name}"), "content").await?;
+    }
+
+    let mut actual: Vec<String> = op
+        .list_with(dir)
+        .glob("*.{csv,json}")
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(actual, vec![format!("{dir}a.csv"), format!("{dir}b.json")]);
+
+    let mut actual: Vec<String> = op
+        .list_with(dir)
+        .glob("**/*.csv")
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![format!("{dir}a.csv"), format!("{dir}sub/d.csv")]
+    );
+
+    let actual: Vec<String> = op
+        .list_with(dir)
+        .glob("sub/*.csv")
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    assert_eq!(actual, vec![format!("{dir}sub/d.csv")]);
+
+    let res = op.list_with(dir).glob("[abc").await;
+    assert!(res.is_err(), "invalid glob pattern must be rejected");
+
+    op.delete_with(dir).recursive(true).await?;
+    Ok(())
+}
+
+/// List with regex should only return entries whose relative path matches.
+pub async fn test_list_with_regex(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+
+    if cfg!(not(feature = "list-regex")) {
+        let res = op.list_with(dir).regex(r"\d{4}").await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+        return Ok(());
+    }
+
+    for name in ["2023.csv", "2024.csv", "notes.csv", "sub/2024.csv"] {
+        op.write(&format!("{dir}{name}"), "content").await?;
+    }
+
+    let mut actual: Vec<String> = op
+        .list_with(dir)
+        .regex(r"^(.*/)?\d{4}\.csv$")
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect();
+    actual.sort();
+    assert_eq!(
+        actual,
+        vec![
+            format!("{dir}2023.csv"),
+            format!("{dir}2024.csv"),
+            format!("{dir}sub/2024.csv"),
+        ]
+    );
+
+    let res = op.list_with(dir).regex("(").await;
+    assert!(res.is_err(), "invalid regex must be rejected");
+
+    op.delete_with(dir).recursive(true).await?;
+    Ok(())
+}
+
 pub async fn test_list_non_exist_dir_with_recursive(op: Operator) -> Result<()> {
     let dir = format!("{}/", uuid::Uuid::new_v4());
 
@@ -35,7 +35,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_presign_write,
             test_presign_read,
             test_presign_stat,
-            test_presign_delete
+            test_presign_delete,
+            test_presign_multipart
         ))
     }
 }
@@ -167,3 +168,74 @@ pub async fn test_presign_delete(op: Operator) -> Result<()> {
     assert!(!op.exists(&path).await.expect("delete must succeed"));
     Ok(())
 }
+
+/// Presigned multipart upload should succeed.
+pub async fn test_presign_multipart(op: Operator) -> Result<()> {
+    if !op.info().capability().presign_multipart {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().capability());
+    let client = reqwest::Client::new();
+
+    let send = |signed_req: raw::PresignedRequest, body: Vec<u8>| {
+        let mut req = client.request(
+            signed_req.method().clone(),
+            Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+        );
+        for (k, v) in signed_req.header() {
+            req = req.header(k, v);
+        }
+        req.header(header::CONTENT_LENGTH, body.len())
+            .body(reqwest::Body::from(body))
+            .send()
+    };
+
+    let signed_req = match op
+        .presign_create_multipart(&path, Duration::from_secs(3600))
+        .await
+    {
+        Ok(v) => v,
+        // Services like azblob don't need to create multipart uploads.
+        Err(err) if err.kind() == ErrorKind::Unsupported => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+    let resp = send(signed_req, vec![]).await?;
+    assert!(resp.status().is_success(), "create multipart must succeed");
+    let body = resp.text().await?;
+    let upload_id = body
+        .split_once("<UploadId>")
+        .and_then(|(_, v)| v.split_once("</UploadId>"))
+        .map(|(v, _)| v.to_string())
+        .expect("response must contain upload id");
+
+    let signed = op
+        .presign_multipart(&path, &upload_id, 1, Duration::from_secs(3600))
+        .await?;
+    assert_eq!(signed.parts().len(), 1);
+
+    let resp = send(signed.parts()[0].clone(), content.clone()).await?;
+    assert!(resp.status().is_success(), "upload part must succeed");
+    let etag = resp
+        .headers()
+        .get(header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .expect("upload part must return etag")
+        .to_string();
+
+    let complete = format!(
+        "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>{etag}</ETag></Part></CompleteMultipartUpload>"
+    );
+    let resp = send(signed.complete().clone(), complete.into_bytes()).await?;
+    assert!(
+        resp.status().is_success(),
+        "complete multipart must succeed"
+    );
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -53,6 +53,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_read_with_override_cache_control,
             test_read_with_override_content_disposition,
             test_read_with_override_content_type,
+            test_read_with_verify_checksum,
             test_read_with_version,
             test_read_with_not_existing_version
         ))
@@ -746,6 +747,36 @@ pub async fn test_read_with_override_content_disposition(op: Operator) -> anyhow
 }
 
 /// Read file with override_content_type should succeed.
+/// Read with verify_checksum should succeed if the service advertises a
+/// checksum and reject range reads.
+pub async fn test_read_with_verify_checksum(op: Operator) -> anyhow::Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let res = op.read_with(&path).verify_checksum(true).await;
+    match res {
+        Ok(bs) => assert_eq!(bs.to_bytes(), content),
+        Err(err) => assert_eq!(err.kind(), ErrorKind::Unsupported),
+    }
+
+    #[cfg(feature = "checksum")]
+    {
+        let meta = op.stat_with(&path).checksum(true).await?;
+        if let Some(checksum) = meta.checksum() {
+            assert!(checksum.verify(&content.clone().into()).is_ok());
+        }
+    }
+
+    let res = op.read_with(&path).range(0..1).verify_checksum(true).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+    Ok(())
+}
+
 pub async fn test_read_with_override_content_type(op: Operator) -> anyhow::Result<()> {
     if !(op.info().capability().read_with_override_content_type && op.info().capability().presign) {
         return Ok(());
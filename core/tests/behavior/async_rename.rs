@@ -34,6 +34,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_rename_overwrite
         ))
     }
+
+    if cap.read && cap.write && cap.rename && cap.rename_is_atomic {
+        tests.extend(async_trials!(op, test_rename_atomic))
+    }
+
+    if cap.write && cap.rename && !cap.rename_is_atomic {
+        tests.extend(async_trials!(op, test_rename_atomic_unsupported))
+    }
 }
 
 /// Rename a file and test with stat.
@@ -206,3 +214,65 @@ pub async fn test_rename_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Rename with atomic should replace the target or fail without touching it.
+pub async fn test_rename_atomic(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().capability());
+
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+    let (target_content, _) = gen_bytes(op.info().capability());
+    assert_ne!(source_content, target_content);
+
+    op.write(&target_path, target_content.clone()).await?;
+
+    let expected = match op
+        .rename_with(&source_path, &target_path)
+        .atomic(true)
+        .await
+    {
+        Ok(()) => {
+            let err = op.stat(&source_path).await.expect_err("stat must fail");
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+            source_content
+        }
+        // Services that can't replace the target atomically must leave it as is.
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => target_content,
+        Err(err) => return Err(err.into()),
+    };
+
+    let actual = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(sha256_digest(actual), sha256_digest(&expected));
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Rename with atomic should fail on services that can't guarantee it.
+pub async fn test_rename_atomic_unsupported(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().capability());
+
+    op.write(&source_path, source_content).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+
+    let err = op
+        .rename_with(&source_path, &target_path)
+        .atomic(true)
+        .await
+        .expect_err("rename must fail");
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    op.stat(&source_path).await.expect("source must be kept");
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -45,6 +45,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_stat_with_override_content_disposition,
             test_stat_with_override_content_type,
             test_stat_root,
+            test_stat_batch,
+            test_exists_batch,
             test_stat_with_version,
             stat_with_not_existing_version
         ))
@@ -444,6 +446,53 @@ pub async fn test_stat_root(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Stat batch should return results in the same order as the input paths.
+pub async fn test_stat_batch(op: Operator) -> Result<()> {
+    let mut paths = Vec::new();
+    let mut sizes = Vec::new();
+    for _ in 0..5 {
+        let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+        op.write(&path, content).await.expect("write must succeed");
+        paths.push(path);
+        sizes.push(size);
+    }
+    let not_exist = uuid::Uuid::new_v4().to_string();
+    paths.insert(2, not_exist.clone());
+
+    let results = op
+        .stat_batch_options(&paths, options::StatBatchOptions { concurrent: 2 })
+        .await?;
+    assert_eq!(results.len(), paths.len());
+
+    let mut sizes = sizes.into_iter();
+    for (path, result) in paths.iter().zip(results) {
+        if path == &not_exist {
+            let err = result.expect_err("stat not exist path must fail");
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+            continue;
+        }
+        let meta = result?;
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), sizes.next().unwrap() as u64);
+    }
+
+    Ok(())
+}
+
+/// Exists batch should map `NotFound` to `false` in input order.
+pub async fn test_exists_batch(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await.expect("write must succeed");
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    let results = op.exists_batch([&not_exist, &path]).await?;
+    assert_eq!(results.len(), 2);
+    assert!(!results[0].as_ref().expect("exists must succeed"));
+    assert!(results[1].as_ref().expect("exists must succeed"));
+
+    Ok(())
+}
+
 /// Stat normal file and dir should return metadata
 pub async fn test_read_only_stat_file_and_dir(op: Operator) -> Result<()> {
     let meta = op.stat("normal_file.txt").await?;
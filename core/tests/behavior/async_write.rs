@@ -43,6 +43,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_write_with_content_disposition,
             test_write_with_content_encoding,
             test_write_with_if_none_match,
+            test_write_with_if_none_match_any,
             test_write_with_if_not_exists,
             test_write_with_if_match,
             test_write_with_user_metadata,
@@ -57,7 +58,9 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_futures_copy,
             test_writer_futures_copy_with_concurrent,
             test_writer_return_metadata,
-            test_writer_write_non_contiguous_data
+            test_writer_write_non_contiguous_data,
+            test_write_from,
+            test_write_from_with_wrong_size
         ))
     }
 
@@ -69,6 +72,71 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_with_append
         ))
     }
+
+    if cap.read && cap.write && cap.write_can_seek && cap.stat {
+        tests.extend(async_trials!(op, test_write_with_offset))
+    }
+
+    if cap.read && cap.write && cap.write_can_multi && cap.write_with_checkpoint {
+        tests.extend(async_trials!(op, test_writer_suspend_and_resume))
+    }
+}
+
+/// Write from an AsyncRead source with and without known size.
+pub async fn test_write_from(op: Operator) -> Result<()> {
+    let cap = op.info().capability();
+    let chunk = cap.write_multi_min_size.unwrap_or(5 * 1024 * 1024);
+
+    let mut cases = vec![(1024, true), (1024, false)];
+    if cap.write_can_multi {
+        cases.extend([(chunk * 2 + 1, true), (chunk * 2 + 1, false)]);
+    }
+
+    for (len, known) in cases {
+        let path = TEST_FIXTURE.new_file_path();
+        let (content, _) = gen_bytes_with_range(len..=len);
+
+        op.write_from(
+            &path,
+            Cursor::new(content.clone()),
+            options::WriteFromOptions {
+                size: known.then_some(len as u64),
+                write: options::WriteOptions {
+                    chunk: Some(chunk),
+                    ..Default::default()
+                },
+            },
+        )
+        .await?;
+
+        let bs = op.read(&path).await?.to_bytes();
+        assert_eq!(bs.len(), len, "read size");
+        assert_eq!(sha256_digest(&bs), sha256_digest(&content), "read content");
+    }
+
+    Ok(())
+}
+
+/// Write from an AsyncRead source that doesn't match the given size should fail.
+pub async fn test_write_from_with_wrong_size(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let (content, size) = gen_bytes(op.info().capability());
+
+    for expect in [size as u64 + 1, size as u64 - 1] {
+        let res = op
+            .write_from(
+                &path,
+                Cursor::new(content.clone()),
+                options::WriteFromOptions {
+                    size: Some(expect),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(res.is_err(), "write with wrong size must fail");
+    }
+
+    Ok(())
 }
 
 /// Write a single file and test with stat.
@@ -392,6 +460,33 @@ pub async fn test_writer_write_with_concurrent(op: Operator) -> Result<()> {
 }
 
 /// Streaming data into writer
+/// Suspend a writer and resume it with the checkpoint.
+pub async fn test_writer_suspend_and_resume(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let chunk = 5 * 1024 * 1024;
+    let content = gen_fixed_bytes(chunk * 3 + 1024);
+
+    let mut w = op.writer_with(&path).chunk(chunk).await?;
+    w.write(content[..chunk * 2 + 1024].to_vec()).await?;
+    let checkpoint = w.suspend().await?;
+    assert!(checkpoint.size() <= (chunk * 2) as u64);
+
+    let mut w = op
+        .writer_with(&path)
+        .chunk(chunk)
+        .checkpoint(checkpoint.clone())
+        .await?;
+    w.write(content[checkpoint.size() as usize..].to_vec())
+        .await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(sha256_digest(&bs), sha256_digest(&content), "read content");
+
+    Ok(())
+}
+
 pub async fn test_writer_sink(op: Operator) -> Result<()> {
     let cap = op.info().capability();
     if !(cap.write && cap.write_can_multi) {
@@ -687,6 +782,44 @@ pub async fn test_writer_with_append(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write at offsets aligned to 512 bytes since page based storage like azblob
+/// page blobs can only be written in pages.
+pub async fn test_write_with_offset(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let content = vec![1; 2048];
+
+    op.write_with(&path, content.clone())
+        .offset(0)
+        .await
+        .expect("write at offset 0 must success");
+
+    op.write_with(&path, vec![2; 512])
+        .offset(512)
+        .await
+        .expect("overwrite at offset must success");
+
+    let mut w = op.writer_with(&path).offset(2048).await?;
+    w.write(vec![3; 512]).await?;
+    w.write(vec![4; 512]).await?;
+    w.close().await.expect("extend at offset must success");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_length(), 3072);
+
+    let bs = op
+        .read(&path)
+        .await
+        .expect("read file must success")
+        .to_vec();
+    assert_eq!(bs[..512], content[..512]);
+    assert_eq!(bs[512..1024], [2; 512]);
+    assert_eq!(bs[1024..2048], content[1024..]);
+    assert_eq!(bs[2048..2560], [3; 512]);
+    assert_eq!(bs[2560..], [4; 512]);
+
+    Ok(())
+}
+
 pub async fn test_writer_write_with_overwrite(op: Operator) -> Result<()> {
     // ghac does not support overwrite
     #[cfg(feature = "services-ghac")]
@@ -748,6 +881,29 @@ pub async fn test_write_with_if_none_match(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a file with `if_none_match("*")` will get a ConditionNotMatch error if file exists.
+pub async fn test_write_with_if_none_match_any(op: Operator) -> Result<()> {
+    if !op.info().capability().write_with_if_none_match {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write_with(&path, content.clone())
+        .if_none_match("*")
+        .await
+        .expect("write must succeed");
+
+    let res = op
+        .write_with(&path, content.clone())
+        .if_none_match("*")
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    Ok(())
+}
+
 /// Write a file with if_not_exists will get a ConditionNotMatch error if file exists.
 pub async fn test_write_with_if_not_exists(op: Operator) -> Result<()> {
     if !op.info().capability().write_with_if_not_exists {